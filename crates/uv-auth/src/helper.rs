@@ -0,0 +1,277 @@
+use std::process::Stdio;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+use tokio::process::Command;
+use tracing::{debug, instrument, warn};
+use url::Url;
+
+use crate::credentials::Credentials;
+use crate::realm::Realm;
+
+/// The credential helpers in use, shared across clients so that tokens are minted once per run.
+static HELPERS: LazyLock<Mutex<FxHashMap<(TokenProvider, Realm), Arc<CredentialHelper>>>> =
+    LazyLock::new(Mutex::default);
+
+/// A cloud service that mints short-lived tokens for a package registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenProvider {
+    /// AWS CodeArtifact, via `aws codeartifact get-authorization-token`.
+    AwsCodeArtifact,
+    /// Google Cloud Artifact Registry, via `gcloud auth print-access-token`.
+    GcpArtifactRegistry,
+    /// Azure Artifacts, via `az account get-access-token`.
+    AzureArtifacts,
+}
+
+impl TokenProvider {
+    /// The username to pair with the minted token.
+    fn username(self) -> &'static str {
+        match self {
+            Self::AwsCodeArtifact => "aws",
+            Self::GcpArtifactRegistry => "oauth2accesstoken",
+            Self::AzureArtifacts => "azure",
+        }
+    }
+
+    /// The duration for which a minted token is reused before minting a new one.
+    ///
+    /// CodeArtifact tokens are requested with a fixed lifetime. The `gcloud` and `az` CLIs cache
+    /// tokens themselves and may return one that's close to expiring, so those are re-requested
+    /// frequently (which is cheap, as the CLIs return the cached token until it's refreshed).
+    fn lifetime(self) -> Duration {
+        match self {
+            Self::AwsCodeArtifact => Duration::from_secs(55 * 60),
+            Self::GcpArtifactRegistry | Self::AzureArtifacts => Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// The command to run to mint a token for the registry at the given URL, which prints the
+    /// token to stdout.
+    fn command(self, url: &Url) -> Option<Command> {
+        match self {
+            Self::AwsCodeArtifact => {
+                // e.g., `https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/`
+                let host = url.host_str()?;
+                let mut labels = host.split('.');
+                let (domain, owner) = labels.next()?.rsplit_once('-')?;
+                if labels.next()? != "d" || labels.next()? != "codeartifact" {
+                    return None;
+                }
+                let region = labels.next()?;
+
+                let mut command = Command::new("aws");
+                command
+                    .args(["codeartifact", "get-authorization-token"])
+                    .args(["--domain", domain])
+                    .args(["--domain-owner", owner])
+                    .args(["--region", region])
+                    .args(["--duration-seconds", "3600"])
+                    .args(["--query", "authorizationToken"])
+                    .args(["--output", "text"]);
+                Some(command)
+            }
+            Self::GcpArtifactRegistry => {
+                let mut command = Command::new(if cfg!(windows) {
+                    "gcloud.cmd"
+                } else {
+                    "gcloud"
+                });
+                command.args(["auth", "print-access-token"]);
+                Some(command)
+            }
+            Self::AzureArtifacts => {
+                let mut command = Command::new(if cfg!(windows) { "az.cmd" } else { "az" });
+                command
+                    .args(["account", "get-access-token"])
+                    // The resource ID of Azure DevOps.
+                    .args(["--resource", "499b84ac-1321-427f-aa17-267ca6975798"])
+                    .args(["--query", "accessToken"])
+                    .args(["--output", "tsv"]);
+                Some(command)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for TokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AwsCodeArtifact => write!(f, "aws-codeartifact"),
+            Self::GcpArtifactRegistry => write!(f, "gcp-artifact-registry"),
+            Self::AzureArtifacts => write!(f, "azure-artifacts"),
+        }
+    }
+}
+
+/// A minted token, and when it should be replaced.
+#[derive(Debug)]
+struct Token {
+    credentials: Arc<Credentials>,
+    refresh_at: Instant,
+}
+
+/// A helper that mints short-lived credentials for a registry from the ambient cloud credentials
+/// (e.g., an AWS profile, `gcloud auth login`, `az login`, or OIDC federation in CI), and
+/// refreshes them as they expire.
+#[derive(Debug)]
+pub struct CredentialHelper {
+    provider: TokenProvider,
+    url: Url,
+    realm: Realm,
+    token: tokio::sync::Mutex<Option<Token>>,
+}
+
+impl CredentialHelper {
+    /// Return the [`CredentialHelper`] for the registry at the given URL.
+    pub fn new(provider: TokenProvider, url: &Url) -> Arc<Self> {
+        let realm = Realm::from(url);
+        let mut helpers = HELPERS.lock().unwrap();
+        helpers
+            .entry((provider, realm.clone()))
+            .or_insert_with(|| {
+                Arc::new(Self {
+                    provider,
+                    url: url.clone(),
+                    realm,
+                    token: tokio::sync::Mutex::new(None),
+                })
+            })
+            .clone()
+    }
+
+    /// Returns `true` if the helper provides credentials for the given URL.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        self.realm == Realm::from(url)
+    }
+
+    /// Return the current credentials, minting new ones if none exist or they're due to expire.
+    pub(crate) async fn credentials(&self) -> Option<Arc<Credentials>> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            if Instant::now() < token.refresh_at {
+                return Some(token.credentials.clone());
+            }
+            debug!(
+                "Credentials from `{}` are expiring; refreshing",
+                self.provider
+            );
+        }
+        *token = self.mint().await;
+        token.as_ref().map(|token| token.credentials.clone())
+    }
+
+    /// Mint new credentials after the given credentials were rejected, unless they've already
+    /// been replaced (e.g., by a concurrent request).
+    pub(crate) async fn refresh(&self, rejected: &Arc<Credentials>) -> Option<Arc<Credentials>> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            if !Arc::ptr_eq(&token.credentials, rejected) {
+                return Some(token.credentials.clone());
+            }
+        }
+        debug!(
+            "Credentials from `{}` were rejected; refreshing",
+            self.provider
+        );
+        *token = self.mint().await;
+        token.as_ref().map(|token| token.credentials.clone())
+    }
+
+    /// Run the provider's command to mint a new token.
+    #[instrument(skip(self), fields(provider = %self.provider, url = %self.url))]
+    async fn mint(&self) -> Option<Token> {
+        let Some(mut command) = self.provider.command(&self.url) else {
+            warn!(
+                "Unable to infer the `{}` registry from the index URL: {}",
+                self.provider, self.url
+            );
+            return None;
+        };
+
+        debug!(
+            "Minting credentials for {} via `{}`",
+            self.realm, self.provider
+        );
+        let output = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .await
+            .inspect_err(|err| warn!("Failed to run credential helper `{}`: {err}", self.provider))
+            .ok()?;
+
+        if !output.status.success() {
+            warn!(
+                "Credential helper `{}` failed with {}",
+                self.provider, output.status
+            );
+            return None;
+        }
+
+        let token = String::from_utf8(output.stdout)
+            .inspect_err(|err| warn!("Failed to parse token from `{}`: {err}", self.provider))
+            .ok()?;
+        let token = token.trim();
+        if token.is_empty() {
+            warn!(
+                "Credential helper `{}` returned an empty token",
+                self.provider
+            );
+            return None;
+        }
+
+        Some(Token {
+            credentials: Arc::new(Credentials::new(
+                Some(self.provider.username().to_string()),
+                Some(token.to_string()),
+            )),
+            refresh_at: Instant::now() + self.provider.lifetime(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::TokenProvider;
+
+    #[test]
+    fn codeartifact_command() {
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+        )
+        .unwrap();
+        let command = TokenProvider::AwsCodeArtifact.command(&url).unwrap();
+        let args = command
+            .as_std()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            args,
+            [
+                "codeartifact",
+                "get-authorization-token",
+                "--domain",
+                "my-domain",
+                "--domain-owner",
+                "111122223333",
+                "--region",
+                "us-west-2",
+                "--duration-seconds",
+                "3600",
+                "--query",
+                "authorizationToken",
+                "--output",
+                "text"
+            ]
+        );
+
+        let url = Url::parse("https://pypi.org/simple/").unwrap();
+        assert!(TokenProvider::AwsCodeArtifact.command(&url).is_none());
+    }
+}
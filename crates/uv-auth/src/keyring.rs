@@ -18,6 +18,8 @@ pub struct KeyringProvider {
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use the operating system's credential store to fetch credentials.
+    Native,
     #[cfg(test)]
     Dummy(std::collections::HashMap<(String, &'static str), &'static str>),
 }
@@ -30,6 +32,13 @@ impl KeyringProvider {
         }
     }
 
+    /// Create a new [`KeyringProvider::Native`].
+    pub fn native() -> Self {
+        Self {
+            backend: KeyringProviderBackend::Native,
+        }
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -57,6 +66,7 @@ impl KeyringProvider {
             KeyringProviderBackend::Subprocess => {
                 self.fetch_subprocess(url.as_str(), username).await
             }
+            KeyringProviderBackend::Native => self.fetch_native(url.as_str(), username).await,
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
                 Self::fetch_dummy(store, url.as_str(), username)
@@ -72,6 +82,7 @@ impl KeyringProvider {
             trace!("Checking keyring for host {host}");
             password = match self.backend {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
+                KeyringProviderBackend::Native => self.fetch_native(&host, username).await,
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
                     Self::fetch_dummy(store, &host, username)
@@ -114,6 +125,29 @@ impl KeyringProvider {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn fetch_native(&self, service_name: &str, username: &str) -> Option<String> {
+        let entry = keyring::Entry::new(service_name, username)
+            .inspect_err(|err| warn!("Failed to create keyring entry: {err}"))
+            .ok()?;
+
+        // The platform credential stores are blocking, and may prompt the user.
+        let password = tokio::task::spawn_blocking(move || entry.get_password())
+            .await
+            .inspect_err(|err| warn!("Failed to wait for keyring lookup: {err}"))
+            .ok()?;
+
+        match password {
+            Ok(password) => Some(password),
+            // No password was available.
+            Err(keyring::Error::NoEntry) => None,
+            Err(err) => {
+                warn!("Failure reading from the native keyring: {err}");
+                None
+            }
+        }
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &std::collections::HashMap<(String, &'static str), &'static str>,
@@ -5,15 +5,20 @@ use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
+use credentials::Username;
+pub use helper::{CredentialHelper, TokenProvider};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
+pub use store::{CredentialStore, StoreError, StoredCredential};
 
 mod cache;
 mod credentials;
+mod helper;
 mod keyring;
 mod middleware;
 mod realm;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
@@ -43,3 +48,13 @@ pub fn store_credentials(url: &Url, credentials: Arc<Credentials>) {
     trace!("Caching credentials for {url}");
     CREDENTIALS_CACHE.insert(url, credentials);
 }
+
+/// Return the credentials for a URL from the global authentication store, if any.
+///
+/// Only returns credentials that include a password.
+pub fn cached_credentials(url: &Url) -> Option<Arc<Credentials>> {
+    CREDENTIALS_CACHE
+        .get_url(url, &Username::none())
+        .or_else(|| CREDENTIALS_CACHE.get_realm(Realm::from(url), Username::none()))
+        .filter(|credentials| credentials.password().is_some())
+}
@@ -6,7 +6,7 @@ use url::Url;
 use crate::{
     credentials::{Credentials, Username},
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    CredentialHelper, CredentialStore, CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -55,6 +55,7 @@ impl NetrcMode {
 pub struct AuthMiddleware {
     netrc: NetrcMode,
     keyring: Option<KeyringProvider>,
+    credential_helpers: Vec<Arc<CredentialHelper>>,
     cache: Option<CredentialsCache>,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
@@ -66,6 +67,7 @@ impl AuthMiddleware {
         Self {
             netrc: NetrcMode::default(),
             keyring: None,
+            credential_helpers: Vec::new(),
             cache: None,
             only_authenticated: false,
         }
@@ -91,6 +93,16 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialHelper`]s to use for requests to their registries.
+    #[must_use]
+    pub fn with_credential_helpers(
+        mut self,
+        credential_helpers: Vec<Arc<CredentialHelper>>,
+    ) -> Self {
+        self.credential_helpers = credential_helpers;
+        self
+    }
+
     /// Configure the [`CredentialsCache`] to use.
     #[must_use]
     pub fn with_cache(mut self, cache: CredentialsCache) -> Self {
@@ -172,6 +184,24 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, credentials.as_ref());
         trace!("Handling request for {url}");
 
+        // If a credential helper is configured for the registry, and the request doesn't already
+        // carry a password, use the helper's short-lived credentials.
+        if credentials
+            .as_ref()
+            .is_none_or(|credentials| credentials.password().is_none())
+        {
+            if let Some(helper) = self
+                .credential_helpers
+                .iter()
+                .find(|helper| helper.matches(request.url()))
+            {
+                trace!("Using credential helper for {url}");
+                return self
+                    .complete_helper_request(helper, request, extensions, next)
+                    .await;
+            }
+        }
+
         if let Some(credentials) = credentials {
             let credentials = Arc::new(credentials);
 
@@ -363,6 +393,48 @@ impl AuthMiddleware {
         result
     }
 
+    /// Run a request with credentials from a [`CredentialHelper`].
+    ///
+    /// If the server rejects the credentials (e.g., because the token expired mid-run), mint new
+    /// credentials and retry the request once.
+    async fn complete_helper_request(
+        &self,
+        helper: &CredentialHelper,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(credentials) = helper.credentials().await else {
+            // If the helper fails, attempt the request without credentials.
+            return next.run(request, extensions).await;
+        };
+
+        let retry_request = request.try_clone();
+        let response = next
+            .clone()
+            .run(credentials.authenticate(request), extensions)
+            .await?;
+        if !matches!(
+            response.status(),
+            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED
+        ) {
+            return Ok(response);
+        }
+
+        let Some(retry_request) = retry_request else {
+            return Ok(response);
+        };
+        let Some(refreshed) = helper.refresh(&credentials).await else {
+            return Ok(response);
+        };
+        trace!(
+            "Retrying request for {} with refreshed credentials",
+            retry_request.url()
+        );
+        next.run(refreshed.authenticate(retry_request), extensions)
+            .await
+    }
+
     /// Fetch credentials for a URL.
     ///
     /// Supports netrc file and keyring lookups.
@@ -429,6 +501,15 @@ impl AuthMiddleware {
         } {
             debug!("Found credentials in keyring for {url}");
             Some(credentials)
+        } else if let Some(credentials) = self
+            .fetch_stored(
+                url,
+                credentials.and_then(|credentials| credentials.username()),
+            )
+            .await
+        {
+            debug!("Found credentials in credential store for {url}");
+            Some(credentials)
         } else {
             None
         }
@@ -439,6 +520,18 @@ impl AuthMiddleware {
 
         credentials
     }
+
+    /// Fetch credentials stored with `uv auth login` from the system keyring.
+    async fn fetch_stored(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let stored = CredentialStore::global()?.find(url, username)?;
+        debug!(
+            "Checking credential store for credentials for {}@{url}",
+            stored.username
+        );
+        KeyringProvider::native()
+            .fetch(&stored.service, &stored.username)
+            .await
+    }
 }
 
 fn tracing_url(request: &Request, credentials: Option<&Credentials>) -> String {
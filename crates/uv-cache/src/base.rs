@@ -0,0 +1,154 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use crate::{ArchiveId, Cache, CacheBucket};
+
+impl Cache {
+    /// Seed a path in the cache from the base cache, if it's missing from the cache but present in
+    /// the base cache.
+    ///
+    /// Files are linked to (or, on Windows, copied from) the base cache, while directories are
+    /// recreated, such that any later writes (e.g., to revalidate an entry, or to lock a shard)
+    /// target the cache rather than the read-only base cache. Paths are seeded at the granularity
+    /// at which they're accessed: a directory that's already present in the cache isn't merged
+    /// with its counterpart in the base cache.
+    ///
+    /// Seeding is an optimization, so failures are logged, but otherwise ignored.
+    pub(crate) fn seed(&self, cache_bucket: CacheBucket, path: &Path) {
+        let Some(base) = self.base.as_deref() else {
+            return;
+        };
+
+        // Unzipped wheels are immutable, and so are read from the base cache directly (see
+        // `Cache::archive`), while the remaining buckets are either ephemeral or aren't
+        // relocatable (e.g., cached environments).
+        if !matches!(
+            cache_bucket,
+            CacheBucket::Wheels
+                | CacheBucket::SourceDistributions
+                | CacheBucket::FlatIndex
+                | CacheBucket::Interpreter
+                | CacheBucket::Simple
+        ) {
+            return;
+        }
+
+        if path.symlink_metadata().is_ok() {
+            return;
+        }
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return;
+        };
+        let source = base.join(relative);
+        if source.symlink_metadata().is_err() {
+            return;
+        }
+
+        debug!("Seeding cache entry from base cache: {}", source.display());
+        if let Err(err) = mirror(&source, path) {
+            debug!("Failed to seed `{}` from base cache: {err}", path.display());
+        }
+    }
+
+    /// Return the path to an archive in the base cache, if it's missing from the cache but
+    /// present in the base cache.
+    pub(crate) fn base_archive(&self, id: &ArchiveId) -> Option<PathBuf> {
+        let base = self.base.as_deref()?;
+        if self.bucket(CacheBucket::Archive).join(id).exists() {
+            return None;
+        }
+        let path = base.join(CacheBucket::Archive.to_str()).join(id);
+        path.exists().then_some(path)
+    }
+}
+
+/// Mirror a file or directory from the base cache into the cache.
+///
+/// The mirror is created in a temporary directory alongside the target, and then moved into
+/// place, such that concurrent readers never observe a partially-seeded entry.
+fn mirror(source: &Path, target: &Path) -> io::Result<()> {
+    let parent = target.parent().expect("Cache entry to have parent");
+    fs_err::create_dir_all(parent)?;
+    let temp_dir = tempfile::tempdir_in(parent)?;
+    let temp = temp_dir.path().join("entry");
+
+    if source.symlink_metadata()?.is_dir() {
+        for entry in walkdir::WalkDir::new(source) {
+            let entry = entry?;
+            let relative = entry
+                .path()
+                .strip_prefix(source)
+                .expect("walkdir starts with root");
+            let dest = temp.join(relative);
+            if entry.file_type().is_dir() {
+                fs_err::create_dir_all(&dest)?;
+            } else {
+                link_file(entry.path(), &dest)?;
+            }
+        }
+    } else {
+        link_file(source, &temp)?;
+    }
+
+    match fs_err::rename(&temp, target) {
+        Ok(()) => Ok(()),
+        // If another process seeded the entry concurrently, retain it.
+        Err(_) if target.symlink_metadata().is_ok() => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Link to a file in the base cache.
+#[cfg(unix)]
+fn link_file(source: &Path, dest: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(source, dest)
+}
+
+/// Copy a file from the base cache, as creating symlinks requires elevated privileges on Windows.
+#[cfg(not(unix))]
+fn link_file(source: &Path, dest: &Path) -> io::Result<()> {
+    fs_err::copy(source, dest)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArchiveId, Cache, CacheBucket};
+
+    #[test]
+    fn seed() {
+        let base = Cache::temp().unwrap();
+        let shard = base.shard(CacheBucket::Wheels, "pypi/iniconfig");
+        fs_err::create_dir_all(&*shard).unwrap();
+        fs_err::write(shard.join("iniconfig-2.0.0.http"), b"pointer").unwrap();
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(base.archive(&id)).unwrap();
+
+        let cache = Cache::temp()
+            .unwrap()
+            .with_base(Some(base.root().to_path_buf()));
+
+        // Missing entries are seeded from the base cache.
+        let entry = cache.entry(
+            CacheBucket::Wheels,
+            "pypi/iniconfig",
+            "iniconfig-2.0.0.http",
+        );
+        assert!(entry.path().starts_with(cache.root()));
+        assert_eq!(fs_err::read(entry.path()).unwrap(), b"pointer");
+
+        // Entries that are missing from both caches are left as-is.
+        let entry = cache.entry(
+            CacheBucket::Wheels,
+            "pypi/iniconfig",
+            "iniconfig-1.0.0.http",
+        );
+        assert!(!entry.path().exists());
+
+        // Archives are read from the base cache directly.
+        assert!(cache.archive(&id).starts_with(base.root()));
+        assert!(cache.archive(&ArchiveId::new()).starts_with(cache.root()));
+    }
+}
@@ -29,6 +29,34 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// The maximum time to wait for a lock on a cache entry, in seconds.
+    ///
+    /// By default, uv waits indefinitely for other uv processes to release the locks that guard
+    /// writes to the cache. When the cache is shared between machines (e.g., on an NFS or SMB
+    /// volume), a lock may be held by a process that was terminated without releasing it; with a
+    /// timeout, uv fails with an error instead of waiting forever.
+    #[arg(
+        global = true,
+        long,
+        value_name = "SECONDS",
+        env = EnvVars::UV_CACHE_LOCK_TIMEOUT,
+    )]
+    pub cache_lock_timeout: Option<u64>,
+
+    /// Avoid reading cached information about Python interpreters, querying each interpreter
+    /// instead.
+    ///
+    /// By default, uv caches the result of querying a Python interpreter, keyed by the path and
+    /// modification time of its executable, to avoid spawning every interpreter on each
+    /// invocation. The cache is refreshed with the results of the new queries.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_NO_DISCOVERY_CACHE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub no_discovery_cache: bool,
 }
 
 impl Cache {
@@ -0,0 +1,188 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use async_compression::tokio::write::ZstdEncoder;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_fs::LockedFile;
+
+use crate::eviction::{children, usage, Links};
+use crate::{rm_rf, Cache, CacheBucket, Removal};
+
+/// A file in the cache root, the modification time of which records when unused source trees
+/// were last compressed.
+pub(crate) const COMPRESSION_MARKER: &str = ".compressed";
+
+/// The period after which an unused source tree is compressed.
+const IDLE_PERIOD: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Return the path of the compressed archive that replaces the directory at the given path, e.g.,
+/// `src.tar.zst` for `src`.
+pub fn compressed_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tar.zst");
+    path.with_file_name(name)
+}
+
+impl Cache {
+    /// Compress the unpacked source trees of source distributions that haven't been used within
+    /// the last week.
+    ///
+    /// Each source tree is replaced by a `.tar.zst` archive alongside it, which is unpacked again
+    /// when the source distribution is next built. The wheels built from a source distribution
+    /// are left as-is, as are the source trees of Git and Mercurial checkouts. Source trees that
+    /// are locked by another process (e.g., as they're being built) are skipped.
+    ///
+    /// Returns a [`Removal`] for the source trees that were replaced.
+    pub async fn compress_sources(&self) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        let root = self.bucket(CacheBucket::SourceDistributions);
+        if !root.is_dir() {
+            return Ok(summary);
+        }
+
+        let Some(cutoff) = SystemTime::now().checked_sub(IDLE_PERIOD) else {
+            return Ok(summary);
+        };
+        let links = Links::default();
+        for shard in source_shards(&root)? {
+            for revision in children(&shard)? {
+                let source = revision.join("src");
+                if !source.is_dir() {
+                    continue;
+                }
+                let (_, last_used) = usage(&source, &links)?;
+                if last_used >= cutoff {
+                    continue;
+                }
+
+                // Skip source trees that are in use, rather than waiting for them.
+                let _lock = match LockedFile::acquire_with_timeout(
+                    shard.join(".lock"),
+                    shard.display(),
+                    Some(Duration::ZERO),
+                )
+                .await
+                {
+                    Ok(lock) => lock,
+                    Err(err) => {
+                        debug!("Skipping compression of `{}`: {err}", source.display());
+                        continue;
+                    }
+                };
+
+                debug!("Compressing unused source tree: {}", source.display());
+                compress(&source).await?;
+                summary += rm_rf(&source)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Compress unused source trees, unless they were already compressed within the last day.
+    ///
+    /// Returns `None` if the source trees weren't compressed.
+    pub async fn compress_sources_periodically(&self) -> Result<Option<Removal>, io::Error> {
+        if !self.is_due(COMPRESSION_MARKER)? {
+            return Ok(None);
+        }
+        self.compress_sources().await.map(Some)
+    }
+}
+
+/// Return the shards in the source distribution bucket that contain a revision pointer, each of
+/// which holds one or more revisions of a source distribution.
+fn source_shards(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut shards = Vec::new();
+    let mut walker = walkdir::WalkDir::new(root).min_depth(1).into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = entry?;
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        if ["revision.http", "revision.rev"]
+            .iter()
+            .any(|file| path.join(file).is_file())
+        {
+            shards.push(path.to_path_buf());
+            walker.skip_current_dir();
+        }
+    }
+    Ok(shards)
+}
+
+/// Write the directory at the given path to a `.tar.zst` archive alongside it.
+///
+/// The archive is written to a temporary file, and then moved into place, such that readers never
+/// observe a partially-written archive.
+async fn compress(source: &Path) -> io::Result<()> {
+    let parent = source.parent().expect("Cache entry to have parent");
+    let name = source.file_name().expect("Cache entry to have file name");
+    let (file, temp_path) = tempfile::NamedTempFile::new_in(parent)?.into_parts();
+
+    let mut builder = tokio_tar::Builder::new(ZstdEncoder::new(tokio::fs::File::from_std(file)));
+    builder.follow_symlinks(false);
+    builder.append_dir_all(name, source).await?;
+    let mut encoder = builder.into_inner().await?;
+    encoder.shutdown().await?;
+
+    temp_path
+        .persist(compressed_path(source))
+        .map_err(|err| err.error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::{Duration, SystemTime};
+
+    use crate::{compressed_path, Cache, CacheBucket};
+
+    #[test]
+    fn compressed() {
+        assert_eq!(
+            compressed_path(Path::new("/cache/sdists-v9/pypi/idna/3.10/abc/src")),
+            Path::new("/cache/sdists-v9/pypi/idna/3.10/abc/src.tar.zst")
+        );
+    }
+
+    #[tokio::test]
+    async fn compress_unused_sources() {
+        let cache = Cache::temp().unwrap();
+        let shard = cache.shard(CacheBucket::SourceDistributions, "pypi/idna/3.10");
+        fs_err::create_dir_all(&*shard).unwrap();
+        fs_err::write(shard.join("revision.http"), b"revision").unwrap();
+
+        // An unused source tree.
+        let unused = shard.join("unused").join("src");
+        fs_err::create_dir_all(unused.join("idna")).unwrap();
+        fs_err::write(unused.join("idna").join("__init__.py"), b"").unwrap();
+        let long_ago = SystemTime::now() - Duration::from_secs(30 * 24 * 60 * 60);
+        let times = std::fs::FileTimes::new()
+            .set_accessed(long_ago)
+            .set_modified(long_ago);
+        std::fs::File::options()
+            .write(true)
+            .open(unused.join("idna").join("__init__.py"))
+            .unwrap()
+            .set_times(times)
+            .unwrap();
+
+        // A recently-used source tree.
+        let used = shard.join("used").join("src");
+        fs_err::create_dir_all(&used).unwrap();
+        fs_err::write(used.join("pyproject.toml"), b"").unwrap();
+
+        let removal = cache.compress_sources().await.unwrap();
+        assert_eq!(removal.num_files, 1);
+        assert!(!unused.exists());
+        assert!(compressed_path(&unused).is_file());
+        assert!(used.is_dir());
+        assert!(!compressed_path(&used).exists());
+    }
+}
@@ -0,0 +1,212 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[cfg(unix)]
+use tracing::debug;
+
+#[cfg(unix)]
+use crate::CacheBucket;
+use crate::{Cache, Removal};
+
+/// The minimum size of a file to store by content, in bytes.
+///
+/// Smaller files occupy a single block regardless, and so aren't worth the cost of hashing.
+#[cfg(unix)]
+const MIN_SIZE: u64 = 4096;
+
+impl Cache {
+    /// Deduplicate the files in a directory against the content-addressed store, replacing each
+    /// file with a hard link to the stored file with the same contents and permissions.
+    ///
+    /// Files that aren't yet in the store are added to it, such that later directories (e.g.,
+    /// another version or platform of the same package) can be deduplicated against them. As
+    /// environments are hard-linked against the unzipped wheels, they share the stored files too.
+    ///
+    /// Deduplication is an optimization: files that can't be linked (e.g., as the store is on a
+    /// different filesystem) are left as-is.
+    #[cfg(unix)]
+    pub(crate) fn deduplicate(&self, dir: &Path) -> io::Result<()> {
+        let store = self.bucket(CacheBucket::Content);
+        for entry in walkdir::WalkDir::new(dir) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if metadata.len() < MIN_SIZE {
+                continue;
+            }
+
+            let key = content_key(entry.path(), &metadata)?;
+            let stored = store.join(&key[..2]).join(&key);
+            fs_err::create_dir_all(stored.parent().expect("Stored file to have parent"))?;
+
+            match fs_err::hard_link(entry.path(), &stored) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if let Err(err) = replace_with_link(&stored, entry.path()) {
+                        debug!("Failed to deduplicate `{}`: {err}", entry.path().display());
+                    }
+                }
+                Err(err) => {
+                    // If the store can't be linked to at all, there's no use in trying the
+                    // remaining files.
+                    debug!("Failed to add `{}` to store: {err}", entry.path().display());
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deduplicate the files in a directory against the content-addressed store.
+    ///
+    /// The store relies on link counts to detect unused files, so it's only supported on Unix.
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_self)]
+    pub(crate) fn deduplicate(&self, _dir: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Remove any files from the content-addressed store that are no longer linked from an
+    /// unzipped wheel or an environment.
+    #[cfg(unix)]
+    pub(crate) fn prune_content(&self) -> io::Result<Removal> {
+        use std::os::unix::fs::MetadataExt;
+
+        let mut summary = Removal::default();
+        let store = self.bucket(CacheBucket::Content);
+        if !store.is_dir() {
+            return Ok(summary);
+        }
+        for entry in walkdir::WalkDir::new(store) {
+            let entry = entry?;
+            if entry.file_type().is_file() && entry.metadata()?.nlink() == 1 {
+                debug!("Removing dangling stored file: {}", entry.path().display());
+                summary += crate::rm_rf(entry.path())?;
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Remove any unused files from the content-addressed store.
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_self)]
+    pub(crate) fn prune_content(&self) -> io::Result<Removal> {
+        Ok(Removal::default())
+    }
+
+    /// Return the files in the content-addressed store with contents that don't match the hash
+    /// under which they're stored (e.g., as they were modified in-place).
+    #[cfg(unix)]
+    pub fn corrupted_content(&self) -> io::Result<Vec<PathBuf>> {
+        let mut corrupted = Vec::new();
+        let store = self.bucket(CacheBucket::Content);
+        if !store.is_dir() {
+            return Ok(corrupted);
+        }
+        for entry in walkdir::WalkDir::new(store) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Some((expected, _)) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.split_once('-'))
+            else {
+                continue;
+            };
+            if sha256(entry.path())? != expected {
+                corrupted.push(entry.into_path());
+            }
+        }
+        Ok(corrupted)
+    }
+
+    /// Return the files in the content-addressed store with contents that don't match the hash
+    /// under which they're stored.
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_self)]
+    pub fn corrupted_content(&self) -> io::Result<Vec<PathBuf>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Return the key under which a file is stored, composed of the SHA-256 hash of its contents and
+/// its permissions (as files with different permissions can't share an inode).
+#[cfg(unix)]
+fn content_key(path: &Path, metadata: &std::fs::Metadata) -> io::Result<String> {
+    use std::os::unix::fs::PermissionsExt;
+
+    Ok(format!(
+        "{}-{:o}",
+        sha256(path)?,
+        metadata.permissions().mode() & 0o777
+    ))
+}
+
+/// Return the hex-encoded SHA-256 hash of the contents of a file.
+#[cfg(unix)]
+fn sha256(path: &Path) -> io::Result<String> {
+    use sha2::Digest;
+
+    let mut hasher = sha2::Sha256::new();
+    io::copy(&mut fs_err::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Replace a file with a hard link to a stored file.
+///
+/// The link is created alongside the file and then moved into place, such that the file is
+/// retained if the link can't be created.
+#[cfg(unix)]
+fn replace_with_link(stored: &Path, path: &Path) -> io::Result<()> {
+    let temp = path.with_file_name(format!(".{}", nanoid::nanoid!()));
+    fs_err::hard_link(stored, &temp)?;
+    if let Err(err) = fs_err::rename(&temp, path) {
+        let _ = fs_err::remove_file(&temp);
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::MetadataExt;
+    use std::path::Path;
+
+    use crate::{Cache, CacheBucket};
+
+    fn inode(path: &Path) -> u64 {
+        fs_err::metadata(path).unwrap().ino()
+    }
+
+    #[test]
+    fn deduplicate() {
+        let cache = Cache::temp().unwrap();
+        let archive = cache.bucket(CacheBucket::Archive);
+        for version in ["1.0.0", "2.0.0"] {
+            let dir = archive.join(version);
+            fs_err::create_dir_all(&dir).unwrap();
+            fs_err::write(dir.join("data.bin"), vec![0; 8192]).unwrap();
+            fs_err::write(dir.join("small.bin"), vec![0; 16]).unwrap();
+            cache.deduplicate(&dir).unwrap();
+        }
+
+        // Identical large files share an inode, while small files are left as-is.
+        let old = archive.join("1.0.0");
+        let new = archive.join("2.0.0");
+        assert_eq!(inode(&old.join("data.bin")), inode(&new.join("data.bin")));
+        assert_eq!(fs_err::metadata(new.join("data.bin")).unwrap().nlink(), 3);
+        assert_ne!(inode(&old.join("small.bin")), inode(&new.join("small.bin")));
+        assert_eq!(fs_err::read(new.join("data.bin")).unwrap(), vec![0; 8192]);
+        assert!(cache.corrupted_content().unwrap().is_empty());
+
+        // Stored files are retained while any directory links to them.
+        fs_err::remove_dir_all(&old).unwrap();
+        assert_eq!(cache.prune_content().unwrap().num_files, 0);
+        fs_err::remove_dir_all(&new).unwrap();
+        assert_eq!(cache.prune_content().unwrap().num_files, 1);
+    }
+}
@@ -0,0 +1,467 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use crate::{rm_rf, Cache, CacheBucket, Removal};
+
+/// A file in the cache root, the modification time of which records when the cache size limit
+/// was last enforced.
+pub(crate) const EVICTION_MARKER: &str = ".evicted";
+
+/// A file in the cache root, the modification time of which records when the retention periods
+/// were last enforced.
+pub(crate) const EXPIRY_MARKER: &str = ".expired";
+
+/// The minimum interval between automatic enforcements of the cache size limit, or of the
+/// retention periods, and between compressions of unused source trees.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// An entry that can be evicted from the cache as a unit, e.g., an unzipped wheel.
+#[derive(Debug)]
+struct Candidate {
+    path: PathBuf,
+    /// The total size of the files in the entry, in bytes.
+    size: u64,
+    /// The most recent time at which the entry was used.
+    last_used: SystemTime,
+}
+
+impl Candidate {
+    fn from_path(path: PathBuf, links: &Links) -> io::Result<Self> {
+        let (size, last_used) = usage(&path, links)?;
+        Ok(Self {
+            path,
+            size,
+            last_used,
+        })
+    }
+}
+
+impl Cache {
+    /// Evict the least-recently-used entries from the cache, until the total size of the cache
+    /// is at most `max_size` bytes.
+    ///
+    /// The whole cache counts towards the limit, but only entries that can be recreated on
+    /// demand are evicted: unzipped wheels, source distribution builds, and Git and Mercurial
+    /// checkouts. Metadata and pointers to evicted entries are retained, such that an evicted
+    /// entry is transparently refetched (or rebuilt) when next used.
+    ///
+    /// Entries are ordered by the time at which any of their files were last accessed or
+    /// modified. Entries with files that are hard-linked into an environment are considered to be
+    /// in use, and are evicted last, as removing them wouldn't reclaim the space. Stored files
+    /// that are no longer linked from an unzipped wheel are removed after eviction.
+    pub fn evict(&self, max_size: u64) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if !self.root.is_dir() {
+            return Ok(summary);
+        }
+
+        let mut total = total_size(&self.root)?;
+        if total <= max_size {
+            debug!("Cache size ({total} bytes) is within the limit ({max_size} bytes)");
+            return Ok(summary);
+        }
+
+        let links = Links::from_cache(self)?;
+        let mut candidates = Vec::new();
+        for bucket in CacheBucket::iter() {
+            for path in self.eviction_candidates(bucket)? {
+                candidates.push(Candidate::from_path(path, &links)?);
+            }
+        }
+        candidates.sort_by_key(|candidate| candidate.last_used);
+
+        for candidate in candidates {
+            if total <= max_size {
+                break;
+            }
+            debug!(
+                "Evicting least-recently-used cache entry: {}",
+                candidate.path.display()
+            );
+            summary += rm_rf(&candidate.path)?;
+            total = total.saturating_sub(candidate.size);
+        }
+
+        summary += self.prune_content()?;
+
+        Ok(summary)
+    }
+
+    /// Evict entries from the cache to enforce the size limit, unless the limit was already
+    /// enforced within the last day.
+    ///
+    /// Returns `None` if the limit wasn't enforced.
+    pub fn evict_periodically(&self, max_size: u64) -> Result<Option<Removal>, io::Error> {
+        if !self.is_due(EVICTION_MARKER)? {
+            return Ok(None);
+        }
+        self.evict(max_size).map(Some)
+    }
+
+    /// Remove the entries in each of the given buckets that haven't been used within the
+    /// corresponding period.
+    ///
+    /// As with [`Cache::evict`], only entries that can be recreated on demand are removed:
+    /// unzipped wheels, source distribution builds, Git and Mercurial checkouts, and interpreter
+    /// metadata. Entries with files that are hard-linked into an environment are considered to be
+    /// in use, and are retained.
+    pub fn expire(&self, periods: &[(CacheBucket, Duration)]) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if periods.is_empty() || !self.root.is_dir() {
+            return Ok(summary);
+        }
+
+        let links = Links::from_cache(self)?;
+        for (bucket, period) in periods {
+            let Some(cutoff) = SystemTime::now().checked_sub(*period) else {
+                continue;
+            };
+            for path in self.expiry_candidates(*bucket)? {
+                let candidate = Candidate::from_path(path, &links)?;
+                if candidate.last_used < cutoff {
+                    debug!("Removing unused cache entry: {}", candidate.path.display());
+                    summary += rm_rf(&candidate.path)?;
+                }
+            }
+        }
+
+        summary += self.prune_content()?;
+
+        Ok(summary)
+    }
+
+    /// Remove unused entries according to the retention periods, unless the periods were already
+    /// enforced within the last day.
+    ///
+    /// Returns `None` if the periods weren't enforced.
+    pub fn expire_periodically(
+        &self,
+        periods: &[(CacheBucket, Duration)],
+    ) -> Result<Option<Removal>, io::Error> {
+        if periods.is_empty() || !self.is_due(EXPIRY_MARKER)? {
+            return Ok(None);
+        }
+        self.expire(periods).map(Some)
+    }
+
+    /// Returns `true` if the periodic task recorded by the given marker file wasn't run within
+    /// the last day, in which case the marker is updated.
+    pub(crate) fn is_due(&self, marker: &str) -> Result<bool, io::Error> {
+        if self.is_temporary() || !self.root.is_dir() {
+            return Ok(false);
+        }
+
+        let marker = self.root.join(marker);
+        if let Ok(modified) = fs_err::metadata(&marker).and_then(|metadata| metadata.modified()) {
+            if modified
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed < EVICTION_INTERVAL)
+            {
+                return Ok(false);
+            }
+        }
+
+        // Record the run before walking the cache, such that concurrent invocations don't
+        // duplicate the work.
+        fs_err::File::create(&marker)?;
+        Ok(true)
+    }
+
+    /// Return the entries in the given bucket that can be removed once unused.
+    fn expiry_candidates(&self, bucket: CacheBucket) -> io::Result<Vec<PathBuf>> {
+        match bucket {
+            // The metadata of each interpreter can be removed, as it's queried again when next
+            // used.
+            CacheBucket::Interpreter => {
+                let mut candidates = Vec::new();
+                for shard in children(&self.bucket(bucket))? {
+                    if shard.is_dir() {
+                        candidates.extend(children(&shard)?);
+                    }
+                }
+                Ok(candidates)
+            }
+            _ => self.eviction_candidates(bucket),
+        }
+    }
+
+    /// Return the entries in the given bucket that can be evicted as a unit.
+    fn eviction_candidates(&self, bucket: CacheBucket) -> io::Result<Vec<PathBuf>> {
+        let root = self.bucket(bucket);
+        match bucket {
+            // Each unzipped wheel can be evicted, as the links to it are treated as missing once
+            // it's removed.
+            CacheBucket::Archive => children(&root),
+            // Each repository database and each checkout can be evicted.
+            CacheBucket::Git | CacheBucket::Hg => {
+                let mut candidates = children(&root.join("db"))?;
+                for checkout in children(&root.join("checkouts"))? {
+                    candidates.extend(children(&checkout)?);
+                }
+                Ok(candidates)
+            }
+            // Each source distribution can be evicted, alongside its revision pointer and any
+            // built wheels. A Git (or Mercurial) source distribution has no revision pointer, so
+            // its built wheels are evicted by revision.
+            CacheBucket::SourceDistributions => {
+                let mut candidates = Vec::new();
+                let mut walker = walkdir::WalkDir::new(&root).min_depth(1).into_iter();
+                while let Some(entry) = walker.next() {
+                    let entry = entry?;
+                    if !entry.file_type().is_dir() {
+                        continue;
+                    }
+                    let path = entry.path();
+                    if ["revision.http", "revision.rev", "metadata.msgpack"]
+                        .iter()
+                        .any(|file| path.join(file).is_file())
+                    {
+                        candidates.push(path.to_path_buf());
+                        walker.skip_current_dir();
+                    }
+                }
+                Ok(candidates)
+            }
+            // Stored files are removed once they're no longer linked from an unzipped wheel.
+            // Other buckets are small, or (as with environments and builds) may be in use.
+            CacheBucket::Content
+            | CacheBucket::Wheels
+            | CacheBucket::FlatIndex
+            | CacheBucket::Interpreter
+            | CacheBucket::Simple
+            | CacheBucket::Builds
+            | CacheBucket::Environments => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Return the paths of the entries in a directory, or an empty list if it doesn't exist.
+pub(crate) fn children(path: &Path) -> io::Result<Vec<PathBuf>> {
+    match fs_err::read_dir(path) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// The hard links to the files in unzipped wheels from within the cache.
+///
+/// Files in the content-addressed store are linked from every unzipped wheel that contains them,
+/// so a file is only linked from outside the cache (e.g., into a virtual environment) if it has
+/// more links than the cache accounts for.
+#[derive(Debug, Default)]
+pub(crate) struct Links {
+    /// The number of links to each file from the unzipped wheels, keyed by device and inode.
+    archives: FxHashMap<(u64, u64), u64>,
+    /// The files in the content-addressed store.
+    stored: FxHashSet<(u64, u64)>,
+}
+
+impl Links {
+    fn from_cache(cache: &Cache) -> io::Result<Self> {
+        let mut links = Self::default();
+        for (bucket, stored) in [(CacheBucket::Archive, false), (CacheBucket::Content, true)] {
+            let root = cache.bucket(bucket);
+            if !root.is_dir() {
+                continue;
+            }
+            for entry in walkdir::WalkDir::new(root) {
+                let entry = entry?;
+                let metadata = entry.metadata()?;
+                let Some(inode) = inode(&metadata).filter(|_| metadata.is_file()) else {
+                    continue;
+                };
+                if stored {
+                    links.stored.insert(inode);
+                } else {
+                    *links.archives.entry(inode).or_default() += 1;
+                }
+            }
+        }
+        Ok(links)
+    }
+
+    /// Returns `true` if the file is hard-linked from outside the cache (e.g., into a virtual
+    /// environment).
+    fn is_linked_elsewhere(&self, metadata: &std::fs::Metadata) -> bool {
+        let (Some(inode), Some(nlink)) = (inode(metadata), nlink(metadata)) else {
+            return false;
+        };
+        let archives = self.archives.get(&inode).copied().unwrap_or(1);
+        nlink > archives + u64::from(self.stored.contains(&inode))
+    }
+
+    /// Return the number of bytes reclaimed by removing the file, which is zero if the file is
+    /// shared with another unzipped wheel.
+    fn reclaimed(&self, metadata: &std::fs::Metadata) -> u64 {
+        let shared = inode(metadata)
+            .and_then(|inode| self.archives.get(&inode))
+            .is_some_and(|count| *count > 1);
+        if shared {
+            0
+        } else {
+            metadata.len()
+        }
+    }
+}
+
+/// Return the total size of the files under a path, in bytes.
+///
+/// Symbolic links (e.g., links to unzipped wheels) aren't followed, and files with multiple hard
+/// links (e.g., stored files) are counted once.
+fn total_size(path: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    let mut seen = FxHashSet::default();
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        if inode(&metadata).is_some_and(|inode| !seen.insert(inode)) {
+            continue;
+        }
+        size += metadata.len();
+    }
+    Ok(size)
+}
+
+/// Return the number of bytes reclaimed by removing the files under a path, along with the most
+/// recent time at which any of them was used.
+///
+/// The times of directories are ignored, as their access times are updated by walking the cache
+/// itself.
+pub(crate) fn usage(path: &Path, links: &Links) -> io::Result<(u64, SystemTime)> {
+    let mut size = 0;
+    let mut last_used = SystemTime::UNIX_EPOCH;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        size += links.reclaimed(&metadata);
+        for time in [metadata.accessed(), metadata.modified()]
+            .into_iter()
+            .flatten()
+        {
+            last_used = last_used.max(time);
+        }
+        if links.is_linked_elsewhere(&metadata) {
+            last_used = SystemTime::now();
+        }
+    }
+    Ok((size, last_used))
+}
+
+/// Return the device and inode of a file.
+#[cfg(unix)]
+pub(crate) fn inode(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Return the device and inode of a file.
+#[cfg(not(unix))]
+pub(crate) fn inode(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Return the number of hard links to a file.
+#[cfg(unix)]
+fn nlink(metadata: &std::fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.nlink())
+}
+
+/// Return the number of hard links to a file.
+#[cfg(not(unix))]
+fn nlink(_metadata: &std::fs::Metadata) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::FileTimes;
+    use std::path::Path;
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Cache, CacheBucket};
+
+    fn write(path: &Path, size: usize) {
+        fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+        fs_err::write(path, vec![0; size]).unwrap();
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let cache = Cache::temp().unwrap();
+        let archive = cache.bucket(CacheBucket::Archive);
+        write(&archive.join("old").join("module.py"), 1000);
+        write(&archive.join("new").join("module.py"), 1000);
+        let index = cache.bucket(CacheBucket::Simple).join("index.msgpack");
+        write(&index, 100);
+
+        // Mark the `old` entry as less recently used.
+        let old = SystemTime::now() - Duration::from_secs(60 * 60);
+        fs_err::OpenOptions::new()
+            .write(true)
+            .open(archive.join("old").join("module.py"))
+            .unwrap()
+            .file()
+            .set_times(FileTimes::new().set_accessed(old).set_modified(old))
+            .unwrap();
+
+        // The cache is within the limit.
+        let removal = cache.evict(2100).unwrap();
+        assert_eq!(removal.num_files, 0);
+
+        // The least-recently-used entry is evicted, while the index metadata is retained.
+        let removal = cache.evict(1500).unwrap();
+        assert_eq!(removal.num_files, 1);
+        assert!(!archive.join("old").exists());
+        assert!(archive.join("new").exists());
+        assert!(index.exists());
+    }
+
+    #[test]
+    fn expire_unused() {
+        let cache = Cache::temp().unwrap();
+        let archive = cache.bucket(CacheBucket::Archive);
+        let interpreter = cache.bucket(CacheBucket::Interpreter).join("shard");
+        write(&archive.join("old").join("module.py"), 1000);
+        write(&archive.join("new").join("module.py"), 1000);
+        write(&interpreter.join("old.msgpack"), 100);
+
+        // Mark the `old` entries as unused for a day.
+        let old = SystemTime::now() - Duration::from_secs(24 * 60 * 60);
+        for path in [
+            archive.join("old").join("module.py"),
+            interpreter.join("old.msgpack"),
+        ] {
+            fs_err::OpenOptions::new()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .file()
+                .set_times(FileTimes::new().set_accessed(old).set_modified(old))
+                .unwrap();
+        }
+
+        // Only the buckets with a retention period are affected.
+        let hour = Duration::from_secs(60 * 60);
+        cache.expire(&[(CacheBucket::Archive, hour)]).unwrap();
+        assert!(!archive.join("old").exists());
+        assert!(archive.join("new").exists());
+        assert!(interpreter.join("old.msgpack").exists());
+
+        cache.expire(&[(CacheBucket::Interpreter, hour)]).unwrap();
+        assert!(!interpreter.join("old.msgpack").exists());
+    }
+}
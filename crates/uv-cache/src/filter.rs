@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionSpecifiers, VersionSpecifiersParseError};
+
+/// A package to remove from the cache, optionally restricted to the versions that match a set of
+/// version specifiers (e.g., `numpy` or `numpy==1.26.4`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageFilter {
+    name: PackageName,
+    specifiers: VersionSpecifiers,
+}
+
+impl PackageFilter {
+    /// Return the name of the package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Returns `true` if the filter matches every version of the package.
+    pub fn is_all_versions(&self) -> bool {
+        self.specifiers.is_empty()
+    }
+
+    /// Returns `true` if the filter matches the given version of the package.
+    ///
+    /// Entries with an unknown version only match filters without version specifiers.
+    pub fn matches(&self, version: Option<&Version>) -> bool {
+        if self.specifiers.is_empty() {
+            return true;
+        }
+        version.is_some_and(|version| self.specifiers.contains(version))
+    }
+
+    /// Returns `true` if the filter matches the version encoded in the name of a cache entry, as
+    /// in `1.26.4` or `1.26.4-cp312-cp312-manylinux_2_17_x86_64`.
+    pub(crate) fn matches_path(&self, path: &Path) -> bool {
+        let version = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('-').next())
+            .and_then(|version| Version::from_str(version).ok());
+        self.matches(version.as_ref())
+    }
+}
+
+impl From<PackageName> for PackageFilter {
+    fn from(name: PackageName) -> Self {
+        Self {
+            name,
+            specifiers: VersionSpecifiers::empty(),
+        }
+    }
+}
+
+impl FromStr for PackageFilter {
+    type Err = PackageFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, specifiers) = match s.find(['=', '!', '<', '>', '~', ' ']) {
+            Some(index) => s.split_at(index),
+            None => (s, ""),
+        };
+        Ok(Self {
+            name: PackageName::from_str(name)?,
+            specifiers: VersionSpecifiers::from_str(specifiers.trim())?,
+        })
+    }
+}
+
+impl Display for PackageFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.name, self.specifiers)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PackageFilterError {
+    #[error(transparent)]
+    Name(#[from] InvalidNameError),
+    #[error(transparent)]
+    Specifiers(#[from] VersionSpecifiersParseError),
+}
+
+/// A kind of cache entry to remove for a package.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum CacheEntryKind {
+    /// Wheels downloaded from an index or URL, alongside their unzipped contents.
+    Wheels,
+    /// Source distributions, alongside the wheels built from them.
+    Builds,
+    /// Package metadata fetched from an index, including the metadata of each wheel.
+    Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::PackageFilter;
+
+    #[test]
+    fn parse() {
+        let filter = PackageFilter::from_str("numpy").unwrap();
+        assert_eq!(filter.name().as_ref(), "numpy");
+        assert!(filter.is_all_versions());
+        assert!(filter.matches(None));
+
+        let filter = PackageFilter::from_str("NumPy>=1.26,<2").unwrap();
+        assert_eq!(filter.to_string(), "numpy>=1.26, <2");
+        assert!(filter.matches(Some(&Version::from_str("1.26.4").unwrap())));
+        assert!(!filter.matches(Some(&Version::from_str("2.0.0").unwrap())));
+        assert!(!filter.matches(None));
+
+        assert!(PackageFilter::from_str("numpy==").is_err());
+        assert!(PackageFilter::from_str("==1.0").is_err());
+    }
+
+    #[test]
+    fn matches_path() {
+        let filter = PackageFilter::from_str("numpy==1.26.4").unwrap();
+        assert!(filter.matches_path(Path::new("1.26.4")));
+        assert!(filter.matches_path(Path::new("1.26.4-cp312-cp312-manylinux_2_17_x86_64.http")));
+        assert!(!filter.matches_path(Path::new("2.0.0-cp312-cp312-manylinux_2_17_x86_64.http")));
+        assert!(!filter.matches_path(Path::new("numpy-1.26.4")));
+    }
+}
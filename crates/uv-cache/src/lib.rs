@@ -5,32 +5,44 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use rustc_hash::FxHashSet;
 use tracing::debug;
+use url::Url;
 
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
 use uv_distribution_filename::WheelFilename;
-use uv_distribution_types::InstalledDist;
-use uv_fs::{cachedir, directories, LockedFile};
+use uv_distribution_types::{IndexName, InstalledDist};
+use uv_fs::{cachedir, directories, entries, LockedFile};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::compression::compressed_path;
+pub use crate::filter::{CacheEntryKind, PackageFilter, PackageFilterError};
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::stats::{AccessCounts, BucketUsage, CacheAccess};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
 mod archive;
+mod base;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod compression;
+mod content;
+mod eviction;
+mod filter;
+mod provenance;
 mod removal;
+mod stats;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -38,6 +50,18 @@ mod wheel;
 /// Must be kept in-sync with the version in [`CacheBucket::to_str`].
 pub const ARCHIVE_VERSION: u8 = 0;
 
+/// The maximum time to wait for another process to release a lock on a cache entry, for a uv
+/// invocation.
+static LOCK_TIMEOUT: OnceLock<Duration> = OnceLock::new();
+
+/// Wait at most `timeout` for another process to release a lock on a cache entry, rather than
+/// waiting indefinitely (e.g., for a process on another machine that shares the cache).
+pub fn set_lock_timeout(timeout: Duration) {
+    if LOCK_TIMEOUT.set(timeout).is_err() {
+        debug!("Cache lock timeout is already set");
+    }
+}
+
 /// A [`CacheEntry`] which may or may not exist yet.
 #[derive(Debug, Clone)]
 pub struct CacheEntry(PathBuf);
@@ -85,7 +109,12 @@ impl CacheEntry {
     /// Acquire the [`CacheEntry`] as an exclusive lock.
     pub async fn lock(&self) -> Result<LockedFile, io::Error> {
         fs_err::create_dir_all(self.dir())?;
-        LockedFile::acquire(self.path(), self.path().display()).await
+        LockedFile::acquire_with_timeout(
+            self.path(),
+            self.path().display(),
+            LOCK_TIMEOUT.get().copied(),
+        )
+        .await
     }
 }
 
@@ -114,7 +143,12 @@ impl CacheShard {
     /// Acquire the cache entry as an exclusive lock.
     pub async fn lock(&self) -> Result<LockedFile, io::Error> {
         fs_err::create_dir_all(self.as_ref())?;
-        LockedFile::acquire(self.join(".lock"), self.display()).await
+        LockedFile::acquire_with_timeout(
+            self.join(".lock"),
+            self.display(),
+            LOCK_TIMEOUT.get().copied(),
+        )
+        .await
     }
 
     /// Return the [`CacheShard`] as a [`PathBuf`].
@@ -149,6 +183,14 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The URL of a remote cache for artifacts built from source distributions, if any.
+    remote: Option<Url>,
+    /// A read-only cache to seed missing entries from, if any.
+    base: Option<PathBuf>,
+    /// Whether to read cached interpreter information, rather than querying each interpreter.
+    discovery: bool,
+    /// Whether to deduplicate the files of unzipped wheels in the content-addressed store.
+    deduplicate: bool,
 }
 
 impl Cache {
@@ -158,6 +200,10 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            remote: None,
+            base: None,
+            discovery: true,
+            deduplicate: false,
         }
     }
 
@@ -168,6 +214,10 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            remote: None,
+            base: None,
+            discovery: true,
+            deduplicate: false,
         })
     }
 
@@ -177,6 +227,34 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the URL of the remote cache for artifacts built from source distributions.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<Url>) -> Self {
+        Self { remote, ..self }
+    }
+
+    /// Set the read-only base cache, from which entries that are missing from the cache are
+    /// seeded (e.g., a cache that's baked into a container image).
+    #[must_use]
+    pub fn with_base(self, base: Option<PathBuf>) -> Self {
+        Self { base, ..self }
+    }
+
+    /// Set whether to read cached interpreter information during Python discovery.
+    #[must_use]
+    pub fn with_discovery_cache(self, discovery: bool) -> Self {
+        Self { discovery, ..self }
+    }
+
+    /// Set whether to deduplicate the files of unzipped wheels in the content-addressed store.
+    #[must_use]
+    pub fn with_deduplication(self, deduplicate: bool) -> Self {
+        Self {
+            deduplicate,
+            ..self
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -187,29 +265,51 @@ impl Cache {
         &self.refresh
     }
 
+    /// Whether cached interpreter information may be read during Python discovery.
+    pub fn discovery_cache(&self) -> bool {
+        self.discovery
+    }
+
+    /// Return the URL of the remote cache, if any.
+    pub fn remote(&self) -> Option<&Url> {
+        self.remote.as_ref()
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
     }
 
     /// Compute an entry in the cache.
+    ///
+    /// If the entry is missing, but present in the base cache, it's seeded from the base cache.
     pub fn shard(&self, cache_bucket: CacheBucket, dir: impl AsRef<Path>) -> CacheShard {
-        CacheShard(self.bucket(cache_bucket).join(dir.as_ref()))
+        let shard = CacheShard(self.bucket(cache_bucket).join(dir.as_ref()));
+        self.seed(cache_bucket, &shard);
+        shard
     }
 
     /// Compute an entry in the cache.
+    ///
+    /// If the entry is missing, but present in the base cache, it's seeded from the base cache.
     pub fn entry(
         &self,
         cache_bucket: CacheBucket,
         dir: impl AsRef<Path>,
         file: impl AsRef<Path>,
     ) -> CacheEntry {
-        CacheEntry::new(self.bucket(cache_bucket).join(dir), file)
+        let entry = CacheEntry::new(self.bucket(cache_bucket).join(dir), file);
+        self.seed(cache_bucket, entry.path());
+        entry
     }
 
     /// Return the path to an archive in the cache.
+    ///
+    /// If the archive is missing, but present in the base cache, the path in the base cache is
+    /// returned instead.
     pub fn archive(&self, id: &ArchiveId) -> PathBuf {
-        self.bucket(CacheBucket::Archive).join(id)
+        self.base_archive(id)
+            .unwrap_or_else(|| self.bucket(CacheBucket::Archive).join(id))
     }
 
     /// Create a temporary directory to be used as a Python virtual environment.
@@ -229,7 +329,7 @@ impl Cache {
         match &self.refresh {
             Refresh::None(_) => false,
             Refresh::All(_) => true,
-            Refresh::Packages(packages, _) => packages.contains(package),
+            Refresh::Packages(packages, _, _) => packages.contains(package),
         }
     }
 
@@ -246,8 +346,10 @@ impl Cache {
         let timestamp = match &self.refresh {
             Refresh::None(_) => return Ok(Freshness::Fresh),
             Refresh::All(timestamp) => timestamp,
-            Refresh::Packages(packages, timestamp) => {
-                if package.is_none_or(|package| packages.contains(package)) {
+            Refresh::Packages(packages, _, timestamp) => {
+                // If only indexes are being refreshed, entries that aren't linked to a package are
+                // fresh.
+                if package.map_or(!packages.is_empty(), |package| packages.contains(package)) {
                     timestamp
                 } else {
                     return Ok(Freshness::Fresh);
@@ -255,9 +357,31 @@ impl Cache {
             }
         };
 
+        Self::freshness_since(entry, *timestamp)
+    }
+
+    /// Returns the [`Freshness`] for a cache entry fetched from an index, validating it against the
+    /// [`Refresh`] policy for both the package (if any) and the index.
+    pub fn index_freshness(
+        &self,
+        entry: &CacheEntry,
+        index: Option<&IndexName>,
+        package: Option<&PackageName>,
+    ) -> io::Result<Freshness> {
+        if let Refresh::Packages(_, indexes, timestamp) = &self.refresh {
+            if index.is_some_and(|index| indexes.contains(index)) {
+                return Self::freshness_since(entry, *timestamp);
+            }
+        }
+        self.freshness(entry, package)
+    }
+
+    /// Returns the [`Freshness`] for a cache entry, given the cutoff timestamp before which
+    /// entries are considered stale.
+    fn freshness_since(entry: &CacheEntry, timestamp: Timestamp) -> io::Result<Freshness> {
         match fs_err::metadata(entry.path()) {
             Ok(metadata) => {
-                if Timestamp::from_metadata(&metadata) >= *timestamp {
+                if Timestamp::from_metadata(&metadata) >= timestamp {
                     Ok(Freshness::Fresh)
                 } else {
                     Ok(Freshness::Stale)
@@ -275,9 +399,24 @@ impl Cache {
         path: impl AsRef<Path>,
     ) -> io::Result<ArchiveId> {
         // Create a unique ID for the artifact.
-        // TODO(charlie): Support content-addressed persistence via SHAs.
         let id = ArchiveId::new();
 
+        // If enabled, deduplicate the files against the content-addressed store. This is an
+        // optimization, so failures are logged, but otherwise ignored.
+        if self.deduplicate {
+            let cache = self.clone();
+            let dir = temp_dir.as_ref().to_path_buf();
+            if let Err(err) = tokio::task::spawn_blocking(move || cache.deduplicate(&dir))
+                .await
+                .unwrap_or_else(|err| Err(io::Error::other(err)))
+            {
+                debug!(
+                    "Failed to deduplicate `{}`: {err}",
+                    temp_dir.as_ref().display()
+                );
+            }
+        }
+
         // Move the temporary directory into the directory store.
         let archive_entry = self.entry(CacheBucket::Archive, "", &id);
         fs_err::create_dir_all(archive_entry.dir())?;
@@ -346,6 +485,7 @@ impl Cache {
 
         Ok(Self {
             root: std::path::absolute(root)?,
+            base: self.base.map(std::path::absolute).transpose()?,
             ..self
         })
     }
@@ -359,13 +499,28 @@ impl Cache {
     ///
     /// Returns the number of entries removed from the cache.
     pub fn remove(&self, name: &PackageName) -> Result<Removal, io::Error> {
+        self.remove_filtered(&PackageFilter::from(name.clone()), &[])
+    }
+
+    /// Remove the entries of the given kinds for the matching versions of a package from the
+    /// cache, or all of its entries if no kinds are provided.
+    ///
+    /// Index metadata covers every version of a package, so it's only removed if the filter
+    /// matches every version.
+    ///
+    /// Returns the number of entries removed from the cache.
+    pub fn remove_filtered(
+        &self,
+        package: &PackageFilter,
+        kinds: &[CacheEntryKind],
+    ) -> Result<Removal, io::Error> {
         // Collect the set of referenced archives.
         let before = self.find_archive_references()?;
 
         // Remove any entries for the package from the cache.
         let mut summary = Removal::default();
         for bucket in CacheBucket::iter() {
-            summary += bucket.remove(self, name)?;
+            summary += bucket.remove(self, package, kinds)?;
         }
 
         // Collect the set of referenced archives after the removal.
@@ -381,6 +536,9 @@ impl Cache {
                     summary += rm_rf(path)?;
                 }
             }
+
+            // Remove any stored files that were only linked from the removed archives.
+            summary += self.prune_content()?;
         }
 
         Ok(summary)
@@ -399,6 +557,10 @@ impl Cache {
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == eviction::EVICTION_MARKER
+                || entry.file_name() == eviction::EXPIRY_MARKER
+                || entry.file_name() == compression::COMPRESSION_MARKER
+                || entry.file_name() == stats::ACCESS_LOG
             {
                 continue;
             }
@@ -468,11 +630,11 @@ impl Cache {
                     let entry = entry?;
                     let path = entry.path();
 
-                    // Retain the resolved metadata (`metadata.msgpack`).
-                    if path
-                        .file_name()
-                        .is_some_and(|file_name| file_name == "metadata.msgpack")
-                    {
+                    // Retain the resolved metadata (`metadata.msgpack`), and the record of the
+                    // indexes that supplied the source distribution.
+                    if path.file_name().is_some_and(|file_name| {
+                        file_name == "metadata.msgpack" || file_name == provenance::ORIGINS
+                    }) {
                         continue;
                     }
 
@@ -508,6 +670,9 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Fifth, remove any stored files that are no longer linked from an archive.
+        summary += self.prune_content()?;
+
         Ok(summary)
     }
 
@@ -578,26 +743,21 @@ impl Cache {
         let link = Link::new(id.clone());
         let contents = link.to_string();
 
-        // First, attempt to create a file at the location, but fail if it already exists.
-        match fs_err::OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(dst.as_ref())
-        {
-            Ok(mut file) => {
-                // Write the target path to the file.
-                file.write_all(contents.as_bytes())?;
-                Ok(())
-            }
-            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
-                // Write to a temporary file, then move it into place.
-                let temp_dir = tempfile::tempdir_in(dst.as_ref().parent().unwrap())?;
-                let temp_file = temp_dir.path().join("link");
-                fs_err::write(&temp_file, contents.as_bytes())?;
-
-                // Move the symlink into the target location.
-                fs_err::rename(&temp_file, dst.as_ref())?;
+        // Write to a temporary file, then move it into place, such that concurrent readers (e.g.,
+        // on a cache that's shared between machines) never observe a partially-written link.
+        let temp_dir = tempfile::tempdir_in(dst.as_ref().parent().unwrap())?;
+        let temp_file = temp_dir.path().join("link");
+        fs_err::write(&temp_file, contents.as_bytes())?;
 
+        match fs_err::rename(&temp_file, dst.as_ref()) {
+            Ok(()) => Ok(()),
+            // If another process created an equivalent link concurrently, and the link is in use,
+            // retain it.
+            Err(err) if self.resolve_link(dst.as_ref()).is_ok() => {
+                debug!(
+                    "Retaining existing link at `{}`: {err}",
+                    dst.as_ref().display()
+                );
                 Ok(())
             }
             Err(err) => Err(err),
@@ -920,6 +1080,8 @@ pub enum CacheBucket {
     FlatIndex,
     /// Git repositories.
     Git,
+    /// Mercurial repositories.
+    Hg,
     /// Information about an interpreter at a path.
     ///
     /// To avoid caching pyenv shims, bash scripts which may redirect to a new python version
@@ -973,6 +1135,14 @@ pub enum CacheBucket {
     /// that cache entries can be atomically replaced and removed, as storing directories in the
     /// other buckets directly would make atomic operations impossible.
     Archive,
+    /// A content-addressed store of the files in unzipped wheels, which are hard-linked into the
+    /// directories in [`CacheBucket::Archive`]. Files that are identical across versions and
+    /// platforms of a package (e.g., the data files of large data-bearing packages) are stored
+    /// once, and environments are hard-linked against the store.
+    ///
+    /// Cache structure:
+    ///  * `content-v0/<sha256[..2]>/<sha256>-<permissions>`
+    Content,
     /// Ephemeral virtual environments used to execute PEP 517 builds and other operations.
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
@@ -984,19 +1154,21 @@ impl CacheBucket {
         match self {
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::SourceDistributions => "sdists-v8",
+            Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Hg => "hg-v0",
+            Self::Interpreter => "interpreter-v6",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v15",
+            Self::Simple => "simple-v16",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::Wheels => "wheels-v5",
+            Self::Wheels => "wheels-v6",
             // Note that when bumping this, you'll also need to bump
             // `ARCHIVE_VERSION` in `crates/uv-cache/src/lib.rs`.
             Self::Archive => "archive-v0",
+            Self::Content => "content-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
         }
@@ -1005,49 +1177,103 @@ impl CacheBucket {
     /// Remove a package from the cache bucket.
     ///
     /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    fn remove(
+        self,
+        cache: &Cache,
+        package: &PackageFilter,
+        kinds: &[CacheEntryKind],
+    ) -> Result<Removal, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
+        fn is_match(path: &Path, package: &PackageFilter) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
                 return false;
             };
             let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
                 return false;
             };
-            metadata.name == *name
+            metadata.name == *package.name() && package.matches(Some(&metadata.version))
+        }
+
+        /// Remove the entries for the matching versions of a package from a directory of
+        /// versioned entries (e.g., `1.26.4` or `1.26.4-cp312-cp312-manylinux_2_17_x86_64.http`).
+        ///
+        /// If `metadata` is provided, only metadata (or only non-metadata) entries are removed.
+        fn remove_versions(
+            directory: &Path,
+            package: &PackageFilter,
+            metadata: Option<bool>,
+        ) -> Result<Removal, io::Error> {
+            if package.is_all_versions() && metadata.is_none() {
+                return rm_rf(directory);
+            }
+            let mut summary = Removal::default();
+            for entry in entries(directory)? {
+                let is_metadata = entry
+                    .extension()
+                    .is_some_and(|extension| extension == "msgpack");
+                if metadata.is_some_and(|metadata| metadata != is_metadata) {
+                    continue;
+                }
+                if package.matches_path(&entry) {
+                    summary += rm_rf(entry)?;
+                }
+            }
+            Ok(summary)
         }
 
+        let includes = |kind: CacheEntryKind| kinds.is_empty() || kinds.contains(&kind);
+        let name = package.name();
+
         let mut summary = Removal::default();
         match self {
             Self::Wheels => {
+                // Each directory contains the wheels (`.http` and `.rev`) and wheel metadata
+                // (`.msgpack`) for a package, keyed by version and tags.
+                let metadata = match (
+                    includes(CacheEntryKind::Wheels),
+                    includes(CacheEntryKind::Metadata),
+                ) {
+                    (true, true) => None,
+                    (true, false) => Some(false),
+                    (false, true) => Some(true),
+                    (false, false) => return Ok(summary),
+                };
+
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                summary += remove_versions(&root.join(name.to_string()), package, metadata)?;
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary +=
+                        remove_versions(&directory.join(name.to_string()), package, metadata)?;
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary +=
+                        remove_versions(&directory.join(name.to_string()), package, metadata)?;
                 }
             }
             Self::SourceDistributions => {
-                // For `pypi` wheels, we expect a directory per package (indexed by name).
+                if !includes(CacheEntryKind::Builds) {
+                    return Ok(summary);
+                }
+
+                // For `pypi` wheels, we expect a directory per package (indexed by name), followed
+                // by a directory per version.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                summary += remove_versions(&root.join(name.to_string()), package, None)?;
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    summary += remove_versions(&directory.join(name.to_string()), package, None)?;
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -1055,7 +1281,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
-                    if directories(&url)?.any(|version| is_match(&version, name)) {
+                    if directories(&url)?.any(|version| is_match(&version, package)) {
                         summary += rm_rf(url)?;
                     }
                 }
@@ -1065,7 +1291,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
-                    if directories(&path)?.any(|version| is_match(&version, name)) {
+                    if directories(&path)?.any(|version| is_match(&version, package)) {
                         summary += rm_rf(path)?;
                     }
                 }
@@ -1076,13 +1302,43 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Git);
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
-                        if is_match(&sha, name) {
+                        if is_match(&sha, package) {
                             summary += rm_rf(sha)?;
                         }
                     }
                 }
+
+                // Likewise, for Mercurial dependencies, we expect a directory for every
+                // repository, followed by a directory for every changeset.
+                let root = cache.bucket(self).join(WheelCacheKind::Hg);
+                for repository in directories(root)? {
+                    for node in directories(repository)? {
+                        if is_match(&node, package) {
+                            summary += rm_rf(node)?;
+                        }
+                    }
+                }
+
+                // For wheels built from source distributions with a known hash, we expect a
+                // directory per package (indexed by name), followed by a directory per source and
+                // build environment.
+                let root = cache.bucket(self).join(WheelCacheKind::Built);
+                let root = root.join(name.to_string());
+                if package.is_all_versions() {
+                    summary += rm_rf(root)?;
+                } else {
+                    for build in directories(root)? {
+                        if is_match(&build, package) {
+                            summary += rm_rf(build)?;
+                        }
+                    }
+                }
             }
             Self::Simple => {
+                if !includes(CacheEntryKind::Metadata) || !package.is_all_versions() {
+                    return Ok(summary);
+                }
+
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(format!("{name}.rkyv")))?;
@@ -1095,18 +1351,22 @@ impl CacheBucket {
                 }
             }
             Self::FlatIndex => {
+                if !includes(CacheEntryKind::Metadata) || !package.is_all_versions() {
+                    return Ok(summary);
+                }
+
                 // We can't know if the flat index includes a package, so we just remove the entire
                 // cache entry.
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
-            Self::Git => {
+            Self::Git | Self::Hg => {
                 // Nothing to do.
             }
             Self::Interpreter => {
                 // Nothing to do.
             }
-            Self::Archive => {
+            Self::Archive | Self::Content => {
                 // Nothing to do.
             }
             Self::Builds => {
@@ -1126,8 +1386,10 @@ impl CacheBucket {
             Self::SourceDistributions,
             Self::FlatIndex,
             Self::Git,
+            Self::Hg,
             Self::Interpreter,
             Self::Simple,
+            Self::Content,
             Self::Archive,
             Self::Builds,
             Self::Environments,
@@ -1285,24 +1547,29 @@ impl Freshness {
 pub enum Refresh {
     /// Don't refresh any entries.
     None(Timestamp),
-    /// Refresh entries linked to the given packages, if created before the given timestamp.
-    Packages(Vec<PackageName>, Timestamp),
+    /// Refresh entries linked to the given packages, along with the cached metadata for the given
+    /// indexes, if created before the given timestamp.
+    Packages(Vec<PackageName>, Vec<IndexName>, Timestamp),
     /// Refresh all entries created before the given timestamp.
     All(Timestamp),
 }
 
 impl Refresh {
     /// Determine the refresh strategy to use based on the command-line arguments.
-    pub fn from_args(refresh: Option<bool>, refresh_package: Vec<PackageName>) -> Self {
+    pub fn from_args(
+        refresh: Option<bool>,
+        refresh_package: Vec<PackageName>,
+        refresh_index: Vec<IndexName>,
+    ) -> Self {
         let timestamp = Timestamp::now();
         match refresh {
             Some(true) => Self::All(timestamp),
             Some(false) => Self::None(timestamp),
             None => {
-                if refresh_package.is_empty() {
+                if refresh_package.is_empty() && refresh_index.is_empty() {
                     Self::None(timestamp)
                 } else {
-                    Self::Packages(refresh_package, timestamp)
+                    Self::Packages(refresh_package, refresh_index, timestamp)
                 }
             }
         }
@@ -1312,7 +1579,7 @@ impl Refresh {
     pub fn timestamp(&self) -> Timestamp {
         match self {
             Self::None(timestamp) => *timestamp,
-            Self::Packages(_, timestamp) => *timestamp,
+            Self::Packages(_, _, timestamp) => *timestamp,
             Self::All(timestamp) => *timestamp,
         }
     }
@@ -1339,22 +1606,30 @@ impl Refresh {
             // Take the `max` of the two timestamps.
             (Self::None(t1), Refresh::None(t2)) => Refresh::None(max(t1, t2)),
             (Self::None(t1), Refresh::All(t2)) => Refresh::All(max(t1, t2)),
-            (Self::None(t1), Refresh::Packages(packages, t2)) => {
-                Refresh::Packages(packages, max(t1, t2))
+            (Self::None(t1), Refresh::Packages(packages, indexes, t2)) => {
+                Refresh::Packages(packages, indexes, max(t1, t2))
             }
 
             // If the policy is `All`, refresh all packages.
             (Self::All(t1), Refresh::None(t2)) => Refresh::All(max(t1, t2)),
             (Self::All(t1), Refresh::All(t2)) => Refresh::All(max(t1, t2)),
-            (Self::All(t1), Refresh::Packages(_packages, t2)) => Refresh::All(max(t1, t2)),
+            (Self::All(t1), Refresh::Packages(_packages, _indexes, t2)) => {
+                Refresh::All(max(t1, t2))
+            }
 
             // If the policy is `Packages`, take the "max" of the two policies.
-            (Self::Packages(packages, t1), Refresh::None(t2)) => {
-                Refresh::Packages(packages, max(t1, t2))
+            (Self::Packages(packages, indexes, t1), Refresh::None(t2)) => {
+                Refresh::Packages(packages, indexes, max(t1, t2))
+            }
+            (Self::Packages(_packages, _indexes, t1), Refresh::All(t2)) => {
+                Refresh::All(max(t1, t2))
             }
-            (Self::Packages(_packages, t1), Refresh::All(t2)) => Refresh::All(max(t1, t2)),
-            (Self::Packages(packages1, t1), Refresh::Packages(packages2, t2)) => Refresh::Packages(
+            (
+                Self::Packages(packages1, indexes1, t1),
+                Refresh::Packages(packages2, indexes2, t2),
+            ) => Refresh::Packages(
                 packages1.into_iter().chain(packages2).collect(),
+                indexes1.into_iter().chain(indexes2).collect(),
                 max(t1, t2),
             ),
         }
@@ -1363,9 +1638,10 @@ impl Refresh {
 
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
     use std::str::FromStr;
 
-    use crate::ArchiveId;
+    use crate::{ArchiveId, Cache, CacheBucket, CacheEntryKind, PackageFilter, WheelCache};
 
     use super::Link;
 
@@ -1386,4 +1662,78 @@ mod tests {
         assert!(Link::from_str("v1/foo").is_err());
         assert!(Link::from_str("archive-v0/").is_err());
     }
+
+    #[test]
+    fn remove_filtered() {
+        fn touch(path: &Path) {
+            fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+            fs_err::write(path, "").unwrap();
+        }
+
+        let cache = Cache::temp().unwrap();
+        let wheels = cache.bucket(CacheBucket::Wheels).join("pypi").join("numpy");
+        let builds = cache
+            .bucket(CacheBucket::SourceDistributions)
+            .join("pypi")
+            .join("numpy");
+        let built = cache
+            .bucket(CacheBucket::SourceDistributions)
+            .join(WheelCache::Built("key").wheel_dir("numpy"));
+        let simple = cache
+            .bucket(CacheBucket::Simple)
+            .join("pypi")
+            .join("numpy.rkyv");
+        let old_wheel = wheels.join("1.26.4-cp312-cp312-linux_x86_64.http");
+        let old_metadata = wheels.join("1.26.4-cp312-cp312-linux_x86_64.msgpack");
+        let new_wheel = wheels.join("2.0.0-cp312-cp312-linux_x86_64.http");
+        for path in [
+            &old_wheel,
+            &old_metadata,
+            &new_wheel,
+            &builds.join("1.26.4").join("metadata.msgpack"),
+            &builds.join("2.0.0").join("metadata.msgpack"),
+            &built.join("metadata.msgpack"),
+            &simple,
+        ] {
+            touch(path);
+        }
+
+        // Remove the build of a single version.
+        let filter = PackageFilter::from_str("numpy==1.26.4").unwrap();
+        cache
+            .remove_filtered(&filter, &[CacheEntryKind::Builds])
+            .unwrap();
+        assert!(!builds.join("1.26.4").exists());
+        assert!(builds.join("2.0.0").exists());
+        assert!(built.exists());
+        assert!(old_wheel.exists());
+
+        // Remove the downloaded wheels of a single version, retaining their metadata.
+        cache
+            .remove_filtered(&filter, &[CacheEntryKind::Wheels])
+            .unwrap();
+        assert!(!old_wheel.exists());
+        assert!(old_metadata.exists());
+        assert!(new_wheel.exists());
+
+        // Index metadata covers every version, so it's only removed without version specifiers.
+        cache
+            .remove_filtered(&filter, &[CacheEntryKind::Metadata])
+            .unwrap();
+        assert!(!old_metadata.exists());
+        assert!(simple.exists());
+
+        let filter = PackageFilter::from_str("numpy").unwrap();
+        cache
+            .remove_filtered(&filter, &[CacheEntryKind::Metadata])
+            .unwrap();
+        assert!(!simple.exists());
+        assert!(new_wheel.exists());
+
+        // Wheels built from any source of the package are removed alongside its builds.
+        cache
+            .remove_filtered(&filter, &[CacheEntryKind::Builds])
+            .unwrap();
+        assert!(!built.exists());
+    }
 }
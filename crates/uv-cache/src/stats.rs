@@ -0,0 +1,176 @@
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::eviction::inode;
+use crate::{Cache, CacheBucket};
+
+/// A file in the cache root that records the cache accesses of recent invocations.
+pub(crate) const ACCESS_LOG: &str = ".accesses";
+
+/// The number of invocations for which cache accesses are retained.
+const ACCESS_LOG_LIMIT: usize = 10;
+
+/// The number of cached HTTP responses that were used without revalidation.
+static HITS: AtomicU64 = AtomicU64::new(0);
+
+/// The number of cached HTTP responses that were used after revalidation with the server.
+static REVALIDATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// The number of HTTP responses that weren't cached, or were cached but outdated.
+static MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// The outcome of a lookup in the HTTP cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CacheAccess {
+    /// The cached response was fresh, and used as-is.
+    Hit,
+    /// The cached response was stale, but the server confirmed that it's still valid.
+    Revalidated,
+    /// The response wasn't cached, or the cached response was outdated.
+    Miss,
+}
+
+impl CacheAccess {
+    /// Record the access in the counters for the current invocation.
+    pub fn record(self) {
+        let counter = match self {
+            Self::Hit => &HITS,
+            Self::Revalidated => &REVALIDATIONS,
+            Self::Miss => &MISSES,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// The cache accesses of a single invocation.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessCounts {
+    /// The time at which the invocation completed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub hits: u64,
+    pub revalidations: u64,
+    pub misses: u64,
+}
+
+impl AccessCounts {
+    /// Return the accesses recorded so far in the current invocation.
+    pub fn current() -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+            hits: HITS.load(Ordering::Relaxed),
+            revalidations: REVALIDATIONS.load(Ordering::Relaxed),
+            misses: MISSES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Return the total number of accesses.
+    pub fn total(&self) -> u64 {
+        self.hits + self.revalidations + self.misses
+    }
+}
+
+/// The contents of a single cache bucket.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BucketUsage {
+    pub bucket: CacheBucket,
+    /// The number of files in the bucket.
+    pub num_files: u64,
+    /// The total size of the files in the bucket, in bytes.
+    pub total_bytes: u64,
+}
+
+impl Cache {
+    /// Return the number of files in, and the total size of, each cache bucket.
+    ///
+    /// Symbolic links (e.g., links to unzipped wheels) aren't followed, such that each file is
+    /// counted once. Files with multiple hard links (e.g., unzipped wheel files that are linked
+    /// from the content-addressed store) are counted in the first bucket that contains them.
+    pub fn usage(&self) -> Result<Vec<BucketUsage>, io::Error> {
+        let mut usage = Vec::new();
+        let mut seen = FxHashSet::default();
+        for bucket in CacheBucket::iter() {
+            let mut num_files = 0;
+            let mut total_bytes = 0;
+            let root = self.bucket(bucket);
+            if root.is_dir() {
+                for entry in walkdir::WalkDir::new(root) {
+                    let entry = entry?;
+                    if entry.file_type().is_file() {
+                        let metadata = entry.metadata()?;
+                        if inode(&metadata).is_some_and(|inode| !seen.insert(inode)) {
+                            continue;
+                        }
+                        num_files += 1;
+                        total_bytes += metadata.len();
+                    }
+                }
+            }
+            usage.push(BucketUsage {
+                bucket,
+                num_files,
+                total_bytes,
+            });
+        }
+        Ok(usage)
+    }
+
+    /// Return the cache accesses of recent invocations, from oldest to newest.
+    pub fn accesses(&self) -> Result<Vec<AccessCounts>, io::Error> {
+        match fs_err::read(self.root.join(ACCESS_LOG)) {
+            Ok(data) => Ok(rmp_serde::from_slice(&data).unwrap_or_else(|err| {
+                debug!("Ignoring malformed cache access log: {err}");
+                Vec::new()
+            })),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Append the cache accesses of the current invocation to the log of recent invocations.
+    ///
+    /// Invocations that didn't access the HTTP cache (and temporary caches) aren't recorded.
+    pub fn record_accesses(&self) -> Result<(), io::Error> {
+        let current = AccessCounts::current();
+        if current.total() == 0 || self.is_temporary() || !self.root.is_dir() {
+            return Ok(());
+        }
+
+        let mut accesses = self.accesses()?;
+        accesses.push(current);
+        if accesses.len() > ACCESS_LOG_LIMIT {
+            accesses.drain(..accesses.len() - ACCESS_LOG_LIMIT);
+        }
+        let data = rmp_serde::to_vec(&accesses).map_err(io::Error::other)?;
+        uv_fs::write_atomic_sync(self.root.join(ACCESS_LOG), data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, CacheBucket};
+
+    #[test]
+    fn usage() {
+        let cache = Cache::temp().unwrap().init().unwrap();
+        let simple = cache.bucket(CacheBucket::Simple);
+        fs_err::create_dir_all(simple.join("pypi")).unwrap();
+        fs_err::write(simple.join("pypi").join("anyio.rkyv"), vec![0; 100]).unwrap();
+        fs_err::write(simple.join("pypi").join("idna.rkyv"), vec![0; 50]).unwrap();
+
+        let usage = cache.usage().unwrap();
+        let simple = usage
+            .iter()
+            .find(|usage| usage.bucket == CacheBucket::Simple)
+            .unwrap();
+        assert_eq!(simple.num_files, 2);
+        assert_eq!(simple.total_bytes, 150);
+    }
+}
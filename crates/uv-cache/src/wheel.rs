@@ -21,6 +21,16 @@ pub enum WheelCache<'a> {
     /// Note that this variant only exists for source distributions; wheels can't be delivered
     /// through Git.
     Git(&'a Url, &'a str),
+    /// A Mercurial dependency, which we key by URL and changeset ID.
+    ///
+    /// As with Git, this variant only exists for source distributions.
+    Hg(&'a Url, &'a str),
+    /// A wheel built from a source distribution with a known hash, which we key by the hash of
+    /// the source distribution and the build environment.
+    ///
+    /// Unlike the other variants, these wheels are shared by every source of the same source
+    /// distribution (e.g., across indexes and projects).
+    Built(&'a str),
 }
 
 impl WheelCache<'_> {
@@ -44,12 +54,22 @@ impl WheelCache<'_> {
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url)))
                 .join(sha),
+            WheelCache::Hg(url, node) => WheelCacheKind::Hg
+                .root()
+                .join(cache_digest(&CanonicalUrl::new(url)))
+                .join(node),
+            WheelCache::Built(_) => WheelCacheKind::Built.root(),
         }
     }
 
     /// A subdirectory in a bucket for wheels for a specific package.
     pub fn wheel_dir(&self, package_name: impl AsRef<Path>) -> PathBuf {
-        self.root().join(package_name)
+        match self {
+            // Built wheels are indexed by package name first, such that they can be removed by
+            // name.
+            WheelCache::Built(key) => self.root().join(package_name).join(key),
+            _ => self.root().join(package_name),
+        }
     }
 }
 
@@ -67,6 +87,10 @@ pub(crate) enum WheelCacheKind {
     Editable,
     /// A cache of data from a Git repository.
     Git,
+    /// A cache of data from a Mercurial repository.
+    Hg,
+    /// A cache of wheels built from source distributions, keyed by source and build environment.
+    Built,
 }
 
 impl WheelCacheKind {
@@ -78,6 +102,8 @@ impl WheelCacheKind {
             Self::Path => "path",
             Self::Editable => "editable",
             Self::Git => "git",
+            Self::Hg => "hg",
+            Self::Built => "built",
         }
     }
 
@@ -337,13 +337,9 @@ impl CompatArgs for VenvCompatArgs {
 ///
 /// These represent a subset of the `pip install` interface that uv supports by default.
 #[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
 pub struct PipInstallCompatArgs {
     #[clap(long, hide = true)]
     disable_pip_version_check: bool,
-
-    #[clap(long, hide = false)]
-    user: bool,
 }
 
 impl CompatArgs for PipInstallCompatArgs {
@@ -357,12 +353,6 @@ impl CompatArgs for PipInstallCompatArgs {
             warn_user!("pip's `--disable-pip-version-check` has no effect");
         }
 
-        if self.user {
-            return Err(anyhow!(
-                "pip's `--user` is unsupported (use a virtual environment instead)"
-            ));
-        }
-
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -9,17 +10,23 @@ use clap::builder::Styles;
 use clap::{Args, Parser, Subcommand};
 
 use url::Url;
-use uv_cache::CacheArgs;
+use uv_cache::{CacheArgs, CacheEntryKind, PackageFilter};
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    CacheSize, ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
+};
+use uv_distribution_types::{
+    Index, IndexName, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex,
 };
-use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::platform::Arch;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode, SupportedPython,
+};
 use uv_static::EnvVars;
 
 pub mod comma;
@@ -44,6 +51,18 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonBuildVariant {
+    /// Builds that are dynamically linked against the GNU C library (glibc).
+    Gnu,
+    /// Builds that target musl libc and are statically linked, such that they run without the
+    /// GNU C library (e.g., on Alpine Linux).
+    Musl,
+    /// Builds without the test suite and Tk (i.e., `tkinter`, IDLE, and `turtle`), which aren't
+    /// needed to run Python applications (e.g., in container images).
+    Minimal,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -221,7 +240,8 @@ pub struct GlobalArgs {
     /// Can be provided multiple times.
     ///
     /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
-    /// `localhost:8080`), or a URL (e.g., `https://localhost`).
+    /// `localhost:8080`), a URL (e.g., `https://localhost`), or a pattern matching any subdomain
+    /// (e.g., `*.example.com:8443`).
     ///
     /// WARNING: Hosts included in this list will not be verified against the system's certificate
     /// store. Only use `--allow-insecure-host` in a secure network with verified sources, as it
@@ -236,6 +256,18 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// Record HTTP requests to the given file, for debugging.
+    ///
+    /// Each request to a registry or index is recorded with its method, URL, response status,
+    /// timing, and cache disposition (i.e., whether the response was served from the cache,
+    /// revalidated, or fetched). Credentials and headers are omitted.
+    ///
+    /// If the file has a `.har` extension, the requests are written as an HTTP Archive (HAR), which
+    /// can be viewed in a browser's developer tools. Otherwise, each request is written as a line of
+    /// JSON.
+    #[arg(global = true, long, env = EnvVars::UV_TRACE_HTTP)]
+    pub trace_http: Option<PathBuf>,
+
     /// Whether to enable experimental, preview features.
     ///
     /// Preview features may change without warning.
@@ -444,6 +476,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage authentication.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -551,13 +589,64 @@ pub enum CacheCommand {
     /// Note that it is important for performance for the cache directory to be located on the same
     /// file system as the Python environment uv is operating on.
     Dir,
+    /// Show the size of each cache bucket, and the cache hit rate of recent commands.
+    ///
+    /// The number of files and total size are reported for each kind of cache entry (e.g.,
+    /// unzipped wheels, source distribution builds, or package index metadata).
+    ///
+    /// For each of the most recent commands that made HTTP requests, the number of responses that
+    /// were served from the cache ("hits"), confirmed to be up-to-date by the server
+    /// ("revalidated"), or downloaded ("misses") is reported.
+    Stats,
+    /// Export the cached artifacts needed to install from a lockfile to an archive.
+    ///
+    /// For each package in the lockfile, the downloaded wheels and the wheels built from source
+    /// distributions that are present in the cache are written to a Zstandard-compressed tarball
+    /// (e.g., `uv-cache.tar.zst`). Other cache entries are omitted.
+    ///
+    /// Import the archive with `uv cache import` to seed the cache of another machine, e.g., one
+    /// without network access, which can then install from the lockfile with `--offline`.
+    Export(CacheExportArgs),
+    /// Import cached artifacts from an archive created by `uv cache export`.
+    ///
+    /// Entries that are already present in the cache are retained.
+    Import(CacheImportArgs),
+    /// Download the artifacts needed to install from the project's lockfile into the cache.
+    ///
+    /// Every wheel and source distribution that the lockfile requires on the target platform is
+    /// downloaded, including those for all extras and dependency groups, such that the project can
+    /// later be installed with `--offline`. The lockfile is read as-is, without checking that it's
+    /// up-to-date.
+    ///
+    /// By default, the target platform is that of the discovered Python interpreter. Use
+    /// `--python-platform` and `--python-version` to prefetch for another platform (e.g., when
+    /// preparing a container image).
+    Prefetch(CachePrefetchArgs),
+    /// Verify the integrity of the cache, removing any corrupted entries.
+    ///
+    /// The files of each unzipped wheel are checked against the hashes recorded in the wheel's
+    /// `RECORD` file. Corrupted entries (e.g., after a crash or a full disk) are removed, such
+    /// that they're downloaded or built again when next used.
+    Verify(CacheVerifyArgs),
 }
 
 #[derive(Args, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
-    pub package: Vec<PackageName>,
+    ///
+    /// A package may include version specifiers (e.g., `numpy==1.26.4` or `numpy<2`), in which
+    /// case only the entries for the matching versions are removed.
+    pub package: Vec<PackageFilter>,
+
+    /// Only remove the given kinds of cache entries for the packages.
+    ///
+    /// By default, all cache entries for the packages are removed. Package index metadata covers
+    /// every version of a package, so it's only removed for packages without version specifiers.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_enum, requires = "package")]
+    pub bucket: Vec<CacheEntryKind>,
 }
 
 #[derive(Args, Debug)]
@@ -578,6 +667,174 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least-recently-used entries from the cache until it's no larger than the given
+    /// size.
+    ///
+    /// Accepts a number of bytes, or a number with a decimal (`K`, `M`, `G`, `T`) or binary (`Ki`,
+    /// `Mi`, `Gi`, `Ti`) unit (e.g., `10GB` or `512MiB`). Unzipped wheels, source distribution
+    /// builds, and Git checkouts are evicted, while metadata is retained; evicted entries are
+    /// refetched or rebuilt when next used.
+    ///
+    /// Defaults to the `cache-max-size` setting, if any.
+    #[arg(long, value_name = "SIZE")]
+    pub max_size: Option<CacheSize>,
+
+    /// Remove every cache entry supplied by the given index (e.g., a mirror that's no longer in
+    /// use, or a registry that was compromised).
+    ///
+    /// Removes the package metadata of the index, the wheels and source distributions downloaded
+    /// from it, and any wheels built from those source distributions, including wheels that are
+    /// shared with other indexes that supplied the same source distribution. Removed entries are
+    /// refetched (or rebuilt) from the configured indexes when next used.
+    ///
+    /// Can be provided multiple times.
+    #[arg(long, value_name = "URL")]
+    pub index: Vec<IndexUrl>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The lockfile (e.g., `uv.lock`) for which to export cached artifacts.
+    #[arg(long, value_name = "PATH")]
+    pub from_lock: PathBuf,
+
+    /// The path to which to write the archive.
+    pub output: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to an archive created by `uv cache export`.
+    pub archive: PathBuf,
+}
+
+#[derive(Args)]
+pub struct CachePrefetchArgs {
+    /// Build wheels from any source distributions, in addition to downloading them.
+    ///
+    /// By default, source distributions are downloaded and unpacked, but not built. Wheels are
+    /// built with the discovered Python interpreter, so `--build` can't be combined with
+    /// `--python-platform` or `--python-version`.
+    #[arg(long, conflicts_with_all = ["python_platform", "python_version"])]
+    pub build: bool,
+
+    #[command(flatten)]
+    pub build_options: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python version for which to prefetch artifacts.
+    ///
+    /// For example, pass `--python-version 3.10` to download the wheels that would be installed on
+    /// Python 3.10.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which to prefetch artifacts.
+    ///
+    /// For example, pass `--python-platform x86_64-unknown-linux-gnu` to download the wheels that
+    /// would be installed on Linux, regardless of the current platform.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python interpreter to use to determine the target platform, and to build any source
+    /// distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Report any corrupted entries, but don't remove them.
+    ///
+    /// Exits with a non-zero status if any corrupted entries are found.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for an index.
+    ///
+    /// The password is stored in the system keyring (e.g., the macOS Keychain, the Windows
+    /// Credential Manager, or the Secret Service on Linux), and used for any request to the index
+    /// that requires authentication.
+    ///
+    /// If the username or password is omitted, uv will prompt for it.
+    Login(AuthLoginArgs),
+    /// Remove stored credentials for an index.
+    Logout(AuthLogoutArgs),
+    /// Show the stored password or token for an index.
+    Token(AuthTokenArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLoginArgs {
+    /// The name of an index in the configuration, or the URL of an index.
+    pub service: String,
+
+    /// The username to store.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// The password to store.
+    ///
+    /// Prefer omitting the password and entering it when prompted, to avoid leaving it in the
+    /// shell history.
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// The token to store.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password`.
+    #[arg(short, long, conflicts_with = "username", conflicts_with = "password")]
+    pub token: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLogoutArgs {
+    /// The name of an index in the configuration, or the URL of an index.
+    pub service: String,
+
+    /// The username to remove credentials for.
+    ///
+    /// If omitted, the credentials for every username are removed.
+    #[arg(short, long)]
+    pub username: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthTokenArgs {
+    /// The name of an index in the configuration, or the URL of an index.
+    pub service: String,
+
+    /// The username to show the password for.
+    #[arg(short, long)]
+    pub username: Option<String>,
 }
 
 #[derive(Args)]
@@ -1696,6 +1953,14 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Install packages into the user site directory (i.e., `site.USER_BASE`), rather than into
+    /// the virtual or system Python environment.
+    ///
+    /// The user base directory can be overridden with the `PYTHONUSERBASE` environment variable.
+    /// Not supported in virtual environments, in which the user site directory is not visible.
+    #[arg(long, conflicts_with_all = ["target", "prefix"])]
+    pub user: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1791,6 +2056,14 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the installed packages to the given path.
+    ///
+    /// The report follows the format of pip's installation report, including the download
+    /// location, hashes, and core metadata of each installed distribution. Use `-` to write the
+    /// report to stdout.
+    #[arg(long, value_name = "PATH", conflicts_with = "dry_run")]
+    pub report: Option<PathBuf>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1826,8 +2099,9 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -1877,10 +2151,26 @@ pub struct PipUninstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Uninstall packages from the user site directory (i.e., `site.USER_BASE`).
+    #[arg(long, conflicts_with_all = ["target", "prefix"])]
+    pub user: bool,
+
     /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Also uninstall any dependencies that are no longer required by another package in the
+    /// environment.
+    ///
+    /// Dependencies are determined from the metadata of the installed packages. Optional
+    /// dependencies are treated as required, such that packages that satisfy an extra of another
+    /// installed package are retained.
+    ///
+    /// When run in an interactive terminal, uv will list the additional packages and prompt for
+    /// confirmation before uninstalling them.
+    #[arg(long)]
+    pub autoremove: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipGlobalCompatArgs,
 }
@@ -2415,7 +2705,7 @@ pub struct VenvArgs {
     /// absolute paths), the entrypoints and scripts themselves will _not_ be relocatable. In other
     /// words, copying those entrypoints and scripts to a location outside the environment will not
     /// work, as they reference paths relative to the environment itself.
-    #[arg(long)]
+    #[arg(long, env = EnvVars::UV_VENV_RELOCATABLE, value_parser = clap::builder::BoolishValueParser::new())]
     pub relocatable: bool,
 
     #[command(flatten)]
@@ -2432,8 +2722,9 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -3075,6 +3366,18 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Make the project environment relocatable, if it needs to be created.
+    ///
+    /// Entrypoints and activation scripts in a relocatable environment use relative, rather than
+    /// absolute, paths, such that the environment can be moved (e.g., copied into a container
+    /// image at a different path) without breaking them. Once created, a relocatable project
+    /// environment remains relocatable when uv recreates it.
+    ///
+    /// Existing environments are not modified; to convert an existing environment, remove it
+    /// first, or create it with `uv venv --relocatable`.
+    #[arg(long, env = EnvVars::UV_VENV_RELOCATABLE, value_parser = clap::builder::BoolishValueParser::new())]
+    pub relocatable: bool,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3103,6 +3406,18 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Install locked wheels that are incompatible with the current interpreter.
+    ///
+    /// By default, uv will refuse to install a package if none of its locked wheels match the
+    /// current interpreter's tags (and no source distribution is available). With
+    /// `--allow-incompatible-wheels`, uv will install one of the incompatible wheels instead.
+    ///
+    /// This is unsafe: the installed package may fail to import or crash at runtime. It is only
+    /// intended for cases in which the wheel is known to be compatible, despite its tags (e.g.,
+    /// when installing into a directory for use by a different interpreter).
+    #[arg(long)]
+    pub allow_incompatible_wheels: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
@@ -3126,6 +3441,17 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with = "locked", conflicts_with = "frozen")]
     pub dry_run: bool,
 
+    /// Install registry distributions from a bundle created by `uv export --bundle`.
+    ///
+    /// Accepts a bundle directory, or a `.tar`, `.tar.gz`, or `.zip` archive of one. Distributions
+    /// are read from the bundle rather than the registry, so the bundle must have been created from
+    /// the same lockfile.
+    ///
+    /// Implies `--offline`. Consider combining with `--frozen`, as any changes to the lockfile
+    /// require network access.
+    #[arg(long, value_name = "PATH")]
+    pub from_bundle: Option<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3224,6 +3550,17 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
     pub dry_run: bool,
 
+    /// Display every point at which the resolution forked.
+    ///
+    /// For each fork point, uv reports the requirement that caused the fork, the marker expression
+    /// of each resulting fork, and the versions selected in each fork, followed by a summary of
+    /// the packages that were resolved to different versions across forks.
+    ///
+    /// If the lockfile is up-to-date, the project is re-resolved using the locked versions as
+    /// preferences.
+    #[arg(long, conflicts_with = "check_exists")]
+    pub dump_forks: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3258,6 +3595,17 @@ pub struct LockArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// The range of Python versions to support in the lockfile.
+    ///
+    /// Accepts a range of the form `3.9..3.12`, in which both bounds are inclusive and either
+    /// bound may be omitted (e.g., `3.10..`), or a set of version specifiers (e.g.,
+    /// `>=3.9, <3.13`).
+    ///
+    /// The range is intersected with the project's `requires-python`, such that the lockfile
+    /// only covers Python versions within both. Overrides the `supported-python` setting.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<SupportedPython>,
 }
 
 #[derive(Args)]
@@ -3761,6 +4109,16 @@ pub struct ExportArgs {
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
 
+    /// Download the distributions for all exported packages into the given directory, for use
+    /// with `uv sync --from-bundle`.
+    ///
+    /// The bundle includes the wheels and source distributions for every package installed from a
+    /// registry, along with a `bundle.json` manifest. Packages from Git, URL, or path sources are
+    /// not included. The directory may be archived (e.g., as a `.tar.gz` or `.zip`) before being
+    /// transferred to another machine.
+    #[arg(long, value_name = "DIR")]
+    pub bundle: Option<PathBuf>,
+
     /// Do not emit the current project.
     ///
     /// By default, the current project is included in the exported requirements file with all of
@@ -4223,8 +4581,9 @@ pub struct ToolUpgradeArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4281,6 +4640,57 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of iterations the resolver may perform before giving up.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Adding constraints for those packages typically allows the
+    /// resolver to converge more quickly.
+    ///
+    /// By default, the number of iterations is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_RESOLVER_ITERATIONS,
+        help_heading = "Resolver options"
+    )]
+    pub max_resolver_iterations: Option<NonZeroUsize>,
+
+    /// The maximum amount of time to spend resolving dependencies, in seconds.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Time spent fetching metadata before resolution begins is not
+    /// counted.
+    ///
+    /// By default, resolution time is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_TIMEOUT,
+        help_heading = "Resolver options"
+    )]
+    pub resolver_timeout: Option<u64>,
+
+    /// Minimize changes to the existing resolution when re-resolving.
+    ///
+    /// By default, when a previously locked version no longer satisfies the requirements, uv
+    /// will select the latest compatible version instead. With `--minimal-changes`, uv will
+    /// instead select the compatible version that's closest to the previously locked version,
+    /// avoiding unnecessary upgrades (or downgrades).
+    #[arg(
+        long,
+        env = EnvVars::UV_MINIMAL_CHANGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub minimal_changes: bool,
+
+    /// Break dependency cycles that are introduced through extras.
+    ///
+    /// By default, uv reports a warning when a package's extra depends (directly or
+    /// transitively) on another extra of a package that's already part of the derivation chain,
+    /// as in `a[x] -> b -> a[y]`. With `--break-extra-cycles`, uv will instead ignore the
+    /// back-edge that closes the cycle (here, `b -> a[y]`).
+    #[arg(long, help_heading = "Resolver options")]
+    pub break_extra_cycles: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -4416,6 +4826,20 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats.
     Install(PythonInstallArgs),
 
+    /// Upgrade installed Python versions to the latest patch release.
+    ///
+    /// For each installed minor version (e.g., `3.12`), the latest available patch release (e.g.,
+    /// `3.12.9`) is installed alongside the existing patch versions.
+    ///
+    /// Virtual environments created from a managed Python version refer to it through a link to
+    /// its minor version, which is updated to point to the latest patch release, such that the
+    /// environments use the upgraded version without being recreated. The previous patch versions
+    /// are retained for environments created before the link was introduced, and can be removed
+    /// with `uv python uninstall`.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Upgrade(PythonUpgradeArgs),
+
     /// Search for a Python installation.
     ///
     /// Displays the path to the Python executable.
@@ -4441,12 +4865,40 @@ pub enum PythonCommand {
     /// `$XDG_DATA_HOME/uv/python` or `$HOME/.local/share/uv/python` on Unix and
     /// `%APPDATA%\uv\data\python` on Windows.
     ///
-    /// The Python installation directory may be overridden with `$UV_PYTHON_INSTALL_DIR`.
+    /// The Python installation directory may be overridden with the `python-install-dir` setting
+    /// or `$UV_PYTHON_INSTALL_DIR`.
     ///
     /// To view the directory where uv installs Python executables instead, use the `--bin` flag.
     /// Note that Python executables are only installed when preview mode is enabled.
     Dir(PythonDirArgs),
 
+    /// Move the managed Python installations to another directory.
+    ///
+    /// The Python installations are moved from the Python installation directory (see `uv python
+    /// dir`) into the given directory, which is then persisted as the `python-install-dir`
+    /// setting in the user configuration file (e.g., `~/.config/uv/uv.toml`).
+    ///
+    /// The virtual environments of installed tools, the active virtual environment, and the
+    /// `.venv` discovered from the working directory are updated to use the moved installations.
+    /// Other virtual environments that were created from the installations must be recreated.
+    ///
+    /// Python versions that were built from source can't be moved, and must be reinstalled.
+    Move(PythonMoveArgs),
+
+    /// Install `python` and `python3` shims that run the managed Python version.
+    ///
+    /// The shims are installed into the Python executable directory (see `uv python dir --bin`).
+    /// When invoked, they run the interpreter reported by `uv python find --python-preference
+    /// only-managed` for the working directory: the virtual environment of the current project, if
+    /// any, or otherwise the managed Python version pinned in a `.python-version` file or required
+    /// by the project, falling back to the latest installed managed Python version.
+    ///
+    /// Existing `python` and `python3` executables, e.g., those installed by `uv python install
+    /// --default`, are only replaced with `--force`.
+    ///
+    /// Shims are not supported on Windows.
+    Shim(PythonShimArgs),
+
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 }
@@ -4472,16 +4924,29 @@ pub struct PythonListArgs {
     #[arg(long, alias = "all_architectures")]
     pub all_arches: bool,
 
+    /// Only show Python versions for the given architecture, e.g., `aarch64` or `x86_64`.
+    ///
+    /// Applies to both installed Python versions and available downloads.
+    #[arg(long, value_name = "ARCH", conflicts_with("all_arches"))]
+    pub arch: Option<Arch>,
+
     /// Only show installed Python versions, exclude available downloads.
     ///
     /// By default, available downloads for the current platform are shown.
-    #[arg(long, conflicts_with("only_downloads"))]
+    #[arg(long, conflicts_with_all = ["only_downloads", "only_managed"])]
     pub only_installed: bool,
 
+    /// Only show Python versions installed by uv, exclude system interpreters and available
+    /// downloads.
+    ///
+    /// By default, available downloads for the current platform are shown.
+    #[arg(long, conflicts_with_all = ["only_installed", "only_downloads"])]
+    pub only_managed: bool,
+
     /// Only show Python downloads, exclude installed distributions.
     ///
     /// By default, available downloads for the current platform are shown.
-    #[arg(long, conflicts_with("only_installed"))]
+    #[arg(long, conflicts_with_all = ["only_installed", "only_managed"])]
     pub only_downloads: bool,
 
     /// Show the URLs of available Python downloads.
@@ -4584,6 +5049,142 @@ pub struct PythonInstallArgs {
     /// If multiple Python versions are requested, uv will exit with an error.
     #[arg(long)]
     pub default: bool,
+
+    /// The build variants of the Python distributions to install.
+    ///
+    /// On Linux, uv installs builds that target the C library of the host by default: `musl`
+    /// builds on musl-based distributions, like Alpine Linux, and `gnu` builds otherwise. The
+    /// `musl` builds are statically linked, and so can also be used on hosts without glibc, but
+    /// can't load compiled extension modules.
+    ///
+    /// The `minimal` variant omits the test suite and Tk (i.e., `tkinter`, IDLE, and `turtle`)
+    /// from the installations, which reduces their size by tens of megabytes, e.g., for container
+    /// images that only run a synced environment. Existing installations are stripped, too; use
+    /// `--reinstall` to restore the omitted components. It can be combined with a C library, e.g.,
+    /// `--variant musl,minimal`.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub variant: Vec<PythonBuildVariant>,
+
+    /// Omit pip (i.e., the `pip` package and `ensurepip`) from the installations of the `minimal`
+    /// variant.
+    ///
+    /// Environments created by uv don't need pip, as uv installs packages into them.
+    #[arg(long)]
+    pub no_pip: bool,
+
+    /// The architecture of the Python distributions to install, e.g., `x86_64` or `aarch64`.
+    ///
+    /// By default, uv installs builds that target the architecture of the host. Builds for other
+    /// architectures can be installed where the host can emulate them, e.g., `x86_64` builds on
+    /// ARM64 macOS with Rosetta 2 and on ARM64 Windows. Packages installed for such a Python
+    /// version use wheels for its architecture.
+    ///
+    /// Equivalent to requesting the architecture in the version request, e.g.,
+    /// `cpython-3.12-macos-x86_64`.
+    #[arg(long, value_name = "ARCH")]
+    pub arch: Option<Arch>,
+
+    /// Install Python from the archives in a local directory, rather than downloading them.
+    ///
+    /// The directory must contain the archives of the requested Python versions, with the
+    /// filenames of their downloads (as shown by `uv python list --show-urls`), e.g.,
+    /// `cpython-3.12.9+20250317-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz`. Each
+    /// archive is verified against the known checksum of the distribution or, if unknown, a
+    /// `.sha256` file next to the archive. The network is not accessed.
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = ["mirror", "pypy_mirror", "build_from_source", "adopt"]
+    )]
+    pub from_dir: Option<PathBuf>,
+
+    /// Build CPython from source if no pre-built distribution is available for the platform.
+    ///
+    /// CPython is built from a source distribution published on `python.org`, which is pinned
+    /// and verified against a known SHA-256 digest, with `./configure`, `make`, and `make
+    /// install`. A C compiler and the development headers of CPython's dependencies are required.
+    /// Building from source is not supported on Windows.
+    ///
+    /// The `./configure` arguments are recorded in the installation, in `uv-source-build.json`,
+    /// and are reused when the Python version is reinstalled.
+    #[arg(long)]
+    pub build_from_source: bool,
+
+    /// Adopt the Python installations of pyenv and asdf, rather than downloading Python.
+    ///
+    /// The installations of pyenv (in `$PYENV_ROOT/versions`) and asdf (in
+    /// `$ASDF_DATA_DIR/installs/python`) that satisfy the requested Python versions are registered
+    /// as managed Python installations, such that they can be used without downloading the same
+    /// version again. If no Python versions are requested, all installations are adopted.
+    ///
+    /// Adopted installations link to the executables of the other version manager, which remains
+    /// responsible for them; they're not modified by uv, and `uv python uninstall` only removes
+    /// the registration. Adopting Python installations is not supported on Windows.
+    #[arg(
+        long,
+        conflicts_with_all = ["mirror", "pypy_mirror", "default", "variant", "no_pip", "arch", "build_from_source"]
+    )]
+    pub adopt: bool,
+
+    /// Register the Python installation in the Windows registry.
+    ///
+    /// Python installations are registered following PEP 514, such that other tools, like the
+    /// `py` launcher, can discover them. This is the default behavior in preview mode.
+    ///
+    /// This option has no effect on other platforms.
+    #[arg(long, overrides_with("no_registry"))]
+    pub registry: bool,
+
+    /// Do not register the Python installation in the Windows registry.
+    #[arg(long, overrides_with("registry"), hide = true)]
+    pub no_registry: bool,
+}
+
+#[derive(Args)]
+pub struct PythonUpgradeArgs {
+    /// The directory where the Python versions are installed.
+    ///
+    /// See `uv python dir` to view the current Python installation directory. Defaults to
+    /// `~/.local/share/uv/python`.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
+
+    /// The Python minor version(s) to upgrade.
+    ///
+    /// If not provided, every installed minor version is upgraded.
+    pub targets: Vec<String>,
+
+    /// Set the URL to use as the source for downloading Python installations.
+    ///
+    /// The provided URL will replace
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g.,
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
+    ///
+    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
+    pub mirror: Option<String>,
+
+    /// Set the URL to use as the source for downloading PyPy installations.
+    ///
+    /// The provided URL will replace `https://downloads.python.org/pypy` in, e.g.,
+    /// `https://downloads.python.org/pypy/pypy3.8-v7.3.7-osx64.tar.bz2`.
+    ///
+    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
+    pub pypy_mirror: Option<String>,
+
+    /// Register the upgraded Python installations in the Windows registry.
+    ///
+    /// Python installations are registered following PEP 514, such that other tools, like the
+    /// `py` launcher, can discover them. This is the default behavior in preview mode.
+    ///
+    /// This option has no effect on other platforms.
+    #[arg(long, overrides_with("no_registry"))]
+    pub registry: bool,
+
+    /// Do not register the upgraded Python installations in the Windows registry.
+    #[arg(long, overrides_with("registry"), hide = true)]
+    pub no_registry: bool,
 }
 
 #[derive(Args)]
@@ -4602,6 +5203,43 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Remove the Python installations from the Windows registry.
+    ///
+    /// This is the default behavior in preview mode. Use it to remove the entries of installations
+    /// that were registered with `uv python install --registry`.
+    ///
+    /// This option has no effect on other platforms.
+    #[arg(long, overrides_with("no_registry"))]
+    pub registry: bool,
+
+    /// Do not remove the Python installations from the Windows registry.
+    #[arg(long, overrides_with("registry"), hide = true)]
+    pub no_registry: bool,
+}
+
+#[derive(Args)]
+pub struct PythonShimArgs {
+    /// Replace existing `python` and `python3` executables that aren't shims.
+    #[arg(long, conflicts_with("uninstall"))]
+    pub force: bool,
+
+    /// Remove the shims, rather than installing them.
+    #[arg(long)]
+    pub uninstall: bool,
+}
+
+#[derive(Args)]
+pub struct PythonMoveArgs {
+    /// The directory to move the Python installations to.
+    pub target: PathBuf,
+
+    /// The directory where the Python versions are installed.
+    ///
+    /// See `uv python dir` to view the current Python installation directory. Defaults to
+    /// `~/.local/share/uv/python`.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -4619,6 +5257,15 @@ pub struct PythonFindArgs {
     #[arg(long, alias = "no_workspace")]
     pub no_project: bool,
 
+    /// Find the Python interpreter for a script, rather than the current project.
+    ///
+    /// The interpreter of the script's environment (as created by `uv run --script`) is reported,
+    /// if it exists and satisfies the script's requirements. Otherwise, a Python interpreter that
+    /// satisfies the script's PEP 723 `requires-python` and the Python version in its shebang
+    /// (e.g., `#!/usr/bin/env python3.12`) is found, and downloaded if necessary.
+    #[arg(long, conflicts_with_all = ["no_project", "system"])]
+    pub script: Option<PathBuf>,
+
     /// Only find system Python interpreters.
     ///
     /// By default, uv will report the first Python interpreter it would use, including those in an
@@ -4672,6 +5319,34 @@ pub struct PythonPinArgs {
     /// `requires-python` constraint.
     #[arg(long, alias = "no-workspace")]
     pub no_project: bool,
+
+    /// Update the global Python version pin.
+    ///
+    /// Writes the pinned Python version to a `.python-version` file in the uv user configuration
+    /// directory, i.e., `$XDG_CONFIG_HOME/uv` or `$HOME/.config/uv` on macOS and Linux, and
+    /// `%APPDATA%\uv` on Windows.
+    ///
+    /// The global pin is used when no `.python-version` file is found in the working directory
+    /// or any of its parents. An explicit request, e.g., with `--python`, takes precedence over
+    /// both.
+    ///
+    /// The global pin is not validated against the `requires-python` of the project or workspace.
+    #[arg(long)]
+    pub global: bool,
+
+    /// Pin the exact build of the Python version, in addition to the version.
+    ///
+    /// Records the build of the managed Python installation that the pinned version resolves to,
+    /// i.e., its release, archive URL, and checksum, in a `.python-build.json` file next to the
+    /// `.python-version` file. `uv python install` then installs exactly that build, rather than
+    /// the latest build of the version, and replaces installations of other builds.
+    ///
+    /// If no request is provided, the build of the currently pinned version is pinned.
+    ///
+    /// Builds are pinned per platform, so run the command on each platform the project is used
+    /// on. Only Python installations downloaded by uv can be pinned.
+    #[arg(long, conflicts_with_all = ["global", "resolved"])]
+    pub build: bool,
 }
 
 #[derive(Args)]
@@ -4704,6 +5379,8 @@ pub struct GenerateShellCompletionArgs {
     #[arg(long, hide = true)]
     pub no_progress: bool,
     #[arg(long, hide = true)]
+    pub trace_http: Option<PathBuf>,
+    #[arg(long, hide = true)]
     pub config_file: Option<PathBuf>,
     #[arg(long, hide = true)]
     pub no_config: bool,
@@ -4782,6 +5459,21 @@ pub struct IndexArgs {
     /// provided via `--find-links`.
     #[arg(long, help_heading = "Index options")]
     pub no_index: bool,
+
+    /// Require that all packages are resolved from an explicitly configured index.
+    ///
+    /// By default, uv falls back to PyPI when no default index is configured. With
+    /// `--strict-index`, uv will never fall back to PyPI, such that a package that isn't available
+    /// on any of the configured indexes (or `--find-links` locations) will fail to resolve, rather
+    /// than being fetched from PyPI. This prevents "dependency confusion" attacks against packages
+    /// that are only published to an internal index or mirror.
+    #[arg(
+        long,
+        env = EnvVars::UV_STRICT_INDEX,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Index options"
+    )]
+    pub strict_index: bool,
 }
 
 #[derive(Args)]
@@ -4808,6 +5500,13 @@ pub struct RefreshArgs {
     /// Refresh cached data for a specific package.
     #[arg(long, help_heading = "Cache options")]
     pub refresh_package: Vec<PackageName>,
+
+    /// Refresh the cached metadata for a specific index, by name.
+    ///
+    /// Revalidates the cached package listings (i.e., the simple API pages) from the named index,
+    /// without invalidating cached wheels or source distributions.
+    #[arg(long, help_heading = "Cache options")]
+    pub refresh_index: Vec<IndexName>,
 }
 
 #[derive(Args)]
@@ -4863,6 +5562,34 @@ pub struct BuildOptionsArgs {
     /// Don't install pre-built wheels for a specific package.
     #[arg(long, help_heading = "Build options", env = EnvVars::UV_NO_BINARY_PACKAGE, value_delimiter = ' ')]
     pub no_binary_package: Vec<PackageName>,
+
+    /// Prefer versions with pre-built wheels for a specific package.
+    ///
+    /// When selecting a version of the given packages, uv will prefer the most recent version with
+    /// a compatible wheel over a more recent version that's only available as a source
+    /// distribution. If no version with a compatible wheel is available, uv will fall back to
+    /// building from source.
+    ///
+    /// Unlike `--no-build-package`, this is a preference, not a requirement.
+    #[arg(long, help_heading = "Build options", env = EnvVars::UV_PREFER_BINARY_PACKAGE, value_delimiter = ' ')]
+    pub prefer_binary_package: Vec<PackageName>,
+
+    /// Only use pre-built wheels that are compatible with the target platforms.
+    ///
+    /// If the most recent version of a package lacks a compatible wheel, uv will select an older
+    /// version that has one, rather than building the package from source. Unlike `--no-build`,
+    /// this also applies to universal resolutions (e.g., `uv lock`), in which the selected wheels
+    /// must cover every environment in the resolution (e.g., as narrowed by `environments`), or,
+    /// if set, each of the `required-environments`.
+    ///
+    /// Implies `--no-build`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BINARY_ONLY,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub binary_only: bool,
 }
 
 /// Arguments that are used by commands that need to install (but not resolve) packages.
@@ -4911,8 +5638,9 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5053,8 +5781,9 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5111,6 +5840,57 @@ pub struct ResolverArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of iterations the resolver may perform before giving up.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Adding constraints for those packages typically allows the
+    /// resolver to converge more quickly.
+    ///
+    /// By default, the number of iterations is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_RESOLVER_ITERATIONS,
+        help_heading = "Resolver options"
+    )]
+    pub max_resolver_iterations: Option<NonZeroUsize>,
+
+    /// The maximum amount of time to spend resolving dependencies, in seconds.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Time spent fetching metadata before resolution begins is not
+    /// counted.
+    ///
+    /// By default, resolution time is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_TIMEOUT,
+        help_heading = "Resolver options"
+    )]
+    pub resolver_timeout: Option<u64>,
+
+    /// Minimize changes to the existing resolution when re-resolving.
+    ///
+    /// By default, when a previously locked version no longer satisfies the requirements, uv
+    /// will select the latest compatible version instead. With `--minimal-changes`, uv will
+    /// instead select the compatible version that's closest to the previously locked version,
+    /// avoiding unnecessary upgrades (or downgrades).
+    #[arg(
+        long,
+        env = EnvVars::UV_MINIMAL_CHANGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub minimal_changes: bool,
+
+    /// Break dependency cycles that are introduced through extras.
+    ///
+    /// By default, uv reports a warning when a package's extra depends (directly or
+    /// transitively) on another extra of a package that's already part of the derivation chain,
+    /// as in `a[x] -> b -> a[y]`. With `--break-extra-cycles`, uv will instead ignore the
+    /// back-edge that closes the cycle (here, `b -> a[y]`).
+    #[arg(long, help_heading = "Resolver options")]
+    pub break_extra_cycles: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -5243,8 +6023,9 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5301,6 +6082,57 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of iterations the resolver may perform before giving up.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Adding constraints for those packages typically allows the
+    /// resolver to converge more quickly.
+    ///
+    /// By default, the number of iterations is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_RESOLVER_ITERATIONS,
+        help_heading = "Resolver options"
+    )]
+    pub max_resolver_iterations: Option<NonZeroUsize>,
+
+    /// The maximum amount of time to spend resolving dependencies, in seconds.
+    ///
+    /// When exceeded, resolution is aborted with a report of the packages that were responsible
+    /// for the most backtracking. Time spent fetching metadata before resolution begins is not
+    /// counted.
+    ///
+    /// By default, resolution time is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_TIMEOUT,
+        help_heading = "Resolver options"
+    )]
+    pub resolver_timeout: Option<u64>,
+
+    /// Minimize changes to the existing resolution when re-resolving.
+    ///
+    /// By default, when a previously locked version no longer satisfies the requirements, uv
+    /// will select the latest compatible version instead. With `--minimal-changes`, uv will
+    /// instead select the compatible version that's closest to the previously locked version,
+    /// avoiding unnecessary upgrades (or downgrades).
+    #[arg(
+        long,
+        env = EnvVars::UV_MINIMAL_CHANGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub minimal_changes: bool,
+
+    /// Break dependency cycles that are introduced through extras.
+    ///
+    /// By default, uv reports a warning when a package's extra depends (directly or
+    /// transitively) on another extra of a package that's already part of the derivation chain,
+    /// as in `a[x] -> b -> a[y]`. With `--break-extra-cycles`, uv will instead ignore the
+    /// back-edge that closes the cycle (here, `b -> a[y]`).
+    #[arg(long, help_heading = "Resolver options")]
+    pub break_extra_cycles: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -5414,8 +6246,9 @@ pub struct FetchArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5532,8 +6365,9 @@ pub struct PublishArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to use the `keyring` CLI to handle authentication, or
+    /// `--keyring-provider native` to read credentials directly from the native credential store
+    /// (i.e., the macOS Keychain, Windows Credential Manager, or Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -24,9 +24,10 @@ impl From<RefreshArgs> for Refresh {
             refresh,
             no_refresh,
             refresh_package,
+            refresh_index,
         } = value;
 
-        Self::from_args(flag(refresh, no_refresh), refresh_package)
+        Self::from_args(flag(refresh, no_refresh), refresh_package, refresh_index)
     }
 }
 
@@ -43,6 +44,10 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            max_resolver_iterations,
+            resolver_timeout,
+            minimal_changes,
+            break_extra_cycles,
             config_setting,
             no_build_isolation,
             no_build_isolation_package,
@@ -59,6 +64,10 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            max_resolver_iterations,
+            resolver_timeout,
+            minimal_changes: if minimal_changes { Some(true) } else { None },
+            break_extra_cycles: if break_extra_cycles { Some(true) } else { None },
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -128,6 +137,10 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            max_resolver_iterations,
+            resolver_timeout,
+            minimal_changes,
+            break_extra_cycles,
             config_setting,
             no_build_isolation,
             no_build_isolation_package,
@@ -153,6 +166,10 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            max_resolver_iterations,
+            resolver_timeout,
+            minimal_changes: if minimal_changes { Some(true) } else { None },
+            break_extra_cycles: if break_extra_cycles { Some(true) } else { None },
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             no_build_isolation: flag(no_build_isolation, build_isolation),
@@ -192,6 +209,7 @@ impl From<IndexArgs> for PipOptions {
             index_url,
             extra_index_url,
             no_index,
+            strict_index,
             find_links,
         } = args;
 
@@ -210,6 +228,7 @@ impl From<IndexArgs> for PipOptions {
                     .collect()
             }),
             no_index: if no_index { Some(true) } else { None },
+            strict_index: if strict_index { Some(true) } else { None },
             find_links: find_links.map(|find_links| {
                 find_links
                     .into_iter()
@@ -237,6 +256,10 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        max_resolver_iterations,
+        resolver_timeout,
+        minimal_changes,
+        break_extra_cycles,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
@@ -253,6 +276,8 @@ pub fn resolver_options(
         no_binary,
         binary,
         no_binary_package,
+        prefer_binary_package,
+        binary_only,
     } = build_args;
 
     ResolverOptions {
@@ -277,6 +302,11 @@ pub fn resolver_options(
         } else {
             None
         },
+        strict_index: if index_args.strict_index {
+            Some(true)
+        } else {
+            None
+        },
         find_links: index_args.find_links.map(|find_links| {
             find_links
                 .into_iter()
@@ -294,7 +324,15 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        max_resolver_iterations,
+        resolver_timeout,
+        minimal_changes: if minimal_changes { Some(true) } else { None },
+        break_extra_cycles: if break_extra_cycles { Some(true) } else { None },
+        prefetch_versions: None,
         dependency_metadata: None,
+        supported_python: None,
+        yanked: None,
+        allow_yanked: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         no_build_isolation: flag(no_build_isolation, build_isolation),
@@ -305,6 +343,8 @@ pub fn resolver_options(
         no_build_package: Some(no_build_package),
         no_binary: flag(no_binary, binary),
         no_binary_package: Some(no_binary_package),
+        prefer_binary_package: Some(prefer_binary_package),
+        binary_only: if binary_only { Some(true) } else { None },
         no_sources: if no_sources { Some(true) } else { None },
     }
 }
@@ -328,6 +368,10 @@ pub fn resolver_installer_options(
         prerelease,
         pre,
         fork_strategy,
+        max_resolver_iterations,
+        resolver_timeout,
+        minimal_changes,
+        break_extra_cycles,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
@@ -346,6 +390,8 @@ pub fn resolver_installer_options(
         no_binary,
         binary,
         no_binary_package,
+        prefer_binary_package,
+        binary_only,
     } = build_args;
 
     let default_index = index_args
@@ -370,6 +416,11 @@ pub fn resolver_installer_options(
         } else {
             None
         },
+        strict_index: if index_args.strict_index {
+            Some(true)
+        } else {
+            None
+        },
         find_links: index_args.find_links.map(|find_links| {
             find_links
                 .into_iter()
@@ -397,7 +448,15 @@ pub fn resolver_installer_options(
             prerelease
         },
         fork_strategy,
+        max_resolver_iterations,
+        resolver_timeout,
+        minimal_changes: if minimal_changes { Some(true) } else { None },
+        break_extra_cycles: if break_extra_cycles { Some(true) } else { None },
+        prefetch_versions: None,
         dependency_metadata: None,
+        supported_python: None,
+        yanked: None,
+        allow_yanked: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         no_build_isolation: flag(no_build_isolation, build_isolation),
@@ -421,6 +480,12 @@ pub fn resolver_installer_options(
         } else {
             Some(no_binary_package)
         },
+        prefer_binary_package: if prefer_binary_package.is_empty() {
+            None
+        } else {
+            Some(prefer_binary_package)
+        },
+        binary_only: if binary_only { Some(true) } else { None },
         no_sources: if no_sources { Some(true) } else { None },
     }
 }
@@ -1,10 +1,8 @@
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, Response};
+use reqwest::{Client, ClientBuilder, Proxy, Response};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::{
-    DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
-};
+use reqwest_retry::{DefaultRetryableStrategy, Retryable, RetryableStrategy};
 use std::error::Error;
 use std::fmt::Debug;
 use std::path::Path;
@@ -13,8 +11,9 @@ use std::time::Duration;
 use std::{env, iter};
 use tracing::{debug, trace};
 use url::Url;
-use uv_auth::AuthMiddleware;
+use uv_auth::{AuthMiddleware, CredentialHelper};
 use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_distribution_types::Index;
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -22,13 +21,17 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::bucket::{BucketMiddleware, Buckets};
+use crate::connection::{ConnectionOptions, ConnectionStats, StatsMiddleware};
+use crate::http_trace::{HttpTrace, TraceMiddleware};
+use crate::limits::{HostPermits, LimitsMiddleware, NetworkLimits};
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{redirect_policy, OfflineMiddleware, RedirectMiddleware};
+use crate::proxy::ProxyConfig;
+use crate::retry::{HttpRetryPolicy, RetryMiddleware, RetryParameters};
+use crate::tls::{read_certificates, read_identity, read_identity_with_key, TlsConfig};
 use crate::Connectivity;
 
-pub const DEFAULT_RETRIES: u32 = 3;
-
 /// Selectively skip parts or the entire auth middleware.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum AuthIntegration {
@@ -48,7 +51,10 @@ pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
     allow_insecure_host: Vec<TrustedHost>,
     native_tls: bool,
-    retries: u32,
+    retry_policy: HttpRetryPolicy,
+    retries: Option<u32>,
+    indexes: Vec<Index>,
+    tls: Vec<(TrustedHost, TlsConfig)>,
     pub connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
@@ -83,7 +89,10 @@ impl BaseClientBuilder<'_> {
             allow_insecure_host: vec![],
             native_tls: false,
             connectivity: Connectivity::Online,
-            retries: DEFAULT_RETRIES,
+            retry_policy: HttpRetryPolicy::default(),
+            retries: None,
+            indexes: vec![],
+            tls: vec![],
             client: None,
             markers: None,
             platform: None,
@@ -113,9 +122,24 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the [`HttpRetryPolicy`] for requests that fail with a transient error.
+    #[must_use]
+    pub fn http_retries(mut self, retry_policy: HttpRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Override the number of retries for every host, regardless of the [`HttpRetryPolicy`].
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
-        self.retries = retries;
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Set the indexes whose proxy settings apply to requests to their hosts.
+    #[must_use]
+    pub fn indexes(mut self, indexes: Vec<Index>) -> Self {
+        self.indexes = indexes;
         self
     }
 
@@ -161,13 +185,26 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Use the given [`TlsConfig`] for requests to the host of the given URL.
+    #[must_use]
+    pub fn tls(mut self, url: &Url, tls: TlsConfig) -> Self {
+        if let Some(host) = TrustedHost::from_url(url) {
+            self.tls.push((host, tls));
+        }
+        self
+    }
+
     pub fn is_offline(&self) -> bool {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+    /// Return the [`HttpRetryPolicy`] for the client, applying any override for the number of
+    /// retries.
+    fn retry_policy(&self) -> HttpRetryPolicy {
+        match self.retries {
+            Some(retries) => self.retry_policy.clone().with_retries(retries),
+            None => self.retry_policy.clone(),
+        }
     }
 
     pub fn build(&self) -> BaseClient {
@@ -194,6 +231,8 @@ impl<'a> BaseClientBuilder<'a> {
             path_exists
         });
 
+        let limits = NetworkLimits::global();
+
         // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
         // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
         let timeout = env::var(EnvVars::UV_HTTP_TIMEOUT)
@@ -205,17 +244,34 @@ impl<'a> BaseClientBuilder<'a> {
                     .or_else(|_| {
                         // On parse error, warn and use the default timeout
                         warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
-                        Ok(self.default_timeout)
+                        Ok(limits.read_timeout.unwrap_or(self.default_timeout))
                     })
             })
-            .unwrap_or(self.default_timeout);
+            .unwrap_or_else(|_| limits.read_timeout.unwrap_or(self.default_timeout));
         debug!("Using request timeout of {}s", timeout.as_secs());
+        if let Some(connect_timeout) = limits.connect_timeout {
+            debug!("Using connect timeout of {}s", connect_timeout.as_secs());
+        }
+
+        // Resolve the proxies from the environment and any per-index overrides.
+        let proxies = ProxyConfig::from_env().with_indexes(&self.indexes);
+
+        // Authenticate requests to any cloud storage buckets used as indexes, and record the
+        // requests to each host.
+        let shared = SharedState {
+            buckets: Buckets::default().with_indexes(&self.indexes),
+            host_permits: HostPermits::default(),
+            stats: ConnectionStats::default(),
+            http_trace: HttpTrace::new(),
+        };
 
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
             &user_agent_string,
             timeout,
             ssl_cert_file_exists,
+            &proxies,
+            &TlsConfig::default(),
             Security::Secure,
         );
 
@@ -224,40 +280,87 @@ impl<'a> BaseClientBuilder<'a> {
             &user_agent_string,
             timeout,
             ssl_cert_file_exists,
+            &proxies,
+            &TlsConfig::default(),
             Security::Insecure,
         );
 
+        // Create a secure client for each host with its own TLS configuration (e.g., an index
+        // that requires a client certificate).
+        let host_clients = self
+            .tls_hosts()
+            .into_iter()
+            .map(|(host, tls)| {
+                let raw_client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    &proxies,
+                    &tls,
+                    Security::Secure,
+                );
+                HostClient {
+                    host,
+                    client: self.apply_middleware(raw_client.clone(), &shared),
+                    raw_client,
+                }
+            })
+            .collect();
+
         // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(raw_client.clone());
-        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        let client = self.apply_middleware(raw_client.clone(), &shared);
+        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone(), &shared);
 
         BaseClient {
             connectivity: self.connectivity,
-            allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            allow_insecure_host: self.insecure_hosts(),
+            retry_policy: self.retry_policy(),
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            host_clients,
             timeout,
+            proxies,
+            shared,
+            keyring: self.keyring,
         }
     }
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
+        // Share the state of the existing client, along with any buckets used by this one.
+        let shared = SharedState {
+            buckets: existing.shared.buckets.clone().with_indexes(&self.indexes),
+            ..existing.shared.clone()
+        };
+
         // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(existing.raw_client.clone());
-        let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+        let client = self.apply_middleware(existing.raw_client.clone(), &shared);
+        let dangerous_client =
+            self.apply_middleware(existing.raw_dangerous_client.clone(), &shared);
 
         BaseClient {
             connectivity: self.connectivity,
-            allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            allow_insecure_host: self.insecure_hosts(),
+            retry_policy: self.retry_policy(),
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            host_clients: existing
+                .host_clients
+                .iter()
+                .map(|host_client| HostClient {
+                    host: host_client.host.clone(),
+                    client: self.apply_middleware(host_client.raw_client.clone(), &shared),
+                    raw_client: host_client.raw_client.clone(),
+                })
+                .collect(),
             timeout: existing.timeout,
+            proxies: existing.proxies.clone(),
+            shared,
+            keyring: self.keyring,
         }
     }
 
@@ -266,16 +369,27 @@ impl<'a> BaseClientBuilder<'a> {
         user_agent: &str,
         timeout: Duration,
         ssl_cert_file_exists: bool,
+        proxies: &ProxyConfig,
+        tls: &TlsConfig,
         security: Security,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
             .http1_title_case_headers()
             .user_agent(user_agent)
-            .pool_max_idle_per_host(20)
             .read_timeout(timeout)
+            .redirect(redirect_policy())
             .tls_built_in_root_certs(false);
 
+        // Apply any connection pooling, HTTP version, or address family options.
+        let client_builder = ConnectionOptions::global().apply(client_builder);
+
+        // If configured, bound the time spent establishing a connection.
+        let client_builder = match NetworkLimits::global().connect_timeout {
+            Some(connect_timeout) => client_builder.connect_timeout(connect_timeout),
+            None => client_builder,
+        };
+
         // If necessary, accept invalid certificates.
         let client_builder = match security {
             Security::Secure => client_builder,
@@ -288,8 +402,41 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+        // Trust any additional certificate authorities for the host.
+        let client_builder = if let Some(ssl_ca_cert) = tls.ca_cert.as_deref() {
+            match read_certificates(ssl_ca_cert) {
+                Ok(certificates) => certificates
+                    .into_iter()
+                    .fold(client_builder, ClientBuilder::add_root_certificate),
+                Err(err) => {
+                    warn_user_once!(
+                        "Ignoring invalid `ssl-ca-cert` ({}): {err}",
+                        ssl_ca_cert.simplified_display().cyan()
+                    );
+                    client_builder
+                }
+            }
+        } else {
+            client_builder
+        };
+
+        // Configure mTLS, preferring the host's client certificate over `SSL_CLIENT_CERT`.
+        let client_builder = if let Some(ssl_client_cert) = tls.client_cert.as_deref() {
+            let identity = match tls.client_key.as_deref() {
+                Some(ssl_client_key) => read_identity_with_key(ssl_client_cert, ssl_client_key),
+                None => read_identity(ssl_client_cert.as_os_str()),
+            };
+            match identity {
+                Ok(identity) => client_builder.identity(identity),
+                Err(err) => {
+                    warn_user_once!(
+                        "Ignoring invalid `ssl-client-cert` ({}): {err}",
+                        ssl_client_cert.simplified_display().cyan()
+                    );
+                    client_builder
+                }
+            }
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
@@ -301,36 +448,89 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Route requests through the configured proxies. If none are configured, defer to
+        // `reqwest`, which also respects the platform's proxy settings (e.g., on macOS).
+        let client_builder = if proxies.is_empty() {
+            client_builder
+        } else {
+            let proxies = proxies.clone();
+            client_builder.proxy(Proxy::custom(move |url| proxies.proxy(url).cloned()))
+        };
+
         client_builder
             .build()
             .expect("Failed to build HTTP client.")
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    /// Return the hosts that are trusted to use the insecure client, from any explicit settings and
+    /// any indexes that allow insecure connections.
+    fn insecure_hosts(&self) -> Vec<TrustedHost> {
+        let indexes = self
+            .indexes
+            .iter()
+            .filter(|index| index.allow_insecure_host)
+            .filter_map(|index| TrustedHost::from_url(index.raw_url()));
+        self.allow_insecure_host
+            .iter()
+            .cloned()
+            .chain(indexes)
+            .collect()
+    }
+
+    /// Return the hosts with their own TLS configuration, from the indexes and any explicit
+    /// overrides, in order of precedence.
+    fn tls_hosts(&self) -> Vec<(TrustedHost, TlsConfig)> {
+        let indexes = self.indexes.iter().filter_map(|index| {
+            let tls = TlsConfig::from_index(index);
+            if tls.is_empty() {
+                return None;
+            }
+            Some((TrustedHost::from_url(index.raw_url())?, tls))
+        });
+        indexes.chain(self.tls.iter().cloned()).collect()
+    }
+
+    /// Return the [`CredentialHelper`]s for any indexes that mint their own credentials.
+    fn credential_helpers(&self) -> Vec<Arc<CredentialHelper>> {
+        self.indexes
+            .iter()
+            .filter_map(|index| {
+                let helper = index.credential_helper?;
+                Some(CredentialHelper::new(helper.into(), index.raw_url()))
+            })
+            .collect()
+    }
+
+    fn apply_middleware(&self, client: Client, shared: &SharedState) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
-                if self.retries > 0 {
+                let retry_policy = self.retry_policy();
+                if !retry_policy.is_disabled() {
                     // Initialize the retry strategy.
-                    let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
-                        UvRetryableStrategy,
-                    );
-                    client = client.with(retry_strategy);
+                    client = client.with(RetryMiddleware::new(retry_policy));
                 }
 
+                // Follow redirects to other origins ahead of authentication, such that credentials
+                // are resolved for the origin to which the request is redirected.
+                client = client.with(RedirectMiddleware);
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(self.keyring.to_provider())
+                                .with_credential_helpers(self.credential_helpers()),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
                                 .with_keyring(self.keyring.to_provider())
+                                .with_credential_helpers(self.credential_helpers())
                                 .with_only_authenticated(true),
                         );
                     }
@@ -346,6 +546,27 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Authenticate requests to any cloud storage buckets used as indexes, including
+                // those resolved once they're read (e.g., for `--find-links`).
+                client = client.with(BucketMiddleware::new(shared.buckets.clone()));
+
+                // Enforce any per-host concurrency or bandwidth limits.
+                let limits = NetworkLimits::global();
+                if limits.is_limited() {
+                    client =
+                        client.with(LimitsMiddleware::new(limits, shared.host_permits.clone()));
+                }
+
+                // Record the requests to each host, to summarize them once the client is dropped.
+                if tracing::enabled!(tracing::Level::TRACE) {
+                    client = client.with(StatsMiddleware::new(shared.stats.clone()));
+                }
+
+                // Record each request in the HTTP trace, if requested (i.e., with `--trace-http`).
+                if let Some(http_trace) = &shared.http_trace {
+                    client = client.with(TraceMiddleware::new(http_trace.clone()));
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -366,14 +587,46 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for hosts with their own TLS configuration.
+    host_clients: Vec<HostClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
-    /// The number of retries to attempt on transient errors.
-    retries: u32,
+    /// The policy for retrying requests that fail with a transient error.
+    retry_policy: HttpRetryPolicy,
+    /// The proxies to use for requests.
+    proxies: ProxyConfig,
+    /// The state shared by the middleware of this client and any clients that wrap it.
+    shared: SharedState,
+    /// The keyring provider used for credential lookup.
+    keyring: KeyringProviderType,
+}
+
+/// The state shared by the middleware of a client and any clients that wrap it.
+#[derive(Debug, Clone)]
+struct SharedState {
+    /// The cloud storage buckets used as indexes, whose requests are authenticated.
+    buckets: Buckets,
+    /// The concurrency limit for each host.
+    host_permits: HostPermits,
+    /// The requests sent to each host.
+    stats: ConnectionStats,
+    /// The requests recorded for the HTTP trace, if enabled.
+    http_trace: Option<HttpTrace>,
+}
+
+/// An HTTP client for requests to a host with its own TLS configuration.
+#[derive(Debug, Clone)]
+struct HostClient {
+    /// The host to which the client applies.
+    host: TrustedHost,
+    /// The HTTP client, with middleware.
+    client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -385,10 +638,16 @@ enum Security {
 }
 
 impl BaseClient {
-    /// Selects the appropriate client based on the host's trustworthiness.
+    /// Selects the appropriate client based on the host's trustworthiness and TLS configuration.
     pub fn for_host(&self, url: &Url) -> &ClientWithMiddleware {
         if self.disable_ssl(url) {
             &self.dangerous_client
+        } else if let Some(host_client) = self
+            .host_clients
+            .iter()
+            .find(|host_client| host_client.host.matches(url))
+        {
+            &host_client.client
         } else {
             &self.client
         }
@@ -411,9 +670,34 @@ impl BaseClient {
         self.connectivity
     }
 
-    /// The [`RetryPolicy`] for the client.
-    pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+    /// The [`RetryPolicy`] for requests to the given URL.
+    pub fn retry_policy(&self, url: &Url) -> ExponentialBackoff {
+        self.retry_policy.parameters(url).backoff()
+    }
+
+    /// The [`RetryParameters`] for requests to the given URL.
+    pub fn retry_parameters(&self, url: &Url) -> &RetryParameters {
+        self.retry_policy.parameters(url)
+    }
+
+    /// The proxy to use for requests to the given URL, if any.
+    pub fn proxy(&self, url: &Url) -> Option<&Url> {
+        self.proxies.proxy(url)
+    }
+
+    /// The configured keyring provider.
+    pub fn keyring(&self) -> KeyringProviderType {
+        self.keyring
+    }
+
+    /// The cloud storage buckets used as indexes.
+    pub(crate) fn buckets(&self) -> &Buckets {
+        &self.shared.buckets
+    }
+
+    /// The requests recorded for the HTTP trace, if enabled.
+    pub(crate) fn http_trace(&self) -> Option<&HttpTrace> {
+        self.shared.http_trace.as_ref()
     }
 }
 
@@ -10,7 +10,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::{CacheAccess, CacheEntry, Freshness};
 use uv_fs::write_atomic;
 
 use crate::base_client::is_extended_transient_error;
@@ -177,6 +177,27 @@ impl From<Freshness> for CacheControl {
     }
 }
 
+impl CacheControl {
+    /// Determine the [`CacheControl`] for a cache entry with a fixed maximum age, overriding the
+    /// `cache-control` header from the response.
+    ///
+    /// Entries younger than the maximum age are reused as-is; older entries are revalidated.
+    pub(crate) fn from_max_age(entry: &CacheEntry, max_age: Duration) -> std::io::Result<Self> {
+        match fs_err::metadata(entry.path()) {
+            Ok(metadata) => {
+                let age = metadata.modified()?.elapsed().unwrap_or_default();
+                if age <= max_age {
+                    Ok(Self::AllowStale)
+                } else {
+                    Ok(Self::MustRevalidate)
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
 /// Custom caching layer over [`reqwest::Client`].
 ///
 /// The implementation takes inspiration from the `http-cache` crate, but adds support for running
@@ -280,6 +301,11 @@ impl CachedClient {
                 cache_policy,
             }
         };
+        match &cached_response {
+            CachedResponse::FreshCache(..) => CacheAccess::Hit.record(),
+            CachedResponse::NotModified { .. } => CacheAccess::Revalidated.record(),
+            CachedResponse::ModifiedOrNew { .. } => CacheAccess::Miss.record(),
+        }
         match cached_response {
             CachedResponse::FreshCache(cached) => match Payload::from_aligned_bytes(cached.data) {
                 Ok(payload) => Ok(payload),
@@ -356,6 +382,7 @@ impl CachedClient {
         Callback: FnOnce(Response) -> CallbackReturn,
         CallbackReturn: Future<Output = Result<Payload, CallBackError>>,
     {
+        CacheAccess::Miss.record();
         let (response, cache_policy) = self.fresh_request(req).await?;
 
         let payload = self
@@ -472,6 +499,9 @@ impl CachedClient {
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                if let Some(http_trace) = self.0.http_trace() {
+                    http_trace.record_cache_hit(&req);
+                }
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
@@ -482,6 +512,9 @@ impl CachedClient {
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    if let Some(http_trace) = self.0.http_trace() {
+                        http_trace.record_cache_hit(&req);
+                    }
                     CachedResponse::FreshCache(cached)
                 }
             },
@@ -615,17 +648,16 @@ impl CachedClient {
     {
         let mut n_past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_parameters = self.uncached().retry_parameters(req.url());
+        let retry_policy = retry_parameters.backoff();
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
                 .get_cacheable(fresh_req, cache_entry, cache_control, &response_callback)
                 .await;
-            if result
-                .as_ref()
-                .err()
-                .is_some_and(|err| is_extended_transient_error(err))
-            {
+            if result.as_ref().err().is_some_and(|err| {
+                is_extended_transient_error(err) || retry_parameters.is_retryable_error(err)
+            }) {
                 let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                     debug!(
@@ -664,17 +696,16 @@ impl CachedClient {
     {
         let mut n_past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_parameters = self.uncached().retry_parameters(req.url());
+        let retry_policy = retry_parameters.backoff();
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
                 .skip_cache(fresh_req, cache_entry, &response_callback)
                 .await;
-            if result
-                .as_ref()
-                .err()
-                .is_some_and(|err| is_extended_transient_error(err))
-            {
+            if result.as_ref().err().is_some_and(|err| {
+                is_extended_transient_error(err) || retry_parameters.is_retryable_error(err)
+            }) {
                 let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                     debug!(
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+
+use http::{Extensions, Version};
+use reqwest::{ClientBuilder, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use tracing::{debug, trace};
+
+use uv_configuration::AddressFamily;
+
+/// The connection options for this process, set once at startup.
+static OPTIONS: OnceLock<ConnectionOptions> = OnceLock::new();
+
+/// The default maximum number of idle connections to keep open per host.
+const DEFAULT_POOL_SIZE: usize = 20;
+
+/// Options for the connections made by uv's HTTP clients, e.g., to tune performance against an
+/// artifact proxy.
+///
+/// Like the [`crate::NetworkLimits`], these options apply to every client in the process.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    /// Whether to negotiate HTTP/2 with hosts that support it, rather than use HTTP/1.1 only.
+    pub http2: Option<bool>,
+    /// The maximum number of idle connections to keep open per host.
+    pub pool_size: Option<usize>,
+    /// The time after which an idle connection is closed.
+    pub pool_idle_timeout: Option<Duration>,
+    /// The IP address families over which to connect.
+    pub address_family: AddressFamily,
+}
+
+impl ConnectionOptions {
+    /// Apply these options to every client created in this process.
+    ///
+    /// Has no effect if the options were already set.
+    pub fn init(self) {
+        if OPTIONS.set(self).is_err() {
+            debug!("Connection options were already initialized");
+        }
+    }
+
+    /// Return the options for this process.
+    pub(crate) fn global() -> Self {
+        OPTIONS.get().copied().unwrap_or_default()
+    }
+
+    /// Apply these options to the given [`ClientBuilder`].
+    pub(crate) fn apply(self, client_builder: ClientBuilder) -> ClientBuilder {
+        let client_builder =
+            client_builder.pool_max_idle_per_host(self.pool_size.unwrap_or(DEFAULT_POOL_SIZE));
+
+        let client_builder = match self.pool_idle_timeout {
+            Some(pool_idle_timeout) => client_builder.pool_idle_timeout(pool_idle_timeout),
+            None => client_builder,
+        };
+
+        let client_builder = if self.http2 == Some(false) {
+            client_builder.http1_only()
+        } else {
+            client_builder
+        };
+
+        // Binding to an unspecified local address of one family restricts connections to remote
+        // addresses of that family, rather than racing the two.
+        match self.address_family {
+            AddressFamily::Auto => client_builder,
+            AddressFamily::Ipv4 => client_builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            AddressFamily::Ipv6 => client_builder.local_address(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
+        }
+    }
+}
+
+/// The requests sent to a single host.
+#[derive(Debug, Default)]
+struct HostStats {
+    /// The number of requests sent to the host, including retries.
+    requests: usize,
+    /// The number of requests that failed without a response (e.g., due to a connection error).
+    failures: usize,
+    /// The number of responses received over HTTP/2.
+    http2: usize,
+    /// The number of responses received over HTTP/1.x.
+    http1: usize,
+    /// The total time spent waiting for response headers.
+    elapsed: Duration,
+    /// The longest time spent waiting for response headers.
+    slowest: Duration,
+}
+
+impl HostStats {
+    fn record(&mut self, result: &reqwest_middleware::Result<Response>, elapsed: Duration) {
+        self.requests += 1;
+        self.elapsed += elapsed;
+        self.slowest = self.slowest.max(elapsed);
+        match result {
+            Ok(response) if response.version() == Version::HTTP_2 => self.http2 += 1,
+            Ok(_) => self.http1 += 1,
+            Err(_) => self.failures += 1,
+        }
+    }
+}
+
+/// The requests sent to each host by a client and the clients that wrap it, which are logged once
+/// they've all been dropped (i.e., with `-vv`).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConnectionStats(Arc<StatsByHost>);
+
+impl ConnectionStats {
+    fn record(
+        &self,
+        host: String,
+        result: &reqwest_middleware::Result<Response>,
+        elapsed: Duration,
+    ) {
+        self.0
+            .hosts
+            .lock()
+            .unwrap()
+            .entry(host)
+            .or_default()
+            .record(result, elapsed);
+    }
+}
+
+#[derive(Debug, Default)]
+struct StatsByHost {
+    hosts: Mutex<BTreeMap<String, HostStats>>,
+}
+
+impl Drop for StatsByHost {
+    fn drop(&mut self) {
+        let stats = self.hosts.get_mut().unwrap_or_else(PoisonError::into_inner);
+        for (host, stats) in stats.iter() {
+            let mean = u32::try_from(stats.requests)
+                .ok()
+                .and_then(|requests| stats.elapsed.checked_div(requests))
+                .unwrap_or_default();
+            trace!(
+                "{host}: {} requests ({} over HTTP/2, {} over HTTP/1.x, {} failed); {:.0?} mean and {:.0?} max time to response",
+                stats.requests,
+                stats.http2,
+                stats.http1,
+                stats.failures,
+                mean,
+                stats.slowest,
+            );
+        }
+    }
+}
+
+/// A middleware that records the [`HostStats`] for each request in the [`ConnectionStats`].
+pub(crate) struct StatsMiddleware {
+    stats: ConnectionStats,
+}
+
+impl StatsMiddleware {
+    pub(crate) fn new(stats: ConnectionStats) -> Self {
+        Self { stats }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for StatsMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let host = req.url().host_str().unwrap_or_default().to_string();
+        let start = Instant::now();
+        let result = next.run(req, extensions).await;
+        self.stats.record(host, &result, start.elapsed());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::HostStats;
+
+    #[test]
+    fn record() {
+        let mut stats = HostStats::default();
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .version(http::Version::HTTP_2)
+                .body("")
+                .unwrap(),
+        );
+        stats.record(&Ok(response), Duration::from_millis(30));
+        let response = reqwest::Response::from(http::Response::new(""));
+        stats.record(&Ok(response), Duration::from_millis(10));
+
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.http2, 1);
+        assert_eq!(stats.http1, 1);
+        assert_eq!(stats.failures, 0);
+        assert_eq!(stats.elapsed, Duration::from_millis(40));
+        assert_eq!(stats.slowest, Duration::from_millis(30));
+    }
+}
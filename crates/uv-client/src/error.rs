@@ -38,6 +38,11 @@ impl Error {
         ErrorKind::BadHtml { source: err, url }.into()
     }
 
+    /// Create a new error from an XML parsing error.
+    pub(crate) fn from_xml_err(err: quick_xml::DeError, url: Url) -> Self {
+        ErrorKind::BadXml { source: err, url }.into()
+    }
+
     /// Returns `true` if this error corresponds to an offline error.
     pub(crate) fn is_offline(&self) -> bool {
         matches!(&*self.kind, ErrorKind::Offline(_))
@@ -51,6 +56,12 @@ impl Error {
         matches!(err.kind(), std::io::ErrorKind::NotFound)
     }
 
+    /// Returns `true` if the error indicates that the server is unavailable (i.e., a connection
+    /// error, a timeout, or a `5xx` response), rather than that the resource doesn't exist.
+    pub(crate) fn is_server_unavailable(&self) -> bool {
+        matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_server_unavailable())
+    }
+
     /// Returns `true` if the error is due to an SSL error.
     pub fn is_ssl(&self) -> bool {
         matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_ssl())
@@ -178,6 +189,32 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the local index")]
     FileNotFound(String),
 
+    /// The package is available from multiple indexes that aren't linked by their repository
+    /// metadata (PEP 708).
+    #[error("Package `{package}` is available from both `{first}` and `{second}`, but neither index tracks the other, nor do they list each other as alternate locations")]
+    UnlinkedIndexes {
+        package: String,
+        first: String,
+        second: String,
+    },
+
+    /// An index declares repository metadata that refers to a different project, or, in the case
+    /// of `tracks`, to the same project on the same index (PEP 708).
+    #[error("Index `{index}` declares invalid repository metadata for package `{package}`: `{url}` does not refer to the same project on another index")]
+    InvalidRepositoryMetadata {
+        package: String,
+        index: String,
+        url: String,
+    },
+
+    /// An index mirror doesn't declare that it tracks the index that it mirrors (PEP 708).
+    #[error("Mirror `{mirror}` does not declare that it tracks `{index}` for package `{package}`")]
+    UntrackedMirror {
+        package: String,
+        index: String,
+        mirror: String,
+    },
+
     /// The metadata file could not be parsed.
     #[error("Couldn't parse metadata of {0} from {1}")]
     MetadataParseError(
@@ -200,6 +237,16 @@ pub enum ErrorKind {
     #[error("Received some unexpected HTML from {url}")]
     BadHtml { source: html::Error, url: Url },
 
+    #[error("Received some unexpected XML from {url}")]
+    BadXml {
+        source: quick_xml::DeError,
+        url: Url,
+    },
+
+    /// The region of an `s3://` bucket couldn't be read from the environment or the AWS CLI.
+    #[error("Failed to determine the AWS region for `{0}`; set `AWS_REGION`, or configure a default region with `aws configure`")]
+    MissingBucketRegion(Url),
+
     #[error("Failed to read zip with range requests: `{0}`")]
     AsyncHttpRangeReader(Url, #[source] AsyncHttpRangeReaderError),
 
@@ -312,6 +359,19 @@ impl WrappedReqwestError {
         false
     }
 
+    /// Check if the error chain contains a `reqwest` error for a failed connection, a timeout, or
+    /// a server error.
+    fn is_server_unavailable(&self) -> bool {
+        if let Some(reqwest_err) = self.inner() {
+            if reqwest_err.is_connect() || reqwest_err.is_timeout() {
+                return true;
+            }
+        }
+        self.0
+            .status()
+            .is_some_and(|status| status.is_server_error())
+    }
+
     /// Check if the error chain contains a `reqwest` error that looks like this:
     /// * invalid peer certificate: `UnknownIssuer`
     fn is_ssl(&self) -> bool {
@@ -12,6 +12,7 @@ use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
 use uv_pypi_types::HashDigests;
 use uv_small_str::SmallString;
 
+use crate::bucket::Bucket;
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
 use crate::{Connectivity, Error, ErrorKind, OwnedArchive, RegistryClient};
@@ -87,8 +88,8 @@ impl FlatIndexEntries {
     }
 }
 
-/// A client for reading distributions from `--find-links` entries (either local directories or
-/// remote HTML indexes).
+/// A client for reading distributions from `--find-links` entries (either local directories,
+/// remote HTML indexes, or cloud storage buckets).
 #[derive(Debug, Clone)]
 pub struct FlatIndexClient<'a> {
     client: &'a RegistryClient,
@@ -117,10 +118,15 @@ impl<'a> FlatIndexClient<'a> {
                         Self::read_from_directory(&path, index)
                             .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
                     }
-                    IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
-                        .read_from_url(url, index)
-                        .await
-                        .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?,
+                    IndexUrl::Pypi(url) | IndexUrl::Url(url) => {
+                        let buckets = self.client.cached_client().uncached().buckets();
+                        let entries = match buckets.resolve(index.url()).await {
+                            Ok(Some(bucket)) => self.read_from_bucket(&bucket, index).await,
+                            Ok(None) => self.read_from_url(url, index).await,
+                            Err(err) => Err(err),
+                        };
+                        entries.map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?
+                    }
                 };
                 if entries.is_empty() {
                     warn!("No packages found in `--find-links` entry: {}", index);
@@ -184,7 +190,7 @@ impl<'a> FlatIndexClient<'a> {
                     .text()
                     .await
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                let SimpleHtml { base, files } = SimpleHtml::parse(&text, &url)
+                let SimpleHtml { base, files, .. } = SimpleHtml::parse(&text, &url)
                     .map_err(|err| Error::from_html_err(err, url.clone()))?;
 
                 // Convert to a reference-counted string.
@@ -243,6 +249,43 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Read a flat index from an `s3://` or `gs://` bucket in `--find-links`.
+    async fn read_from_bucket(
+        &self,
+        bucket: &Bucket,
+        flat_index: &IndexUrl,
+    ) -> Result<FlatIndexEntries, Error> {
+        let files = match bucket
+            .read_flat(self.client.uncached_client(bucket.endpoint()))
+            .await
+        {
+            Ok(files) => files,
+            Err(err) if err.is_offline() => return Ok(FlatIndexEntries::offline()),
+            Err(err) => return Err(err),
+        };
+
+        let base = SmallString::from(bucket.endpoint().as_str());
+        let entries = files
+            .into_iter()
+            .filter_map(|file| {
+                let file = match File::try_from(file, &base) {
+                    Ok(file) => file,
+                    Err(err) => {
+                        // Ignore files with unparsable version specifiers.
+                        warn!("Skipping file in {flat_index}: {err}");
+                        return None;
+                    }
+                };
+                Some(FlatIndexEntry {
+                    filename: DistFilename::try_from_normalized_filename(&file.filename)?,
+                    file,
+                    index: flat_index.clone(),
+                })
+            })
+            .collect();
+        Ok(FlatIndexEntries::from_entries(entries))
+    }
+
     /// Read a flat remote index from a `--find-links` directory.
     fn read_from_directory(
         path: &Path,
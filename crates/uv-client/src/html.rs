@@ -8,6 +8,9 @@ use url::Url;
 use uv_pep440::VersionSpecifiers;
 use uv_pypi_types::{BaseUrl, CoreMetadata, File, Hashes, Yanked};
 use uv_pypi_types::{HashError, LenientVersionSpecifiers};
+use uv_small_str::SmallString;
+
+use crate::RepositoryMetadata;
 
 /// A parsed structure from PyPI "HTML" index format for a single package.
 #[derive(Debug, Clone)]
@@ -16,6 +19,8 @@ pub(crate) struct SimpleHtml {
     pub(crate) base: BaseUrl,
     /// The list of [`File`]s available for download sorted by filename.
     pub(crate) files: Vec<File>,
+    /// The repository metadata declared by the page, as specified in PEP 708.
+    pub(crate) repository: RepositoryMetadata,
 }
 
 impl SimpleHtml {
@@ -39,6 +44,32 @@ impl SimpleHtml {
                 .unwrap_or_else(|| url.clone()),
         );
 
+        // Parse the `<meta>` tags that declare the repository metadata of the project (e.g.,
+        // `<meta name="pypi:tracks" content="https://pypi.org/simple/flask/">`), which may be
+        // repeated.
+        let mut repository = RepositoryMetadata::default();
+        for tag in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let attributes = tag.attributes();
+            let (Some(Some(name)), Some(Some(content))) =
+                (attributes.get("name"), attributes.get("content"))
+            else {
+                continue;
+            };
+            let content = SmallString::from(html_escape::decode_html_entities(
+                std::str::from_utf8(content.as_bytes())?,
+            ));
+            match name.as_bytes() {
+                b"pypi:tracks" => repository.tracks.push(content),
+                b"pypi:alternate-locations" => repository.alternate_locations.push(content),
+                _ => {}
+            }
+        }
+
         // Parse each `<a>` tag, to extract the filename, hash, and URL.
         let mut files: Vec<File> = dom
             .nodes()
@@ -62,7 +93,11 @@ impl SimpleHtml {
         // probably be the thing that does the sorting.)
         files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
 
-        Ok(Self { base, files })
+        Ok(Self {
+            base,
+            files,
+            repository,
+        })
     }
 
     /// Parse the `href` from a `<base>` tag.
@@ -317,6 +352,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -375,6 +414,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -436,6 +479,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -494,6 +541,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -552,6 +603,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -608,6 +663,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -664,6 +723,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -702,6 +765,10 @@ mod tests {
                 },
             ),
             files: [],
+            repository: RepositoryMetadata {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "###);
     }
@@ -740,6 +807,10 @@ mod tests {
                 },
             ),
             files: [],
+            repository: RepositoryMetadata {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "###);
     }
@@ -796,6 +867,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -852,6 +927,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -909,6 +988,10 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                repository: RepositoryMetadata {
+                    tracks: [],
+                    alternate_locations: [],
+                },
             },
         )
         "###);
@@ -967,6 +1050,10 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                repository: RepositoryMetadata {
+                    tracks: [],
+                    alternate_locations: [],
+                },
             },
         )
         "###);
@@ -1059,6 +1146,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -1177,6 +1268,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -1245,6 +1340,10 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
@@ -1394,7 +1493,42 @@ mod tests {
                 yanked: None,
             },
         ],
+        repository: RepositoryMetadata {
+            tracks: [],
+            alternate_locations: [],
+        },
     }
     "###);
     }
+
+    #[test]
+    fn parse_repository_metadata() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta name="pypi:repository-version" content="1.2">
+<meta name="pypi:tracks" content="https://pypi.org/simple/jinja2/">
+<meta name="pypi:alternate-locations" content="https://download.pytorch.org/whl/jinja2/">
+<meta name="pypi:alternate-locations" content="https://pypi.org/simple/jinja2/">
+</head>
+<body>
+<h1>Links for jinja2</h1>
+</body>
+</html>
+    "#;
+        let base = Url::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleHtml::parse(text, &base).unwrap();
+        insta::assert_debug_snapshot!(result.repository, @r###"
+        RepositoryMetadata {
+            tracks: [
+                "https://pypi.org/simple/jinja2/",
+            ],
+            alternate_locations: [
+                "https://download.pytorch.org/whl/jinja2/",
+                "https://pypi.org/simple/jinja2/",
+            ],
+        }
+        "###);
+    }
 }
@@ -1,26 +1,43 @@
 pub use base_client::{
     is_extended_transient_error, AuthIntegration, BaseClient, BaseClientBuilder,
-    UvRetryableStrategy, DEFAULT_RETRIES,
+    UvRetryableStrategy,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
+pub use connection::ConnectionOptions;
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
+pub use http_trace::init_http_trace;
+pub use limits::NetworkLimits;
 pub use linehaul::LineHaul;
+pub use proxy::ProxyConfig;
 pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
-    VersionFiles,
+    VersionFiles, DEFAULT_RANGE_REQUEST_SIZE,
 };
+pub use repository_metadata::RepositoryMetadata;
+pub use retry::{HttpRetryPolicy, RetryParameters, DEFAULT_RETRIES};
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use throttle::warn_on_throttling;
+pub use tls::TlsConfig;
 
 mod base_client;
+mod bucket;
 mod cached_client;
+mod connection;
 mod error;
 mod flat_index;
 mod html;
+mod http_trace;
 mod httpcache;
+mod limits;
 mod linehaul;
+mod local_index;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
+mod repository_metadata;
+mod retry;
 mod rkyvutil;
+mod throttle;
 mod tls;
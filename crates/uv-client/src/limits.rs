@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use http::Extensions;
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+use url::Url;
+
+use uv_configuration::Bandwidth;
+
+/// The network limits for this process, set once at startup.
+static LIMITS: OnceLock<NetworkLimits> = OnceLock::new();
+
+/// The bandwidth limit, shared by all clients in the process.
+static BANDWIDTH: OnceLock<Option<RateLimiter>> = OnceLock::new();
+
+/// Limits on the network resources used by uv, e.g., to run politely on a shared CI runner or a
+/// constrained link.
+///
+/// Unlike most client settings, these limits apply to every client in the process. Concurrent
+/// requests to the same host share a single limit across a client and the clients that wrap it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct NetworkLimits {
+    /// The maximum number of concurrent requests to a single host, including the time spent
+    /// streaming the response body.
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The maximum combined download rate across all requests.
+    pub bandwidth: Option<Bandwidth>,
+    /// The timeout for establishing a connection.
+    pub connect_timeout: Option<Duration>,
+    /// The timeout for reading from a connection, if not set by `UV_HTTP_TIMEOUT`.
+    pub read_timeout: Option<Duration>,
+}
+
+impl NetworkLimits {
+    /// Apply these limits to every client created in this process.
+    ///
+    /// Has no effect if the limits were already set.
+    pub fn init(self) {
+        if LIMITS.set(self).is_err() {
+            debug!("Network limits were already initialized");
+        }
+    }
+
+    /// Return the limits for this process.
+    pub(crate) fn global() -> Self {
+        LIMITS.get().copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if requests need to pass through the [`LimitsMiddleware`].
+    pub(crate) fn is_limited(&self) -> bool {
+        self.concurrent_downloads_per_host.is_some() || self.bandwidth.is_some()
+    }
+}
+
+/// A token bucket that limits the combined rate of all response bodies.
+#[derive(Debug)]
+struct RateLimiter {
+    bytes_per_second: f64,
+    /// The time at which the next chunk may be released.
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// The maximum burst, i.e., how far ahead of the limit a chunk may be released.
+    const BURST: Duration = Duration::from_millis(250);
+
+    fn new(bandwidth: Bandwidth) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let bytes_per_second = bandwidth.bytes_per_second() as f64;
+        Self {
+            bytes_per_second,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Return the global [`RateLimiter`], if a bandwidth limit is configured.
+    fn global() -> Option<&'static Self> {
+        BANDWIDTH
+            .get_or_init(|| NetworkLimits::global().bandwidth.map(Self::new))
+            .as_ref()
+    }
+
+    /// Reserve the given number of bytes, returning the time to wait before releasing them.
+    fn reserve(&self, bytes: usize, now: Instant) -> Duration {
+        #[allow(clippy::cast_precision_loss)]
+        let cost = Duration::from_secs_f64(bytes as f64 / self.bytes_per_second);
+        let mut next = self.next.lock().unwrap();
+        let start = (*next).max(now.checked_sub(Self::BURST).unwrap_or(now));
+        *next = start + cost;
+        next.saturating_duration_since(now + Self::BURST)
+    }
+
+    /// Wait until the given number of bytes may be released.
+    async fn acquire(&self, bytes: usize) {
+        let delay = self.reserve(bytes, Instant::now());
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// The concurrency limit for each host, shared by a client and the clients that wrap it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HostPermits(Arc<Mutex<BTreeMap<String, Arc<Semaphore>>>>);
+
+impl HostPermits {
+    /// Return the concurrency limit for the host of the given URL.
+    fn semaphore(&self, url: &Url, limit: NonZeroUsize) -> Option<Arc<Semaphore>> {
+        let host = url.host_str()?;
+        let mut hosts = self.0.lock().unwrap();
+        Some(
+            hosts
+                .entry(host.to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(limit.get())))
+                .clone(),
+        )
+    }
+}
+
+/// A middleware that enforces the [`NetworkLimits`], holding each request's host permit and
+/// throttling its body until the body has been read or dropped.
+pub(crate) struct LimitsMiddleware {
+    limits: NetworkLimits,
+    permits: HostPermits,
+}
+
+impl LimitsMiddleware {
+    pub(crate) fn new(limits: NetworkLimits, permits: HostPermits) -> Self {
+        Self { limits, permits }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for LimitsMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let permit = match self
+            .limits
+            .concurrent_downloads_per_host
+            .and_then(|limit| self.permits.semaphore(req.url(), limit))
+        {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("host semaphores are never closed"),
+            ),
+            None => None,
+        };
+
+        let response = next.run(req, extensions).await?;
+        Ok(limit_body(response, permit, RateLimiter::global()))
+    }
+}
+
+/// Wrap the body of the given response, such that it's released no faster than the bandwidth
+/// limit, and the host permit is held until the body is dropped.
+fn limit_body(
+    response: Response,
+    permit: Option<OwnedSemaphorePermit>,
+    limiter: Option<&'static RateLimiter>,
+) -> Response {
+    let mut builder = http::Response::builder()
+        .status(response.status())
+        .version(response.version());
+    if let Some(headers) = builder.headers_mut() {
+        headers.clone_from(response.headers());
+    }
+    if let Some(extensions) = builder.extensions_mut() {
+        extensions.clone_from(response.extensions());
+    }
+    let builder = builder.url(response.url().clone());
+
+    let body = response.bytes_stream().then(move |chunk| {
+        // Hold the permit until the body is dropped.
+        let _ = &permit;
+        async move {
+            if let (Ok(bytes), Some(limiter)) = (&chunk, limiter) {
+                limiter.acquire(bytes.len()).await;
+            }
+            chunk
+        }
+    });
+
+    Response::from(
+        builder
+            .body(reqwest::Body::wrap_stream(body))
+            .expect("the response parts are valid"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use uv_configuration::Bandwidth;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn rate_limiter() {
+        let limiter = RateLimiter::new(Bandwidth::from_bytes_per_second(1000).unwrap());
+        let now = Instant::now();
+        *limiter.next.lock().unwrap() = now;
+
+        // Up to the burst is released immediately.
+        assert_eq!(limiter.reserve(250, now), Duration::ZERO);
+        // Beyond that, chunks are delayed in proportion to their size.
+        assert_eq!(limiter.reserve(500, now), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(1000, now), Duration::from_millis(1500));
+        // Once the reserved time has passed, chunks are released immediately again.
+        let later = now + Duration::from_secs(10);
+        assert_eq!(limiter.reserve(250, later), Duration::ZERO);
+    }
+}
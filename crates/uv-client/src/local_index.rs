@@ -0,0 +1,231 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_distribution_filename::DistFilename;
+use uv_normalize::PackageName;
+use uv_pypi_types::{File, HashAlgorithm, Hashes};
+use uv_small_str::SmallString;
+
+/// The hash algorithms recognized in sidecar files (e.g., `flask-3.0.0-py3-none-any.whl.sha256`)
+/// and checksum manifests (e.g., `SHA256SUMS`), along with the length of their hex digests.
+pub(crate) const ALGORITHMS: [(HashAlgorithm, usize); 3] = [
+    (HashAlgorithm::Sha256, 64),
+    (HashAlgorithm::Sha384, 96),
+    (HashAlgorithm::Sha512, 128),
+];
+
+/// List the distributions for a package in a directory of a local index, in lieu of an
+/// `index.html`.
+///
+/// Hashes are read from a sidecar file alongside each distribution (e.g.,
+/// `flask-3.0.0-py3-none-any.whl.sha256`), or from a checksum manifest in the directory, as
+/// produced by (e.g.) `sha256sum *.whl > SHA256SUMS`.
+pub(crate) fn read_directory(
+    directory: &Path,
+    package_name: &PackageName,
+) -> Result<Vec<File>, std::io::Error> {
+    let manifest = Manifest::read(directory)?;
+
+    let mut files = Vec::new();
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        // Follow symlinks, to support directories assembled from links to a shared store.
+        let Ok(metadata) = fs_err::metadata(&path) else {
+            warn!(
+                "Skipping unreadable entry in local index: {}",
+                path.display()
+            );
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        if DistFilename::try_from_filename(&filename, package_name).is_none() {
+            continue;
+        }
+        let Ok(url) = Url::from_file_path(&path) else {
+            continue;
+        };
+
+        let mut hashes = manifest.hashes(&filename);
+        read_sidecars(&path, &mut hashes)?;
+
+        files.push(File {
+            core_metadata: None,
+            dist_info_metadata: None,
+            data_dist_info_metadata: None,
+            filename: SmallString::from(filename),
+            hashes,
+            requires_python: None,
+            size: Some(metadata.len()),
+            upload_time: None,
+            url: SmallString::from(url.as_str()),
+            yanked: None,
+        });
+    }
+    Ok(files)
+}
+
+/// Fill in the hashes for any files in a local index that don't include them (e.g., links in an
+/// `index.html` without a hash fragment) from their sidecar files or checksum manifests.
+pub(crate) fn read_missing_hashes(files: &mut [File], base: &Url) -> Result<(), std::io::Error> {
+    let mut manifests = BTreeMap::<PathBuf, Manifest>::new();
+    for file in files {
+        if file.hashes != Hashes::default() {
+            continue;
+        }
+        let Some(path) = base
+            .join(&file.url)
+            .ok()
+            .filter(|url| url.scheme() == "file")
+            .and_then(|url| url.to_file_path().ok())
+        else {
+            continue;
+        };
+        if let (Some(directory), Some(filename)) = (path.parent(), path.file_name()) {
+            let manifest = match manifests.entry(directory.to_path_buf()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(Manifest::read(directory)?),
+            };
+            file.hashes = manifest.hashes(&filename.to_string_lossy());
+        }
+        read_sidecars(&path, &mut file.hashes)?;
+    }
+    Ok(())
+}
+
+/// Read the hashes for the distribution at the given path from its sidecar files, if any.
+fn read_sidecars(path: &Path, hashes: &mut Hashes) -> Result<(), std::io::Error> {
+    for (algorithm, len) in ALGORITHMS {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(format!(".{algorithm}"));
+        let contents = match fs_err::read_to_string(Path::new(&sidecar)) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        match parse_sidecar(&contents, len) {
+            Some(digest) => set_digest(hashes, algorithm, digest),
+            None => warn!(
+                "Ignoring invalid {algorithm} digest in: {}",
+                Path::new(&sidecar).display()
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Parse the digest in a sidecar file, which may contain either a bare digest, or the output of
+/// (e.g.) `sha256sum`.
+pub(crate) fn parse_sidecar(contents: &str, len: usize) -> Option<SmallString> {
+    parse_digest(contents.split_whitespace().next().unwrap_or_default(), len)
+}
+
+/// The digests recorded in the checksum manifests of a directory, by filename.
+#[derive(Debug, Default)]
+pub(crate) struct Manifest(BTreeMap<String, Hashes>);
+
+impl Manifest {
+    /// Read any checksum manifests (e.g., `SHA256SUMS`) in the given directory.
+    fn read(directory: &Path) -> Result<Self, std::io::Error> {
+        let mut manifest = Self::default();
+        for (algorithm, len) in ALGORITHMS {
+            let path = directory.join(format!("{}SUMS", algorithm.to_string().to_uppercase()));
+            let contents = match fs_err::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            debug!("Reading checksums from: {}", path.display());
+            manifest.parse(&contents, algorithm, len);
+        }
+        Ok(manifest)
+    }
+
+    /// Parse the lines of a checksum manifest, in the format produced by `sha256sum` (i.e., a
+    /// digest, followed by a space and either a space or an asterisk, followed by the filename).
+    pub(crate) fn parse(&mut self, contents: &str, algorithm: HashAlgorithm, len: usize) {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((digest, filename)) = line.split_once(char::is_whitespace) else {
+                continue;
+            };
+            let filename = filename.trim_start().trim_start_matches('*');
+            let Some(digest) = parse_digest(digest, len) else {
+                warn!("Ignoring invalid {algorithm} digest for: {filename}");
+                continue;
+            };
+            set_digest(
+                self.0.entry(filename.to_string()).or_default(),
+                algorithm,
+                digest,
+            );
+        }
+    }
+
+    /// Return the hashes recorded for the given filename.
+    pub(crate) fn hashes(&self, filename: &str) -> Hashes {
+        self.0.get(filename).cloned().unwrap_or_default()
+    }
+}
+
+/// Parse a hex digest of the given length, normalizing it to lowercase.
+fn parse_digest(digest: &str, len: usize) -> Option<SmallString> {
+    (digest.len() == len && digest.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| SmallString::from(digest.to_ascii_lowercase()))
+}
+
+pub(crate) fn set_digest(hashes: &mut Hashes, algorithm: HashAlgorithm, digest: SmallString) {
+    match algorithm {
+        HashAlgorithm::Md5 => hashes.md5 = Some(digest),
+        HashAlgorithm::Sha256 => hashes.sha256 = Some(digest),
+        HashAlgorithm::Sha384 => hashes.sha384 = Some(digest),
+        HashAlgorithm::Sha512 => hashes.sha512 = Some(digest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_pypi_types::HashAlgorithm;
+
+    use super::Manifest;
+
+    #[test]
+    fn manifest() {
+        let sha256 = "a".repeat(64);
+        let mut manifest = Manifest::default();
+        manifest.parse(
+            &format!(
+                "# Generated by sha256sum\n{sha256}  flask-3.0.0-py3-none-any.whl\n{}  *flask-3.0.0.tar.gz\ninvalid  flask-2.0.0.tar.gz\n",
+                sha256.to_uppercase()
+            ),
+            HashAlgorithm::Sha256,
+            64,
+        );
+        assert_eq!(
+            manifest
+                .hashes("flask-3.0.0-py3-none-any.whl")
+                .sha256
+                .as_deref(),
+            Some(sha256.as_str())
+        );
+        assert_eq!(
+            manifest.hashes("flask-3.0.0.tar.gz").sha256.as_deref(),
+            Some(sha256.as_str())
+        );
+        assert_eq!(manifest.hashes("flask-2.0.0.tar.gz").sha256, None);
+    }
+}
@@ -1,10 +1,14 @@
-use http::Extensions;
+use http::{header, Extensions, Method, StatusCode};
 use std::fmt::Debug;
 
-use reqwest::{Request, Response};
+use reqwest::{redirect, Request, Response};
 use reqwest_middleware::{Middleware, Next};
+use tracing::debug;
 use url::Url;
 
+/// The maximum number of redirects to follow for a single request.
+const MAX_REDIRECTS: usize = 10;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -45,3 +49,149 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// Return the redirect policy for uv's HTTP clients.
+///
+/// Redirects within an origin are followed by the HTTP client itself, which retains any
+/// credentials on the request. Redirects to another origin are returned to the
+/// [`RedirectMiddleware`] instead.
+pub(crate) fn redirect_policy() -> redirect::Policy {
+    redirect::Policy::custom(|attempt| {
+        if attempt.previous().len() > MAX_REDIRECTS {
+            attempt.error("too many redirects")
+        } else if attempt
+            .previous()
+            .last()
+            .is_some_and(|previous| previous.origin() != attempt.url().origin())
+        {
+            attempt.stop()
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// A middleware that follows redirects to another origin.
+///
+/// Rather than forwarding the credentials for the original origin, the redirected request is sent
+/// through the remaining middleware, such that the credentials for the new origin are resolved
+/// from the URL, the credentials cache, the netrc file, or the keyring.
+pub(crate) struct RedirectMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RedirectMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut request = req;
+        for _ in 0..MAX_REDIRECTS {
+            let retry_request = request.try_clone();
+            let response = next.clone().run(request, extensions).await?;
+            let Some(redirect) =
+                retry_request.and_then(|retry_request| redirect_request(retry_request, &response))
+            else {
+                return Ok(response);
+            };
+            debug!(
+                "Following redirect from {} to {}",
+                response.url(),
+                redirect.url()
+            );
+            request = redirect;
+        }
+        next.run(request, extensions).await
+    }
+}
+
+/// Return the request with which to follow a redirect to another origin, if the response is a
+/// redirect.
+fn redirect_request(mut request: Request, response: &Response) -> Option<Request> {
+    let location = response.headers().get(header::LOCATION)?.to_str().ok()?;
+    let url = response.url().join(location).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+
+    match response.status() {
+        // As in browsers, follow these redirects with a `GET` request for anything but a `HEAD`.
+        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND | StatusCode::SEE_OTHER => {
+            if request.method() != Method::HEAD {
+                *request.method_mut() = Method::GET;
+                *request.body_mut() = None;
+            }
+        }
+        StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT => {}
+        _ => return None,
+    }
+
+    // Never forward credentials to another origin.
+    if url.origin() != request.url().origin() {
+        request.headers_mut().remove(header::AUTHORIZATION);
+        request.headers_mut().remove(header::COOKIE);
+    }
+    *request.url_mut() = url;
+    Some(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{header, Method, StatusCode};
+    use reqwest::{Request, Response};
+    use url::Url;
+
+    use super::redirect_request;
+
+    fn redirect(status: StatusCode, location: &str) -> Response {
+        let response = http::Response::builder()
+            .status(status)
+            .header(header::LOCATION, location)
+            .body("")
+            .unwrap();
+        let mut response = Response::from(response);
+        *response.url_mut() = Url::parse("https://links.example.com/wheels/").unwrap();
+        response
+    }
+
+    #[test]
+    fn cross_origin() {
+        let mut request = Request::new(
+            Method::GET,
+            Url::parse("https://links.example.com/wheels/").unwrap(),
+        );
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_static("Basic dXNlcjpwYXNz"),
+        );
+
+        // The credentials for the original origin are dropped.
+        let redirected = redirect_request(
+            request.try_clone().unwrap(),
+            &redirect(StatusCode::FOUND, "https://cdn.example.com/wheels/"),
+        )
+        .unwrap();
+        assert_eq!(redirected.url().as_str(), "https://cdn.example.com/wheels/");
+        assert!(!redirected.headers().contains_key(header::AUTHORIZATION));
+
+        // Relative locations are resolved against the response URL.
+        let redirected = redirect_request(
+            request.try_clone().unwrap(),
+            &redirect(StatusCode::TEMPORARY_REDIRECT, "/simple/"),
+        )
+        .unwrap();
+        assert_eq!(
+            redirected.url().as_str(),
+            "https://links.example.com/simple/"
+        );
+        assert!(redirected.headers().contains_key(header::AUTHORIZATION));
+
+        // Other responses aren't followed.
+        assert!(redirect_request(
+            request,
+            &redirect(StatusCode::NOT_MODIFIED, "https://cdn.example.com/wheels/"),
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,256 @@
+use std::env;
+use std::net::IpAddr;
+
+use tracing::debug;
+use url::Url;
+
+use uv_configuration::TrustedHost;
+use uv_distribution_types::{Index, IndexProxy};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// The proxies to use for HTTP requests.
+///
+/// Resolved once from the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY`, and `NO_PROXY` environment
+/// variables (in either case), along with any per-index overrides, such that every client (and
+/// any Git operations) routes a given URL through the same proxy.
+#[derive(Debug, Clone, Default)]
+pub struct ProxyConfig {
+    /// The proxy for `http` requests.
+    http: Option<Url>,
+    /// The proxy for `https` requests.
+    https: Option<Url>,
+    /// The proxy for requests of any scheme, used as a fallback.
+    all: Option<Url>,
+    /// The hosts that should be reached directly, bypassing the proxies above.
+    no_proxy: Vec<NoProxy>,
+    /// Overrides for requests to specific index hosts, in order of precedence.
+    hosts: Vec<(TrustedHost, IndexProxy)>,
+}
+
+impl ProxyConfig {
+    /// Read the proxy configuration from the environment.
+    pub fn from_env() -> Self {
+        Self {
+            http: read_proxy(EnvVars::HTTP_PROXY),
+            https: read_proxy(EnvVars::HTTPS_PROXY),
+            all: read_proxy(EnvVars::ALL_PROXY),
+            no_proxy: read_var(EnvVars::NO_PROXY)
+                .map(|value| NoProxy::parse_list(&value))
+                .unwrap_or_default(),
+            hosts: Vec::new(),
+        }
+    }
+
+    /// Apply the proxy overrides from the given indexes to requests to each index's host.
+    #[must_use]
+    pub fn with_indexes<'a>(mut self, indexes: impl IntoIterator<Item = &'a Index>) -> Self {
+        for index in indexes {
+            let Some(proxy) = index.proxy.as_ref() else {
+                continue;
+            };
+            let Some(host) = TrustedHost::from_url(index.raw_url()) else {
+                continue;
+            };
+            self.hosts.push((host, proxy.clone()));
+        }
+        self
+    }
+
+    /// Returns `true` if no proxy is configured.
+    pub fn is_empty(&self) -> bool {
+        self.http.is_none() && self.https.is_none() && self.all.is_none() && self.hosts.is_empty()
+    }
+
+    /// Return the proxy to use for requests to the given URL, or `None` if the URL should be
+    /// reached directly.
+    pub fn proxy(&self, url: &Url) -> Option<&Url> {
+        if let Some((_, proxy)) = self.hosts.iter().find(|(host, _)| host.matches(url)) {
+            return proxy.url();
+        }
+
+        if let Some(host) = url.host_str() {
+            if self.no_proxy.iter().any(|no_proxy| no_proxy.matches(host)) {
+                return None;
+            }
+        }
+
+        match url.scheme() {
+            "https" => self.https.as_ref().or(self.all.as_ref()),
+            "http" => self.http.as_ref().or(self.all.as_ref()),
+            _ => self.all.as_ref(),
+        }
+    }
+}
+
+/// Read an environment variable, falling back to its lowercase variant.
+fn read_var(name: &str) -> Option<String> {
+    env::var(name)
+        .or_else(|_| env::var(name.to_ascii_lowercase()))
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Read a proxy URL from the environment, assuming `http://` if no scheme is given.
+fn read_proxy(name: &str) -> Option<Url> {
+    let value = read_var(name)?;
+    let value = value.trim();
+    let parsed = if value.contains("://") {
+        Url::parse(value)
+    } else {
+        Url::parse(&format!("http://{value}"))
+    };
+    match parsed {
+        Ok(url) => {
+            debug!("Using proxy from `{name}`");
+            Some(url)
+        }
+        Err(err) => {
+            warn_user_once!("Ignoring invalid `{name}`: {err}");
+            None
+        }
+    }
+}
+
+/// An entry in `NO_PROXY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NoProxy {
+    /// `*`, matching every host.
+    Wildcard,
+    /// An IP address or network, in CIDR notation (e.g., `10.0.0.0/8`).
+    Network { address: IpAddr, prefix: u8 },
+    /// A domain, matching itself and all subdomains (e.g., `example.com` or `.example.com`).
+    Domain(String),
+}
+
+impl NoProxy {
+    /// Parse a comma-separated list of `NO_PROXY` entries.
+    fn parse_list(value: &str) -> Vec<Self> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    fn parse(entry: &str) -> Self {
+        if entry == "*" {
+            return Self::Wildcard;
+        }
+
+        let (address, prefix) = match entry.split_once('/') {
+            Some((address, prefix)) => (address, prefix.parse::<u8>().ok()),
+            None => (entry, None),
+        };
+        let address = address.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(address) = address.parse::<IpAddr>() {
+            let max = if address.is_ipv4() { 32 } else { 128 };
+            return Self::Network {
+                address,
+                prefix: prefix.unwrap_or(max).min(max),
+            };
+        }
+
+        // Ignore any port, and any leading wildcard or dot.
+        let domain = entry.split(':').next().unwrap_or(entry);
+        let domain = domain.trim_start_matches("*.").trim_start_matches('.');
+        Self::Domain(domain.to_ascii_lowercase())
+    }
+
+    /// Returns `true` if the given host should bypass the proxy.
+    fn matches(&self, host: &str) -> bool {
+        match self {
+            Self::Wildcard => true,
+            Self::Network { address, prefix } => {
+                let host = host.trim_start_matches('[').trim_end_matches(']');
+                let Ok(host) = host.parse::<IpAddr>() else {
+                    return false;
+                };
+                match (address, host) {
+                    (IpAddr::V4(address), IpAddr::V4(host)) => {
+                        let mask = u32::MAX.checked_shl(32 - u32::from(*prefix)).unwrap_or(0);
+                        u32::from(*address) & mask == u32::from(host) & mask
+                    }
+                    (IpAddr::V6(address), IpAddr::V6(host)) => {
+                        let mask = u128::MAX.checked_shl(128 - u32::from(*prefix)).unwrap_or(0);
+                        u128::from(*address) & mask == u128::from(host) & mask
+                    }
+                    _ => false,
+                }
+            }
+            Self::Domain(domain) => {
+                let host = host.to_ascii_lowercase();
+                host == *domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use uv_distribution_types::{Index, IndexProxy};
+
+    use super::{NoProxy, ProxyConfig};
+
+    #[test]
+    fn no_proxy() {
+        let no_proxy = NoProxy::parse_list("localhost, .internal.example.com,10.0.0.0/8,::1");
+        let matches = |host: &str| no_proxy.iter().any(|entry| entry.matches(host));
+        assert!(matches("localhost"));
+        assert!(matches("internal.example.com"));
+        assert!(matches("pypi.internal.example.com"));
+        assert!(!matches("notinternal.example.com"));
+        assert!(matches("10.1.2.3"));
+        assert!(!matches("11.1.2.3"));
+        assert!(matches("[::1]"));
+        assert!(!matches("pypi.org"));
+
+        let no_proxy = NoProxy::parse_list("*");
+        assert!(no_proxy.iter().any(|entry| entry.matches("pypi.org")));
+    }
+
+    #[test]
+    fn proxy() {
+        let mut internal = Index::from_str("https://pypi.internal.example.com/simple").unwrap();
+        internal.proxy = Some(IndexProxy::Direct);
+        let mut mirror = Index::from_str("https://mirror.example.com/simple").unwrap();
+        mirror.proxy = Some(IndexProxy::Url(
+            Url::parse("http://mirror-proxy.example.com:3128").unwrap(),
+        ));
+
+        let config = ProxyConfig {
+            https: Some(Url::parse("http://proxy.example.com:3128").unwrap()),
+            no_proxy: NoProxy::parse_list("localhost"),
+            ..ProxyConfig::default()
+        }
+        .with_indexes([&internal, &mirror]);
+
+        let proxy = |url: &str| {
+            config
+                .proxy(&Url::parse(url).unwrap())
+                .map(ToString::to_string)
+        };
+        assert_eq!(
+            proxy("https://pypi.org/simple/flask/").as_deref(),
+            Some("http://proxy.example.com:3128/")
+        );
+        assert_eq!(
+            proxy("https://pypi.internal.example.com/files/flask.whl"),
+            None
+        );
+        assert_eq!(
+            proxy("https://mirror.example.com/simple/flask/").as_deref(),
+            Some("http://mirror-proxy.example.com:3128/")
+        );
+        assert_eq!(proxy("https://localhost:8080/simple/"), None);
+        assert_eq!(proxy("http://pypi.org/simple/"), None);
+    }
+}
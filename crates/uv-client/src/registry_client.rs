@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::num::NonZeroU64;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -10,23 +11,29 @@ use http::HeaderMap;
 use itertools::Either;
 use reqwest::{Client, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
+use serde::{Deserialize, Serialize};
 use tokio::sync::Semaphore;
-use tracing::{info_span, instrument, trace, warn, Instrument};
+use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware};
+use crate::bucket::Bucket;
 use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
+use crate::local_index;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
+use crate::repository_metadata::{self, RepositoryMetadata};
+use crate::retry::HttpRetryPolicy;
 use crate::rkyvutil::OwnedArchive;
 use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind};
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{IndexStrategy, TrustedHost};
+use uv_configuration::{IndexStrategy, IndexTracking, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
     BuiltDist, File, FileLocation, Index, IndexCapabilities, IndexUrl, IndexUrls, Name,
 };
+use uv_fs::write_atomic;
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -34,12 +41,15 @@ use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
 use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 use uv_small_str::SmallString;
+use uv_warnings::warn_user_once;
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
 pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
+    index_tracking: IndexTracking,
+    range_request_size: NonZeroU64,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -49,6 +59,8 @@ impl RegistryClientBuilder<'_> {
         Self {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
+            index_tracking: IndexTracking::default(),
+            range_request_size: DEFAULT_RANGE_REQUEST_SIZE,
             cache,
             base_client_builder: BaseClientBuilder::new(),
         }
@@ -68,6 +80,20 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn index_tracking(mut self, index_tracking: IndexTracking) -> Self {
+        self.index_tracking = index_tracking;
+        self
+    }
+
+    /// Set the number of bytes to fetch from the end of a remote wheel when reading its metadata
+    /// via range requests.
+    #[must_use]
+    pub fn range_request_size(mut self, range_request_size: NonZeroU64) -> Self {
+        self.range_request_size = range_request_size;
+        self
+    }
+
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
         self.base_client_builder = self.base_client_builder.keyring(keyring_type);
@@ -88,6 +114,12 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn http_retries(mut self, retry_policy: HttpRetryPolicy) -> Self {
+        self.base_client_builder = self.base_client_builder.http_retries(retry_policy);
+        self
+    }
+
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
         self.base_client_builder = self.base_client_builder.retries(retries);
@@ -131,8 +163,10 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 
     pub fn build(self) -> RegistryClient {
-        // Build a base client
-        let builder = self.base_client_builder;
+        // Build a base client, applying any per-index proxy settings.
+        let builder = self
+            .base_client_builder
+            .indexes(self.index_urls.defined_indexes().cloned().collect());
 
         let client = builder.build();
 
@@ -145,10 +179,12 @@ impl<'a> RegistryClientBuilder<'a> {
         RegistryClient {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
+            index_tracking: self.index_tracking,
             cache: self.cache,
             connectivity,
             client,
             timeout,
+            range_request_size: self.range_request_size.get(),
         }
     }
 
@@ -166,10 +202,12 @@ impl<'a> RegistryClientBuilder<'a> {
         RegistryClient {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
+            index_tracking: self.index_tracking,
             cache: self.cache,
             connectivity,
             client,
             timeout,
+            range_request_size: self.range_request_size.get(),
         }
     }
 }
@@ -181,12 +219,18 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
         Ok(Self {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
+            index_tracking: IndexTracking::default(),
+            range_request_size: DEFAULT_RANGE_REQUEST_SIZE,
             cache: Cache::temp()?,
             base_client_builder: value,
         })
     }
 }
 
+/// The default number of bytes to fetch from the end of a remote wheel when reading its metadata
+/// via range requests, as a best guess for the size of the central directory.
+pub const DEFAULT_RANGE_REQUEST_SIZE: NonZeroU64 = NonZeroU64::new(16384).unwrap();
+
 /// A client for fetching packages from a `PyPI`-compatible index.
 #[derive(Debug, Clone)]
 pub struct RegistryClient {
@@ -194,6 +238,8 @@ pub struct RegistryClient {
     index_urls: IndexUrls,
     /// The strategy to use when fetching across multiple indexes.
     index_strategy: IndexStrategy,
+    /// How to validate the repository metadata of indexes (PEP 708).
+    index_tracking: IndexTracking,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -202,6 +248,9 @@ pub struct RegistryClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
+    /// The number of bytes to fetch from the end of a remote wheel when reading its metadata via
+    /// range requests.
+    range_request_size: u64,
 }
 
 impl RegistryClient {
@@ -220,6 +269,16 @@ impl RegistryClient {
         self.client.uncached().disable_ssl(url)
     }
 
+    /// Return the proxy to use for requests to the given URL, if any.
+    pub fn proxy(&self, url: &Url) -> Option<&Url> {
+        self.client.uncached().proxy(url)
+    }
+
+    /// Return the keyring provider used by this client.
+    pub fn keyring(&self) -> KeyringProviderType {
+        self.client.uncached().keyring()
+    }
+
     /// Return the [`Connectivity`] mode used by this client.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -303,6 +362,31 @@ impl RegistryClient {
             };
         }
 
+        // Validate the repository metadata that each index declares and, if the package is
+        // available from multiple indexes, ensure that they're linked by their repository metadata.
+        if self.index_tracking != IndexTracking::Ignore {
+            let projects = results
+                .iter()
+                .map(|(index, metadata)| {
+                    Ok((
+                        *index,
+                        project_url(index, package_name)?,
+                        metadata.repository(),
+                    ))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            for (index, url, metadata) in &projects {
+                repository_metadata::validate_index(
+                    self.index_tracking,
+                    package_name,
+                    index,
+                    url,
+                    metadata,
+                )?;
+            }
+            repository_metadata::validate_indexes(self.index_tracking, package_name, &projects)?;
+        }
+
         Ok(results)
     }
 
@@ -318,29 +402,30 @@ impl RegistryClient {
         index: &IndexUrl,
         capabilities: &IndexCapabilities,
     ) -> Result<Option<OwnedArchive<SimpleMetadata>>, Error> {
-        // Format the URL for PyPI.
-        let mut url: Url = index.clone().into();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.clone().into()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
-
-        trace!("Fetching metadata for {package_name} from {url}");
-
         let cache_entry = self.cache.entry(
             CacheBucket::Simple,
             WheelCache::Index(index).root(),
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
-                self.cache
-                    .freshness(&cache_entry, Some(package_name))
-                    .map_err(ErrorKind::Io)?,
-            ),
+            Connectivity::Online => {
+                let definition = self.index_urls.find(index);
+                let freshness = self
+                    .cache
+                    .index_freshness(
+                        &cache_entry,
+                        definition.and_then(|index| index.name.as_ref()),
+                        Some(package_name),
+                    )
+                    .map_err(ErrorKind::Io)?;
+                match definition.and_then(|index| index.cache_max_age) {
+                    Some(max_age) if matches!(freshness, Freshness::Fresh) => {
+                        CacheControl::from_max_age(&cache_entry, Duration::from_secs(max_age))
+                            .map_err(ErrorKind::Io)?
+                    }
+                    _ => CacheControl::from(freshness),
+                }
+            }
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -351,12 +436,52 @@ impl RegistryClient {
             lock_entry.lock().await.map_err(ErrorKind::CacheWrite)?
         };
 
-        let result = if matches!(index, IndexUrl::Path(_)) {
-            self.fetch_local_index(package_name, &url).await
-        } else {
-            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
-                .await
-        };
+        let mut result = self
+            .fetch_index(
+                package_name,
+                index,
+                &cache_entry,
+                cache_control,
+                capabilities,
+            )
+            .await;
+
+        // If the index is unavailable, fall back to its mirrors, in order.
+        for mirror in self.index_urls.mirrors(index) {
+            match &result {
+                Err(err) if err.is_server_unavailable() => {
+                    warn_user_once!(
+                        "Index `{}` is unavailable; falling back to mirror `{}`",
+                        index.redacted(),
+                        mirror.redacted()
+                    );
+                }
+                _ => break,
+            }
+            result = self
+                .fetch_index(
+                    package_name,
+                    mirror,
+                    &cache_entry,
+                    cache_control,
+                    capabilities,
+                )
+                .await;
+            if let Ok(metadata) = &result {
+                debug!(
+                    "Fetched metadata for {package_name} from mirror: {}",
+                    mirror.redacted()
+                );
+                repository_metadata::validate_mirror(
+                    self.index_tracking,
+                    package_name,
+                    index,
+                    &project_url(index, package_name)?,
+                    mirror,
+                    metadata.repository(),
+                )?;
+            }
+        }
 
         match result {
             Ok(metadata) => Ok(Some(metadata)),
@@ -386,13 +511,56 @@ impl RegistryClient {
         }
     }
 
+    /// Fetch the [`SimpleMetadata`] for a given package from the given index URL, which may be the
+    /// index itself or one of its mirrors.
+    async fn fetch_index(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl,
+        capabilities: &IndexCapabilities,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let url = project_url(index, package_name)?;
+
+        trace!("Fetching metadata for {package_name} from {url}");
+
+        if matches!(index, IndexUrl::Path(_)) {
+            self.fetch_local_index(package_name, &url).await
+        } else if let Some(bucket) = self
+            .client
+            .uncached()
+            .buckets()
+            .resolve(index.url())
+            .await?
+        {
+            self.fetch_bucket_index(package_name, &bucket).await
+        } else {
+            self.load_capabilities(index, capabilities).await;
+            self.fetch_remote_index(
+                package_name,
+                index,
+                &url,
+                cache_entry,
+                cache_control,
+                capabilities,
+            )
+            .await
+        }
+    }
+
     /// Fetch the [`SimpleMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
+    ///
+    /// The Simple API features supported by the index (e.g., PEP 691 and PEP 700) are recorded in
+    /// the [`IndexCapabilities`] whenever a fresh response is received.
     async fn fetch_remote_index(
         &self,
         package_name: &PackageName,
+        index: &IndexUrl,
         url: &Url,
         cache_entry: &CacheEntry,
         cache_control: CacheControl,
+        capabilities: &IndexCapabilities,
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
         let simple_request = self
             .uncached_client(url)
@@ -431,13 +599,29 @@ impl RegistryClient {
                         let data: SimpleJson = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
-                        SimpleMetadata::from_files(data.files, package_name, &url)
+                        // If there are no files, fall back to the API version to determine whether
+                        // upload times are provided.
+                        let upload_time = if data.files.is_empty() {
+                            data.meta.supports_pep700()
+                        } else {
+                            data.files.iter().any(|file| file.upload_time.is_some())
+                        };
+                        self.set_simple_api(index, capabilities, true, upload_time)
+                            .await?;
+
+                        let repository = RepositoryMetadata {
+                            tracks: data.meta.tracks,
+                            alternate_locations: data.alternate_locations,
+                        };
+                        SimpleMetadata::from_files(data.files, repository, package_name, &url)
                     }
                     MediaType::Html => {
                         let text = response
                             .text()
                             .await
                             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                        self.set_simple_api(index, capabilities, false, false)
+                            .await?;
                         SimpleMetadata::from_html(&text, package_name, &url)?
                     }
                 };
@@ -460,8 +644,106 @@ impl RegistryClient {
             })
     }
 
-    /// Fetch the [`SimpleMetadata`] from a local file, using a PEP 503-compatible directory
+    /// Return the cache entry for the capabilities detected for the given index.
+    fn capabilities_entry(&self, index: &IndexUrl) -> CacheEntry {
+        self.cache.entry(
+            CacheBucket::Simple,
+            WheelCache::Index(index).root(),
+            "capabilities.json",
+        )
+    }
+
+    /// Load the capabilities detected for the given index in a previous run, unless they were
+    /// already loaded, or the cache is being refreshed for the index.
+    async fn load_capabilities(&self, index: &IndexUrl, capabilities: &IndexCapabilities) {
+        if !capabilities.set_loaded(index.clone()) {
+            return;
+        }
+
+        let cache_entry = self.capabilities_entry(index);
+        let name = self
+            .index_urls
+            .find(index)
+            .and_then(|index| index.name.as_ref());
+        if !matches!(
+            self.cache.index_freshness(&cache_entry, name, None),
+            Ok(Freshness::Fresh)
+        ) {
+            return;
+        }
+
+        let Ok(bytes) = fs_err::tokio::read(cache_entry.path()).await else {
+            return;
+        };
+        match serde_json::from_slice::<CachedCapabilities>(&bytes) {
+            Ok(cached) => {
+                trace!("Loaded capabilities for {}: {cached:?}", index.redacted());
+                capabilities.set_simple_api(index.clone(), cached.json_api, cached.upload_time);
+                if !cached.range_requests {
+                    capabilities.set_no_range_requests(index.clone());
+                }
+            }
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid capabilities at {}: {err}",
+                    cache_entry.path().display()
+                );
+            }
+        }
+    }
+
+    /// Record the Simple API features supported by the given index, as detected from a fresh
+    /// response, persisting them for future runs if they changed.
+    async fn set_simple_api(
+        &self,
+        index: &IndexUrl,
+        capabilities: &IndexCapabilities,
+        json_api: bool,
+        upload_time: bool,
+    ) -> Result<(), Error> {
+        if capabilities.supports_json_api(index) == json_api
+            && capabilities.provides_upload_time(index) == upload_time
+        {
+            return Ok(());
+        }
+        debug!(
+            "Detected capabilities for {}: JSON API: {json_api}, upload times: {upload_time}",
+            index.redacted()
+        );
+        capabilities.set_simple_api(index.clone(), json_api, upload_time);
+        self.persist_capabilities(index, capabilities).await
+    }
+
+    /// Persist the capabilities of the given index to the cache, for use in future runs.
+    async fn persist_capabilities(
+        &self,
+        index: &IndexUrl,
+        capabilities: &IndexCapabilities,
+    ) -> Result<(), Error> {
+        let cached = CachedCapabilities {
+            json_api: capabilities.supports_json_api(index),
+            upload_time: capabilities.provides_upload_time(index),
+            range_requests: capabilities.supports_range_requests(index),
+        };
+        let cache_entry = self.capabilities_entry(index);
+        fs_err::tokio::create_dir_all(cache_entry.dir())
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+        let bytes = serde_json::to_vec(&cached).map_err(|err| ErrorKind::CacheWrite(err.into()))?;
+        write_atomic(cache_entry.path(), bytes)
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+        Ok(())
+    }
+
+    /// Fetch the [`SimpleMetadata`] from a local directory, using a PEP 503-compatible directory
     /// structure.
+    ///
+    /// Each package directory may contain an `index.html` or a PEP 691 `index.json` or, in lieu
+    /// of either, the distributions themselves. If there's no directory for the package, the
+    /// distributions are read from the root of the index instead (i.e., a flat directory of
+    /// wheels). In any case, missing hashes are read from sidecar files or checksum manifests
+    /// alongside the distributions.
     async fn fetch_local_index(
         &self,
         package_name: &PackageName,
@@ -469,20 +751,64 @@ impl RegistryClient {
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
         let path = url
             .to_file_path()
-            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?
-            .join("index.html");
-        let text = match fs_err::tokio::read_to_string(&path).await {
-            Ok(text) => text,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+
+        let (base, mut files, repository) = if let Some(text) =
+            read_optional(&path.join("index.html")).await?
+        {
+            let text =
+                String::from_utf8(text).map_err(|err| ErrorKind::Io(std::io::Error::other(err)))?;
+            let SimpleHtml {
+                base,
+                files,
+                repository,
+            } = SimpleHtml::parse(&text, url)
+                .map_err(|err| Error::from_html_err(err, url.clone()))?;
+            (base.as_url().clone(), files, repository)
+        } else if let Some(bytes) = read_optional(&path.join("index.json")).await? {
+            let data: SimpleJson = serde_json::from_slice(&bytes)
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+            let repository = RepositoryMetadata {
+                tracks: data.meta.tracks,
+                alternate_locations: data.alternate_locations,
+            };
+            (url.clone(), data.files, repository)
+        } else if path.is_dir() {
+            let files = local_index::read_directory(&path, package_name).map_err(ErrorKind::Io)?;
+            (url.clone(), files, RepositoryMetadata::default())
+        } else if let Some(root) = path.parent().filter(|root| root.is_dir()) {
+            let files = local_index::read_directory(root, package_name).map_err(ErrorKind::Io)?;
+            if files.is_empty() {
                 return Err(Error::from(ErrorKind::FileNotFound(
                     package_name.to_string(),
                 )));
             }
-            Err(err) => {
-                return Err(Error::from(ErrorKind::Io(err)));
-            }
+            (url.clone(), files, RepositoryMetadata::default())
+        } else {
+            return Err(Error::from(ErrorKind::FileNotFound(
+                package_name.to_string(),
+            )));
         };
-        let metadata = SimpleMetadata::from_html(&text, package_name, url)?;
+
+        local_index::read_missing_hashes(&mut files, &base).map_err(ErrorKind::Io)?;
+        let metadata = SimpleMetadata::from_files(files, repository, package_name, &base);
+        OwnedArchive::from_unarchived(&metadata)
+    }
+
+    /// Fetch the [`SimpleMetadata`] from an `s3://` or `gs://` bucket, laid out like a local
+    /// directory index.
+    ///
+    /// Like local indexes, bucket listings are read on every run, rather than cached.
+    async fn fetch_bucket_index(
+        &self,
+        package_name: &PackageName,
+        bucket: &Bucket,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let (base, files) = bucket
+            .read_package(self.uncached_client(bucket.endpoint()), package_name)
+            .await?;
+        let metadata =
+            SimpleMetadata::from_files(files, RepositoryMetadata::default(), package_name, &base);
         OwnedArchive::from_unarchived(&metadata)
     }
 
@@ -737,7 +1063,13 @@ impl RegistryClient {
                     .await
                     .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
                     trace!("Getting metadata for {filename} by range request");
-                    let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
+                    let text = wheel_metadata_from_remote_zip(
+                        filename,
+                        url,
+                        &mut reader,
+                        self.range_request_size,
+                    )
+                    .await?;
                     let metadata =
                         ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
                             Error::from(ErrorKind::MetadataParseError(
@@ -774,6 +1106,7 @@ impl RegistryClient {
                         // Mark the index as not supporting range requests.
                         if let Some(index) = index {
                             capabilities.set_no_range_requests(index.clone());
+                            self.persist_capabilities(index, capabilities).await?;
                         }
                     } else {
                         return Err(err);
@@ -832,6 +1165,15 @@ impl RegistryClient {
     }
 }
 
+/// Read the file at the given path, returning `None` if it doesn't exist.
+async fn read_optional(path: &Path) -> Result<Option<Vec<u8>>, Error> {
+    match fs_err::tokio::read(path).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(Error::from(ErrorKind::Io(err))),
+    }
+}
+
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
 pub struct VersionFiles {
@@ -877,7 +1219,10 @@ pub struct VersionSourceDist {
 
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    versions: Vec<SimpleMetadatum>,
+    repository: RepositoryMetadata,
+}
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
@@ -888,10 +1233,15 @@ pub struct SimpleMetadatum {
 
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.versions.iter()
     }
 
-    fn from_files(files: Vec<uv_pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
+    fn from_files(
+        files: Vec<uv_pypi_types::File>,
+        repository: RepositoryMetadata,
+        package_name: &PackageName,
+        base: &Url,
+    ) -> Self {
         let mut map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
 
         // Convert to a reference-counted string.
@@ -927,20 +1277,26 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            versions: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
-        )
+            repository,
+        }
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
     fn from_html(text: &str, package_name: &PackageName, url: &Url) -> Result<Self, Error> {
-        let SimpleHtml { base, files } =
-            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+        let SimpleHtml {
+            base,
+            files,
+            repository,
+        } = SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
 
         Ok(SimpleMetadata::from_files(
             files,
+            repository,
             package_name,
             base.as_url(),
         ))
@@ -952,20 +1308,38 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.versions.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.versions.get(i)
+    }
+
+    /// The repository metadata declared by the index for the package, as specified in PEP 708.
+    pub fn repository(&self) -> &rkyv::Archived<RepositoryMetadata> {
+        &self.repository
     }
 }
 
+/// Format the URL of the project page for a package on the given index.
+fn project_url(index: &IndexUrl, package_name: &PackageName) -> Result<Url, Error> {
+    let mut url: Url = index.clone().into();
+    url.path_segments_mut()
+        .map_err(|()| ErrorKind::CannotBeABase(index.clone().into()))?
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL *must* end in a trailing slash for proper relative path behavior
+        // ref https://github.com/servo/rust-url/issues/333
+        .push("");
+    Ok(url)
+}
+
 #[derive(Debug)]
 enum MediaType {
     Json,
@@ -990,6 +1364,18 @@ impl MediaType {
     }
 }
 
+/// The capabilities of an index, as persisted in the cache between runs.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CachedCapabilities {
+    /// Whether the index serves the JSON-based Simple API (PEP 691).
+    json_api: bool,
+    /// Whether the index reports the upload time of its files (PEP 700).
+    upload_time: bool,
+    /// Whether the index supports range requests.
+    range_requests: bool,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum Connectivity {
     /// Allow access to the network.
@@ -1060,6 +1446,7 @@ mod tests {
         let base = Url::parse("https://pypi.org/simple/pyflyby/").unwrap();
         let simple_metadata = SimpleMetadata::from_files(
             data.files,
+            RepositoryMetadata::default(),
             &PackageName::from_str("pyflyby").unwrap(),
             &base,
         );
@@ -1096,7 +1483,7 @@ mod tests {
         // Note the lack of a trailing `/` here is important for coverage of url-join behavior
         let base = Url::parse("https://account.d.codeartifact.us-west-2.amazonaws.com/pypi/shared-packages-pypi/simple/flask")
             .unwrap();
-        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+        let SimpleHtml { base, files, .. } = SimpleHtml::parse(text, &base).unwrap();
 
         // Test parsing of the file urls
         let urls = files
@@ -1,7 +1,10 @@
 use crate::{Error, ErrorKind};
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::io::BufReader;
+use std::io::SeekFrom;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 use tokio_util::compat::TokioAsyncReadCompatExt;
+use tracing::trace;
 use url::Url;
 use uv_distribution_filename::WheelFilename;
 use uv_metadata::find_archive_dist_info;
@@ -51,14 +54,30 @@ pub(crate) async fn wheel_metadata_from_remote_zip(
     filename: &WheelFilename,
     debug_name: &Url,
     reader: &mut AsyncHttpRangeReader,
+    range_request_size: u64,
 ) -> Result<String, Error> {
-    // Make sure we have the back part of the stream.
-    // Best guess for the central directory size inside the zip
-    const CENTRAL_DIRECTORY_SIZE: u64 = 16384;
-    // Because the zip index is at the back
-    reader
-        .prefetch(reader.len().saturating_sub(CENTRAL_DIRECTORY_SIZE)..reader.len())
-        .await;
+    // Make sure we have the back part of the stream, since the zip index is at the back. The
+    // request size is a best guess for the size of the central directory inside the zip.
+    let tail_start = reader.len().saturating_sub(range_request_size);
+    reader.prefetch(tail_start..reader.len()).await;
+
+    // If the central directory doesn't fit in the tail, fetch the remainder in a single request.
+    // Otherwise, the zip reader would fetch it in a series of small requests, one per buffered
+    // read, each of which incurs a round trip.
+    let mut tail = Vec::new();
+    if reader.seek(SeekFrom::Start(tail_start)).await.is_ok()
+        && reader.read_to_end(&mut tail).await.is_ok()
+    {
+        if let Some(offset) = central_directory_offset(&tail) {
+            if offset < tail_start {
+                trace!(
+                    "Fetching {} additional bytes of the central directory for {filename}",
+                    tail_start - offset
+                );
+                reader.prefetch(offset..tail_start).await;
+            }
+        }
+    }
 
     // Construct a zip reader to uses the stream.
     let buf = BufReader::new(reader.compat());
@@ -107,3 +126,54 @@ pub(crate) async fn wheel_metadata_from_remote_zip(
 
     Ok(contents)
 }
+
+/// Determine the offset of the central directory from the tail of a zip archive, by way of the end
+/// of central directory record.
+///
+/// Returns `None` if the record isn't contained in the tail (e.g., due to a long archive comment),
+/// or if the archive uses the ZIP64 format.
+fn central_directory_offset(tail: &[u8]) -> Option<u64> {
+    const SIGNATURE: [u8; 4] = [0x50, 0x4b, 0x05, 0x06];
+    const RECORD_SIZE: usize = 22;
+
+    let start = (0..=tail.len().checked_sub(RECORD_SIZE)?)
+        .rev()
+        .find(|&start| tail[start..start + 4] == SIGNATURE)?;
+    let offset = u32::from_le_bytes(tail[start + 16..start + 20].try_into().ok()?);
+    if offset == u32::MAX {
+        return None;
+    }
+    Some(u64::from(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::central_directory_offset;
+
+    /// Build an end of central directory record pointing to the given offset.
+    fn record(offset: u32, comment: &[u8]) -> Vec<u8> {
+        let mut record = vec![0x50, 0x4b, 0x05, 0x06];
+        record.extend_from_slice(&[0; 12]);
+        record.extend_from_slice(&offset.to_le_bytes());
+        record.extend_from_slice(&u16::try_from(comment.len()).unwrap().to_le_bytes());
+        record.extend_from_slice(comment);
+        record
+    }
+
+    #[test]
+    fn central_directory() {
+        let mut tail = vec![0; 64];
+        tail.extend(record(1234, b""));
+        assert_eq!(central_directory_offset(&tail), Some(1234));
+
+        let mut tail = vec![0; 64];
+        tail.extend(record(1234, b"comment"));
+        assert_eq!(central_directory_offset(&tail), Some(1234));
+
+        // ZIP64 archives store the offset elsewhere.
+        assert_eq!(central_directory_offset(&record(u32::MAX, b"")), None);
+
+        // The record is truncated.
+        assert_eq!(central_directory_offset(&record(1234, b"")[4..]), None);
+    }
+}
@@ -0,0 +1,241 @@
+use std::str::FromStr;
+
+use url::Url;
+
+use uv_configuration::IndexTracking;
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_small_str::SmallString;
+use uv_warnings::warn_user_once;
+
+use crate::{Error, ErrorKind};
+
+/// The repository metadata that an index declares for a project, as specified in PEP 708.
+///
+/// <https://peps.python.org/pep-0708/>
+#[derive(Debug, Clone, Default, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
+#[rkyv(derive(Debug))]
+pub struct RepositoryMetadata {
+    /// The URLs of the projects on other indexes that this project tracks (i.e., mirrors).
+    pub tracks: Vec<SmallString>,
+    /// The URLs of the projects on other indexes (including this one) from which the project is
+    /// also available.
+    pub alternate_locations: Vec<SmallString>,
+}
+
+impl ArchivedRepositoryMetadata {
+    /// Returns `true` if the index didn't declare any repository metadata for the project.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty() && self.alternate_locations.is_empty()
+    }
+
+    /// Returns `true` if the project tracks the project at the given URL.
+    pub fn tracks(&self, url: &Url) -> bool {
+        self.tracks.iter().any(|track| is_same_project(track, url))
+    }
+
+    /// Returns `true` if the project lists the project at the given URL as an alternate location.
+    pub fn is_alternate_location(&self, url: &Url) -> bool {
+        self.alternate_locations
+            .iter()
+            .any(|location| is_same_project(location, url))
+    }
+}
+
+/// Returns `true` if the declared project URL refers to the project at the given URL, ignoring
+/// any trailing slash.
+fn is_same_project(declared: &str, url: &Url) -> bool {
+    declared.trim_end_matches('/') == url.as_str().trim_end_matches('/')
+}
+
+/// Returns `true` if the declared project URL refers to a project with the given name, i.e., if
+/// the last segment of its path is the (normalized) name of the package.
+fn is_project_named(declared: &str, package_name: &PackageName) -> bool {
+    let Ok(url) = Url::parse(declared) else {
+        return false;
+    };
+    url.path_segments()
+        .and_then(|segments| segments.filter(|segment| !segment.is_empty()).next_back())
+        .and_then(|segment| PackageName::from_str(segment).ok())
+        .is_some_and(|name| name == *package_name)
+}
+
+/// Returns `true` if two projects are linked by their repository metadata: that is, if either
+/// tracks the other, or if each lists the other as an alternate location.
+fn is_linked(
+    (first_url, first): (&Url, &ArchivedRepositoryMetadata),
+    (second_url, second): (&Url, &ArchivedRepositoryMetadata),
+) -> bool {
+    first.tracks(second_url)
+        || second.tracks(first_url)
+        || (first.is_alternate_location(second_url) && second.is_alternate_location(first_url))
+}
+
+/// Validate the repository metadata that a single index declares for a package: every project it
+/// tracks must be the same project on another index, and every alternate location must be the
+/// same project.
+///
+/// The project URL is that of the package on the index.
+pub(crate) fn validate_index(
+    tracking: IndexTracking,
+    package_name: &PackageName,
+    index: &IndexUrl,
+    project_url: &Url,
+    metadata: &ArchivedRepositoryMetadata,
+) -> Result<(), Error> {
+    if tracking == IndexTracking::Ignore {
+        return Ok(());
+    }
+    let invalid = metadata
+        .tracks
+        .iter()
+        .find(|track| !is_project_named(track, package_name) || is_same_project(track, project_url))
+        .or_else(|| {
+            metadata
+                .alternate_locations
+                .iter()
+                .find(|location| !is_project_named(location, package_name))
+        });
+    let Some(url) = invalid else {
+        return Ok(());
+    };
+    let err = ErrorKind::InvalidRepositoryMetadata {
+        package: package_name.to_string(),
+        index: index.redacted().to_string(),
+        url: url.to_string(),
+    };
+    match tracking {
+        IndexTracking::Strict => return Err(err.into()),
+        IndexTracking::Warn => warn_user_once!("{err}"),
+        IndexTracking::Ignore => {}
+    }
+    Ok(())
+}
+
+/// Validate that every index that serves a package is linked to the others by its repository
+/// metadata.
+///
+/// Each index is given alongside the URL of its project page and the repository metadata it
+/// declares.
+pub(crate) fn validate_indexes(
+    tracking: IndexTracking,
+    package_name: &PackageName,
+    projects: &[(&IndexUrl, Url, &ArchivedRepositoryMetadata)],
+) -> Result<(), Error> {
+    if tracking == IndexTracking::Ignore {
+        return Ok(());
+    }
+    for (i, (first, first_url, first_metadata)) in projects.iter().enumerate() {
+        for (second, second_url, second_metadata) in &projects[i + 1..] {
+            if is_linked((first_url, first_metadata), (second_url, second_metadata)) {
+                continue;
+            }
+            let err = ErrorKind::UnlinkedIndexes {
+                package: package_name.to_string(),
+                first: first.redacted().to_string(),
+                second: second.redacted().to_string(),
+            };
+            match tracking {
+                IndexTracking::Strict => return Err(err.into()),
+                IndexTracking::Warn => {
+                    // Only warn if either index opted in to declaring its repository metadata.
+                    if !first_metadata.is_empty() || !second_metadata.is_empty() {
+                        warn_user_once!("{err}");
+                    }
+                }
+                IndexTracking::Ignore => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate that a mirror declares that it tracks the project on the index that it mirrors.
+///
+/// The project URL is that of the package on the mirrored index.
+pub(crate) fn validate_mirror(
+    tracking: IndexTracking,
+    package_name: &PackageName,
+    index: &IndexUrl,
+    project_url: &Url,
+    mirror: &IndexUrl,
+    metadata: &ArchivedRepositoryMetadata,
+) -> Result<(), Error> {
+    if tracking == IndexTracking::Ignore || metadata.tracks(project_url) {
+        return Ok(());
+    }
+    let err = ErrorKind::UntrackedMirror {
+        package: package_name.to_string(),
+        index: index.redacted().to_string(),
+        mirror: mirror.redacted().to_string(),
+    };
+    match tracking {
+        IndexTracking::Strict => return Err(err.into()),
+        IndexTracking::Warn => {
+            // Only warn if the mirror declares that it tracks some other repository.
+            if !metadata.tracks.is_empty() {
+                warn_user_once!("{err}");
+            }
+        }
+        IndexTracking::Ignore => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+    use uv_normalize::PackageName;
+    use uv_small_str::SmallString;
+
+    use crate::OwnedArchive;
+
+    use super::{is_linked, is_project_named, RepositoryMetadata};
+
+    fn archive(tracks: &[&str], alternate_locations: &[&str]) -> OwnedArchive<RepositoryMetadata> {
+        OwnedArchive::from_unarchived(&RepositoryMetadata {
+            tracks: tracks.iter().copied().map(SmallString::from).collect(),
+            alternate_locations: alternate_locations
+                .iter()
+                .copied()
+                .map(SmallString::from)
+                .collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn linked() {
+        let pypi = Url::parse("https://pypi.org/simple/flask/").unwrap();
+        let mirror = Url::parse("https://mirror.example.com/simple/flask/").unwrap();
+        let internal = Url::parse("https://internal.example.com/simple/flask/").unwrap();
+
+        // A mirror is linked to the index it tracks, regardless of trailing slashes.
+        let tracking = archive(&["https://pypi.org/simple/flask"], &[]);
+        let empty = archive(&[], &[]);
+        assert!(is_linked((&mirror, &tracking), (&pypi, &empty)));
+        assert!(is_linked((&pypi, &empty), (&mirror, &tracking)));
+        assert!(!is_linked((&internal, &tracking), (&mirror, &empty)));
+
+        // Alternate locations must be declared by both indexes.
+        let first = archive(&[], &[pypi.as_str(), internal.as_str()]);
+        let second = archive(&[], &[internal.as_str(), pypi.as_str()]);
+        assert!(is_linked((&pypi, &first), (&internal, &second)));
+        assert!(!is_linked((&pypi, &first), (&internal, &empty)));
+
+        // Indexes without any repository metadata aren't linked.
+        assert!(!is_linked((&pypi, &empty), (&internal, &empty)));
+    }
+
+    #[test]
+    fn project_named() {
+        let flask = PackageName::from_str("flask").unwrap();
+        assert!(is_project_named("https://pypi.org/simple/flask/", &flask));
+        assert!(is_project_named("https://pypi.org/simple/Flask", &flask));
+        assert!(!is_project_named("https://pypi.org/simple/django/", &flask));
+        assert!(!is_project_named("https://pypi.org/", &flask));
+        assert!(!is_project_named("not a url", &flask));
+    }
+}
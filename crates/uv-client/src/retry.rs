@@ -0,0 +1,350 @@
+use std::time::{Duration, SystemTime};
+
+use http::Extensions;
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::policies::ExponentialBackoff;
+use reqwest_retry::{RetryDecision, RetryPolicy, Retryable, RetryableStrategy};
+use tracing::debug;
+use url::Url;
+
+use uv_configuration::{HttpRetryOptions, TrustedHost};
+use uv_warnings::warn_user_once;
+
+use crate::base_client::UvRetryableStrategy;
+use crate::throttle::{is_rate_limited, retry_after, Throttle};
+use crate::{CachedClientError, ErrorKind};
+
+pub const DEFAULT_RETRIES: u32 = 3;
+
+/// The retry parameters for requests to a given host.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryParameters {
+    /// The maximum number of times to retry a failed request.
+    pub retries: u32,
+    /// Additional HTTP status codes to treat as transient.
+    pub status_codes: Vec<u16>,
+    /// The minimum delay before retrying a request.
+    pub backoff_min: Duration,
+    /// The maximum delay before retrying a request.
+    pub backoff_max: Duration,
+    /// The base of the exponential backoff between retries.
+    pub backoff_base: u32,
+}
+
+impl Default for RetryParameters {
+    /// The defaults match those of [`ExponentialBackoff`].
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            status_codes: Vec::new(),
+            backoff_min: Duration::from_secs(1),
+            backoff_max: Duration::from_secs(30 * 60),
+            backoff_base: 2,
+        }
+    }
+}
+
+impl RetryParameters {
+    /// Apply the given overrides on top of the current parameters.
+    #[must_use]
+    fn with_overrides(
+        &self,
+        retries: Option<u32>,
+        status_codes: Option<&[u16]>,
+        backoff_min: Option<f64>,
+        backoff_max: Option<f64>,
+        backoff_base: Option<u32>,
+    ) -> Self {
+        Self {
+            retries: retries.unwrap_or(self.retries),
+            status_codes: status_codes.map_or_else(|| self.status_codes.clone(), <[u16]>::to_vec),
+            backoff_min: backoff_min
+                .and_then(duration_from_secs)
+                .unwrap_or(self.backoff_min),
+            backoff_max: backoff_max
+                .and_then(duration_from_secs)
+                .unwrap_or(self.backoff_max),
+            backoff_base: backoff_base.unwrap_or(self.backoff_base),
+        }
+    }
+
+    /// Return the [`ExponentialBackoff`] policy for these parameters.
+    pub fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::builder()
+            .retry_bounds(self.backoff_min, self.backoff_max.max(self.backoff_min))
+            .base(self.backoff_base)
+            .build_with_max_retries(self.retries)
+    }
+
+    /// Return the delay before the `n`-th retry of a rate-limited request, for hosts that don't
+    /// provide a `Retry-After` header.
+    fn delay(&self, n: u32) -> Duration {
+        self.backoff_min
+            .saturating_mul(self.backoff_base.saturating_pow(n))
+            .min(self.backoff_max)
+    }
+
+    /// Returns `true` if the given status code should be retried, in addition to those retried
+    /// by [`UvRetryableStrategy`].
+    fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.status_codes.contains(&status.as_u16())
+    }
+
+    /// Returns `true` if the given error was caused by a response with one of the configured
+    /// status codes.
+    pub(crate) fn is_retryable_error<E: std::error::Error + 'static>(
+        &self,
+        err: &CachedClientError<E>,
+    ) -> bool {
+        let CachedClientError::Client(err) = err else {
+            return false;
+        };
+        match err.kind() {
+            ErrorKind::WrappedReqwestError(_, err) => err
+                .status()
+                .is_some_and(|status| self.is_retryable_status(status)),
+            _ => false,
+        }
+    }
+}
+
+/// Convert a number of seconds to a [`Duration`], warning on invalid values.
+fn duration_from_secs(secs: f64) -> Option<Duration> {
+    match Duration::try_from_secs_f64(secs) {
+        Ok(duration) => Some(duration),
+        Err(_) => {
+            warn_user_once!(
+                "Ignoring invalid HTTP retry backoff. Expected a non-negative number of seconds, got `{secs}`."
+            );
+            None
+        }
+    }
+}
+
+/// The policy for retrying HTTP requests that fail with a transient error, with optional
+/// per-host overrides.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpRetryPolicy {
+    /// The parameters for requests to hosts without an override.
+    pub default: RetryParameters,
+    /// The parameters for requests to specific hosts, in order of precedence.
+    pub hosts: Vec<(TrustedHost, RetryParameters)>,
+}
+
+impl HttpRetryPolicy {
+    /// Resolve the [`HttpRetryPolicy`] from the user-provided options.
+    pub fn from_options(options: Option<&HttpRetryOptions>) -> Self {
+        let Some(options) = options else {
+            return Self::default();
+        };
+
+        let default = RetryParameters::default().with_overrides(
+            options.retries,
+            options.status_codes.as_deref(),
+            options.backoff_min,
+            options.backoff_max,
+            options.backoff_base,
+        );
+        let hosts = options
+            .hosts
+            .iter()
+            .flatten()
+            .map(|host| {
+                let parameters = default.with_overrides(
+                    host.retries,
+                    host.status_codes.as_deref(),
+                    host.backoff_min,
+                    host.backoff_max,
+                    host.backoff_base,
+                );
+                (host.host.clone(), parameters)
+            })
+            .collect();
+
+        Self { default, hosts }
+    }
+
+    /// Return a policy that retries every request up to the given number of times, discarding
+    /// any per-host overrides for the number of retries.
+    #[must_use]
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.default.retries = retries;
+        for (_, parameters) in &mut self.hosts {
+            parameters.retries = retries;
+        }
+        self
+    }
+
+    /// Returns `true` if no request would ever be retried.
+    pub fn is_disabled(&self) -> bool {
+        self.default.retries == 0 && self.hosts.iter().all(|(_, params)| params.retries == 0)
+    }
+
+    /// Return the [`RetryParameters`] for requests to the given URL.
+    pub fn parameters(&self, url: &Url) -> &RetryParameters {
+        self.hosts
+            .iter()
+            .find(|(host, _)| host.matches(url))
+            .map_or(&self.default, |(_, parameters)| parameters)
+    }
+}
+
+/// A middleware that retries requests failing with a transient error, according to the
+/// [`HttpRetryPolicy`] for the requested host.
+///
+/// Like [`reqwest_retry::RetryTransientMiddleware`], but with per-host retry parameters.
+pub(crate) struct RetryMiddleware {
+    policy: HttpRetryPolicy,
+}
+
+impl RetryMiddleware {
+    pub(crate) fn new(policy: HttpRetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let parameters = self.policy.parameters(req.url());
+        let backoff = parameters.backoff();
+        let throttle = Throttle::global().host(req.url());
+        let start_time = SystemTime::now();
+        let mut n_past_retries = 0;
+        let mut n_rate_limited = 0;
+        let mut rate_limited_for = Duration::default();
+        loop {
+            // Respect the concurrency limit for the host, if it has rate-limited us.
+            let permit = match throttle.as_ref() {
+                Some(throttle) => Some(throttle.acquire().await),
+                None => None,
+            };
+
+            // Requests with a streaming body can't be cloned, and so can't be retried.
+            let Some(duplicate) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+
+            let result = next.clone().run(duplicate, extensions).await;
+            drop(permit);
+
+            // If the host is rate-limiting us, back off (as requested by the host, if possible)
+            // and retry, rather than failing outright, until we've waited for the maximum backoff.
+            let rate_limited = match &result {
+                Ok(response) if is_rate_limited(response) => Some(retry_after(response)),
+                _ => None,
+            };
+            if let Some(retry_after) = rate_limited {
+                if let Some(throttle) = throttle.as_ref() {
+                    throttle.on_rate_limited(req.url());
+                }
+                let duration = retry_after
+                    .unwrap_or_else(|| parameters.delay(n_rate_limited))
+                    .min(parameters.backoff_max);
+                if rate_limited_for + duration <= parameters.backoff_max {
+                    debug!(
+                        "Rate-limited by {}, retrying in {:.1}s",
+                        req.url(),
+                        duration.as_secs_f64()
+                    );
+                    if let Some(throttle) = throttle.as_ref() {
+                        throttle.on_wait(duration);
+                    }
+                    tokio::time::sleep(duration).await;
+                    rate_limited_for += duration;
+                    n_rate_limited += 1;
+                    continue;
+                }
+                return result;
+            }
+
+            if let Some(throttle) = throttle.as_ref() {
+                if result
+                    .as_ref()
+                    .is_ok_and(|response| response.status().is_success())
+                {
+                    throttle.on_success();
+                }
+            }
+
+            let retryable = match UvRetryableStrategy.handle(&result) {
+                None | Some(Retryable::Fatal)
+                    if result.as_ref().is_ok_and(|response| {
+                        parameters.is_retryable_status(response.status())
+                    }) =>
+                {
+                    debug!(
+                        "Retrying request to {} on configured status code",
+                        req.url()
+                    );
+                    Some(Retryable::Transient)
+                }
+                retryable => retryable,
+            };
+
+            if retryable == Some(Retryable::Transient) {
+                if let RetryDecision::Retry { execute_after } =
+                    backoff.should_retry(start_time, n_past_retries)
+                {
+                    let duration = execute_after
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_else(|_| Duration::default());
+                    tokio::time::sleep(duration).await;
+                    n_past_retries += 1;
+                    continue;
+                }
+            }
+
+            return result;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use url::Url;
+
+    use uv_configuration::HttpRetryOptions;
+
+    use super::{HttpRetryPolicy, DEFAULT_RETRIES};
+
+    #[test]
+    fn policy() {
+        let options: HttpRetryOptions = serde_json::from_value(serde_json::json!({
+            "retries": 5,
+            "status-codes": [403],
+            "backoff-min": 0.5,
+            "hosts": [{ "host": "proxy.example.com", "retries": 10, "backoff-max": 60 }],
+        }))
+        .unwrap();
+
+        let policy = HttpRetryPolicy::from_options(Some(&options));
+
+        let pypi = policy.parameters(&Url::from_str("https://pypi.org/simple/").unwrap());
+        assert_eq!(pypi.retries, 5);
+        assert_eq!(pypi.status_codes, vec![403]);
+        assert_eq!(pypi.backoff_min, Duration::from_millis(500));
+        assert_eq!(pypi.backoff_max, Duration::from_secs(30 * 60));
+
+        let proxy = policy.parameters(&Url::from_str("https://proxy.example.com/simple/").unwrap());
+        assert_eq!(proxy.retries, 10);
+        assert_eq!(proxy.status_codes, vec![403]);
+        assert_eq!(proxy.backoff_min, Duration::from_millis(500));
+        assert_eq!(proxy.backoff_max, Duration::from_secs(60));
+
+        // Without any options, use the defaults.
+        let policy = HttpRetryPolicy::from_options(None);
+        assert_eq!(policy.default.retries, DEFAULT_RETRIES);
+        assert!(!policy.is_disabled());
+        assert!(policy.with_retries(0).is_disabled());
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+use tokio::sync::Notify;
+use tracing::debug;
+use url::Url;
+
+use uv_warnings::warn_user;
+
+/// The throttling state for every host, shared by all clients in the process.
+static THROTTLE: LazyLock<Throttle> = LazyLock::new(Throttle::default);
+
+/// Tracks rate limiting (i.e., `429 Too Many Requests` responses) per host, and adapts the number
+/// of concurrent requests to each host accordingly.
+#[derive(Debug, Default)]
+pub(crate) struct Throttle {
+    hosts: Mutex<BTreeMap<String, Arc<HostThrottle>>>,
+}
+
+impl Throttle {
+    /// Return the global [`Throttle`].
+    pub(crate) fn global() -> &'static Self {
+        &THROTTLE
+    }
+
+    /// Return the [`HostThrottle`] for the host of the given URL.
+    pub(crate) fn host(&self, url: &Url) -> Option<Arc<HostThrottle>> {
+        let host = url.host_str()?;
+        let mut hosts = self.hosts.lock().unwrap();
+        Some(hosts.entry(host.to_string()).or_default().clone())
+    }
+}
+
+/// The concurrency limit for requests to a single host.
+///
+/// Follows an additive-increase, multiplicative-decrease scheme: each rate-limited response halves
+/// the number of concurrent requests to the host, and the limit is gradually raised again as
+/// requests succeed, until it's lifted entirely.
+#[derive(Debug, Default)]
+pub(crate) struct HostThrottle {
+    state: Mutex<HostState>,
+    notify: Notify,
+}
+
+#[derive(Debug, Default)]
+struct HostState {
+    /// The number of requests to the host that are currently in flight.
+    in_flight: usize,
+    /// The maximum number of concurrent requests, if the host has rate-limited us.
+    limit: Option<usize>,
+    /// The highest number of concurrent requests observed when the host rate-limited us.
+    ceiling: usize,
+    /// The number of successful requests since the limit was last raised.
+    successes: usize,
+    /// The number of rate-limited responses from the host.
+    throttled: usize,
+    /// The total time spent waiting to retry rate-limited requests.
+    waited: Duration,
+}
+
+impl HostThrottle {
+    /// Wait until a request to the host is permitted by the current limit.
+    pub(crate) async fn acquire(self: &Arc<Self>) -> ThrottlePermit {
+        loop {
+            // Register for notifications before checking the limit, to avoid missing a release.
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.limit.is_none_or(|limit| state.in_flight < limit) {
+                    state.in_flight += 1;
+                    return ThrottlePermit(self.clone());
+                }
+            }
+            notified.await;
+        }
+    }
+
+    /// Record a rate-limited response, reducing the number of concurrent requests.
+    pub(crate) fn on_rate_limited(&self, url: &Url) {
+        let mut state = self.state.lock().unwrap();
+        state.throttled += 1;
+        state.successes = 0;
+        state.ceiling = state.ceiling.max(state.in_flight);
+        let current = state.limit.unwrap_or(state.in_flight).min(state.in_flight);
+        let limit = (current / 2).max(1);
+        if state.limit != Some(limit) {
+            debug!(
+                "Rate-limited by {}; reducing concurrency to {limit}",
+                url.host_str().unwrap_or_default()
+            );
+        }
+        state.limit = Some(limit);
+    }
+
+    /// Record a successful response, gradually raising the limit on concurrent requests.
+    pub(crate) fn on_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        let Some(limit) = state.limit else {
+            return;
+        };
+        state.successes += 1;
+        if state.successes >= limit {
+            state.successes = 0;
+            state.limit = (limit + 1 < state.ceiling).then_some(limit + 1);
+            self.notify.notify_waiters();
+        }
+    }
+
+    /// Record time spent waiting to retry a rate-limited request.
+    pub(crate) fn on_wait(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.waited += duration;
+    }
+}
+
+/// A permit to send a request to a host, released on drop.
+pub(crate) struct ThrottlePermit(Arc<HostThrottle>);
+
+impl Drop for ThrottlePermit {
+    fn drop(&mut self) {
+        let mut state = self.0.state.lock().unwrap();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        self.0.notify.notify_waiters();
+    }
+}
+
+/// Returns `true` if the response indicates that the server is rate-limiting requests.
+pub(crate) fn is_rate_limited(response: &Response) -> bool {
+    response.status() == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Parse the `Retry-After` header of a response, as either a number of seconds or an HTTP date.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::parse(value).ok()?;
+    let date = SystemTime::from(date.timestamp());
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// Warn about any hosts that rate-limited requests during this process.
+pub fn warn_on_throttling() {
+    let hosts = Throttle::global().hosts.lock().unwrap();
+    for (host, throttle) in hosts.iter() {
+        let state = throttle.state.lock().unwrap();
+        if state.throttled == 0 {
+            continue;
+        }
+        let s = if state.throttled == 1 { "" } else { "s" };
+        warn_user!(
+            "`{host}` rate-limited {} request{s} (waited {:.1}s in total); consider reducing `UV_CONCURRENT_DOWNLOADS`",
+            state.throttled,
+            state.waited.as_secs_f64()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use url::Url;
+
+    use super::HostThrottle;
+
+    #[tokio::test]
+    async fn limit() {
+        let url = Url::parse("https://pypi.org/simple/").unwrap();
+        let throttle = Arc::new(HostThrottle::default());
+
+        let permits = futures::future::join_all((0..8).map(|_| throttle.acquire())).await;
+        throttle.on_rate_limited(&url);
+        assert_eq!(throttle.state.lock().unwrap().limit, Some(4));
+        throttle.on_rate_limited(&url);
+        assert_eq!(throttle.state.lock().unwrap().limit, Some(2));
+        drop(permits);
+
+        // The limit is raised as requests succeed, until it's lifted entirely.
+        for _ in 0..32 {
+            let _permit = throttle.acquire().await;
+            throttle.on_success();
+        }
+        assert_eq!(throttle.state.lock().unwrap().limit, None);
+        assert_eq!(throttle.state.lock().unwrap().throttled, 2);
+    }
+}
@@ -1,6 +1,9 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use uv_distribution_types::Index;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -10,6 +13,35 @@ pub(crate) enum CertificateError {
     Reqwest(reqwest::Error),
 }
 
+/// The TLS configuration for requests to a specific host, in addition to the global
+/// configuration (e.g., `--native-tls`, `SSL_CERT_FILE`, and `SSL_CLIENT_CERT`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// A PEM file of additional certificate authorities to trust.
+    pub ca_cert: Option<PathBuf>,
+    /// A PEM file containing the client certificate to present, along with its private key if
+    /// `client_key` is not set.
+    pub client_cert: Option<PathBuf>,
+    /// A PEM file containing the private key for the client certificate.
+    pub client_key: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    /// Return the [`TlsConfig`] for requests to the given [`Index`].
+    pub fn from_index(index: &Index) -> Self {
+        Self {
+            ca_cert: index.ssl_ca_cert.clone(),
+            client_cert: index.ssl_client_cert.clone(),
+            client_key: index.ssl_client_key.clone(),
+        }
+    }
+
+    /// Returns `true` if the configuration doesn't change the default TLS behavior.
+    pub fn is_empty(&self) -> bool {
+        self.ca_cert.is_none() && self.client_cert.is_none() && self.client_key.is_none()
+    }
+}
+
 /// Return the `Identity` from the provided file.
 pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, CertificateError> {
     let mut buf = Vec::new();
@@ -19,3 +51,20 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Identity` from the provided certificate file and a separate private key file.
+pub(crate) fn read_identity_with_key(
+    ssl_client_cert: &Path,
+    ssl_client_key: &Path,
+) -> Result<Identity, CertificateError> {
+    let mut buf = fs_err::read(ssl_client_cert)?;
+    buf.push(b'\n');
+    buf.extend(fs_err::read(ssl_client_key)?);
+    Identity::from_pem(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// Return the certificates from the provided PEM bundle.
+pub(crate) fn read_certificates(ssl_ca_cert: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let buf = fs_err::read(ssl_ca_cert)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
@@ -0,0 +1,15 @@
+/// The IP address families over which to connect to a host.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AddressFamily {
+    /// Connect over either IPv4 or IPv6, racing the two for hosts that have both addresses
+    /// ("Happy Eyeballs").
+    #[default]
+    Auto,
+    /// Connect over IPv4 only.
+    Ipv4,
+    /// Connect over IPv6 only.
+    Ipv6,
+}
@@ -11,6 +11,8 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use the native credential store (e.g., the macOS Keychain) for credential lookup.
+    Native,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -23,6 +25,7 @@ impl KeyringProviderType {
         match self {
             Self::Disabled => None,
             Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Native => Some(KeyringProvider::native()),
         }
     }
 }
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+/// A data rate limit, in bytes per second.
+///
+/// Parsed from either an integer number of bytes per second, or a number with a decimal (`K`,
+/// `M`, `G`) or binary (`Ki`, `Mi`, `Gi`) unit prefix and an optional `B` and `/s` suffix (e.g.,
+/// `10M`, `512KiB`, or `1.5 MB/s`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bandwidth(u64);
+
+impl Bandwidth {
+    /// Create a [`Bandwidth`] from a number of bytes per second.
+    pub fn from_bytes_per_second(bytes: u64) -> Option<Self> {
+        (bytes > 0).then_some(Self(bytes))
+    }
+
+    /// The number of bytes per second.
+    pub fn bytes_per_second(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BandwidthError {
+    #[error(
+        "invalid bandwidth: `{0}`; expected a number of bytes per second (e.g., `10M` or `512KiB`)"
+    )]
+    Invalid(String),
+    #[error("bandwidth must be greater than zero: `{0}`")]
+    Zero(String),
+}
+
+impl FromStr for Bandwidth {
+    type Err = BandwidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let value = value.strip_suffix("/s").unwrap_or(value);
+        let bytes = parse_bytes(value).ok_or_else(|| BandwidthError::Invalid(s.to_string()))?;
+
+        Self::from_bytes_per_second(bytes).ok_or_else(|| BandwidthError::Zero(s.to_string()))
+    }
+}
+
+/// Parse a number of bytes, optionally with a decimal (`K`, `M`, `G`, `T`) or binary (`Ki`, `Mi`,
+/// `Gi`, `Ti`) unit prefix and an optional `B` suffix (e.g., `10M`, `512KiB`, or `1.5 GB`).
+pub(crate) fn parse_bytes(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let value = value.strip_suffix(['B', 'b']).unwrap_or(value).trim_end();
+
+    // Split the number from its unit prefix.
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let multiplier: u64 = match unit.trim_start().to_ascii_lowercase().as_str() {
+        "" => 1,
+        "k" => 1000,
+        "m" => 1000 * 1000,
+        "g" => 1000 * 1000 * 1000,
+        "t" => 1000 * 1000 * 1000 * 1000,
+        "ki" => 1024,
+        "mi" => 1024 * 1024,
+        "gi" => 1024 * 1024 * 1024,
+        "ti" => 1024 * 1024 * 1024 * 1024,
+        _ => return None,
+    };
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    match number.parse::<u64>() {
+        Ok(number) => Some(number.saturating_mul(multiplier)),
+        Err(_) => {
+            let number = number.parse::<f64>().ok()?;
+            Some((number * multiplier as f64) as u64)
+        }
+    }
+}
+
+impl std::fmt::Display for Bandwidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bandwidth {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .u64(|bytes| {
+                Bandwidth::from_bytes_per_second(bytes).ok_or_else(|| {
+                    serde::de::Error::custom(BandwidthError::Zero(bytes.to_string()))
+                })
+            })
+            .string(|string| Bandwidth::from_str(string).map_err(serde::de::Error::custom))
+            .deserialize(deserializer)
+    }
+}
+
+impl serde::Serialize for Bandwidth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Bandwidth {
+    fn schema_name() -> String {
+        "Bandwidth".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(
+                vec![
+                    schemars::schema::InstanceType::Integer,
+                    schemars::schema::InstanceType::String,
+                ]
+                .into(),
+            ),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A number of bytes per second, optionally with a unit (e.g., `10M` or `512KiB`)."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Bandwidth;
+
+    #[test]
+    fn parse() {
+        let bytes = |s: &str| Bandwidth::from_str(s).map(Bandwidth::bytes_per_second).ok();
+        assert_eq!(bytes("1024"), Some(1024));
+        assert_eq!(bytes("10M"), Some(10_000_000));
+        assert_eq!(bytes("10 MB/s"), Some(10_000_000));
+        assert_eq!(bytes("512KiB"), Some(512 * 1024));
+        assert_eq!(bytes("1.5mib"), Some(1_572_864));
+        assert_eq!(bytes("2G"), Some(2_000_000_000));
+        assert_eq!(bytes("0"), None);
+        assert_eq!(bytes("10X"), None);
+        assert_eq!(bytes("fast"), None);
+    }
+}
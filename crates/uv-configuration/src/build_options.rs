@@ -40,6 +40,10 @@ pub enum BuildOutput {
 pub struct BuildOptions {
     no_binary: NoBinary,
     no_build: NoBuild,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    prefer_binary: Vec<PackageName>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    binary_only: bool,
 }
 
 impl BuildOptions {
@@ -47,17 +51,49 @@ impl BuildOptions {
         Self {
             no_binary,
             no_build,
+            prefer_binary: Vec::new(),
+            binary_only: false,
         }
     }
 
+    /// Set the packages for which versions with pre-built wheels should be preferred.
+    #[must_use]
+    pub fn with_prefer_binary(mut self, prefer_binary: Vec<PackageName>) -> Self {
+        self.prefer_binary = prefer_binary;
+        self
+    }
+
+    /// Require pre-built wheels that are compatible with the current platform, even in universal
+    /// resolutions.
+    ///
+    /// Implies that no source distributions will be built.
+    #[must_use]
+    pub fn with_binary_only(mut self, binary_only: bool) -> Self {
+        if binary_only {
+            self.no_build = NoBuild::All;
+        }
+        self.binary_only = binary_only;
+        self
+    }
+
     #[must_use]
     pub fn combine(self, no_binary: NoBinary, no_build: NoBuild) -> Self {
         Self {
             no_binary: self.no_binary.combine(no_binary),
             no_build: self.no_build.combine(no_build),
+            prefer_binary: self.prefer_binary,
+            binary_only: self.binary_only,
         }
     }
 
+    /// Returns `true` if versions with pre-built wheels should be preferred for the given package.
+    ///
+    /// Unlike [`BuildOptions::no_build_package`], this is a preference: if no version with a
+    /// compatible wheel is available, the package may still be built from source.
+    pub fn prefer_binary_package(&self, package_name: &PackageName) -> bool {
+        self.prefer_binary.contains(package_name)
+    }
+
     pub fn no_binary_package(&self, package_name: &PackageName) -> bool {
         match &self.no_binary {
             NoBinary::None => false,
@@ -96,6 +132,12 @@ impl BuildOptions {
         }
     }
 
+    /// Returns `true` if the resolution should only select versions with wheels that are
+    /// compatible with the current platform, even in universal resolutions.
+    pub fn binary_only(&self) -> bool {
+        self.binary_only
+    }
+
     pub fn no_build_all(&self) -> bool {
         matches!(self.no_build, NoBuild::All)
     }
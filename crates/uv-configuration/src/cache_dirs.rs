@@ -0,0 +1,154 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer};
+use tracing::debug;
+
+/// The candidate locations of the cache directory, in order of preference.
+///
+/// Parsed from either a single path, or a list of paths (e.g.,
+/// `["$SCRATCH/uv-cache", "$HOME/.cache/uv"]`). Each path may reference environment variables as
+/// `$NAME` or `${NAME}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheDirs(Vec<String>);
+
+impl CacheDirs {
+    /// Return the first candidate that's writable, creating it if necessary.
+    ///
+    /// Candidates that reference an unset environment variable are skipped.
+    pub fn select(&self) -> Option<PathBuf> {
+        for candidate in &self.0 {
+            let Some(path) = expand_env_vars(candidate) else {
+                debug!("Skipping cache directory with unset environment variable: `{candidate}`");
+                continue;
+            };
+            let path = PathBuf::from(path);
+            match is_writable(&path) {
+                Ok(()) => return Some(path),
+                Err(err) => {
+                    debug!(
+                        "Skipping cache directory that isn't writable: `{}` ({err})",
+                        path.display()
+                    );
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Expand the environment variables in a path, returning `None` if any of them are unset.
+fn expand_env_vars(path: &str) -> Option<String> {
+    let mut expanded = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(index) = rest.find('$') {
+        expanded.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+
+        // Parse the name, as either `${NAME}` or `$NAME`.
+        let (name, remainder) = if let Some(braced) = rest.strip_prefix('{') {
+            let end = braced.find('}')?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            (&rest[..end], &rest[end..])
+        };
+
+        // A `$` that isn't followed by a name is retained as-is.
+        if name.is_empty() {
+            expanded.push('$');
+            continue;
+        }
+
+        expanded.push_str(&std::env::var(name).ok()?);
+        rest = remainder;
+    }
+    expanded.push_str(rest);
+    Some(expanded)
+}
+
+/// Returns `Ok(())` if files can be created in the directory, creating it if necessary.
+fn is_writable(path: &Path) -> io::Result<()> {
+    fs_err::create_dir_all(path)?;
+    let probe = path.join(format!(".uv-write-probe-{}", std::process::id()));
+    fs_err::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)?;
+    fs_err::remove_file(&probe)
+}
+
+impl<'de> Deserialize<'de> for CacheDirs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .string(|string| Ok(CacheDirs(vec![string.to_string()])))
+            .seq(|seq| seq.deserialize().map(CacheDirs))
+            .deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheDirs {
+    fn schema_name() -> String {
+        "CacheDirs".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(
+                vec![
+                    schemars::schema::InstanceType::String,
+                    schemars::schema::InstanceType::Array,
+                ]
+                .into(),
+            ),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A path to the cache directory, or a list of paths, of which the first \
+                     writable path is used."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_env_vars, CacheDirs};
+
+    #[test]
+    fn expand() {
+        let path = std::env::var("PATH").unwrap();
+        assert_eq!(expand_env_vars("$PATH/uv").unwrap(), format!("{path}/uv"));
+        assert_eq!(expand_env_vars("${PATH}uv").unwrap(), format!("{path}uv"));
+        assert_eq!(expand_env_vars("/tmp/$/uv").unwrap(), "/tmp/$/uv");
+        assert_eq!(expand_env_vars("/tmp/uv").unwrap(), "/tmp/uv");
+        assert_eq!(expand_env_vars("$UV_TEST_UNSET_VARIABLE/uv"), None);
+        assert_eq!(expand_env_vars("${PATH"), None);
+    }
+
+    #[test]
+    fn select() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("file");
+        fs_err::write(&file, "").unwrap();
+        let writable = temp_dir.path().join("cache");
+
+        let dirs = CacheDirs(vec![
+            "$UV_TEST_UNSET_VARIABLE/cache".to_string(),
+            file.join("cache").to_string_lossy().to_string(),
+            writable.to_string_lossy().to_string(),
+        ]);
+        assert_eq!(dirs.select(), Some(writable.clone()));
+        assert!(writable.is_dir());
+    }
+}
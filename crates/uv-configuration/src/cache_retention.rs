@@ -0,0 +1,214 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
+
+use uv_cache::CacheBucket;
+
+/// A period after which unused cache entries are removed.
+///
+/// Parsed from either an integer number of seconds, or a number with a unit of seconds (`s`),
+/// minutes (`m`), hours (`h`), days (`d`), or weeks (`w`) (e.g., `30d` or `12h`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetentionPeriod(Duration);
+
+impl RetentionPeriod {
+    /// The period as a [`Duration`].
+    pub fn duration(self) -> Duration {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid retention period: `{0}`; expected a number with a unit (e.g., `30d` or `12h`)")]
+pub struct RetentionPeriodError(String);
+
+impl FromStr for RetentionPeriod {
+    type Err = RetentionPeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let seconds: u64 = match unit.trim_start() {
+            "" | "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            _ => return Err(RetentionPeriodError(s.to_string())),
+        };
+        let number = number
+            .parse::<u64>()
+            .map_err(|_| RetentionPeriodError(s.to_string()))?;
+        Ok(Self(Duration::from_secs(number.saturating_mul(seconds))))
+    }
+}
+
+impl<'de> Deserialize<'de> for RetentionPeriod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .u64(|seconds| Ok(RetentionPeriod(Duration::from_secs(seconds))))
+            .string(|string| RetentionPeriod::from_str(string).map_err(serde::de::Error::custom))
+            .deserialize(deserializer)
+    }
+}
+
+/// The periods after which unused cache entries are removed, for each kind of entry.
+///
+/// Parsed from either a single period, which applies to every kind of entry (e.g., `"30d"`), or a
+/// table of periods by kind (e.g., `{ wheels = "30d", interpreters = "90d" }`).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CacheRetention {
+    /// Unzipped wheels, including those built from source distributions.
+    pub wheels: Option<RetentionPeriod>,
+    /// Source distributions, alongside the wheels built from them.
+    pub builds: Option<RetentionPeriod>,
+    /// Git and Mercurial repositories and checkouts.
+    pub repositories: Option<RetentionPeriod>,
+    /// Cached metadata of Python interpreters.
+    pub interpreters: Option<RetentionPeriod>,
+}
+
+impl CacheRetention {
+    /// Return the cache buckets with a retention period, alongside the period.
+    pub fn buckets(&self) -> Vec<(CacheBucket, Duration)> {
+        [
+            (CacheBucket::Archive, self.wheels),
+            (CacheBucket::SourceDistributions, self.builds),
+            (CacheBucket::Git, self.repositories),
+            (CacheBucket::Hg, self.repositories),
+            (CacheBucket::Interpreter, self.interpreters),
+        ]
+        .into_iter()
+        .filter_map(|(bucket, period)| Some((bucket, period?.duration())))
+        .collect()
+    }
+}
+
+impl From<RetentionPeriod> for CacheRetention {
+    fn from(period: RetentionPeriod) -> Self {
+        Self {
+            wheels: Some(period),
+            builds: Some(period),
+            repositories: Some(period),
+            interpreters: Some(period),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheRetention {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// The table form of the retention periods.
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+        struct Table {
+            wheels: Option<RetentionPeriod>,
+            builds: Option<RetentionPeriod>,
+            repositories: Option<RetentionPeriod>,
+            interpreters: Option<RetentionPeriod>,
+        }
+
+        serde_untagged::UntaggedEnumVisitor::new()
+            .u64(|seconds| Ok(RetentionPeriod(Duration::from_secs(seconds)).into()))
+            .string(|string| {
+                RetentionPeriod::from_str(string)
+                    .map(CacheRetention::from)
+                    .map_err(serde::de::Error::custom)
+            })
+            .map(|map| {
+                let table: Table = map.deserialize()?;
+                Ok(CacheRetention {
+                    wheels: table.wheels,
+                    builds: table.builds,
+                    repositories: table.repositories,
+                    interpreters: table.interpreters,
+                })
+            })
+            .deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheRetention {
+    fn schema_name() -> String {
+        "CacheRetention".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(
+                vec![
+                    schemars::schema::InstanceType::Integer,
+                    schemars::schema::InstanceType::String,
+                    schemars::schema::InstanceType::Object,
+                ]
+                .into(),
+            ),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A retention period (e.g., `30d`), or a table of retention periods by kind of \
+                     cache entry (e.g., `{ wheels = \"30d\", interpreters = \"90d\" }`)."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::{CacheRetention, RetentionPeriod};
+
+    #[test]
+    fn parse() {
+        let seconds = |s: &str| {
+            RetentionPeriod::from_str(s)
+                .map(|period| period.duration().as_secs())
+                .ok()
+        };
+        assert_eq!(seconds("60"), Some(60));
+        assert_eq!(seconds("90m"), Some(90 * 60));
+        assert_eq!(seconds("30d"), Some(30 * 24 * 60 * 60));
+        assert_eq!(seconds("2 w"), Some(2 * 7 * 24 * 60 * 60));
+        assert_eq!(seconds("1.5d"), None);
+        assert_eq!(seconds("30 days"), None);
+    }
+
+    #[test]
+    fn deserialize() {
+        #[derive(serde::Deserialize)]
+        struct Options {
+            retention: CacheRetention,
+        }
+
+        let options: Options = serde_json::from_str(r#"{"retention": "30d"}"#).unwrap();
+        assert_eq!(options.retention.buckets().len(), 5);
+
+        let options: Options =
+            serde_json::from_str(r#"{"retention": {"interpreters": "90d"}}"#).unwrap();
+        assert_eq!(
+            options.retention.buckets(),
+            vec![(
+                uv_cache::CacheBucket::Interpreter,
+                Duration::from_secs(90 * 24 * 60 * 60)
+            )]
+        );
+
+        assert!(serde_json::from_str::<Options>(r#"{"retention": {"archives": "90d"}}"#).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::bandwidth::parse_bytes;
+
+/// A limit on the total size of the cache, in bytes.
+///
+/// Parsed from either an integer number of bytes, or a number with a decimal (`K`, `M`, `G`, `T`)
+/// or binary (`Ki`, `Mi`, `Gi`, `Ti`) unit prefix and an optional `B` suffix (e.g., `10GB` or
+/// `512MiB`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CacheSize(u64);
+
+impl CacheSize {
+    /// Create a [`CacheSize`] from a number of bytes.
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// The number of bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid cache size: `{0}`; expected a number of bytes (e.g., `10GB` or `512MiB`)")]
+pub struct CacheSizeError(String);
+
+impl FromStr for CacheSize {
+    type Err = CacheSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_bytes(s)
+            .map(Self)
+            .ok_or_else(|| CacheSizeError(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for CacheSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .u64(|bytes| Ok(CacheSize(bytes)))
+            .string(|string| CacheSize::from_str(string).map_err(serde::de::Error::custom))
+            .deserialize(deserializer)
+    }
+}
+
+impl serde::Serialize for CacheSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheSize {
+    fn schema_name() -> String {
+        "CacheSize".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(
+                vec![
+                    schemars::schema::InstanceType::Integer,
+                    schemars::schema::InstanceType::String,
+                ]
+                .into(),
+            ),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A number of bytes, optionally with a unit (e.g., `10GB` or `512MiB`)."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::CacheSize;
+
+    #[test]
+    fn parse() {
+        let bytes = |s: &str| CacheSize::from_str(s).map(CacheSize::bytes).ok();
+        assert_eq!(bytes("0"), Some(0));
+        assert_eq!(bytes("10GB"), Some(10_000_000_000));
+        assert_eq!(bytes("512MiB"), Some(512 * 1024 * 1024));
+        assert_eq!(bytes("1.5 TiB"), Some(1_649_267_441_664));
+        assert_eq!(bytes("large"), None);
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::TrustedHost;
+
+/// Settings for retrying HTTP requests that fail with a transient error (e.g., a dropped
+/// connection or a `503` from a proxy).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HttpRetryOptions {
+    /// The maximum number of times to retry a failed request.
+    pub retries: Option<u32>,
+    /// Additional HTTP status codes to treat as transient, beyond `408`, `429`, and `5xx`.
+    pub status_codes: Option<Vec<u16>>,
+    /// The minimum delay before retrying a request, in seconds.
+    pub backoff_min: Option<f64>,
+    /// The maximum delay before retrying a request, in seconds.
+    pub backoff_max: Option<f64>,
+    /// The base of the exponential backoff between retries.
+    pub backoff_base: Option<u32>,
+    /// Overrides for requests to specific hosts.
+    pub hosts: Option<Vec<HostHttpRetryOptions>>,
+}
+
+/// Settings for retrying HTTP requests to a specific host, overriding the top-level settings.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HostHttpRetryOptions {
+    /// The host to which the settings apply, as a hostname (e.g., `proxy.example.com`), a
+    /// host-port pair (e.g., `proxy.example.com:8080`), or a URL (e.g.,
+    /// `https://proxy.example.com`).
+    pub host: TrustedHost,
+    /// The maximum number of times to retry a failed request.
+    pub retries: Option<u32>,
+    /// Additional HTTP status codes to treat as transient, beyond `408`, `429`, and `5xx`.
+    pub status_codes: Option<Vec<u16>>,
+    /// The minimum delay before retrying a request, in seconds.
+    pub backoff_min: Option<f64>,
+    /// The maximum delay before retrying a request, in seconds.
+    pub backoff_max: Option<f64>,
+    /// The base of the exponential backoff between retries.
+    pub backoff_base: Option<u32>,
+}
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+/// How to handle a package that's served by multiple indexes (or by an index mirror) that aren't
+/// linked by their repository metadata, as specified in PEP 708.
+///
+/// Indexes are linked if either one "tracks" the other (i.e., mirrors its project), or if each
+/// lists the other as an "alternate location" of the project.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IndexTracking {
+    /// Warn if an index declares repository metadata that doesn't link it to the other indexes
+    /// that serve the package, or if a mirror declares that it tracks a different repository.
+    #[default]
+    Warn,
+    /// Fail unless every index that serves the package is linked to the others, and every mirror
+    /// declares that it tracks the index it's mirroring.
+    Strict,
+    /// Don't validate the repository metadata of indexes.
+    Ignore,
+}
+
+impl FromStr for IndexTracking {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(Self::Warn),
+            "strict" => Ok(Self::Strict),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err(format!("unknown index tracking mode: `{s}`")),
+        }
+    }
+}
@@ -12,6 +12,9 @@ pub struct InstallOptions {
     pub no_install_workspace: bool,
     /// Omit the specified packages from the resolution.
     pub no_install_package: Vec<PackageName>,
+    /// Install locked wheels that are incompatible with the current platform, if no compatible
+    /// distribution is available.
+    pub allow_incompatible_wheels: bool,
 }
 
 impl InstallOptions {
@@ -24,9 +27,17 @@ impl InstallOptions {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            allow_incompatible_wheels: false,
         }
     }
 
+    /// Set whether to install locked wheels that are incompatible with the current platform.
+    #[must_use]
+    pub fn with_allow_incompatible_wheels(mut self, allow_incompatible_wheels: bool) -> Self {
+        self.allow_incompatible_wheels = allow_incompatible_wheels;
+        self
+    }
+
     /// Returns `true` if a package passes the install filters.
     pub fn include_package(
         &self,
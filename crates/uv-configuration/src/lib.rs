@@ -1,5 +1,10 @@
+pub use address_family::*;
 pub use authentication::*;
+pub use bandwidth::*;
 pub use build_options::*;
+pub use cache_dirs::*;
+pub use cache_retention::*;
+pub use cache_size::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
@@ -9,6 +14,8 @@ pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use http_retries::*;
+pub use index_tracking::*;
 pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
@@ -23,8 +30,13 @@ pub use trusted_host::*;
 pub use trusted_publishing::*;
 pub use vcs::*;
 
+mod address_family;
 mod authentication;
+mod bandwidth;
 mod build_options;
+mod cache_dirs;
+mod cache_retention;
+mod cache_size;
 mod concurrency;
 mod config_settings;
 mod constraints;
@@ -34,6 +46,8 @@ mod editable;
 mod export_format;
 mod extras;
 mod hash;
+mod http_retries;
+mod index_tracking;
 mod install_options;
 mod name_specifiers;
 mod overrides;
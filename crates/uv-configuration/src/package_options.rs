@@ -87,7 +87,7 @@ impl From<Reinstall> for Refresh {
         match value {
             Reinstall::None => Self::None(Timestamp::now()),
             Reinstall::All => Self::All(Timestamp::now()),
-            Reinstall::Packages(packages) => Self::Packages(packages, Timestamp::now()),
+            Reinstall::Packages(packages) => Self::Packages(packages, Vec::new(), Timestamp::now()),
         }
     }
 }
@@ -200,9 +200,11 @@ impl From<Upgrade> for Refresh {
         match value {
             Upgrade::None => Self::None(Timestamp::now()),
             Upgrade::All => Self::All(Timestamp::now()),
-            Upgrade::Packages(packages) => {
-                Self::Packages(packages.into_keys().collect::<Vec<_>>(), Timestamp::now())
-            }
+            Upgrade::Packages(packages) => Self::Packages(
+                packages.into_keys().collect::<Vec<_>>(),
+                Vec::new(),
+                Timestamp::now(),
+            ),
         }
     }
 }
@@ -4,6 +4,8 @@ use url::Url;
 
 /// A host specification (wildcard, or host, with optional scheme and/or port) for which
 /// certificates are not verified when making HTTPS requests.
+///
+/// The host may itself be a pattern that matches any subdomain, as in `*.example.com`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TrustedHost {
     Wildcard,
@@ -15,6 +17,15 @@ pub enum TrustedHost {
 }
 
 impl TrustedHost {
+    /// Return the [`TrustedHost`] matching the scheme, host, and port of the given [`Url`].
+    pub fn from_url(url: &Url) -> Option<Self> {
+        Some(Self::Host {
+            scheme: Some(url.scheme().to_string()),
+            host: url.host_str()?.to_string(),
+            port: url.port(),
+        })
+    }
+
     /// Returns `true` if the [`Url`] matches this trusted host.
     pub fn matches(&self, url: &Url) -> bool {
         match self {
@@ -28,11 +39,19 @@ impl TrustedHost {
                     return false;
                 }
 
-                if Some(host.as_str()) != url.host_str() {
+                let Some(url_host) = url.host_str() else {
                     return false;
+                };
+
+                // A host of the form `*.example.com` matches any subdomain of `example.com`, but
+                // not `example.com` itself.
+                if let Some(domain) = host.strip_prefix("*.") {
+                    return url_host
+                        .strip_suffix(domain)
+                        .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.'));
                 }
 
-                true
+                host == url_host
             }
         }
     }
@@ -206,5 +225,27 @@ mod tests {
                 port: None
             }
         );
+
+        assert_eq!(
+            "*.example.com:8443".parse::<super::TrustedHost>().unwrap(),
+            super::TrustedHost::Host {
+                scheme: None,
+                host: "*.example.com".to_string(),
+                port: Some(8443)
+            }
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let host = "*.example.com:8443".parse::<super::TrustedHost>().unwrap();
+        let matches = |url: &str| host.matches(&url::Url::parse(url).unwrap());
+
+        assert!(matches("https://pypi.example.com:8443/simple"));
+        assert!(matches("https://a.b.example.com:8443/simple"));
+        assert!(!matches("https://example.com:8443/simple"));
+        assert!(!matches("https://pypi.example.com/simple"));
+        assert!(!matches("https://pypiexample.com:8443/simple"));
+        assert!(!matches("https://pypi.example.com.evil.org:8443/simple"));
     }
 }
@@ -11,22 +11,23 @@ use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{debug, instrument, trace};
+use url::Url;
 use uv_build_backend::check_direct_build;
 use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, PreviewMode, Reinstall,
-    SourceStrategy,
+    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, KeyringProviderType,
+    PreviewMode, Reinstall, SourceStrategy,
 };
 use uv_configuration::{BuildOutput, Concurrency};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, Identifier, IndexCapabilities, IndexLocations,
+    CachedDist, DependencyMetadata, Identifier, Index, IndexCapabilities, IndexLocations,
     IsBuildBackendError, Name, Resolution, SourceDist, VersionOrUrlRef,
 };
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
 use uv_pypi_types::{Conflicts, Requirement};
 use uv_python::{Interpreter, PythonEnvironment};
@@ -34,6 +35,7 @@ use uv_resolver::{
     ExcludeNewer, FlatIndex, Flexibility, InMemoryIndex, Manifest, OptionsBuilder,
     PythonRequirement, Resolver, ResolverEnvironment,
 };
+use uv_static::EnvVars;
 use uv_types::{
     AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, EmptyInstalledPackages, HashStrategy,
     InFlight,
@@ -158,6 +160,80 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Return the environment variables that forward the index configuration (including any
+    /// known credentials) and the keyring provider to the build backend, such that any tools it
+    /// invokes to install packages (e.g., `pip`, or `uv` itself) can reach the same indexes.
+    ///
+    /// Variables that are already set in the environment are left untouched.
+    fn index_environment_variables(&self) -> FxHashMap<OsString, OsString> {
+        let mut variables = Vec::new();
+
+        if !self.index_locations.is_none() {
+            if let Some(index) = self.index_locations.default_index() {
+                let url = authenticated_url(index);
+                variables.push((EnvVars::UV_DEFAULT_INDEX, url.to_string()));
+                variables.push((EnvVars::PIP_INDEX_URL, url.to_string()));
+            }
+
+            let indexes = self
+                .index_locations
+                .implicit_indexes()
+                .map(authenticated_url)
+                .collect::<Vec<_>>();
+            if !indexes.is_empty() {
+                let indexes = indexes.iter().map(Url::as_str).join(" ");
+                variables.push((EnvVars::UV_INDEX, indexes.clone()));
+                variables.push((EnvVars::PIP_EXTRA_INDEX_URL, indexes));
+            }
+
+            let find_links = self
+                .index_locations
+                .flat_indexes()
+                .map(authenticated_url)
+                .collect::<Vec<_>>();
+            if !find_links.is_empty() {
+                variables.push((
+                    EnvVars::UV_FIND_LINKS,
+                    find_links.iter().map(Url::as_str).join(","),
+                ));
+                variables.push((
+                    EnvVars::PIP_FIND_LINKS,
+                    find_links.iter().map(Url::as_str).join(" "),
+                ));
+            }
+        }
+
+        match self.client.keyring() {
+            KeyringProviderType::Disabled => {}
+            KeyringProviderType::Subprocess => {
+                variables.push((EnvVars::UV_KEYRING_PROVIDER, "subprocess".to_string()));
+                variables.push((EnvVars::PIP_KEYRING_PROVIDER, "subprocess".to_string()));
+            }
+            // `pip` doesn't support the native credential store.
+            KeyringProviderType::Native => {
+                variables.push((EnvVars::UV_KEYRING_PROVIDER, "native".to_string()));
+            }
+        }
+
+        variables
+            .into_iter()
+            .filter(|(key, _)| std::env::var_os(key).is_none())
+            .map(|(key, value)| (OsString::from(key), OsString::from(value)))
+            .collect()
+    }
+}
+
+/// Return the URL of an index, with any known credentials applied.
+fn authenticated_url(index: &Index) -> Url {
+    let url = index.raw_url().clone();
+    match index.credentials() {
+        Some(credentials) => credentials.apply(url),
+        None => match uv_auth::cached_credentials(&url) {
+            Some(credentials) => credentials.apply(url),
+            None => url,
+        },
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -176,6 +252,10 @@ impl BuildContext for BuildDispatch<'_> {
         &self.shared_state.git
     }
 
+    fn hg(&self) -> &HgResolver {
+        &self.shared_state.hg
+    }
+
     fn capabilities(&self) -> &IndexCapabilities {
         &self.shared_state.capabilities
     }
@@ -405,6 +485,11 @@ impl BuildContext for BuildDispatch<'_> {
             build_stack.insert(dist.distribution_id());
         }
 
+        // Forward the index configuration to the build backend, giving precedence to any
+        // user-provided environment variables.
+        let mut environment_variables = self.index_environment_variables();
+        environment_variables.extend(self.build_extra_env_vars.clone());
+
         let builder = SourceBuild::setup(
             source,
             subdirectory,
@@ -421,7 +506,7 @@ impl BuildContext for BuildDispatch<'_> {
             self.build_isolation,
             &build_stack,
             build_kind,
-            self.build_extra_env_vars.clone(),
+            environment_variables,
             build_output,
             self.concurrency.builds,
         )
@@ -505,6 +590,8 @@ impl BuildContext for BuildDispatch<'_> {
 pub struct SharedState {
     /// The resolved Git references.
     git: GitResolver,
+    /// The resolved Mercurial revisions.
+    hg: HgResolver,
     /// The discovered capabilities for each registry index.
     capabilities: IndexCapabilities,
     /// The fetched package versions and metadata.
@@ -522,6 +609,7 @@ impl SharedState {
     pub fn fork(&self) -> Self {
         Self {
             git: self.git.clone(),
+            hg: self.hg.clone(),
             capabilities: self.capabilities.clone(),
             ..Default::default()
         }
@@ -532,6 +620,11 @@ impl SharedState {
         &self.git
     }
 
+    /// Return the [`HgResolver`] used by the [`SharedState`].
+    pub fn hg(&self) -> &HgResolver {
+        &self.hg
+    }
+
     /// Return the [`InMemoryIndex`] used by the [`SharedState`].
     pub fn index(&self) -> &InMemoryIndex {
         &self.index
@@ -3,13 +3,13 @@ use std::path::Path;
 
 use url::Url;
 use uv_distribution_filename::SourceDistExtension;
-use uv_git_types::GitUrl;
+use uv_git_types::{GitUrl, HgUrl};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::VerbatimUrl;
 
 use uv_normalize::PackageName;
 
-use crate::{DirectorySourceDist, GitSourceDist, Name, PathSourceDist, SourceDist};
+use crate::{DirectorySourceDist, GitSourceDist, HgSourceDist, Name, PathSourceDist, SourceDist};
 
 /// A reference to a source that can be built into a built distribution.
 ///
@@ -89,6 +89,7 @@ impl std::fmt::Display for BuildableSource<'_> {
 pub enum SourceUrl<'a> {
     Direct(DirectSourceUrl<'a>),
     Git(GitSourceUrl<'a>),
+    Hg(HgSourceUrl<'a>),
     Path(PathSourceUrl<'a>),
     Directory(DirectorySourceUrl<'a>),
 }
@@ -99,6 +100,7 @@ impl SourceUrl<'_> {
         match self {
             Self::Direct(dist) => dist.url,
             Self::Git(dist) => dist.url,
+            Self::Hg(dist) => dist.url,
             Self::Path(dist) => dist.url,
             Self::Directory(dist) => dist.url,
         }
@@ -123,6 +125,7 @@ impl std::fmt::Display for SourceUrl<'_> {
         match self {
             Self::Direct(url) => write!(f, "{url}"),
             Self::Git(url) => write!(f, "{url}"),
+            Self::Hg(url) => write!(f, "{url}"),
             Self::Path(url) => write!(f, "{url}"),
             Self::Directory(url) => write!(f, "{url}"),
         }
@@ -167,6 +170,31 @@ impl<'a> From<&'a GitSourceDist> for GitSourceUrl<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct HgSourceUrl<'a> {
+    /// The URL with the revision and subdirectory fragment.
+    pub url: &'a VerbatimUrl,
+    /// The URL without the revision and subdirectory fragment.
+    pub hg: &'a HgUrl,
+    pub subdirectory: Option<&'a Path>,
+}
+
+impl std::fmt::Display for HgSourceUrl<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{url}", url = self.url)
+    }
+}
+
+impl<'a> From<&'a HgSourceDist> for HgSourceUrl<'a> {
+    fn from(dist: &'a HgSourceDist) -> Self {
+        Self {
+            url: &dist.url,
+            hg: &dist.hg,
+            subdirectory: dist.subdirectory.as_deref(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PathSourceUrl<'a> {
     pub url: &'a Url,
@@ -98,6 +98,16 @@ impl CachedDist {
                 cache_info,
                 path,
             }),
+            Dist::Source(SourceDist::Hg(dist)) => Self::Url(CachedDirectUrlDist {
+                filename,
+                url: VerbatimParsedUrl {
+                    parsed_url: dist.parsed_url(),
+                    verbatim: dist.url,
+                },
+                hashes,
+                cache_info,
+                path,
+            }),
             Dist::Source(SourceDist::Path(dist)) => Self::Url(CachedDirectUrlDist {
                 filename,
                 url: VerbatimParsedUrl {
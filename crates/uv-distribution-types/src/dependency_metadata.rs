@@ -36,15 +36,15 @@ impl DependencyMetadata {
                 .inspect(|_| {
                     debug!("Found dependency metadata entry for `{package}=={version}`");
                 })
-                .or_else(|| versions.iter().find(|v| v.version.is_none()))
-                .inspect(|_| {
-                    debug!("Found global metadata entry for `{package}`");
+                .or_else(|| {
+                    versions.iter().find(|v| v.version.is_none()).inspect(|_| {
+                        debug!("Found global metadata entry for `{package}`");
+                    })
                 });
             let Some(metadata) = metadata else {
                 warn!("No dependency metadata entry found for `{package}=={version}`");
                 return None;
             };
-            debug!("Found dependency metadata entry for `{package}=={version}`");
             Some(ResolutionMetadata {
                 name: metadata.name.clone(),
                 version: version.clone(),
@@ -52,6 +52,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extras: metadata.provides_extras.clone(),
                 dynamic: false,
+                license_expression: None,
             })
         } else {
             // If no version was requested (i.e., it's a direct URL dependency), allow a single
@@ -72,6 +73,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extras: metadata.provides_extras.clone(),
                 dynamic: false,
+                license_expression: None,
             })
         }
     }
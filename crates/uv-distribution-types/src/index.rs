@@ -1,6 +1,7 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use jiff::Timestamp;
 use thiserror::Error;
 use url::Url;
 
@@ -82,6 +83,129 @@ pub struct Index {
     /// publish-url = "https://upload.pypi.org/legacy/"
     /// ```
     pub publish_url: Option<Url>,
+    /// The upload time to assume for files on the index that don't provide one.
+    ///
+    /// When resolving with `--exclude-newer`, files without an upload time are treated as
+    /// unavailable. For registries that don't report upload times (e.g., dated snapshots of PyPI),
+    /// a timestamp hint can be provided instead: files without an upload time are treated as if
+    /// they were uploaded at the given time, and are thus included whenever the `--exclude-newer`
+    /// cutoff is later than the hint.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "snapshot"
+    /// url = "https://mirror.example.com/2024-03-01/simple"
+    /// upload-time-fallback = "2024-03-01T00:00:00Z"
+    /// ```
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub upload_time_fallback: Option<Timestamp>,
+    /// The proxy to use for requests to the index.
+    ///
+    /// By default, uv respects the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY`, and `NO_PROXY`
+    /// environment variables. Setting `proxy` overrides the environment for all requests to the
+    /// index's host: a URL routes those requests through the given proxy, while `false` connects
+    /// to the index directly.
+    ///
+    /// For example, to reach an internal index directly while other requests go through the
+    /// proxy set in `HTTPS_PROXY`:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// proxy = false
+    /// ```
+    pub proxy: Option<IndexProxy>,
+    /// Mirrors of the index to fall back to, in order, if the index is unavailable.
+    ///
+    /// If a request to the index fails with a connection error or a server error (`5xx`), uv will
+    /// retry the request against each mirror in turn. A missing package (e.g., a `404`) does _not_
+    /// trigger a fallback.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// mirrors = ["https://pypi-backup.internal.example.com/simple"]
+    /// ```
+    #[serde(default)]
+    pub mirrors: Vec<IndexUrl>,
+    /// The path to a PEM file of additional certificate authorities to trust for requests to the
+    /// index's host (e.g., an internal index with a certificate issued by a private CA).
+    ///
+    /// The certificates are trusted in addition to the system or bundled root certificates.
+    /// Relative paths are resolved against the directory containing the configuration file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// ssl-ca-cert = "certs/internal-ca.pem"
+    /// ```
+    pub ssl_ca_cert: Option<PathBuf>,
+    /// The path to a PEM file containing a client certificate to present to the index's host, for
+    /// mutual TLS (mTLS) authentication.
+    ///
+    /// The file should also contain the certificate's private key, unless `ssl-client-key` is
+    /// provided. Takes precedence over the `SSL_CLIENT_CERT` environment variable for requests to
+    /// the index's host.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// ssl-client-cert = "certs/client.pem"
+    /// ssl-client-key = "certs/client.key"
+    /// ```
+    pub ssl_client_cert: Option<PathBuf>,
+    /// The path to a PEM file containing the private key for `ssl-client-cert`, if the key isn't
+    /// included in the certificate file itself.
+    pub ssl_client_key: Option<PathBuf>,
+    /// A helper to mint short-lived credentials for the index from the ambient cloud credentials
+    /// (e.g., an AWS profile, `gcloud auth login`, `az login`, or OIDC federation in CI).
+    ///
+    /// The credentials are minted on first use, and refreshed automatically when they're due to
+    /// expire or are rejected by the index.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "codeartifact"
+    /// url = "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/"
+    /// credential-helper = "aws-codeartifact"
+    /// ```
+    pub credential_helper: Option<IndexCredentialHelper>,
+    /// The maximum age, in seconds, of cached metadata from the index before it's revalidated.
+    ///
+    /// By default, uv respects the `Cache-Control` headers returned by the index. Setting
+    /// `cache-max-age` overrides them: cached pages are reused without contacting the index until
+    /// they reach the given age, after which uv revalidates them (via the `ETag` or
+    /// `Last-Modified` headers, if the index provides them). Use `0` to revalidate on every
+    /// request, e.g., for an internal index that publishes frequently but claims a long lifetime.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// cache-max-age = 60
+    /// ```
+    pub cache_max_age: Option<u64>,
+    /// Allow insecure connections to the index's host, skipping certificate verification.
+    ///
+    /// Unlike the global `allow-insecure-host` setting, this only affects requests to the host
+    /// of this index (including its scheme and port), such that certificates are still verified
+    /// for every other host.
+    ///
+    /// WARNING: Only allow insecure connections to an index in a secure network with verified
+    /// sources, as it bypasses SSL verification and could expose you to MITM attacks.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// allow-insecure-host = true
+    /// ```
+    #[serde(default)]
+    pub allow_insecure_host: bool,
 }
 
 // #[derive(
@@ -106,6 +230,15 @@ impl Index {
             default: true,
             origin: None,
             publish_url: None,
+            upload_time_fallback: None,
+            proxy: None,
+            mirrors: Vec::new(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            credential_helper: None,
+            cache_max_age: None,
+            allow_insecure_host: false,
         }
     }
 
@@ -118,6 +251,15 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            upload_time_fallback: None,
+            proxy: None,
+            mirrors: Vec::new(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            credential_helper: None,
+            cache_max_age: None,
+            allow_insecure_host: false,
         }
     }
 
@@ -130,6 +272,15 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            upload_time_fallback: None,
+            proxy: None,
+            mirrors: Vec::new(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            credential_helper: None,
+            cache_max_age: None,
+            allow_insecure_host: false,
         }
     }
 
@@ -196,6 +347,23 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        for mirror in &mut self.mirrors {
+            if let IndexUrl::Path(url) = mirror {
+                if let Some(given) = url.given() {
+                    *mirror = IndexUrl::parse(given, Some(root_dir))?;
+                }
+            }
+        }
+        for path in [
+            &mut self.ssl_ca_cert,
+            &mut self.ssl_client_cert,
+            &mut self.ssl_client_key,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = root_dir.join(&*path);
+        }
         Ok(self)
     }
 }
@@ -216,6 +384,15 @@ impl FromStr for Index {
                     default: false,
                     origin: None,
                     publish_url: None,
+                    upload_time_fallback: None,
+                    proxy: None,
+                    mirrors: Vec::new(),
+                    ssl_ca_cert: None,
+                    ssl_client_cert: None,
+                    ssl_client_key: None,
+                    credential_helper: None,
+                    cache_max_age: None,
+                    allow_insecure_host: false,
                 });
             }
         }
@@ -229,10 +406,135 @@ impl FromStr for Index {
             default: false,
             origin: None,
             publish_url: None,
+            upload_time_fallback: None,
+            proxy: None,
+            mirrors: Vec::new(),
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            credential_helper: None,
+            cache_max_age: None,
+            allow_insecure_host: false,
         })
     }
 }
 
+/// The proxy configuration for an [`Index`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum IndexProxy {
+    /// Route requests to the index through the given proxy.
+    Url(Url),
+    /// Connect to the index directly, ignoring any proxy set in the environment.
+    Direct,
+}
+
+impl IndexProxy {
+    /// Return the proxy [`Url`], if requests should be routed through a proxy.
+    pub fn url(&self) -> Option<&Url> {
+        match self {
+            Self::Url(url) => Some(url),
+            Self::Direct => None,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IndexProxy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = IndexProxy;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a proxy URL or `false`")
+            }
+
+            fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                if v {
+                    Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Bool(v),
+                        &self,
+                    ))
+                } else {
+                    Ok(IndexProxy::Direct)
+                }
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Url::parse(v)
+                    .map(IndexProxy::Url)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl serde::Serialize for IndexProxy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Url(url) => serializer.serialize_str(url.as_str()),
+            Self::Direct => serializer.serialize_bool(false),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for IndexProxy {
+    fn schema_name() -> String {
+        "IndexProxy".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(
+                vec![
+                    schemars::schema::InstanceType::String,
+                    schemars::schema::InstanceType::Boolean,
+                ]
+                .into(),
+            ),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A proxy URL (e.g., `http://proxy.example.com:3128`), or `false` to connect directly.".to_string()),
+              ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// A helper to mint short-lived credentials for an [`Index`].
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexCredentialHelper {
+    /// AWS CodeArtifact, via `aws codeartifact get-authorization-token`.
+    #[serde(rename = "aws-codeartifact")]
+    AwsCodeArtifact,
+    /// Google Cloud Artifact Registry, via `gcloud auth print-access-token`.
+    GcpArtifactRegistry,
+    /// Azure Artifacts, via `az account get-access-token`.
+    AzureArtifacts,
+}
+
+impl From<IndexCredentialHelper> for uv_auth::TokenProvider {
+    fn from(helper: IndexCredentialHelper) -> Self {
+        match helper {
+            IndexCredentialHelper::AwsCodeArtifact => Self::AwsCodeArtifact,
+            IndexCredentialHelper::GcpArtifactRegistry => Self::GcpArtifactRegistry,
+            IndexCredentialHelper::AzureArtifacts => Self::AzureArtifacts,
+        }
+    }
+}
+
 /// An error that can occur when parsing an [`Index`].
 #[derive(Error, Debug)]
 pub enum IndexSourceError {
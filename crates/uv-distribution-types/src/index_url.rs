@@ -6,6 +6,7 @@ use std::str::FromStr;
 use std::sync::{Arc, LazyLock, RwLock};
 
 use itertools::Either;
+use jiff::Timestamp;
 use rustc_hash::{FxHashMap, FxHashSet};
 use thiserror::Error;
 use url::{ParseError, Url};
@@ -43,6 +44,10 @@ impl IndexUrl {
                         // Ex) `https://pypi.org/simple`
                         VerbatimUrl::parse_url(path)?
                     }
+                    None if matches!(scheme, "s3" | "gs") => {
+                        // Ex) `s3://my-bucket/simple`
+                        VerbatimUrl::parse_url(path)?
+                    }
                     None => {
                         // Ex) `C:\Users\user\index`
                         if let Some(root_dir) = root_dir {
@@ -233,6 +238,7 @@ pub struct IndexLocations {
     indexes: Vec<Index>,
     flat_index: Vec<Index>,
     no_index: bool,
+    strict_index: bool,
 }
 
 impl IndexLocations {
@@ -242,6 +248,17 @@ impl IndexLocations {
             indexes,
             flat_index,
             no_index,
+            strict_index: false,
+        }
+    }
+
+    /// Disallow falling back to the default PyPI index, such that packages must be resolved from
+    /// an explicitly configured index.
+    #[must_use]
+    pub fn with_strict_index(self, strict_index: bool) -> Self {
+        Self {
+            strict_index,
+            ..self
         }
     }
 
@@ -257,6 +274,7 @@ impl IndexLocations {
             indexes: self.indexes.into_iter().chain(indexes).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            strict_index: self.strict_index,
         }
     }
 
@@ -272,7 +290,7 @@ impl<'a> IndexLocations {
     ///
     /// If `--no-index` is set, return `None`.
     ///
-    /// If no index is provided, use the `PyPI` index.
+    /// If no index is provided, use the `PyPI` index, unless `--strict-index` is set.
     pub fn default_index(&'a self) -> Option<&'a Index> {
         if self.no_index {
             None
@@ -282,7 +300,7 @@ impl<'a> IndexLocations {
                 .iter()
                 .filter(move |index| index.name.as_ref().is_none_or(|name| seen.insert(name)))
                 .find(|index| index.default)
-                .or_else(|| Some(&DEFAULT_INDEX))
+                .or_else(|| (!self.strict_index).then_some(&*DEFAULT_INDEX))
         }
     }
 
@@ -333,6 +351,13 @@ impl<'a> IndexLocations {
         }
     }
 
+    /// Return the upload time to assume for files from the given index that don't provide one.
+    pub fn upload_time_fallback(&'a self, url: &IndexUrl) -> Option<Timestamp> {
+        self.simple_indexes()
+            .find(|index| index.url() == url)
+            .and_then(|index| index.upload_time_fallback)
+    }
+
     /// Return an iterator over the [`FlatIndexLocation`] entries.
     pub fn flat_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         self.flat_index.iter()
@@ -343,11 +368,17 @@ impl<'a> IndexLocations {
         self.no_index
     }
 
+    /// Return the `--strict-index` flag.
+    pub fn strict_index(&self) -> bool {
+        self.strict_index
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
             indexes: self.indexes.clone(),
             no_index: self.no_index,
+            strict_index: self.strict_index,
         }
     }
 
@@ -379,7 +410,7 @@ impl<'a> IndexLocations {
                 }
                 indexes.push(index);
             }
-            if !default {
+            if !default && !self.strict_index {
                 indexes.push(&*DEFAULT_INDEX);
             }
 
@@ -397,6 +428,7 @@ impl<'a> IndexLocations {
 pub struct IndexUrls {
     indexes: Vec<Index>,
     no_index: bool,
+    strict_index: bool,
 }
 
 impl<'a> IndexUrls {
@@ -404,6 +436,7 @@ impl<'a> IndexUrls {
         Self {
             indexes,
             no_index: false,
+            strict_index: false,
         }
     }
 
@@ -411,7 +444,7 @@ impl<'a> IndexUrls {
     ///
     /// If `--no-index` is set, return `None`.
     ///
-    /// If no index is provided, use the `PyPI` index.
+    /// If no index is provided, use the `PyPI` index, unless `--strict-index` is set.
     fn default_index(&'a self) -> Option<&'a Index> {
         if self.no_index {
             None
@@ -421,7 +454,7 @@ impl<'a> IndexUrls {
                 .iter()
                 .filter(move |index| index.name.as_ref().is_none_or(|name| seen.insert(name)))
                 .find(|index| index.default)
-                .or_else(|| Some(&DEFAULT_INDEX))
+                .or_else(|| (!self.strict_index).then_some(&*DEFAULT_INDEX))
         }
     }
 
@@ -455,6 +488,18 @@ impl<'a> IndexUrls {
             .filter(|index| !index.explicit)
     }
 
+    /// Return the [`Index`] entry with the given URL, if any.
+    pub fn find(&'a self, url: &IndexUrl) -> Option<&'a Index> {
+        self.indexes.iter().find(|index| index.url() == url)
+    }
+
+    /// Return the mirrors to fall back to if the given index is unavailable.
+    pub fn mirrors(&'a self, url: &IndexUrl) -> &'a [IndexUrl] {
+        self.find(url)
+            .map(|index| index.mirrors.as_slice())
+            .unwrap_or_default()
+    }
+
     /// Return an iterator over all user-defined [`Index`] entries in order.
     ///
     /// Prioritizes the `[tool.uv.index]` definitions over the `--extra-index-url` definitions
@@ -503,14 +548,20 @@ bitflags::bitflags! {
         const UNAUTHORIZED      = 1 << 2;
         /// Whether the index returned a `403 Forbidden` status code.
         const FORBIDDEN         = 1 << 1;
+        /// Whether the index serves the HTML-based Simple API only (i.e., not PEP 691).
+        const NO_JSON_API       = 1 << 3;
+        /// Whether the index omits upload times from its Simple API responses (i.e., PEP 700).
+        const NO_UPLOAD_TIME    = 1 << 4;
+        /// Whether the capabilities detected in a previous run have been loaded for the index.
+        const LOADED            = 1 << 5;
     }
 }
 
 /// A map of [`IndexUrl`]s to their capabilities.
 ///
 /// We only store indexes that lack capabilities (i.e., don't support range requests, aren't
-/// authorized). The benefit is that the map is almost always empty, so validating capabilities is
-/// extremely cheap.
+/// authorized) or whose capabilities were loaded from a previous run. The benefit is that the map
+/// is almost always small, so validating capabilities is extremely cheap.
 #[derive(Debug, Default, Clone)]
 pub struct IndexCapabilities(Arc<RwLock<FxHashMap<IndexUrl, Flags>>>);
 
@@ -572,4 +623,45 @@ impl IndexCapabilities {
             .or_insert(Flags::empty())
             .insert(Flags::FORBIDDEN);
     }
+
+    /// Returns `true` if the given [`IndexUrl`] serves the JSON-based Simple API (PEP 691), or
+    /// if it hasn't been determined.
+    pub fn supports_json_api(&self, index_url: &IndexUrl) -> bool {
+        !self
+            .0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|flags| flags.intersects(Flags::NO_JSON_API))
+    }
+
+    /// Returns `true` if the given [`IndexUrl`] reports the upload time of its files (PEP 700),
+    /// or if it hasn't been determined.
+    pub fn provides_upload_time(&self, index_url: &IndexUrl) -> bool {
+        !self
+            .0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|flags| flags.intersects(Flags::NO_UPLOAD_TIME))
+    }
+
+    /// Record the Simple API features supported by an [`IndexUrl`], as detected from one of its
+    /// responses.
+    pub fn set_simple_api(&self, index_url: IndexUrl, json_api: bool, upload_time: bool) {
+        let mut map = self.0.write().unwrap();
+        let flags = map.entry(index_url).or_insert(Flags::empty());
+        flags.set(Flags::NO_JSON_API, !json_api);
+        flags.set(Flags::NO_UPLOAD_TIME, !upload_time);
+    }
+
+    /// Mark the capabilities of an [`IndexUrl`] from a previous run as loaded, returning `true`
+    /// if they weren't already.
+    pub fn set_loaded(&self, index_url: IndexUrl) -> bool {
+        let mut map = self.0.write().unwrap();
+        let flags = map.entry(index_url).or_insert(Flags::empty());
+        let loaded = flags.contains(Flags::LOADED);
+        flags.insert(Flags::LOADED);
+        !loaded
+    }
 }
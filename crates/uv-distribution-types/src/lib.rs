@@ -13,10 +13,11 @@
 //!   * [`RegistryBuiltDist`]
 //!   * [`DirectUrlBuiltDist`]
 //!   * [`PathBuiltDist`]
-//! * [`SourceDist`]: A source distribution, with its four possible origins:
+//! * [`SourceDist`]: A source distribution, with its possible origins:
 //!   * [`RegistrySourceDist`]
 //!   * [`DirectUrlSourceDist`]
 //!   * [`GitSourceDist`]
+//!   * [`HgSourceDist`]
 //!   * [`PathSourceDist`]
 //!
 //! ## `CachedDist`
@@ -43,12 +44,13 @@ use uv_distribution_filename::{
     DistExtension, SourceDistExtension, SourceDistFilename, WheelFilename,
 };
 use uv_fs::normalize_absolute_path;
-use uv_git_types::GitUrl;
+use uv_git_types::{GitUrl, HgUrl};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::{Pep508Url, VerbatimUrl};
 use uv_pypi_types::{
-    ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedPathUrl, ParsedUrl, VerbatimParsedUrl,
+    ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedHgUrl, ParsedPathUrl, ParsedUrl,
+    VerbatimParsedUrl,
 };
 
 pub use crate::annotation::*;
@@ -74,6 +76,7 @@ pub use crate::requested::*;
 pub use crate::resolution::*;
 pub use crate::resolved::*;
 pub use crate::specified_requirement::*;
+pub use crate::torch::*;
 pub use crate::traits::*;
 
 mod annotation;
@@ -99,6 +102,7 @@ mod requested;
 mod resolution;
 mod resolved;
 mod specified_requirement;
+mod torch;
 mod traits;
 
 #[derive(Debug, Clone)]
@@ -198,13 +202,14 @@ pub enum BuiltDist {
     Path(PathBuiltDist),
 }
 
-/// A source distribution, with its possible origins (index, url, path, git)
+/// A source distribution, with its possible origins (index, url, path, git, hg)
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 #[allow(clippy::large_enum_variant)]
 pub enum SourceDist {
     Registry(RegistrySourceDist),
     DirectUrl(DirectUrlSourceDist),
     Git(GitSourceDist),
+    Hg(HgSourceDist),
     Path(PathSourceDist),
     Directory(DirectorySourceDist),
 }
@@ -316,6 +321,19 @@ pub struct GitSourceDist {
     pub url: VerbatimUrl,
 }
 
+/// A source distribution that exists in a Mercurial repository.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct HgSourceDist {
+    pub name: PackageName,
+    /// The URL without the revision and subdirectory fragment.
+    pub hg: Box<HgUrl>,
+    /// The subdirectory within the Mercurial repository in which the source distribution is
+    /// located.
+    pub subdirectory: Option<PathBuf>,
+    /// The URL as it was provided by the user, including the revision and subdirectory fragment.
+    pub url: VerbatimUrl,
+}
+
 /// A source distribution that exists in a local archive (e.g., a `.tar.gz` file).
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct PathSourceDist {
@@ -484,6 +502,21 @@ impl Dist {
         })))
     }
 
+    /// A remote source distribution from a `hg+https://` or `hg+ssh://` url.
+    pub fn from_hg_url(
+        name: PackageName,
+        url: VerbatimUrl,
+        hg: HgUrl,
+        subdirectory: Option<PathBuf>,
+    ) -> Result<Dist, Error> {
+        Ok(Self::Source(SourceDist::Hg(HgSourceDist {
+            name,
+            hg: Box::new(hg),
+            subdirectory,
+            url,
+        })))
+    }
+
     /// Create a [`Dist`] for a URL-based distribution.
     pub fn from_url(name: PackageName, url: VerbatimParsedUrl) -> Result<Self, Error> {
         match url.parsed_url {
@@ -507,6 +540,7 @@ impl Dist {
             ParsedUrl::Git(git) => {
                 Self::from_git_url(name, url.verbatim, git.url, git.subdirectory)
             }
+            ParsedUrl::Hg(hg) => Self::from_hg_url(name, url.verbatim, hg.url, hg.subdirectory),
         }
     }
 
@@ -617,7 +651,11 @@ impl SourceDist {
     pub fn index(&self) -> Option<&IndexUrl> {
         match self {
             Self::Registry(registry) => Some(&registry.index),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
@@ -625,14 +663,22 @@ impl SourceDist {
     pub fn file(&self) -> Option<&File> {
         match self {
             Self::Registry(registry) => Some(&registry.file),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
     pub fn version(&self) -> Option<&Version> {
         match self {
             Self::Registry(source_dist) => Some(&source_dist.version),
-            Self::DirectUrl(_) | Self::Git(_) | Self::Path(_) | Self::Directory(_) => None,
+            Self::DirectUrl(_)
+            | Self::Git(_)
+            | Self::Hg(_)
+            | Self::Path(_)
+            | Self::Directory(_) => None,
         }
     }
 
@@ -720,6 +766,16 @@ impl GitSourceDist {
     }
 }
 
+impl HgSourceDist {
+    /// Return the [`ParsedUrl`] for the distribution.
+    pub fn parsed_url(&self) -> ParsedUrl {
+        ParsedUrl::Hg(ParsedHgUrl::from_source(
+            (*self.hg).clone(),
+            self.subdirectory.clone(),
+        ))
+    }
+}
+
 impl DirectorySourceDist {
     /// Return the [`ParsedUrl`] for the distribution.
     pub fn parsed_url(&self) -> ParsedUrl {
@@ -774,6 +830,12 @@ impl Name for GitSourceDist {
     }
 }
 
+impl Name for HgSourceDist {
+    fn name(&self) -> &PackageName {
+        &self.name
+    }
+}
+
 impl Name for PathSourceDist {
     fn name(&self) -> &PackageName {
         &self.name
@@ -792,6 +854,7 @@ impl Name for SourceDist {
             Self::Registry(dist) => dist.name(),
             Self::DirectUrl(dist) => dist.name(),
             Self::Git(dist) => dist.name(),
+            Self::Hg(dist) => dist.name(),
             Self::Path(dist) => dist.name(),
             Self::Directory(dist) => dist.name(),
         }
@@ -859,6 +922,12 @@ impl DistributionMetadata for GitSourceDist {
     }
 }
 
+impl DistributionMetadata for HgSourceDist {
+    fn version_or_url(&self) -> VersionOrUrlRef {
+        VersionOrUrlRef::Url(&self.url)
+    }
+}
+
 impl DistributionMetadata for PathSourceDist {
     fn version_or_url(&self) -> VersionOrUrlRef {
         VersionOrUrlRef::Url(&self.url)
@@ -877,6 +946,7 @@ impl DistributionMetadata for SourceDist {
             Self::Registry(dist) => dist.version_or_url(),
             Self::DirectUrl(dist) => dist.version_or_url(),
             Self::Git(dist) => dist.version_or_url(),
+            Self::Hg(dist) => dist.version_or_url(),
             Self::Path(dist) => dist.version_or_url(),
             Self::Directory(dist) => dist.version_or_url(),
         }
@@ -1029,6 +1099,32 @@ impl RemoteSource for GitSourceDist {
     }
 }
 
+impl RemoteSource for HgSourceDist {
+    fn filename(&self) -> Result<Cow<'_, str>, Error> {
+        // The filename is the last segment of the URL, before any `@`.
+        match self.url.filename()? {
+            Cow::Borrowed(filename) => {
+                if let Some((_, filename)) = filename.rsplit_once('@') {
+                    Ok(Cow::Borrowed(filename))
+                } else {
+                    Ok(Cow::Borrowed(filename))
+                }
+            }
+            Cow::Owned(filename) => {
+                if let Some((_, filename)) = filename.rsplit_once('@') {
+                    Ok(Cow::Owned(filename.to_owned()))
+                } else {
+                    Ok(Cow::Owned(filename))
+                }
+            }
+        }
+    }
+
+    fn size(&self) -> Option<u64> {
+        self.url.size()
+    }
+}
+
 impl RemoteSource for PathBuiltDist {
     fn filename(&self) -> Result<Cow<'_, str>, Error> {
         self.url.filename()
@@ -1065,6 +1161,7 @@ impl RemoteSource for SourceDist {
             Self::Registry(dist) => dist.filename(),
             Self::DirectUrl(dist) => dist.filename(),
             Self::Git(dist) => dist.filename(),
+            Self::Hg(dist) => dist.filename(),
             Self::Path(dist) => dist.filename(),
             Self::Directory(dist) => dist.filename(),
         }
@@ -1075,6 +1172,7 @@ impl RemoteSource for SourceDist {
             Self::Registry(dist) => dist.size(),
             Self::DirectUrl(dist) => dist.size(),
             Self::Git(dist) => dist.size(),
+            Self::Hg(dist) => dist.size(),
             Self::Path(dist) => dist.size(),
             Self::Directory(dist) => dist.size(),
         }
@@ -1263,12 +1361,23 @@ impl Identifier for GitSourceDist {
     }
 }
 
+impl Identifier for HgSourceDist {
+    fn distribution_id(&self) -> DistributionId {
+        self.url.distribution_id()
+    }
+
+    fn resource_id(&self) -> ResourceId {
+        self.url.resource_id()
+    }
+}
+
 impl Identifier for SourceDist {
     fn distribution_id(&self) -> DistributionId {
         match self {
             Self::Registry(dist) => dist.distribution_id(),
             Self::DirectUrl(dist) => dist.distribution_id(),
             Self::Git(dist) => dist.distribution_id(),
+            Self::Hg(dist) => dist.distribution_id(),
             Self::Path(dist) => dist.distribution_id(),
             Self::Directory(dist) => dist.distribution_id(),
         }
@@ -1279,6 +1388,7 @@ impl Identifier for SourceDist {
             Self::Registry(dist) => dist.resource_id(),
             Self::DirectUrl(dist) => dist.resource_id(),
             Self::Git(dist) => dist.resource_id(),
+            Self::Hg(dist) => dist.resource_id(),
             Self::Path(dist) => dist.resource_id(),
             Self::Directory(dist) => dist.resource_id(),
         }
@@ -1349,6 +1459,16 @@ impl Identifier for GitSourceUrl<'_> {
     }
 }
 
+impl Identifier for HgSourceUrl<'_> {
+    fn distribution_id(&self) -> DistributionId {
+        self.url.distribution_id()
+    }
+
+    fn resource_id(&self) -> ResourceId {
+        self.url.resource_id()
+    }
+}
+
 impl Identifier for PathSourceUrl<'_> {
     fn distribution_id(&self) -> DistributionId {
         self.url.distribution_id()
@@ -1374,6 +1494,7 @@ impl Identifier for SourceUrl<'_> {
         match self {
             Self::Direct(url) => url.distribution_id(),
             Self::Git(url) => url.distribution_id(),
+            Self::Hg(url) => url.distribution_id(),
             Self::Path(url) => url.distribution_id(),
             Self::Directory(url) => url.distribution_id(),
         }
@@ -1383,6 +1504,7 @@ impl Identifier for SourceUrl<'_> {
         match self {
             Self::Direct(url) => url.resource_id(),
             Self::Git(url) => url.resource_id(),
+            Self::Hg(url) => url.resource_id(),
             Self::Path(url) => url.resource_id(),
             Self::Directory(url) => url.resource_id(),
         }
@@ -100,6 +100,17 @@ impl CompatibleDist<'_> {
             CompatibleDist::IncompatibleWheel { prioritized, .. } => prioritized.0.markers,
         }
     }
+
+    /// Return the set of platforms supported by the compatible wheels of the distribution, in
+    /// terms of their markers, ignoring any source distribution.
+    pub fn wheel_markers(&self) -> MarkerTree {
+        match self {
+            CompatibleDist::InstalledDist(_) => MarkerTree::TRUE,
+            CompatibleDist::SourceDist { prioritized, .. } => prioritized.wheel_markers(),
+            CompatibleDist::CompatibleWheel { prioritized, .. } => prioritized.wheel_markers(),
+            CompatibleDist::IncompatibleWheel { prioritized, .. } => prioritized.wheel_markers(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -528,6 +539,18 @@ impl PrioritizedDist {
         Some(sdist)
     }
 
+    /// Returns the set of platforms supported by the compatible wheels in
+    /// this prioritized distribution, in terms of their markers.
+    pub fn wheel_markers(&self) -> MarkerTree {
+        let mut markers = MarkerTree::FALSE;
+        for (wheel, compatibility) in &self.0.wheels {
+            if compatibility.is_compatible() {
+                markers.or(implied_markers(&wheel.filename));
+            }
+        }
+        markers
+    }
+
     /// Returns the "best" wheel in this prioritized distribution, if one
     /// exists.
     pub fn best_wheel(&self) -> Option<&(RegistryBuiltWheel, WheelCompatibility)> {
@@ -750,7 +773,57 @@ impl IncompatibleWheel {
 ///
 /// This is roughly the inverse of platform tag generation: given a tag, we want to infer the
 /// supported platforms (rather than generating the supported tags from a given platform).
+///
+/// The markers cover both the operating system and architecture (via the platform tags) and the
+/// Python implementation (via the Python and ABI tags).
 pub fn implied_markers(filename: &WheelFilename) -> MarkerTree {
+    let mut marker = implied_platform_markers(filename);
+    marker.and(implied_implementation_markers(filename));
+    marker
+}
+
+/// Given a wheel filename, determine the set of supported Python implementations, in terms of
+/// their markers.
+///
+/// Implementations that can't be expressed precisely via `platform_python_implementation` (e.g.,
+/// GraalPy or Pyston) are treated as unconstrained.
+fn implied_implementation_markers(filename: &WheelFilename) -> MarkerTree {
+    let implementation = |value: &'static str| {
+        MarkerTree::expression(MarkerExpression::String {
+            key: MarkerValueString::PlatformPythonImplementation,
+            operator: MarkerOperator::Equal,
+            value: ArcStr::from(value),
+        })
+    };
+
+    let mut marker = MarkerTree::FALSE;
+    for abi_tag in filename.abi_tags() {
+        match abi_tag {
+            // Without an ABI, the implementation is determined by the Python tag (e.g., `py3` is
+            // compatible with any implementation, while `cp312` is specific to CPython).
+            AbiTag::None => {
+                for python_tag in filename.python_tags() {
+                    match python_tag {
+                        LanguageTag::CPython { .. } => marker.or(implementation("CPython")),
+                        LanguageTag::PyPy { .. } => marker.or(implementation("PyPy")),
+                        LanguageTag::None
+                        | LanguageTag::Python { .. }
+                        | LanguageTag::GraalPy { .. }
+                        | LanguageTag::Pyston { .. } => return MarkerTree::TRUE,
+                    }
+                }
+            }
+            AbiTag::Abi3 | AbiTag::CPython { .. } => marker.or(implementation("CPython")),
+            AbiTag::PyPy { .. } => marker.or(implementation("PyPy")),
+            AbiTag::GraalPy { .. } | AbiTag::Pyston { .. } => return MarkerTree::TRUE,
+        }
+    }
+    marker
+}
+
+/// Given a wheel filename, determine the set of supported operating systems and architectures, in
+/// terms of their markers.
+fn implied_platform_markers(filename: &WheelFilename) -> MarkerTree {
     let mut marker = MarkerTree::FALSE;
     for platform_tag in filename.platform_tags() {
         match platform_tag {
@@ -871,39 +944,51 @@ mod tests {
 
         assert_markers(
             "example-1.0-cp310-cp310-win32.whl",
-            "sys_platform == 'win32' and platform_machine == 'x86'",
+            "sys_platform == 'win32' and platform_machine == 'x86' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp313-cp313t-win_amd64.whl",
-            "sys_platform == 'win32' and platform_machine == 'x86_64'",
+            "sys_platform == 'win32' and platform_machine == 'x86_64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp313-cp313t-win_arm64.whl",
-            "sys_platform == 'win32' and platform_machine == 'arm64'",
+            "sys_platform == 'win32' and platform_machine == 'arm64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp313-cp313t-manylinux_2_17_aarch64.manylinux2014_aarch64.whl",
-            "sys_platform == 'linux' and platform_machine == 'aarch64'",
+            "sys_platform == 'linux' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp313-cp313t-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
-            "sys_platform == 'linux' and platform_machine == 'x86_64'",
+            "sys_platform == 'linux' and platform_machine == 'x86_64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp312-cp312-musllinux_1_2_aarch64.whl",
-            "sys_platform == 'linux' and platform_machine == 'aarch64'",
+            "sys_platform == 'linux' and platform_machine == 'aarch64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp310-cp310-macosx_14_0_x86_64.whl",
-            "sys_platform == 'darwin' and platform_machine == 'x86_64'",
+            "sys_platform == 'darwin' and platform_machine == 'x86_64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp310-cp310-macosx_10_9_x86_64.whl",
-            "sys_platform == 'darwin' and platform_machine == 'x86_64'",
+            "sys_platform == 'darwin' and platform_machine == 'x86_64' and platform_python_implementation == 'CPython'",
         );
         assert_markers(
             "numpy-2.2.1-cp310-cp310-macosx_11_0_arm64.whl",
-            "sys_platform == 'darwin' and platform_machine == 'arm64'",
+            "sys_platform == 'darwin' and platform_machine == 'arm64' and platform_python_implementation == 'CPython'",
+        );
+        assert_markers(
+            "example-1.0-cp39-abi3-any.whl",
+            "platform_python_implementation == 'CPython'",
+        );
+        assert_markers(
+            "example-1.0-pp310-pypy310_pp73-manylinux_2_17_x86_64.whl",
+            "sys_platform == 'linux' and platform_machine == 'x86_64' and platform_python_implementation == 'PyPy'",
+        );
+        assert_markers(
+            "example-1.0-cp312.pp310-none-any.whl",
+            "platform_python_implementation == 'CPython' or platform_python_implementation == 'PyPy'",
         );
     }
 }
@@ -3,7 +3,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{HashDigest, HashDigests, RequirementSource};
 
-use crate::{BuiltDist, Diagnostic, Dist, Name, ResolvedDist, SourceDist};
+use crate::{BuiltDist, DerivationChain, Diagnostic, Dist, Name, ResolvedDist, SourceDist};
 
 /// A set of packages pinned at specific versions.
 ///
@@ -134,6 +134,14 @@ pub enum ResolutionDiagnostic {
         /// resolution. For example, `black`.
         package_name: PackageName,
     },
+    DisallowedLicense {
+        /// The distribution that declares a disallowed license.
+        dist: ResolvedDist,
+        /// The SPDX license expression declared by the distribution. For example, `GPL-3.0-only`.
+        license: String,
+        /// The chain of dependencies through which the distribution was included.
+        chain: DerivationChain,
+    },
 }
 
 impl Diagnostic for ResolutionDiagnostic {
@@ -160,6 +168,33 @@ impl Diagnostic for ResolutionDiagnostic {
                     `--resolution lowest` to avoid using outdated versions."
                 )
             }
+            Self::DisallowedLicense {
+                dist,
+                license,
+                chain,
+            } => {
+                if chain.is_empty() {
+                    format!("`{dist}` has a disallowed license (`{license}`)")
+                } else {
+                    let chain = chain
+                        .iter()
+                        .map(|step| match (&step.extra, &step.group, &step.version) {
+                            (Some(extra), _, Some(version)) => {
+                                format!("{}[{extra}]=={version}", step.name)
+                            }
+                            (None, Some(group), Some(version)) => {
+                                format!("{}:{group}=={version}", step.name)
+                            }
+                            (None, None, Some(version)) => format!("{}=={version}", step.name),
+                            (_, _, None) => step.name.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    format!(
+                        "`{dist}` has a disallowed license (`{license}`), and is required by: {chain}"
+                    )
+                }
+            }
         }
     }
 
@@ -170,6 +205,7 @@ impl Diagnostic for ResolutionDiagnostic {
             Self::MissingDev { dist, .. } => name == dist.name(),
             Self::YankedVersion { dist, .. } => name == dist.name(),
             Self::MissingLowerBound { package_name } => name == package_name,
+            Self::DisallowedLicense { dist, .. } => name == dist.name(),
         }
     }
 }
@@ -270,6 +306,11 @@ impl From<&ResolvedDist> for RequirementSource {
                     url: sdist.url.clone(),
                     subdirectory: sdist.subdirectory.clone(),
                 },
+                Dist::Source(SourceDist::Hg(sdist)) => RequirementSource::Hg {
+                    hg: (*sdist.hg).clone(),
+                    url: sdist.url.clone(),
+                    subdirectory: sdist.subdirectory.clone(),
+                },
                 Dist::Source(SourceDist::Path(sdist)) => RequirementSource::Path {
                     install_path: sdist.install_path.clone(),
                     url: sdist.url.clone(),
@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
+use crate::{Index, IndexName, IndexUrl};
+
+/// The packages that are distributed via the PyTorch indexes, and should be routed to the index
+/// for the selected [`TorchBackend`].
+///
+/// When PyTorch publishes a new companion package to its indexes, add it here; when it publishes
+/// a new backend (i.e., a new `https://download.pytorch.org/whl/<backend>` index), add a variant
+/// to [`TorchBackend`], then regenerate the settings reference and JSON schema.
+const TORCH_PACKAGES: &[&str] = &[
+    "torch",
+    "torchao",
+    "torchaudio",
+    "torchdata",
+    "torchtext",
+    "torchvision",
+    "pytorch-triton",
+    "pytorch-triton-rocm",
+    "pytorch-triton-xpu",
+];
+
+/// The hardware backend for which to install PyTorch (e.g., a specific CUDA or ROCm version).
+///
+/// Each backend corresponds to a dedicated PyTorch index (e.g., `cu121` maps to
+/// `https://download.pytorch.org/whl/cu121`), which hosts builds of PyTorch and its companion
+/// packages tagged with a local version (e.g., `torch==2.5.1+cu121`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TorchBackend {
+    /// Use the CPU-only PyTorch index.
+    #[serde(rename = "cpu")]
+    Cpu,
+    /// Use the PyTorch index for CUDA 12.8.
+    #[serde(rename = "cu128")]
+    Cu128,
+    /// Use the PyTorch index for CUDA 12.6.
+    #[serde(rename = "cu126")]
+    Cu126,
+    /// Use the PyTorch index for CUDA 12.4.
+    #[serde(rename = "cu124")]
+    Cu124,
+    /// Use the PyTorch index for CUDA 12.1.
+    #[serde(rename = "cu121")]
+    Cu121,
+    /// Use the PyTorch index for CUDA 11.8.
+    #[serde(rename = "cu118")]
+    Cu118,
+    /// Use the PyTorch index for ROCm 6.3.
+    #[serde(rename = "rocm6.3")]
+    Rocm63,
+    /// Use the PyTorch index for ROCm 6.2.
+    #[serde(rename = "rocm6.2")]
+    Rocm62,
+    /// Use the PyTorch index for ROCm 6.1.
+    #[serde(rename = "rocm6.1")]
+    Rocm61,
+    /// Use the PyTorch index for Intel XPUs.
+    #[serde(rename = "xpu")]
+    Xpu,
+}
+
+impl TorchBackend {
+    /// Returns the identifier of the backend, as used in the PyTorch index URL.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::Cu128 => "cu128",
+            Self::Cu126 => "cu126",
+            Self::Cu124 => "cu124",
+            Self::Cu121 => "cu121",
+            Self::Cu118 => "cu118",
+            Self::Rocm63 => "rocm6.3",
+            Self::Rocm62 => "rocm6.2",
+            Self::Rocm61 => "rocm6.1",
+            Self::Xpu => "xpu",
+        }
+    }
+
+    /// Returns the packages that are distributed via the PyTorch indexes.
+    pub fn packages() -> impl Iterator<Item = PackageName> {
+        TORCH_PACKAGES
+            .iter()
+            .map(|name| PackageName::from_str(name).expect("valid package name"))
+    }
+
+    /// Returns the name of the index for the backend (e.g., `pytorch-cu121`).
+    pub fn index_name(self) -> IndexName {
+        IndexName::new(format!("pytorch-{}", self.as_str()))
+            .expect("PyTorch backend should produce a valid index name")
+    }
+
+    /// Returns the URL of the PyTorch index for the backend.
+    pub fn index_url(self) -> IndexUrl {
+        IndexUrl::from_str(&format!(
+            "https://download.pytorch.org/whl/{}",
+            self.as_str()
+        ))
+        .expect("PyTorch backend should produce a valid index URL")
+    }
+
+    /// Returns the explicit [`Index`] for the backend, to which the PyTorch packages are pinned.
+    pub fn index(self) -> Index {
+        Index {
+            name: Some(self.index_name()),
+            explicit: true,
+            ..Index::from_extra_index_url(self.index_url())
+        }
+    }
+}
+
+impl Display for TorchBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -6,7 +6,7 @@ use uv_pep508::VerbatimUrl;
 use crate::error::Error;
 use crate::{
     BuiltDist, CachedDirectUrlDist, CachedDist, CachedRegistryDist, DirectUrlBuiltDist,
-    DirectUrlSourceDist, DirectorySourceDist, Dist, DistributionId, GitSourceDist,
+    DirectUrlSourceDist, DirectorySourceDist, Dist, DistributionId, GitSourceDist, HgSourceDist,
     InstalledDirectUrlDist, InstalledDist, InstalledEggInfoDirectory, InstalledEggInfoFile,
     InstalledLegacyEditable, InstalledRegistryDist, InstalledVersion, LocalDist, PackageId,
     PathBuiltDist, PathSourceDist, RegistryBuiltWheel, RegistrySourceDist, ResourceId, SourceDist,
@@ -172,6 +172,12 @@ impl std::fmt::Display for GitSourceDist {
     }
 }
 
+impl std::fmt::Display for HgSourceDist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.name(), self.version_or_url())
+    }
+}
+
 impl std::fmt::Display for InstalledDist {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}{}", self.name(), self.installed_version())
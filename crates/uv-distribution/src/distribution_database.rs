@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::future::Future;
 use std::io;
 use std::path::Path;
@@ -10,7 +11,7 @@ use tempfile::TempDir;
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{info_span, instrument, warn, Instrument};
+use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
@@ -20,11 +21,13 @@ use uv_client::{
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
-    BuildableSource, BuiltDist, Dist, FileLocation, HashPolicy, Hashed, InstalledDist, Name,
-    SourceDist,
+    BuildableSource, BuiltDist, Dist, FileLocation, HashPolicy, Hashed, IndexLocations,
+    InstalledDist, Name, SourceDist,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_platform_tags::Tags;
 use uv_pypi_types::{HashDigest, HashDigests};
 use uv_types::{BuildContext, BuildStack};
@@ -32,7 +35,14 @@ use uv_types::{BuildContext, BuildStack};
 use crate::archive::Archive;
 use crate::metadata::{ArchiveMetadata, Metadata};
 use crate::source::SourceDistributionBuilder;
-use crate::{Error, LocalWheel, Reporter, RequiresDist};
+use crate::{Error, LocalWheel, RegistryWheelIndex, Reporter, RequiresDist};
+
+/// The size (in bytes) above which wheels are downloaded to disk and unzipped in parallel, rather
+/// than unzipped in a single pass as they're streamed.
+///
+/// Streaming avoids writing the wheel to disk, but extracts one file at a time; for large wheels
+/// (e.g., `torch`), extracting files in parallel from the on-disk archive is substantially faster.
+const PARALLEL_UNZIP_THRESHOLD: u64 = 64 * 1024 * 1024;
 
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
 /// to a wheel or wheel metadata.
@@ -202,7 +212,34 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
-                // Download and unzip.
+                // If the wheel is large, download it to disk, then unzip it in parallel.
+                if wheel
+                    .file
+                    .size
+                    .is_some_and(|size| size >= PARALLEL_UNZIP_THRESHOLD)
+                {
+                    debug!("Downloading large wheel to disk for parallel unzip: {dist}");
+                    let archive = self
+                        .download_wheel(
+                            url,
+                            &wheel.filename,
+                            wheel.file.size,
+                            &wheel_entry,
+                            dist,
+                            hashes,
+                        )
+                        .await?;
+
+                    return Ok(LocalWheel {
+                        dist: Dist::Built(dist.clone()),
+                        archive: self.build_context.cache().archive(&archive.id),
+                        hashes: archive.hashes,
+                        filename: wheel.filename.clone(),
+                        cache: CacheInfo::default(),
+                    });
+                }
+
+                // Otherwise, download and unzip in a single pass.
                 match self
                     .stream_wheel(
                         url.clone(),
@@ -675,6 +712,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
+                // Create a hasher for each hash algorithm, to hash the wheel as it's downloaded.
+                let algorithms = hashes.algorithms();
+                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+
                 // Download the wheel to a temporary file.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
@@ -683,56 +725,41 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 match progress {
                     Some((reporter, progress)) => {
                         // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
+                        // after the download is complete, even if we still have to unzip part of
+                        // the file.
+                        let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                     None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
+                        tokio::io::copy(&mut hasher, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                 }
 
-                // Unzip the wheel to a temporary directory.
+                let hashes = hashers.into_iter().map(HashDigest::from).collect();
+
+                // Unzip the wheel to a temporary directory. Since the hashes were computed during
+                // the download, the unzip can always be parallelized.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
                 let mut file = writer.into_inner();
                 file.seek(io::SeekFrom::Start(0))
                     .await
                     .map_err(Error::CacheWrite)?;
-
-                // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
-                    let file = file.into_std().await;
-                    tokio::task::spawn_blocking({
-                        let target = temp_dir.path().to_owned();
-                        move || -> Result<(), uv_extract::Error> {
-                            // Unzip the wheel into a temporary directory.
-                            uv_extract::unzip(file, &target)?;
-                            Ok(())
-                        }
-                    })
-                    .await??;
-
-                    HashDigests::empty()
-                } else {
-                    // Create a hasher for each hash algorithm.
-                    let algorithms = hashes.algorithms();
-                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
-                    uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
-
-                    // If necessary, exhaust the reader to compute the hash.
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
-
-                    hashers.into_iter().map(HashDigest::from).collect()
-                };
+                let file = file.into_std().await;
+                tokio::task::spawn_blocking({
+                    let target = temp_dir.path().to_owned();
+                    move || -> Result<(), uv_extract::Error> {
+                        // Unzip the wheel into a temporary directory.
+                        uv_extract::unzip(file, &target)?;
+                        Ok(())
+                    }
+                })
+                .await??;
 
                 // Persist the temporary directory to the directory store.
                 let id = self
@@ -869,29 +896,36 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             let file = fs_err::tokio::File::open(path)
                 .await
                 .map_err(Error::CacheRead)?;
-            let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                .map_err(Error::CacheWrite)?;
+            let size = file.metadata().await.map_err(Error::CacheRead)?.len();
 
             // Create a hasher for each hash algorithm.
             let algorithms = hashes.algorithms();
             let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
             let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
 
-            // Unzip the wheel to a temporary directory.
-            uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
+            let id = if size >= PARALLEL_UNZIP_THRESHOLD {
+                // If the wheel is large, hash it up-front, then unzip it in parallel.
+                debug!("Hashing large wheel prior to parallel unzip: {dist}");
+                hasher.finish().await.map_err(Error::HashExhaustion)?;
+                self.unzip_wheel(path, wheel_entry.path()).await?
+            } else {
+                // Otherwise, unzip the wheel to a temporary directory, hashing it along the way.
+                let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
+                    .map_err(Error::CacheWrite)?;
+                uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await?;
 
-            // Exhaust the reader to compute the hash.
-            hasher.finish().await.map_err(Error::HashExhaustion)?;
+                // Exhaust the reader to compute the hash.
+                hasher.finish().await.map_err(Error::HashExhaustion)?;
 
-            let hashes = hashers.into_iter().map(HashDigest::from).collect();
+                // Persist the temporary directory to the directory store.
+                self.build_context
+                    .cache()
+                    .persist(temp_dir.into_path(), wheel_entry.path())
+                    .await
+                    .map_err(Error::CacheWrite)?
+            };
 
-            // Persist the temporary directory to the directory store.
-            let id = self
-                .build_context
-                .cache()
-                .persist(temp_dir.into_path(), wheel_entry.path())
-                .await
-                .map_err(Error::CacheWrite)?;
+            let hashes = hashers.into_iter().map(HashDigest::from).collect();
 
             // Create an archive.
             let archive = Archive::new(id, hashes, filename.clone());
@@ -959,6 +993,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     pub fn client(&self) -> &ManagedClient<'a> {
         &self.client
     }
+
+    /// Return the versions of a registry package that are available in the cache.
+    pub fn cached_versions(
+        &self,
+        package: &PackageName,
+        index_locations: &IndexLocations,
+    ) -> BTreeSet<Version> {
+        RegistryWheelIndex::cached_versions(package, self.build_context.cache(), index_locations)
+    }
 }
 
 /// A wrapper around `RegistryClient` that manages a concurrency limit.
@@ -32,6 +32,8 @@ pub enum Error {
     #[error(transparent)]
     Git(#[from] uv_git::GitResolverError),
     #[error(transparent)]
+    Hg(#[from] uv_git::HgResolverError),
+    #[error(transparent)]
     Reqwest(#[from] WrappedReqwestError),
     #[error(transparent)]
     Client(#[from] uv_client::Error),
@@ -151,6 +153,9 @@ pub enum Error {
 
     #[error("Hash-checking is not supported for Git repositories: `{0}`")]
     HashesNotSupportedGit(String),
+
+    #[error("Hash-checking is not supported for Mercurial repositories: `{0}`")]
+    HashesNotSupportedHg(String),
 }
 
 impl From<reqwest::Error> for Error {
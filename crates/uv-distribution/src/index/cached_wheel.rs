@@ -5,7 +5,7 @@ use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     CachedDirectUrlDist, CachedRegistryDist, DirectUrlSourceDist, DirectorySourceDist,
-    GitSourceDist, Hashed, PathSourceDist,
+    GitSourceDist, Hashed, HgSourceDist, PathSourceDist,
 };
 use uv_pypi_types::{HashDigest, HashDigests, VerbatimParsedUrl};
 
@@ -116,6 +116,21 @@ impl CachedWheel {
         }
     }
 
+    /// Convert a [`CachedWheel`] into a [`CachedDirectUrlDist`] by merging in the given
+    /// [`HgSourceDist`].
+    pub fn into_hg_dist(self, dist: &HgSourceDist) -> CachedDirectUrlDist {
+        CachedDirectUrlDist {
+            filename: self.filename,
+            url: VerbatimParsedUrl {
+                parsed_url: dist.parsed_url(),
+                verbatim: dist.url.clone(),
+            },
+            path: self.entry.into_path_buf(),
+            hashes: self.hashes,
+            cache_info: self.cache_info,
+        }
+    }
+
     /// Read a cached wheel from a `.http` pointer
     pub fn from_http_pointer(path: impl AsRef<Path>, cache: &Cache) -> Option<Self> {
         let path = path.as_ref();
@@ -1,4 +1,6 @@
 use std::collections::hash_map::Entry;
+use std::collections::BTreeSet;
+use std::str::FromStr;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -8,6 +10,7 @@ use uv_configuration::ConfigSettings;
 use uv_distribution_types::{CachedRegistryDist, Hashed, Index, IndexLocations, IndexUrl};
 use uv_fs::{directories, files};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_platform_tags::Tags;
 use uv_types::HashStrategy;
 
@@ -253,4 +256,65 @@ impl<'a> RegistryWheelIndex<'a> {
 
         entries
     }
+
+    /// Return the versions of a package for which any distribution or metadata from the given
+    /// indexes is available in the cache, regardless of whether it's compatible with the current
+    /// platform.
+    pub fn cached_versions(
+        package: &PackageName,
+        cache: &Cache,
+        index_locations: &IndexLocations,
+    ) -> BTreeSet<Version> {
+        let mut versions = BTreeSet::new();
+
+        let mut seen = FxHashSet::default();
+        for index in index_locations.allowed_indexes() {
+            if !seen.insert(index.url()) {
+                continue;
+            }
+
+            // For registry wheels, the cache structure is: `<index>/<package-name>/<key>.http` (or
+            // `.rev`, for local registries), alongside the metadata in `<key>.msgpack`, where the
+            // key starts with the version (e.g., `2.0.0-py3-none-any`).
+            let wheel_dir = cache.shard(
+                CacheBucket::Wheels,
+                WheelCache::Index(index.url()).wheel_dir(package.as_ref()),
+            );
+            for file in files(&wheel_dir).ok().into_iter().flatten() {
+                if !file.extension().is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("http")
+                        || ext.eq_ignore_ascii_case("rev")
+                        || ext.eq_ignore_ascii_case("msgpack")
+                }) {
+                    continue;
+                }
+                if let Some(version) = file
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.split('-').next())
+                    .and_then(|version| Version::from_str(version).ok())
+                {
+                    versions.insert(version);
+                }
+            }
+
+            // For registry source distributions, the cache structure is:
+            // `<index>/<package-name>/<version>/`.
+            let cache_shard = cache.shard(
+                CacheBucket::SourceDistributions,
+                WheelCache::Index(index.url()).wheel_dir(package.as_ref()),
+            );
+            for shard in directories(&cache_shard).ok().into_iter().flatten() {
+                if let Some(version) = shard
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|version| Version::from_str(version).ok())
+                {
+                    versions.insert(version);
+                }
+            }
+        }
+
+        versions
+    }
 }
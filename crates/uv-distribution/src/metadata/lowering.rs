@@ -7,7 +7,8 @@ use thiserror::Error;
 use url::Url;
 
 use uv_distribution_filename::DistExtension;
-use uv_distribution_types::{Index, IndexLocations, IndexName, Origin};
+use uv_distribution_types::{Index, IndexLocations, IndexName, IndexUrl, IndexUrlError, Origin};
+use uv_fs::PortablePathBuf;
 use uv_git_types::{GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
@@ -15,7 +16,7 @@ use uv_pep508::{looks_like_git_repository, MarkerTree, VerbatimUrl, VersionOrUrl
 use uv_pypi_types::{
     ConflictItem, ParsedUrlError, Requirement, RequirementSource, VerbatimParsedUrl,
 };
-use uv_workspace::pyproject::{PyProjectToml, Source, Sources};
+use uv_workspace::pyproject::{PathSourceKind, PyProjectToml, Source, Sources};
 use uv_workspace::Workspace;
 
 use crate::metadata::GitWorkspaceMember;
@@ -160,6 +161,10 @@ impl LoweredRequirement {
             sources
                 .into_iter()
                 .map(move |source| {
+                    let ssh_key = source.ssh_key(match origin {
+                        RequirementOrigin::Project => project_dir,
+                        RequirementOrigin::Workspace => workspace.install_path(),
+                    });
                     let (source, mut marker) = match source {
                         Source::Git {
                             git,
@@ -176,6 +181,7 @@ impl LoweredRequirement {
                                 rev,
                                 tag,
                                 branch,
+                                ssh_key,
                             )?;
                             (source, marker)
                         }
@@ -189,6 +195,34 @@ impl LoweredRequirement {
                                 url_source(&requirement, url, subdirectory.map(PathBuf::from))?;
                             (source, marker)
                         }
+                        Source::Path {
+                            path,
+                            kind: Some(PathSourceKind::Flat),
+                            marker,
+                            extra,
+                            group,
+                            ..
+                        } => {
+                            let conflict = project_name.and_then(|project_name| {
+                                if let Some(extra) = extra {
+                                    Some(ConflictItem::from((project_name.clone(), extra)))
+                                } else {
+                                    group.map(|group| {
+                                        ConflictItem::from((project_name.clone(), group))
+                                    })
+                                }
+                            });
+                            let source = flat_source(
+                                &requirement,
+                                &path,
+                                git_member,
+                                origin,
+                                project_dir,
+                                workspace.install_path(),
+                                conflict,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Path {
                             path,
                             editable,
@@ -389,6 +423,7 @@ impl LoweredRequirement {
             source
                 .into_iter()
                 .map(move |source| {
+                    let ssh_key = source.ssh_key(dir);
                     let (source, mut marker) = match source {
                         Source::Git {
                             git,
@@ -405,6 +440,7 @@ impl LoweredRequirement {
                                 rev,
                                 tag,
                                 branch,
+                                ssh_key,
                             )?;
                             (source, marker)
                         }
@@ -418,6 +454,23 @@ impl LoweredRequirement {
                                 url_source(&requirement, url, subdirectory.map(PathBuf::from))?;
                             (source, marker)
                         }
+                        Source::Path {
+                            path,
+                            kind: Some(PathSourceKind::Flat),
+                            marker,
+                            ..
+                        } => {
+                            let source = flat_source(
+                                &requirement,
+                                &path,
+                                None,
+                                RequirementOrigin::Project,
+                                dir,
+                                dir,
+                                None,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Path {
                             path,
                             editable,
@@ -515,6 +568,10 @@ pub enum LoweringError {
     EditableFile(String),
     #[error("Git repository references local file source, but only directories are supported as transitive Git dependencies: `{0}`")]
     GitFile(String),
+    #[error("Git repository references a flat directory source, but only directories are supported as transitive Git dependencies: `{0}`")]
+    GitFlat(String),
+    #[error(transparent)]
+    InvalidIndexUrl(#[from] IndexUrlError),
     #[error(transparent)]
     ParsedUrl(#[from] ParsedUrlError),
     #[error("Path must be UTF-8: `{0}`")]
@@ -543,17 +600,20 @@ impl std::fmt::Display for SourceKind {
 }
 
 /// Convert a Git source into a [`RequirementSource`].
+///
+/// If the source specifies an SSH key, it's registered for use when fetching the repository.
 fn git_source(
     git: &Url,
     subdirectory: Option<PathBuf>,
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
+    ssh_key: Option<PathBuf>,
 ) -> Result<RequirementSource, LoweringError> {
     let reference = match (rev, tag, branch) {
         (None, None, None) => GitReference::DefaultBranch,
         (Some(rev), None, None) => GitReference::from_rev(rev),
-        (None, Some(tag), None) => GitReference::Tag(tag),
+        (None, Some(tag), None) => GitReference::from_tag(tag),
         (None, None, Some(branch)) => GitReference::Branch(branch),
         _ => return Err(LoweringError::MoreThanOneGitRef),
     };
@@ -571,6 +631,10 @@ fn git_source(
     }
     let url = VerbatimUrl::from_url(url);
 
+    if let Some(ssh_key) = ssh_key {
+        uv_git::store_ssh_key(git, ssh_key);
+    }
+
     let repository = git.clone();
 
     Ok(RequirementSource::Git {
@@ -644,6 +708,30 @@ fn registry_source(
     }
 }
 
+/// Convert a flat `path` source (i.e., a directory of distributions) into a [`RequirementSource`].
+///
+/// The directory is treated as a local registry, such that the resolver can select from any of
+/// the distributions it contains.
+fn flat_source(
+    requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
+    path: &PortablePathBuf,
+    git_member: Option<&GitWorkspaceMember>,
+    origin: RequirementOrigin,
+    project_dir: &Path,
+    workspace_root: &Path,
+    conflict: Option<ConflictItem>,
+) -> Result<RequirementSource, LoweringError> {
+    if git_member.is_some() {
+        return Err(LoweringError::GitFlat(path.to_string()));
+    }
+    let base = match origin {
+        RequirementOrigin::Project => project_dir,
+        RequirementOrigin::Workspace => workspace_root,
+    };
+    let index = IndexUrl::parse(&path.to_string(), Some(base))?;
+    Ok(registry_source(requirement, index.into_url(), conflict))
+}
+
 /// Convert a path string to a file or directory source.
 fn path_source(
     path: impl AsRef<Path>,
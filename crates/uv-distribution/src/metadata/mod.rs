@@ -51,6 +51,7 @@ pub struct Metadata {
     pub provides_extras: Vec<ExtraName>,
     pub dependency_groups: BTreeMap<GroupName, Vec<uv_pypi_types::Requirement>>,
     pub dynamic: bool,
+    pub license_expression: Option<String>,
 }
 
 impl Metadata {
@@ -69,6 +70,7 @@ impl Metadata {
             provides_extras: metadata.provides_extras,
             dependency_groups: BTreeMap::default(),
             dynamic: metadata.dynamic,
+            license_expression: metadata.license_expression,
         }
     }
 
@@ -112,6 +114,7 @@ impl Metadata {
             provides_extras,
             dependency_groups,
             dynamic,
+            license_expression: metadata.license_expression,
         })
     }
 }
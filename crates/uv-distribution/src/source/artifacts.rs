@@ -0,0 +1,138 @@
+//! A local cache of the wheels built from source distributions, shared by every source of the same
+//! source distribution (e.g., across indexes, projects, and branches).
+
+use std::path::Path;
+
+use fs_err::tokio as fs;
+use tracing::debug;
+
+use uv_cache_key::cache_digest;
+use uv_configuration::ConfigSettings;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// The environment in which a source distribution is built.
+pub(crate) struct BuildEnvironment<'a> {
+    /// The implementation and full version of the interpreter, e.g., `cpython-3.12.3`.
+    pub(crate) interpreter: String,
+    /// The base prefix of the interpreter, which is shared by any virtual environments created
+    /// from it.
+    pub(crate) base_prefix: &'a Path,
+    /// The platform tag, e.g., `cp312-cp312-manylinux_2_17_x86_64`.
+    pub(crate) tag: String,
+    /// The build requirements declared by the source distribution, if any.
+    pub(crate) requires: Option<Vec<String>>,
+}
+
+/// Return the key for the wheels built from a source distribution in the given build environment,
+/// if the SHA-256 hash of the source distribution is known.
+pub(crate) fn key(
+    hashes: &[HashDigest],
+    subdirectory: Option<&Path>,
+    config_settings: &ConfigSettings,
+    environment: &BuildEnvironment,
+) -> Option<String> {
+    let sha256 = hashes
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?;
+    Some(cache_digest(&(
+        sha256.digest.as_str(),
+        subdirectory,
+        config_settings,
+        environment.interpreter.as_str(),
+        environment.base_prefix,
+        environment.tag.as_str(),
+        environment.requires.as_deref(),
+    )))
+}
+
+/// Read the build requirements declared in the `pyproject.toml` of a source tree, sorted such
+/// that the order in which they're declared doesn't affect the key.
+///
+/// Returns `None` if the source tree doesn't declare any build requirements, in which case the
+/// build falls back to the default `setuptools` backend.
+pub(crate) async fn build_requires(source_tree: &Path) -> Option<Vec<String>> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct PyProjectToml {
+        build_system: Option<BuildSystem>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct BuildSystem {
+        requires: Vec<String>,
+    }
+
+    let content = fs::read_to_string(source_tree.join("pyproject.toml"))
+        .await
+        .ok()?;
+    let pyproject_toml: PyProjectToml = match toml::from_str(&content) {
+        Ok(pyproject_toml) => pyproject_toml,
+        Err(err) => {
+            debug!("Failed to read build requirements from `pyproject.toml`: {err}");
+            return None;
+        }
+    };
+    let mut requires = pyproject_toml.build_system?.requires;
+    requires.sort();
+    Some(requires)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_configuration::ConfigSettings;
+    use uv_pypi_types::HashDigest;
+
+    use super::{key, BuildEnvironment};
+
+    #[test]
+    fn artifact_key() {
+        let sha256 = HashDigest::from_str(
+            "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2",
+        )
+        .unwrap();
+        let settings = ConfigSettings::default();
+        let environment = |interpreter: &str, requires: &[&str]| BuildEnvironment {
+            interpreter: interpreter.to_string(),
+            base_prefix: Path::new("/usr"),
+            tag: "cp312-cp312-manylinux_2_17_x86_64".to_string(),
+            requires: Some(requires.iter().map(ToString::to_string).collect()),
+        };
+
+        // The key depends on the interpreter and the build requirements.
+        let root = key(
+            std::slice::from_ref(&sha256),
+            None,
+            &settings,
+            &environment("cpython-3.12.3", &["meson-python"]),
+        )
+        .unwrap();
+        assert_ne!(
+            key(
+                std::slice::from_ref(&sha256),
+                None,
+                &settings,
+                &environment("cpython-3.12.4", &["meson-python"]),
+            ),
+            Some(root.clone())
+        );
+        assert_ne!(
+            key(
+                std::slice::from_ref(&sha256),
+                None,
+                &settings,
+                &environment("cpython-3.12.3", &["meson-python", "cython"]),
+            ),
+            Some(root)
+        );
+
+        // Without a SHA-256 hash, there's no key.
+        let md5 = HashDigest::from_str("md5:d41d8cd98f00b204e9800998ecf8427e").unwrap();
+        assert_eq!(
+            key(&[md5], None, &settings, &environment("cpython-3.12.3", &[])),
+            None
+        );
+    }
+}
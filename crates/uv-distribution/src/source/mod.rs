@@ -22,7 +22,7 @@ use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 use zip::ZipArchive;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
+use uv_cache::{compressed_path, Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
 use uv_client::{
@@ -32,11 +32,12 @@ use uv_configuration::{BuildKind, BuildOutput, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildableSource, DirectorySourceUrl, FileLocation, GitSourceUrl, HashPolicy, Hashed,
-    PathSourceUrl, SourceDist, SourceUrl,
+    HgSourceUrl, PathSourceUrl, SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic};
-use uv_git_types::{GitHubRepository, GitOid};
+use uv_git::HgFetch;
+use uv_git_types::{GitHubRepository, GitOid, HgUrl};
 use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
 use uv_pep440::{release_specifiers_to_ranges, Version};
@@ -48,11 +49,15 @@ use uv_workspace::pyproject::ToolUvSources;
 use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
+use crate::source::artifacts::BuildEnvironment;
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
+use crate::source::remote::RemoteCache;
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
+mod artifacts;
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -187,6 +192,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Dist(SourceDist::Hg(dist)) => {
+                self.hg(source, &HgSourceUrl::from(dist), tags, hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
                 self.source_tree(source, &DirectorySourceUrl::from(dist), tags, hashes)
                     .boxed_local()
@@ -232,6 +242,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Url(SourceUrl::Hg(resource)) => {
+                self.hg(source, resource, tags, hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
                 self.source_tree(source, resource, tags, hashes)
                     .boxed_local()
@@ -325,6 +340,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Dist(SourceDist::Hg(dist)) => {
+                self.hg_metadata(source, &HgSourceUrl::from(dist), hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
                 self.source_tree_metadata(source, &DirectorySourceUrl::from(dist), hashes)
                     .boxed_local()
@@ -363,6 +383,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .boxed_local()
                     .await?
             }
+            BuildableSource::Url(SourceUrl::Hg(resource)) => {
+                self.hg_metadata(source, resource, hashes)
+                    .boxed_local()
+                    .await?
+            }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
                 self.source_tree_metadata(source, resource, hashes)
                     .boxed_local()
@@ -433,6 +458,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
+        self.decompress_source(&source_dist_entry).await;
         let revision = if source_dist_entry.path().is_dir() {
             revision
         } else {
@@ -458,28 +484,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        let task = self
-            .reporter
-            .as_ref()
-            .map(|reporter| reporter.on_build_start(source));
-
-        // Build the source distribution.
+        // Build the source distribution, or fetch a wheel built from it from the remote cache.
         let (disk_filename, wheel_filename, metadata) = self
-            .build_distribution(
+            .build_distribution_or_fetch(
                 source,
                 source_dist_entry.path(),
                 subdirectory,
                 &cache_shard,
-                SourceStrategy::Disabled,
+                revision.hashes(),
+                tags,
+                client,
             )
             .await?;
 
-        if let Some(task) = task {
-            if let Some(reporter) = self.reporter.as_ref() {
-                reporter.on_build_complete(source, task);
-            }
-        }
-
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -562,7 +579,22 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If the remote cache contains the metadata, use it.
+        let remote = self.remote_cache(revision.hashes(), subdirectory, client);
+        if let Some(remote) = &remote {
+            if let Some(metadata) = self
+                .fetch_remote_metadata(source, remote, &metadata_entry)
+                .await?
+            {
+                return Ok(ArchiveMetadata {
+                    metadata: Metadata::from_metadata23(metadata),
+                    hashes: revision.into_hashes(),
+                });
+            }
+        }
+
         // Otherwise, we need a wheel.
+        self.decompress_source(&source_dist_entry).await;
         let revision = if source_dist_entry.path().is_dir() {
             revision
         } else {
@@ -625,6 +657,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
                 .await
                 .map_err(Error::CacheWrite)?;
+            if let Some(remote) = &remote {
+                remote.put_metadata(rmp_serde::to_vec(&metadata)?).await;
+            }
 
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
@@ -668,6 +703,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
+        if let Some(remote) = &remote {
+            remote.put_metadata(rmp_serde::to_vec(&metadata)?).await;
+        }
 
         Ok(ArchiveMetadata {
             metadata: Metadata::from_metadata23(metadata),
@@ -805,6 +843,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel, which requires a source distribution.
+        self.decompress_source(&source_entry).await;
         let revision = if source_entry.path().is_dir() {
             revision
         } else {
@@ -912,6 +951,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need a source distribution.
+        self.decompress_source(&source_entry).await;
         let revision = if source_entry.path().is_dir() {
             revision
         } else {
@@ -1443,6 +1483,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .uncached_client(resource.git.repository())
                     .clone(),
                 client.unmanaged.disable_ssl(resource.git.repository()),
+                client.unmanaged.proxy(resource.git.repository()),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
                     .clone()
@@ -1604,6 +1645,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .uncached_client(resource.git.repository())
                     .clone(),
                 client.unmanaged.disable_ssl(resource.git.repository()),
+                client.unmanaged.proxy(resource.git.repository()),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
                     .clone()
@@ -1798,184 +1840,516 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         ))
     }
 
-    /// Resolve a source to a specific revision.
-    pub(crate) async fn resolve_revision(
-        &self,
-        source: &BuildableSource<'_>,
-        client: &ManagedClient<'_>,
-    ) -> Result<(), Error> {
-        let git = match source {
-            BuildableSource::Dist(SourceDist::Git(source)) => &*source.git,
-            BuildableSource::Url(SourceUrl::Git(source)) => source.git,
-            _ => {
-                return Ok(());
-            }
-        };
-
-        // If this is GitHub URL, attempt to resolve to a precise commit using the GitHub API.
-        if self
+    /// Fetch a Mercurial repository into the cache.
+    async fn fetch_hg(&self, hg: &HgUrl) -> Result<HgFetch, Error> {
+        Ok(self
             .build_context
-            .git()
-            .github_fast_path(
-                git,
-                client.unmanaged.uncached_client(git.repository()).clone(),
-            )
-            .await?
-            .is_some()
-        {
-            debug!("Resolved to precise commit via GitHub fast path: {source}");
-            return Ok(());
-        }
-
-        // Otherwise, fetch the Git repository.
-        self.build_context
-            .git()
+            .hg()
             .fetch(
-                git,
-                client.unmanaged.uncached_client(git.repository()).clone(),
-                client.unmanaged.disable_ssl(git.repository()),
-                self.build_context.cache().bucket(CacheBucket::Git),
+                hg,
+                self.build_context.cache().bucket(CacheBucket::Hg),
                 self.reporter
                     .clone()
                     .map(|reporter| reporter.into_git_reporter()),
             )
-            .await?;
-
-        Ok(())
+            .await?)
     }
 
-    /// Fetch static [`ResolutionMetadata`] from a GitHub repository, if possible.
-    ///
-    /// Attempts to fetch the `pyproject.toml` from the resolved commit using the GitHub API.
-    async fn github_metadata(
+    /// Build a source distribution from a Mercurial repository.
+    async fn hg(
         &self,
-        commit: GitOid,
         source: &BuildableSource<'_>,
-        resource: &GitSourceUrl<'_>,
-        client: &ManagedClient<'_>,
-    ) -> Result<Option<ResolutionMetadata>, Error> {
-        let GitSourceUrl {
-            git, subdirectory, ..
-        } = resource;
-
-        // The fast path isn't available for subdirectories. If a `pyproject.toml` is in a
-        // subdirectory, it could be part of a workspace; and if it's part of a workspace, it could
-        // have `tool.uv.sources` entries that it inherits from the workspace root.
-        if subdirectory.is_some() {
-            return Ok(None);
+        resource: &HgSourceUrl<'_>,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+    ) -> Result<BuiltWheelMetadata, Error> {
+        // Before running the build, check that the hashes match.
+        if hashes.is_validate() {
+            return Err(Error::HashesNotSupportedHg(source.to_string()));
         }
 
-        let Some(GitHubRepository { owner, repo }) = GitHubRepository::parse(git.repository())
-        else {
-            return Ok(None);
-        };
-
-        // Fetch the `pyproject.toml` from the resolved commit.
-        let url =
-            format!("https://raw.githubusercontent.com/{owner}/{repo}/{commit}/pyproject.toml");
-
-        debug!("Attempting to fetch `pyproject.toml` from: {url}");
+        // Fetch the Mercurial repository.
+        let fetch = self.fetch_hg(resource.hg).await?;
 
-        let content = client
-            .managed(|client| async {
-                let response = client
-                    .uncached_client(git.repository())
-                    .get(&url)
-                    .send()
-                    .await?;
+        // Validate that the subdirectory exists.
+        if let Some(subdirectory) = resource.subdirectory {
+            if !fetch.path().join(subdirectory).is_dir() {
+                return Err(Error::MissingSubdirectory(
+                    resource.url.to_url(),
+                    subdirectory.to_path_buf(),
+                ));
+            }
+        }
 
-                // If the `pyproject.toml` does not exist, the GitHub API will return a 404.
-                if response.status() == StatusCode::NOT_FOUND {
-                    return Ok::<Option<String>, Error>(None);
-                }
-                response.error_for_status_ref()?;
+        let node = fetch
+            .hg()
+            .precise()
+            .expect("Exact changeset after checkout");
+        let cache_shard = self.build_context.cache().shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Hg(resource.url, node.as_short_str()).root(),
+        );
+        let metadata_entry = cache_shard.entry(METADATA);
 
-                let content = response.text().await?;
-                Ok::<Option<String>, Error>(Some(content))
-            })
-            .await?;
+        // Acquire the advisory lock.
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
-        let Some(content) = content else {
-            debug!("GitHub API returned a 404 for: {url}");
-            return Ok(None);
+        // If there are build settings, we need to scope to a cache shard.
+        let config_settings = self.build_context.config_settings();
+        let cache_shard = if config_settings.is_empty() {
+            cache_shard
+        } else {
+            cache_shard.shard(cache_digest(config_settings))
         };
 
-        // Parse the `pyproject.toml`.
-        let pyproject_toml = match PyProjectToml::from_toml(&content) {
-            Ok(metadata) => metadata,
-            Err(
-                uv_pypi_types::MetadataError::InvalidPyprojectTomlSyntax(..)
-                | uv_pypi_types::MetadataError::InvalidPyprojectTomlSchema(..),
-            ) => {
-                debug!("Failed to read `pyproject.toml` from GitHub API for: {url}");
-                return Ok(None);
-            }
-            Err(err) => return Err(err.into()),
-        };
+        // If the cache contains a compatible wheel, return it.
+        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
+            .ok()
+            .flatten()
+            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
+        {
+            return Ok(built_wheel);
+        }
 
-        // Parse the metadata.
-        let metadata =
-            match ResolutionMetadata::parse_pyproject_toml(pyproject_toml, source.version()) {
-                Ok(metadata) => metadata,
-                Err(
-                    uv_pypi_types::MetadataError::Pep508Error(..)
-                    | uv_pypi_types::MetadataError::DynamicField(..)
-                    | uv_pypi_types::MetadataError::FieldNotFound(..)
-                    | uv_pypi_types::MetadataError::PoetrySyntax,
-                ) => {
-                    debug!("Failed to extract static metadata from GitHub API for: {url}");
-                    return Ok(None);
-                }
-                Err(err) => return Err(err.into()),
-            };
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_build_start(source));
 
-        // Determine whether the project has `tool.uv.sources`. If the project has sources, it must
-        // be lowered, which requires access to the workspace. For example, it could have workspace
-        // members that need to be translated to concrete paths on disk.
-        //
-        // TODO(charlie): We could still use the `pyproject.toml` if the sources are all `git` or
-        // `url` sources; this is only applicable to `workspace` and `path` sources. It's awkward,
-        // though, because we'd need to pass a path into the lowering routine, and that path would
-        // be incorrect (we'd just be relying on it not being used).
-        match has_sources(&content) {
-            Ok(false) => {}
-            Ok(true) => {
-                debug!("Skipping GitHub fast path; `pyproject.toml` has sources: {url}");
-                return Ok(None);
-            }
-            Err(err) => {
-                debug!("Failed to parse `tool.uv.sources` from GitHub API for: {url} ({err})");
-                return Ok(None);
+        let (disk_filename, filename, metadata) = self
+            .build_distribution(
+                source,
+                fetch.path(),
+                resource.subdirectory,
+                &cache_shard,
+                self.build_context.sources(),
+            )
+            .await?;
+
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_build_complete(source, task);
             }
         }
 
-        Ok(Some(metadata))
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(BuiltWheelMetadata {
+            path: cache_shard.join(&disk_filename),
+            target: cache_shard.join(filename.stem()),
+            filename,
+            hashes: HashDigests::empty(),
+            cache_info: CacheInfo::default(),
+        })
     }
 
-    /// Heal a [`Revision`] for a local archive.
-    async fn heal_archive_revision(
+    /// Build the source distribution's metadata from a Mercurial repository.
+    ///
+    /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
+    /// building the wheel.
+    async fn hg_metadata(
         &self,
         source: &BuildableSource<'_>,
-        resource: &PathSourceUrl<'_>,
-        entry: &CacheEntry,
-        revision: Revision,
+        resource: &HgSourceUrl<'_>,
         hashes: HashPolicy<'_>,
-    ) -> Result<Revision, Error> {
-        warn!("Re-extracting missing source distribution: {source}");
+    ) -> Result<ArchiveMetadata, Error> {
+        // Before running the build, check that the hashes match.
+        if hashes.is_validate() {
+            return Err(Error::HashesNotSupportedHg(source.to_string()));
+        }
 
-        // Take the union of the requested and existing hash algorithms.
-        let algorithms = {
-            let mut algorithms = hashes.algorithms();
-            for digest in revision.hashes() {
-                algorithms.push(digest.algorithm());
-            }
-            algorithms.sort();
-            algorithms.dedup();
-            algorithms
-        };
+        // Fetch the Mercurial repository.
+        let fetch = self.fetch_hg(resource.hg).await?;
 
-        let hashes = self
-            .persist_archive(&resource.path, resource.ext, entry.path(), &algorithms)
+        // Validate that the subdirectory exists.
+        if let Some(subdirectory) = resource.subdirectory {
+            if !fetch.path().join(subdirectory).is_dir() {
+                return Err(Error::MissingSubdirectory(
+                    resource.url.to_url(),
+                    subdirectory.to_path_buf(),
+                ));
+            }
+        }
+
+        let node = fetch
+            .hg()
+            .precise()
+            .expect("Exact changeset after checkout");
+        let cache_shard = self.build_context.cache().shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Hg(resource.url, node.as_short_str()).root(),
+        );
+        let metadata_entry = cache_shard.entry(METADATA);
+
+        // Acquire the advisory lock.
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        let path = if let Some(subdirectory) = resource.subdirectory {
+            Cow::Owned(fetch.path().join(subdirectory))
+        } else {
+            Cow::Borrowed(fetch.path())
+        };
+
+        // If the metadata is static, return it.
+        let dynamic =
+            match StaticMetadata::read(source, fetch.path(), resource.subdirectory).await? {
+                StaticMetadata::Some(metadata) => {
+                    return Ok(ArchiveMetadata::from(
+                        Metadata::from_workspace(
+                            metadata,
+                            &path,
+                            None,
+                            self.build_context.locations(),
+                            self.build_context.sources(),
+                        )
+                        .await?,
+                    ));
+                }
+                StaticMetadata::Dynamic => true,
+                StaticMetadata::None => false,
+            };
+
+        // If the cache contains compatible metadata, return it.
+        if self
+            .build_context
+            .cache()
+            .freshness(&metadata_entry, source.name())
+            .map_err(Error::CacheRead)?
+            .is_fresh()
+        {
+            match CachedMetadata::read(&metadata_entry).await {
+                Ok(Some(metadata)) => {
+                    if metadata.matches(source.name(), source.version()) {
+                        debug!("Using cached metadata for: {source}");
+                        return Ok(ArchiveMetadata::from(
+                            Metadata::from_workspace(
+                                metadata.into(),
+                                &path,
+                                None,
+                                self.build_context.locations(),
+                                self.build_context.sources(),
+                            )
+                            .await?,
+                        ));
+                    }
+                    debug!(
+                        "Cached metadata does not match expected name and version for: {source}"
+                    );
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    debug!("Failed to deserialize cached metadata for: {source} ({err})");
+                }
+            }
+        }
+
+        // If the backend supports `prepare_metadata_for_build_wheel`, use it.
+        let metadata = if let Some(metadata) = self
+            .build_metadata(
+                source,
+                fetch.path(),
+                resource.subdirectory,
+                self.build_context.sources(),
+            )
+            .boxed_local()
+            .await?
+        {
+            fs::create_dir_all(metadata_entry.dir())
+                .await
+                .map_err(Error::CacheWrite)?;
+            metadata
+        } else {
+            // If there are build settings, we need to scope to a cache shard.
+            let config_settings = self.build_context.config_settings();
+            let cache_shard = if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(config_settings))
+            };
+
+            // Otherwise, we need to build a wheel.
+            let task = self
+                .reporter
+                .as_ref()
+                .map(|reporter| reporter.on_build_start(source));
+
+            let (_disk_filename, _filename, metadata) = self
+                .build_distribution(
+                    source,
+                    fetch.path(),
+                    resource.subdirectory,
+                    &cache_shard,
+                    self.build_context.sources(),
+                )
+                .await?;
+
+            if let Some(task) = task {
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_build_complete(source, task);
+                }
+            }
+            metadata
+        };
+
+        // If necessary, mark the metadata as dynamic.
+        let metadata = if dynamic {
+            ResolutionMetadata {
+                dynamic: true,
+                ..metadata
+            }
+        } else {
+            metadata
+        };
+
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(ArchiveMetadata::from(
+            Metadata::from_workspace(
+                metadata,
+                &path,
+                None,
+                self.build_context.locations(),
+                self.build_context.sources(),
+            )
+            .await?,
+        ))
+    }
+
+    /// Resolve a source to a specific revision.
+    pub(crate) async fn resolve_revision(
+        &self,
+        source: &BuildableSource<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<(), Error> {
+        let git = match source {
+            BuildableSource::Dist(SourceDist::Git(source)) => &*source.git,
+            BuildableSource::Url(SourceUrl::Git(source)) => source.git,
+            BuildableSource::Dist(SourceDist::Hg(source)) => {
+                self.fetch_hg(&source.hg).await?;
+                return Ok(());
+            }
+            BuildableSource::Url(SourceUrl::Hg(source)) => {
+                self.fetch_hg(source.hg).await?;
+                return Ok(());
+            }
+            _ => {
+                return Ok(());
+            }
+        };
+
+        // If this is GitHub URL, attempt to resolve to a precise commit using the GitHub API.
+        if self
+            .build_context
+            .git()
+            .github_fast_path(
+                git,
+                client.unmanaged.uncached_client(git.repository()).clone(),
+            )
+            .await?
+            .is_some()
+        {
+            debug!("Resolved to precise commit via GitHub fast path: {source}");
+            return Ok(());
+        }
+
+        // Otherwise, fetch the Git repository.
+        self.build_context
+            .git()
+            .fetch(
+                git,
+                client.unmanaged.uncached_client(git.repository()).clone(),
+                client.unmanaged.disable_ssl(git.repository()),
+                client.unmanaged.proxy(git.repository()),
+                self.build_context.cache().bucket(CacheBucket::Git),
+                self.reporter
+                    .clone()
+                    .map(|reporter| reporter.into_git_reporter()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Fetch static [`ResolutionMetadata`] from a GitHub repository, if possible.
+    ///
+    /// Attempts to fetch the `pyproject.toml` from the resolved commit using the GitHub API.
+    async fn github_metadata(
+        &self,
+        commit: GitOid,
+        source: &BuildableSource<'_>,
+        resource: &GitSourceUrl<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<ResolutionMetadata>, Error> {
+        let GitSourceUrl {
+            git, subdirectory, ..
+        } = resource;
+
+        // The fast path isn't available for subdirectories. If a `pyproject.toml` is in a
+        // subdirectory, it could be part of a workspace; and if it's part of a workspace, it could
+        // have `tool.uv.sources` entries that it inherits from the workspace root.
+        if subdirectory.is_some() {
+            return Ok(None);
+        }
+
+        let Some(GitHubRepository { owner, repo }) = GitHubRepository::parse(git.repository())
+        else {
+            return Ok(None);
+        };
+
+        // Fetch the `pyproject.toml` from the resolved commit.
+        let url =
+            format!("https://raw.githubusercontent.com/{owner}/{repo}/{commit}/pyproject.toml");
+
+        debug!("Attempting to fetch `pyproject.toml` from: {url}");
+
+        let content = client
+            .managed(|client| async {
+                let response = client
+                    .uncached_client(git.repository())
+                    .get(&url)
+                    .send()
+                    .await?;
+
+                // If the `pyproject.toml` does not exist, the GitHub API will return a 404.
+                if response.status() == StatusCode::NOT_FOUND {
+                    return Ok::<Option<String>, Error>(None);
+                }
+                response.error_for_status_ref()?;
+
+                let content = response.text().await?;
+                Ok::<Option<String>, Error>(Some(content))
+            })
+            .await?;
+
+        let Some(content) = content else {
+            debug!("GitHub API returned a 404 for: {url}");
+            return Ok(None);
+        };
+
+        // Parse the `pyproject.toml`.
+        let pyproject_toml = match PyProjectToml::from_toml(&content) {
+            Ok(metadata) => metadata,
+            Err(
+                uv_pypi_types::MetadataError::InvalidPyprojectTomlSyntax(..)
+                | uv_pypi_types::MetadataError::InvalidPyprojectTomlSchema(..),
+            ) => {
+                debug!("Failed to read `pyproject.toml` from GitHub API for: {url}");
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Parse the metadata.
+        let metadata =
+            match ResolutionMetadata::parse_pyproject_toml(pyproject_toml, source.version()) {
+                Ok(metadata) => metadata,
+                Err(
+                    uv_pypi_types::MetadataError::Pep508Error(..)
+                    | uv_pypi_types::MetadataError::DynamicField(..)
+                    | uv_pypi_types::MetadataError::FieldNotFound(..)
+                    | uv_pypi_types::MetadataError::PoetrySyntax,
+                ) => {
+                    debug!("Failed to extract static metadata from GitHub API for: {url}");
+                    return Ok(None);
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+        // Determine whether the project has `tool.uv.sources`. If the project has sources, it must
+        // be lowered, which requires access to the workspace. For example, it could have workspace
+        // members that need to be translated to concrete paths on disk.
+        //
+        // TODO(charlie): We could still use the `pyproject.toml` if the sources are all `git` or
+        // `url` sources; this is only applicable to `workspace` and `path` sources. It's awkward,
+        // though, because we'd need to pass a path into the lowering routine, and that path would
+        // be incorrect (we'd just be relying on it not being used).
+        match has_sources(&content) {
+            Ok(false) => {}
+            Ok(true) => {
+                debug!("Skipping GitHub fast path; `pyproject.toml` has sources: {url}");
+                return Ok(None);
+            }
+            Err(err) => {
+                debug!("Failed to parse `tool.uv.sources` from GitHub API for: {url} ({err})");
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(metadata))
+    }
+
+    /// Unpack the source tree of a source distribution, if it was compressed after going unused
+    /// (see [`Cache::compress_sources`]).
+    ///
+    /// If the archive can't be unpacked, the source tree is left missing, such that the revision
+    /// is healed instead.
+    async fn decompress_source(&self, entry: &CacheEntry) {
+        if entry.path().is_dir() {
+            return;
+        }
+        let archive = compressed_path(entry.path());
+        if !archive.is_file() {
+            return;
+        }
+
+        debug!("Decompressing source distribution: {}", archive.display());
+        if let Err(err) = self.unpack_source(&archive, entry.path()).await {
+            warn!(
+                "Failed to decompress source distribution at `{}`: {err}",
+                archive.display()
+            );
+        }
+    }
+
+    /// Unpack a compressed source tree to the given path, removing the archive.
+    async fn unpack_source(&self, archive: &Path, target: &Path) -> Result<(), Error> {
+        let temp_dir = tempfile::tempdir_in(
+            self.build_context
+                .cache()
+                .bucket(CacheBucket::SourceDistributions),
+        )
+        .map_err(Error::CacheWrite)?;
+        let reader = fs::File::open(archive).await.map_err(Error::CacheRead)?;
+        uv_extract::stream::untar_zst(reader, temp_dir.path()).await?;
+
+        let name = target.file_name().expect("Cache entry to have file name");
+        rename_with_retry(temp_dir.path().join(name), target)
+            .await
+            .map_err(Error::CacheWrite)?;
+        fs::remove_file(archive).await.map_err(Error::CacheWrite)?;
+        Ok(())
+    }
+
+    /// Heal a [`Revision`] for a local archive.
+    async fn heal_archive_revision(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &PathSourceUrl<'_>,
+        entry: &CacheEntry,
+        revision: Revision,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Revision, Error> {
+        warn!("Re-extracting missing source distribution: {source}");
+
+        // Take the union of the requested and existing hash algorithms.
+        let algorithms = {
+            let mut algorithms = hashes.algorithms();
+            for digest in revision.hashes() {
+                algorithms.push(digest.algorithm());
+            }
+            algorithms.sort();
+            algorithms.dedup();
+            algorithms
+        };
+
+        let hashes = self
+            .persist_archive(&resource.path, resource.ext, entry.path(), &algorithms)
             .await?;
         for existing in revision.hashes() {
             if !hashes.contains(existing) {
@@ -2255,18 +2629,407 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         validate_metadata(source, &metadata)?;
         validate_filename(&filename, &metadata)?;
 
-        // Move the wheel to the cache.
-        rename_with_retry(
+        // Move the wheel to the cache. If another process (e.g., on a machine that shares the cache)
+        // moved the same wheel into place concurrently, and it's in use, retain it.
+        if let Err(err) = rename_with_retry(
             temp_dir.path().join(&disk_filename),
             cache_shard.join(&disk_filename),
         )
         .await
-        .map_err(Error::CacheWrite)?;
+        {
+            if !cache_shard.join(&disk_filename).is_file() {
+                return Err(Error::CacheWrite(err));
+            }
+            debug!("Retaining existing wheel for {source}: {err}");
+        }
 
         debug!("Finished building: {source}");
         Ok((disk_filename, filename, metadata))
     }
 
+    /// Return the remote cache entries for a source distribution, if a remote cache is configured
+    /// and the SHA-256 hash of the source distribution is known.
+    fn remote_cache<'b>(
+        &'b self,
+        hashes: &[HashDigest],
+        subdirectory: Option<&Path>,
+        client: &'b ManagedClient<'_>,
+    ) -> Option<RemoteCache<'b>> {
+        let root = self.build_context.cache().remote()?;
+        RemoteCache::new(
+            root,
+            client.unmanaged,
+            hashes,
+            subdirectory,
+            self.build_context.config_settings(),
+        )
+    }
+
+    /// Return the cache shard for the wheels built from a source distribution in the current build
+    /// environment, which is shared by every source of the same source distribution.
+    ///
+    /// Returns `None` if the name or SHA-256 hash of the source distribution isn't known, or if the
+    /// package is being refreshed.
+    async fn artifact_shard(
+        &self,
+        source: &BuildableSource<'_>,
+        source_root: &Path,
+        subdirectory: Option<&Path>,
+        hashes: &[HashDigest],
+        tags: &Tags,
+    ) -> Option<CacheShard> {
+        let name = source.name()?;
+        let cache = self.build_context.cache();
+        if cache.must_revalidate(name) {
+            return None;
+        }
+
+        let interpreter = self.build_context.interpreter();
+        let source_tree = subdirectory.map_or_else(
+            || source_root.to_path_buf(),
+            |subdirectory| source_root.join(subdirectory),
+        );
+        let environment = BuildEnvironment {
+            interpreter: format!(
+                "{}-{}",
+                interpreter.implementation_name(),
+                interpreter.python_full_version()
+            ),
+            base_prefix: interpreter.sys_base_prefix(),
+            tag: remote_tag(tags)?,
+            requires: artifacts::build_requires(&source_tree).await,
+        };
+        let key = artifacts::key(
+            hashes,
+            subdirectory,
+            self.build_context.config_settings(),
+            &environment,
+        )?;
+        Some(cache.shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Built(&key).wheel_dir(name.as_ref()),
+        ))
+    }
+
+    /// Build a source distribution, storing the built wheel in the cache.
+    ///
+    /// If the same source distribution was already built in the same build environment (e.g., for
+    /// another project, or from another index), the existing wheel is reused instead; otherwise,
+    /// the built wheel is stored for reuse.
+    ///
+    /// If a remote cache is configured, a wheel built from the same source distribution for the
+    /// same platform is fetched from the remote cache instead; otherwise, the built wheel is
+    /// stored in the remote cache.
+    async fn build_distribution_or_fetch(
+        &self,
+        source: &BuildableSource<'_>,
+        source_root: &Path,
+        subdirectory: Option<&Path>,
+        cache_shard: &CacheShard,
+        hashes: &[HashDigest],
+        tags: &Tags,
+        client: &ManagedClient<'_>,
+    ) -> Result<(String, WheelFilename, ResolutionMetadata), Error> {
+        let artifacts = self
+            .artifact_shard(source, source_root, subdirectory, hashes, tags)
+            .await;
+
+        if let Some(artifacts) = &artifacts {
+            if let Some(built) = self
+                .fetch_artifact_wheel(source, artifacts, cache_shard, tags)
+                .await?
+            {
+                return Ok(built);
+            }
+        }
+
+        let remote = self
+            .remote_cache(hashes, subdirectory, client)
+            .zip(remote_tag(tags));
+
+        if let Some((remote, tag)) = &remote {
+            if let Some(built) = self
+                .fetch_remote_wheel(source, remote, tag, cache_shard, tags)
+                .await?
+            {
+                return Ok(built);
+            }
+        }
+
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_build_start(source));
+
+        // Build the source distribution.
+        let (disk_filename, wheel_filename, metadata) = self
+            .build_distribution(
+                source,
+                source_root,
+                subdirectory,
+                cache_shard,
+                SourceStrategy::Disabled,
+            )
+            .await?;
+
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_build_complete(source, task);
+            }
+        }
+
+        // Share the built wheel with other sources of the same source distribution.
+        if let Some(artifacts) = &artifacts {
+            if let Err(err) = self
+                .store_artifact_wheel(source, artifacts, cache_shard, &disk_filename, &metadata)
+                .await
+            {
+                warn!("Failed to store built wheel for {source}: {err}");
+            }
+        }
+
+        // Share the built wheel with other machines.
+        if let Some((remote, tag)) = &remote {
+            match fs::read(cache_shard.join(&disk_filename)).await {
+                Ok(contents) => remote.put_wheel(tag, &disk_filename, &contents).await,
+                Err(err) => warn!("Failed to read built wheel for remote cache: {err}"),
+            }
+        }
+
+        Ok((disk_filename, wheel_filename, metadata))
+    }
+
+    /// Fetch a wheel built from the same source distribution in the same build environment,
+    /// storing it in the cache shard for the source distribution.
+    ///
+    /// Returns `None` if no compatible wheel was built.
+    async fn fetch_artifact_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        artifacts: &CacheShard,
+        cache_shard: &CacheShard,
+        tags: &Tags,
+    ) -> Result<Option<(String, WheelFilename, ResolutionMetadata)>, Error> {
+        let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, artifacts)
+            .ok()
+            .flatten()
+            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
+        else {
+            return Ok(None);
+        };
+        let Some(metadata) = CachedMetadata::read(&artifacts.entry(METADATA))
+            .await
+            .ok()
+            .flatten()
+            .filter(|metadata| metadata.matches(source.name(), source.version()))
+        else {
+            return Ok(None);
+        };
+        let Some(disk_filename) = built_wheel
+            .path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(ToString::to_string)
+        else {
+            return Ok(None);
+        };
+
+        // Record that the source distribution was also supplied by this source, such that the
+        // shared wheel is removed alongside the other entries of either index.
+        if let Some(index) = source.as_dist().and_then(SourceDist::index) {
+            artifacts.record_origin(index).map_err(Error::CacheWrite)?;
+        }
+
+        // Link the wheel into a temporary directory, to prevent partial writes.
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+        link_or_copy(&built_wheel.path, &temp_dir.path().join(&disk_filename))
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        // Move the wheel to the cache.
+        fs::create_dir_all(&cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        if let Err(err) = rename_with_retry(
+            temp_dir.path().join(&disk_filename),
+            cache_shard.join(&disk_filename),
+        )
+        .await
+        {
+            if !cache_shard.join(&disk_filename).is_file() {
+                return Err(Error::CacheWrite(err));
+            }
+            debug!("Retaining existing wheel for {source}: {err}");
+        }
+
+        debug!("Using previously built wheel for: {source}");
+        Ok(Some((disk_filename, built_wheel.filename, metadata.into())))
+    }
+
+    /// Store a wheel built from a source distribution, such that it can be reused by other
+    /// sources of the same source distribution.
+    async fn store_artifact_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        artifacts: &CacheShard,
+        cache_shard: &CacheShard,
+        disk_filename: &str,
+        metadata: &ResolutionMetadata,
+    ) -> Result<(), Error> {
+        // Write the metadata (and the index that supplied the source distribution) first, such
+        // that the wheel is never visible without it.
+        fs::create_dir_all(&artifacts)
+            .await
+            .map_err(Error::CacheWrite)?;
+        if let Some(index) = source.as_dist().and_then(SourceDist::index) {
+            artifacts.record_origin(index).map_err(Error::CacheWrite)?;
+        }
+        write_atomic(
+            artifacts.entry(METADATA).path(),
+            rmp_serde::to_vec(metadata)?,
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
+
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+        link_or_copy(
+            &cache_shard.join(disk_filename),
+            &temp_dir.path().join(disk_filename),
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
+        rename_with_retry(
+            temp_dir.path().join(disk_filename),
+            artifacts.join(disk_filename),
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
+
+        Ok(())
+    }
+
+    /// Fetch a wheel built from a source distribution from the remote cache, storing it in the
+    /// local cache.
+    ///
+    /// Returns `None` if the remote cache doesn't contain a compatible wheel.
+    async fn fetch_remote_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        remote: &RemoteCache<'_>,
+        tag: &str,
+        cache_shard: &CacheShard,
+        tags: &Tags,
+    ) -> Result<Option<(String, WheelFilename, ResolutionMetadata)>, Error> {
+        let Some((disk_filename, contents)) = remote.wheel(tag).await else {
+            return Ok(None);
+        };
+
+        // Reject filenames that would escape the cache, or that don't match the platform.
+        if disk_filename.contains(['/', '\\']) {
+            warn!(
+                "Ignoring remote cache entry with invalid filename for {source}: {disk_filename}"
+            );
+            return Ok(None);
+        }
+        let Some(filename) = WheelFilename::from_str(&disk_filename)
+            .ok()
+            .filter(|filename| filename.is_compatible(tags))
+        else {
+            warn!(
+                "Ignoring remote cache entry with incompatible wheel for {source}: {disk_filename}"
+            );
+            return Ok(None);
+        };
+
+        // Write the wheel to a temporary directory, to prevent partial writes.
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+        fs::write(temp_dir.path().join(&disk_filename), contents)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        // Read and validate the metadata from the wheel.
+        let metadata = match read_wheel_metadata(&filename, &temp_dir.path().join(&disk_filename))
+            .and_then(|metadata| {
+                validate_metadata(source, &metadata)?;
+                validate_filename(&filename, &metadata)?;
+                Ok(metadata)
+            }) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                warn!("Ignoring invalid remote cache entry for {source}: {err}");
+                return Ok(None);
+            }
+        };
+
+        // Move the wheel to the cache.
+        fs::create_dir_all(&cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        if let Err(err) = rename_with_retry(
+            temp_dir.path().join(&disk_filename),
+            cache_shard.join(&disk_filename),
+        )
+        .await
+        {
+            if !cache_shard.join(&disk_filename).is_file() {
+                return Err(Error::CacheWrite(err));
+            }
+            debug!("Retaining existing wheel for {source}: {err}");
+        }
+
+        debug!("Using wheel from remote cache for: {source}");
+        Ok(Some((disk_filename, filename, metadata)))
+    }
+
+    /// Fetch the metadata of a source distribution from the remote cache, storing it in the
+    /// local cache.
+    ///
+    /// Returns `None` if the remote cache doesn't contain matching metadata.
+    async fn fetch_remote_metadata(
+        &self,
+        source: &BuildableSource<'_>,
+        remote: &RemoteCache<'_>,
+        metadata_entry: &CacheEntry,
+    ) -> Result<Option<ResolutionMetadata>, Error> {
+        let Some(data) = remote.metadata().await else {
+            return Ok(None);
+        };
+        let metadata = match rmp_serde::from_slice::<ResolutionMetadata>(&data) {
+            Ok(metadata) => CachedMetadata(metadata),
+            Err(err) => {
+                warn!("Ignoring invalid remote cache entry for {source}: {err}");
+                return Ok(None);
+            }
+        };
+        if !metadata.matches(source.name(), source.version()) {
+            debug!("Remote metadata does not match expected name and version for: {source}");
+            return Ok(None);
+        }
+
+        // Store the metadata.
+        fs::create_dir_all(metadata_entry.dir())
+            .await
+            .map_err(Error::CacheWrite)?;
+        write_atomic(metadata_entry.path(), data)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        debug!("Using metadata from remote cache for: {source}");
+        Ok(Some(metadata.into()))
+    }
+
     /// Build the metadata for a source distribution.
     #[instrument(skip_all, fields(dist = %source))]
     async fn build_metadata(
@@ -2784,6 +3547,26 @@ impl From<CachedMetadata> for ResolutionMetadata {
     }
 }
 
+/// Hard link a file, falling back to a copy if the link can't be created (e.g., across
+/// filesystems).
+async fn link_or_copy(from: &Path, to: &Path) -> std::io::Result<()> {
+    if fs::hard_link(from, to).await.is_err() {
+        fs::copy(from, to).await?;
+    }
+    Ok(())
+}
+
+/// Return the tag under which wheels built for the given platform are stored in the remote cache,
+/// e.g., `cp312-cp312-manylinux_2_17_x86_64`.
+fn remote_tag(tags: &Tags) -> Option<String> {
+    Some(format!(
+        "{}-{}-{}",
+        tags.python_tag()?,
+        tags.abi_tag()?,
+        tags.platform_tag()?
+    ))
+}
+
 /// Read the [`ResolutionMetadata`] from a built wheel.
 fn read_wheel_metadata(
     filename: &WheelFilename,
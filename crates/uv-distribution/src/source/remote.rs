@@ -0,0 +1,198 @@
+//! A remote cache of the artifacts built from source distributions, shared between machines
+//! (e.g., ephemeral CI runners).
+
+use std::path::Path;
+
+use reqwest::StatusCode;
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_cache_key::cache_digest;
+use uv_client::RegistryClient;
+use uv_configuration::ConfigSettings;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// The version of the layout of the remote cache.
+const REMOTE_CACHE_VERSION: &str = "v0";
+
+/// The remote cache entries for a single source distribution.
+///
+/// Entries are keyed by the SHA-256 hash of the source distribution, along with the subdirectory
+/// and build settings, such that they're shared by any machine that builds the same source.
+///
+/// The remote cache is read with `GET` requests and written with `PUT` requests, such that any
+/// HTTP server or object store that supports both can act as a remote cache. The remote cache is
+/// an optimization: failures to read from or write to it are logged, but otherwise ignored.
+pub(crate) struct RemoteCache<'a> {
+    client: &'a RegistryClient,
+    /// The URL under which the entries for the source distribution are stored.
+    url: Url,
+}
+
+impl<'a> RemoteCache<'a> {
+    /// Return the remote cache entries for the source distribution with the given hashes.
+    ///
+    /// Returns `None` if the SHA-256 hash of the source distribution isn't known.
+    pub(crate) fn new(
+        root: &Url,
+        client: &'a RegistryClient,
+        hashes: &[HashDigest],
+        subdirectory: Option<&Path>,
+        config_settings: &ConfigSettings,
+    ) -> Option<Self> {
+        let Some(key) = key(hashes, subdirectory, config_settings) else {
+            debug!("Skipping remote cache for source distribution without a SHA-256 hash");
+            return None;
+        };
+
+        // Ensure that the root is treated as a directory when joining.
+        let mut root = root.clone();
+        if !root.path().ends_with('/') {
+            root.set_path(&format!("{}/", root.path()));
+        }
+        let url = root.join(&format!("{REMOTE_CACHE_VERSION}/{key}/")).ok()?;
+        Some(Self { client, url })
+    }
+
+    /// Fetch the serialized metadata of the source distribution, if present.
+    pub(crate) async fn metadata(&self) -> Option<Vec<u8>> {
+        self.get("metadata.msgpack").await
+    }
+
+    /// Store the serialized metadata of the source distribution.
+    pub(crate) async fn put_metadata(&self, data: Vec<u8>) {
+        self.put("metadata.msgpack", data).await;
+    }
+
+    /// Fetch the wheel built from the source distribution for the given platform tag, if present,
+    /// returning its filename and contents.
+    pub(crate) async fn wheel(&self, tag: &str) -> Option<(String, Vec<u8>)> {
+        let data = self.get(&format!("wheels/{tag}")).await?;
+
+        // The filename is stored on the first line, followed by the contents of the wheel.
+        let newline = data.iter().position(|&byte| byte == b'\n')?;
+        let filename = std::str::from_utf8(&data[..newline]).ok()?.to_string();
+        Some((filename, data[newline + 1..].to_vec()))
+    }
+
+    /// Store the wheel built from the source distribution for the given platform tag.
+    pub(crate) async fn put_wheel(&self, tag: &str, filename: &str, contents: &[u8]) {
+        let mut data = Vec::with_capacity(filename.len() + 1 + contents.len());
+        data.extend_from_slice(filename.as_bytes());
+        data.push(b'\n');
+        data.extend_from_slice(contents);
+        self.put(&format!("wheels/{tag}"), data).await;
+    }
+
+    async fn get(&self, name: &str) -> Option<Vec<u8>> {
+        let url = self.url.join(name).ok()?;
+        let response = match self
+            .client
+            .uncached_client(&url)
+            .get(url.clone())
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                warn!("Failed to read from remote cache at `{url}`: {err}");
+                return None;
+            }
+        };
+        if response.status() == StatusCode::NOT_FOUND {
+            debug!("No remote cache entry at: {url}");
+            return None;
+        }
+        match response.error_for_status() {
+            Ok(response) => match response.bytes().await {
+                Ok(bytes) => {
+                    debug!("Using remote cache entry at: {url}");
+                    Some(bytes.to_vec())
+                }
+                Err(err) => {
+                    warn!("Failed to read from remote cache at `{url}`: {err}");
+                    None
+                }
+            },
+            Err(err) => {
+                warn!("Failed to read from remote cache at `{url}`: {err}");
+                None
+            }
+        }
+    }
+
+    async fn put(&self, name: &str, data: Vec<u8>) {
+        let Ok(url) = self.url.join(name) else {
+            return;
+        };
+        match self
+            .client
+            .uncached_client(&url)
+            .put(url.clone())
+            .body(data)
+            .send()
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|response| response.error_for_status().map_err(|err| err.to_string()))
+        {
+            Ok(_) => debug!("Stored remote cache entry at: {url}"),
+            Err(err) => warn!("Failed to write to remote cache at `{url}`: {err}"),
+        }
+    }
+}
+
+/// Return the key for the remote cache entries of a source distribution, if its SHA-256 hash is
+/// known.
+fn key(
+    hashes: &[HashDigest],
+    subdirectory: Option<&Path>,
+    config_settings: &ConfigSettings,
+) -> Option<String> {
+    let sha256 = hashes
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?;
+    Some(cache_digest(&(
+        sha256.digest.as_str(),
+        subdirectory,
+        config_settings,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_configuration::ConfigSettings;
+    use uv_pypi_types::HashDigest;
+
+    use super::key;
+
+    #[test]
+    fn remote_key() {
+        let sha256 = HashDigest::from_str(
+            "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2",
+        )
+        .unwrap();
+        let md5 = HashDigest::from_str("md5:d41d8cd98f00b204e9800998ecf8427e").unwrap();
+        let settings = ConfigSettings::default();
+
+        // The key is stable, and depends on the subdirectory.
+        let root = key(&[md5.clone(), sha256.clone()], None, &settings).unwrap();
+        assert_eq!(
+            key(std::slice::from_ref(&sha256), None, &settings),
+            Some(root.clone())
+        );
+        assert_ne!(
+            key(
+                std::slice::from_ref(&sha256),
+                Some(Path::new("pkg")),
+                &settings
+            ),
+            Some(root)
+        );
+
+        // Without a SHA-256 hash, there's no key.
+        assert_eq!(key(&[md5], None, &settings), None);
+    }
+}
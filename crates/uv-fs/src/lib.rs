@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use fs2::FileExt;
 use tempfile::NamedTempFile;
@@ -592,11 +593,22 @@ pub fn is_temporary(path: impl AsRef<Path>) -> bool {
 
 /// A file lock that is automatically released when dropped.
 #[derive(Debug)]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile {
+    file: fs_err::File,
+    /// Whether the lock is held, as opposed to unsupported by the filesystem.
+    locked: bool,
+}
 
 impl LockedFile {
     /// Inner implementation for [`LockedFile::acquire_blocking`] and [`LockedFile::acquire`].
-    fn lock_file_blocking(file: fs_err::File, resource: &str) -> Result<Self, std::io::Error> {
+    ///
+    /// If a timeout is provided, fails with [`std::io::ErrorKind::TimedOut`] if the lock isn't
+    /// released by another process within the timeout.
+    fn lock_file_blocking(
+        file: fs_err::File,
+        resource: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Self, std::io::Error> {
         trace!(
             "Checking lock for `{resource}` at `{}`",
             file.path().user_display()
@@ -604,7 +616,20 @@ impl LockedFile {
         match file.file().try_lock_exclusive() {
             Ok(()) => {
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self { file, locked: true })
+            }
+            Err(err) if is_lock_unsupported(&err) => {
+                // Some network filesystems (e.g., NFS without a lock daemon) don't support
+                // locking. Proceed without the lock, relying on atomic writes for consistency.
+                warn!(
+                    "Proceeding without a lock for `{resource}`, as the filesystem at `{}` \
+                    doesn't support locking: {err}",
+                    file.path().user_display()
+                );
+                Ok(Self {
+                    file,
+                    locked: false,
+                })
             }
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures.
@@ -615,20 +640,52 @@ impl LockedFile {
                     "Waiting to acquire lock for `{resource}` at `{}`",
                     file.path().user_display(),
                 );
-                file.file().lock_exclusive().map_err(|err| {
-                    // Not an fs_err method, we need to build our own path context
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!(
-                            "Could not acquire lock for `{resource}` at `{}`: {}",
-                            file.path().user_display(),
-                            err
-                        ),
-                    )
-                })?;
+                if let Some(timeout) = timeout {
+                    let start = Instant::now();
+                    loop {
+                        std::thread::sleep(LOCK_POLL_INTERVAL);
+                        match file.file().try_lock_exclusive() {
+                            Ok(()) => break,
+                            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+                            Err(err) => {
+                                return Err(std::io::Error::new(
+                                    std::io::ErrorKind::Other,
+                                    format!(
+                                        "Could not acquire lock for `{resource}` at `{}`: {}",
+                                        file.path().user_display(),
+                                        err
+                                    ),
+                                ));
+                            }
+                        }
+                        if start.elapsed() >= timeout {
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::TimedOut,
+                                format!(
+                                    "Timed out after {}s waiting for the lock for `{resource}` at \
+                                    `{}`, which is held by another process",
+                                    timeout.as_secs(),
+                                    file.path().user_display(),
+                                ),
+                            ));
+                        }
+                    }
+                } else {
+                    file.file().lock_exclusive().map_err(|err| {
+                        // Not an fs_err method, we need to build our own path context
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!(
+                                "Could not acquire lock for `{resource}` at `{}`: {}",
+                                file.path().user_display(),
+                                err
+                            ),
+                        )
+                    })?;
+                }
 
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self { file, locked: true })
             }
         }
     }
@@ -642,7 +699,7 @@ impl LockedFile {
     ) -> Result<Self, std::io::Error> {
         let file = Self::create(path)?;
         let resource = resource.to_string();
-        Self::lock_file_blocking(file, &resource)
+        Self::lock_file_blocking(file, &resource, None)
     }
 
     /// Acquire a cross-process lock for a resource using a file at the provided path.
@@ -650,10 +707,22 @@ impl LockedFile {
     pub async fn acquire(
         path: impl AsRef<Path>,
         resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        Self::acquire_with_timeout(path, resource, None).await
+    }
+
+    /// Acquire a cross-process lock for a resource using a file at the provided path, waiting at
+    /// most `timeout` for another process to release it, if provided.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_with_timeout(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        timeout: Option<Duration>,
     ) -> Result<Self, std::io::Error> {
         let file = Self::create(path)?;
         let resource = resource.to_string();
-        tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
+        tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource, timeout))
+            .await?
     }
 
     #[cfg(unix)]
@@ -711,16 +780,43 @@ impl LockedFile {
 
 impl Drop for LockedFile {
     fn drop(&mut self) {
-        if let Err(err) = fs2::FileExt::unlock(self.0.file()) {
+        if !self.locked {
+            return;
+        }
+        if let Err(err) = fs2::FileExt::unlock(self.file.file()) {
             error!(
                 "Failed to unlock {}; program may be stuck: {}",
-                self.0.path().display(),
+                self.file.path().display(),
                 err
             );
         } else {
-            debug!("Released lock at `{}`", self.0.path().display());
+            debug!("Released lock at `{}`", self.file.path().display());
+        }
+    }
+}
+
+/// The interval at which to check whether a lock was released, when waiting with a timeout.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Returns `true` if a lock failed because the filesystem doesn't support locking.
+fn is_lock_unsupported(err: &io::Error) -> bool {
+    if err.kind() == io::ErrorKind::Unsupported {
+        return true;
+    }
+    #[cfg(unix)]
+    {
+        let unsupported = [
+            rustix::io::Errno::NOLCK.raw_os_error(),
+            rustix::io::Errno::OPNOTSUPP.raw_os_error(),
+        ];
+        if err
+            .raw_os_error()
+            .is_some_and(|code| unsupported.contains(&code))
+        {
+            return true;
         }
     }
+    false
 }
 
 /// An asynchronous reader that reports progress as bytes are read.
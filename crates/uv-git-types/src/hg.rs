@@ -0,0 +1,154 @@
+use thiserror::Error;
+use url::Url;
+
+use crate::GitOid;
+
+#[derive(Debug, Error)]
+pub enum HgUrlParseError {
+    #[error(
+        "Unsupported Mercurial URL scheme `{0}:` in `{1}` (expected one of `https:`, `ssh:`, or `file:`)"
+    )]
+    UnsupportedHgScheme(String, Url),
+}
+
+/// A URL reference to a Mercurial repository.
+///
+/// Mercurial changeset IDs share the representation of Git object IDs (i.e., 40 hexadecimal
+/// characters), so the precise changeset is stored as a [`GitOid`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Ord)]
+pub struct HgUrl {
+    /// The URL of the Mercurial repository, with any query parameters, fragments, and leading
+    /// `hg+` removed.
+    repository: Url,
+    /// The revision to use, which could be a branch, bookmark, tag, or changeset.
+    revision: Option<String>,
+    /// The precise changeset to use, if known.
+    precise: Option<GitOid>,
+}
+
+impl HgUrl {
+    /// Create a new [`HgUrl`] from a repository URL and a revision.
+    pub fn from_revision(
+        repository: Url,
+        revision: Option<String>,
+    ) -> Result<Self, HgUrlParseError> {
+        Self::from_fields(repository, revision, None)
+    }
+
+    /// Create a new [`HgUrl`] from a repository URL and a precise changeset, if known.
+    pub fn from_fields(
+        repository: Url,
+        revision: Option<String>,
+        precise: Option<GitOid>,
+    ) -> Result<Self, HgUrlParseError> {
+        match repository.scheme() {
+            "http" | "https" | "ssh" | "file" => {}
+            unsupported => {
+                return Err(HgUrlParseError::UnsupportedHgScheme(
+                    unsupported.to_string(),
+                    repository,
+                ))
+            }
+        }
+        Ok(Self {
+            repository,
+            revision,
+            precise,
+        })
+    }
+
+    /// Set the precise changeset to use for this Mercurial URL.
+    #[must_use]
+    pub fn with_precise(mut self, precise: GitOid) -> Self {
+        self.precise = Some(precise);
+        self
+    }
+
+    /// Return the [`Url`] of the Mercurial repository.
+    pub fn repository(&self) -> &Url {
+        &self.repository
+    }
+
+    /// Return the requested revision, if any.
+    pub fn revision(&self) -> Option<&str> {
+        self.revision.as_deref()
+    }
+
+    /// Return the revision to update to, defaulting to the tip of the `default` branch.
+    pub fn as_rev(&self) -> &str {
+        self.revision.as_deref().unwrap_or("default")
+    }
+
+    /// Return the precise changeset, if known.
+    pub fn precise(&self) -> Option<GitOid> {
+        self.precise
+    }
+}
+
+impl TryFrom<Url> for HgUrl {
+    type Error = HgUrlParseError;
+
+    /// Initialize a [`HgUrl`] source from a URL.
+    fn try_from(mut url: Url) -> Result<Self, Self::Error> {
+        // Remove any query parameters and fragments.
+        url.set_fragment(None);
+        url.set_query(None);
+
+        // If the URL ends with a revision, like `https://hg.example.com/MyProject@v1.0`, extract
+        // it.
+        let mut revision = None;
+        if let Some((prefix, suffix)) = url
+            .path()
+            .rsplit_once('@')
+            .map(|(prefix, suffix)| (prefix.to_string(), suffix.to_string()))
+        {
+            revision = Some(suffix);
+            url.set_path(&prefix);
+        }
+
+        Self::from_revision(url, revision)
+    }
+}
+
+impl From<HgUrl> for Url {
+    fn from(hg: HgUrl) -> Self {
+        let mut url = hg.repository;
+
+        // If we have a precise changeset, add `@` and the changeset ID to the URL; otherwise, add
+        // the requested revision.
+        if let Some(precise) = hg.precise {
+            url.set_path(&format!("{}@{}", url.path(), precise));
+        } else if let Some(revision) = hg.revision {
+            url.set_path(&format!("{}@{}", url.path(), revision));
+        }
+
+        url
+    }
+}
+
+impl std::fmt::Display for HgUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.repository)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::HgUrl;
+
+    #[test]
+    fn revision() {
+        let url = Url::parse("https://hg.example.com/project@1.0#subdirectory=pkg").unwrap();
+        let hg = HgUrl::try_from(url).unwrap();
+        assert_eq!(hg.repository().as_str(), "https://hg.example.com/project");
+        assert_eq!(hg.revision(), Some("1.0"));
+        assert_eq!(Url::from(hg).as_str(), "https://hg.example.com/project@1.0");
+
+        let url = Url::parse("ssh://hg@hg.example.com/project").unwrap();
+        let hg = HgUrl::try_from(url).unwrap();
+        assert_eq!(hg.revision(), None);
+        assert_eq!(hg.as_rev(), "default");
+    }
+}
@@ -1,4 +1,5 @@
 pub use crate::github::GitHubRepository;
+pub use crate::hg::{HgUrl, HgUrlParseError};
 pub use crate::oid::{GitOid, OidParseError};
 pub use crate::reference::GitReference;
 
@@ -6,6 +7,7 @@ use thiserror::Error;
 use url::Url;
 
 mod github;
+mod hg;
 mod oid;
 mod reference;
 
@@ -136,6 +138,7 @@ impl From<GitUrl> for Url {
             match git.reference {
                 GitReference::Branch(rev)
                 | GitReference::Tag(rev)
+                | GitReference::TagRange(rev)
                 | GitReference::BranchOrTag(rev)
                 | GitReference::NamedRef(rev)
                 | GitReference::BranchOrTagOrCommit(rev) => {
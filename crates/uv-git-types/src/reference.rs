@@ -8,6 +8,9 @@ pub enum GitReference {
     Branch(String),
     /// A specific tag.
     Tag(String),
+    /// A range of tags, given as version specifiers (e.g., `>=1.2,<2`), of which the tag with the
+    /// highest matching version is used.
+    TagRange(String),
     /// From a reference that's ambiguously a branch or tag.
     BranchOrTag(String),
     /// From a reference that's ambiguously a commit, branch, or tag.
@@ -31,10 +34,21 @@ impl GitReference {
         }
     }
 
+    /// Creates a [`GitReference`] from a tag, which could represent a specific tag or a range of
+    /// tags (e.g., `>=1.2,<2`).
+    pub fn from_tag(tag: String) -> Self {
+        if looks_like_version_specifiers(&tag) {
+            Self::TagRange(tag)
+        } else {
+            Self::Tag(tag)
+        }
+    }
+
     /// Converts the [`GitReference`] to a `str`.
     pub fn as_str(&self) -> Option<&str> {
         match self {
             Self::Tag(rev) => Some(rev),
+            Self::TagRange(rev) => Some(rev),
             Self::Branch(rev) => Some(rev),
             Self::BranchOrTag(rev) => Some(rev),
             Self::BranchOrTagOrCommit(rev) => Some(rev),
@@ -47,6 +61,7 @@ impl GitReference {
     pub fn as_rev(&self) -> &str {
         match self {
             Self::Tag(rev) => rev,
+            Self::TagRange(rev) => rev,
             Self::Branch(rev) => rev,
             Self::BranchOrTag(rev) => rev,
             Self::BranchOrTagOrCommit(rev) => rev,
@@ -60,6 +75,7 @@ impl GitReference {
         match self {
             Self::Branch(_) => "branch",
             Self::Tag(_) => "tag",
+            Self::TagRange(_) => "tag range",
             Self::BranchOrTag(_) => "branch or tag",
             Self::BranchOrTagOrCommit(_) => "branch, tag, or commit",
             Self::NamedRef(_) => "ref",
@@ -78,3 +94,9 @@ impl Display for GitReference {
 fn looks_like_commit_hash(rev: &str) -> bool {
     rev.len() >= 7 && rev.chars().all(|ch| ch.is_ascii_hexdigit())
 }
+
+/// Whether a `tag` looks like a set of version specifiers (e.g., `>=1.2,<2`), rather than a tag
+/// name (i.e., starts with a comparison operator).
+fn looks_like_version_specifiers(tag: &str) -> bool {
+    tag.trim_start().starts_with(['<', '>', '=', '!', '~'])
+}
@@ -4,7 +4,7 @@
 use std::env;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
-use std::str::{self};
+use std::str::{self, FromStr};
 use std::sync::LazyLock;
 
 use anyhow::{Context, Result};
@@ -16,13 +16,26 @@ use url::Url;
 
 use uv_fs::Simplified;
 use uv_git_types::{GitHubRepository, GitOid, GitReference};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_static::EnvVars;
 use uv_version::version;
 
+use crate::ssh::{ssh_command, SshError};
+
 /// A file indicates that if present, `git reset` has been done and a repo
 /// checkout is ready to go. See [`GitCheckout::reset`] for why we need this.
 const CHECKOUT_READY_LOCK: &str = ".ok";
 
+/// The name of the remote from which a [`GitDatabase`] is fetched.
+///
+/// The URL of the remote isn't persisted, as it may include credentials; instead, it's provided
+/// on every fetch.
+const PROMISOR_REMOTE: &str = "origin";
+
+/// The filter with which a [`GitDatabase`] is fetched, omitting file contents (i.e., blobs) until
+/// a commit is checked out.
+const PARTIAL_CLONE_FILTER: &str = "blob:none";
+
 #[derive(Debug, thiserror::Error)]
 pub enum GitError {
     #[error("Git executable not found. Ensure that Git is installed and available.")]
@@ -69,6 +82,13 @@ impl ReferenceOrOid<'_> {
                 repo.rev_parse(&format!("refs/remotes/origin/tags/{s}^0"))
             }
 
+            // Resolve the commit pointed to by the highest tag in the range.
+            Self::Reference(GitReference::TagRange(s)) => {
+                let tag = repo.select_tag(s)?;
+                debug!("Selected tag `{tag}` for tag range `{s}`");
+                repo.rev_parse(&format!("refs/remotes/origin/tags/{tag}^0"))
+            }
+
             // Resolve the commit pointed to by the branch.
             Self::Reference(GitReference::Branch(s)) => repo.rev_parse(&format!("origin/{s}^0")),
 
@@ -129,6 +149,8 @@ impl Display for ReferenceOrOid<'_> {
 pub(crate) struct GitRemote {
     /// URL to a remote repository.
     url: Url,
+    /// The SSH key with which to fetch the repository, if any.
+    ssh_key: Option<PathBuf>,
 }
 
 /// A local clone of a remote repository's database. Multiple [`GitCheckout`]s
@@ -185,6 +207,61 @@ impl GitRepository {
         })
     }
 
+    /// Configures the repository as a partial clone of [`PROMISOR_REMOTE`], such that it can be
+    /// fetched without file contents.
+    ///
+    /// Objects fetched from a promisor remote may be missing without the repository being
+    /// considered corrupt.
+    fn configure_partial_clone(&self) -> Result<()> {
+        let configured = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("config")
+            .arg("--get")
+            .arg("extensions.partialClone")
+            .cwd(&self.path)
+            .exec_with_output()
+            .is_ok();
+        if configured {
+            return Ok(());
+        }
+
+        for (key, value) in [
+            (format!("remote.{PROMISOR_REMOTE}.promisor"), "true"),
+            (
+                format!("remote.{PROMISOR_REMOTE}.partialCloneFilter"),
+                PARTIAL_CLONE_FILTER,
+            ),
+            ("core.repositoryFormatVersion".to_string(), "1"),
+            ("extensions.partialClone".to_string(), PROMISOR_REMOTE),
+        ] {
+            ProcessBuilder::new(GIT.as_ref()?)
+                .arg("config")
+                .arg(key)
+                .arg(value)
+                .cwd(&self.path)
+                .exec_with_output()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the objects in the tree of the given commit that are missing from the
+    /// repository, i.e., the file contents that haven't been fetched into a partial clone.
+    fn missing_objects(&self, revision: GitOid) -> Result<Vec<String>> {
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("rev-list")
+            .arg("--objects")
+            .arg("--no-walk")
+            .arg("--missing=print")
+            .arg(revision.as_str())
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        Ok(String::from_utf8(result.stdout)?
+            .lines()
+            .filter_map(|line| line.strip_prefix('?'))
+            .map(ToString::to_string)
+            .collect())
+    }
+
     /// Parses the object ID of the given `refname`.
     fn rev_parse(&self, refname: &str) -> Result<GitOid> {
         let result = ProcessBuilder::new(GIT.as_ref()?)
@@ -197,12 +274,49 @@ impl GitRepository {
         result.truncate(result.trim_end().len());
         Ok(result.parse()?)
     }
+
+    /// Returns the name of the fetched tag with the highest version that satisfies the given
+    /// version specifiers.
+    ///
+    /// Tags are parsed as versions after stripping a leading `v` (as in `v1.2.0`); tags that
+    /// aren't valid versions are ignored. Pre-release tags are only considered if the specifiers
+    /// include a pre-release.
+    fn select_tag(&self, specifiers: &str) -> Result<String> {
+        let specifiers = VersionSpecifiers::from_str(specifiers)?;
+        let allow_prerelease = specifiers.iter().any(VersionSpecifier::any_prerelease);
+
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("for-each-ref")
+            .arg("--format=%(refname:strip=4)")
+            .arg("refs/remotes/origin/tags")
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        String::from_utf8(result.stdout)?
+            .lines()
+            .filter_map(|tag| {
+                let version = tag
+                    .strip_prefix(['v', 'V'])
+                    .unwrap_or(tag)
+                    .parse::<Version>()
+                    .ok()?;
+                Some((version, tag))
+            })
+            .filter(|(version, _)| allow_prerelease || !version.any_prerelease())
+            .filter(|(version, _)| specifiers.contains(version))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, tag)| tag.to_string())
+            .ok_or_else(|| anyhow::format_err!("no tags match `{specifiers}`"))
+    }
 }
 
 impl GitRemote {
-    /// Creates an instance for a remote repository URL.
-    pub(crate) fn new(url: &Url) -> Self {
-        Self { url: url.clone() }
+    /// Creates an instance for a remote repository URL, fetched with the given SSH key (if any).
+    pub(crate) fn new(url: &Url, ssh_key: Option<PathBuf>) -> Self {
+        Self {
+            url: url.clone(),
+            ssh_key,
+        }
     }
 
     /// Gets the remote repository URL.
@@ -230,6 +344,7 @@ impl GitRemote {
         locked_rev: Option<GitOid>,
         client: &ClientWithMiddleware,
         disable_ssl: bool,
+        proxy: Option<&Url>,
     ) -> Result<(GitDatabase, GitOid)> {
         let reference = locked_rev
             .map(ReferenceOrOid::Oid)
@@ -237,18 +352,42 @@ impl GitRemote {
         let enable_lfs_fetch = env::var(EnvVars::UV_GIT_LFS).is_ok();
 
         if let Some(mut db) = db {
-            fetch(&mut db.repo, &self.url, reference, client, disable_ssl)
-                .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
+            fetch(
+                &mut db.repo,
+                &self.url,
+                reference,
+                client,
+                disable_ssl,
+                proxy,
+                self.ssh_key.as_deref(),
+            )
+            .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
-                Some(rev) => db.contains(rev).then_some(rev),
+                Some(rev) => db.contains_commit(rev).then_some(rev),
                 None => reference.resolve(&db.repo).ok(),
             };
 
             if let Some(rev) = resolved_commit_hash {
+                fetch_missing_objects(
+                    &mut db.repo,
+                    &self.url,
+                    rev,
+                    disable_ssl,
+                    proxy,
+                    self.ssh_key.as_deref(),
+                )
+                .with_context(|| format!("failed to fetch the files at {rev}"))?;
                 if enable_lfs_fetch {
-                    fetch_lfs(&mut db.repo, &self.url, &rev, disable_ssl)
-                        .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
+                    fetch_lfs(
+                        &mut db.repo,
+                        &self.url,
+                        &rev,
+                        disable_ssl,
+                        proxy,
+                        self.ssh_key.as_deref(),
+                    )
+                    .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
                 }
                 return Ok((db, rev));
             }
@@ -265,15 +404,39 @@ impl GitRemote {
 
         fs_err::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, &self.url, reference, client, disable_ssl)
-            .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
+        fetch(
+            &mut repo,
+            &self.url,
+            reference,
+            client,
+            disable_ssl,
+            proxy,
+            self.ssh_key.as_deref(),
+        )
+        .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
             None => reference.resolve(&repo)?,
         };
+        fetch_missing_objects(
+            &mut repo,
+            &self.url,
+            rev,
+            disable_ssl,
+            proxy,
+            self.ssh_key.as_deref(),
+        )
+        .with_context(|| format!("failed to fetch the files at {rev}"))?;
         if enable_lfs_fetch {
-            fetch_lfs(&mut repo, &self.url, &rev, disable_ssl)
-                .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
+            fetch_lfs(
+                &mut repo,
+                &self.url,
+                &rev,
+                disable_ssl,
+                proxy,
+                self.ssh_key.as_deref(),
+            )
+            .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
         }
 
         Ok((GitDatabase { repo }, rev))
@@ -319,8 +482,18 @@ impl GitDatabase {
         Ok(result)
     }
 
-    /// Checks if `oid` resolves to a commit in this database.
+    /// Checks if `oid` resolves to a commit in this database, including the files in its tree.
     pub(crate) fn contains(&self, oid: GitOid) -> bool {
+        self.contains_commit(oid)
+            && self
+                .repo
+                .missing_objects(oid)
+                .is_ok_and(|missing| missing.is_empty())
+    }
+
+    /// Checks if `oid` resolves to a commit in this database, whose files may not have been
+    /// fetched yet.
+    fn contains_commit(&self, oid: GitOid) -> bool {
         self.repo.rev_parse(&format!("{oid}^0")).is_ok()
     }
 }
@@ -441,6 +614,8 @@ fn fetch(
     reference: ReferenceOrOid<'_>,
     client: &ClientWithMiddleware,
     disable_ssl: bool,
+    proxy: Option<&Url>,
+    ssh_key: Option<&Path>,
 ) -> Result<()> {
     let oid_to_fetch = match github_fast_path(repo, remote_url, reference, client) {
         Ok(FastPathRev::UpToDate) => return Ok(()),
@@ -471,6 +646,11 @@ fn fetch(
             refspecs.push(format!("+refs/tags/{tag}:refs/remotes/origin/tags/{tag}"));
         }
 
+        // For tag ranges, we fetch all tags, and then select the highest matching tag.
+        ReferenceOrOid::Reference(GitReference::TagRange(_)) => {
+            refspecs.push(String::from("+refs/tags/*:refs/remotes/origin/tags/*"));
+        }
+
         ReferenceOrOid::Reference(GitReference::BranchOrTag(branch_or_tag)) => {
             refspecs.push(format!(
                 "+refs/heads/{branch_or_tag}:refs/remotes/origin/{branch_or_tag}"
@@ -514,11 +694,20 @@ fn fetch(
         }
     }
 
+    // Fetch the commits and trees only; the files are fetched once a commit is checked out.
+    repo.configure_partial_clone()?;
+
     debug!("Performing a Git fetch for: {remote_url}");
     let result = match refspec_strategy {
-        RefspecStrategy::All => {
-            fetch_with_cli(repo, remote_url, refspecs.as_slice(), tags, disable_ssl)
-        }
+        RefspecStrategy::All => fetch_with_cli(
+            repo,
+            remote_url,
+            refspecs.as_slice(),
+            tags,
+            disable_ssl,
+            proxy,
+            ssh_key,
+        ),
         RefspecStrategy::First => {
             // Try each refspec
             let mut errors = refspecs
@@ -530,6 +719,8 @@ fn fetch(
                         std::slice::from_ref(refspec),
                         tags,
                         disable_ssl,
+                        proxy,
+                        ssh_key,
                     );
 
                     // Stop after the first success and log failures
@@ -576,30 +767,93 @@ fn fetch_with_cli(
     refspecs: &[String],
     tags: bool,
     disable_ssl: bool,
+    proxy: Option<&Url>,
+    ssh_key: Option<&Path>,
 ) -> Result<()> {
+    let mut cmd = fetch_command(repo, url, disable_ssl, proxy, ssh_key)?;
+    if tags {
+        cmd.arg("--tags");
+    }
+    cmd.arg("--force") // handle force pushes
+        .arg("--update-head-ok") // see discussion in #2078
+        .arg(PROMISOR_REMOTE)
+        .args(refspecs);
+
+    exec_fetch(&cmd, url, ssh_key)
+}
+
+/// Fetches the files at a `revision` that are missing from a partial clone, i.e., that weren't
+/// fetched along with the commit.
+///
+/// This mirrors Git's own on-demand fetching of missing objects, but with the same configuration
+/// as any other fetch (e.g., the proxy and SSH key).
+fn fetch_missing_objects(
+    repo: &mut GitRepository,
+    url: &Url,
+    revision: GitOid,
+    disable_ssl: bool,
+    proxy: Option<&Url>,
+    ssh_key: Option<&Path>,
+) -> Result<()> {
+    let missing = repo.missing_objects(revision)?;
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    debug!("Fetching {} missing objects at {revision}", missing.len());
+    let mut cmd = fetch_command(repo, url, disable_ssl, proxy, ssh_key)?;
+    cmd.arg("--no-tags")
+        .arg("--no-write-fetch-head")
+        .arg("--recurse-submodules=no")
+        .arg("--stdin")
+        .arg(PROMISOR_REMOTE)
+        .stdin(missing.join("\n"));
+
+    exec_fetch(&cmd, url, ssh_key)
+}
+
+/// Creates a `git fetch` command from `url` into the given partial clone, to which the remote
+/// (i.e., [`PROMISOR_REMOTE`]) and the refspecs are yet to be added.
+fn fetch_command(
+    repo: &GitRepository,
+    url: &Url,
+    disable_ssl: bool,
+    proxy: Option<&Url>,
+    ssh_key: Option<&Path>,
+) -> Result<ProcessBuilder> {
     let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
     // Disable interactive prompts in the terminal, as they'll be erased by the progress bar
     // animation and the process will "hang". Interactive prompts via the GUI like `SSH_ASKPASS`
     // are still usable.
     cmd.env(EnvVars::GIT_TERMINAL_PROMPT, "0");
 
-    cmd.arg("fetch");
-    if tags {
-        cmd.arg("--tags");
+    // Use the same proxy as uv's HTTP clients. Git would otherwise ignore, e.g., an uppercase
+    // `HTTP_PROXY`.
+    if let Some(proxy) = proxy {
+        debug!("Using proxy for Git fetch");
+        cmd.arg("-c").arg(format!("http.proxy={proxy}"));
+    }
+
+    // Connect with the configured SSH key (e.g., a deploy key), if any.
+    if let Some(ssh_key) = ssh_key {
+        cmd.env(EnvVars::GIT_SSH_COMMAND, ssh_command(ssh_key));
     }
+
+    // Provide the URL of the promisor remote, which isn't persisted in the repository.
+    cmd.arg("-c")
+        .arg(format!("remote.{PROMISOR_REMOTE}.url={url}"));
+
+    cmd.arg("fetch")
+        .arg(format!("--filter={PARTIAL_CLONE_FILTER}"));
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
     }
-    cmd.arg("--force") // handle force pushes
-        .arg("--update-head-ok") // see discussion in #2078
-        .arg(url.as_str())
-        .args(refspecs)
-        // If cargo is run by git (for example, the `exec` command in `git
-        // rebase`), the GIT_DIR is set by git and will point to the wrong
-        // location (this takes precedence over the cwd). Make sure this is
-        // unset so git will look at cwd for the repo.
-        .env_remove(EnvVars::GIT_DIR)
+    // If cargo is run by git (for example, the `exec` command in `git
+    // rebase`), the GIT_DIR is set by git and will point to the wrong
+    // location (this takes precedence over the cwd). Make sure this is
+    // unset so git will look at cwd for the repo.
+    cmd.env_remove(EnvVars::GIT_DIR)
         // The reset of these may not be necessary, but I'm including them
         // just to be extra paranoid and avoid any issues.
         .env_remove(EnvVars::GIT_WORK_TREE)
@@ -607,11 +861,21 @@ fn fetch_with_cli(
         .env_remove(EnvVars::GIT_OBJECT_DIRECTORY)
         .env_remove(EnvVars::GIT_ALTERNATE_OBJECT_DIRECTORIES)
         .cwd(&repo.path);
+    Ok(cmd)
+}
 
+/// Runs a `git fetch` command from `url`.
+fn exec_fetch(cmd: &ProcessBuilder, url: &Url, ssh_key: Option<&Path>) -> Result<()> {
     // We capture the output to avoid streaming it to the user's console during clones.
     // The required `on...line` callbacks currently do nothing.
-    // The output appears to be included in error messages by default.
-    cmd.exec_with_output()?;
+    // The output appears to be included in error messages by default, except for SSH failures,
+    // for which we surface an actionable error instead.
+    if let Err(err) = cmd.exec_with_output() {
+        if let Some(ssh_err) = SshError::from_process_error(&err, url, ssh_key) {
+            return Err(ssh_err.into());
+        }
+        return Err(err);
+    }
 
     Ok(())
 }
@@ -635,6 +899,8 @@ fn fetch_lfs(
     url: &Url,
     revision: &GitOid,
     disable_ssl: bool,
+    proxy: Option<&Url>,
+    ssh_key: Option<&Path>,
 ) -> Result<()> {
     let mut cmd = if let Ok(lfs) = GIT_LFS.as_ref() {
         debug!("Fetching Git LFS objects");
@@ -650,6 +916,16 @@ fn fetch_lfs(
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
     }
 
+    if let Some(proxy) = proxy {
+        debug!("Using proxy for Git LFS");
+        cmd.env(EnvVars::HTTPS_PROXY, proxy.as_str())
+            .env(EnvVars::HTTP_PROXY, proxy.as_str());
+    }
+
+    if let Some(ssh_key) = ssh_key {
+        cmd.env(EnvVars::GIT_SSH_COMMAND, ssh_command(ssh_key));
+    }
+
     cmd.arg("fetch")
         .arg(url.as_str())
         .arg(revision.as_str())
@@ -707,6 +983,10 @@ fn github_fast_path(
         ReferenceOrOid::Reference(GitReference::DefaultBranch) => "HEAD",
         ReferenceOrOid::Reference(GitReference::Branch(branch)) => branch,
         ReferenceOrOid::Reference(GitReference::Tag(tag)) => tag,
+        ReferenceOrOid::Reference(GitReference::TagRange(range)) => {
+            debug!("Skipping GitHub fast path; tag range provided: {range}");
+            return Ok(FastPathRev::Indeterminate);
+        }
         ReferenceOrOid::Reference(GitReference::BranchOrTag(branch_or_tag)) => branch_or_tag,
         ReferenceOrOid::Reference(GitReference::NamedRef(rev)) => rev,
         ReferenceOrOid::Reference(GitReference::BranchOrTagOrCommit(rev)) => {
@@ -0,0 +1,240 @@
+//! Support for fetching Mercurial repositories via the `hg` CLI.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+
+use anyhow::{Context, Result};
+use cargo_util::{paths, ProcessBuilder};
+use tracing::{debug, instrument};
+use url::Url;
+
+use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_fs::Simplified;
+use uv_git_types::{GitOid, HgUrl};
+use uv_static::EnvVars;
+
+use crate::Reporter;
+
+/// A file that indicates that, if present, the checkout was archived completely.
+const CHECKOUT_READY_LOCK: &str = ".ok";
+
+#[derive(Debug, thiserror::Error)]
+pub enum HgError {
+    #[error(
+        "Mercurial executable not found. Ensure that Mercurial (`hg`) is installed and available."
+    )]
+    HgNotFound,
+    #[error(transparent)]
+    Other(#[from] which::Error),
+}
+
+/// A global cache of the result of `which hg`.
+pub static HG: LazyLock<Result<PathBuf, HgError>> = LazyLock::new(|| {
+    which::which("hg").map_err(|e| match e {
+        which::Error::CannotFindBinaryPath => HgError::HgNotFound,
+        e => HgError::Other(e),
+    })
+});
+
+/// Create a [`ProcessBuilder`] for an `hg` command against the given repository.
+///
+/// `HGPLAIN` is set such that the output isn't affected by the user's configuration (e.g.,
+/// aliases or localization), while still respecting their authentication settings.
+fn hg(repository: &Path) -> Result<ProcessBuilder> {
+    let mut cmd = ProcessBuilder::new(HG.as_ref()?);
+    cmd.arg("--repository")
+        .arg(repository)
+        .arg("--noninteractive")
+        .env(EnvVars::HGPLAIN, "1");
+    Ok(cmd)
+}
+
+/// A local clone of a remote Mercurial repository, without a working directory.
+struct HgDatabase {
+    path: PathBuf,
+}
+
+impl HgDatabase {
+    /// Open the database at `path`, initializing an empty repository if none exists.
+    fn init(path: &Path) -> Result<Self> {
+        if !path.join(".hg").is_dir() {
+            fs_err::create_dir_all(path)?;
+            ProcessBuilder::new(HG.as_ref()?)
+                .arg("init")
+                .arg(path)
+                .env(EnvVars::HGPLAIN, "1")
+                .exec_with_output()?;
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Pull changesets from the remote into the database.
+    ///
+    /// If a precise changeset is provided, only its ancestors are pulled.
+    fn pull(&self, remote: &Url, precise: Option<GitOid>) -> Result<()> {
+        debug!("Updating Mercurial source `{remote}`");
+        let mut cmd = hg(&self.path)?;
+        cmd.arg("pull");
+        if let Some(precise) = precise {
+            cmd.arg("--rev").arg(precise.as_str());
+        }
+        cmd.arg(remote.as_str());
+        cmd.exec_with_output()
+            .with_context(|| format!("failed to pull from Mercurial repository `{remote}`"))?;
+        Ok(())
+    }
+
+    /// Resolve a revision (e.g., a branch, bookmark, tag, or changeset) to its changeset ID.
+    fn resolve(&self, revision: &str) -> Result<GitOid> {
+        let output = hg(&self.path)?
+            .arg("log")
+            .arg("--rev")
+            .arg(revision)
+            .arg("--limit")
+            .arg("1")
+            .arg("--template")
+            .arg("{node}")
+            .exec_with_output()?;
+        let node = String::from_utf8(output.stdout)?;
+        Ok(GitOid::from_str(node.trim())?)
+    }
+
+    /// Returns `true` if the database contains the given changeset.
+    fn contains(&self, node: GitOid) -> bool {
+        self.resolve(node.as_str()).is_ok()
+    }
+
+    /// Archive the files at the given changeset to `destination`, reusing an existing archive.
+    fn archive_to(&self, node: GitOid, destination: &Path) -> Result<()> {
+        if destination.join(CHECKOUT_READY_LOCK).exists() {
+            return Ok(());
+        }
+
+        // Remove any partial archive left behind by an interrupted operation.
+        if destination.exists() {
+            paths::remove_dir_all(destination)?;
+        }
+        fs_err::create_dir_all(destination.parent().context("checkout has no parent")?)?;
+
+        hg(&self.path)?
+            .arg("archive")
+            .arg("--rev")
+            .arg(node.as_str())
+            .arg("--type")
+            .arg("files")
+            .arg("--no-decode")
+            .arg(destination)
+            .exec_with_output()
+            .with_context(|| {
+                format!(
+                    "failed to archive Mercurial changeset `{node}` to `{}`",
+                    destination.user_display()
+                )
+            })?;
+        paths::create(destination.join(CHECKOUT_READY_LOCK))?;
+        Ok(())
+    }
+}
+
+/// A remote Mercurial source that can be checked out locally.
+pub struct HgSource {
+    /// The Mercurial URL and revision to check out.
+    hg: HgUrl,
+    /// The path to the Mercurial source database.
+    cache: PathBuf,
+    /// The reporter to use for this source.
+    reporter: Option<Arc<dyn Reporter>>,
+}
+
+impl HgSource {
+    /// Initialize a [`HgSource`] with the given Mercurial URL and cache path.
+    pub fn new(hg: HgUrl, cache: impl Into<PathBuf>) -> Self {
+        Self {
+            hg,
+            cache: cache.into(),
+            reporter: None,
+        }
+    }
+
+    /// Set the [`Reporter`] to use for the [`HgSource`].
+    #[must_use]
+    pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
+        Self {
+            reporter: Some(reporter),
+            ..self
+        }
+    }
+
+    /// Fetch the underlying Mercurial repository at the given revision.
+    #[instrument(skip(self), fields(repository = %self.hg.repository(), rev = ?self.hg.precise()))]
+    pub fn fetch(self) -> Result<HgFetch> {
+        // The path to the repo, within the Mercurial database.
+        let ident = cache_digest(&RepositoryUrl::new(self.hg.repository()));
+        let db = HgDatabase::init(&self.cache.join("db").join(&ident))?;
+
+        let node = match self.hg.precise() {
+            // If we have a locked changeset, and the database already contains it, no update
+            // needs to happen.
+            Some(precise) if db.contains(precise) => {
+                debug!("Using existing Mercurial source `{}`", self.hg.repository());
+                precise
+            }
+            precise => {
+                let task = self.reporter.as_ref().map(|reporter| {
+                    reporter.on_checkout_start(self.hg.repository(), self.hg.as_rev())
+                });
+
+                db.pull(self.hg.repository(), precise)?;
+                let node = match precise {
+                    Some(precise) => precise,
+                    None => db.resolve(self.hg.as_rev()).with_context(|| {
+                        format!(
+                            "failed to find revision `{}` in Mercurial repository `{}`",
+                            self.hg.as_rev(),
+                            self.hg.repository()
+                        )
+                    })?,
+                };
+
+                if let (Some(task), Some(reporter)) = (task, self.reporter.as_ref()) {
+                    reporter.on_checkout_complete(self.hg.repository(), node.as_str(), task);
+                }
+                node
+            }
+        };
+
+        // Archive the changeset to a scoped location on the filesystem, using a short ID to
+        // contribute less to reaching the path length limit on Windows.
+        let checkout_path = self
+            .cache
+            .join("checkouts")
+            .join(&ident)
+            .join(node.as_short_str());
+        db.archive_to(node, &checkout_path)?;
+
+        Ok(HgFetch {
+            hg: self.hg.with_precise(node),
+            path: checkout_path,
+        })
+    }
+}
+
+pub struct HgFetch {
+    /// The [`HgUrl`] reference that was fetched.
+    hg: HgUrl,
+    /// The path to the checked out files.
+    path: PathBuf,
+}
+
+impl HgFetch {
+    pub fn hg(&self) -> &HgUrl {
+        &self.hg
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
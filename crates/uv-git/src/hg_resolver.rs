@@ -0,0 +1,148 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use fs_err::tokio as fs;
+use tracing::debug;
+
+use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_fs::LockedFile;
+use uv_git_types::{GitOid, HgUrl};
+
+use crate::hg::{HgFetch, HgSource};
+use crate::Reporter;
+
+#[derive(Debug, thiserror::Error)]
+pub enum HgResolverError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+    #[error("Mercurial operation failed")]
+    Hg(#[source] anyhow::Error),
+}
+
+/// A resolver for Mercurial repositories.
+#[derive(Default, Clone)]
+pub struct HgResolver(Arc<DashMap<HgRepositoryReference, GitOid>>);
+
+impl HgResolver {
+    /// Inserts a new changeset for the given [`HgRepositoryReference`].
+    pub fn insert(&self, reference: HgRepositoryReference, node: GitOid) {
+        self.0.insert(reference, node);
+    }
+
+    /// Returns the changeset for the given [`HgRepositoryReference`], if it exists.
+    fn get(&self, reference: &HgRepositoryReference) -> Option<GitOid> {
+        self.0.get(reference).map(|node| *node)
+    }
+
+    /// Fetch a remote Mercurial repository.
+    pub async fn fetch(
+        &self,
+        url: &HgUrl,
+        cache: PathBuf,
+        reporter: Option<Arc<dyn Reporter>>,
+    ) -> Result<HgFetch, HgResolverError> {
+        debug!("Fetching source distribution from Mercurial: {url}");
+
+        let reference = HgRepositoryReference::from(url);
+
+        // If we know the precise changeset already, reuse it, to ensure that all fetches within a
+        // single process are consistent.
+        let url = if let Some(precise) = self.get(&reference) {
+            url.clone().with_precise(precise)
+        } else {
+            url.clone()
+        };
+
+        // Avoid races between different processes, too.
+        let lock_dir = cache.join("locks");
+        fs::create_dir_all(&lock_dir).await?;
+        let repository_url = RepositoryUrl::new(url.repository());
+        let _lock = LockedFile::acquire(
+            lock_dir.join(cache_digest(&repository_url)),
+            &repository_url,
+        )
+        .await?;
+
+        // Fetch the Mercurial repository.
+        let source = if let Some(reporter) = reporter {
+            HgSource::new(url, cache).with_reporter(reporter)
+        } else {
+            HgSource::new(url, cache)
+        };
+
+        let fetch = tokio::task::spawn_blocking(move || source.fetch())
+            .await?
+            .map_err(HgResolverError::Hg)?;
+
+        // Insert the resolved URL into the in-memory cache, such that subsequent fetches resolve
+        // to the same precise changeset.
+        if let Some(precise) = fetch.hg().precise() {
+            self.insert(reference, precise);
+        }
+
+        Ok(fetch)
+    }
+
+    /// Given a Mercurial source distribution, return a variant pinned to the precise changeset, if
+    /// it has already been resolved.
+    pub fn precise(&self, url: HgUrl) -> Option<HgUrl> {
+        let reference = HgRepositoryReference::from(&url);
+        let precise = self.get(&reference)?;
+        Some(url.with_precise(precise))
+    }
+
+    /// Returns `true` if the two Mercurial URLs refer to the same precise changeset.
+    pub fn same_ref(&self, a: &HgUrl, b: &HgUrl) -> bool {
+        let a_ref = HgRepositoryReference::from(a);
+        let b_ref = HgRepositoryReference::from(b);
+
+        // The URLs must refer to the same repository.
+        if a_ref.url != b_ref.url {
+            return false;
+        }
+
+        // If the URLs have the same revision, they refer to the same changeset.
+        if a_ref.revision == b_ref.revision {
+            return true;
+        }
+
+        // Otherwise, the URLs must resolve to the same precise changeset.
+        let Some(a_precise) = a.precise().or_else(|| self.get(&a_ref)) else {
+            return false;
+        };
+
+        let Some(b_precise) = b.precise().or_else(|| self.get(&b_ref)) else {
+            return false;
+        };
+
+        a_precise == b_precise
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolvedHgRepositoryReference {
+    /// An abstract reference to a Mercurial repository, including the URL and the revision.
+    pub reference: HgRepositoryReference,
+    /// The precise changeset of the reference.
+    pub node: GitOid,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HgRepositoryReference {
+    /// The URL of the Mercurial repository, with any query parameters and fragments removed.
+    pub url: RepositoryUrl,
+    /// The requested revision, which could be a branch, bookmark, tag, or changeset.
+    pub revision: Option<String>,
+}
+
+impl From<&HgUrl> for HgRepositoryReference {
+    fn from(hg: &HgUrl) -> Self {
+        Self {
+            url: RepositoryUrl::new(hg.repository()),
+            revision: hg.revision().map(ToString::to_string),
+        }
+    }
+}
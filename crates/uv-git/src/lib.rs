@@ -1,11 +1,19 @@
 pub use crate::credentials::{store_credentials_from_url, GIT_STORE};
 pub use crate::git::GIT;
+pub use crate::hg::{HgFetch, HgSource, HG};
+pub use crate::hg_resolver::{
+    HgRepositoryReference, HgResolver, HgResolverError, ResolvedHgRepositoryReference,
+};
 pub use crate::resolver::{
     GitResolver, GitResolverError, RepositoryReference, ResolvedRepositoryReference,
 };
 pub use crate::source::{Fetch, GitSource, Reporter};
+pub use crate::ssh::store_ssh_key;
 
 mod credentials;
 mod git;
+mod hg;
+mod hg_resolver;
 mod resolver;
 mod source;
+mod ssh;
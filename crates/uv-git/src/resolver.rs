@@ -8,6 +8,7 @@ use dashmap::DashMap;
 use fs_err::tokio as fs;
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::debug;
+use url::Url;
 
 use uv_cache_key::{cache_digest, RepositoryUrl};
 use uv_fs::LockedFile;
@@ -109,6 +110,7 @@ impl GitResolver {
         url: &GitUrl,
         client: ClientWithMiddleware,
         disable_ssl: bool,
+        proxy: Option<&Url>,
         cache: PathBuf,
         reporter: Option<Arc<dyn Reporter>>,
     ) -> Result<Fetch, GitResolverError> {
@@ -150,6 +152,13 @@ impl GitResolver {
             source
         };
 
+        // If necessary, fetch through a proxy.
+        let source = if let Some(proxy) = proxy {
+            source.with_proxy(proxy.clone())
+        } else {
+            source
+        };
+
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
             .map_err(GitResolverError::Git)?;
@@ -15,6 +15,7 @@ use uv_cache_key::{cache_digest, RepositoryUrl};
 use uv_git_types::GitUrl;
 
 use crate::git::GitRemote;
+use crate::ssh::ssh_key;
 use crate::GIT_STORE;
 
 /// A remote Git source that can be checked out locally.
@@ -25,6 +26,8 @@ pub struct GitSource {
     client: ClientWithMiddleware,
     /// Whether to disable SSL verification.
     disable_ssl: bool,
+    /// The proxy to use for fetching, if any.
+    proxy: Option<Url>,
     /// The path to the Git source database.
     cache: PathBuf,
     /// The reporter to use for this source.
@@ -41,6 +44,7 @@ impl GitSource {
         Self {
             git,
             disable_ssl: false,
+            proxy: None,
             client: client.into(),
             cache: cache.into(),
             reporter: None,
@@ -56,6 +60,15 @@ impl GitSource {
         }
     }
 
+    /// Fetch this [`GitSource`] through the given proxy.
+    #[must_use]
+    pub fn with_proxy(self, proxy: Url) -> Self {
+        Self {
+            proxy: Some(proxy),
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for the [`GitSource`].
     #[must_use]
     pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -82,7 +95,7 @@ impl GitSource {
             Cow::Borrowed(self.git.repository())
         };
 
-        let remote = GitRemote::new(&remote);
+        let remote = GitRemote::new(&remote, ssh_key(self.git.repository()));
         let (db, actual_rev, task) = match (self.git.precise(), remote.db_at(&db_path).ok()) {
             // If we have a locked revision, and we have a preexisting database
             // which has that revision, then no update needs to happen.
@@ -110,6 +123,7 @@ impl GitSource {
                     locked_rev,
                     &self.client,
                     self.disable_ssl,
+                    self.proxy.as_ref(),
                 )?;
 
                 (db, actual_rev, task)
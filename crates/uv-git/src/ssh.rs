@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, RwLock};
+
+use cargo_util::{ProcessBuilder, ProcessError};
+use tracing::{debug, trace};
+use url::Url;
+
+use uv_cache_key::RepositoryUrl;
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::GIT;
+
+/// Global store of the SSH keys with which to fetch Git repositories, for a uv invocation.
+static SSH_KEYS: LazyLock<RwLock<HashMap<RepositoryUrl, PathBuf>>> = LazyLock::new(RwLock::default);
+
+/// Fetch the Git repository at the given URL over SSH using the given key (e.g., a deploy key),
+/// rather than any key offered by the SSH agent.
+pub fn store_ssh_key(url: &Url, key: PathBuf) {
+    trace!("Using SSH key `{}` for {url}", key.user_display());
+    SSH_KEYS
+        .write()
+        .unwrap()
+        .insert(RepositoryUrl::new(url), key);
+}
+
+/// Return the SSH key with which to fetch the Git repository at the given URL, if any.
+pub(crate) fn ssh_key(url: &Url) -> Option<PathBuf> {
+    SSH_KEYS
+        .read()
+        .unwrap()
+        .get(&RepositoryUrl::new(url))
+        .cloned()
+}
+
+/// The `core.sshCommand` from the user's Git configuration, if set.
+static CORE_SSH_COMMAND: LazyLock<Option<String>> = LazyLock::new(|| {
+    let output = ProcessBuilder::new(GIT.as_ref().ok()?)
+        .arg("config")
+        .arg("--get")
+        .arg("core.sshCommand")
+        .exec_with_output()
+        .ok()?;
+    let command = String::from_utf8(output.stdout).ok()?;
+    let command = command.trim();
+    (!command.is_empty()).then(|| command.to_string())
+});
+
+/// Return the SSH command with which Git should connect using the given key.
+///
+/// The key is appended to the command that Git would otherwise use, such that `GIT_SSH_COMMAND`,
+/// `core.sshCommand`, and `GIT_SSH` are all respected.
+pub(crate) fn ssh_command(key: &Path) -> String {
+    let command = if let Some(command) = std::env::var(EnvVars::GIT_SSH_COMMAND)
+        .ok()
+        .filter(|command| !command.is_empty())
+    {
+        command
+    } else if let Some(command) = CORE_SSH_COMMAND.as_ref() {
+        command.clone()
+    } else if let Some(program) = std::env::var_os(EnvVars::GIT_SSH) {
+        quote(&program.to_string_lossy())
+    } else {
+        "ssh".to_string()
+    };
+    debug!("Using SSH key `{}` for Git fetch", key.user_display());
+    format!(
+        "{command} -i {} -o IdentitiesOnly=yes",
+        quote(&key.to_string_lossy())
+    )
+}
+
+/// Quote an argument for the shell with which Git runs the SSH command.
+fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// An error encountered when connecting to a Git repository over SSH.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SshError {
+    #[error(
+        "The SSH host key for `{0}` could not be verified. Add the host's key to your `known_hosts` file (e.g., with `ssh-keyscan {0} >> ~/.ssh/known_hosts`, after checking its fingerprint), then try again."
+    )]
+    HostKey(String),
+    #[error(
+        "Failed to authenticate with `{host}` over SSH using the key at `{}`. Ensure that the key has access to the repository (e.g., as a deploy key), and that it doesn't require a passphrase.",
+        key.user_display()
+    )]
+    Key { host: String, key: PathBuf },
+    #[error(
+        "Failed to authenticate with `{0}` over SSH. Ensure that an SSH agent is running with a key that has access to the repository (list the available keys with `ssh-add -l`), or set `ssh-key` on the source in `tool.uv.sources`."
+    )]
+    Agent(String),
+}
+
+impl SshError {
+    /// Identify an SSH failure from the error of a Git command for the given URL.
+    ///
+    /// Returns `None` if the command didn't fail to connect over SSH.
+    pub(crate) fn from_process_error(
+        err: &anyhow::Error,
+        url: &Url,
+        key: Option<&Path>,
+    ) -> Option<Self> {
+        if url.scheme() != "ssh" {
+            return None;
+        }
+        let stderr = err.downcast_ref::<ProcessError>()?.stderr.as_deref()?;
+        Self::from_stderr(&String::from_utf8_lossy(stderr), url, key)
+    }
+
+    fn from_stderr(stderr: &str, url: &Url, key: Option<&Path>) -> Option<Self> {
+        let host = url.host_str()?.to_string();
+        if stderr.contains("Host key verification failed") {
+            Some(Self::HostKey(host))
+        } else if stderr.contains("Permission denied (publickey") {
+            if let Some(key) = key {
+                Some(Self::Key {
+                    host,
+                    key: key.to_path_buf(),
+                })
+            } else {
+                Some(Self::Agent(host))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use url::Url;
+
+    use super::{quote, SshError};
+
+    #[test]
+    fn quoting() {
+        assert_eq!(
+            quote("/home/ferris/.ssh/id_ed25519"),
+            "'/home/ferris/.ssh/id_ed25519'"
+        );
+        assert_eq!(quote("/keys/ferris's key"), r"'/keys/ferris'\''s key'");
+    }
+
+    #[test]
+    fn classify() {
+        let url = Url::parse("ssh://git@github.com/astral-sh/uv").unwrap();
+
+        let err = SshError::from_stderr(
+            "Host key verification failed.\nfatal: Could not read from remote repository.",
+            &url,
+            None,
+        );
+        assert!(matches!(err, Some(SshError::HostKey(host)) if host == "github.com"));
+
+        let stderr = "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository.";
+        let err = SshError::from_stderr(stderr, &url, None);
+        assert!(matches!(err, Some(SshError::Agent(host)) if host == "github.com"));
+        let err = SshError::from_stderr(stderr, &url, Some(Path::new("/keys/deploy")));
+        assert!(matches!(err, Some(SshError::Key { key, .. }) if key == Path::new("/keys/deploy")));
+
+        let err = SshError::from_stderr("fatal: couldn't find remote ref main", &url, None);
+        assert!(err.is_none());
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use configparser::ini::Ini;
+use fs_err as fs;
+
+use crate::wheel::{read_record_file, read_scripts_from_section};
+use crate::Error;
+
+/// A location in the environment that may be written by more than one distribution, such that
+/// installing one distribution could clobber a file written by another.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SharedPath {
+    /// A top-level module (like `foo.py` or `foo/__init__.py`), relative to `site-packages`.
+    Module(String),
+    /// A console or GUI script entrypoint, by name.
+    Script(String),
+}
+
+impl Display for SharedPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Module(path) => write!(f, "module `{path}`"),
+            Self::Script(name) => write!(f, "script `{name}`"),
+        }
+    }
+}
+
+/// Read the [`SharedPath`]s written by the distribution with the given `.dist-info` directory.
+///
+/// Each path is paired with a fingerprint of its contents (the hash of a module, or the target of
+/// an entrypoint), such that distributions that write identical contents to the same location
+/// (e.g., a shared `__init__.py` in a `pkgutil`-style namespace package) can be distinguished
+/// from those that would clobber one another.
+pub fn read_shared_paths(dist_info: &Path) -> Result<Vec<(SharedPath, Option<String>)>, Error> {
+    let mut paths = Vec::new();
+
+    // Read the top-level modules from the `RECORD`.
+    if let Ok(mut record) = fs::File::open(dist_info.join("RECORD")) {
+        for entry in read_record_file(&mut record)? {
+            let is_top_level = match entry.path.split_once('/') {
+                None => [".py", ".so", ".pyd"]
+                    .iter()
+                    .any(|ext| entry.path.ends_with(ext)),
+                Some((_, rest)) => rest == "__init__.py",
+            };
+            if is_top_level {
+                paths.push((SharedPath::Module(entry.path), entry.hash));
+            }
+        }
+    }
+
+    // Read the entrypoints from `entry_points.txt`.
+    if let Ok(ini) = fs::read_to_string(dist_info.join("entry_points.txt")) {
+        let entry_points = Ini::new_cs()
+            .read(ini)
+            .map_err(|err| Error::InvalidWheel(format!("entry_points.txt is invalid: {err}")))?;
+        for section in ["console_scripts", "gui_scripts"] {
+            let Some(scripts) = entry_points.get(section) else {
+                continue;
+            };
+            for script in read_scripts_from_section(scripts, section, None)? {
+                let target = format!("{}:{}", script.module, script.function);
+                paths.push((SharedPath::Script(script.name), Some(target)));
+            }
+        }
+    }
+
+    Ok(paths)
+}
@@ -13,10 +13,10 @@ use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
 use uv_pypi_types::{DirectUrl, Metadata10};
 
-use crate::linker::{LinkMode, Locks};
+use crate::linker::{LinkMode, LinkStats, Locks};
 use crate::wheel::{
     dist_info_metadata, find_dist_info, install_data, parse_scripts, parse_wheel_file,
-    read_record_file, write_installer_metadata, write_script_entrypoints, LibKind,
+    read_record_file, write_installer_metadata, write_script_entrypoints, LibKind, ScriptLauncher,
 };
 use crate::{Error, Layout};
 
@@ -38,7 +38,9 @@ pub fn install_wheel(
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
+    script_launcher: ScriptLauncher,
     locks: &Locks,
+    stats: &LinkStats,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
     let metadata = dist_info_metadata(&dist_info_prefix, &wheel)?;
@@ -75,7 +77,7 @@ pub fn install_wheel(
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks)?;
+    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks, stats)?;
     trace!(?name, "Extracted {num_unpacked} files");
 
     // Read the RECORD file.
@@ -102,6 +104,7 @@ pub fn install_wheel(
             &console_scripts,
             &mut record,
             false,
+            script_launcher,
         )?;
         write_script_entrypoints(
             layout,
@@ -110,6 +113,7 @@ pub fn install_wheel(
             &gui_scripts,
             &mut record,
             true,
+            script_launcher,
         )?;
     }
 
@@ -10,11 +10,15 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
+pub use conflicts::{read_shared_paths, SharedPath};
 pub use install::install_wheel;
-pub use linker::{LinkMode, Locks};
+pub use linker::{LinkMode, LinkStats, Locks};
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
-pub use wheel::{parse_wheel_file, read_record_file, LibKind};
+pub use wheel::{
+    find_dist_info, parse_wheel_file, read_record_file, verify_record, LibKind, ScriptLauncher,
+};
 
+mod conflicts;
 mod install;
 mod linker;
 mod record;
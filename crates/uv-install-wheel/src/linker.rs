@@ -1,3 +1,4 @@
+use crate::wheel::find_dist_info;
 use crate::Error;
 use fs_err as fs;
 use fs_err::DirEntry;
@@ -5,6 +6,7 @@ use reflink_copy as reflink;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 use tempfile::tempdir_in;
@@ -15,12 +17,49 @@ use walkdir::WalkDir;
 #[derive(Debug, Default)]
 pub struct Locks(Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>);
 
+/// The number of bytes linked or copied into the environment, shared across concurrent wheel
+/// installations.
+#[derive(Debug, Default)]
+pub struct LinkStats {
+    /// The number of bytes that were linked from the cache (i.e., hard-linked, cloned, or
+    /// symlinked), and so don't consume additional disk space.
+    linked: AtomicU64,
+    /// The number of bytes that were copied from the cache.
+    copied: AtomicU64,
+}
+
+impl LinkStats {
+    /// Return the number of bytes that were linked from the cache.
+    pub fn linked_bytes(&self) -> u64 {
+        self.linked.load(Ordering::Relaxed)
+    }
+
+    /// Return the number of bytes that were copied from the cache.
+    pub fn copied_bytes(&self) -> u64 {
+        self.copied.load(Ordering::Relaxed)
+    }
+
+    /// Record that the given file or directory was linked from the cache.
+    fn add_linked(&self, path: &Path) {
+        let bytes = WalkDir::new(path)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        self.linked.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum LinkMode {
     /// Clone (i.e., copy-on-write) packages from the wheel into the `site-packages` directory.
+    #[serde(alias = "reflink")]
+    #[cfg_attr(feature = "clap", value(alias = "reflink"))]
     Clone,
     /// Copy packages from the wheel into the `site-packages` directory.
     Copy,
@@ -53,12 +92,13 @@ impl LinkMode {
         site_packages: impl AsRef<Path>,
         wheel: impl AsRef<Path>,
         locks: &Locks,
+        stats: &LinkStats,
     ) -> Result<usize, Error> {
         match self {
-            Self::Clone => clone_wheel_files(site_packages, wheel, locks),
-            Self::Copy => copy_wheel_files(site_packages, wheel, locks),
-            Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks),
-            Self::Symlink => symlink_wheel_files(site_packages, wheel, locks),
+            Self::Clone => clone_wheel_files(site_packages, wheel, locks, stats),
+            Self::Copy => copy_wheel_files(site_packages, wheel, locks, stats),
+            Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks, stats),
+            Self::Symlink => symlink_wheel_files(site_packages, wheel, locks, stats),
         }
     }
 
@@ -66,6 +106,41 @@ impl LinkMode {
     pub fn is_symlink(&self) -> bool {
         matches!(self, Self::Symlink)
     }
+
+    /// Returns `true` if files can be linked from the given unzipped wheel into `site_packages`
+    /// with this link mode, without falling back to copying (e.g., because the cache and the
+    /// target directory are on different filesystems).
+    pub fn is_supported(
+        self,
+        site_packages: impl AsRef<Path>,
+        wheel: impl AsRef<Path>,
+    ) -> Result<bool, Error> {
+        let dist_info_prefix = find_dist_info(&wheel)?;
+        let from = wheel
+            .as_ref()
+            .join(format!("{dist_info_prefix}.dist-info"))
+            .join("METADATA");
+
+        fs::create_dir_all(site_packages.as_ref())?;
+        let tempdir = tempdir_in(site_packages)?;
+        let to = tempdir.path().join("METADATA");
+
+        let result = match self {
+            Self::Clone => reflink::reflink(&from, &to),
+            Self::Copy => return Ok(true),
+            Self::Hardlink => fs::hard_link(&from, &to),
+            Self::Symlink => create_symlink(&from, &to),
+        };
+        if let Err(err) = result {
+            debug!(
+                "Failed to link `{}` to `{}`: {err}",
+                from.display(),
+                to.display()
+            );
+            return Ok(false);
+        }
+        Ok(true)
+    }
 }
 
 /// Extract a wheel by cloning all of its files into site packages. The files will be cloned
@@ -78,6 +153,7 @@ fn clone_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
+    stats: &LinkStats,
 ) -> Result<usize, Error> {
     let mut count = 0usize;
     let mut attempt = Attempt::default();
@@ -87,6 +163,7 @@ fn clone_wheel_files(
             site_packages.as_ref(),
             wheel.as_ref(),
             locks,
+            stats,
             &entry?,
             &mut attempt,
         )?;
@@ -153,6 +230,7 @@ fn clone_recursive(
     site_packages: &Path,
     wheel: &Path,
     locks: &Locks,
+    stats: &LinkStats,
     entry: &DirEntry,
     attempt: &mut Attempt,
 ) -> Result<(), Error> {
@@ -165,7 +243,7 @@ fn clone_recursive(
     if (cfg!(windows) || cfg!(target_os = "linux")) && from.is_dir() {
         fs::create_dir_all(&to)?;
         for entry in fs::read_dir(from)? {
-            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+            clone_recursive(site_packages, wheel, locks, stats, &entry?, attempt)?;
         }
         return Ok(());
     }
@@ -178,7 +256,7 @@ fn clone_recursive(
                     // merged recursively.
                     if entry.file_type()?.is_dir() {
                         for entry in fs::read_dir(from)? {
-                            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                            clone_recursive(site_packages, wheel, locks, stats, &entry?, attempt)?;
                         }
                     } else {
                         // If file already exists, overwrite it.
@@ -186,6 +264,7 @@ fn clone_recursive(
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         if reflink::reflink(&from, &tempfile).is_ok() {
                             fs::rename(&tempfile, to)?;
+                            stats.add_linked(&from);
                         } else {
                             debug!(
                                 "Failed to clone `{}` to temporary location `{}`, attempting to copy files as a fallback",
@@ -193,7 +272,7 @@ fn clone_recursive(
                                 tempfile.display(),
                             );
                             *attempt = Attempt::UseCopyFallback;
-                            synchronized_copy(&from, &to, locks)?;
+                            synchronized_copy(&from, &to, locks, stats)?;
                         }
                     }
                 } else {
@@ -204,8 +283,10 @@ fn clone_recursive(
                     );
                     // Fallback to copying
                     *attempt = Attempt::UseCopyFallback;
-                    clone_recursive(site_packages, wheel, locks, entry, attempt)?;
+                    clone_recursive(site_packages, wheel, locks, stats, entry, attempt)?;
                 }
+            } else {
+                stats.add_linked(&from);
             }
         }
         Attempt::Subsequent => {
@@ -214,7 +295,7 @@ fn clone_recursive(
                     // If cloning/copying fails and the directory exists already, it must be merged recursively.
                     if entry.file_type()?.is_dir() {
                         for entry in fs::read_dir(from)? {
-                            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                            clone_recursive(site_packages, wheel, locks, stats, &entry?, attempt)?;
                         }
                     } else {
                         // If file already exists, overwrite it.
@@ -222,20 +303,23 @@ fn clone_recursive(
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         reflink::reflink(&from, &tempfile)?;
                         fs::rename(&tempfile, to)?;
+                        stats.add_linked(&from);
                     }
                 } else {
                     return Err(Error::Reflink { from, to, err });
                 }
+            } else {
+                stats.add_linked(&from);
             }
         }
         Attempt::UseCopyFallback => {
             if entry.file_type()?.is_dir() {
                 fs::create_dir_all(&to)?;
                 for entry in fs::read_dir(from)? {
-                    clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                    clone_recursive(site_packages, wheel, locks, stats, &entry?, attempt)?;
                 }
             } else {
-                synchronized_copy(&from, &to, locks)?;
+                synchronized_copy(&from, &to, locks, stats)?;
             }
             warn_user_once!("Failed to clone files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, reflinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
         }
@@ -252,6 +336,7 @@ fn copy_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
+    stats: &LinkStats,
 ) -> Result<usize, Error> {
     let mut count = 0usize;
 
@@ -268,7 +353,7 @@ fn copy_wheel_files(
             continue;
         }
 
-        synchronized_copy(path, &out_path, locks)?;
+        synchronized_copy(path, &out_path, locks, stats)?;
 
         count += 1;
     }
@@ -281,6 +366,7 @@ fn hardlink_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
+    stats: &LinkStats,
 ) -> Result<usize, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
@@ -300,7 +386,7 @@ fn hardlink_wheel_files(
 
         // The `RECORD` file is modified during installation, so we copy it instead of hard-linking.
         if path.ends_with("RECORD") {
-            synchronized_copy(path, &out_path, locks)?;
+            synchronized_copy(path, &out_path, locks, stats)?;
             count += 1;
             continue;
         }
@@ -328,7 +414,7 @@ fn hardlink_wheel_files(
                                 out_path.display(),
                                 path.display()
                             );
-                            synchronized_copy(path, &out_path, locks)?;
+                            synchronized_copy(path, &out_path, locks, stats)?;
                             attempt = Attempt::UseCopyFallback;
                         }
                     } else {
@@ -337,7 +423,7 @@ fn hardlink_wheel_files(
                             out_path.display(),
                             path.display()
                         );
-                        synchronized_copy(path, &out_path, locks)?;
+                        synchronized_copy(path, &out_path, locks, stats)?;
                         attempt = Attempt::UseCopyFallback;
                     }
                 }
@@ -361,11 +447,15 @@ fn hardlink_wheel_files(
                 }
             }
             Attempt::UseCopyFallback => {
-                synchronized_copy(path, &out_path, locks)?;
+                synchronized_copy(path, &out_path, locks, stats)?;
                 warn_user_once!("Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
             }
         }
 
+        if attempt != Attempt::UseCopyFallback {
+            stats.add_linked(path);
+        }
+
         count += 1;
     }
 
@@ -377,6 +467,7 @@ fn symlink_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
+    stats: &LinkStats,
 ) -> Result<usize, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
@@ -396,7 +487,7 @@ fn symlink_wheel_files(
 
         // The `RECORD` file is modified during installation, so we copy it instead of symlinking.
         if path.ends_with("RECORD") {
-            synchronized_copy(path, &out_path, locks)?;
+            synchronized_copy(path, &out_path, locks, stats)?;
             count += 1;
             continue;
         }
@@ -424,7 +515,7 @@ fn symlink_wheel_files(
                                 out_path.display(),
                                 path.display()
                             );
-                            synchronized_copy(path, &out_path, locks)?;
+                            synchronized_copy(path, &out_path, locks, stats)?;
                             attempt = Attempt::UseCopyFallback;
                         }
                     } else {
@@ -433,7 +524,7 @@ fn symlink_wheel_files(
                             out_path.display(),
                             path.display()
                         );
-                        synchronized_copy(path, &out_path, locks)?;
+                        synchronized_copy(path, &out_path, locks, stats)?;
                         attempt = Attempt::UseCopyFallback;
                     }
                 }
@@ -457,11 +548,15 @@ fn symlink_wheel_files(
                 }
             }
             Attempt::UseCopyFallback => {
-                synchronized_copy(path, &out_path, locks)?;
+                synchronized_copy(path, &out_path, locks, stats)?;
                 warn_user_once!("Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, symlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
             }
         }
 
+        if attempt != Attempt::UseCopyFallback {
+            stats.add_linked(path);
+        }
+
         count += 1;
     }
 
@@ -472,7 +567,12 @@ fn symlink_wheel_files(
 /// writes to the same file, which can lead to corruption.
 ///
 /// See: <https://github.com/astral-sh/uv/issues/4831>
-fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<()> {
+fn synchronized_copy(
+    from: &Path,
+    to: &Path,
+    locks: &Locks,
+    stats: &LinkStats,
+) -> std::io::Result<()> {
     // Ensure we have a lock for the directory.
     let dir_lock = {
         let mut locks_guard = locks.0.lock().unwrap();
@@ -486,7 +586,8 @@ fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<(
     let _dir_guard = dir_lock.lock().unwrap();
 
     // Copy the file, which will also set its permissions.
-    fs::copy(from, to)?;
+    let bytes = fs::copy(from, to)?;
+    stats.copied.fetch_add(bytes, Ordering::Relaxed);
 
     Ok(())
 }
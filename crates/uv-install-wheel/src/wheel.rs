@@ -158,19 +158,76 @@ fn get_script_executable(python_executable: &Path, is_gui: bool) -> PathBuf {
     }
 }
 
+/// The kind of launcher to generate for entrypoints on Windows.
+///
+/// On other platforms, entrypoints are always written as Python scripts with a shebang, and the
+/// launcher kind has no effect.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptLauncher {
+    /// Use a console launcher for `console_scripts` entrypoints, and a GUI (i.e.,
+    /// windows-subsystem) launcher for `gui_scripts` entrypoints, such that GUI applications don't
+    /// open a console window.
+    #[default]
+    Auto,
+    /// Use a console launcher for all entrypoints.
+    Console,
+    /// Use a GUI launcher for all entrypoints.
+    Gui,
+    /// Write all entrypoints as plain Python scripts (`.py` for `console_scripts`, and `.pyw` for
+    /// `gui_scripts`), to be run via the Python launcher's file associations, rather than as
+    /// `.exe` trampolines.
+    Script,
+}
+
+impl ScriptLauncher {
+    /// Returns `true` if an entrypoint from the given section should use a GUI launcher.
+    fn is_gui(self, is_gui_script: bool) -> bool {
+        match self {
+            Self::Auto | Self::Script => is_gui_script,
+            Self::Console => false,
+            Self::Gui => true,
+        }
+    }
+}
+
+impl std::str::FromStr for ScriptLauncher {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "console" => Ok(Self::Console),
+            "gui" => Ok(Self::Gui),
+            "script" => Ok(Self::Script),
+            _ => Err(format!(
+                "`{s}` (expected `auto`, `console`, `gui`, or `script`)"
+            )),
+        }
+    }
+}
+
 /// Determine the absolute path to an entrypoint script.
-fn entrypoint_path(entrypoint: &Script, layout: &Layout) -> PathBuf {
+fn entrypoint_path(
+    entrypoint: &Script,
+    layout: &Layout,
+    launcher: ScriptLauncher,
+    is_gui: bool,
+) -> PathBuf {
     if cfg!(windows) {
-        // On windows we actually build an .exe wrapper
-        let script_name = entrypoint
+        // FIXME: What are the in-reality rules here for names?
+        let stem = entrypoint
             .name
-            // FIXME: What are the in-reality rules here for names?
             .strip_suffix(".py")
-            .unwrap_or(&entrypoint.name)
-            .to_string()
-            + ".exe";
+            .unwrap_or(&entrypoint.name);
+
+        // On windows we actually build an .exe wrapper, unless plain scripts were requested.
+        let extension = match launcher {
+            ScriptLauncher::Script if is_gui => "pyw",
+            ScriptLauncher::Script => "py",
+            _ => "exe",
+        };
 
-        layout.scheme.scripts.join(script_name)
+        layout.scheme.scripts.join(format!("{stem}.{extension}"))
     } else {
         layout.scheme.scripts.join(&entrypoint.name)
     }
@@ -183,10 +240,12 @@ pub(crate) fn write_script_entrypoints(
     site_packages: &Path,
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
-    is_gui: bool,
+    is_gui_script: bool,
+    launcher: ScriptLauncher,
 ) -> Result<(), Error> {
+    let is_gui = launcher.is_gui(is_gui_script);
     for entrypoint in entrypoints {
-        let entrypoint_absolute = entrypoint_path(entrypoint, layout);
+        let entrypoint_absolute = entrypoint_path(entrypoint, layout, launcher, is_gui);
 
         let entrypoint_relative = pathdiff::diff_paths(&entrypoint_absolute, site_packages)
             .ok_or_else(|| {
@@ -209,7 +268,7 @@ pub(crate) fn write_script_entrypoints(
         );
 
         // If necessary, wrap the launcher script in a Windows launcher binary.
-        if cfg!(windows) {
+        if cfg!(windows) && launcher != ScriptLauncher::Script {
             write_file_recorded(
                 site_packages,
                 &entrypoint_relative,
@@ -730,6 +789,57 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Verify the files of an unzipped wheel against the hashes recorded in its `RECORD` file.
+///
+/// Returns the number of files verified. Entries without a SHA-256 hash (e.g., the `RECORD` file
+/// itself) are skipped.
+pub fn verify_record(wheel: impl AsRef<Path>) -> Result<usize, Error> {
+    let wheel = wheel.as_ref();
+    let dist_info_prefix = find_dist_info(wheel)?;
+    let record_path = wheel.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+    let mut record_file = match File::open(&record_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::InvalidWheel("Missing RECORD file".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut verified = 0;
+    for entry in read_record_file(&mut record_file)? {
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .filter(|hash| hash.starts_with("sha256="))
+        else {
+            continue;
+        };
+
+        // Skip any entries outside the wheel.
+        if entry.path.split('/').any(|component| component == "..") {
+            continue;
+        }
+
+        let mut file = match File::open(wheel.join(&entry.path)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::RecordFile(format!("Missing file: {}", entry.path)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual != expected {
+            return Err(Error::RecordFile(format!(
+                "Hash mismatch for: {}",
+                entry.path
+            )));
+        }
+        verified += 1;
+    }
+
+    Ok(verified)
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
@@ -767,7 +877,7 @@ fn parse_email_message_file(
 /// See: <https://github.com/PyO3/python-pkginfo-rs>
 ///
 /// See: <https://github.com/pypa/pip/blob/36823099a9cdd83261fdbc8c1d2a24fa2eea72ca/src/pip/_internal/utils/wheel.py#L38>
-pub(crate) fn find_dist_info(path: impl AsRef<Path>) -> Result<String, Error> {
+pub fn find_dist_info(path: impl AsRef<Path>) -> Result<String, Error> {
     // Iterate over `path` to find the `.dist-info` directory. It should be at the top-level.
     let Some(dist_info) = fs::read_dir(path.as_ref())?.find_map(|entry| {
         let entry = entry.ok()?;
@@ -876,7 +986,7 @@ mod test {
 
     use super::{
         get_script_executable, parse_email_message_file, parse_wheel_file, read_record_file,
-        write_installer_metadata, RecordEntry, Script,
+        verify_record, write_installer_metadata, RecordEntry, Script,
     };
 
     #[test]
@@ -1183,4 +1293,33 @@ mod test {
             .collect::<Vec<String>>();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_verify_record() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        temp_dir
+            .child("foo/__init__.py")
+            .write_str("__version__ = \"0.1.0\"\n")?;
+        temp_dir
+            .child("foo-0.1.0.dist-info/RECORD")
+            .write_str(indoc! {r"
+            foo/__init__.py,sha256=kUR5RAFc7HCeiqdlX36dZOHkUI5wI6V_43RpEcD8b-0,22
+            foo-0.1.0.dist-info/RECORD,,
+        "})?;
+        assert_eq!(verify_record(temp_dir.path())?, 1);
+
+        // A modified file is detected.
+        temp_dir
+            .child("foo/__init__.py")
+            .write_str("__version__ = \"0.2.0\"\n")?;
+        let err = verify_record(temp_dir.path()).unwrap_err();
+        assert!(matches!(err, Error::RecordFile(_)), "{err}");
+
+        // As is a missing file.
+        fs_err::remove_file(temp_dir.child("foo/__init__.py").path())?;
+        let err = verify_record(temp_dir.path()).unwrap_err();
+        assert!(matches!(err, Error::RecordFile(_)), "{err}");
+
+        Ok(())
+    }
 }
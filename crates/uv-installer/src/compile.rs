@@ -55,13 +55,56 @@ pub enum CompileError {
     },
     #[error("Python startup timed out ({}s)", _0.as_secs_f32())]
     StartupTimeout(Duration),
+    #[error("Invalid value for `UV_COMPILE_BYTECODE_OPTIMIZE`: `{0}` (expected `0`, `1`, or `2`)")]
+    InvalidOptimizationLevel(String),
+}
+
+/// The optimization level to use when compiling bytecode, as in `python -O` or `python -OO`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum OptimizationLevel {
+    /// Compile without optimizations.
+    #[default]
+    None,
+    /// Remove `assert` statements and code conditional on `__debug__` (`-O`).
+    Asserts,
+    /// Additionally remove docstrings (`-OO`).
+    Docstrings,
+}
+
+impl OptimizationLevel {
+    /// Read the optimization level from the environment, if set.
+    fn from_env() -> Result<Self, CompileError> {
+        match std::env::var(EnvVars::UV_COMPILE_BYTECODE_OPTIMIZE) {
+            Ok(value) => match value.trim() {
+                "" | "0" => Ok(Self::None),
+                "1" => Ok(Self::Asserts),
+                "2" => Ok(Self::Docstrings),
+                _ => Err(CompileError::InvalidOptimizationLevel(value)),
+            },
+            Err(_) => Ok(Self::None),
+        }
+    }
+
+    /// The flag to pass to the Python interpreter, if any.
+    fn flag(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Asserts => Some("-O"),
+            Self::Docstrings => Some("-OO"),
+        }
+    }
 }
 
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
-/// a broken `python`.
+/// Compilation errors in individual files (e.g., syntax errors in vendored Python 2 code) don't
+/// abort the compilation; like pip, we skip the file and continue, logging the error in verbose
+/// mode. There is a 60s timeout for each file to handle a broken `python`.
+///
+/// The optimization level can be set via `UV_COMPILE_BYTECODE_OPTIMIZE` and the invalidation
+/// mode via `PYC_INVALIDATION_MODE`. If `SOURCE_DATE_EPOCH` is set, the invalidation mode
+/// defaults to `checked-hash`, such that the output is reproducible.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
@@ -80,6 +123,7 @@ pub async fn compile_tree(
         dir.display()
     );
     let worker_count = concurrency.installs;
+    let optimization = OptimizationLevel::from_env()?;
 
     // A larger buffer is significantly faster than just 1 or the worker count.
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count * 10);
@@ -97,6 +141,7 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            optimization,
             receiver.clone(),
         );
 
@@ -188,6 +233,7 @@ async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    optimization: OptimizationLevel,
     receiver: Receiver<PathBuf>,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
@@ -202,7 +248,8 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, optimization)
+                    .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -227,8 +274,8 @@ async fn worker(
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
-    // If there was something printed to stderr (which shouldn't happen, we muted all errors), tell
-    // the user, otherwise only forward the result.
+    // If there was something printed to stderr (e.g., errors for files that failed to compile),
+    // log it, otherwise only forward the result.
     let child_stderr_collected = stderr_reader
         .await
         .map_err(|_| CompileError::Join)?
@@ -266,6 +313,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    optimization: OptimizationLevel,
 ) -> Result<
     Option<(
         Child,
@@ -277,6 +325,7 @@ async fn launch_bytecode_compiler(
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
     let mut bytecode_compiler = Command::new(interpreter)
+        .args(optimization.flag())
         .arg(pip_compileall_py)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
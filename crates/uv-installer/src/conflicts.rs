@@ -0,0 +1,168 @@
+use std::collections::hash_map::Entry;
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use uv_distribution_types::{CachedDist, InstalledDist, Name};
+use uv_install_wheel::{find_dist_info, read_shared_paths, SharedPath};
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+use crate::SitePackages;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConflictError {
+    #[error(transparent)]
+    Install(#[from] uv_install_wheel::Error),
+    #[error("Failed to read the installed packages")]
+    SitePackages(#[source] anyhow::Error),
+    #[error("Invalid value for `UV_INSTALL_CONFLICTS`: `{0}` (expected `warn` or `error`)")]
+    InvalidPolicy(String),
+}
+
+/// How to handle distributions that would write to the same location as another distribution.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Warn about the conflict, and install the distributions anyway.
+    #[default]
+    Warn,
+    /// Fail the installation.
+    Error,
+}
+
+impl ConflictPolicy {
+    /// Read the conflict policy from the environment, if set.
+    pub fn from_env() -> Result<Self, ConflictError> {
+        match std::env::var(EnvVars::UV_INSTALL_CONFLICTS) {
+            Ok(value) => match value.as_str() {
+                "warn" => Ok(Self::Warn),
+                "error" => Ok(Self::Error),
+                _ => Err(ConflictError::InvalidPolicy(value)),
+            },
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+/// A module or entrypoint that would be written by more than one distribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallConflict {
+    /// The conflicting location.
+    pub path: SharedPath,
+    /// The distribution that already provides the location.
+    pub existing: PackageName,
+    /// The distribution that would overwrite it.
+    pub new: PackageName,
+}
+
+impl Display for InstallConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The {} is provided by both `{}` and `{}`",
+            self.path, self.existing, self.new
+        )
+    }
+}
+
+/// Detect any modules or entrypoints that the given wheels would write to the same location as
+/// another distribution, but with different contents.
+///
+/// Wheels are compared against each other, and against any distributions in the environment that
+/// are not part of the given `uninstalls`.
+pub fn find_conflicts(
+    wheels: &[CachedDist],
+    uninstalls: &[InstalledDist],
+    venv: &PythonEnvironment,
+) -> Result<Vec<InstallConflict>, ConflictError> {
+    let mut conflicts = Vec::new();
+
+    // Compare the wheels against each other.
+    let mut claims: FxHashMap<SharedPath, (&PackageName, Option<String>)> = FxHashMap::default();
+    for wheel in wheels {
+        let dist_info_prefix = find_dist_info(wheel.path())?;
+        let dist_info = wheel.path().join(format!("{dist_info_prefix}.dist-info"));
+        for (path, fingerprint) in read_shared_paths(&dist_info)? {
+            match claims.entry(path) {
+                Entry::Occupied(entry) => {
+                    let (owner, existing) = entry.get();
+                    if *owner != wheel.name() && *existing != fingerprint {
+                        conflicts.push(InstallConflict {
+                            path: entry.key().clone(),
+                            existing: (*owner).clone(),
+                            new: wheel.name().clone(),
+                        });
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((wheel.name(), fingerprint));
+                }
+            }
+        }
+    }
+
+    // Ignore any locations that are provided by distributions that are being removed (e.g., a
+    // previous version of the same package).
+    let mut removed = FxHashSet::default();
+    for dist in uninstalls {
+        removed.extend(
+            read_shared_paths(dist.path())?
+                .into_iter()
+                .map(|(path, _)| path),
+        );
+    }
+
+    // Only index the environment if one of the claimed locations already exists on disk.
+    let exists = |path: &SharedPath| match path {
+        SharedPath::Module(module) => venv
+            .site_packages()
+            .any(|site_packages| site_packages.join(module).exists()),
+        SharedPath::Script(name) => {
+            if cfg!(windows) {
+                let name = name.strip_suffix(".py").unwrap_or(name);
+                venv.scripts().join(format!("{name}.exe")).exists()
+            } else {
+                venv.scripts().join(name).exists()
+            }
+        }
+    };
+    if !claims
+        .keys()
+        .filter(|path| !removed.contains(*path))
+        .any(exists)
+    {
+        return Ok(conflicts);
+    }
+
+    // Compare the wheels against the distributions that will remain installed.
+    let site_packages =
+        SitePackages::from_environment(venv).map_err(ConflictError::SitePackages)?;
+    for dist in site_packages.iter() {
+        if uninstalls
+            .iter()
+            .any(|uninstall| uninstall.path() == dist.path())
+        {
+            continue;
+        }
+        if wheels.iter().any(|wheel| wheel.name() == dist.name()) {
+            continue;
+        }
+        for (path, fingerprint) in read_shared_paths(dist.path())? {
+            let Some((owner, claimed)) = claims.get(&path) else {
+                continue;
+            };
+            if *claimed != fingerprint {
+                debug!("Found conflicting {path} in `{}`", dist.name());
+                conflicts.push(InstallConflict {
+                    path,
+                    existing: dist.name().clone(),
+                    new: (*owner).clone(),
+                });
+            }
+        }
+    }
+
+    Ok(conflicts)
+}
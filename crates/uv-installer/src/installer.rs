@@ -1,16 +1,18 @@
 use std::convert;
+use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
 use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tokio::sync::oneshot;
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 use uv_cache::Cache;
 use uv_configuration::RAYON_INITIALIZE;
 use uv_distribution_types::CachedDist;
-use uv_install_wheel::{Layout, LinkMode};
+use uv_install_wheel::{Layout, LinkMode, LinkStats, ScriptLauncher};
 use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
@@ -145,6 +147,42 @@ impl<'a> Installer<'a> {
     }
 }
 
+/// How to handle a [`LinkMode`] that isn't supported between the cache and the target
+/// environment (e.g., hard-linking across filesystems).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LinkFallback {
+    /// Warn, and fall back to copying.
+    #[default]
+    Warn,
+    /// Fail the installation.
+    Error,
+}
+
+impl LinkFallback {
+    /// Read the fallback behavior from the environment, if set.
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(EnvVars::UV_LINK_FALLBACK) {
+            Ok(value) => match value.as_str() {
+                "warn" => Ok(Self::Warn),
+                "error" => Ok(Self::Error),
+                _ => Err(anyhow::anyhow!(
+                    "Invalid value for `UV_LINK_FALLBACK`: `{value}` (expected `warn` or `error`)"
+                )),
+            },
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+/// Read the kind of launcher to generate for entrypoints from the environment, if set.
+fn script_launcher_from_env() -> Result<ScriptLauncher> {
+    match std::env::var(EnvVars::UV_SCRIPT_LAUNCHER) {
+        Ok(value) => ScriptLauncher::from_str(&value)
+            .map_err(|err| anyhow::anyhow!("Invalid value for `UV_SCRIPT_LAUNCHER`: {err}")),
+        Err(_) => Ok(ScriptLauncher::default()),
+    }
+}
+
 /// Install a set of wheels into a Python virtual environment synchronously.
 #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
 fn install(
@@ -156,9 +194,30 @@ fn install(
     relocatable: bool,
     installer_metadata: bool,
 ) -> Result<Vec<CachedDist>> {
+    // If falling back to copying is disallowed, verify that the link mode is supported before
+    // installing anything.
+    if link_mode != LinkMode::Copy && LinkFallback::from_env()? == LinkFallback::Error {
+        if let Some(wheel) = wheels.first() {
+            if !link_mode.is_supported(&layout.scheme.purelib, wheel.path())? {
+                return Err(anyhow::anyhow!(
+                    "Failed to {} files from the cache into the environment. If the cache and target directories are on different filesystems, set `UV_CACHE_DIR` to a directory on the same filesystem as the environment, or use `--link-mode=copy`.",
+                    match link_mode {
+                        LinkMode::Clone => "clone",
+                        LinkMode::Hardlink => "hardlink",
+                        LinkMode::Symlink => "symlink",
+                        LinkMode::Copy => "copy",
+                    }
+                ));
+            }
+        }
+    }
+
+    let script_launcher = script_launcher_from_env()?;
+
     // Initialize the threadpool with the user settings.
     LazyLock::force(&RAYON_INITIALIZE);
     let locks = uv_install_wheel::Locks::default();
+    let stats = LinkStats::default();
     wheels.par_iter().try_for_each(|wheel| {
         uv_install_wheel::install_wheel(
             &layout,
@@ -177,7 +236,9 @@ fn install(
             installer_name.as_deref(),
             installer_metadata,
             link_mode,
+            script_launcher,
             &locks,
+            &stats,
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
@@ -188,6 +249,12 @@ fn install(
         Ok::<(), Error>(())
     })?;
 
+    debug!(
+        "Linked {} bytes from the cache ({} bytes copied)",
+        stats.linked_bytes(),
+        stats.copied_bytes()
+    );
+
     Ok(wheels)
 }
 
@@ -1,15 +1,21 @@
 pub use compile::{compile_tree, CompileError};
-pub use installer::{Installer, Reporter as InstallReporter};
+pub use conflicts::{find_conflicts, ConflictError, ConflictPolicy, InstallConflict};
+pub use installer::{Installer, LinkFallback, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
+pub use reproducible::{Reproducible, ReproducibleError};
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
+pub use transaction::{Transaction, TransactionError};
 pub use uninstall::{uninstall, UninstallError};
 
 mod compile;
+mod conflicts;
 mod preparer;
 
 mod installer;
 mod plan;
+mod reproducible;
 mod satisfies;
 mod site_packages;
+mod transaction;
 mod uninstall;
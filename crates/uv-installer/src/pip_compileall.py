@@ -9,6 +9,7 @@ which contains some vendored Python 2 code which fails to compile.
 """
 
 import compileall
+import contextlib
 import os
 import py_compile
 import sys
@@ -21,11 +22,13 @@ with warnings.catch_warnings():
     print("Ready")
 
     # https://docs.python.org/3/library/py_compile.html#py_compile.PycInvalidationMode
-    # TIMESTAMP, CHECKED_HASH, UNCHECKED_HASH
+    # TIMESTAMP, CHECKED_HASH, UNCHECKED_HASH (or, equivalently, `checked-hash`, etc.)
     invalidation_mode = os.environ.get("PYC_INVALIDATION_MODE")
     if invalidation_mode is not None:
         try:
-            invalidation_mode = py_compile.PycInvalidationMode[invalidation_mode]
+            invalidation_mode = py_compile.PycInvalidationMode[
+                invalidation_mode.upper().replace("-", "_")
+            ]
         except KeyError:
             invalidation_modes = ", ".join(
                 '"' + x.name + '"' for x in py_compile.PycInvalidationMode
@@ -36,6 +39,11 @@ with warnings.catch_warnings():
                 file=sys.stderr,
             )
             sys.exit(1)
+    if invalidation_mode is None and os.environ.get("SOURCE_DATE_EPOCH"):
+        # For reproducible installs, use hash-based invalidation, such that the bytecode doesn't
+        # embed the modification time of the source file. (`py_compile` does the same by default,
+        # but we don't want to rely on its implementation details.)
+        invalidation_mode = py_compile.PycInvalidationMode.CHECKED_HASH
     if invalidation_mode is None:
         try:
             invalidation_mode = py_compile._get_default_invalidation_mode()
@@ -57,11 +65,12 @@ with warnings.catch_warnings():
         path = path.strip()
         if not path:
             continue
-        # Unlike pip, we set quiet=2, so we don't have to capture stdout.
-        # We'd like to show those errors, but given that pip thinks that's totally fine,
-        # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
-        )
+        # Like pip, we skip files that fail to compile (e.g., vendored Python 2 code), but we
+        # forward the errors to stderr (stdout is used for communication with the parent), such
+        # that they're logged in verbose mode.
+        with contextlib.redirect_stdout(sys.stderr):
+            success = compileall.compile_file(
+                path, invalidation_mode=invalidation_mode, force=force, quiet=1
+            )
         # We're ready for the next file.
         print(path)
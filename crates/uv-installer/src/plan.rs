@@ -25,12 +25,23 @@ use crate::SitePackages;
 #[derive(Debug)]
 pub struct Planner<'a> {
     resolution: &'a Resolution,
+    allow_incompatible_wheels: bool,
 }
 
 impl<'a> Planner<'a> {
     /// Set the requirements use in the [`Plan`].
     pub fn new(resolution: &'a Resolution) -> Self {
-        Self { resolution }
+        Self {
+            resolution,
+            allow_incompatible_wheels: false,
+        }
+    }
+
+    /// Allow the installation of wheels that are incompatible with the current platform.
+    #[must_use]
+    pub fn with_allow_incompatible_wheels(mut self, allow_incompatible_wheels: bool) -> Self {
+        self.allow_incompatible_wheels = allow_incompatible_wheels;
+        self
     }
 
     /// Partition a set of requirements into those that should be linked from the cache, those that
@@ -138,7 +149,7 @@ impl<'a> Planner<'a> {
                     }
                 }
                 Dist::Built(BuiltDist::DirectUrl(wheel)) => {
-                    if !wheel.filename.is_compatible(tags) {
+                    if !self.allow_incompatible_wheels && !wheel.filename.is_compatible(tags) {
                         bail!(
                             "A URL dependency is incompatible with the current platform: {}",
                             wheel.url
@@ -189,7 +200,7 @@ impl<'a> Planner<'a> {
                         return Err(Error::NotFound(wheel.url.to_url()).into());
                     }
 
-                    if !wheel.filename.is_compatible(tags) {
+                    if !self.allow_incompatible_wheels && !wheel.filename.is_compatible(tags) {
                         bail!(
                             "A path dependency is incompatible with the current platform: {}",
                             wheel.install_path.user_display()
@@ -296,6 +307,24 @@ impl<'a> Planner<'a> {
                         );
                     }
                 }
+                Dist::Source(SourceDist::Hg(sdist)) => {
+                    // Find the most-compatible wheel from the cache, since we don't know
+                    // the filename in advance.
+                    if let Some(wheel) = built_index.hg(sdist) {
+                        if wheel.filename.name == sdist.name {
+                            let cached_dist = wheel.into_hg_dist(sdist);
+                            debug!("Mercurial source requirement already cached: {cached_dist}");
+                            cached.push(CachedDist::Url(cached_dist));
+                            continue;
+                        }
+
+                        warn!(
+                            "Cached wheel filename does not match requested distribution for: `{}` (found: `{}`)",
+                            sdist,
+                            wheel.filename
+                        );
+                    }
+                }
                 Dist::Source(SourceDist::Path(sdist)) => {
                     // Validate that the path exists.
                     if !sdist.install_path.exists() {
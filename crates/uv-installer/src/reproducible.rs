@@ -0,0 +1,122 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReproducibleError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    WalkDir(#[from] walkdir::Error),
+    #[error(transparent)]
+    Install(#[from] uv_install_wheel::Error),
+    #[error("Invalid value for `SOURCE_DATE_EPOCH`: `{0}` (expected a Unix timestamp)")]
+    InvalidEpoch(String),
+}
+
+/// Settings for producing reproducible installations, such that installing the same set of
+/// distributions twice yields bit-identical environments.
+///
+/// Enabled by setting `SOURCE_DATE_EPOCH`.
+///
+/// See: <https://reproducible-builds.org/specs/source-date-epoch/>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reproducible {
+    /// The modification time to assign to all installed files.
+    epoch: SystemTime,
+}
+
+impl Reproducible {
+    /// Read the reproducibility settings from the environment, if enabled.
+    pub fn from_env() -> Result<Option<Self>, ReproducibleError> {
+        let Ok(value) = std::env::var(EnvVars::SOURCE_DATE_EPOCH) else {
+            return Ok(None);
+        };
+        let seconds = value
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| ReproducibleError::InvalidEpoch(value.clone()))?;
+        Ok(Some(Self {
+            epoch: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+        }))
+    }
+
+    /// Set the modification time of every file and directory in the environment's
+    /// `site-packages`, along with any files that installed distributions recorded outside of
+    /// `site-packages` (e.g., entrypoint scripts), to the epoch.
+    ///
+    /// Symlinks are skipped, as updating them would modify their target instead.
+    ///
+    /// Returns the number of paths that were updated.
+    pub fn normalize_mtimes(&self, venv: &PythonEnvironment) -> Result<usize, ReproducibleError> {
+        let mut count = 0;
+        for site_packages in venv.site_packages() {
+            if !site_packages.is_dir() {
+                continue;
+            }
+
+            // Visit directories after their contents, such that creating `__pycache__`
+            // directories and the like doesn't invalidate the timestamps we've already written.
+            for entry in WalkDir::new(&site_packages).contents_first(true) {
+                let entry = entry?;
+                if entry.path_is_symlink() {
+                    continue;
+                }
+
+                // Any files recorded outside of `site-packages` (like scripts) won't be visited
+                // by the walk, so read them from the `RECORD`.
+                if entry.file_name() == "RECORD"
+                    && entry
+                        .path()
+                        .parent()
+                        .and_then(Path::extension)
+                        .is_some_and(|ext| ext == "dist-info")
+                {
+                    let mut record = fs_err::File::open(entry.path())?;
+                    for record_entry in read_record_file(&mut record)? {
+                        if !record_entry.path.starts_with("..") {
+                            continue;
+                        }
+                        let path = site_packages.join(&record_entry.path);
+                        if path.is_symlink() || !path.is_file() {
+                            continue;
+                        }
+                        set_mtime(&path, self.epoch);
+                        count += 1;
+                    }
+                }
+
+                set_mtime(entry.path(), self.epoch);
+                count += 1;
+            }
+        }
+
+        debug!("Normalized the modification time of {count} paths");
+        Ok(count)
+    }
+}
+
+/// Set the modification time of the given file or directory, logging (rather than failing) on
+/// error.
+fn set_mtime(path: &Path, mtime: SystemTime) {
+    // `File.set_modified` is not available in `fs_err` yet
+    #[allow(clippy::disallowed_types)]
+    match std::fs::File::open(path) {
+        Ok(file) => {
+            if let Err(err) = file.set_modified(mtime) {
+                debug!("Failed to update mtime for {}: {err}", path.user_display());
+            }
+        }
+        Err(err) => debug!(
+            "Failed to open {} to update mtime: {err}",
+            path.user_display()
+        ),
+    }
+}
@@ -152,6 +152,61 @@ impl RequirementSatisfaction {
 
                 Ok(Self::Satisfied)
             }
+            RequirementSource::Hg {
+                url: _,
+                hg: requested_hg,
+                subdirectory: requested_subdirectory,
+            } => {
+                let InstalledDist::Url(InstalledDirectUrlDist { direct_url, .. }) = &distribution
+                else {
+                    return Ok(Self::Mismatch);
+                };
+                let DirectUrl::VcsUrl {
+                    url: installed_url,
+                    vcs_info:
+                        VcsInfo {
+                            vcs: VcsKind::Hg,
+                            requested_revision: _,
+                            commit_id: installed_precise,
+                        },
+                    subdirectory: installed_subdirectory,
+                } = direct_url.as_ref()
+                else {
+                    return Ok(Self::Mismatch);
+                };
+
+                if requested_subdirectory != installed_subdirectory {
+                    debug!(
+                        "Subdirectory mismatch: {:?} vs. {:?}",
+                        installed_subdirectory, requested_subdirectory
+                    );
+                    return Ok(Self::Mismatch);
+                }
+
+                if !RepositoryUrl::parse(installed_url).is_ok_and(|installed_url| {
+                    installed_url == RepositoryUrl::new(requested_hg.repository())
+                }) {
+                    debug!(
+                        "Repository mismatch: {:?} vs. {:?}",
+                        installed_url,
+                        requested_hg.repository()
+                    );
+                    return Ok(Self::Mismatch);
+                }
+
+                if installed_precise.as_deref()
+                    != requested_hg.precise().as_ref().map(GitOid::as_str)
+                {
+                    debug!(
+                        "Precise mismatch: {:?} vs. {:?}",
+                        installed_precise,
+                        requested_hg.precise()
+                    );
+                    return Ok(Self::OutOfDate);
+                }
+
+                Ok(Self::Satisfied)
+            }
             RequirementSource::Path {
                 install_path: requested_path,
                 ext: _,
@@ -0,0 +1,232 @@
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+use tracing::debug;
+
+use uv_distribution_types::{CachedDist, InstalledDist};
+use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
+use uv_python::PythonEnvironment;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Install(#[from] uv_install_wheel::Error),
+}
+
+/// A set of modifications to a Python environment that can be rolled back if the installation
+/// fails partway through (e.g., due to a full disk), rather than leaving the environment in a
+/// partially-modified state.
+///
+/// Before a distribution is uninstalled, its files are staged (hard linked, if possible, or
+/// copied) into a temporary directory next to (but outside of) `site-packages`, such that they
+/// can be restored on rollback. Any distributions that were (partially) installed are removed on
+/// rollback.
+///
+/// Distributions without a `RECORD` file (e.g., `.egg-info` distributions) can't be staged, and
+/// are not restored on rollback.
+#[derive(Debug)]
+pub struct Transaction {
+    /// The directory in which uninstalled files are staged, created on first use.
+    staging: Option<TempDir>,
+    /// The original path of each staged file, along with its path in the staging directory.
+    staged: Vec<(PathBuf, PathBuf)>,
+    /// The unzipped wheels that are being installed.
+    installs: Vec<PathBuf>,
+    /// The `site-packages` directories of the environment.
+    site_packages: Vec<PathBuf>,
+}
+
+impl Transaction {
+    /// Begin a transaction against the given environment.
+    pub fn begin(venv: &PythonEnvironment) -> Self {
+        Self {
+            staging: None,
+            staged: Vec::new(),
+            installs: Vec::new(),
+            site_packages: venv
+                .site_packages()
+                .map(std::borrow::Cow::into_owned)
+                .collect(),
+        }
+    }
+
+    /// Stage the files of an installed distribution, prior to uninstalling it.
+    pub fn stage_uninstall(&mut self, dist: &InstalledDist) -> Result<(), TransactionError> {
+        let (InstalledDist::Registry(_) | InstalledDist::Url(_)) = dist else {
+            debug!(
+                "Unable to stage {} for rollback; it will not be restored if installation fails",
+                dist.path().user_display()
+            );
+            return Ok(());
+        };
+        let Some(site_packages) = dist.path().parent() else {
+            return Ok(());
+        };
+        let mut record = match fs_err::File::open(dist.path().join("RECORD")) {
+            Ok(record) => record,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let staging = match self.staging.as_ref() {
+            Some(staging) => staging.path().to_path_buf(),
+            None => {
+                // Stage files in a sibling of `site-packages`, rather than within it, so that the
+                // staging directory is never mistaken for an importable package, while remaining
+                // on the same filesystem such that files can be hard linked.
+                let staging = tempfile::Builder::new()
+                    .prefix(".uv-rollback-")
+                    .tempdir_in(site_packages.parent().unwrap_or(site_packages))?;
+                let path = staging.path().to_path_buf();
+                self.staging = Some(staging);
+                path
+            }
+        };
+
+        for entry in read_record_file(&mut record)? {
+            let path = site_packages.join(&entry.path);
+            let Ok(metadata) = fs_err::symlink_metadata(&path) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                continue;
+            }
+            let target = staging.join(self.staged.len().to_string());
+            if fs_err::hard_link(&path, &target).is_err() {
+                fs_err::copy(&path, &target)?;
+            }
+            self.staged.push((path, target));
+        }
+
+        Ok(())
+    }
+
+    /// Record the distributions that are about to be installed, such that they can be removed
+    /// on rollback.
+    pub fn stage_installs(&mut self, wheels: &[CachedDist]) {
+        self.installs
+            .extend(wheels.iter().map(|wheel| wheel.path().to_path_buf()));
+    }
+
+    /// Commit the transaction, discarding any staged files.
+    pub fn commit(self) {
+        debug!(
+            "Committed environment changes ({} staged file{} discarded)",
+            self.staged.len(),
+            if self.staged.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    /// Roll back the transaction, removing any installed distributions and restoring any
+    /// uninstalled distributions.
+    pub fn rollback(self) -> Result<(), TransactionError> {
+        debug!("Rolling back environment changes");
+
+        // Remove any distributions that were (partially) installed.
+        for wheel in &self.installs {
+            let Some(dist_info) = fs_err::read_dir(wheel)?
+                .filter_map(Result::ok)
+                .map(|entry| entry.file_name())
+                .find(|name| {
+                    Path::new(name)
+                        .extension()
+                        .is_some_and(|ext| ext == "dist-info")
+                })
+            else {
+                continue;
+            };
+            if let Some(dist_info) = self
+                .site_packages
+                .iter()
+                .map(|site_packages| site_packages.join(&dist_info))
+                .find(|dist_info| dist_info.join("RECORD").is_file())
+            {
+                uv_install_wheel::uninstall_wheel(&dist_info)?;
+            } else {
+                for site_packages in &self.site_packages {
+                    remove_linked(wheel, &dist_info, site_packages)?;
+                }
+            }
+        }
+
+        // Restore any staged files, in reverse order.
+        for (path, staged) in self.staged.iter().rev() {
+            if let Some(parent) = path.parent() {
+                fs_err::create_dir_all(parent)?;
+            }
+            match fs_err::remove_file(path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+            if fs_err::rename(staged, path).is_err() {
+                fs_err::copy(staged, path)?;
+            }
+        }
+
+        debug!(
+            "Restored {} file{} from the staging directory",
+            self.staged.len(),
+            if self.staged.len() == 1 { "" } else { "s" }
+        );
+
+        Ok(())
+    }
+}
+
+/// Remove any files that were linked from the unzipped wheel into `site-packages`, for wheels
+/// whose installation failed before the `RECORD` was written to `site-packages`.
+///
+/// The files are read from the `RECORD` of the unzipped wheel, rather than by walking the wheel,
+/// such that only the files that the wheel itself declares are removed.
+fn remove_linked(
+    wheel: &Path,
+    dist_info: &OsStr,
+    site_packages: &Path,
+) -> Result<(), TransactionError> {
+    let mut record = match fs_err::File::open(wheel.join(dist_info).join("RECORD")) {
+        Ok(record) => record,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut directories = BTreeSet::new();
+    for entry in read_record_file(&mut record)? {
+        let relative = Path::new(&entry.path);
+
+        // The `.data` directory is not linked into `site-packages`.
+        if relative.components().next().is_some_and(|component| {
+            Path::new(component.as_os_str())
+                .extension()
+                .is_some_and(|ext| ext == "data")
+        }) {
+            continue;
+        }
+
+        let path = site_packages.join(relative);
+        match fs_err::remove_file(&path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        directories.extend(
+            relative
+                .ancestors()
+                .skip(1)
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .map(|parent| site_packages.join(parent)),
+        );
+    }
+
+    // Remove any directories that were left empty, deepest first.
+    for directory in directories.iter().rev() {
+        let _ = fs_err::remove_dir(directory);
+    }
+
+    Ok(())
+}
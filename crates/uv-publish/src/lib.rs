@@ -379,7 +379,7 @@ pub async fn upload(
 
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
-    let retry_policy = client.retry_policy();
+    let retry_policy = client.retry_policy(registry);
     loop {
         let (request, idx) = build_request(
             file,
@@ -464,9 +464,11 @@ pub async fn check_url(
     } = check_url_client;
 
     // Avoid using the PyPI 10min default cache.
-    let cache_refresh = (*cache)
-        .clone()
-        .with_refresh(Refresh::from_args(None, vec![filename.name().clone()]));
+    let cache_refresh = (*cache).clone().with_refresh(Refresh::from_args(
+        None,
+        vec![filename.name().clone()],
+        Vec::new(),
+    ));
     let registry_client = registry_client_builder
         .clone()
         .cache(cache_refresh)
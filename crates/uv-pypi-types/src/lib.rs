@@ -2,6 +2,7 @@ pub use base_url::*;
 pub use conflicts::*;
 pub use direct_url::*;
 pub use lenient_requirement::*;
+pub use license::*;
 pub use marker_environment::*;
 pub use metadata::*;
 pub use parsed_url::*;
@@ -14,6 +15,7 @@ mod base_url;
 mod conflicts;
 mod direct_url;
 mod lenient_requirement;
+mod license;
 mod marker_environment;
 mod metadata;
 mod parsed_url;
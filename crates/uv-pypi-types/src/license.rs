@@ -0,0 +1,197 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An SPDX license identifier, as used in an allowlist or denylist (e.g., `MIT` or
+/// `Apache-2.0 WITH LLVM-exception`).
+///
+/// GNU licenses may be written with the `-only` or `-or-later` suffix, which are ignored, as they
+/// only apply to the license holder.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LicenseId {
+    source: String,
+    licensee: spdx::Licensee,
+}
+
+impl LicenseId {
+    /// Returns `true` if the license identifier satisfies the given license requirement.
+    fn satisfies(&self, req: &spdx::LicenseReq) -> bool {
+        self.licensee.satisfies(req)
+    }
+}
+
+impl FromStr for LicenseId {
+    type Err = LicenseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let trimmed = source.trim();
+        let normalized = trimmed
+            .strip_suffix("-or-later")
+            .or_else(|| trimmed.strip_suffix("-only"))
+            .unwrap_or(trimmed);
+        let licensee = spdx::Licensee::parse(normalized)
+            .map_err(|err| LicenseError::InvalidId(source.to_string(), err))?;
+        Ok(Self {
+            source: source.to_string(),
+            licensee,
+        })
+    }
+}
+
+impl Display for LicenseId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Serialize for LicenseId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for LicenseId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let source = String::deserialize(deserializer)?;
+        Self::from_str(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LicenseId {
+    fn schema_name() -> String {
+        "LicenseId".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("An SPDX license identifier, e.g., `MIT`.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LicenseError {
+    #[error("`{0}` is not a valid SPDX license identifier")]
+    InvalidId(String, #[source] spdx::error::ParseError),
+}
+
+/// How to treat candidates whose license is disallowed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LicenseMode {
+    /// Reject candidates with a disallowed license, preferring alternative versions.
+    #[default]
+    Deny,
+    /// Accept candidates with a disallowed license, but warn about them.
+    Warn,
+}
+
+/// A policy governing the licenses that are permitted in a resolution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LicensePolicy {
+    allowed: Vec<LicenseId>,
+    denied: Vec<LicenseId>,
+    mode: LicenseMode,
+}
+
+impl LicensePolicy {
+    /// Create a [`LicensePolicy`] from an allowlist and a denylist.
+    ///
+    /// An empty allowlist permits any license that isn't explicitly denied.
+    pub fn new(allowed: Vec<LicenseId>, denied: Vec<LicenseId>, mode: LicenseMode) -> Self {
+        Self {
+            allowed,
+            denied,
+            mode,
+        }
+    }
+
+    /// Returns `true` if the policy doesn't restrict any licenses.
+    pub fn is_empty(&self) -> bool {
+        self.allowed.is_empty() && self.denied.is_empty()
+    }
+
+    /// Returns the [`LicenseMode`] of the policy.
+    pub fn mode(&self) -> LicenseMode {
+        self.mode
+    }
+
+    /// Returns `true` if a distribution declaring the given SPDX license expression is permitted.
+    ///
+    /// An expression is permitted if at least one of its alternatives (i.e., the operands of an
+    /// `OR`) consists solely of licenses that are allowed and not denied. Expressions that can't be
+    /// parsed as SPDX, along with distributions that don't declare a license, are permitted, since
+    /// their license can't be determined.
+    pub fn permits(&self, expression: &str) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let Ok(expression) = spdx::Expression::parse_mode(expression, spdx::ParseMode::LAX) else {
+            return true;
+        };
+        expression.evaluate(|req| {
+            let allowed =
+                self.allowed.is_empty() || self.allowed.iter().any(|id| id.satisfies(req));
+            let denied = self.denied.iter().any(|id| id.satisfies(req));
+            allowed && !denied
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{LicenseId, LicenseMode, LicensePolicy};
+
+    fn ids(ids: &[&str]) -> Vec<LicenseId> {
+        ids.iter()
+            .map(|id| LicenseId::from_str(id).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn parse_license_id() {
+        assert!(LicenseId::from_str("MIT").is_ok());
+        assert!(LicenseId::from_str("GPL-3.0-or-later").is_ok());
+        assert!(LicenseId::from_str("Apache-2.0 WITH LLVM-exception").is_ok());
+        assert!(LicenseId::from_str("MIT OR Apache-2.0").is_err());
+        assert!(LicenseId::from_str("Not a license").is_err());
+    }
+
+    #[test]
+    fn allowlist() {
+        let policy = LicensePolicy::new(ids(&["MIT", "Apache-2.0"]), vec![], LicenseMode::Deny);
+        assert!(policy.permits("MIT"));
+        assert!(policy.permits("MIT OR GPL-3.0-only"));
+        assert!(policy.permits("Apache-2.0 AND MIT"));
+        assert!(!policy.permits("GPL-3.0-only"));
+        assert!(!policy.permits("MIT AND GPL-3.0-only"));
+        // Unrecognized expressions are permitted.
+        assert!(policy.permits("Proprietary, all rights reserved"));
+    }
+
+    #[test]
+    fn denylist() {
+        let policy = LicensePolicy::new(vec![], ids(&["GPL-3.0", "AGPL-3.0"]), LicenseMode::Deny);
+        assert!(policy.permits("MIT"));
+        assert!(policy.permits("MIT OR GPL-3.0-only"));
+        assert!(!policy.permits("GPL-3.0-or-later"));
+        assert!(!policy.permits("BSD-3-Clause AND AGPL-3.0-only"));
+    }
+}
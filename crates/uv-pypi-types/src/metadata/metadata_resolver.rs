@@ -32,6 +32,9 @@ pub struct ResolutionMetadata {
     /// Whether the version field is dynamic.
     #[serde(default)]
     pub dynamic: bool,
+    /// The SPDX license expression of the distribution, if known.
+    #[serde(default)]
+    pub license_expression: Option<String>,
 }
 
 /// From <https://github.com/PyO3/python-pkginfo-rs/blob/d719988323a0cfea86d4737116d7917f30e819e2/src/metadata.rs#LL78C2-L91C26>
@@ -76,6 +79,7 @@ impl ResolutionMetadata {
         let dynamic = headers
             .get_all_values("Dynamic")
             .any(|field| field == "Version");
+        let license_expression = parse_license_expression(&headers);
 
         Ok(Self {
             name,
@@ -84,6 +88,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extras,
             dynamic,
+            license_expression,
         })
     }
 
@@ -153,6 +158,7 @@ impl ResolutionMetadata {
                 },
             )
             .collect::<Vec<_>>();
+        let license_expression = parse_license_expression(&headers);
 
         Ok(Self {
             name,
@@ -161,6 +167,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extras,
             dynamic,
+            license_expression,
         })
     }
 
@@ -252,10 +259,23 @@ impl ResolutionMetadata {
             requires_python,
             provides_extras,
             dynamic,
+            license_expression: None,
         })
     }
 }
 
+/// Extract the SPDX license expression from the `License-Expression` field (Metadata 2.4), falling
+/// back to the legacy `License` field if it contains a valid SPDX expression.
+fn parse_license_expression(headers: &Headers<'_>) -> Option<String> {
+    if let Some(license_expression) = headers.get_first_value("License-Expression") {
+        return Some(license_expression);
+    }
+    headers
+        .get_first_value("License")
+        .map(|license| license.trim().to_string())
+        .filter(|license| spdx::Expression::parse(license).is_ok())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -5,7 +5,7 @@ use thiserror::Error;
 use url::{ParseError, Url};
 
 use uv_distribution_filename::{DistExtension, ExtensionError};
-use uv_git_types::{GitUrl, GitUrlParseError};
+use uv_git_types::{GitUrl, GitUrlParseError, HgUrl, HgUrlParseError};
 use uv_pep508::{
     looks_like_git_repository, Pep508Url, UnnamedRequirementUrl, VerbatimUrl, VerbatimUrlError,
 };
@@ -24,6 +24,8 @@ pub enum ParsedUrlError {
     InvalidFileUrl(String),
     #[error(transparent)]
     GitUrlParse(#[from] GitUrlParseError),
+    #[error(transparent)]
+    HgUrlParse(#[from] HgUrlParseError),
     #[error("Not a valid URL: `{0}`")]
     UrlParse(String, #[source] ParseError),
     #[error(transparent)]
@@ -155,6 +157,8 @@ impl Display for VerbatimParsedUrl {
 /// * The path to a file or directory (`file://`)
 /// * A Git repository (`git+https://` or `git+ssh://`), optionally with a subdirectory and/or
 ///   string to checkout.
+/// * A Mercurial repository (`hg+https://` or `hg+ssh://`), optionally with a subdirectory and/or
+///   revision to update to.
 /// * A remote archive (`https://`), optional with a subdirectory (source dist only).
 ///
 /// A URL in a requirement `foo @ <url>` must be one of the above.
@@ -166,6 +170,8 @@ pub enum ParsedUrl {
     Directory(ParsedDirectoryUrl),
     /// The direct URL is path to a Git repository.
     Git(ParsedGitUrl),
+    /// The direct URL is path to a Mercurial repository.
+    Hg(ParsedHgUrl),
     /// The direct URL is a URL to a source archive (e.g., a `.tar.gz` file) or built archive
     /// (i.e., a `.whl` file).
     Archive(ParsedArchiveUrl),
@@ -269,6 +275,41 @@ impl TryFrom<Url> for ParsedGitUrl {
     }
 }
 
+/// A Mercurial repository URL.
+///
+/// Examples:
+/// * `hg+https://hg.example.com/MyProject`
+/// * `hg+https://hg.example.com/MyProject@v1.0#egg=pkg&subdirectory=pkg_dir`
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash, Ord)]
+pub struct ParsedHgUrl {
+    pub url: HgUrl,
+    pub subdirectory: Option<PathBuf>,
+}
+
+impl ParsedHgUrl {
+    /// Construct a [`ParsedHgUrl`] from a Mercurial requirement source.
+    pub fn from_source(url: HgUrl, subdirectory: Option<PathBuf>) -> Self {
+        Self { url, subdirectory }
+    }
+}
+
+impl TryFrom<Url> for ParsedHgUrl {
+    type Error = ParsedUrlError;
+
+    /// Supports URLs with and without the `hg+` prefix.
+    fn try_from(url_in: Url) -> Result<Self, Self::Error> {
+        let subdirectory = get_subdirectory(&url_in);
+
+        let url = url_in
+            .as_str()
+            .strip_prefix("hg+")
+            .unwrap_or(url_in.as_str());
+        let url = Url::parse(url).map_err(|err| ParsedUrlError::UrlParse(url.to_string(), err))?;
+        let url = HgUrl::try_from(url)?;
+        Ok(Self { url, subdirectory })
+    }
+}
+
 /// A URL to a source or built archive.
 ///
 /// Examples:
@@ -344,11 +385,7 @@ impl TryFrom<Url> for ParsedUrl {
                     url: url.to_string(),
                     message: "Bazaar is not supported",
                 }),
-                "hg" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Mercurial is not supported",
-                }),
+                "hg" => Ok(Self::Hg(ParsedHgUrl::try_from(url)?)),
                 "svn" => Err(ParsedUrlError::UnsupportedUrlPrefix {
                     prefix: prefix.to_string(),
                     url: url.to_string(),
@@ -401,6 +438,7 @@ impl From<&ParsedUrl> for DirectUrl {
             ParsedUrl::Path(value) => Self::from(value),
             ParsedUrl::Directory(value) => Self::from(value),
             ParsedUrl::Git(value) => Self::from(value),
+            ParsedUrl::Hg(value) => Self::from(value),
             ParsedUrl::Archive(value) => Self::from(value),
         }
     }
@@ -457,12 +495,27 @@ impl From<&ParsedGitUrl> for DirectUrl {
     }
 }
 
+impl From<&ParsedHgUrl> for DirectUrl {
+    fn from(value: &ParsedHgUrl) -> Self {
+        Self::VcsUrl {
+            url: value.url.repository().to_string(),
+            vcs_info: VcsInfo {
+                vcs: VcsKind::Hg,
+                commit_id: value.url.precise().as_ref().map(ToString::to_string),
+                requested_revision: value.url.revision().map(ToString::to_string),
+            },
+            subdirectory: value.subdirectory.clone(),
+        }
+    }
+}
+
 impl From<ParsedUrl> for Url {
     fn from(value: ParsedUrl) -> Self {
         match value {
             ParsedUrl::Path(value) => value.into(),
             ParsedUrl::Directory(value) => value.into(),
             ParsedUrl::Git(value) => value.into(),
+            ParsedUrl::Hg(value) => value.into(),
             ParsedUrl::Archive(value) => value.into(),
         }
     }
@@ -501,6 +554,17 @@ impl From<ParsedGitUrl> for Url {
     }
 }
 
+impl From<ParsedHgUrl> for Url {
+    fn from(value: ParsedHgUrl) -> Self {
+        let mut url = Self::parse(&format!("{}{}", "hg+", Self::from(value.url).as_str()))
+            .expect("Mercurial URL is invalid");
+        if let Some(subdirectory) = value.subdirectory {
+            url.set_fragment(Some(&format!("subdirectory={}", subdirectory.display())));
+        }
+        url
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -533,6 +597,10 @@ mod tests {
         let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
         assert_ne!(expected, actual);
 
+        let expected = Url::parse("hg+https://hg.example.com/flask@2.0.0#subdirectory=pkg_dir")?;
+        let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
+        assert_eq!(expected, actual);
+
         Ok(())
     }
 
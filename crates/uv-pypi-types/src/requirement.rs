@@ -8,7 +8,9 @@ use url::Url;
 use uv_distribution_filename::DistExtension;
 
 use uv_fs::{relative_to, PortablePath, PortablePathBuf, CWD};
-use uv_git_types::{GitOid, GitReference, GitUrl, GitUrlParseError, OidParseError};
+use uv_git_types::{
+    GitOid, GitReference, GitUrl, GitUrlParseError, HgUrl, HgUrlParseError, OidParseError,
+};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{
@@ -16,8 +18,8 @@ use uv_pep508::{
 };
 
 use crate::{
-    ConflictItem, Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedPathUrl,
-    ParsedUrl, ParsedUrlError, VerbatimParsedUrl,
+    ConflictItem, Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedHgUrl,
+    ParsedPathUrl, ParsedUrl, ParsedUrlError, VerbatimParsedUrl,
 };
 
 #[derive(Debug, Error)]
@@ -32,6 +34,8 @@ pub enum RequirementError {
     OidParseError(#[from] OidParseError),
     #[error(transparent)]
     GitUrlParse(#[from] GitUrlParseError),
+    #[error(transparent)]
+    HgUrlParse(#[from] HgUrlParseError),
 }
 
 /// A representation of dependency on a package, an extension over a PEP 508's requirement.
@@ -195,6 +199,7 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimUrl> {
                 }
                 RequirementSource::Url { url, .. }
                 | RequirementSource::Git { url, .. }
+                | RequirementSource::Hg { url, .. }
                 | RequirementSource::Path { url, .. }
                 | RequirementSource::Directory { url, .. } => Some(VersionOrUrl::Url(url)),
             },
@@ -238,6 +243,17 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimParsedUrl> {
                     }),
                     verbatim: url,
                 })),
+                RequirementSource::Hg {
+                    hg,
+                    subdirectory,
+                    url,
+                } => Some(VersionOrUrl::Url(VerbatimParsedUrl {
+                    parsed_url: ParsedUrl::Hg(ParsedHgUrl {
+                        url: hg,
+                        subdirectory,
+                    }),
+                    verbatim: url,
+                })),
                 RequirementSource::Path {
                     install_path,
                     ext,
@@ -340,6 +356,19 @@ impl Display for Requirement {
                     writeln!(f, "#subdirectory={}", subdirectory.display())?;
                 }
             }
+            RequirementSource::Hg {
+                url: _,
+                hg,
+                subdirectory,
+            } => {
+                write!(f, " @ hg+{}", hg.repository())?;
+                if let Some(revision) = hg.revision() {
+                    write!(f, "@{revision}")?;
+                }
+                if let Some(subdirectory) = subdirectory {
+                    writeln!(f, "#subdirectory={}", subdirectory.display())?;
+                }
+            }
             RequirementSource::Path { url, .. } => {
                 write!(f, " @ {url}")?;
             }
@@ -400,6 +429,16 @@ pub enum RequirementSource {
         /// `git+<scheme>://<domain>/<path>@<rev>#subdirectory=<subdirectory>`.
         url: VerbatimUrl,
     },
+    /// A remote Mercurial repository, over either HTTPS or SSH.
+    Hg {
+        /// The repository URL and the revision to use.
+        hg: HgUrl,
+        /// The path to the source distribution if it is not in the repository root.
+        subdirectory: Option<PathBuf>,
+        /// The PEP 508 style url in the format
+        /// `hg+<scheme>://<domain>/<path>@<rev>#subdirectory=<subdirectory>`.
+        url: VerbatimUrl,
+    },
     /// A local built or source distribution, either from a path or a `file://` URL. It can either
     /// be a binary distribution (a `.whl` file) or a source distribution archive (a `.zip` or
     /// `.tar.gz` file).
@@ -448,6 +487,11 @@ impl RequirementSource {
                 url,
                 subdirectory: git.subdirectory,
             },
+            ParsedUrl::Hg(hg) => RequirementSource::Hg {
+                hg: hg.url.clone(),
+                url,
+                subdirectory: hg.subdirectory,
+            },
             ParsedUrl::Archive(archive) => RequirementSource::Url {
                 url,
                 location: archive.url,
@@ -511,6 +555,17 @@ impl RequirementSource {
                 )),
                 verbatim: url.clone(),
             }),
+            Self::Hg {
+                hg,
+                subdirectory,
+                url,
+            } => Some(VerbatimParsedUrl {
+                parsed_url: ParsedUrl::Hg(ParsedHgUrl::from_source(
+                    hg.clone(),
+                    subdirectory.clone(),
+                )),
+                verbatim: url.clone(),
+            }),
         }
     }
 
@@ -526,9 +581,11 @@ impl RequirementSource {
                     Some(VersionOrUrl::VersionSpecifier(specifier.clone()))
                 }
             }
-            Self::Url { .. } | Self::Git { .. } | Self::Path { .. } | Self::Directory { .. } => {
-                Some(VersionOrUrl::Url(self.to_verbatim_parsed_url()?))
-            }
+            Self::Url { .. }
+            | Self::Git { .. }
+            | Self::Hg { .. }
+            | Self::Path { .. }
+            | Self::Directory { .. } => Some(VersionOrUrl::Url(self.to_verbatim_parsed_url()?)),
         }
     }
 
@@ -541,9 +598,11 @@ impl RequirementSource {
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Registry { specifier, .. } => specifier.is_empty(),
-            Self::Url { .. } | Self::Git { .. } | Self::Path { .. } | Self::Directory { .. } => {
-                false
-            }
+            Self::Url { .. }
+            | Self::Git { .. }
+            | Self::Hg { .. }
+            | Self::Path { .. }
+            | Self::Directory { .. } => false,
         }
     }
 
@@ -553,6 +612,7 @@ impl RequirementSource {
             RequirementSource::Registry { specifier, .. } => Some(specifier),
             RequirementSource::Url { .. }
             | RequirementSource::Git { .. }
+            | RequirementSource::Hg { .. }
             | RequirementSource::Path { .. }
             | RequirementSource::Directory { .. } => None,
         }
@@ -563,7 +623,8 @@ impl RequirementSource {
         match self {
             RequirementSource::Registry { .. }
             | RequirementSource::Url { .. }
-            | RequirementSource::Git { .. } => Ok(self),
+            | RequirementSource::Git { .. }
+            | RequirementSource::Hg { .. } => Ok(self),
             RequirementSource::Path {
                 install_path,
                 ext,
@@ -620,6 +681,19 @@ impl Display for RequirementSource {
                     writeln!(f, "#subdirectory={}", subdirectory.display())?;
                 }
             }
+            Self::Hg {
+                url: _,
+                hg,
+                subdirectory,
+            } => {
+                write!(f, " hg+{}", hg.repository())?;
+                if let Some(revision) = hg.revision() {
+                    write!(f, "@{revision}")?;
+                }
+                if let Some(subdirectory) = subdirectory {
+                    writeln!(f, "#subdirectory={}", subdirectory.display())?;
+                }
+            }
             Self::Path { url, .. } => {
                 write!(f, "{url}")?;
             }
@@ -636,6 +710,8 @@ impl Display for RequirementSource {
 enum RequirementSourceWire {
     /// Ex) `source = { git = "<https://github.com/astral-test/uv-public-pypackage?rev=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979>" }`
     Git { git: String },
+    /// Ex) `source = { hg = "<https://hg.example.com/project?rev=1.0#0dacfd662c64cb4ceb16e6cf65a157a8b715b979>" }`
+    Hg { hg: String },
     /// Ex) `source = { url = "<https://example.org/foo-1.0.zip>" }`
     Direct {
         url: Url,
@@ -714,7 +790,7 @@ impl From<RequirementSource> for RequirementSourceWire {
                     GitReference::Branch(branch) => {
                         url.query_pairs_mut().append_pair("branch", branch.as_str());
                     }
-                    GitReference::Tag(tag) => {
+                    GitReference::Tag(tag) | GitReference::TagRange(tag) => {
                         url.query_pairs_mut().append_pair("tag", tag.as_str());
                     }
                     GitReference::BranchOrTag(rev)
@@ -734,6 +810,45 @@ impl From<RequirementSource> for RequirementSourceWire {
                     git: url.to_string(),
                 }
             }
+            RequirementSource::Hg {
+                hg,
+                subdirectory,
+                url: _,
+            } => {
+                let mut url = hg.repository().clone();
+
+                // Redact the credentials.
+                redact_credentials(&mut url);
+
+                // Clear out any existing state.
+                url.set_fragment(None);
+                url.set_query(None);
+
+                // Put the subdirectory in the query.
+                if let Some(subdirectory) = subdirectory
+                    .as_deref()
+                    .map(PortablePath::from)
+                    .as_ref()
+                    .map(PortablePath::to_string)
+                {
+                    url.query_pairs_mut()
+                        .append_pair("subdirectory", &subdirectory);
+                }
+
+                // Put the requested revision in the query.
+                if let Some(revision) = hg.revision() {
+                    url.query_pairs_mut().append_pair("rev", revision);
+                }
+
+                // Put the precise changeset in the fragment.
+                if let Some(precise) = hg.precise() {
+                    url.set_fragment(Some(&precise.to_string()));
+                }
+
+                Self::Hg {
+                    hg: url.to_string(),
+                }
+            }
             RequirementSource::Path {
                 install_path,
                 ext: _,
@@ -786,7 +901,7 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                 let mut subdirectory: Option<PortablePathBuf> = None;
                 for (key, val) in repository.query_pairs() {
                     match &*key {
-                        "tag" => reference = GitReference::Tag(val.into_owned()),
+                        "tag" => reference = GitReference::from_tag(val.into_owned()),
                         "branch" => reference = GitReference::Branch(val.into_owned()),
                         "rev" => reference = GitReference::from_rev(val.into_owned()),
                         "subdirectory" => {
@@ -821,6 +936,46 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                     url,
                 })
             }
+            RequirementSourceWire::Hg { hg } => {
+                let mut repository = Url::parse(&hg)?;
+
+                let mut revision = None;
+                let mut subdirectory: Option<PortablePathBuf> = None;
+                for (key, val) in repository.query_pairs() {
+                    match &*key {
+                        "rev" => revision = Some(val.into_owned()),
+                        "subdirectory" => {
+                            subdirectory = Some(PortablePathBuf::from(val.as_ref()));
+                        }
+                        _ => continue,
+                    };
+                }
+
+                let precise = repository.fragment().map(GitOid::from_str).transpose()?;
+
+                // Clear out any existing state.
+                repository.set_fragment(None);
+                repository.set_query(None);
+
+                // Redact the credentials.
+                redact_credentials(&mut repository);
+
+                // Create a PEP 508-compatible URL.
+                let mut url = Url::parse(&format!("hg+{repository}"))?;
+                if let Some(revision) = revision.as_deref() {
+                    url.set_path(&format!("{}@{}", url.path(), revision));
+                }
+                if let Some(subdirectory) = subdirectory.as_ref() {
+                    url.set_fragment(Some(&format!("subdirectory={subdirectory}")));
+                }
+                let url = VerbatimUrl::from_url(url);
+
+                Ok(Self::Hg {
+                    hg: HgUrl::from_fields(repository, revision, precise)?,
+                    subdirectory: subdirectory.map(PathBuf::from),
+                    url,
+                })
+            }
             RequirementSourceWire::Direct { url, subdirectory } => {
                 let location = url.clone();
 
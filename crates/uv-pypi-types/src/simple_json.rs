@@ -14,6 +14,45 @@ pub struct SimpleJson {
     /// The list of [`File`]s available for download sorted by filename.
     #[serde(deserialize_with = "sorted_simple_json_files")]
     pub files: Vec<File>,
+    /// The metadata for the response, e.g., the version of the API implemented by the index.
+    #[serde(default)]
+    pub meta: SimpleJsonMeta,
+    /// The URLs of the projects on other indexes from which the project is also available, as
+    /// specified in PEP 708.
+    #[serde(default, rename = "alternate-locations")]
+    pub alternate_locations: Vec<SmallString>,
+}
+
+/// The `meta` key of a response from the JSON-based Simple API.
+///
+/// <https://peps.python.org/pep-0691/#project-detail>
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SimpleJsonMeta {
+    /// The version of the Simple API implemented by the index (e.g., `1.1`).
+    pub api_version: Option<SmallString>,
+    /// The URLs of the projects on other indexes that this project tracks (i.e., mirrors), as
+    /// specified in PEP 708.
+    #[serde(default)]
+    pub tracks: Vec<SmallString>,
+}
+
+impl SimpleJsonMeta {
+    /// Returns `true` if the index implements PEP 700 (i.e., version 1.1 or later of the API),
+    /// which adds the available versions and the size and upload time of each file.
+    pub fn supports_pep700(&self) -> bool {
+        let Some((major, minor)) = self
+            .api_version
+            .as_deref()
+            .and_then(|version| version.split_once('.'))
+        else {
+            return false;
+        };
+        match (major.parse::<u64>(), minor.parse::<u64>()) {
+            (Ok(major), Ok(minor)) => (major, minor) >= (1, 1),
+            _ => false,
+        }
+    }
 }
 
 /// Deserializes a sequence of "simple" files from `PyPI` and ensures that they
@@ -516,7 +555,47 @@ pub enum HashError {
 
 #[cfg(test)]
 mod tests {
-    use crate::{HashError, Hashes};
+    use uv_small_str::SmallString;
+
+    use crate::{HashError, Hashes, SimpleJson};
+
+    #[test]
+    fn parse_meta() {
+        let data: SimpleJson =
+            serde_json::from_str(r#"{"meta": {"api-version": "1.1"}, "files": []}"#).unwrap();
+        assert!(data.meta.supports_pep700());
+
+        let data: SimpleJson =
+            serde_json::from_str(r#"{"meta": {"api-version": "1.0"}, "files": []}"#).unwrap();
+        assert!(!data.meta.supports_pep700());
+
+        let data: SimpleJson = serde_json::from_str(r#"{"files": []}"#).unwrap();
+        assert!(!data.meta.supports_pep700());
+    }
+
+    #[test]
+    fn parse_repository_metadata() {
+        let data: SimpleJson = serde_json::from_str(
+            r#"{
+                "meta": {"api-version": "1.2", "tracks": ["https://pypi.org/simple/flask/"]},
+                "alternate-locations": ["https://example.com/simple/flask/"],
+                "files": []
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            data.meta.tracks,
+            vec![SmallString::from("https://pypi.org/simple/flask/")]
+        );
+        assert_eq!(
+            data.alternate_locations,
+            vec![SmallString::from("https://example.com/simple/flask/")]
+        );
+
+        let data: SimpleJson = serde_json::from_str(r#"{"files": []}"#).unwrap();
+        assert!(data.meta.tracks.is_empty());
+        assert!(data.alternate_locations.is_empty());
+    }
 
     #[test]
     fn parse_hashes() -> Result<(), HashError> {
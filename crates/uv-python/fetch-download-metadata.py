@@ -7,7 +7,9 @@
 """
 Fetch Python version download metadata.
 
-Generates the `download-metadata.json` file.
+Generates the `download-metadata.json` file, and the `source-metadata.json` file with the pinned
+CPython source distributions used to build Python from source on platforms without a
+pre-built distribution.
 
 Usage:
 
@@ -45,6 +47,7 @@ Acknowledgements:
 import abc
 import argparse
 import asyncio
+import hashlib
 import itertools
 import json
 import logging
@@ -60,6 +63,7 @@ import httpx
 
 SELF_DIR = Path(__file__).parent
 VERSIONS_FILE = SELF_DIR / "download-metadata.json"
+SOURCES_FILE = SELF_DIR / "source-metadata.json"
 
 
 def batched(iterable: Iterable, n: int) -> Generator[tuple, None, None]:
@@ -526,6 +530,67 @@ class PyPyFinder(Finder):
             download.sha256 = checksums.get(download.filename)
 
 
+class CPythonSourceFinder:
+    """Find the CPython source distributions published on `python.org`.
+
+    Only the latest stable patch version of each minor version with a pre-built distribution is
+    included. The distributions are downloaded to compute their SHA-256 digests.
+    """
+
+    SOURCE_URL = "https://www.python.org/ftp/python/{version}/Python-{version}.tgz"
+
+    def __init__(self, client: httpx.AsyncClient):
+        self.client = client
+
+    async def find(self, downloads: list[PythonDownload]) -> list[dict]:
+        latest: dict[tuple[int, int], Version] = {}
+        for download in downloads:
+            if download.implementation != ImplementationName.CPYTHON:
+                continue
+            version = download.version
+            if version.prerelease:
+                continue
+            key = (version.major, version.minor)
+            if key not in latest or version > latest[key]:
+                latest[key] = version
+
+        sources = []
+        for version in sorted(latest.values(), reverse=True):
+            url = self.SOURCE_URL.format(version=version)
+            sha256 = await self._fetch_sha256(url)
+            if sha256 is None:
+                continue
+            logging.info("Selected source distribution %s", url)
+            sources.append(
+                {
+                    "major": version.major,
+                    "minor": version.minor,
+                    "patch": version.patch,
+                    "url": url,
+                    "sha256": sha256,
+                }
+            )
+        return sources
+
+    async def _fetch_sha256(self, url: str) -> str | None:
+        hasher = hashlib.sha256()
+        async with self.client.stream("GET", url) as resp:
+            if resp.status_code == 404:
+                logging.warning("No source distribution found at %s", url)
+                return None
+            resp.raise_for_status()
+            async for chunk in resp.aiter_bytes():
+                hasher.update(chunk)
+        return hasher.hexdigest()
+
+
+def render_sources(sources: list[dict]) -> None:
+    """Render `source-metadata.json`."""
+    SOURCES_FILE.parent.mkdir(parents=True, exist_ok=True)
+    # Make newlines consistent across platforms
+    SOURCES_FILE.write_text(json.dumps(sources, indent=2) + "\n", newline="\n")
+
+
 def render(downloads: list[PythonDownload]) -> None:
     """Render `download-metadata.json`."""
 
@@ -616,7 +681,11 @@ async def find() -> None:
             logging.info("Finding %s downloads...", finder.implementation)
             downloads.extend(await finder.find())
 
+        logging.info("Finding CPython source distributions...")
+        sources = await CPythonSourceFinder(client).find(downloads)
+
     render(downloads)
+    render_sources(sources)
 
 
 def main() -> None:
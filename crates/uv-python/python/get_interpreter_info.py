@@ -99,6 +99,15 @@ def running_under_virtualenv() -> bool:
     return _running_under_venv() or _running_under_legacy_virtualenv()
 
 
+def is_conda_environment() -> bool:
+    """True if the interpreter belongs to a conda environment (including the base environment).
+
+    Conda tracks its installed packages in a `conda-meta` directory at the root of each
+    environment.
+    """
+    return os.path.isdir(os.path.join(sys.prefix, "conda-meta"))
+
+
 def get_major_minor_version() -> str:
     """
     Return the major-minor version of the current Python as a string, e.g.
@@ -225,7 +234,7 @@ def get_virtualenv():
         }
 
 
-def get_scheme(use_sysconfig_scheme: bool):
+def get_scheme(use_sysconfig_scheme: bool, conda: bool):
     """Return the Scheme for the current interpreter.
 
     The paths returned should be absolute.
@@ -296,7 +305,14 @@ def get_scheme(use_sysconfig_scheme: bool):
             * Just the OS name, used by CPython on Windows (``nt``).
 
             If none of the above works, fall back to ``posix_prefix``.
+
+            Conda environments always use the plain prefix scheme, as conda lays out every
+            environment (including the base environment) like a standard prefix installation,
+            regardless of any scheme preferred by the interpreter's build (e.g., a framework
+            scheme on macOS).
             """
+            if conda:
+                return "nt" if os.name == "nt" else "posix_prefix"
             if _PREFERRED_SCHEME_API:
                 return _PREFERRED_SCHEME_API("prefix")
             if _should_use_osx_framework_prefix():
@@ -395,6 +411,57 @@ def get_scheme(use_sysconfig_scheme: bool):
         return get_distutils_scheme()
 
 
+def get_user_scheme():
+    """Return the Scheme for `--user` installations, along with the default user base directory.
+
+    The paths in the scheme are relative to the user base directory, such that uv can respect
+    `PYTHONUSERBASE` without re-querying the interpreter.
+
+    Returns `None` if the interpreter doesn't define a user scheme.
+
+    This is based on pip's path discovery logic:
+        https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/locations/_sysconfig.py#L124
+    """
+    import site
+
+    _AVAILABLE_SCHEMES = set(sysconfig.get_scheme_names())
+
+    _PREFERRED_SCHEME_API = getattr(sysconfig, "get_preferred_scheme", None)
+
+    if _PREFERRED_SCHEME_API:
+        scheme_name = _PREFERRED_SCHEME_API("user")
+    elif sys.platform == "darwin" and sysconfig.get_config_var("PYTHONFRAMEWORK"):
+        scheme_name = "osx_framework_user"
+    else:
+        scheme_name = f"{os.name}_user"
+
+    if scheme_name not in _AVAILABLE_SCHEMES:
+        return None
+
+    # Resolve the default user base, ignoring any `PYTHONUSERBASE` override, which is applied by
+    # uv at install time.
+    os.environ.pop("PYTHONUSERBASE", None)
+    user_base = site.getuserbase()
+    if not user_base:
+        return None
+
+    placeholder = os.path.join(os.path.abspath(os.sep), "userbase")
+    paths = sysconfig.get_paths(scheme=scheme_name, vars={"userbase": placeholder})
+
+    return {
+        "base": user_base,
+        "scheme": {
+            "platlib": os.path.relpath(paths["platlib"], placeholder),
+            "purelib": os.path.relpath(paths["purelib"], placeholder),
+            "include": os.path.relpath(
+                paths.get("include", os.path.join(placeholder, "include")), placeholder
+            ),
+            "scripts": os.path.relpath(paths["scripts"], placeholder),
+            "data": os.path.relpath(paths["data"], placeholder),
+        },
+    }
+
+
 def get_operating_system_and_architecture():
     """Determine the Python interpreter architecture and operating system.
 
@@ -564,6 +631,13 @@ def main() -> None:
         getattr(sysconfig, "_PIP_USE_SYSCONFIG", sys.version_info >= (3, 10))
     )
 
+    # Conda patches `sysconfig` to match its environment layout, while `distutils` (if present at
+    # all) may be shadowed by setuptools' `_distutils` or affected by `pydistutils.cfg` files, and
+    # place scripts and data files outside the environment. Always use `sysconfig` for conda.
+    conda = is_conda_environment()
+    if conda:
+        use_sysconfig_scheme = True
+
     # If we're not using sysconfig, make sure distutils is available.
     if not use_sysconfig_scheme:
         try:
@@ -614,8 +688,10 @@ def main() -> None:
         # "/install" as the prefix. With `sysconfig` patching, we rewrite the prefix to match the actual installation
         # location. So in newer versions, we also write a dedicated flag to indicate standalone builds.
         "standalone": sysconfig.get_config_var("prefix") == "/install" or bool(sysconfig.get_config_var("PYTHON_BUILD_STANDALONE")),
-        "scheme": get_scheme(use_sysconfig_scheme),
+        "scheme": get_scheme(use_sysconfig_scheme, conda),
+        "conda": conda,
         "virtualenv": get_virtualenv(),
+        "user": get_user_scheme(),
         "platform": os_and_arch,
         "manylinux_compatible": manylinux_compatible,
         # The `t` abiflag for freethreading Python.
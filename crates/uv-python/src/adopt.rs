@@ -0,0 +1,140 @@
+//! Finding the Python installations of other version managers, i.e., pyenv and asdf, to adopt
+//! them as managed Python installations.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+/// The name of the file in which the origin of an adopted installation is recorded, in the
+/// installation directory.
+pub const ADOPTED_RECORD: &str = "uv-adopted.json";
+
+/// A version manager whose Python installations can be adopted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VersionManager {
+    Pyenv,
+    Asdf,
+}
+
+impl VersionManager {
+    /// The directory in which the version manager stores its Python installations, if any.
+    ///
+    /// Respects `PYENV_ROOT` and `ASDF_DATA_DIR`, respectively, and defaults to `~/.pyenv` and
+    /// `~/.asdf`.
+    fn versions_dir(self) -> Option<PathBuf> {
+        let home = || {
+            std::env::var_os(EnvVars::HOME)
+                .filter(|home| !home.is_empty())
+                .map(PathBuf::from)
+        };
+        match self {
+            Self::Pyenv => std::env::var_os(EnvVars::PYENV_ROOT)
+                .filter(|root| !root.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".pyenv")))
+                .map(|root| root.join("versions")),
+            Self::Asdf => std::env::var_os(EnvVars::ASDF_DATA_DIR)
+                .filter(|root| !root.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".asdf")))
+                .map(|root| root.join("installs").join("python")),
+        }
+    }
+}
+
+impl Display for VersionManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+        }
+    }
+}
+
+/// A Python installation of another version manager.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalInstallation {
+    /// The version manager that installed the Python installation.
+    manager: VersionManager,
+    /// The installation prefix, e.g., `~/.pyenv/versions/3.12.4`.
+    prefix: PathBuf,
+}
+
+impl ExternalInstallation {
+    /// Read the origin of an adopted installation from its installation directory, if any.
+    pub fn read(installation: &Path) -> Option<Self> {
+        let path = installation.join(ADOPTED_RECORD);
+        let contents = fs_err::read_to_string(&path).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(external) => Some(external),
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid adoption record at `{}`: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Record the origin of an adopted installation in its installation directory.
+    pub(crate) fn write(&self, installation: &Path) -> Result<(), std::io::Error> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs_err::write(installation.join(ADOPTED_RECORD), contents)
+    }
+
+    pub fn manager(&self) -> VersionManager {
+        self.manager
+    }
+
+    pub fn prefix(&self) -> &Path {
+        &self.prefix
+    }
+
+    /// The path to the installation's `python3` executable.
+    pub fn executable(&self) -> PathBuf {
+        self.prefix.join("bin").join("python3")
+    }
+}
+
+impl Display for ExternalInstallation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.prefix.user_display(), self.manager)
+    }
+}
+
+/// Find the Python installations of pyenv and asdf.
+///
+/// Aliases (i.e., links to other installations) and virtual environments (e.g., as created by
+/// `pyenv-virtualenv`) are skipped.
+pub fn find_external_installations() -> Vec<ExternalInstallation> {
+    [VersionManager::Pyenv, VersionManager::Asdf]
+        .into_iter()
+        .filter_map(|manager| Some((manager, manager.versions_dir()?)))
+        .flat_map(|(manager, versions_dir)| {
+            let Ok(entries) = fs_err::read_dir(&versions_dir) else {
+                debug!(
+                    "No {manager} installations found at `{}`",
+                    versions_dir.user_display()
+                );
+                return Vec::new();
+            };
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+                .map(|entry| entry.path())
+                .filter(|prefix| !prefix.join("pyvenv.cfg").is_file())
+                .map(|prefix| ExternalInstallation { manager, prefix })
+                .filter(|external| external.executable().is_file())
+                .sorted_by(|a, b| a.prefix.cmp(&b.prefix))
+                .collect()
+        })
+        .collect()
+}
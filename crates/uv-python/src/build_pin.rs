@@ -0,0 +1,248 @@
+//! Pinning the exact builds of the Python versions used by a project, such that
+//! `uv python install` reproduces the same binaries, rather than the latest build of each version.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+use crate::downloads::{ManagedPythonDownload, PythonDownloadRequest};
+use crate::installation::PythonInstallationKey;
+use crate::managed::ManagedPythonInstallation;
+
+/// The file name for Python build pins.
+pub static PYTHON_BUILD_FILENAME: &str = ".python-build.json";
+
+/// The name of the file in which the build of an installation is recorded, in the installation
+/// directory.
+pub const BUILD_RECORD: &str = "uv-build.json";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse Python build pins at: {}", _0.user_display())]
+    Parse(PathBuf, #[source] serde_json::Error),
+}
+
+/// The exact build of a Python distribution, i.e., its archive and the checksum of the archive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PythonBuild {
+    key: PythonInstallationKey,
+    /// The release of the build, e.g., `20250317` for `python-build-standalone`, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    build: Option<String>,
+    /// The URL of the archive, without any mirror applied.
+    url: String,
+    sha256: String,
+}
+
+impl PythonBuild {
+    /// Create the [`PythonBuild`] of a download, given the SHA-256 digest of its archive.
+    pub(crate) fn new(download: &ManagedPythonDownload, sha256: String) -> Self {
+        Self {
+            key: download.key().clone(),
+            build: download.build().map(ToString::to_string),
+            url: download.url().to_string(),
+            sha256,
+        }
+    }
+
+    /// Read the build recorded in an installation directory, if any.
+    ///
+    /// Installations are recorded when they're downloaded, so adopted installations, source
+    /// builds, and installations downloaded by older versions of uv have no record.
+    pub fn read(installation: &Path) -> Option<Self> {
+        let path = installation.join(BUILD_RECORD);
+        let contents = fs_err::read_to_string(&path).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(build) => Some(build),
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid build record at `{}`: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Record the build in an installation directory.
+    pub(crate) fn write(&self, installation: &Path) -> Result<(), io::Error> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs_err::write(installation.join(BUILD_RECORD), contents)
+    }
+
+    pub fn key(&self) -> &PythonInstallationKey {
+        &self.key
+    }
+
+    pub fn build(&self) -> Option<&str> {
+        self.build.as_deref()
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn sha256(&self) -> &str {
+        &self.sha256
+    }
+
+    /// Returns `true` if the installation is of this build.
+    pub fn matches_installation(&self, installation: &ManagedPythonInstallation) -> bool {
+        *installation.key() == self.key
+            && installation
+                .build()
+                .is_some_and(|build| build.sha256.eq_ignore_ascii_case(&self.sha256))
+    }
+}
+
+/// A `.python-build.json` file, pinning the exact builds of the Python versions of a project.
+///
+/// Builds are pinned per platform, i.e., by installation key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonBuildFile {
+    /// The path to the build file.
+    #[serde(skip)]
+    path: PathBuf,
+    /// The pinned builds, from the newest version to the oldest.
+    builds: Vec<PythonBuild>,
+}
+
+impl PythonBuildFile {
+    /// Find a build file in the given directory or any of its parents.
+    pub async fn discover(working_directory: &Path) -> Result<Option<Self>, Error> {
+        let Some(path) = working_directory
+            .ancestors()
+            .map(|directory| directory.join(PYTHON_BUILD_FILENAME))
+            .find(|path| path.is_file())
+        else {
+            return Ok(None);
+        };
+        Self::try_from_path(path).await
+    }
+
+    /// Try to read a build file at the given path.
+    ///
+    /// If the file does not exist, `Ok(None)` is returned.
+    pub async fn try_from_path(path: PathBuf) -> Result<Option<Self>, Error> {
+        let content = match fs_err::tokio::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        debug!("Reading Python build pins from `{}`", path.user_display());
+        let mut file: Self =
+            serde_json::from_str(&content).map_err(|err| Error::Parse(path.clone(), err))?;
+        file.path = path;
+        file.builds.sort_by(|a, b| b.key.cmp(&a.key));
+        Ok(Some(file))
+    }
+
+    /// Create a new representation of a build file at the given path.
+    ///
+    /// The file will not be created; see [`PythonBuildFile::write`].
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            builds: Vec::new(),
+        }
+    }
+
+    /// Return the path to the build file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Iterate over the pinned builds.
+    pub fn builds(&self) -> impl Iterator<Item = &PythonBuild> {
+        self.builds.iter()
+    }
+
+    /// Return the newest pinned build that satisfies a download request, if any.
+    pub fn find(&self, request: &PythonDownloadRequest) -> Option<&PythonBuild> {
+        self.builds
+            .iter()
+            .find(|build| request.satisfied_by_key(&build.key))
+    }
+
+    /// Pin a build, replacing any existing pin for the same installation key.
+    pub fn insert(&mut self, build: PythonBuild) {
+        self.builds.retain(|existing| existing.key != build.key);
+        self.builds.push(build);
+        self.builds.sort_by(|a, b| b.key.cmp(&a.key));
+    }
+
+    /// Write the build file.
+    pub async fn write(&self) -> Result<(), io::Error> {
+        debug!(
+            "Writing Python build pins to `{}`",
+            self.path.user_display()
+        );
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs_err::tokio::write(&self.path, contents).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn build(key: &str, sha256: &str) -> PythonBuild {
+        PythonBuild {
+            key: PythonInstallationKey::from_str(key).unwrap(),
+            build: Some("20250317".to_string()),
+            url: format!("https://example.com/{key}.tar.gz"),
+            sha256: sha256.to_string(),
+        }
+    }
+
+    #[test]
+    fn insert_and_find() {
+        let mut file = PythonBuildFile::new(PathBuf::from(PYTHON_BUILD_FILENAME));
+        file.insert(build("cpython-3.12.8-linux-x86_64-gnu", "a"));
+        file.insert(build("cpython-3.12.9-linux-x86_64-gnu", "b"));
+        file.insert(build("cpython-3.12.9-macos-aarch64-none", "c"));
+        file.insert(build("cpython-3.12.9-linux-x86_64-gnu", "d"));
+        assert_eq!(file.builds().count(), 3);
+
+        // The newest pinned build for the platform is selected.
+        let request = PythonDownloadRequest::from_str("cpython-3.12-linux-x86_64-gnu").unwrap();
+        assert_eq!(file.find(&request).map(PythonBuild::sha256), Some("d"));
+
+        let request = PythonDownloadRequest::from_str("cpython-3.12.8-linux-x86_64-gnu").unwrap();
+        assert_eq!(file.find(&request).map(PythonBuild::sha256), Some("a"));
+
+        let request = PythonDownloadRequest::from_str("cpython-3.13-linux-x86_64-gnu").unwrap();
+        assert_eq!(file.find(&request), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut file = PythonBuildFile::new(PathBuf::from(PYTHON_BUILD_FILENAME));
+        file.insert(build("cpython-3.12.9-linux-x86_64-gnu", "b"));
+        let contents = serde_json::to_string_pretty(&file).unwrap();
+        insta::assert_snapshot!(contents, @r###"
+        {
+          "builds": [
+            {
+              "key": "cpython-3.12.9-linux-x86_64-gnu",
+              "build": "20250317",
+              "url": "https://example.com/cpython-3.12.9-linux-x86_64-gnu.tar.gz",
+              "sha256": "b"
+            }
+          ]
+        }
+        "###);
+        let parsed: PythonBuildFile = serde_json::from_str(&contents).unwrap();
+        assert_eq!(parsed.builds, file.builds);
+    }
+}
@@ -307,9 +307,10 @@ fn python_executables_from_installed<'a>(
     version: &'a VersionRequest,
     implementation: Option<&'a ImplementationName>,
     preference: PythonPreference,
+    install_dir: Option<&'a Path>,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     let from_managed_installations = iter::once_with(move || {
-        ManagedPythonInstallations::from_settings(None)
+        ManagedPythonInstallations::from_settings(install_dir.map(Path::to_path_buf))
             .map_err(Error::from)
             .and_then(|installed_installations| {
                 debug!(
@@ -420,6 +421,7 @@ fn python_executables<'a>(
     implementation: Option<&'a ImplementationName>,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    install_dir: Option<&'a Path>,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     // Always read from `UV_INTERNAL__PARENT_INTERPRETER` — it could be a system interpreter
     let from_parent_interpreter = iter::once_with(|| {
@@ -439,7 +441,8 @@ fn python_executables<'a>(
     .flatten();
 
     let from_virtual_environments = python_executables_from_virtual_environments();
-    let from_installed = python_executables_from_installed(version, implementation, preference);
+    let from_installed =
+        python_executables_from_installed(version, implementation, preference, install_dir);
 
     // Limit the search to the relevant environment preference; this avoids unnecessary work like
     // traversal of the file system. Subsequent filtering should be done by the caller with
@@ -614,17 +617,23 @@ fn python_interpreters<'a>(
     implementation: Option<&'a ImplementationName>,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    install_dir: Option<&'a Path>,
     cache: &'a Cache,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     python_interpreters_from_executables(
         // Perform filtering on the discovered executables based on their source. This avoids
         // unnecessary interpreter queries, which are generally expensive. We'll filter again
         // with `interpreter_satisfies_environment_preference` after querying.
-        python_executables(version, implementation, environments, preference).filter_ok(
-            move |(source, path)| {
-                source_satisfies_environment_preference(*source, path, environments)
-            },
-        ),
+        python_executables(
+            version,
+            implementation,
+            environments,
+            preference,
+            install_dir,
+        )
+        .filter_ok(move |(source, path)| {
+            source_satisfies_environment_preference(*source, path, environments)
+        }),
         cache,
     )
     .filter_ok(move |(source, interpreter)| {
@@ -861,6 +870,7 @@ pub fn find_python_installations<'a>(
     request: &'a PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    install_dir: Option<&'a Path>,
     cache: &'a Cache,
 ) -> Box<dyn Iterator<Item = Result<FindPythonResult, Error>> + 'a> {
     let sources = DiscoveryPreferences {
@@ -942,8 +952,15 @@ pub fn find_python_installations<'a>(
         }
         PythonRequest::Any => Box::new({
             debug!("Searching for any Python interpreter in {sources}");
-            python_interpreters(&VersionRequest::Any, None, environments, preference, cache)
-                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+            python_interpreters(
+                &VersionRequest::Any,
+                None,
+                environments,
+                preference,
+                install_dir,
+                cache,
+            )
+            .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
         PythonRequest::Default => Box::new({
             debug!("Searching for default Python interpreter in {sources}");
@@ -952,6 +969,7 @@ pub fn find_python_installations<'a>(
                 None,
                 environments,
                 preference,
+                install_dir,
                 cache,
             )
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
@@ -962,7 +980,7 @@ pub fn find_python_installations<'a>(
             };
             Box::new({
                 debug!("Searching for {request} in {sources}");
-                python_interpreters(version, None, environments, preference, cache)
+                python_interpreters(version, None, environments, preference, install_dir, cache)
                     .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
@@ -973,6 +991,7 @@ pub fn find_python_installations<'a>(
                 Some(implementation),
                 environments,
                 preference,
+                install_dir,
                 cache,
             )
             .filter_ok(|(_source, interpreter)| {
@@ -993,6 +1012,7 @@ pub fn find_python_installations<'a>(
                     Some(implementation),
                     environments,
                     preference,
+                    install_dir,
                     cache,
                 )
                 .filter_ok(|(_source, interpreter)| {
@@ -1016,6 +1036,7 @@ pub fn find_python_installations<'a>(
                     request.implementation(),
                     environments,
                     preference,
+                    install_dir,
                     cache,
                 )
                 .filter_ok(|(_source, interpreter)| request.satisfied_by_interpreter(interpreter))
@@ -1033,9 +1054,11 @@ pub(crate) fn find_python_installation(
     request: &PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    install_dir: Option<&Path>,
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
-    let installations = find_python_installations(request, environments, preference, cache);
+    let installations =
+        find_python_installations(request, environments, preference, install_dir, cache);
     let mut first_prerelease = None;
     let mut first_error = None;
     for result in installations {
@@ -1130,13 +1153,14 @@ pub fn find_best_python_installation(
     request: &PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    install_dir: Option<&Path>,
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
     debug!("Starting Python discovery for {}", request);
 
     // First, check for an exact match (or the first available version if no Python version was provided)
     debug!("Looking for exact match for request {request}");
-    let result = find_python_installation(request, environments, preference, cache);
+    let result = find_python_installation(request, environments, preference, install_dir, cache);
     match result {
         Ok(Ok(installation)) => {
             warn_on_unsupported_python(installation.interpreter());
@@ -1164,7 +1188,8 @@ pub fn find_best_python_installation(
         _ => None,
     } {
         debug!("Looking for relaxed patch version {request}");
-        let result = find_python_installation(&request, environments, preference, cache);
+        let result =
+            find_python_installation(&request, environments, preference, install_dir, cache);
         match result {
             Ok(Ok(installation)) => {
                 warn_on_unsupported_python(installation.interpreter());
@@ -1181,14 +1206,16 @@ pub fn find_best_python_installation(
     debug!("Looking for a default Python installation");
     let request = PythonRequest::Default;
     Ok(
-        find_python_installation(&request, environments, preference, cache)?.map_err(|err| {
-            // Use a more general error in this case since we looked for multiple versions
-            PythonNotFound {
-                request,
-                python_preference: err.python_preference,
-                environment_preference: err.environment_preference,
-            }
-        }),
+        find_python_installation(&request, environments, preference, install_dir, cache)?.map_err(
+            |err| {
+                // Use a more general error in this case since we looked for multiple versions
+                PythonNotFound {
+                    request,
+                    python_preference: err.python_preference,
+                    environment_preference: err.environment_preference,
+                }
+            },
+        ),
     )
 }
 
@@ -152,6 +152,7 @@ impl PythonEnvironment {
             preference,
             // Ignore managed installations when looking for environments
             PythonPreference::OnlySystem,
+            None,
             cache,
         )? {
             Ok(installation) => installation,
@@ -252,6 +253,16 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Create a [`PythonEnvironment`] from an existing [`Interpreter`] and its user site
+    /// directory (i.e., `--user`).
+    pub fn with_user(self) -> std::io::Result<Self> {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Ok(Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.with_user()?,
+            ..inner
+        })))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -314,6 +325,14 @@ impl PythonEnvironment {
         } else if let Some(prefix) = self.0.interpreter.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
             LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+        } else if let Some(user) = self.0.interpreter.user() {
+            // If we're installing into the user site directory, use a lockfile that's specific to
+            // the user base, which may be shared across interpreters.
+            LockedFile::acquire(
+                env::temp_dir().join(format!("uv-{}.lock", cache_digest(user.root()))),
+                user.root().user_display(),
+            )
+            .await
         } else if self.0.interpreter.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
             LockedFile::acquire(self.0.root.join(".lock"), self.0.root.user_display()).await
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::path::Path;
 use std::str::FromStr;
 
 use tracing::{debug, info};
@@ -10,10 +11,13 @@ use uv_pep440::{Prerelease, Version};
 use crate::discovery::{
     find_best_python_installation, find_python_installation, EnvironmentPreference, PythonRequest,
 };
-use crate::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest, Reporter};
+use crate::downloads::{
+    with_download_settings, DownloadResult, ManagedPythonDownload, PythonDownloadRequest, Reporter,
+};
 use crate::implementation::LenientImplementationName;
 use crate::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
 use crate::platform::{Arch, Libc, Os};
+use crate::policy::PythonPolicy;
 use crate::{
     downloads, Error, ImplementationName, Interpreter, PythonDownloads, PythonPreference,
     PythonSource, PythonVariant, PythonVersion,
@@ -53,9 +57,11 @@ impl PythonInstallation {
         request: &PythonRequest,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        install_dir: Option<&Path>,
         cache: &Cache,
     ) -> Result<Self, Error> {
-        let installation = find_python_installation(request, environments, preference, cache)??;
+        let installation =
+            find_python_installation(request, environments, preference, install_dir, cache)??;
         Ok(installation)
     }
 
@@ -65,12 +71,14 @@ impl PythonInstallation {
         request: &PythonRequest,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        install_dir: Option<&Path>,
         cache: &Cache,
     ) -> Result<Self, Error> {
         Ok(find_best_python_installation(
             request,
             environments,
             preference,
+            install_dir,
             cache,
         )??)
     }
@@ -82,17 +90,20 @@ impl PythonInstallation {
         request: Option<&PythonRequest>,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        install_dir: Option<&Path>,
         python_downloads: PythonDownloads,
+        policy: Option<&PythonPolicy>,
         client_builder: &BaseClientBuilder<'_>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        python_downloads_json_url: Option<&str>,
     ) -> Result<Self, Error> {
         let request = request.unwrap_or(&PythonRequest::Default);
 
         // Search for the installation
-        let err = match Self::find(request, environments, preference, cache) {
+        let err = match Self::find(request, environments, preference, install_dir, cache) {
             Ok(installation) => return Ok(installation),
             Err(err) => err,
         };
@@ -122,11 +133,14 @@ impl PythonInstallation {
         debug!("Requested Python not found, checking for available download...");
         match Self::fetch(
             request.fill()?,
+            install_dir,
+            policy,
             client_builder,
             cache,
             reporter,
             python_install_mirror,
             pypy_install_mirror,
+            python_downloads_json_url,
         )
         .await
         {
@@ -138,22 +152,42 @@ impl PythonInstallation {
         }
     }
 
-    /// Download and install the requested installation.
+    /// Download and install the requested installation into the given directory or, if not
+    /// provided, the default directory for managed Python installations.
+    ///
+    /// If a system policy is provided, only downloads allowed by the policy are considered, and
+    /// the mirrors required by the policy take precedence over the given mirrors.
     pub async fn fetch(
         request: PythonDownloadRequest,
+        install_dir: Option<&Path>,
+        policy: Option<&PythonPolicy>,
         client_builder: &BaseClientBuilder<'_>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        python_downloads_json_url: Option<&str>,
     ) -> Result<Self, Error> {
-        let installations = ManagedPythonInstallations::from_settings(None)?.init()?;
+        let installations =
+            ManagedPythonInstallations::from_settings(install_dir.map(Path::to_path_buf))?
+                .init()?;
         let installations_dir = installations.root();
         let scratch_dir = installations.scratch();
         let _lock = installations.lock().await?;
 
-        let download = ManagedPythonDownload::from_request(&request)?;
-        let client = client_builder.build();
+        let download =
+            ManagedPythonDownload::from_request(&request, policy, python_downloads_json_url)?;
+        let (python_install_mirror, pypy_install_mirror) = PythonPolicy::restrict_install_mirrors(
+            policy,
+            python_install_mirror,
+            pypy_install_mirror,
+        );
+        let client = with_download_settings(
+            client_builder.clone(),
+            python_install_mirror,
+            pypy_install_mirror,
+        )
+        .build();
 
         info!("Fetching requested Python...");
         let result = download
@@ -173,10 +207,11 @@ impl PythonInstallation {
             DownloadResult::Fetched(path) => path,
         };
 
-        let installed = ManagedPythonInstallation::new(path, download);
+        let installed = ManagedPythonInstallation::new(path, &download);
         installed.ensure_externally_managed()?;
         installed.ensure_sysconfig_patched()?;
         installed.ensure_canonical_executables()?;
+        installed.ensure_minor_version_link()?;
         if let Err(e) = installed.ensure_dylib_patched() {
             e.warn_user(&installed);
         }
@@ -468,6 +503,19 @@ impl FromStr for PythonInstallationKey {
     }
 }
 
+impl serde::Serialize for PythonInstallationKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PythonInstallationKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&key).map_err(serde::de::Error::custom)
+    }
+}
+
 impl PartialOrd for PythonInstallationKey {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
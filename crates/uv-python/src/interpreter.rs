@@ -29,7 +29,7 @@ use crate::implementation::LenientImplementationName;
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
 use crate::{
-    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, VersionRequest,
+    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, User, VersionRequest,
     VirtualEnvironment,
 };
 
@@ -49,9 +49,12 @@ pub struct Interpreter {
     sys_path: Vec<PathBuf>,
     stdlib: PathBuf,
     standalone: bool,
+    conda: bool,
     tags: OnceLock<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user_site: Option<User>,
+    user: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
 }
@@ -83,9 +86,12 @@ impl Interpreter {
             sys_path: info.sys_path,
             stdlib: info.stdlib,
             standalone: info.standalone,
+            conda: info.conda,
             tags: OnceLock::new(),
             target: None,
             prefix: None,
+            user_site: info.user,
+            user: false,
         })
     }
 
@@ -99,6 +105,7 @@ impl Interpreter {
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
+            user: false,
             ..self
         }
     }
@@ -121,6 +128,23 @@ impl Interpreter {
         })
     }
 
+    /// Return a new [`Interpreter`] to install into the user site directory (i.e., `--user`).
+    pub fn with_user(self) -> io::Result<Self> {
+        let Some(user_site) = self.user_site else {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "The interpreter does not define a user installation scheme",
+            ));
+        };
+        let user_site = user_site.with_env();
+        user_site.init()?;
+        Ok(Self {
+            user_site: Some(user_site),
+            user: true,
+            ..self
+        })
+    }
+
     /// Return the base Python executable; that is, the Python executable that should be
     /// considered the "base" for the virtual environment. This is typically the Python executable
     /// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
@@ -261,6 +285,11 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
+    /// Returns `true` if the environment is a `--user` environment.
+    pub fn is_user(&self) -> bool {
+        self.user
+    }
+
     /// Returns `Some` if the environment is externally managed, optionally including an error
     /// message from the `EXTERNALLY-MANAGED` file.
     ///
@@ -462,6 +491,11 @@ impl Interpreter {
         self.prefix.as_ref()
     }
 
+    /// Return the user site directory for this interpreter, if installing with `--user`.
+    pub fn user(&self) -> Option<&User> {
+        self.user_site.as_ref().filter(|_| self.user)
+    }
+
     /// Returns `true` if an [`Interpreter`] may be a `python-build-standalone` interpreter.
     ///
     /// This method may return false positives, but it should not return false negatives. In other
@@ -474,6 +508,12 @@ impl Interpreter {
         self.standalone
     }
 
+    /// Returns `true` if the [`Interpreter`] belongs to a conda environment (including the base
+    /// environment), in which case its scheme is always derived from `sysconfig`.
+    pub fn is_conda(&self) -> bool {
+        self.conda
+    }
+
     /// Return the [`Layout`] environment used to install wheels into this interpreter.
     pub fn layout(&self) -> Layout {
         Layout {
@@ -484,6 +524,8 @@ impl Interpreter {
                 target.scheme()
             } else if let Some(prefix) = self.prefix.as_ref() {
                 prefix.scheme(&self.virtualenv)
+            } else if let Some(user) = self.user() {
+                user.scheme()
             } else {
                 Scheme {
                     purelib: self.purelib().to_path_buf(),
@@ -520,7 +562,9 @@ impl Interpreter {
             .prefix()
             .map(|prefix| prefix.site_packages(self.virtualenv()));
 
-        let interpreter = if target.is_none() && prefix.is_none() {
+        let user = self.user().map(User::site_packages);
+
+        let interpreter = if target.is_none() && prefix.is_none() && user.is_none() {
             let purelib = self.purelib();
             let platlib = self.platlib();
             Some(std::iter::once(purelib).chain(
@@ -539,6 +583,7 @@ impl Interpreter {
             .flatten()
             .map(Cow::Borrowed)
             .chain(prefix.into_iter().flatten().map(Cow::Owned))
+            .chain(user.into_iter().flatten().map(Cow::Owned))
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
     }
 
@@ -728,6 +773,8 @@ struct InterpreterInfo {
     sys_path: Vec<PathBuf>,
     stdlib: PathBuf,
     standalone: bool,
+    conda: bool,
+    user: Option<User>,
     pointer_size: PointerSize,
     gil_disabled: bool,
 }
@@ -872,10 +919,11 @@ impl InterpreterInfo {
                 }
             })?;
 
-        // Read from the cache.
-        if cache
-            .freshness(&cache_entry, None)
-            .is_ok_and(Freshness::is_fresh)
+        // Read from the cache, unless the user opted out (e.g., with `--no-discovery-cache`).
+        if cache.discovery_cache()
+            && cache
+                .freshness(&cache_entry, None)
+                .is_ok_and(Freshness::is_fresh)
         {
             if let Ok(data) = fs::read(cache_entry.path()) {
                 match rmp_serde::from_slice::<CachedByTimestamp<Self>>(&data) {
@@ -1050,6 +1098,8 @@ mod tests {
             },
             "manylinux_compatible": false,
             "standalone": false,
+            "conda": false,
+            "user": null,
             "markers": {
                 "implementation_name": "cpython",
                 "implementation_version": "3.12.0",
@@ -16,12 +16,15 @@ pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
 pub use crate::target::Target;
+pub use crate::user::User;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
     PythonVersionFile, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
+pub mod adopt;
+pub mod build_pin;
 mod cpuinfo;
 mod discovery;
 pub mod downloads;
@@ -36,10 +39,13 @@ pub mod managed;
 mod microsoft_store;
 pub mod platform;
 mod pointer_size;
+pub mod policy;
 mod prefix;
 mod python_version;
+pub mod source_build;
 mod sysconfig;
 mod target;
+mod user;
 mod version_files;
 mod virtualenv;
 #[cfg(windows)]
@@ -231,6 +237,8 @@ mod tests {
                     },
                     "manylinux_compatible": true,
                     "standalone": true,
+                    "conda": false,
+                    "user": null,
                     "markers": {
                         "implementation_name": "{IMPLEMENTATION}",
                         "implementation_version": "{FULL_VERSION}",
@@ -444,6 +452,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         });
@@ -458,6 +467,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         });
@@ -482,6 +492,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         });
@@ -503,6 +514,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         })??;
@@ -564,6 +576,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         })??;
@@ -595,6 +608,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         });
@@ -631,6 +645,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                None,
                 &context.cache,
             )
         })??;
@@ -662,6 +677,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -683,6 +699,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -708,6 +725,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -733,6 +751,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -755,6 +774,7 @@ mod tests {
                 &PythonRequest::parse("3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -788,6 +808,7 @@ mod tests {
                 &PythonRequest::parse("3.11.2"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -821,6 +842,7 @@ mod tests {
                 &PythonRequest::parse("3.9"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -842,6 +864,7 @@ mod tests {
                 &PythonRequest::parse("3.11.9"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -863,6 +886,7 @@ mod tests {
                 &PythonRequest::parse("3.11.3"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -896,6 +920,7 @@ mod tests {
                 &PythonRequest::parse("3.11.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -932,6 +957,7 @@ mod tests {
                     &PythonRequest::parse("3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -962,6 +988,7 @@ mod tests {
                     &PythonRequest::parse("3.10.2"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -996,6 +1023,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1021,6 +1049,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1047,6 +1076,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1071,6 +1101,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1092,6 +1123,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1114,6 +1146,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1146,6 +1179,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1166,6 +1200,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1192,6 +1227,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1209,6 +1245,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1237,6 +1274,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1274,6 +1312,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1301,6 +1340,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1325,6 +1365,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1349,6 +1390,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1373,6 +1415,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1410,6 +1453,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1437,6 +1481,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1453,6 +1498,7 @@ mod tests {
                     &PythonRequest::parse("3.12"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1469,6 +1515,7 @@ mod tests {
                     &PythonRequest::parse("3.12.3"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })?;
@@ -1490,6 +1537,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1506,6 +1554,7 @@ mod tests {
                     &PythonRequest::parse("3.12.3"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1527,6 +1576,7 @@ mod tests {
                 &PythonRequest::parse("foobar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1541,6 +1591,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1554,6 +1605,7 @@ mod tests {
                 &PythonRequest::parse("3.10.0"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1582,6 +1634,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1597,6 +1650,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1626,6 +1680,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1641,6 +1696,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1656,6 +1712,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1671,6 +1728,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1694,6 +1752,7 @@ mod tests {
                 &PythonRequest::parse("../foo/.venv"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1708,6 +1767,7 @@ mod tests {
                 &PythonRequest::parse(venv.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1731,6 +1791,7 @@ mod tests {
                 &PythonRequest::parse(context.tempdir.child("bar").to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1750,6 +1811,7 @@ mod tests {
                     &PythonRequest::parse(venv.to_str().unwrap()),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             },
@@ -1778,6 +1840,7 @@ mod tests {
                 &PythonRequest::parse("../proj/.venv"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1799,6 +1862,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1828,6 +1892,7 @@ mod tests {
                 &PythonRequest::parse("bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1843,6 +1908,7 @@ mod tests {
                 &PythonRequest::parse("bar"),
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1869,6 +1935,7 @@ mod tests {
                     &PythonRequest::parse("bar"),
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -1893,6 +1960,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -1909,6 +1977,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1923,6 +1992,7 @@ mod tests {
                 &PythonRequest::parse("pypy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1948,6 +2018,7 @@ mod tests {
                 &PythonRequest::parse("pypy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1962,6 +2033,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -1987,6 +2059,7 @@ mod tests {
                 &PythonRequest::parse("pypy3.10"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2013,6 +2086,7 @@ mod tests {
                 &PythonRequest::parse("pypy@3.10"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2039,6 +2113,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2065,6 +2140,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2091,6 +2167,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2118,6 +2195,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })?;
@@ -2139,6 +2217,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2153,6 +2232,7 @@ mod tests {
                 &PythonRequest::parse("graalpy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2178,6 +2258,7 @@ mod tests {
                 &PythonRequest::parse("graalpy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2192,6 +2273,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2229,6 +2311,7 @@ mod tests {
                     &PythonRequest::parse("pypy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2246,6 +2329,7 @@ mod tests {
                     &PythonRequest::parse("pypy"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2287,6 +2371,7 @@ mod tests {
                     &PythonRequest::parse("pypy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2304,6 +2389,7 @@ mod tests {
                     &PythonRequest::parse("default"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2340,6 +2426,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2362,6 +2449,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2384,6 +2472,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })
@@ -2422,6 +2511,7 @@ mod tests {
                 &PythonRequest::parse("3.13t"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -2474,6 +2564,7 @@ mod tests {
                 &PythonRequest::parse("3.13"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
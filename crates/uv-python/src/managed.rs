@@ -1,4 +1,5 @@
 use core::fmt;
+use std::borrow::Cow;
 use std::cmp::Reverse;
 use std::ffi::OsStr;
 use std::io::{self, Write};
@@ -16,7 +17,9 @@ use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{windows_python_launcher, Launcher};
 
-use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
+use crate::adopt::{ExternalInstallation, ADOPTED_RECORD};
+use crate::build_pin::PythonBuild;
+use crate::downloads::{Error as DownloadError, ManagedPythonDownload, PythonDownloadRequest};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -24,8 +27,12 @@ use crate::installation::{self, PythonInstallationKey};
 use crate::libc::LibcDetectionError;
 use crate::platform::Error as PlatformError;
 use crate::platform::{Arch, Libc, Os};
+use crate::policy::PythonPolicy;
 use crate::python_version::PythonVersion;
-use crate::{macos_dylib, sysconfig, PythonRequest, PythonVariant};
+use crate::source_build::{PythonSourceBuild, SOURCE_BUILD_RECORD};
+use crate::{
+    macos_dylib, sysconfig, PyVenvConfiguration, PythonRequest, PythonVariant, VersionRequest,
+};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -65,6 +72,13 @@ pub enum Error {
         #[source]
         err: io::Error,
     },
+    #[error("Failed to link {} to {}", to.user_display(), from.user_display())]
+    MinorVersionLink {
+        from: PathBuf,
+        to: PathBuf,
+        #[source]
+        err: io::Error,
+    },
     #[error("Failed to create directory for Python executable link at {}", to.user_display())]
     ExecutableDirectory {
         to: PathBuf,
@@ -91,7 +105,17 @@ pub enum Error {
     LibcDetection(#[from] LibcDetectionError),
     #[error(transparent)]
     MacOsDylib(#[from] macos_dylib::Error),
+    #[error("Adopting Python installations is not supported on Windows")]
+    AdoptUnsupportedPlatform,
+    #[error("Failed to move {} to {}", from.user_display(), to.user_display())]
+    Relocate {
+        from: PathBuf,
+        to: PathBuf,
+        #[source]
+        err: io::Error,
+    },
 }
+
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ManagedPythonInstallations {
@@ -113,7 +137,8 @@ impl ManagedPythonInstallations {
 
     /// Prefer, in order:
     ///
-    /// 1. The specific Python directory passed via the `install_dir` argument.
+    /// 1. The specific Python directory passed via the `install_dir` argument (e.g., from the
+    ///    `python-install-dir` setting).
     /// 2. The specific Python directory specified with the `UV_PYTHON_INSTALL_DIR` environment variable.
     /// 3. A directory in the system-appropriate user-level data directory, e.g., `~/.local/uv/python`.
     /// 4. A directory in the local data directory, e.g., `./.uv/python`.
@@ -246,7 +271,7 @@ impl ManagedPythonInstallations {
         let arch = Arch::from_env();
         let libc = Libc::from_env()?;
 
-        let iter = ManagedPythonInstallations::from_settings(None)?
+        let installations = self
             .find_all()?
             .filter(move |installation| {
                 installation.key.os == os
@@ -254,10 +279,14 @@ impl ManagedPythonInstallations {
                         // TODO(zanieb): Allow inequal variants, as `Arch::supports` does not
                         // implement this yet. See https://github.com/astral-sh/uv/pull/9788
                         || arch.family == installation.key.arch.family)
-                    && installation.key.libc == libc
-            });
+                    // The musl builds are statically linked, and so are usable on any Linux host.
+                    && (installation.key.libc == libc || installation.key.libc.is_musl())
+            })
+            // Prefer native installations over emulated ones, e.g., x86-64 installations on
+            // ARM64 macOS.
+            .sorted_by_key(|installation| installation.key.arch.family != arch.family);
 
-        Ok(iter)
+        Ok(installations)
     }
 
     /// Iterate over managed Python installations that satisfy the requested version on this platform.
@@ -281,6 +310,72 @@ impl ManagedPythonInstallations {
             }))
     }
 
+    /// Point the minor version link of each installed minor version at its latest installed patch
+    /// version (see [`ManagedPythonInstallation::minor_version_link`]).
+    pub fn ensure_minor_version_links(&self) -> Result<(), Error> {
+        for installation in self
+            .find_all()?
+            .filter(|installation| installation.key.prerelease.is_none())
+            .unique_by(ManagedPythonInstallation::minor_version_link)
+        {
+            installation.ensure_minor_version_link()?;
+        }
+        Ok(())
+    }
+
+    /// Adopt a Python installation of another version manager as a managed installation with
+    /// the given key, replacing any existing installation with the same key.
+    ///
+    /// The managed installation links to the executable of the external installation, which
+    /// remains owned by the other version manager.
+    pub fn adopt(
+        &self,
+        key: PythonInstallationKey,
+        external: &ExternalInstallation,
+    ) -> Result<ManagedPythonInstallation, Error> {
+        if cfg!(windows) {
+            return Err(Error::AdoptUnsupportedPlatform);
+        }
+
+        let installation = ManagedPythonInstallation {
+            path: self.root.join(key.to_string()),
+            key,
+            url: None,
+            sha256: None,
+        };
+
+        let executable = installation.executable(false);
+        let source = external.prefix().join("bin").join(
+            executable
+                .file_name()
+                .expect("Python executable should have a file name"),
+        );
+        if !source.is_file() {
+            return Err(Error::MissingExecutable(source));
+        }
+
+        if installation.path.is_dir() {
+            debug!(
+                "Removing existing directory: {}",
+                installation.path.user_display()
+            );
+            fs::remove_dir_all(&installation.path)?;
+        }
+        let bin = executable.parent().ok_or(Error::NoExecutableDirectory)?;
+        fs::create_dir_all(bin).map_err(|err| Error::ExecutableDirectory {
+            to: bin.to_path_buf(),
+            err,
+        })?;
+        symlink_or_copy_file(&source, &executable).map_err(|err| Error::LinkExecutable {
+            from: source.clone(),
+            to: executable.clone(),
+            err,
+        })?;
+        external.write(&installation.path)?;
+
+        Ok(installation)
+    }
+
     pub fn root(&self) -> &Path {
         &self.root
     }
@@ -300,11 +395,11 @@ pub struct ManagedPythonInstallation {
     /// The URL with the Python archive.
     ///
     /// Empty when self was constructed from a path.
-    url: Option<&'static str>,
+    url: Option<Cow<'static, str>>,
     /// The SHA256 of the Python archive at the URL.
     ///
     /// Empty when self was constructed from a path.
-    sha256: Option<&'static str>,
+    sha256: Option<Cow<'static, str>>,
 }
 
 impl ManagedPythonInstallation {
@@ -312,8 +407,19 @@ impl ManagedPythonInstallation {
         Self {
             path,
             key: download.key().clone(),
-            url: Some(download.url()),
-            sha256: download.sha256(),
+            url: Some(Cow::Owned(download.url().to_string())),
+            sha256: download
+                .sha256()
+                .map(|sha256| Cow::Owned(sha256.to_string())),
+        }
+    }
+
+    pub fn from_source_build(path: PathBuf, build: &PythonSourceBuild) -> Self {
+        Self {
+            path,
+            key: build.key().clone(),
+            url: Some(Cow::Borrowed(build.url())),
+            sha256: Some(Cow::Borrowed(build.sha256())),
         }
     }
 
@@ -504,9 +610,112 @@ impl ManagedPythonInstallation {
 
     /// Ensure the environment is marked as externally managed with the
     /// standard `EXTERNALLY-MANAGED` file.
+    ///
+    /// Adopted installations are left as-is.
     pub fn ensure_externally_managed(&self) -> Result<(), Error> {
-        // Construct the path to the `stdlib` directory.
-        let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
+        if self.is_adopted() {
+            return Ok(());
+        }
+
+        let file = self.stdlib().join("EXTERNALLY-MANAGED");
+        fs_err::write(file, EXTERNALLY_MANAGED)?;
+
+        Ok(())
+    }
+
+    /// Ensure that the components that aren't needed to run Python applications are removed,
+    /// i.e., the test suite and Tk (`tkinter`, IDLE, and `turtle`), as in
+    /// `uv python install --variant minimal`. If `pip` is `false`, pip and `ensurepip` are removed
+    /// too.
+    ///
+    /// The removed components aren't restored until the installation is reinstalled. Adopted
+    /// installations are left as-is.
+    pub fn ensure_minimal(&self, pip: bool) -> Result<(), Error> {
+        if self.is_adopted() {
+            return Ok(());
+        }
+
+        let python_dir = self.python_dir();
+        let stdlib = self.stdlib();
+        let windows = matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows));
+
+        let mut paths = vec![
+            stdlib.join("test"),
+            stdlib.join("idlelib"),
+            stdlib.join("tkinter"),
+            stdlib.join("turtle.py"),
+            stdlib.join("turtledemo"),
+            // Prior to Python 3.12, some packages include their tests.
+            stdlib.join("ctypes").join("test"),
+            stdlib.join("distutils").join("tests"),
+            stdlib.join("lib2to3").join("tests"),
+            stdlib.join("sqlite3").join("test"),
+            stdlib.join("unittest").join("test"),
+        ];
+
+        // The Tcl/Tk libraries, e.g., `lib/tcl8.6` and `lib/tk8.6`, or `tcl` and `DLLs/tk86t.dll`
+        // on Windows.
+        let is_tk = |path: &PathBuf| {
+            path.file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| {
+                    ["tcl", "tk", "itcl", "thread", "Tix", "_tkinter"]
+                        .iter()
+                        .any(|prefix| {
+                            name.strip_prefix(prefix).is_some_and(|rest| {
+                                rest.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+                            })
+                        })
+                })
+        };
+        if windows {
+            paths.push(python_dir.join("tcl"));
+            paths.extend(uv_fs::files(python_dir.join("DLLs"))?.filter(is_tk));
+        } else {
+            paths.extend(uv_fs::directories(python_dir.join("lib"))?.filter(is_tk));
+        }
+
+        // The IDLE scripts, e.g., `bin/idle3`.
+        let scripts = python_dir.join(if windows { "Scripts" } else { "bin" });
+        paths.extend(uv_fs::entries(&scripts)?.filter(|path| {
+            path.file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| name.starts_with("idle"))
+        }));
+
+        if !pip {
+            paths.push(stdlib.join("ensurepip"));
+            let site_packages = stdlib.join("site-packages");
+            paths.extend(uv_fs::entries(&site_packages)?.filter(|path| {
+                path.file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|name| name == "pip" || name.starts_with("pip-"))
+            }));
+            paths.extend(uv_fs::entries(&scripts)?.filter(|path| {
+                path.file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|name| name.starts_with("pip"))
+            }));
+        }
+
+        for path in paths {
+            let Ok(metadata) = path.symlink_metadata() else {
+                continue;
+            };
+            debug!("Removing `{}`", path.user_display());
+            if metadata.is_dir() {
+                fs::remove_dir_all(&path)?;
+            } else {
+                fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The path to the standard library of the installation.
+    fn stdlib(&self) -> PathBuf {
+        if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
         } else {
             let lib_suffix = self.key.variant.suffix();
@@ -519,16 +728,16 @@ impl ManagedPythonInstallation {
                 format!("python{}{lib_suffix}", self.key.version().python_version())
             };
             self.python_dir().join("lib").join(python)
-        };
-
-        let file = stdlib.join("EXTERNALLY-MANAGED");
-        fs_err::write(file, EXTERNALLY_MANAGED)?;
-
-        Ok(())
+        }
     }
 
     /// Ensure that the `sysconfig` data is patched to match the installation path.
+    ///
+    /// Adopted installations are left as-is.
     pub fn ensure_sysconfig_patched(&self) -> Result<(), Error> {
+        if self.is_adopted() {
+            return Ok(());
+        }
         if cfg!(unix) {
             if *self.implementation() == ImplementationName::CPython {
                 sysconfig::update_sysconfig(
@@ -549,6 +758,9 @@ impl ManagedPythonInstallation {
     ///
     /// See <https://github.com/astral-sh/uv/issues/10598> for more information.
     pub fn ensure_dylib_patched(&self) -> Result<(), macos_dylib::Error> {
+        if self.is_adopted() {
+            return Ok(());
+        }
         if cfg!(target_os = "macos") {
             if self.key().os.is_like_darwin() {
                 if *self.implementation() == ImplementationName::CPython {
@@ -659,15 +871,206 @@ impl ManagedPythonInstallation {
         self.key.patch != other.key.patch
     }
 
-    pub fn url(&self) -> Option<&'static str> {
-        self.url
+    /// The path of the link to the latest installed patch version of this installation's minor
+    /// version, e.g., `cpython-3.12-linux-x86_64-gnu` for `cpython-3.12.4-linux-x86_64-gnu`.
+    ///
+    /// Virtual environments refer to managed installations through this link, such that they use
+    /// the latest patch version once it's installed (e.g., with `uv python upgrade`).
+    pub fn minor_version_link(&self) -> PathBuf {
+        let variant = match self.key.variant {
+            PythonVariant::Default => String::new(),
+            PythonVariant::Freethreaded => format!("+{}", self.key.variant),
+        };
+        self.path.with_file_name(format!(
+            "{}-{}.{}{}-{}-{}-{}",
+            self.key.implementation,
+            self.key.major,
+            self.key.minor,
+            variant,
+            self.key.os,
+            self.key.arch,
+            self.key.libc
+        ))
+    }
+
+    /// Point the minor version link at this installation, unless it already points at the same or
+    /// a later patch version.
+    ///
+    /// Pre-release versions are never linked.
+    pub fn ensure_minor_version_link(&self) -> Result<(), Error> {
+        if self.key.prerelease.is_some() {
+            return Ok(());
+        }
+
+        let link = self.minor_version_link();
+        if let Some(current) = fs::canonicalize(&link)
+            .ok()
+            .and_then(|path| ManagedPythonInstallation::from_path(path).ok())
+        {
+            if !self.is_upgrade_of(&current) {
+                return Ok(());
+            }
+        }
+
+        debug!(
+            "Linking `{}` to `{}`",
+            link.user_display(),
+            self.path.user_display()
+        );
+        uv_fs::replace_symlink(&self.path, &link).map_err(|err| Error::MinorVersionLink {
+            from: self.path.clone(),
+            to: link,
+            err,
+        })
+    }
+
+    /// Return the latest available download of this installation's minor version, if it's a later
+    /// patch version than this installation.
+    pub fn find_upgrade(
+        &self,
+        policy: Option<&PythonPolicy>,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<Option<ManagedPythonDownload>, Error> {
+        let request = PythonDownloadRequest::from(self)
+            .with_version(VersionRequest::MajorMinor(
+                self.key.major,
+                self.key.minor,
+                self.key.variant,
+            ))
+            .with_prereleases(false);
+        match ManagedPythonDownload::from_request(&request, policy, python_downloads_json_url) {
+            Ok(download) => Ok((download.key().patch > self.key.patch).then_some(download)),
+            Err(DownloadError::NoDownloadFound(_)) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns `true` if the virtual environment at `venv` was created from this installation,
+    /// i.e., if the `home` directory recorded in its `pyvenv.cfg` resolves into the installation.
+    pub fn is_base_of_virtualenv(&self, venv: &Path) -> bool {
+        let Ok(cfg) = PyVenvConfiguration::parse(venv.join("pyvenv.cfg")) else {
+            return false;
+        };
+        let Some(home) = cfg.home().and_then(|home| home.simple_canonicalize().ok()) else {
+            return false;
+        };
+        self.path
+            .simple_canonicalize()
+            .is_ok_and(|path| home.starts_with(path))
+    }
+
+    /// Remove the minor version link, if it points at this (removed) installation.
+    pub fn remove_minor_version_link(&self) -> Result<(), Error> {
+        let link = self.minor_version_link();
+        if link.symlink_metadata().is_ok() && !link.exists() {
+            debug!("Removing dangling link `{}`", link.user_display());
+            uv_fs::remove_symlink(&link)?;
+        }
+        Ok(())
     }
 
-    pub fn sha256(&self) -> Option<&'static str> {
-        self.sha256
+    /// Returns `true` if this installation was built from source (see [`PythonSourceBuild`]).
+    ///
+    /// Installations built from source aren't relocatable.
+    pub fn is_source_build(&self) -> bool {
+        self.path.join(SOURCE_BUILD_RECORD).is_file()
+    }
+
+    /// Move this installation into the installation directory at `root`, e.g., with
+    /// `uv python move`.
+    ///
+    /// If the installation can't be renamed (e.g., if `root` is on another file system), it's
+    /// copied and then removed. Links within the installation are preserved, such that adopted
+    /// installations continue to link to the executables of the other version manager. The
+    /// `sysconfig` data is updated to match the new location.
+    pub fn relocate(&self, root: &Path) -> Result<Self, Error> {
+        let path = root.join(
+            self.path
+                .file_name()
+                .ok_or(Error::NameError("name is empty".to_string()))?,
+        );
+        let relocate_error = |err| Error::Relocate {
+            from: self.path.clone(),
+            to: path.clone(),
+            err,
+        };
+
+        if let Err(err) = fs::rename(&self.path, &path) {
+            debug!(
+                "Failed to rename `{}`, copying instead: {err}",
+                self.path.user_display()
+            );
+            copy_dir_preserving_links(&self.path, &path).map_err(relocate_error)?;
+            fs::remove_dir_all(&self.path).map_err(relocate_error)?;
+        }
+
+        let installation = Self {
+            path,
+            key: self.key.clone(),
+            url: self.url.clone(),
+            sha256: self.sha256.clone(),
+        };
+        if cfg!(unix)
+            && *installation.implementation() == ImplementationName::CPython
+            && !installation.is_adopted()
+        {
+            sysconfig::relocate_sysconfig(
+                installation.path(),
+                &self.path,
+                installation.key.major,
+                installation.key.minor,
+                installation.key.variant.suffix(),
+            )?;
+        }
+        Ok(installation)
+    }
+
+    /// The installation of another version manager that this installation was adopted from, if
+    /// any (see [`ManagedPythonInstallations::adopt`]).
+    pub fn adopted_from(&self) -> Option<ExternalInstallation> {
+        ExternalInstallation::read(&self.path)
+    }
+
+    /// The build that this installation was downloaded from, if recorded (see [`PythonBuild`]).
+    pub fn build(&self) -> Option<PythonBuild> {
+        PythonBuild::read(&self.path)
+    }
+
+    /// Returns `true` if this installation was adopted from another version manager.
+    pub fn is_adopted(&self) -> bool {
+        self.path.join(ADOPTED_RECORD).is_file()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn sha256(&self) -> Option<&str> {
+        self.sha256.as_deref()
     }
 }
 
+/// Recursively copy a directory, recreating links rather than copying their targets.
+fn copy_dir_preserving_links(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            #[cfg(unix)]
+            fs_err::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+            #[cfg(windows)]
+            fs::copy(entry.path(), &target)?;
+        } else if file_type.is_dir() {
+            copy_dir_preserving_links(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
 // TODO(zanieb): Only used in tests now.
 /// Generate a platform portion of a key from the environment.
 pub fn platform_key_from_env() -> Result<String, Error> {
@@ -690,6 +1093,22 @@ impl fmt::Display for ManagedPythonInstallation {
     }
 }
 
+/// Return the path to an executable of a managed Python installation through the installation's
+/// minor version link, if the link points at the installation.
+///
+/// For example, `cpython-3.12-linux-x86_64-gnu/bin/python3.12` for
+/// `cpython-3.12.4-linux-x86_64-gnu/bin/python3.12`.
+pub fn minor_version_link_executable(executable: &Path) -> Option<PathBuf> {
+    executable.ancestors().skip(1).find_map(|ancestor| {
+        let installation = ManagedPythonInstallation::from_path(ancestor.to_path_buf()).ok()?;
+        let link = installation.minor_version_link();
+        if !is_same_file(&link, ancestor).unwrap_or(false) {
+            return None;
+        }
+        Some(link.join(executable.strip_prefix(ancestor).ok()?))
+    })
+}
+
 /// Find the directory to install Python executables into.
 pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
@@ -115,8 +115,11 @@ impl Arch {
 
         // TODO: Implement `variant` support checks
 
-        // Windows ARM64 runs emulated x86_64 binaries transparently
-        if cfg!(windows) && matches!(self.family, target_lexicon::Architecture::Aarch64(_)) {
+        // Windows ARM64 runs emulated x86_64 binaries transparently, as does macOS ARM64 with
+        // Rosetta 2
+        if (cfg!(windows) || cfg!(target_os = "macos"))
+            && matches!(self.family, target_lexicon::Architecture::Aarch64(_))
+        {
             return other.family == target_lexicon::Architecture::X86_64;
         }
 
@@ -0,0 +1,201 @@
+//! A system-level policy for managed Python downloads, e.g., as configured by an organization.
+//!
+//! The policy takes precedence over the user's settings, including command-line arguments and
+//! environment variables. It's read once, alongside the global settings, and passed to each
+//! operation that selects or fetches a managed Python download.
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::implementation::{ImplementationName, LenientImplementationName};
+use crate::installation::PythonInstallationKey;
+use crate::{PythonDownloads, VersionRequest};
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid Python version in `allowed-versions`: `{0}`")]
+    InvalidVersion(String),
+    #[error("Invalid Python implementation in `allowed-implementations`: `{0}`")]
+    InvalidImplementation(String),
+}
+
+/// A policy restricting the managed Python downloads, as read from a `python-policy.toml` file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "PythonPolicyWire")]
+pub struct PythonPolicy {
+    /// The Python versions that may be downloaded. If empty, all versions are allowed.
+    allowed_versions: Vec<VersionRequest>,
+    /// The Python implementations that may be downloaded. If empty, all implementations are
+    /// allowed.
+    allowed_implementations: Vec<ImplementationName>,
+    /// The most permissive setting for automatic Python downloads.
+    python_downloads: Option<PythonDownloads>,
+    /// The mirror that CPython distributions must be downloaded from.
+    python_install_mirror: Option<String>,
+    /// The mirror that PyPy distributions must be downloaded from.
+    pypy_install_mirror: Option<String>,
+}
+
+impl PythonPolicy {
+    /// Whether the policy allows downloading the Python installation with the given key.
+    pub fn allows(&self, key: &PythonInstallationKey) -> bool {
+        if !self.allowed_implementations.is_empty()
+            && !self.allowed_implementations.iter().any(|implementation| {
+                key.implementation == LenientImplementationName::Known(*implementation)
+            })
+        {
+            return false;
+        }
+        if !self.allowed_versions.is_empty()
+            && !self.allowed_versions.iter().any(|version| {
+                version.matches_major_minor_patch_prerelease(
+                    key.major,
+                    key.minor,
+                    key.patch,
+                    key.prerelease,
+                )
+            })
+        {
+            return false;
+        }
+        true
+    }
+
+    /// Restrict a setting for automatic Python downloads to the policy, i.e., return the stricter
+    /// of the setting and the policy.
+    pub fn restrict_python_downloads(&self, python_downloads: PythonDownloads) -> PythonDownloads {
+        match (self.python_downloads, python_downloads) {
+            (Some(PythonDownloads::Never), _) | (_, PythonDownloads::Never) => {
+                PythonDownloads::Never
+            }
+            (Some(PythonDownloads::Manual), _) | (_, PythonDownloads::Manual) => {
+                PythonDownloads::Manual
+            }
+            (None | Some(PythonDownloads::Automatic), PythonDownloads::Automatic) => {
+                PythonDownloads::Automatic
+            }
+        }
+    }
+
+    /// The mirror that CPython distributions must be downloaded from, if any.
+    pub fn python_install_mirror(&self) -> Option<&str> {
+        self.python_install_mirror.as_deref()
+    }
+
+    /// The mirror that PyPy distributions must be downloaded from, if any.
+    pub fn pypy_install_mirror(&self) -> Option<&str> {
+        self.pypy_install_mirror.as_deref()
+    }
+
+    /// Return the CPython and PyPy mirrors to download from, i.e., the mirrors required by the
+    /// policy, if any, in place of those from the user's settings.
+    pub fn restrict_install_mirrors<'a>(
+        policy: Option<&'a Self>,
+        python_install_mirror: Option<&'a str>,
+        pypy_install_mirror: Option<&'a str>,
+    ) -> (Option<&'a str>, Option<&'a str>) {
+        (
+            policy
+                .and_then(Self::python_install_mirror)
+                .or(python_install_mirror),
+            policy
+                .and_then(Self::pypy_install_mirror)
+                .or(pypy_install_mirror),
+        )
+    }
+}
+
+/// The `python-policy.toml` file, as written.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct PythonPolicyWire {
+    #[serde(default)]
+    allowed_versions: Vec<String>,
+    #[serde(default)]
+    allowed_implementations: Vec<String>,
+    python_downloads: Option<PythonDownloads>,
+    python_install_mirror: Option<String>,
+    pypy_install_mirror: Option<String>,
+}
+
+impl TryFrom<PythonPolicyWire> for PythonPolicy {
+    type Error = Error;
+
+    fn try_from(wire: PythonPolicyWire) -> Result<Self, Self::Error> {
+        let allowed_versions = wire
+            .allowed_versions
+            .into_iter()
+            .map(|version| {
+                VersionRequest::from_str(&version).map_err(|_| Error::InvalidVersion(version))
+            })
+            .collect::<Result<_, _>>()?;
+        let allowed_implementations = wire
+            .allowed_implementations
+            .into_iter()
+            .map(|implementation| {
+                ImplementationName::from_str(&implementation)
+                    .map_err(|_| Error::InvalidImplementation(implementation))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            allowed_versions,
+            allowed_implementations,
+            python_downloads: wire.python_downloads,
+            python_install_mirror: wire.python_install_mirror,
+            pypy_install_mirror: wire.pypy_install_mirror,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::installation::PythonInstallationKey;
+    use crate::PythonDownloads;
+
+    use super::PythonPolicy;
+
+    fn policy(json: &str) -> PythonPolicy {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn allows() {
+        let key = |key: &str| PythonInstallationKey::from_str(key).unwrap();
+
+        let policy = policy(
+            r#"{"allowed-versions": ["3.11", "3.12"], "allowed-implementations": ["cpython"]}"#,
+        );
+        assert!(policy.allows(&key("cpython-3.12.9-linux-x86_64-gnu")));
+        assert!(policy.allows(&key("cpython-3.11.4-macos-aarch64-none")));
+        assert!(!policy.allows(&key("cpython-3.13.2-linux-x86_64-gnu")));
+        assert!(!policy.allows(&key("pypy-3.11.11-linux-x86_64-gnu")));
+
+        // An empty policy allows everything.
+        let policy = PythonPolicy::default();
+        assert!(policy.allows(&key("pypy-3.11.11-linux-x86_64-gnu")));
+    }
+
+    #[test]
+    fn restrict_python_downloads() {
+        let policy = policy(r#"{"python-downloads": "manual"}"#);
+        assert_eq!(
+            policy.restrict_python_downloads(PythonDownloads::Automatic),
+            PythonDownloads::Manual
+        );
+        assert_eq!(
+            policy.restrict_python_downloads(PythonDownloads::Never),
+            PythonDownloads::Never
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let err =
+            serde_json::from_str::<PythonPolicy>(r#"{"allowed-versions": ["foo"]}"#).unwrap_err();
+        assert!(err.to_string().contains("allowed-versions"), "{err}");
+    }
+}
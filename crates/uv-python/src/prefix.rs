@@ -20,8 +20,14 @@ impl Prefix {
     }
 
     /// Return an iterator over the `site-packages` directories inside the environment.
+    ///
+    /// Includes both the `purelib` and `platlib` directories, if they differ (e.g., `lib` and
+    /// `lib64` on some Linux distributions).
     pub fn site_packages(&self, virtualenv: &Scheme) -> impl Iterator<Item = PathBuf> {
-        std::iter::once(self.0.join(&virtualenv.purelib))
+        let purelib = self.0.join(&virtualenv.purelib);
+        let platlib = self.0.join(&virtualenv.platlib);
+        let platlib = (platlib != purelib).then_some(platlib);
+        std::iter::once(purelib).chain(platlib)
     }
 
     /// Initialize the `--prefix` directory.
@@ -0,0 +1,363 @@
+//! Building CPython from source, for platforms without a pre-built distribution.
+
+use std::fmt::Display;
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+
+use crate::downloads::{self, download_archive, DownloadResult, PythonDownloadRequest, Reporter};
+use crate::implementation::{ImplementationName, LenientImplementationName};
+use crate::installation::PythonInstallationKey;
+use crate::policy::PythonPolicy;
+use crate::PythonVariant;
+
+/// The pinned CPython source distributions, as generated by `fetch-download-metadata.py`.
+const SOURCE_METADATA: &str = include_str!("../source-metadata.json");
+
+/// The name of the file in which the configuration of a source build is recorded, in the
+/// installation directory.
+pub const SOURCE_BUILD_RECORD: &str = "uv-source-build.json";
+
+/// The arguments passed to `./configure`, in addition to `--prefix`, unless a previous build of
+/// the same version recorded others.
+const DEFAULT_CONFIGURE_ARGS: &[&str] = &["--with-ensurepip=install"];
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Download(#[from] downloads::Error),
+    #[error("Building Python from source is not supported on Windows")]
+    UnsupportedPlatform,
+    #[error("Failed to run `{command}` to build `{key}` from source")]
+    Spawn {
+        key: PythonInstallationKey,
+        command: String,
+        #[source]
+        err: io::Error,
+    },
+    #[error("Failed to build `{key}` from source: `{command}` failed with {status}\n\n--- stderr:\n{stderr}\n---")]
+    Build {
+        key: PythonInstallationKey,
+        command: String,
+        status: ExitStatus,
+        stderr: String,
+    },
+    #[error("Failed to record the source build configuration at: {}", path.user_display())]
+    Record {
+        path: PathBuf,
+        #[source]
+        err: serde_json::Error,
+    },
+}
+
+/// A pinned CPython source distribution, as published on `python.org`.
+#[derive(Debug, Deserialize)]
+struct SourceDistribution {
+    major: u8,
+    minor: u8,
+    patch: u8,
+    url: String,
+    sha256: String,
+}
+
+/// The configuration of a source build, recorded in the installation directory such that a
+/// rebuild of the same version (e.g., with `--reinstall`) uses the same configuration.
+#[derive(Debug, Serialize, Deserialize)]
+struct SourceBuildRecord {
+    url: String,
+    sha256: String,
+    configure_args: Vec<String>,
+}
+
+impl SourceBuildRecord {
+    /// Read the record of a previous build from an installation directory, if any.
+    fn read(installation: &Path) -> Option<Self> {
+        let path = installation.join(SOURCE_BUILD_RECORD);
+        let contents = fs_err::read_to_string(&path).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(record) => Some(record),
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid source build record at `{}`: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Write the record to an installation directory.
+    fn write(&self, installation: &Path) -> Result<(), Error> {
+        let path = installation.join(SOURCE_BUILD_RECORD);
+        let contents = serde_json::to_string_pretty(self).map_err(|err| Error::Record {
+            path: path.clone(),
+            err,
+        })?;
+        fs_err::write(&path, contents)?;
+        Ok(())
+    }
+}
+
+/// Return the pinned CPython source distributions.
+fn source_distributions() -> &'static [SourceDistribution] {
+    static SOURCE_DISTRIBUTIONS: OnceLock<Vec<SourceDistribution>> = OnceLock::new();
+    SOURCE_DISTRIBUTIONS.get_or_init(|| {
+        serde_json::from_str::<Vec<SourceDistribution>>(SOURCE_METADATA)
+            .expect("`source-metadata.json` should be valid")
+    })
+}
+
+/// A CPython version to build from a pinned source distribution, for the host platform.
+#[derive(Debug, Clone)]
+pub struct PythonSourceBuild {
+    key: PythonInstallationKey,
+    url: &'static str,
+    sha256: &'static str,
+}
+
+impl PythonSourceBuild {
+    /// Return the latest pinned source distribution satisfying a request, if any.
+    ///
+    /// Only the default variant of CPython can be built from source. The request must include
+    /// the target platform (see [`PythonDownloadRequest::fill`]).
+    ///
+    /// Source distributions are downloaded from `python.org`, so they're unavailable if the system
+    /// policy requires a mirror for CPython distributions.
+    pub fn from_request(
+        request: &PythonDownloadRequest,
+        policy: Option<&PythonPolicy>,
+    ) -> Option<Self> {
+        let (Some(os), Some(arch), Some(libc)) = (request.os(), request.arch(), request.libc())
+        else {
+            return None;
+        };
+        if policy.is_some_and(|policy| policy.python_install_mirror().is_some()) {
+            return None;
+        }
+        source_distributions()
+            .iter()
+            .map(|distribution| Self {
+                key: PythonInstallationKey::new(
+                    LenientImplementationName::Known(ImplementationName::CPython),
+                    distribution.major,
+                    distribution.minor,
+                    distribution.patch,
+                    None,
+                    *os,
+                    *arch,
+                    *libc,
+                    PythonVariant::Default,
+                ),
+                url: distribution.url.as_str(),
+                sha256: distribution.sha256.as_str(),
+            })
+            .filter(|build| request.satisfied_by_key(&build.key))
+            .filter(|build| policy.is_none_or(|policy| policy.allows(&build.key)))
+            .max_by_key(|build| (build.key.major, build.key.minor, build.key.patch))
+    }
+
+    pub fn key(&self) -> &PythonInstallationKey {
+        &self.key
+    }
+
+    pub fn url(&self) -> &'static str {
+        self.url
+    }
+
+    pub fn sha256(&self) -> &'static str {
+        self.sha256
+    }
+
+    /// Download, verify, and build the source distribution, and install it into the
+    /// installation directory.
+    pub async fn build(
+        &self,
+        client: &uv_client::BaseClient,
+        installation_dir: &Path,
+        scratch_dir: &Path,
+        reinstall: bool,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        let path = installation_dir.join(self.key.to_string());
+
+        // If it is not a reinstall and the dir already exists, return it.
+        if !reinstall && path.is_dir() {
+            return Ok(DownloadResult::AlreadyAvailable(path));
+        }
+
+        if cfg!(windows) {
+            return Err(Error::UnsupportedPlatform);
+        }
+
+        // Reuse the configuration of a previous build of this version, if any.
+        let configure_args = SourceBuildRecord::read(&path).map_or_else(
+            || {
+                DEFAULT_CONFIGURE_ARGS
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            },
+            |record| record.configure_args,
+        );
+
+        // Download and extract into a temporary directory.
+        let temp_dir =
+            tempfile::tempdir_in(scratch_dir).map_err(downloads::Error::DownloadDirError)?;
+        let url = Url::parse(self.url).map_err(downloads::Error::from)?;
+        let filename = url.path_segments().unwrap().last().unwrap();
+        download_archive(
+            &self.key,
+            &url,
+            Some(self.sha256),
+            client,
+            temp_dir.path(),
+            reporter,
+        )
+        .await?;
+        let source = uv_extract::strip_component(temp_dir.path())
+            .map_err(|err| downloads::Error::ExtractError(filename.to_string(), err))?;
+
+        // Remove the target if it already exists.
+        if path.is_dir() {
+            debug!("Removing existing directory: {}", path.user_display());
+            fs_err::tokio::remove_dir_all(&path).await?;
+        }
+
+        // CPython isn't relocatable when built from source, so it's installed into its final
+        // location directly.
+        let jobs = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let result = async {
+            self.run(
+                &source,
+                "./configure",
+                std::iter::once(format!("--prefix={}", path.display()))
+                    .chain(configure_args.iter().cloned()),
+            )
+            .await?;
+            self.run(&source, "make", [format!("-j{jobs}")]).await?;
+            self.run(&source, "make", ["install".to_string()]).await
+        }
+        .await;
+        if let Err(err) = result {
+            if path.is_dir() {
+                fs_err::tokio::remove_dir_all(&path).await?;
+            }
+            return Err(err);
+        }
+
+        // Add a `python`-to-`pythonX.Y` symlink, as in pre-built distributions.
+        #[cfg(unix)]
+        {
+            match std::os::unix::fs::symlink(
+                format!("python{}.{}", self.key.major, self.key.minor),
+                path.join("bin").join("python"),
+            ) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        SourceBuildRecord {
+            url: self.url.to_string(),
+            sha256: self.sha256.to_string(),
+            configure_args,
+        }
+        .write(&path)?;
+
+        Ok(DownloadResult::Fetched(path))
+    }
+
+    /// Run a build command in the source directory.
+    async fn run(
+        &self,
+        source: &Path,
+        program: &str,
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<(), Error> {
+        let args = args.into_iter().collect::<Vec<_>>();
+        let command = std::iter::once(program.to_string())
+            .chain(args.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(" ");
+        debug!("Running `{command}` in `{}`", source.user_display());
+        let output = tokio::process::Command::new(program)
+            .args(&args)
+            .current_dir(source)
+            .output()
+            .await
+            .map_err(|err| Error::Spawn {
+                key: self.key.clone(),
+                command: command.clone(),
+                err,
+            })?;
+        if !output.status.success() {
+            return Err(Error::Build {
+                key: self.key.clone(),
+                command,
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Display for PythonSourceBuild {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::downloads::PythonDownloadRequest;
+
+    use super::{source_distributions, PythonSourceBuild};
+
+    #[test]
+    fn source_metadata() {
+        // The pinned source distributions must be parseable, and each must be verifiable.
+        assert!(!source_distributions().is_empty());
+        for distribution in source_distributions() {
+            assert_eq!(distribution.sha256.len(), 64, "{}", distribution.url);
+        }
+    }
+
+    #[test]
+    fn from_request() {
+        // The latest pinned patch version is selected.
+        let request = PythonDownloadRequest::from_str("cpython-3.12-linux-aarch64-musl").unwrap();
+        let build = PythonSourceBuild::from_request(&request, None).unwrap();
+        assert_eq!(build.key().to_string(), "cpython-3.12.9-linux-aarch64-musl");
+        assert_eq!(
+            build.url(),
+            "https://www.python.org/ftp/python/3.12.9/Python-3.12.9.tgz"
+        );
+
+        // Only pinned versions can be built.
+        let request = PythonDownloadRequest::from_str("cpython-3.12.8-linux-aarch64-musl").unwrap();
+        assert!(PythonSourceBuild::from_request(&request, None).is_none());
+
+        // The platform must be known.
+        let request = PythonDownloadRequest::from_str("cpython-3.12").unwrap();
+        assert!(PythonSourceBuild::from_request(&request, None).is_none());
+
+        // Only CPython can be built.
+        let request = PythonDownloadRequest::from_str("pypy-3.10-linux-aarch64-gnu").unwrap();
+        assert!(PythonSourceBuild::from_request(&request, None).is_none());
+    }
+}
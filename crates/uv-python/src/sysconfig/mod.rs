@@ -135,12 +135,27 @@ static DEFAULT_VARIABLE_UPDATES: LazyLock<BTreeMap<String, ReplacementEntry>> =
         ])
     });
 
+/// The prefix that `python-build-standalone` distributions are built with.
+const BUILD_PREFIX: &str = "/install";
+
 /// Update the `sysconfig` data in a Python installation.
 pub(crate) fn update_sysconfig(
     install_root: &Path,
     major: u8,
     minor: u8,
     suffix: &str,
+) -> Result<(), Error> {
+    relocate_sysconfig(install_root, Path::new(BUILD_PREFIX), major, minor, suffix)
+}
+
+/// Update the `sysconfig` data in a Python installation that was moved from `previous_prefix`
+/// (e.g., with `uv python move`).
+pub(crate) fn relocate_sysconfig(
+    install_root: &Path,
+    previous_prefix: &Path,
+    major: u8,
+    minor: u8,
+    suffix: &str,
 ) -> Result<(), Error> {
     // Find the `_sysconfigdata_` file in the Python installation.
     let real_prefix = std::path::absolute(install_root)?;
@@ -153,7 +168,7 @@ pub(crate) fn update_sysconfig(
     // Update the `_sysconfigdata_` file in-memory.
     let contents = fs_err::read_to_string(&sysconfigdata)?;
     let data = SysconfigData::from_str(&contents)?;
-    let data = patch_sysconfigdata(data, &real_prefix);
+    let data = patch_sysconfigdata(data, previous_prefix, &real_prefix);
     let contents = data.to_string_pretty()?;
 
     // Write the updated `_sysconfigdata_` file.
@@ -235,20 +250,21 @@ fn find_sysconfigdata(
 }
 
 /// Patch the given `_sysconfigdata_` contents.
-fn patch_sysconfigdata(mut data: SysconfigData, real_prefix: &Path) -> SysconfigData {
-    /// Update the `/install` prefix in a whitespace-separated string.
-    fn update_prefix(s: &str, real_prefix: &Path) -> String {
+fn patch_sysconfigdata(
+    mut data: SysconfigData,
+    previous_prefix: &Path,
+    real_prefix: &Path,
+) -> SysconfigData {
+    /// Update the previous prefix (e.g., `/install`) in a whitespace-separated string.
+    fn update_prefix(s: &str, previous_prefix: &Path, real_prefix: &Path) -> String {
+        let previous_prefix = previous_prefix.display().to_string();
         s.split_whitespace()
-            .map(|part| {
-                if let Some(rest) = part.strip_prefix("/install") {
-                    if rest.is_empty() {
-                        real_prefix.display().to_string()
-                    } else {
-                        real_prefix.join(&rest[1..]).display().to_string()
-                    }
-                } else {
-                    part.to_string()
+            .map(|part| match part.strip_prefix(previous_prefix.as_str()) {
+                Some("") => real_prefix.display().to_string(),
+                Some(rest) if rest.starts_with('/') => {
+                    real_prefix.join(&rest[1..]).display().to_string()
                 }
+                _ => part.to_string(),
             })
             .collect::<Vec<_>>()
             .join(" ")
@@ -275,7 +291,7 @@ fn patch_sysconfigdata(mut data: SysconfigData, real_prefix: &Path) -> Sysconfig
         let Value::String(value) = value else {
             continue;
         };
-        let patched = update_prefix(value, real_prefix);
+        let patched = update_prefix(value, previous_prefix, real_prefix);
         let mut patched = remove_isysroot(&patched);
 
         if let Some(replacement_entry) = DEFAULT_VARIABLE_UPDATES.get(key) {
@@ -400,7 +416,7 @@ mod tests {
         .collect::<SysconfigData>();
 
         let real_prefix = Path::new("/real/prefix");
-        let data = patch_sysconfigdata(sysconfigdata, real_prefix);
+        let data = patch_sysconfigdata(sysconfigdata, Path::new(BUILD_PREFIX), real_prefix);
 
         insta::assert_snapshot!(data.to_string_pretty()?, @r###"
         # system configuration generated and used by the sysconfig module
@@ -420,6 +436,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn relocate_real_prefix() -> Result<(), Error> {
+        let sysconfigdata = [
+            ("BINDIR", "/old/prefix/bin"),
+            ("LIBDIRS", "/old/prefix/lib /old/prefixes/lib"),
+            ("prefix", "/old/prefix"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+        .collect::<SysconfigData>();
+
+        let data = patch_sysconfigdata(
+            sysconfigdata,
+            Path::new("/old/prefix"),
+            Path::new("/new/prefix"),
+        );
+
+        insta::assert_snapshot!(data.to_string_pretty()?, @r###"
+        # system configuration generated and used by the sysconfig module
+        build_time_vars = {
+            "BINDIR": "/new/prefix/bin",
+            "LIBDIRS": "/new/prefix/lib /old/prefixes/lib",
+            "PYTHON_BUILD_STANDALONE": 1,
+            "prefix": "/new/prefix"
+        }
+        "###);
+
+        Ok(())
+    }
+
     #[test]
     fn test_replacements() -> Result<(), Error> {
         let sysconfigdata = [
@@ -432,7 +478,7 @@ mod tests {
         .collect::<SysconfigData>();
 
         let real_prefix = Path::new("/real/prefix");
-        let data = patch_sysconfigdata(sysconfigdata, real_prefix);
+        let data = patch_sysconfigdata(sysconfigdata, Path::new(BUILD_PREFIX), real_prefix);
 
         insta::assert_snapshot!(data.to_string_pretty()?, @r###"
         # system configuration generated and used by the sysconfig module
@@ -457,7 +503,7 @@ mod tests {
             .collect::<SysconfigData>();
 
         let real_prefix = Path::new("/real/prefix");
-        let data = patch_sysconfigdata(sysconfigdata, real_prefix);
+        let data = patch_sysconfigdata(sysconfigdata, Path::new(BUILD_PREFIX), real_prefix);
 
         insta::assert_snapshot!(data.to_string_pretty()?, @r###"
         # system configuration generated and used by the sysconfig module
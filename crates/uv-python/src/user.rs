@@ -0,0 +1,64 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use uv_pypi_types::Scheme;
+use uv_static::EnvVars;
+
+/// The user site directory (i.e., `site.USER_BASE`) into which packages can be installed via
+/// `--user`, separate from a virtual environment or system Python interpreter.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct User {
+    /// The user base directory.
+    base: PathBuf,
+    /// The user scheme, relative to the user base directory.
+    scheme: Scheme,
+}
+
+impl User {
+    /// Apply any `PYTHONUSERBASE` override to the user base directory.
+    #[must_use]
+    pub fn with_env(self) -> Self {
+        match std::env::var_os(EnvVars::PYTHONUSERBASE) {
+            Some(base) if !base.is_empty() => Self {
+                base: PathBuf::from(base),
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Return the [`Scheme`] for the user site directory.
+    pub fn scheme(&self) -> Scheme {
+        Scheme {
+            purelib: self.base.join(&self.scheme.purelib),
+            platlib: self.base.join(&self.scheme.platlib),
+            scripts: self.base.join(&self.scheme.scripts),
+            data: self.base.join(&self.scheme.data),
+            include: self.base.join(&self.scheme.include),
+        }
+    }
+
+    /// Return an iterator over the `site-packages` directories inside the user site directory.
+    ///
+    /// Includes both the `purelib` and `platlib` directories, if they differ.
+    pub fn site_packages(&self) -> impl Iterator<Item = PathBuf> {
+        let purelib = self.base.join(&self.scheme.purelib);
+        let platlib = self.base.join(&self.scheme.platlib);
+        let platlib = (platlib != purelib).then_some(platlib);
+        std::iter::once(purelib).chain(platlib)
+    }
+
+    /// Initialize the user site directory.
+    pub fn init(&self) -> std::io::Result<()> {
+        for site_packages in self.site_packages() {
+            fs_err::create_dir_all(site_packages)?;
+        }
+        Ok(())
+    }
+
+    /// Return the path to the user base directory.
+    pub fn root(&self) -> &Path {
+        &self.base
+    }
+}
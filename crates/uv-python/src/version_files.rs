@@ -64,11 +64,16 @@ impl<'a> DiscoveryOptions<'a> {
 
 impl PythonVersionFile {
     /// Find a Python version file in the given directory or any of its parents.
+    ///
+    /// If no version file is found, the global version file in the user configuration directory
+    /// is used instead, if any (see [`PythonVersionFile::global_path`]).
     pub async fn discover(
         working_directory: impl AsRef<Path>,
         options: &DiscoveryOptions<'_>,
     ) -> Result<Option<Self>, std::io::Error> {
-        let Some(path) = Self::find_nearest(working_directory, options) else {
+        let Some(path) =
+            Self::find_nearest(working_directory, options).or_else(|| Self::find_global(options))
+        else {
             return Ok(None);
         };
 
@@ -98,6 +103,23 @@ impl PythonVersionFile {
             .find_map(|path| Self::find_in_directory(path, options))
     }
 
+    fn find_global(options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
+        let path = Self::find_in_directory(&uv_dirs::user_config_dir()?, options)?;
+        debug!(
+            "Found global Python version file at `{}`",
+            path.user_display()
+        );
+        Some(path)
+    }
+
+    /// Return the path to the global `.python-version` file, in the user configuration directory.
+    ///
+    /// The global version file is used when no version file is found in the working directory or
+    /// any of its parents.
+    pub fn global_path() -> Option<PathBuf> {
+        uv_dirs::user_config_dir().map(|dir| dir.join(PYTHON_VERSION_FILENAME))
+    }
+
     fn find_in_directory(path: &Path, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
         let version_path = path.join(PYTHON_VERSION_FILENAME);
         let versions_path = path.join(PYTHON_VERSIONS_FILENAME);
@@ -204,6 +226,9 @@ impl PythonVersionFile {
     /// Update the version file on the file system.
     pub async fn write(&self) -> Result<(), std::io::Error> {
         debug!("Writing Python versions to `{}`", self.path.display());
+        if let Some(parent) = self.path.parent() {
+            fs::tokio::create_dir_all(parent).await?;
+        }
         fs::tokio::write(
             &self.path,
             self.versions
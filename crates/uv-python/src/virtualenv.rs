@@ -41,6 +41,8 @@ pub struct PyVenvConfiguration {
     pub(crate) seed: bool,
     /// Should the virtual environment include system site packages?
     pub(crate) include_system_site_packages: bool,
+    /// The directory containing the base interpreter of the virtual environment, if recorded.
+    pub(crate) home: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -193,6 +195,7 @@ impl PyVenvConfiguration {
         let mut relocatable = false;
         let mut seed = false;
         let mut include_system_site_packages = true;
+        let mut home = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -219,6 +222,9 @@ impl PyVenvConfiguration {
                 "include-system-site-packages" => {
                     include_system_site_packages = value.trim().to_lowercase() == "true";
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 _ => {}
             }
         }
@@ -229,6 +235,7 @@ impl PyVenvConfiguration {
             relocatable,
             seed,
             include_system_site_packages,
+            home,
         })
     }
 
@@ -257,6 +264,12 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the directory containing the base interpreter of the virtual environment, if
+    /// recorded.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -56,6 +56,11 @@ pub(crate) fn registry_pythons() -> Result<Vec<WindowsPython>, windows_result::E
             if company == "PyLauncher" {
                 continue;
             }
+            // Managed Python installations are registered by uv, but are discovered from the
+            // managed installations directory instead.
+            if company == COMPANY_KEY {
+                continue;
+            }
             let Ok(company_key) = key_python.open(&company) else {
                 // Ignore invalid entries
                 continue;
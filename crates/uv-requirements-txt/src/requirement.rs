@@ -23,6 +23,9 @@ pub enum EditableError {
     #[error("Editable `{0}` must refer to a local directory, not a Git URL: `{1}`")]
     Git(PackageName, String),
 
+    #[error("Editable `{0}` must refer to a local directory, not a Mercurial URL: `{1}`")]
+    Hg(PackageName, String),
+
     #[error("Editable must refer to a local directory, not an archive: `{0}`")]
     UnnamedFile(String),
 
@@ -31,6 +34,9 @@ pub enum EditableError {
 
     #[error("Editable must refer to a local directory, not a Git URL: `{0}`")]
     UnnamedGit(String),
+
+    #[error("Editable must refer to a local directory, not a Mercurial URL: `{0}`")]
+    UnnamedHg(String),
 }
 
 /// A requirement specifier in a `requirements.txt` file.
@@ -84,6 +90,9 @@ impl RequirementsTxtRequirement {
                     ParsedUrl::Git(_) => {
                         return Err(EditableError::Git(requirement.name, url.to_string()))
                     }
+                    ParsedUrl::Hg(_) => {
+                        return Err(EditableError::Hg(requirement.name, url.to_string()))
+                    }
                 };
 
                 Ok(Self::Named(uv_pep508::Requirement {
@@ -109,6 +118,9 @@ impl RequirementsTxtRequirement {
                     ParsedUrl::Git(_) => {
                         return Err(EditableError::UnnamedGit(requirement.to_string()))
                     }
+                    ParsedUrl::Hg(_) => {
+                        return Err(EditableError::UnnamedHg(requirement.to_string()))
+                    }
                 };
 
                 Ok(Self::Unnamed(UnnamedRequirement {
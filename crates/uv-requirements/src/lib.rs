@@ -5,7 +5,7 @@ pub use crate::sources::*;
 pub use crate::specification::*;
 pub use crate::unnamed::*;
 
-use uv_distribution_types::{Dist, DistErrorKind, GitSourceDist, SourceDist};
+use uv_distribution_types::{Dist, DistErrorKind, GitSourceDist, HgSourceDist, SourceDist};
 use uv_pypi_types::{Requirement, RequirementSource};
 
 mod extras;
@@ -66,6 +66,16 @@ pub(crate) fn required_dist(
             subdirectory: subdirectory.clone(),
             url: url.clone(),
         })),
+        RequirementSource::Hg {
+            hg,
+            subdirectory,
+            url,
+        } => Dist::Source(SourceDist::Hg(HgSourceDist {
+            name: requirement.name.clone(),
+            hg: Box::new(hg.clone()),
+            subdirectory: subdirectory.clone(),
+            url: url.clone(),
+        })),
         RequirementSource::Path {
             install_path,
             ext,
@@ -13,7 +13,7 @@ use crate::Error;
 use uv_distribution::{DistributionDatabase, Reporter};
 use uv_distribution_filename::{DistExtension, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuildableSource, DirectSourceUrl, DirectorySourceUrl, GitSourceUrl, PathSourceUrl,
+    BuildableSource, DirectSourceUrl, DirectorySourceUrl, GitSourceUrl, HgSourceUrl, PathSourceUrl,
     RemoteSource, SourceUrl, VersionId,
 };
 use uv_normalize::PackageName;
@@ -269,6 +269,11 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
                 git: &parsed_git_url.url,
                 subdirectory: parsed_git_url.subdirectory.as_deref(),
             }),
+            ParsedUrl::Hg(parsed_hg_url) => SourceUrl::Hg(HgSourceUrl {
+                url: &requirement.url.verbatim,
+                hg: &parsed_hg_url.url,
+                subdirectory: parsed_hg_url.subdirectory.as_deref(),
+            }),
         };
 
         // Fetch the metadata for the distribution.
@@ -5,7 +5,7 @@ use anyhow::Result;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
 use uv_fs::CWD;
-use uv_git::ResolvedRepositoryReference;
+use uv_git::{ResolvedHgRepositoryReference, ResolvedRepositoryReference};
 use uv_requirements_txt::RequirementsTxt;
 use uv_resolver::{Lock, LockError, Preference, PreferenceError};
 
@@ -15,6 +15,8 @@ pub struct LockedRequirements {
     pub preferences: Vec<Preference>,
     /// The pinned Git SHAs from the lockfile.
     pub git: Vec<ResolvedRepositoryReference>,
+    /// The pinned Mercurial changesets from the lockfile.
+    pub hg: Vec<ResolvedHgRepositoryReference>,
 }
 
 /// Load the preferred requirements from an existing `requirements.txt`, applying the upgrade strategy.
@@ -75,6 +77,7 @@ pub fn read_lock_requirements(
 
     let mut preferences = Vec::new();
     let mut git = Vec::new();
+    let mut hg = Vec::new();
 
     for package in lock.packages() {
         // Skip the distribution if it's not included in the upgrade strategy.
@@ -91,7 +94,16 @@ pub fn read_lock_requirements(
         if let Some(git_ref) = package.as_git_ref()? {
             git.push(git_ref);
         }
+
+        // Map each entry in the lockfile to a Mercurial changeset.
+        if let Some(hg_ref) = package.as_hg_ref()? {
+            hg.push(hg_ref);
+        }
     }
 
-    Ok(LockedRequirements { preferences, git })
+    Ok(LockedRequirements {
+        preferences,
+        git,
+        hg,
+    })
 }
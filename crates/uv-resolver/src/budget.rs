@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+/// A limit on the amount of work the resolver may perform before giving up.
+///
+/// When either limit is exceeded, resolution is aborted with a report of the packages that were
+/// responsible for the most backtracking.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionBudget {
+    /// The maximum number of resolver iterations, summed across all forks.
+    max_iterations: Option<NonZeroUsize>,
+    /// The maximum amount of time to spend in the solver.
+    timeout: Option<Duration>,
+}
+
+impl ResolutionBudget {
+    /// Create a [`ResolutionBudget`] from the given iteration and time limits.
+    pub fn new(max_iterations: Option<NonZeroUsize>, timeout: Option<Duration>) -> Self {
+        Self {
+            max_iterations,
+            timeout,
+        }
+    }
+
+    /// Create a [`ResolutionBudget`] from the user-provided settings, where the timeout is given in
+    /// seconds.
+    pub fn from_args(max_iterations: Option<NonZeroUsize>, timeout: Option<u64>) -> Self {
+        Self::new(max_iterations, timeout.map(Duration::from_secs))
+    }
+
+    /// Returns `true` if the budget is unlimited.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_iterations.is_none() && self.timeout.is_none()
+    }
+
+    /// Returns the limit that was exceeded, if any, given the number of iterations performed and
+    /// the time spent so far.
+    pub(crate) fn exceeded(
+        &self,
+        iterations: usize,
+        elapsed: impl FnOnce() -> Duration,
+    ) -> Option<BudgetLimit> {
+        if let Some(max_iterations) = self.max_iterations {
+            if iterations > max_iterations.get() {
+                return Some(BudgetLimit::Iterations(max_iterations));
+            }
+        }
+        if let Some(timeout) = self.timeout {
+            if elapsed() > timeout {
+                return Some(BudgetLimit::Timeout(timeout));
+            }
+        }
+        None
+    }
+}
+
+/// The limit of a [`ResolutionBudget`] that was exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetLimit {
+    /// The resolver exceeded the maximum number of iterations.
+    Iterations(NonZeroUsize),
+    /// The resolver exceeded the maximum resolution time.
+    Timeout(Duration),
+}
+
+impl Display for BudgetLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Iterations(max_iterations) => {
+                write!(f, "the iteration limit of {max_iterations}")
+            }
+            Self::Timeout(timeout) => {
+                write!(f, "the time limit of {}s", timeout.as_secs())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::time::Duration;
+
+    use super::{BudgetLimit, ResolutionBudget};
+
+    #[test]
+    fn unlimited() {
+        let budget = ResolutionBudget::default();
+        assert!(budget.is_unlimited());
+        assert_eq!(budget.exceeded(usize::MAX, || Duration::MAX), None);
+    }
+
+    #[test]
+    fn iterations() {
+        let max_iterations = NonZeroUsize::new(10).unwrap();
+        let budget = ResolutionBudget::new(Some(max_iterations), None);
+        assert_eq!(budget.exceeded(10, || Duration::ZERO), None);
+        assert_eq!(
+            budget.exceeded(11, || Duration::ZERO),
+            Some(BudgetLimit::Iterations(max_iterations))
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let budget = ResolutionBudget::from_args(None, Some(30));
+        assert_eq!(budget.exceeded(1, || Duration::from_secs(30)), None);
+        assert_eq!(
+            budget.exceeded(1, || Duration::from_secs(31)),
+            Some(BudgetLimit::Timeout(Duration::from_secs(30)))
+        );
+    }
+}
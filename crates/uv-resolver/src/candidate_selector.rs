@@ -6,7 +6,7 @@ use pubgrub::Range;
 use smallvec::SmallVec;
 use tracing::{debug, trace};
 
-use uv_configuration::IndexStrategy;
+use uv_configuration::{BuildOptions, IndexStrategy};
 use uv_distribution_types::{CompatibleDist, IncompatibleDist, IncompatibleSource, IndexUrl};
 use uv_distribution_types::{DistributionMetadata, IncompatibleWheel, Name, PrioritizedDist};
 use uv_normalize::PackageName;
@@ -26,6 +26,8 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    build_options: BuildOptions,
+    minimal_changes: bool,
 }
 
 impl CandidateSelector {
@@ -49,6 +51,8 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            build_options: options.build_options.clone(),
+            minimal_changes: options.minimal_changes,
         }
     }
 
@@ -112,6 +116,21 @@ impl CandidateSelector {
             return Some(preferred);
         }
 
+        // If the preference doesn't satisfy the current range, and we're minimizing changes,
+        // search for the version that's closest to the preference.
+        if self.minimal_changes {
+            if let Some(nearest) =
+                self.get_nearest(package_name, range, version_maps, preferences, index, env)
+            {
+                trace!(
+                    "Using version {} {} closest to preference",
+                    nearest.name,
+                    nearest.version
+                );
+                return Some(nearest);
+            }
+        }
+
         // If we don't have a preference, find an already-installed distribution that satisfies the
         // range.
         let installed = if reinstall {
@@ -341,6 +360,46 @@ impl CandidateSelector {
         None
     }
 
+    /// If the package has a preference that doesn't satisfy the current range, select the version
+    /// that's closest to it, i.e., the lowest version above the preference or, failing that, the
+    /// highest version below it.
+    ///
+    /// Selecting the closest version (rather than, e.g., the latest version) minimizes the change
+    /// relative to an existing lockfile when the preference is invalidated by a new constraint.
+    fn get_nearest<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        preferences: &'a Preferences,
+        index: Option<&'a IndexUrl>,
+        env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        // Use the preference that matches the current environment, if any.
+        let preferred = preferences
+            .get(package_name)
+            .iter()
+            .filter(|entry| index.is_none_or(|index| entry.index().matches(index)))
+            .max_by_key(|entry| {
+                (
+                    env.included_by_marker(entry.marker().pep508()),
+                    entry.pin().version(),
+                )
+            })?
+            .pin()
+            .version();
+
+        let above = range.intersection(&Range::strictly_higher_than(preferred.clone()));
+        if let Some(candidate) =
+            self.select_from_version_maps(package_name, &above, version_maps, env, false, false)
+        {
+            return Some(candidate);
+        }
+
+        let below = range.intersection(&Range::strictly_lower_than(preferred.clone()));
+        self.select_from_version_maps(package_name, &below, version_maps, env, true, false)
+    }
+
     /// Check for an installed distribution that satisfies the current range and is allowed.
     fn get_installed<'a, InstalledPackages: InstalledPackagesProvider>(
         package_name: &'a PackageName,
@@ -386,12 +445,38 @@ impl CandidateSelector {
         range: &Range<Version>,
         version_maps: &'a [VersionMap],
         env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        // If the package prefers pre-built wheels, first look for a version with a compatible
+        // wheel, falling back to any version (e.g., a source distribution) if none exists.
+        let highest = self.use_highest_version(package_name, env);
+        if self.build_options.prefer_binary_package(package_name) {
+            if let Some(candidate) =
+                self.select_from_version_maps(package_name, range, version_maps, env, highest, true)
+            {
+                return Some(candidate);
+            }
+            debug!("No version of {package_name} with a compatible wheel satisfies {range}");
+        }
+        self.select_from_version_maps(package_name, range, version_maps, env, highest, false)
+    }
+
+    /// Select a [`Candidate`] from the given [`VersionMap`]s.
+    ///
+    /// If `highest` is set, the highest matching version is selected; otherwise, the lowest. If
+    /// `binary_only` is set, versions without a compatible wheel are skipped.
+    fn select_from_version_maps<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        env: &ResolverEnvironment,
+        highest: bool,
+        binary_only: bool,
     ) -> Option<Candidate<'a>> {
         trace!(
             "Selecting candidate for {package_name} with range {range} with {} remote versions",
             version_maps.iter().map(VersionMap::len).sum::<usize>(),
         );
-        let highest = self.use_highest_version(package_name, env);
 
         let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
             AllowPrerelease::Yes => true,
@@ -425,6 +510,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    binary_only,
                 )
             } else {
                 Self::select_candidate(
@@ -447,6 +533,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    binary_only,
                 )
             }
         } else {
@@ -457,6 +544,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        binary_only,
                     )
                 })
             } else {
@@ -466,6 +554,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        binary_only,
                     )
                 })
             }
@@ -499,6 +588,7 @@ impl CandidateSelector {
         package_name: &'a PackageName,
         range: &Range<Version>,
         allow_prerelease: bool,
+        binary_only: bool,
     ) -> Option<Candidate<'a>> {
         let mut steps = 0usize;
         let mut incompatible: Option<Candidate> = None;
@@ -526,6 +616,13 @@ impl CandidateSelector {
                 let Some(dist) = maybe_dist.prioritized_dist() else {
                     continue;
                 };
+                if binary_only
+                    && !dist
+                        .best_wheel()
+                        .is_some_and(|(_, compatibility)| compatibility.is_compatible())
+                {
+                    continue;
+                }
                 trace!("Found candidate for package {package_name} with range {range} after {steps} steps: {version} version");
                 Candidate::new(package_name, version, dist, VersionChoiceKind::Compatible)
             };
@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 
 use indexmap::IndexSet;
 use pubgrub::{
@@ -28,7 +29,7 @@ use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
     MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
 };
-use crate::{InMemoryIndex, Options};
+use crate::{BudgetLimit, InMemoryIndex, Options};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ResolveError {
@@ -100,12 +101,24 @@ pub enum ResolveError {
     #[error(transparent)]
     NoSolution(#[from] NoSolutionError),
 
+    #[error(transparent)]
+    BudgetExceeded(#[from] BudgetExceededError),
+
     #[error("Attempted to construct an invalid version specifier")]
     InvalidVersion(#[from] uv_pep440::VersionSpecifierBuildError),
 
     #[error("In `--require-hashes` mode, all requirements must be pinned upfront with `==`, but found: `{0}`")]
     UnhashedPackage(PackageName),
 
+    #[error("With `require-hashes` enabled, all direct URL requirements must include a hash, but none was provided for: `{0}`")]
+    MissingUrlHash(String),
+
+    #[error("With `require-hashes` enabled, all distributions from `--find-links` must include a hash, but none was provided for: `{0}`")]
+    MissingFlatIndexHash(String),
+
+    #[error("Package `{0}` is excluded via `exclude-dependencies`, but is required by: {1}")]
+    ExcludedDependency(PackageName, String),
+
     #[error("found conflicting distribution in resolution: {0}")]
     ConflictingDistribution(ConflictingDistributionError),
 
@@ -148,6 +161,7 @@ pub struct NoSolutionError {
     index_capabilities: IndexCapabilities,
     unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
     incomplete_packages: FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
+    cached_versions: FxHashMap<PackageName, BTreeSet<Version>>,
     fork_urls: ForkUrls,
     fork_indexes: ForkIndexes,
     env: ResolverEnvironment,
@@ -187,6 +201,7 @@ impl NoSolutionError {
             index_capabilities,
             unavailable_packages,
             incomplete_packages,
+            cached_versions: FxHashMap::default(),
             fork_urls,
             fork_indexes,
             env,
@@ -196,6 +211,36 @@ impl NoSolutionError {
         }
     }
 
+    /// Record the versions that are available in the cache for each package that was unavailable
+    /// because the network was disabled, to be listed alongside the error.
+    #[must_use]
+    pub(crate) fn with_cached_versions(
+        mut self,
+        cached_versions: impl Fn(&PackageName) -> BTreeSet<Version>,
+    ) -> Self {
+        let offline = self
+            .unavailable_packages
+            .iter()
+            .filter(|(_, reason)| matches!(reason, UnavailablePackage::Offline))
+            .map(|(name, _)| name)
+            .chain(
+                self.incomplete_packages
+                    .iter()
+                    .filter(|(_, versions)| {
+                        versions
+                            .values()
+                            .any(|reason| matches!(reason, MetadataUnavailable::Offline))
+                    })
+                    .map(|(name, _)| name),
+            );
+        for name in offline {
+            self.cached_versions
+                .entry(name.clone())
+                .or_insert_with(|| cached_versions(name));
+        }
+        self
+    }
+
     /// Given a [`DerivationTree`], collapse any [`External::FromDependencyOf`] incompatibilities
     /// wrap an [`PubGrubPackageInner::Extra`] package.
     pub(crate) fn collapse_proxies(derivation_tree: ErrorTree) -> ErrorTree {
@@ -357,6 +402,7 @@ impl std::fmt::Debug for NoSolutionError {
             index_capabilities,
             unavailable_packages,
             incomplete_packages,
+            cached_versions,
             fork_urls,
             fork_indexes,
             env,
@@ -374,6 +420,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("index_capabilities", index_capabilities)
             .field("unavailable_packages", unavailable_packages)
             .field("incomplete_packages", incomplete_packages)
+            .field("cached_versions", cached_versions)
             .field("fork_urls", fork_urls)
             .field("fork_indexes", fork_indexes)
             .field("env", env)
@@ -449,6 +496,7 @@ impl std::fmt::Display for NoSolutionError {
             &self.available_indexes,
             &self.unavailable_packages,
             &self.incomplete_packages,
+            &self.cached_versions,
             &self.fork_urls,
             &self.fork_indexes,
             &self.env,
@@ -1196,6 +1244,120 @@ impl std::fmt::Display for NoSolutionHeader {
     }
 }
 
+/// The resolver exceeded its [`ResolutionBudget`](crate::ResolutionBudget).
+#[derive(Debug)]
+pub struct BudgetExceededError {
+    /// The limit that was exceeded.
+    limit: BudgetLimit,
+    /// The number of iterations performed before the resolver gave up.
+    iterations: usize,
+    /// The time spent in the solver before it gave up.
+    elapsed: Duration,
+    /// The [`ResolverEnvironment`] that was being solved when the resolver gave up.
+    env: ResolverEnvironment,
+    /// The packages that were responsible for the most backtracking, in descending order.
+    hotspots: Vec<BacktrackHotspot>,
+}
+
+impl BudgetExceededError {
+    pub(crate) fn new(
+        limit: BudgetLimit,
+        iterations: usize,
+        elapsed: Duration,
+        env: ResolverEnvironment,
+        hotspots: Vec<BacktrackHotspot>,
+    ) -> Self {
+        Self {
+            limit,
+            iterations,
+            elapsed,
+            env,
+            hotspots,
+        }
+    }
+
+    /// The packages that were responsible for the most backtracking, in descending order.
+    pub fn hotspots(&self) -> &[BacktrackHotspot] {
+        &self.hotspots
+    }
+}
+
+impl std::error::Error for BudgetExceededError {}
+
+impl std::fmt::Display for BudgetExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Resolution exceeded {} after {} iterations ({:.2}s)",
+            self.limit,
+            self.iterations,
+            self.elapsed.as_secs_f32()
+        )?;
+        if let Some(split) = self.env.end_user_fork_display() {
+            write!(f, " while solving {split}")?;
+        }
+        if !self.hotspots.is_empty() {
+            write!(
+                f,
+                "\n\nThe following packages were responsible for the most backtracking:"
+            )?;
+            for hotspot in &self.hotspots {
+                write!(f, "\n  - {hotspot}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A package that consumed a significant share of the resolver's backtracking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacktrackHotspot {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The number of distinct candidate versions that were tried for the package.
+    pub candidates: usize,
+    /// The number of times a decision on the package was discarded due to a conflict with a
+    /// package decided earlier.
+    pub retries: usize,
+    /// The number of times a decision on the package caused another package to be discarded.
+    pub conflicts: usize,
+}
+
+impl BacktrackHotspot {
+    /// A score used to rank hotspots, higher is worse.
+    pub(crate) fn score(&self) -> usize {
+        self.candidates + self.retries + self.conflicts
+    }
+}
+
+impl std::fmt::Display for BacktrackHotspot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} {} tried, {} {}, {} {} with other packages",
+            self.name,
+            self.candidates,
+            if self.candidates == 1 {
+                "candidate"
+            } else {
+                "candidates"
+            },
+            self.retries,
+            if self.retries == 1 {
+                "retry"
+            } else {
+                "retries"
+            },
+            self.conflicts,
+            if self.conflicts == 1 {
+                "conflict"
+            } else {
+                "conflicts"
+            },
+        )
+    }
+}
+
 /// Given a [`DerivationTree`], simplify version ranges using the available versions for each
 /// package.
 fn simplify_derivation_tree_ranges(
@@ -24,6 +24,10 @@ use uv_types::HashStrategy;
 pub struct FlatIndex {
     /// The list of [`FlatDistributions`] from the `--find-links` entries, indexed by package name.
     index: FxHashMap<PackageName, FlatDistributions>,
+    /// The list of [`FlatDistributions`] from flat `path` sources, indexed by location and
+    /// package name. Unlike `--find-links` entries, these are only available to the packages that
+    /// select the location explicitly.
+    explicit: FxHashMap<IndexUrl, FxHashMap<PackageName, FlatDistributions>>,
     /// Whether any `--find-links` entries could not be resolved due to a lack of network
     /// connectivity.
     offline: bool,
@@ -56,7 +60,52 @@ impl FlatIndex {
         // Collect offline entries.
         let offline = entries.offline;
 
-        Self { index, offline }
+        Self {
+            index,
+            explicit: FxHashMap::default(),
+            offline,
+        }
+    }
+
+    /// Add the files from flat `path` sources (e.g., `{ path = "dist/", kind = "flat" }`) to the
+    /// [`FlatIndex`].
+    ///
+    /// Unlike `--find-links` entries, the files are only available to the packages that select
+    /// one of the `locations` explicitly.
+    #[must_use]
+    pub fn with_explicit_entries<'a>(
+        mut self,
+        locations: impl IntoIterator<Item = &'a IndexUrl>,
+        entries: FlatIndexEntries,
+        tags: Option<&Tags>,
+        hasher: &HashStrategy,
+        build_options: &BuildOptions,
+    ) -> Self {
+        // Track every location, even if it doesn't contain any distributions.
+        for location in locations {
+            self.explicit.entry(location.clone()).or_default();
+        }
+
+        for entry in entries.entries {
+            let distributions = self
+                .explicit
+                .entry(entry.index.clone())
+                .or_default()
+                .entry(entry.filename.name().clone())
+                .or_default();
+            Self::add_file(
+                distributions,
+                entry.file,
+                entry.filename,
+                tags,
+                hasher,
+                build_options,
+                entry.index,
+            );
+        }
+
+        self.offline |= entries.offline;
+        self
     }
 
     fn add_file(
@@ -200,6 +249,22 @@ impl FlatIndex {
         self.index.get(package_name)
     }
 
+    /// Returns `true` if the given index is a flat `path` source.
+    pub fn is_explicit(&self, index: &IndexUrl) -> bool {
+        self.explicit.contains_key(index)
+    }
+
+    /// Get the [`FlatDistributions`] for the given package name from a flat `path` source.
+    pub fn get_explicit(
+        &self,
+        index: &IndexUrl,
+        package_name: &PackageName,
+    ) -> Option<&FlatDistributions> {
+        self.explicit
+            .get(index)
+            .and_then(|packages| packages.get(package_name))
+    }
+
     /// Returns `true` if there are any offline `--find-links` entries.
     pub fn offline(&self) -> bool {
         self.offline
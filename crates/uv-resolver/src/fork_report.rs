@@ -0,0 +1,267 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use owo_colors::OwoColorize;
+
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::pubgrub::PubGrubPackage;
+use crate::universal_marker::UniversalMarker;
+
+/// The reason the resolver split into multiple forks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ForkCause {
+    /// The resolution was split upfront, based on the supported environments or the forks of an
+    /// existing lockfile.
+    Initial,
+    /// A package declares requirements with disjoint markers on the same package(s).
+    Dependencies {
+        package: PubGrubPackage,
+        version: Version,
+        diverging: Vec<PackageName>,
+    },
+    /// The `Requires-Python` of a candidate doesn't cover the supported Python range.
+    RequiresPython {
+        package: PubGrubPackage,
+        version: Version,
+        requires_python: VersionSpecifiers,
+    },
+    /// A candidate lacks a compatible distribution for one of the required environments.
+    RequiredEnvironment {
+        package: PubGrubPackage,
+        version: Version,
+    },
+    /// A candidate is a local version that doesn't cover all platforms covered by the base
+    /// version.
+    LocalVersion {
+        package: PubGrubPackage,
+        version: Version,
+    },
+}
+
+impl ForkCause {
+    /// Returns the names of the packages whose versions are affected by the fork.
+    fn affected(&self) -> Vec<&PackageName> {
+        match self {
+            Self::Initial => Vec::new(),
+            Self::Dependencies { diverging, .. } => diverging.iter().collect(),
+            Self::RequiresPython { package, .. }
+            | Self::RequiredEnvironment { package, .. }
+            | Self::LocalVersion { package, .. } => package.name().into_iter().collect(),
+        }
+    }
+}
+
+impl Display for ForkCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Initial => write!(
+                f,
+                "The resolution was split upfront, based on the supported environments or the existing lockfile"
+            ),
+            Self::Dependencies {
+                package,
+                version,
+                diverging,
+            } => {
+                let diverging = diverging
+                    .iter()
+                    .map(|name| format!("`{}`", name.cyan()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if package.name().is_some() {
+                    write!(
+                        f,
+                        "`{}` has requirements on {diverging} with disjoint markers",
+                        format!("{package}=={version}").cyan(),
+                    )
+                } else {
+                    write!(f, "The root requirements on {diverging} have disjoint markers")
+                }
+            }
+            Self::RequiresPython {
+                package,
+                version,
+                requires_python,
+            } => write!(
+                f,
+                "`{}` requires Python `{}`",
+                format!("{package}=={version}").cyan(),
+                requires_python.cyan(),
+            ),
+            Self::RequiredEnvironment { package, version } => write!(
+                f,
+                "`{}` lacks a compatible distribution for a required environment",
+                format!("{package}=={version}").cyan(),
+            ),
+            Self::LocalVersion { package, version } => write!(
+                f,
+                "`{}` is a local version that doesn't support all platforms",
+                format!("{package}=={version}").cyan(),
+            ),
+        }
+    }
+}
+
+/// A point at which the resolver forked, along with the branch that was taken.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ForkPoint {
+    /// The reason for the fork.
+    pub(crate) cause: ForkCause,
+    /// The markers of the branch that was taken, including those of any parent forks.
+    pub(crate) marker: UniversalMarker,
+}
+
+/// A fork that was successfully resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedFork {
+    /// The markers under which the fork applies.
+    pub(crate) marker: UniversalMarker,
+    /// The fork points that led to this fork, from the root of the resolution.
+    pub(crate) history: Vec<ForkPoint>,
+    /// The selected version for each package in the fork.
+    pub(crate) versions: BTreeMap<PackageName, Version>,
+}
+
+/// A branch taken at a fork point, along with the resolved forks that descend from it.
+type ForkBranch<'a> = (UniversalMarker, Vec<&'a ResolvedFork>);
+
+/// A report of the forks that were created during a universal resolution, for debugging
+/// platform-specific version skew.
+#[derive(Debug, Clone, Default)]
+pub struct ForkReport {
+    forks: Vec<ResolvedFork>,
+}
+
+impl ForkReport {
+    pub(crate) fn new(forks: Vec<ResolvedFork>) -> Self {
+        Self { forks }
+    }
+
+    /// Returns `true` if the resolution didn't fork.
+    pub fn is_empty(&self) -> bool {
+        self.forks.iter().all(|fork| fork.history.is_empty())
+    }
+
+    /// Group the fork points across all resolved forks, in the order in which they were first
+    /// encountered.
+    ///
+    /// Each fork point is identified by the path of fork points leading up to it, such that the
+    /// same cause in two different parent forks is reported twice.
+    fn points(&self) -> Vec<(&ForkCause, Vec<ForkBranch<'_>>)> {
+        let mut points: Vec<(&[ForkPoint], &ForkCause, Vec<ForkBranch>)> = Vec::new();
+        for fork in &self.forks {
+            for (depth, point) in fork.history.iter().enumerate() {
+                let parents = &fork.history[..depth];
+                let branches = if let Some((.., branches)) = points
+                    .iter_mut()
+                    .find(|(prefix, cause, _)| *prefix == parents && **cause == point.cause)
+                {
+                    branches
+                } else {
+                    points.push((parents, &point.cause, Vec::new()));
+                    &mut points.last_mut().unwrap().2
+                };
+                if let Some((_, forks)) = branches
+                    .iter_mut()
+                    .find(|(marker, _)| *marker == point.marker)
+                {
+                    forks.push(fork);
+                } else {
+                    branches.push((point.marker, vec![fork]));
+                }
+            }
+        }
+        points
+            .into_iter()
+            .map(|(_, cause, branches)| (cause, branches))
+            .collect()
+    }
+}
+
+impl Display for ForkReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No forks were created during resolution");
+        }
+
+        // Display every fork point, along with the versions selected in each branch for the
+        // packages that caused the fork.
+        for (index, (cause, branches)) in self.points().into_iter().enumerate() {
+            writeln!(f, "{} {cause}", format!("Fork point {}:", index + 1).bold())?;
+            for (marker, forks) in branches {
+                let versions = cause
+                    .affected()
+                    .into_iter()
+                    .flat_map(|name| {
+                        forks
+                            .iter()
+                            .filter_map(move |fork| {
+                                fork.versions
+                                    .get(name)
+                                    .map(|version| format!("{name}=={version}"))
+                            })
+                            .collect::<BTreeSet<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                if matches!(cause, ForkCause::Initial) {
+                    writeln!(f, "  `{}`", format!("{marker:?}").yellow())?;
+                } else if versions.is_empty() {
+                    writeln!(f, "  `{}`: (not selected)", format!("{marker:?}").yellow())?;
+                } else {
+                    writeln!(
+                        f,
+                        "  `{}`: {}",
+                        format!("{marker:?}").yellow(),
+                        versions.join(", ")
+                    )?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        // Display every package that was resolved to different versions across forks.
+        let mut skew: BTreeMap<&PackageName, BTreeMap<&Version, Vec<UniversalMarker>>> =
+            BTreeMap::new();
+        for fork in &self.forks {
+            for (name, version) in &fork.versions {
+                skew.entry(name)
+                    .or_default()
+                    .entry(version)
+                    .or_default()
+                    .push(fork.marker);
+            }
+        }
+        skew.retain(|_, versions| versions.len() > 1);
+
+        if skew.is_empty() {
+            writeln!(
+                f,
+                "All packages were resolved to the same version in every fork"
+            )?;
+        } else {
+            writeln!(
+                f,
+                "{}",
+                "Packages with different versions across forks:".bold()
+            )?;
+            for (name, versions) in skew {
+                writeln!(f, "  {}", name.cyan())?;
+                for (version, markers) in versions {
+                    writeln!(
+                        f,
+                        "    {version}: {}",
+                        markers
+                            .iter()
+                            .map(|marker| format!("`{}`", format!("{marker:?}").yellow()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
+pub use budget::{BudgetLimit, ResolutionBudget};
 pub use dependency_mode::DependencyMode;
-pub use error::{NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    BacktrackHotspot, BudgetExceededError, NoSolutionError, NoSolutionHeader, ResolveError,
+    SentinelRange,
+};
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
+pub use fork_report::ForkReport;
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
     Installable, Lock, LockError, LockVersion, Package, PackageMap, RequirementsTxtExport,
@@ -13,7 +18,9 @@ pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
-pub use requires_python::{RequiresPython, RequiresPythonRange};
+pub use requires_python::{
+    RequiresPython, RequiresPythonRange, SupportedPython, SupportedPythonError,
+};
 pub use resolution::{
     AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
 };
@@ -25,7 +32,7 @@ pub use resolver::{
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use yanks::{AllowedYank, AllowedYankError, AllowedYanks, YankedMode, YankedPolicy};
 
 /// A custom `HashSet` using `hashbrown`.
 ///
@@ -36,6 +43,7 @@ type FxHashbrownSet<T> = hashbrown::HashSet<T, rustc_hash::FxBuildHasher>;
 
 type FxHashbrownMap<K, V> = hashbrown::HashMap<K, V, rustc_hash::FxBuildHasher>;
 
+mod budget;
 mod candidate_selector;
 mod dependency_mode;
 mod dependency_provider;
@@ -44,6 +52,7 @@ mod exclude_newer;
 mod exclusions;
 mod flat_index;
 mod fork_indexes;
+mod fork_report;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
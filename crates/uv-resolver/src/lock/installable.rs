@@ -17,6 +17,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
 use uv_platform_tags::Tags;
 use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_warnings::warn_user;
 
 use crate::lock::{LockErrorKind, Package, TagPolicy};
 use crate::{Lock, LockError};
@@ -508,12 +509,32 @@ pub trait Installable<'lock> {
         package: &Package,
         tags: &Tags,
         build_options: &BuildOptions,
+        install_options: &InstallOptions,
     ) -> Result<Node, LockError> {
-        let dist = package.to_dist(
+        let dist = match package.to_dist(
             self.install_path(),
             TagPolicy::Required(tags),
             build_options,
-        )?;
+            self.lock().prefer_smaller_wheels(),
+        ) {
+            Ok(dist) => dist,
+            // If requested, fall back to an incompatible wheel.
+            Err(err)
+                if install_options.allow_incompatible_wheels && err.is_incompatible_wheel() =>
+            {
+                warn_user!(
+                    "Installing a wheel for `{}` that is incompatible with the current platform",
+                    package.name()
+                );
+                package.to_dist(
+                    self.install_path(),
+                    TagPolicy::Preferred(tags),
+                    build_options,
+                    self.lock().prefer_smaller_wheels(),
+                )?
+            }
+            Err(err) => return Err(err),
+        };
         let version = package.version().cloned();
         let dist = ResolvedDist::Installable {
             dist: Arc::new(dist),
@@ -533,6 +554,7 @@ pub trait Installable<'lock> {
             self.install_path(),
             TagPolicy::Preferred(tags),
             &BuildOptions::default(),
+            false,
         )?;
         let version = package.version().cloned();
         let dist = ResolvedDist::Installable {
@@ -560,7 +582,7 @@ pub trait Installable<'lock> {
             self.project_name(),
             self.lock().members(),
         ) {
-            self.installable_node(package, tags, build_options)
+            self.installable_node(package, tags, build_options, install_options)
         } else {
             self.non_installable_node(package, tags)
         }
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cmp::Reverse;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::convert::Infallible;
 use std::error::Error;
@@ -19,20 +20,25 @@ use tracing::debug;
 use url::Url;
 
 use uv_cache_key::RepositoryUrl;
-use uv_configuration::BuildOptions;
+use uv_configuration::{BuildOptions, Upgrade};
 use uv_distribution::{DistributionDatabase, FlatRequiresDist};
 use uv_distribution_filename::{
     BuildTag, DistExtension, ExtensionError, SourceDistExtension, WheelFilename,
 };
 use uv_distribution_types::{
     BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
-    Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexUrl, Name,
-    PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
-    RemoteSource, ResolvedDist, StaticMetadata, ToUrlError, UrlString,
+    Dist, DistributionMetadata, FileLocation, GitSourceDist, HashComparison, HgSourceDist,
+    IndexLocations, IndexUrl, Name, PathBuiltDist, PathSourceDist, PrioritizedDist,
+    RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist, RemoteSource, ResolvedDist,
+    SourceDistCompatibility, StaticMetadata, ToUrlError, TorchBackend, UrlString,
+    WheelCompatibility,
 };
 use uv_fs::{relative_to, PortablePath, PortablePathBuf};
-use uv_git::{RepositoryReference, ResolvedRepositoryReference};
-use uv_git_types::{GitOid, GitReference, GitUrl, GitUrlParseError};
+use uv_git::{
+    HgRepositoryReference, RepositoryReference, ResolvedHgRepositoryReference,
+    ResolvedRepositoryReference,
+};
+use uv_git_types::{GitOid, GitReference, GitUrl, GitUrlParseError, HgUrl, HgUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{split_scheme, MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError};
@@ -40,8 +46,8 @@ use uv_platform_tags::{
     AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
 };
 use uv_pypi_types::{
-    redact_credentials, ConflictPackage, Conflicts, HashDigest, HashDigests, ParsedArchiveUrl,
-    ParsedGitUrl, Requirement, RequirementSource,
+    redact_credentials, ConflictPackage, Conflicts, HashDigest, HashDigests, LicensePolicy,
+    ParsedArchiveUrl, ParsedGitUrl, ParsedHgUrl, Requirement, RequirementSource,
 };
 use uv_small_str::SmallString;
 use uv_types::{BuildContext, HashStrategy};
@@ -56,8 +62,8 @@ use crate::requires_python::SimplifiedMarkerTree;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
-    ExcludeNewer, InMemoryIndex, MetadataResponse, PrereleaseMode, RequiresPython, ResolutionMode,
-    ResolverOutput,
+    ExcludeNewer, FlatDistributions, InMemoryIndex, MetadataResponse, PrereleaseMode,
+    RequiresPython, ResolutionMode, ResolverOutput, VersionMap, VersionsResponse,
 };
 
 mod installable;
@@ -69,7 +75,7 @@ mod tree;
 pub const VERSION: u32 = 1;
 
 /// The current revision of the lockfile format.
-const REVISION: u32 = 1;
+const REVISION: u32 = 2;
 
 static LINUX_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 = MarkerTree::from_str("os_name == 'posix' and sys_platform == 'linux'").unwrap();
@@ -280,6 +286,8 @@ impl Lock {
             prerelease_mode: resolution.options.prerelease_mode,
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer: resolution.options.exclude_newer,
+            torch_backend: resolution.options.torch_backend,
+            prefer_smaller_wheels: resolution.options.prefer_smaller_wheels,
         };
         let lock = Self::new(
             VERSION,
@@ -637,6 +645,16 @@ impl Lock {
         self.options.exclude_newer
     }
 
+    /// Returns the PyTorch backend used to generate this lock.
+    pub fn torch_backend(&self) -> Option<TorchBackend> {
+        self.options.torch_backend
+    }
+
+    /// Returns `true` if the smallest compatible wheel should be preferred at install time.
+    pub fn prefer_smaller_wheels(&self) -> bool {
+        self.options.prefer_smaller_wheels
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicts(&self) -> &Conflicts {
         &self.conflicts
@@ -710,6 +728,91 @@ impl Lock {
         self.requires_python.simplify_markers(marker)
     }
 
+    /// Seed the given [`InMemoryIndex`] with the registry distributions pinned in this lock.
+    ///
+    /// Each locked registry package is registered as the only available version on its index, such
+    /// that a subsequent resolution can reuse the prior solution without querying the registry.
+    /// Packages that are being upgraded are omitted, as are distributions that are disallowed by
+    /// the [`BuildOptions`].
+    ///
+    /// If the seeded index is insufficient to satisfy the requirements, the caller is expected to
+    /// fall back to a resolution against an empty index.
+    pub fn seed_index(
+        &self,
+        root: &Path,
+        index: &InMemoryIndex,
+        locations: &IndexLocations,
+        upgrade: &Upgrade,
+        build_options: &BuildOptions,
+    ) -> Result<(), LockError> {
+        let mut distributions: BTreeMap<
+            (PackageName, IndexUrl),
+            BTreeMap<Version, PrioritizedDist>,
+        > = BTreeMap::new();
+
+        for package in &self.packages {
+            let Source::Registry(source) = &package.id.source else {
+                continue;
+            };
+            let Some(version) = package.id.version.as_ref() else {
+                continue;
+            };
+            if upgrade.contains(&package.id.name) {
+                continue;
+            }
+            let Some(url) = package.index(root)? else {
+                continue;
+            };
+
+            let mut dist = PrioritizedDist::default();
+            if !build_options.no_binary_package(&package.id.name) {
+                for wheel in &package.wheels {
+                    let wheel = wheel.to_registry_dist(source, root)?;
+                    let hashes = wheel.file.hashes.iter().cloned().collect::<Vec<_>>();
+                    let build_tag = wheel.filename.build_tag().cloned();
+                    dist.insert_built(
+                        wheel,
+                        hashes,
+                        WheelCompatibility::Compatible(HashComparison::Matched, None, build_tag),
+                    );
+                }
+            }
+            if !build_options.no_build_package(&package.id.name) {
+                if let Some(uv_distribution_types::SourceDist::Registry(sdist)) =
+                    package.to_source_dist(root)?
+                {
+                    let hashes = sdist.file.hashes.iter().cloned().collect::<Vec<_>>();
+                    dist.insert_source(
+                        sdist,
+                        hashes,
+                        SourceDistCompatibility::Compatible(HashComparison::Matched),
+                    );
+                }
+            }
+            if dist.is_empty() {
+                continue;
+            }
+
+            distributions
+                .entry((package.id.name.clone(), url))
+                .or_default()
+                .insert(version.clone(), dist);
+        }
+
+        for ((name, url), versions) in distributions {
+            let response = Arc::new(VersionsResponse::Found(vec![VersionMap::from(
+                FlatDistributions::from(versions),
+            )]));
+            if locations.indexes().any(|index| index.url == url) {
+                index.implicit().done(name, response);
+            } else {
+                index.explicit().done((name, url), response);
+            }
+        }
+
+        Ok(())
+    }
+
     /// If this lockfile was built from a forking resolution with non-identical forks, return the
     /// markers of those forks, otherwise `None`.
     pub fn fork_markers(&self) -> &[UniversalMarker] {
@@ -807,6 +910,12 @@ impl Lock {
             if let Some(exclude_newer) = self.options.exclude_newer {
                 options_table.insert("exclude-newer", value(exclude_newer.to_string()));
             }
+            if let Some(torch_backend) = self.options.torch_backend {
+                options_table.insert("torch-backend", value(torch_backend.to_string()));
+            }
+            if self.options.prefer_smaller_wheels {
+                options_table.insert("prefer-smaller-wheels", value(true));
+            }
 
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
@@ -1375,8 +1484,12 @@ impl Lock {
 
             if let Some(version) = package.id.version.as_ref() {
                 // For a non-dynamic package, fetch the metadata from the distribution database.
-                let dist =
-                    package.to_dist(root, TagPolicy::Preferred(tags), &BuildOptions::default())?;
+                let dist = package.to_dist(
+                    root,
+                    TagPolicy::Preferred(tags),
+                    &BuildOptions::default(),
+                    false,
+                )?;
 
                 let metadata = {
                     let id = dist.version_id();
@@ -1510,6 +1623,7 @@ impl Lock {
                         root,
                         TagPolicy::Preferred(tags),
                         &BuildOptions::default(),
+                        false,
                     )?;
 
                     let metadata = {
@@ -1605,6 +1719,80 @@ impl Lock {
 
         Ok(SatisfiesResult::Satisfied)
     }
+
+    /// Return the locked packages whose license is disallowed by the [`LicensePolicy`], along with
+    /// the offending license expression.
+    ///
+    /// The metadata of each package is read from the distribution database (and thus, from the
+    /// cache, if available), such that an up-to-date lockfile can be checked without resolving.
+    /// Local source trees (e.g., workspace members) are exempt, as in the resolver.
+    pub async fn disallowed_licenses<Context: BuildContext>(
+        &self,
+        root: &Path,
+        licenses: &LicensePolicy,
+        tags: &Tags,
+        hasher: &HashStrategy,
+        index: &InMemoryIndex,
+        database: &DistributionDatabase<'_, Context>,
+    ) -> Result<Vec<(&Package, String)>, LockError> {
+        let mut disallowed = Vec::new();
+        if licenses.is_empty() {
+            return Ok(disallowed);
+        }
+
+        for package in &self.packages {
+            if package.id.source.is_source_tree() || package.id.version.is_none() {
+                continue;
+            }
+
+            let dist = package.to_dist(
+                root,
+                TagPolicy::Preferred(tags),
+                &BuildOptions::default(),
+                false,
+            )?;
+
+            let id = dist.version_id();
+            let metadata = if let Some(archive) = index
+                .distributions()
+                .get(&id)
+                .as_deref()
+                .and_then(|response| {
+                    if let MetadataResponse::Found(archive, ..) = response {
+                        Some(archive)
+                    } else {
+                        None
+                    }
+                }) {
+                archive.metadata.clone()
+            } else {
+                let archive = database
+                    .get_or_build_wheel_metadata(&dist, hasher.get(&dist))
+                    .await
+                    .map_err(|err| LockErrorKind::Resolution {
+                        id: package.id.clone(),
+                        err,
+                    })?;
+
+                let metadata = archive.metadata.clone();
+
+                index
+                    .distributions()
+                    .done(id, Arc::new(MetadataResponse::Found(archive)));
+
+                metadata
+            };
+
+            if let Some(license) = metadata
+                .license_expression
+                .filter(|license| !licenses.permits(license))
+            {
+                disallowed.push((package, license));
+            }
+        }
+
+        Ok(disallowed)
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -1697,6 +1885,11 @@ struct ResolverOptions {
     fork_strategy: ForkStrategy,
     /// The [`ExcludeNewer`] used to generate this lock.
     exclude_newer: Option<ExcludeNewer>,
+    /// The [`TorchBackend`] used to generate this lock.
+    torch_backend: Option<TorchBackend>,
+    /// Whether to prefer the smallest compatible wheel when installing from this lock.
+    #[serde(default)]
+    prefer_smaller_wheels: bool,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
@@ -2076,18 +2269,72 @@ impl Package {
         Ok(())
     }
 
+    /// Convert each of the artifacts of the [`Package`] to a [`Dist`]: one for each locked wheel
+    /// from a registry or direct URL, and one for the source distribution, if any.
+    ///
+    /// Unlike [`Package::to_dist`], wheels aren't filtered by their compatibility with the
+    /// current platform.
+    pub fn to_dists(&self, workspace_root: &Path) -> Result<Vec<Dist>, LockError> {
+        let mut dists = Vec::new();
+        match &self.id.source {
+            Source::Registry(source) => {
+                let wheels = self
+                    .wheels
+                    .iter()
+                    .map(|wheel| wheel.to_registry_dist(source, workspace_root))
+                    .collect::<Result<Vec<_>, LockError>>()?;
+                for best_wheel_index in 0..wheels.len() {
+                    dists.push(Dist::Built(BuiltDist::Registry(RegistryBuiltDist {
+                        wheels: wheels.clone(),
+                        best_wheel_index,
+                        sdist: None,
+                    })));
+                }
+            }
+            Source::Direct(url, direct) => {
+                for wheel in &self.wheels {
+                    let url = Url::from(ParsedArchiveUrl {
+                        url: url.to_url().map_err(LockErrorKind::InvalidUrl)?,
+                        subdirectory: direct.subdirectory.clone(),
+                        ext: DistExtension::Wheel,
+                    });
+                    dists.push(Dist::Built(BuiltDist::DirectUrl(DirectUrlBuiltDist {
+                        filename: wheel.filename.clone(),
+                        location: Box::new(url.clone()),
+                        url: VerbatimUrl::from_url(url),
+                    })));
+                }
+            }
+            Source::Git(..)
+            | Source::Hg(..)
+            | Source::Path(..)
+            | Source::Directory(..)
+            | Source::Editable(..)
+            | Source::Virtual(..) => {}
+        }
+        if let Some(sdist) = self.to_source_dist(workspace_root)? {
+            dists.push(Dist::Source(sdist));
+        }
+        Ok(dists)
+    }
+
     /// Convert the [`Package`] to a [`Dist`] that can be used in installation.
+    ///
+    /// If `prefer_smaller_wheels` is set, the smallest compatible wheel is selected, rather than
+    /// the wheel with the most specific platform tags.
     fn to_dist(
         &self,
         workspace_root: &Path,
         tag_policy: TagPolicy<'_>,
         build_options: &BuildOptions,
+        prefer_smaller_wheels: bool,
     ) -> Result<Dist, LockError> {
         let no_binary = build_options.no_binary_package(&self.id.name);
         let no_build = build_options.no_build_package(&self.id.name);
 
         if !no_binary {
-            if let Some(best_wheel_index) = self.find_best_wheel(tag_policy) {
+            if let Some(best_wheel_index) = self.find_best_wheel(tag_policy, prefer_smaller_wheels)
+            {
                 return match &self.id.source {
                     Source::Registry(source) => {
                         let wheels = self
@@ -2135,6 +2382,11 @@ impl Package {
                         source_type: "Git",
                     }
                     .into()),
+                    Source::Hg(_, _) => Err(LockErrorKind::InvalidWheelSource {
+                        id: self.id.clone(),
+                        source_type: "Mercurial",
+                    }
+                    .into()),
                     Source::Directory(_) => Err(LockErrorKind::InvalidWheelSource {
                         id: self.id.clone(),
                         source_type: "directory",
@@ -2181,17 +2433,52 @@ impl Package {
                 kind: Box::new(LockErrorKind::IncompatibleWheelOnly {
                     id: self.id.clone(),
                 }),
-                hint: self.tag_hint(tag_policy),
+                hints: self.tag_hints(tag_policy),
             }),
             (false, false) => Err(LockError {
                 kind: Box::new(LockErrorKind::NeitherSourceDistNorWheel {
                     id: self.id.clone(),
                 }),
-                hint: self.tag_hint(tag_policy),
+                hints: self.tag_hints(tag_policy),
             }),
         }
     }
 
+    /// Generate the [`LockErrorHint`]s for a package with no compatible wheels, comparing the tags
+    /// of the locked wheels against those supported by the current interpreter.
+    fn tag_hints(&self, tag_policy: TagPolicy<'_>) -> Vec<LockErrorHint> {
+        let mut hints = Vec::new();
+        hints.extend(self.tag_hint(tag_policy));
+        if !self.wheels.is_empty() {
+            let tags = tag_policy.tags();
+            let best = match (tags.python_tag(), tags.abi_tag(), tags.platform_tag()) {
+                (Some(python), Some(abi), Some(platform)) => {
+                    Some(format!("{python}-{abi}-{platform}"))
+                }
+                _ => None,
+            };
+            let wheels = self
+                .wheels
+                .iter()
+                .map(|wheel| {
+                    format!(
+                        "{}-{}-{}",
+                        wheel.filename.python_tags().iter().join("."),
+                        wheel.filename.abi_tags().iter().join("."),
+                        wheel.filename.platform_tags().iter().join("."),
+                    )
+                })
+                .collect();
+            hints.push(LockErrorHint::IncompatibleWheels {
+                package: self.id.name.clone(),
+                version: self.id.version.clone(),
+                wheels,
+                best,
+            });
+        }
+        hints
+    }
+
     /// Generate a [`LockErrorHint`] based on wheel-tag incompatibilities.
     fn tag_hint(&self, tag_policy: TagPolicy<'_>) -> Option<LockErrorHint> {
         let incompatibility = self
@@ -2351,6 +2638,30 @@ impl Package {
                 };
                 uv_distribution_types::SourceDist::Git(git_dist)
             }
+            Source::Hg(url, hg) => {
+                // Remove the fragment and query from the URL; they're already present in the
+                // `HgSource`.
+                let mut url = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
+                url.set_fragment(None);
+                url.set_query(None);
+
+                // Reconstruct the `HgUrl` from the `HgSource`.
+                let hg_url = HgUrl::from_fields(url, hg.revision.clone(), Some(hg.precise))?;
+
+                // Reconstruct the PEP 508-compatible URL from the `HgSource`.
+                let url = Url::from(ParsedHgUrl {
+                    url: hg_url.clone(),
+                    subdirectory: hg.subdirectory.clone(),
+                });
+
+                let hg_dist = HgSourceDist {
+                    name: self.id.name.clone(),
+                    url: VerbatimUrl::from_url(url),
+                    hg: Box::new(hg_url),
+                    subdirectory: hg.subdirectory.clone(),
+                };
+                uv_distribution_types::SourceDist::Hg(hg_dist)
+            }
             Source::Direct(url, direct) => {
                 // A direct URL source can also be a wheel, so validate the extension.
                 let DistExtension::Source(ext) = DistExtension::from_path(url.as_ref())? else {
@@ -2657,8 +2968,12 @@ impl Package {
         })
     }
 
-    fn find_best_wheel(&self, tag_policy: TagPolicy<'_>) -> Option<usize> {
-        type WheelPriority<'lock> = (TagPriority, Option<&'lock BuildTag>);
+    fn find_best_wheel(
+        &self,
+        tag_policy: TagPolicy<'_>,
+        prefer_smaller_wheels: bool,
+    ) -> Option<usize> {
+        type WheelPriority<'lock> = (Reverse<u64>, TagPriority, Option<&'lock BuildTag>);
 
         let mut best: Option<(WheelPriority, usize)> = None;
         for (i, wheel) in self.wheels.iter().enumerate() {
@@ -2668,7 +2983,14 @@ impl Package {
                 continue;
             };
             let build_tag = wheel.filename.build_tag();
-            let wheel_priority = (tag_priority, build_tag);
+            // If requested, rank wheels by size first, treating wheels of unknown size as the
+            // largest.
+            let size = if prefer_smaller_wheels {
+                wheel.size.unwrap_or(u64::MAX)
+            } else {
+                0
+            };
+            let wheel_priority = (Reverse(size), tag_priority, build_tag);
             match best {
                 None => {
                     best = Some((wheel_priority, i));
@@ -2723,6 +3045,24 @@ impl Package {
         }
     }
 
+    /// Returns the URLs of the wheels and source distribution for the package, if it is a remote
+    /// registry source.
+    pub fn registry_urls(&self) -> impl Iterator<Item = &UrlString> {
+        let is_remote = matches!(self.id.source, Source::Registry(RegistrySource::Url(_)));
+        let sdist = match &self.sdist {
+            Some(SourceDist::Url { url, .. }) if is_remote => Some(url),
+            _ => None,
+        };
+        let wheels = self
+            .wheels
+            .iter()
+            .filter_map(move |wheel| match &wheel.url {
+                WheelWireSource::Url { url } if is_remote => Some(url),
+                _ => None,
+            });
+        sdist.into_iter().chain(wheels)
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
@@ -2758,6 +3098,21 @@ impl Package {
         }
     }
 
+    /// Returns the [`ResolvedHgRepositoryReference`] for the package, if it is a Mercurial
+    /// source.
+    pub fn as_hg_ref(&self) -> Result<Option<ResolvedHgRepositoryReference>, LockError> {
+        match &self.id.source {
+            Source::Hg(url, hg) => Ok(Some(ResolvedHgRepositoryReference {
+                reference: HgRepositoryReference {
+                    url: RepositoryUrl::new(&url.to_url().map_err(LockErrorKind::InvalidUrl)?),
+                    revision: hg.revision.clone(),
+                },
+                node: hg.precise,
+            })),
+            _ => Ok(None),
+        }
+    }
+
     /// Returns `true` if the package is a dynamic source tree.
     fn is_dynamic(&self) -> bool {
         self.id.version.is_none()
@@ -3005,6 +3360,8 @@ enum Source {
     Editable(PathBuf),
     /// A path to a local directory that should not be built or installed.
     Virtual(PathBuf),
+    /// A Mercurial repository.
+    Hg(UrlString, HgSource),
 }
 
 impl Source {
@@ -3047,6 +3404,7 @@ impl Source {
             uv_distribution_types::SourceDist::Git(ref git_dist) => {
                 Ok(Source::from_git_dist(git_dist))
             }
+            uv_distribution_types::SourceDist::Hg(ref hg_dist) => Ok(Source::from_hg_dist(hg_dist)),
             uv_distribution_types::SourceDist::Path(ref path_dist) => {
                 Source::from_path_source_dist(path_dist, root)
             }
@@ -3148,14 +3506,28 @@ impl Source {
         )
     }
 
+    fn from_hg_dist(hg_dist: &HgSourceDist) -> Source {
+        Source::Hg(
+            UrlString::from(locked_hg_url(hg_dist)),
+            HgSource {
+                revision: hg_dist.hg.revision().map(ToString::to_string),
+                precise: hg_dist.hg.precise().unwrap_or_else(|| {
+                    panic!("Mercurial distribution is missing a precise changeset: {hg_dist}")
+                }),
+                subdirectory: hg_dist.subdirectory.clone(),
+            },
+        )
+    }
+
     /// Returns `true` if the source should be considered immutable.
     ///
     /// We assume that registry sources are immutable. In other words, we expect that once a
     /// package-version is published to a registry, its metadata will not change.
     ///
-    /// We also assume that Git sources are immutable, since a Git source encodes a specific commit.
+    /// We also assume that Git and Mercurial sources are immutable, since they encode a specific
+    /// commit or changeset.
     fn is_immutable(&self) -> bool {
-        matches!(self, Self::Registry(..) | Self::Git(_, _))
+        matches!(self, Self::Registry(..) | Self::Git(_, _) | Self::Hg(_, _))
     }
 
     /// Returns `true` if the source is that of a wheel.
@@ -3177,6 +3549,7 @@ impl Source {
             Source::Editable(..) => false,
             Source::Virtual(..) => false,
             Source::Git(..) => false,
+            Source::Hg(..) => false,
             Source::Registry(..) => false,
         }
     }
@@ -3185,7 +3558,11 @@ impl Source {
     fn is_source_tree(&self) -> bool {
         match self {
             Source::Directory(..) | Source::Editable(..) | Source::Virtual(..) => true,
-            Source::Path(..) | Source::Git(..) | Source::Registry(..) | Source::Direct(..) => false,
+            Source::Path(..)
+            | Source::Git(..)
+            | Source::Hg(..)
+            | Source::Registry(..)
+            | Source::Direct(..) => false,
         }
     }
 
@@ -3193,7 +3570,11 @@ impl Source {
     fn as_source_tree(&self) -> Option<&Path> {
         match self {
             Source::Directory(path) | Source::Editable(path) | Source::Virtual(path) => Some(path),
-            Source::Path(..) | Source::Git(..) | Source::Registry(..) | Source::Direct(..) => None,
+            Source::Path(..)
+            | Source::Git(..)
+            | Source::Hg(..)
+            | Source::Registry(..)
+            | Source::Direct(..) => None,
         }
     }
 
@@ -3214,6 +3595,9 @@ impl Source {
             Source::Git(ref url, _) => {
                 source_table.insert("git", Value::from(url.as_ref()));
             }
+            Source::Hg(ref url, _) => {
+                source_table.insert("hg", Value::from(url.as_ref()));
+            }
             Source::Direct(ref url, DirectSource { ref subdirectory }) => {
                 source_table.insert("url", Value::from(url.as_ref()));
                 if let Some(ref subdirectory) = *subdirectory {
@@ -3251,6 +3635,7 @@ impl Display for Source {
         match self {
             Source::Registry(RegistrySource::Url(url))
             | Source::Git(url, _)
+            | Source::Hg(url, _)
             | Source::Direct(url, _) => {
                 write!(f, "{}+{}", self.name(), url)
             }
@@ -3270,6 +3655,7 @@ impl Source {
         match *self {
             Self::Registry(..) => "registry",
             Self::Git(..) => "git",
+            Self::Hg(..) => "hg",
             Self::Direct(..) => "direct",
             Self::Path(..) => "path",
             Self::Directory(..) => "directory",
@@ -3289,9 +3675,11 @@ impl Source {
         match *self {
             Self::Registry(..) => None,
             Self::Direct(..) | Self::Path(..) => Some(true),
-            Self::Git(..) | Self::Directory(..) | Self::Editable(..) | Self::Virtual(..) => {
-                Some(false)
-            }
+            Self::Git(..)
+            | Self::Hg(..)
+            | Self::Directory(..)
+            | Self::Editable(..)
+            | Self::Virtual(..) => Some(false),
         }
     }
 }
@@ -3321,6 +3709,9 @@ enum SourceWire {
     Virtual {
         r#virtual: PortablePathBuf,
     },
+    Hg {
+        hg: String,
+    },
 }
 
 impl TryFrom<SourceWire> for Source {
@@ -3363,6 +3754,27 @@ impl TryFrom<SourceWire> for Source {
             Directory { directory } => Ok(Source::Directory(directory.into())),
             Editable { editable } => Ok(Source::Editable(editable.into())),
             Virtual { r#virtual } => Ok(Source::Virtual(r#virtual.into())),
+            Hg { hg } => {
+                let url = Url::parse(&hg)
+                    .map_err(|err| SourceParseError::InvalidUrl {
+                        given: hg.to_string(),
+                        err,
+                    })
+                    .map_err(LockErrorKind::InvalidHgSourceUrl)?;
+
+                let hg_source = HgSource::from_url(&url)
+                    .map_err(|err| match err {
+                        GitSourceError::InvalidSha => SourceParseError::InvalidSha {
+                            given: hg.to_string(),
+                        },
+                        GitSourceError::MissingSha => SourceParseError::MissingSha {
+                            given: hg.to_string(),
+                        },
+                    })
+                    .map_err(LockErrorKind::InvalidHgSourceUrl)?;
+
+                Ok(Source::Hg(UrlString::from(url), hg_source))
+            }
         }
     }
 }
@@ -3491,6 +3903,39 @@ impl GitSource {
     }
 }
 
+/// NOTE: Care should be taken when adding fields to this struct, for the same reasons as
+/// [`GitSource`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+struct HgSource {
+    precise: GitOid,
+    subdirectory: Option<PathBuf>,
+    revision: Option<String>,
+}
+
+impl HgSource {
+    /// Extracts a Mercurial source reference from the query pairs and the changeset fragment in
+    /// the given URL.
+    fn from_url(url: &Url) -> Result<HgSource, GitSourceError> {
+        let mut revision = None;
+        let mut subdirectory = None;
+        for (key, val) in url.query_pairs() {
+            match &*key {
+                "rev" => revision = Some(val.into_owned()),
+                "subdirectory" => subdirectory = Some(PortablePathBuf::from(val.as_ref()).into()),
+                _ => continue,
+            };
+        }
+        let precise = GitOid::from_str(url.fragment().ok_or(GitSourceError::MissingSha)?)
+            .map_err(|_| GitSourceError::InvalidSha)?;
+
+        Ok(HgSource {
+            precise,
+            subdirectory,
+            revision,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize)]
 enum GitSourceKind {
     Tag(String),
@@ -3634,6 +4079,7 @@ impl SourceDist {
             // it's from a registry or a direct URL. Otherwise, it's strictly
             // redundant with the information in all other kinds of `source`.
             uv_distribution_types::SourceDist::Git(_)
+            | uv_distribution_types::SourceDist::Hg(_)
             | uv_distribution_types::SourceDist::Directory(_) => Ok(None),
         }
     }
@@ -3782,6 +4228,7 @@ impl From<GitReference> for GitSourceKind {
         match value {
             GitReference::Branch(branch) => GitSourceKind::Branch(branch.to_string()),
             GitReference::Tag(tag) => GitSourceKind::Tag(tag.to_string()),
+            GitReference::TagRange(range) => GitSourceKind::Tag(range.to_string()),
             GitReference::BranchOrTag(rev) => GitSourceKind::Rev(rev.to_string()),
             GitReference::BranchOrTagOrCommit(rev) => GitSourceKind::Rev(rev.to_string()),
             GitReference::NamedRef(rev) => GitSourceKind::Rev(rev.to_string()),
@@ -3794,7 +4241,7 @@ impl From<GitSourceKind> for GitReference {
     fn from(value: GitSourceKind) -> Self {
         match value {
             GitSourceKind::Branch(branch) => GitReference::Branch(branch),
-            GitSourceKind::Tag(tag) => GitReference::Tag(tag),
+            GitSourceKind::Tag(tag) => GitReference::from_tag(tag),
             GitSourceKind::Rev(rev) => GitReference::from_rev(rev),
             GitSourceKind::DefaultBranch => GitReference::DefaultBranch,
         }
@@ -3829,7 +4276,7 @@ fn locked_git_url(git_dist: &GitSourceDist) -> Url {
         GitReference::Branch(branch) => {
             url.query_pairs_mut().append_pair("branch", branch.as_str());
         }
-        GitReference::Tag(tag) => {
+        GitReference::Tag(tag) | GitReference::TagRange(tag) => {
             url.query_pairs_mut().append_pair("tag", tag.as_str());
         }
         GitReference::BranchOrTag(rev)
@@ -3853,6 +4300,47 @@ fn locked_git_url(git_dist: &GitSourceDist) -> Url {
     url
 }
 
+/// Construct the lockfile-compatible [`URL`] for a [`HgSourceDist`].
+fn locked_hg_url(hg_dist: &HgSourceDist) -> Url {
+    let mut url = hg_dist.hg.repository().clone();
+
+    // Redact the credentials.
+    redact_credentials(&mut url);
+
+    // Clear out any existing state.
+    url.set_fragment(None);
+    url.set_query(None);
+
+    // Put the subdirectory in the query.
+    if let Some(subdirectory) = hg_dist
+        .subdirectory
+        .as_deref()
+        .map(PortablePath::from)
+        .as_ref()
+        .map(PortablePath::to_string)
+    {
+        url.query_pairs_mut()
+            .append_pair("subdirectory", &subdirectory);
+    }
+
+    // Put the requested revision in the query.
+    if let Some(revision) = hg_dist.hg.revision() {
+        url.query_pairs_mut().append_pair("rev", revision);
+    }
+
+    // Put the precise changeset in the fragment.
+    url.set_fragment(
+        hg_dist
+            .hg
+            .precise()
+            .as_ref()
+            .map(GitOid::to_string)
+            .as_deref(),
+    );
+
+    url
+}
+
 /// Inspired by: <https://discuss.python.org/t/lock-files-again-but-this-time-w-sdists/46593>
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
 #[serde(try_from = "WheelWire")]
@@ -4453,6 +4941,48 @@ fn normalize_requirement(
                 origin: None,
             })
         }
+        RequirementSource::Hg {
+            hg,
+            subdirectory,
+            url: _,
+        } => {
+            // Reconstruct the Mercurial URL.
+            let hg = {
+                let mut repository = hg.repository().clone();
+
+                // Redact the credentials.
+                redact_credentials(&mut repository);
+
+                // Remove the fragment and query from the URL; they're already present in the source.
+                repository.set_fragment(None);
+                repository.set_query(None);
+
+                HgUrl::from_fields(
+                    repository,
+                    hg.revision().map(ToString::to_string),
+                    hg.precise(),
+                )?
+            };
+
+            // Reconstruct the PEP 508 URL from the underlying data.
+            let url = Url::from(ParsedHgUrl {
+                url: hg.clone(),
+                subdirectory: subdirectory.clone(),
+            });
+
+            Ok(Requirement {
+                name: requirement.name,
+                extras: requirement.extras,
+                groups: requirement.groups,
+                marker: requirement.marker,
+                source: RequirementSource::Hg {
+                    hg,
+                    subdirectory,
+                    url: VerbatimUrl::from_url(url),
+                },
+                origin: None,
+            })
+        }
         RequirementSource::Path {
             install_path,
             ext,
@@ -4559,7 +5089,7 @@ fn normalize_requirement(
 #[derive(Debug)]
 pub struct LockError {
     kind: Box<LockErrorKind>,
-    hint: Option<LockErrorHint>,
+    hints: Vec<LockErrorHint>,
 }
 
 impl std::error::Error for LockError {
@@ -4571,7 +5101,7 @@ impl std::error::Error for LockError {
 impl std::fmt::Display for LockError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.kind)?;
-        if let Some(hint) = &self.hint {
+        for hint in &self.hints {
             write!(f, "\n\n{hint}")?;
         }
         Ok(())
@@ -4583,6 +5113,16 @@ impl LockError {
     pub fn is_resolution(&self) -> bool {
         matches!(&*self.kind, LockErrorKind::Resolution { .. })
     }
+
+    /// Returns true if the [`LockError`] is due to a package without any compatible wheels (or
+    /// source distribution).
+    fn is_incompatible_wheel(&self) -> bool {
+        matches!(
+            &*self.kind,
+            LockErrorKind::IncompatibleWheelOnly { .. }
+                | LockErrorKind::NeitherSourceDistNorWheel { .. }
+        )
+    }
 }
 
 impl<E> From<E> for LockError
@@ -4592,7 +5132,7 @@ where
     fn from(err: E) -> Self {
         LockError {
             kind: Box::new(LockErrorKind::from(err)),
-            hint: None,
+            hints: Vec::new(),
         }
     }
 }
@@ -4624,6 +5164,15 @@ enum LockErrorHint {
         tags: BTreeSet<PlatformTag>,
         best: Option<PlatformTag>,
     },
+    /// None of the locked wheels for a package are compatible with the current interpreter. Lists
+    /// the tags of each wheel alongside the most specific tag supported by the interpreter (e.g.,
+    /// `cp312-cp312-manylinux_2_17_x86_64`).
+    IncompatibleWheels {
+        package: PackageName,
+        version: Option<Version>,
+        wheels: Vec<String>,
+        best: Option<String>,
+    },
 }
 
 impl std::fmt::Display for LockErrorHint {
@@ -4829,6 +5378,36 @@ impl std::fmt::Display for LockErrorHint {
                     }
                 }
             }
+            Self::IncompatibleWheels {
+                package,
+                version,
+                wheels,
+                best,
+            } => {
+                let s = if wheels.len() == 1 { "" } else { "s" };
+                let package = if let Some(version) = version {
+                    format!("`{}` ({})", package.cyan(), format!("v{version}").cyan())
+                } else {
+                    format!("`{}`", package.cyan())
+                };
+                let wheels = wheels
+                    .iter()
+                    .map(|tag| format!("`{}`", tag.cyan()))
+                    .join(", ");
+                write!(f, "{}{} ", "hint".bold().cyan(), ":".bold())?;
+                if let Some(best) = best {
+                    write!(
+                        f,
+                        "The current interpreter's most specific tag is `{}`, but ",
+                        best.cyan()
+                    )?;
+                }
+                write!(
+                    f,
+                    "{package} is locked with wheel{s} tagged: {wheels}. To install an incompatible wheel anyway, use `{}`",
+                    "uv sync --allow-incompatible-wheels".green()
+                )
+            }
         }
     }
 }
@@ -4905,6 +5484,14 @@ enum LockErrorKind {
         #[source]
         SourceParseError,
     ),
+    /// Failed to parse a Mercurial source URL.
+    #[error("Failed to parse Mercurial URL")]
+    InvalidHgSourceUrl(
+        /// The underlying error that occurred. This includes the
+        /// errant URL in the message.
+        #[source]
+        SourceParseError,
+    ),
     /// An error that occurs when there's an unrecognized dependency.
     ///
     /// That is, a dependency for a package that isn't in the lockfile.
@@ -5126,6 +5713,8 @@ enum LockErrorKind {
     },
     #[error(transparent)]
     GitUrlParse(#[from] GitUrlParseError),
+    #[error(transparent)]
+    HgUrlParse(#[from] HgUrlParseError),
 }
 
 /// An error that occurs when a source string could not be parsed.
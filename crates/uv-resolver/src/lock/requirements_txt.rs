@@ -20,7 +20,7 @@ use uv_fs::Simplified;
 use uv_git_types::GitReference;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
-use uv_pypi_types::{ConflictItem, ParsedArchiveUrl, ParsedGitUrl};
+use uv_pypi_types::{ConflictItem, ParsedArchiveUrl, ParsedGitUrl, ParsedHgUrl};
 
 use crate::graph_ops::{marker_reachability, Reachable};
 use crate::lock::{Package, PackageId, Source};
@@ -327,6 +327,11 @@ impl<'lock> RequirementsTxtExport<'lock> {
             editable,
         })
     }
+
+    /// Returns the packages included in the export.
+    pub fn packages(&self) -> impl Iterator<Item = &'lock Package> + '_ {
+        self.nodes.iter().map(|requirement| requirement.package)
+    }
 }
 
 /// Determine the markers under which a package is reachable in the dependency tree, taking into
@@ -529,6 +534,29 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
 
                     write!(f, "{} @ {}", package.id.name, url)?;
                 }
+                Source::Hg(url, hg) => {
+                    // Remove the fragment and query from the URL; they're already present in the
+                    // `HgSource`.
+                    let mut url = url.to_url().map_err(|_| std::fmt::Error)?;
+                    url.set_fragment(None);
+                    url.set_query(None);
+
+                    // Reconstruct the `HgUrl` from the `HgSource`.
+                    let hg_url = uv_git_types::HgUrl::from_fields(
+                        url,
+                        hg.revision.clone(),
+                        Some(hg.precise),
+                    )
+                    .expect("Internal Mercurial URLs must have supported schemes");
+
+                    // Reconstruct the PEP 508-compatible URL from the `HgSource`.
+                    let url = Url::from(ParsedHgUrl {
+                        url: hg_url,
+                        subdirectory: hg.subdirectory.clone(),
+                    });
+
+                    write!(f, "{} @ {}", package.id.name, url)?;
+                }
                 Source::Direct(url, direct) => {
                     let subdirectory = direct.subdirectory.as_ref().map(PathBuf::from);
                     let url = Url::from(ParsedArchiveUrl {
@@ -11,9 +11,10 @@ use uv_configuration::DependencyGroupsWithDefaults;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::MarkerTree;
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{ConflictSet, ResolverMarkerEnvironment};
 
 use crate::lock::PackageId;
+use crate::universal_marker::UniversalMarker;
 use crate::{Lock, PackageMap};
 
 #[derive(Debug)]
@@ -43,6 +44,33 @@ impl<'env> TreeDisplay<'env> {
         no_dedupe: bool,
         invert: bool,
     ) -> Self {
+        // Identify the conflicting dependency groups, along with whether each is enabled. Since
+        // conflicting groups are encoded in the dependency markers, they determine which of the
+        // (conflicting) dependencies are relevant for the selected groups.
+        let groups = lock
+            .conflicts()
+            .iter()
+            .flat_map(ConflictSet::iter)
+            .filter_map(|item| {
+                let group = item.group()?;
+                Some((item.package(), group, dev.contains(group)))
+            })
+            .collect::<Vec<_>>();
+
+        // Determine whether a dependency is relevant for the selected groups and, if provided,
+        // the marker environment.
+        let included = |marker: UniversalMarker| {
+            let mut marker = marker;
+            for (package, group, enabled) in &groups {
+                if *enabled {
+                    marker.assume_group(package, group);
+                } else {
+                    marker.assume_not_group(package, group);
+                }
+            }
+            !marker.is_false() && markers.is_none_or(|markers| marker.evaluate_no_extras(markers))
+        };
+
         // Create a graph.
         let mut graph = petgraph::graph::Graph::<Node, Edge, petgraph::Directed>::new();
 
@@ -64,9 +92,7 @@ impl<'env> TreeDisplay<'env> {
 
             if dev.prod() {
                 for dependency in &package.dependencies {
-                    if markers.is_some_and(|markers| {
-                        !dependency.complexified_marker.evaluate_no_extras(markers)
-                    }) {
+                    if !included(dependency.complexified_marker) {
                         continue;
                     }
 
@@ -91,9 +117,7 @@ impl<'env> TreeDisplay<'env> {
             if dev.prod() {
                 for (extra, dependencies) in &package.optional_dependencies {
                     for dependency in dependencies {
-                        if markers.is_some_and(|markers| {
-                            !dependency.complexified_marker.evaluate_no_extras(markers)
-                        }) {
+                        if !included(dependency.complexified_marker) {
                             continue;
                         }
 
@@ -120,9 +144,7 @@ impl<'env> TreeDisplay<'env> {
             for (group, dependencies) in &package.dependency_groups {
                 if dev.contains(group) {
                     for dependency in dependencies {
-                        if markers.is_some_and(|markers| {
-                            !dependency.complexified_marker.evaluate_no_extras(markers)
-                        }) {
+                        if !included(dependency.complexified_marker) {
                             continue;
                         }
 
@@ -1,7 +1,13 @@
+use std::collections::BTreeSet;
+
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{
+    DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionBudget, ResolutionMode, YankedPolicy,
+};
 use uv_configuration::{BuildOptions, IndexStrategy};
-use uv_pypi_types::SupportedEnvironments;
+use uv_distribution_types::TorchBackend;
+use uv_normalize::PackageName;
+use uv_pypi_types::{LicensePolicy, SupportedEnvironments};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -15,6 +21,15 @@ pub struct Options {
     pub required_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
+    pub budget: ResolutionBudget,
+    pub yanked: YankedPolicy,
+    pub licenses: LicensePolicy,
+    pub torch_backend: Option<TorchBackend>,
+    pub minimal_changes: bool,
+    pub break_extra_cycles: bool,
+    pub prefer_smaller_wheels: bool,
+    pub exclude_dependencies: BTreeSet<PackageName>,
+    pub prefetch_versions: Option<usize>,
 }
 
 /// Builder for [`Options`].
@@ -29,6 +44,15 @@ pub struct OptionsBuilder {
     required_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
+    budget: ResolutionBudget,
+    yanked: YankedPolicy,
+    licenses: LicensePolicy,
+    torch_backend: Option<TorchBackend>,
+    minimal_changes: bool,
+    break_extra_cycles: bool,
+    prefer_smaller_wheels: bool,
+    exclude_dependencies: BTreeSet<PackageName>,
+    prefetch_versions: Option<usize>,
 }
 
 impl OptionsBuilder {
@@ -100,6 +124,72 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`ResolutionBudget`].
+    #[must_use]
+    pub fn budget(mut self, budget: ResolutionBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Sets the [`YankedPolicy`].
+    #[must_use]
+    pub fn yanked(mut self, yanked: YankedPolicy) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    /// Sets the [`LicensePolicy`].
+    #[must_use]
+    pub fn licenses(mut self, licenses: LicensePolicy) -> Self {
+        self.licenses = licenses;
+        self
+    }
+
+    /// Sets the [`TorchBackend`].
+    #[must_use]
+    pub fn torch_backend(mut self, torch_backend: Option<TorchBackend>) -> Self {
+        self.torch_backend = torch_backend;
+        self
+    }
+
+    /// Sets whether to minimize changes relative to the preferred versions.
+    #[must_use]
+    pub fn minimal_changes(mut self, minimal_changes: bool) -> Self {
+        self.minimal_changes = minimal_changes;
+        self
+    }
+
+    /// Sets whether to break dependency cycles that are introduced through extras.
+    #[must_use]
+    pub fn break_extra_cycles(mut self, break_extra_cycles: bool) -> Self {
+        self.break_extra_cycles = break_extra_cycles;
+        self
+    }
+
+    /// Sets whether to prefer the smallest compatible wheel at install time.
+    #[must_use]
+    pub fn prefer_smaller_wheels(mut self, prefer_smaller_wheels: bool) -> Self {
+        self.prefer_smaller_wheels = prefer_smaller_wheels;
+        self
+    }
+
+    /// Sets the packages that are banned from the resolution.
+    #[must_use]
+    pub fn exclude_dependencies(
+        mut self,
+        exclude_dependencies: impl IntoIterator<Item = PackageName>,
+    ) -> Self {
+        self.exclude_dependencies = exclude_dependencies.into_iter().collect();
+        self
+    }
+
+    /// Sets the maximum number of versions to prefetch for a package at once.
+    #[must_use]
+    pub fn prefetch_versions(mut self, prefetch_versions: Option<usize>) -> Self {
+        self.prefetch_versions = prefetch_versions;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -112,6 +202,15 @@ impl OptionsBuilder {
             required_environments: self.required_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
+            budget: self.budget,
+            yanked: self.yanked,
+            licenses: self.licenses,
+            torch_backend: self.torch_backend,
+            minimal_changes: self.minimal_changes,
+            break_extra_cycles: self.break_extra_cycles,
+            prefer_smaller_wheels: self.prefer_smaller_wheels,
+            exclude_dependencies: self.exclude_dependencies,
+            prefetch_versions: self.prefetch_versions,
         }
     }
 }
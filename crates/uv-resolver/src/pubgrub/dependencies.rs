@@ -7,8 +7,8 @@ use pubgrub::Ranges;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{
-    Conflicts, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedPathUrl, ParsedUrl,
-    Requirement, RequirementSource, VerbatimParsedUrl,
+    Conflicts, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitUrl, ParsedHgUrl, ParsedPathUrl,
+    ParsedUrl, Requirement, RequirementSource, VerbatimParsedUrl,
 };
 
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
@@ -189,6 +189,15 @@ impl PubGrubRequirement {
                     ParsedUrl::Git(ParsedGitUrl::from_source(git.clone(), subdirectory.clone()));
                 (url, parsed_url)
             }
+            RequirementSource::Hg {
+                hg,
+                url,
+                subdirectory,
+            } => {
+                let parsed_url =
+                    ParsedUrl::Hg(ParsedHgUrl::from_source(hg.clone(), subdirectory.clone()));
+                (url, parsed_url)
+            }
             RequirementSource::Path {
                 ext,
                 url,
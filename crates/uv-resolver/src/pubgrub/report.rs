@@ -533,6 +533,7 @@ impl PubGrubReportFormatter<'_> {
         available_indexes: &FxHashMap<PackageName, BTreeSet<IndexUrl>>,
         unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
         incomplete_packages: &FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
+        cached_versions: &FxHashMap<PackageName, BTreeSet<Version>>,
         fork_urls: &ForkUrls,
         fork_indexes: &ForkIndexes,
         env: &ResolverEnvironment,
@@ -561,6 +562,7 @@ impl PubGrubReportFormatter<'_> {
                         available_indexes,
                         unavailable_packages,
                         incomplete_packages,
+                        cached_versions,
                         output_hints,
                     );
 
@@ -580,6 +582,18 @@ impl PubGrubReportFormatter<'_> {
                                 output_hints.insert(PubGrubHint::NoBuild {
                                     package: name.clone(),
                                     option: options.build_options.no_build().clone(),
+                                    binary_only: options.build_options.binary_only(),
+                                });
+                            }
+                            // Check for versions without wheels for the resolved platforms, with
+                            // `--binary-only`.
+                            IncompatibleDist::Wheel(IncompatibleWheel::MissingPlatform(_))
+                                if options.build_options.binary_only() =>
+                            {
+                                output_hints.insert(PubGrubHint::NoBuild {
+                                    package: name.clone(),
+                                    option: options.build_options.no_build().clone(),
+                                    binary_only: true,
                                 });
                             }
                             // Check for unavailable versions due to incompatible tags.
@@ -621,6 +635,7 @@ impl PubGrubReportFormatter<'_> {
                         available_indexes,
                         unavailable_packages,
                         incomplete_packages,
+                        cached_versions,
                         output_hints,
                     );
                 }
@@ -685,6 +700,7 @@ impl PubGrubReportFormatter<'_> {
                     available_indexes,
                     unavailable_packages,
                     incomplete_packages,
+                    cached_versions,
                     fork_urls,
                     fork_indexes,
                     env,
@@ -702,6 +718,7 @@ impl PubGrubReportFormatter<'_> {
                     available_indexes,
                     unavailable_packages,
                     incomplete_packages,
+                    cached_versions,
                     fork_urls,
                     fork_indexes,
                     env,
@@ -819,19 +836,30 @@ impl PubGrubReportFormatter<'_> {
         available_indexes: &FxHashMap<PackageName, BTreeSet<IndexUrl>>,
         unavailable_packages: &FxHashMap<PackageName, UnavailablePackage>,
         incomplete_packages: &FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
+        cached_versions: &FxHashMap<PackageName, BTreeSet<Version>>,
         hints: &mut IndexSet<PubGrubHint>,
     ) {
         let no_find_links = index_locations.flat_indexes().peekable().peek().is_none();
 
         // Add hints due to the package being entirely unavailable.
         match unavailable_packages.get(name) {
+            Some(UnavailablePackage::NoIndex | UnavailablePackage::NotFound)
+                if index_locations.strict_index() =>
+            {
+                hints.insert(PubGrubHint::StrictIndex {
+                    package: name.clone(),
+                });
+            }
             Some(UnavailablePackage::NoIndex) => {
                 if no_find_links {
                     hints.insert(PubGrubHint::NoIndex);
                 }
             }
             Some(UnavailablePackage::Offline) => {
-                hints.insert(PubGrubHint::Offline);
+                hints.insert(PubGrubHint::OfflinePackage {
+                    package: name.clone(),
+                    cached: cached_versions.get(name).cloned().unwrap_or_default(),
+                });
             }
             Some(UnavailablePackage::InvalidMetadata(reason)) => {
                 hints.insert(PubGrubHint::InvalidPackageMetadata {
@@ -851,12 +879,25 @@ impl PubGrubReportFormatter<'_> {
 
         // Add hints due to the package being unavailable at specific versions.
         if let Some(versions) = incomplete_packages.get(name) {
+            let offline = versions
+                .iter()
+                .filter(|(version, incomplete)| {
+                    set.contains(version) && matches!(incomplete, MetadataUnavailable::Offline)
+                })
+                .map(|(version, _)| version.clone())
+                .collect::<BTreeSet<_>>();
+            if !offline.is_empty() {
+                hints.insert(PubGrubHint::OfflineVersions {
+                    package: name.clone(),
+                    versions: offline,
+                    cached: cached_versions.get(name).cloned().unwrap_or_default(),
+                });
+            }
+
             for (version, incomplete) in versions.iter().rev() {
                 if set.contains(version) {
                     match incomplete {
-                        MetadataUnavailable::Offline => {
-                            hints.insert(PubGrubHint::Offline);
-                        }
+                        MetadataUnavailable::Offline => {}
                         MetadataUnavailable::InvalidMetadata(reason) => {
                             hints.insert(PubGrubHint::InvalidVersionMetadata {
                                 package: name.clone(),
@@ -1003,8 +1044,24 @@ pub(crate) enum PubGrubHint {
     /// Requirements were unavailable due to lookups in the index being disabled and no extra
     /// index was provided via `--find-links`
     NoIndex,
-    /// A package was not found in the registry, but network access was disabled.
-    Offline,
+    /// A package was not found on any of the configured indexes, and `--strict-index` prevented
+    /// falling back to PyPI.
+    StrictIndex { package: PackageName },
+    /// The versions of a package were not found in the cache, but network access was disabled.
+    OfflinePackage {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        cached: BTreeSet<Version>,
+    },
+    /// The metadata for versions of a package were not found in the cache, but network access was
+    /// disabled.
+    OfflineVersions {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        versions: BTreeSet<Version>,
+        // excluded from `PartialEq` and `Hash`
+        cached: BTreeSet<Version>,
+    },
     /// Metadata for a package could not be parsed.
     InvalidPackageMetadata {
         package: PackageName,
@@ -1093,6 +1150,8 @@ pub(crate) enum PubGrubHint {
         package: PackageName,
         // excluded from `PartialEq` and `Hash`
         option: NoBuild,
+        // excluded from `PartialEq` and `Hash`
+        binary_only: bool,
     },
     /// No source distributions are available for a package, and using pre-built wheels was disabled.
     NoBinary {
@@ -1149,7 +1208,15 @@ enum PubGrubHintCore {
         package: PackageName,
     },
     NoIndex,
-    Offline,
+    StrictIndex {
+        package: PackageName,
+    },
+    OfflinePackage {
+        package: PackageName,
+    },
+    OfflineVersions {
+        package: PackageName,
+    },
     InvalidPackageMetadata {
         package: PackageName,
     },
@@ -1218,7 +1285,9 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 Self::PrereleaseRequested { package }
             }
             PubGrubHint::NoIndex => Self::NoIndex,
-            PubGrubHint::Offline => Self::Offline,
+            PubGrubHint::StrictIndex { package } => Self::StrictIndex { package },
+            PubGrubHint::OfflinePackage { package, .. } => Self::OfflinePackage { package },
+            PubGrubHint::OfflineVersions { package, .. } => Self::OfflineVersions { package },
             PubGrubHint::InvalidPackageMetadata { package, .. } => {
                 Self::InvalidPackageMetadata { package }
             }
@@ -1320,12 +1389,39 @@ impl std::fmt::Display for PubGrubHint {
                     "--find-links <uri>".green(),
                 )
             }
-            Self::Offline => {
+            Self::StrictIndex { package } => {
                 write!(
                     f,
-                    "{}{} Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.",
+                    "{}{} `{}` was not found on any of the configured indexes, and falling back to PyPI is disabled by `{}`",
                     "hint".bold().cyan(),
                     ":".bold(),
+                    package.cyan(),
+                    "--strict-index".green(),
+                )
+            }
+            Self::OfflinePackage { package, cached } => {
+                write!(
+                    f,
+                    "{}{} The available versions of `{}` weren't found in the cache, and listing them requires network access, which was disabled. {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    CachedVersions { package, cached },
+                )
+            }
+            Self::OfflineVersions {
+                package,
+                versions,
+                cached,
+            } => {
+                write!(
+                    f,
+                    "{}{} Resolving the dependencies of `{}` ({}) requires its metadata, which wasn't found in the cache, and the network was disabled. {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    format_versions(versions),
+                    CachedVersions { package, cached },
                 )
             }
             Self::InvalidPackageMetadata { package, reason } => {
@@ -1539,8 +1635,15 @@ impl std::fmt::Display for PubGrubHint {
                     "403 Forbidden".red(),
                 )
             }
-            Self::NoBuild { package, option } => {
+            Self::NoBuild {
+                package,
+                option,
+                binary_only,
+            } => {
                 let option = match option {
+                    NoBuild::All if *binary_only => {
+                        "for all packages (i.e., with `--binary-only`)".to_string()
+                    }
                     NoBuild::All => "for all packages (i.e., with `--no-build`)".to_string(),
                     NoBuild::Packages(_) => {
                         format!("for `{package}` (i.e., with `--no-build-package {package}`)")
@@ -2074,3 +2177,41 @@ impl<T: std::fmt::Display> std::fmt::Display for Padded<'_, T> {
         write!(f, "{result}")
     }
 }
+
+/// The maximum number of versions to list in a hint.
+const MAX_LISTED_VERSIONS: usize = 3;
+
+/// Format the given versions in descending order, truncated to [`MAX_LISTED_VERSIONS`].
+fn format_versions(versions: &BTreeSet<Version>) -> String {
+    let listed = versions.iter().rev().take(MAX_LISTED_VERSIONS).join(", ");
+    if versions.len() > MAX_LISTED_VERSIONS {
+        format!(
+            "{listed}, and {} more",
+            versions.len() - MAX_LISTED_VERSIONS
+        )
+    } else {
+        listed
+    }
+}
+
+/// The versions of a package that are available in the cache, for display in an offline hint.
+#[derive(Debug)]
+struct CachedVersions<'a> {
+    package: &'a PackageName,
+    cached: &'a BTreeSet<Version>,
+}
+
+impl std::fmt::Display for CachedVersions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.cached.is_empty() {
+            write!(f, "No versions of `{}` are cached.", self.package.cyan())
+        } else {
+            write!(
+                f,
+                "Only the following versions of `{}` are cached: {}.",
+                self.package.cyan(),
+                format_versions(self.cached)
+            )
+        }
+    }
+}
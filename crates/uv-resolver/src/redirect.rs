@@ -1,11 +1,40 @@
 use url::Url;
 
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_pep508::VerbatimUrl;
-use uv_pypi_types::{ParsedGitUrl, ParsedUrl, VerbatimParsedUrl};
+use uv_pypi_types::{ParsedGitUrl, ParsedHgUrl, ParsedUrl, VerbatimParsedUrl};
 
 /// Map a URL to a precise URL, if possible.
-pub(crate) fn url_to_precise(url: VerbatimParsedUrl, git: &GitResolver) -> VerbatimParsedUrl {
+pub(crate) fn url_to_precise(
+    url: VerbatimParsedUrl,
+    git: &GitResolver,
+    hg: &HgResolver,
+) -> VerbatimParsedUrl {
+    if let ParsedUrl::Hg(ParsedHgUrl {
+        url: hg_url,
+        subdirectory,
+    }) = &url.parsed_url
+    {
+        let Some(new_hg_url) = hg.precise(hg_url.clone()) else {
+            if cfg!(debug_assertions) {
+                panic!("Unresolved Mercurial URL: {}, {hg_url:?}", url.verbatim);
+            } else {
+                return url;
+            }
+        };
+
+        let new_parsed_url = ParsedHgUrl {
+            url: new_hg_url,
+            subdirectory: subdirectory.clone(),
+        };
+        let new_url = Url::from(new_parsed_url.clone());
+        let new_verbatim_url = apply_redirect(&url.verbatim, new_url);
+        return VerbatimParsedUrl {
+            parsed_url: ParsedUrl::Hg(new_parsed_url),
+            verbatim: new_verbatim_url,
+        };
+    }
+
     let ParsedUrl::Git(ParsedGitUrl {
         url: git_url,
         subdirectory,
@@ -1,11 +1,12 @@
 use std::collections::Bound;
+use std::str::FromStr;
 
 use pubgrub::Range;
 
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::{
-    release_specifiers_to_ranges, LowerBound, UpperBound, Version, VersionSpecifier,
-    VersionSpecifiers,
+    release_specifiers_to_ranges, LowerBound, UpperBound, Version, VersionParseError,
+    VersionSpecifier, VersionSpecifiers, VersionSpecifiersParseError,
 };
 use uv_pep508::{MarkerExpression, MarkerTree, MarkerValueVersion};
 use uv_platform_tags::{AbiTag, LanguageTag};
@@ -532,6 +533,117 @@ impl<'de> serde::Deserialize<'de> for RequiresPython {
     }
 }
 
+/// A range of Python versions to target during universal resolution, e.g., `3.9..3.12`.
+///
+/// Accepts either a range of the form `<lower>..<upper>`, in which both bounds are inclusive at
+/// the given precision (such that `3.9..3.12` includes all 3.12 patch releases), or a set of PEP
+/// 440 version specifiers (e.g., `>=3.9, <3.13`). Either bound of a range may be omitted.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SupportedPython {
+    source: String,
+    specifiers: VersionSpecifiers,
+}
+
+impl SupportedPython {
+    /// Returns the [`VersionSpecifiers`] for the range.
+    pub fn specifiers(&self) -> &VersionSpecifiers {
+        &self.specifiers
+    }
+}
+
+impl FromStr for SupportedPython {
+    type Err = SupportedPythonError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let specifiers = if let Some((lower, upper)) = source.split_once("..") {
+            let parse = |bound: &str| -> Result<Option<Version>, Self::Err> {
+                let bound = bound.trim();
+                if bound.is_empty() {
+                    return Ok(None);
+                }
+                Version::from_str(bound)
+                    .map(|version| Some(version.only_release()))
+                    .map_err(|err| SupportedPythonError::InvalidBound(bound.to_string(), err))
+            };
+            let lower = parse(lower)?;
+            let upper = parse(upper)?;
+            if lower.is_none() && upper.is_none() {
+                return Err(SupportedPythonError::Unbounded(source.to_string()));
+            }
+            lower
+                .map(VersionSpecifier::greater_than_equal_version)
+                .into_iter()
+                .chain(upper.map(|upper| {
+                    // Treat the upper bound as inclusive at the given precision, such that
+                    // `..3.12` is equivalent to `<3.13`.
+                    let mut release = upper.release().to_vec();
+                    if let Some(last) = release.last_mut() {
+                        *last += 1;
+                    }
+                    VersionSpecifier::less_than_version(Version::new(release))
+                }))
+                .collect()
+        } else {
+            VersionSpecifiers::from_str(source)
+                .map_err(|err| SupportedPythonError::InvalidSpecifiers(source.to_string(), err))?
+        };
+        Ok(Self {
+            source: source.to_string(),
+            specifiers,
+        })
+    }
+}
+
+impl std::fmt::Display for SupportedPython {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl serde::Serialize for SupportedPython {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SupportedPython {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SupportedPython {
+    fn schema_name() -> String {
+        String::from("SupportedPython")
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A range of Python versions, e.g., `3.9..3.12` or `>=3.9, <3.13`.".to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SupportedPythonError {
+    #[error("Invalid bound in Python version range: `{0}`")]
+    InvalidBound(String, #[source] VersionParseError),
+    #[error("Python version range must have at least one bound: `{0}`")]
+    Unbounded(String),
+    #[error("Invalid Python version range: `{0}`")]
+    InvalidSpecifiers(String, #[source] VersionSpecifiersParseError),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct RequiresPythonRange(LowerBound, UpperBound);
 
@@ -633,7 +745,25 @@ mod tests {
     use uv_distribution_filename::WheelFilename;
     use uv_pep440::{LowerBound, UpperBound, Version, VersionSpecifiers};
 
-    use crate::RequiresPython;
+    use crate::{RequiresPython, SupportedPython};
+
+    #[test]
+    fn supported_python() {
+        let range = SupportedPython::from_str("3.9..3.12").unwrap();
+        assert_eq!(range.specifiers().to_string(), ">=3.9, <3.13");
+
+        let range = SupportedPython::from_str("3.10..").unwrap();
+        assert_eq!(range.specifiers().to_string(), ">=3.10");
+
+        let range = SupportedPython::from_str("..3.12.4").unwrap();
+        assert_eq!(range.specifiers().to_string(), "<3.12.5");
+
+        let range = SupportedPython::from_str(">=3.9, <3.12").unwrap();
+        assert_eq!(range.specifiers().to_string(), ">=3.9, <3.12");
+
+        assert!(SupportedPython::from_str("..").is_err());
+        assert!(SupportedPython::from_str("3.9..foo").is_err());
+    }
 
     #[test]
     fn requires_python_included() {
@@ -60,6 +60,7 @@ impl AnnotatedDist {
                     SourceDist::Registry(dist) => Some(&dist.index),
                     SourceDist::DirectUrl(_) => None,
                     SourceDist::Git(_) => None,
+                    SourceDist::Hg(_) => None,
                     SourceDist::Path(_) => None,
                     SourceDist::Directory(_) => None,
                 },
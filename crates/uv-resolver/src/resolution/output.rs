@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
@@ -7,22 +7,25 @@ use petgraph::{
     graph::{Graph, NodeIndex},
     Directed, Direction,
 };
+use pubgrub::Ranges;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use uv_configuration::{Constraints, Overrides};
 use uv_distribution::Metadata;
 use uv_distribution_types::{
-    Dist, DistributionMetadata, Edge, IndexUrl, Name, Node, ResolutionDiagnostic, ResolvedDist,
-    VersionId, VersionOrUrlRef,
+    BuiltDist, DerivationChain, DerivationStep, Dist, DistributionMetadata, Edge, IndexUrl, Name,
+    Node, ResolutionDiagnostic, ResolvedDist, SourceDist, Verbatim, VersionId, VersionOrUrlRef,
 };
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
-use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
+use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind, VerbatimUrl};
 use uv_pypi_types::{
-    Conflicts, HashDigests, ParsedUrlError, Requirement, VerbatimParsedUrl, Yanked,
+    Conflicts, HashDigests, Hashes, LicenseMode, LicensePolicy, ParsedUrlError, Requirement,
+    VerbatimParsedUrl, Yanked,
 };
 
+use crate::fork_report::{ForkReport, ResolvedFork};
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
 use crate::preferences::Preferences;
@@ -59,6 +62,8 @@ pub struct ResolverOutput {
     pub(crate) overrides: Overrides,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// The forks that were created during resolution.
+    pub(crate) forks: ForkReport,
 }
 
 #[derive(Debug, Clone)]
@@ -132,6 +137,7 @@ impl ResolverOutput {
         preferences: &Preferences,
         index: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         python: &PythonRequirement,
         conflicts: &Conflicts,
         resolution_strategy: &ResolutionStrategy,
@@ -163,6 +169,7 @@ impl ResolverOutput {
                     &resolution.pins,
                     index,
                     git,
+                    hg,
                     package,
                     version,
                 )?;
@@ -230,6 +237,36 @@ impl ResolverOutput {
             report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides);
         }
 
+        // In `warn` mode, disallowed licenses are accepted by the resolver, but reported here.
+        if options.licenses.mode() == LicenseMode::Warn {
+            report_disallowed_licenses(&graph, &mut diagnostics, &options.licenses);
+        }
+
+        // Omit warnings for yanked versions that the user opted into.
+        diagnostics.retain(|diagnostic| match diagnostic {
+            ResolutionDiagnostic::YankedVersion { dist, .. } => dist
+                .version()
+                .is_none_or(|version| options.yanked.should_warn(dist.name(), version)),
+            _ => true,
+        });
+
+        // Record the fork points that led to each resolution.
+        let forks = ForkReport::new(
+            resolutions
+                .iter()
+                .map(|resolution| ResolvedFork {
+                    marker: resolution.env.try_universal_markers().unwrap_or_default(),
+                    history: resolution.fork_history.clone(),
+                    versions: resolution
+                        .nodes
+                        .iter()
+                        .filter(|(package, _)| package.extra.is_none() && package.dev.is_none())
+                        .map(|(package, version)| (package.name.clone(), version.clone()))
+                        .collect(),
+                })
+                .collect(),
+        );
+
         let output = Self {
             graph,
             requires_python,
@@ -239,6 +276,7 @@ impl ResolverOutput {
             overrides: overrides.clone(),
             options,
             fork_markers,
+            forks,
         };
 
         // We only do conflicting distribution detection when no
@@ -334,6 +372,7 @@ impl ResolverOutput {
         pins: &FilePins,
         in_memory: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         package: &'a ResolutionPackage,
         version: &'a Version,
     ) -> Result<(), ResolveError> {
@@ -355,6 +394,7 @@ impl ResolverOutput {
             preferences,
             in_memory,
             git,
+            hg,
         )?;
 
         if let Some(metadata) = metadata.as_ref() {
@@ -414,10 +454,11 @@ impl ResolverOutput {
         preferences: &Preferences,
         in_memory: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
     ) -> Result<(ResolvedDist, HashDigests, Option<Metadata>), ResolveError> {
         Ok(if let Some(url) = url {
             // Create the distribution.
-            let dist = Dist::from_url(name.clone(), url_to_precise(url.clone(), git))?;
+            let dist = Dist::from_url(name.clone(), url_to_precise(url.clone(), git, hg))?;
 
             let version_id = VersionId::from_url(&url.verbatim);
 
@@ -620,6 +661,103 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Return a [`ForkReport`] describing the forks that were created during resolution.
+    pub fn fork_report(&self) -> &ForkReport {
+        &self.forks
+    }
+
+    /// Validate that every distribution that wasn't resolved from a package index includes a
+    /// hash.
+    ///
+    /// Direct URL distributions must include a hash fragment (e.g., `#sha256=...`), which must
+    /// match the hash of the downloaded artifact. Distributions resolved from any of the given
+    /// `--find-links` locations must include a hash for every file. Git and local path
+    /// distributions are exempt.
+    pub fn require_hashes(&self, flat_indexes: &[&IndexUrl]) -> Result<(), ResolveError> {
+        for annotated in self.dists() {
+            let ResolvedDist::Installable { dist, .. } = &annotated.dist else {
+                continue;
+            };
+            match &**dist {
+                Dist::Built(BuiltDist::DirectUrl(built)) => {
+                    Self::require_url_hash(annotated, &built.url)?;
+                }
+                Dist::Source(SourceDist::DirectUrl(source)) => {
+                    Self::require_url_hash(annotated, &source.url)?;
+                }
+                Dist::Built(BuiltDist::Registry(built)) => {
+                    let missing = built
+                        .wheels
+                        .iter()
+                        .map(|wheel| (&wheel.index, &wheel.file))
+                        .chain(built.sdist.iter().map(|sdist| (&sdist.index, &sdist.file)))
+                        .any(|(index, file)| {
+                            flat_indexes.contains(&index) && file.hashes.is_empty()
+                        });
+                    if missing {
+                        return Err(ResolveError::MissingFlatIndexHash(format!(
+                            "{}=={}",
+                            annotated.name, annotated.version
+                        )));
+                    }
+                }
+                Dist::Source(SourceDist::Registry(source)) => {
+                    if flat_indexes.contains(&&source.index) && source.file.hashes.is_empty() {
+                        return Err(ResolveError::MissingFlatIndexHash(format!(
+                            "{}=={}",
+                            annotated.name, annotated.version
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate that the given direct URL includes a hash fragment that matches the hash of the
+    /// downloaded artifact.
+    fn require_url_hash(annotated: &AnnotatedDist, url: &VerbatimUrl) -> Result<(), ResolveError> {
+        let expected = url
+            .fragment()
+            .and_then(|fragment| Hashes::parse_fragment(fragment).ok())
+            .map(HashDigests::from)
+            .unwrap_or_else(HashDigests::empty);
+        if expected.is_empty() {
+            return Err(ResolveError::MissingUrlHash(format!(
+                "{} @ {}",
+                annotated.name,
+                url.verbatim()
+            )));
+        }
+
+        // Only compare digests for which the artifact hash was computed with the same algorithm.
+        let comparable = expected
+            .iter()
+            .filter(|digest| {
+                annotated
+                    .hashes
+                    .iter()
+                    .any(|actual| actual.algorithm() == digest.algorithm())
+            })
+            .collect::<Vec<_>>();
+        if !comparable.is_empty()
+            && !comparable
+                .iter()
+                .any(|digest| annotated.hashes.iter().any(|actual| actual == *digest))
+        {
+            return Err(ResolveError::Distribution(
+                uv_distribution::Error::hash_mismatch(
+                    annotated.dist.to_string(),
+                    expected.as_slice(),
+                    annotated.hashes.as_slice(),
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -954,6 +1092,85 @@ fn report_missing_lower_bounds(
     }
 }
 
+/// Find any packages whose license is disallowed by the [`LicensePolicy`].
+fn report_disallowed_licenses(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker>,
+    diagnostics: &mut Vec<ResolutionDiagnostic>,
+    licenses: &LicensePolicy,
+) {
+    if licenses.is_empty() {
+        return;
+    }
+    for node_index in graph.node_indices() {
+        let ResolutionGraphNode::Dist(dist) = &graph[node_index] else {
+            continue;
+        };
+        if !dist.is_base() {
+            continue;
+        }
+        // Ignore local source trees (e.g., workspace members).
+        if let ResolvedDist::Installable { dist, .. } = &dist.dist {
+            if matches!(dist.as_ref(), Dist::Source(SourceDist::Directory(_))) {
+                continue;
+            }
+        }
+        let Some(license) = dist
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.license_expression.as_ref())
+        else {
+            continue;
+        };
+        if licenses.permits(license) {
+            continue;
+        }
+        diagnostics.push(ResolutionDiagnostic::DisallowedLicense {
+            dist: dist.dist.clone(),
+            license: license.clone(),
+            chain: derivation_chain(graph, node_index),
+        });
+    }
+}
+
+/// Compute the shortest chain of dependencies from the root to the given node.
+fn derivation_chain(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker>,
+    target: NodeIndex,
+) -> DerivationChain {
+    let mut queue = VecDeque::new();
+    queue.push_back((target, Vec::new()));
+
+    let mut seen = FxHashSet::default();
+    while let Some((node, mut path)) = queue.pop_front() {
+        if !seen.insert(node) {
+            continue;
+        }
+        match &graph[node] {
+            ResolutionGraphNode::Root => {
+                path.reverse();
+                return DerivationChain::from_iter(path);
+            }
+            ResolutionGraphNode::Dist(dist) => {
+                for parent in graph.neighbors_directed(node, Direction::Incoming) {
+                    let mut path = path.clone();
+                    if node != target {
+                        path.push(DerivationStep::new(
+                            dist.name.clone(),
+                            dist.extra.clone(),
+                            dist.dev.clone(),
+                            Some(dist.version.clone()),
+                            Ranges::empty(),
+                        ));
+                    }
+                    queue.push_back((parent, path));
+                }
+            }
+        }
+    }
+
+    DerivationChain::default()
+}
+
 /// Whether the given package has a lower version bound by another package.
 fn has_lower_bound(
     node_index: NodeIndex,
@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 
+use crate::fork_report::ForkCause;
 use crate::resolver::{MetadataUnavailable, VersionFork};
 use uv_distribution_types::IncompatibleDist;
 use uv_pep440::{Version, VersionSpecifiers};
@@ -43,6 +44,8 @@ pub(crate) enum UnavailableVersion {
     /// The source distribution has a `requires-python` requirement that is not met by the installed
     /// Python version (and static metadata is not available).
     RequiresPython(VersionSpecifiers),
+    /// The distribution declares a license that is disallowed by the license policy.
+    DisallowedLicense(String),
 }
 
 impl UnavailableVersion {
@@ -56,6 +59,9 @@ impl UnavailableVersion {
             UnavailableVersion::RequiresPython(requires_python) => {
                 format!("Python {requires_python}")
             }
+            UnavailableVersion::DisallowedLicense(license) => {
+                format!("a disallowed license (`{license}`)")
+            }
         }
     }
 
@@ -67,6 +73,7 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => format!("has {self}"),
             UnavailableVersion::Offline => format!("needs {self}"),
             UnavailableVersion::RequiresPython(..) => format!("requires {self}"),
+            UnavailableVersion::DisallowedLicense(..) => format!("has {self}"),
         }
     }
 
@@ -78,6 +85,7 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => format!("have {self}"),
             UnavailableVersion::Offline => format!("need {self}"),
             UnavailableVersion::RequiresPython(..) => format!("require {self}"),
+            UnavailableVersion::DisallowedLicense(..) => format!("have {self}"),
         }
     }
 
@@ -95,6 +103,7 @@ impl UnavailableVersion {
             UnavailableVersion::InvalidStructure => None,
             UnavailableVersion::Offline => None,
             UnavailableVersion::RequiresPython(..) => None,
+            UnavailableVersion::DisallowedLicense(..) => None,
         }
     }
 }
@@ -187,5 +196,5 @@ pub(crate) enum ResolverVersion {
     /// A usable version
     Unforked(Version),
     /// A set of forks, optionally with resolved versions
-    Forked(Vec<VersionFork>),
+    Forked(Vec<VersionFork>, ForkCause),
 }
@@ -18,6 +18,9 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 
+/// The default maximum number of versions to prefetch for a package at once.
+const DEFAULT_PREFETCH_VERSIONS: usize = 50;
+
 enum BatchPrefetchStrategy {
     /// Go through the next versions assuming the existing selection and its constraints
     /// remain.
@@ -45,6 +48,8 @@ pub(crate) struct BatchPrefetcher {
     // Types to determine whether we need to prefetch.
     tried_versions: FxHashMap<PackageName, FxHashSet<Version>>,
     last_prefetch: FxHashMap<PackageName, usize>,
+    /// The maximum number of versions to prefetch for a package at once.
+    max_prefetch: usize,
     // Types to execute the prefetch.
     prefetch_runner: BatchPrefetcherRunner,
 }
@@ -62,6 +67,7 @@ pub(crate) struct BatchPrefetcherRunner {
 
 impl BatchPrefetcher {
     pub(crate) fn new(
+        max_prefetch: Option<usize>,
         capabilities: IndexCapabilities,
         index: InMemoryIndex,
         request_sink: Sender<Request>,
@@ -69,6 +75,7 @@ impl BatchPrefetcher {
         Self {
             tried_versions: FxHashMap::default(),
             last_prefetch: FxHashMap::default(),
+            max_prefetch: max_prefetch.unwrap_or(DEFAULT_PREFETCH_VERSIONS),
             prefetch_runner: BatchPrefetcherRunner {
                 capabilities,
                 index,
@@ -100,10 +107,10 @@ impl BatchPrefetcher {
         };
 
         let (num_tried, do_prefetch) = self.should_prefetch(next);
-        if !do_prefetch {
+        if !do_prefetch || self.max_prefetch == 0 {
             return Ok(());
         }
-        let total_prefetch = min(num_tried, 50);
+        let total_prefetch = min(num_tried, self.max_prefetch);
 
         // This is immediate, we already fetched the version map.
         let versions_response = if let Some(index) = index {
@@ -160,8 +167,9 @@ impl BatchPrefetcher {
     }
 
     /// After 5, 10, 20, 40 tried versions, prefetch that many versions to start early but not
-    /// too aggressive. Later we schedule the prefetch of 50 versions every 20 versions, this gives
-    /// us a good buffer until we see prefetch again and is high enough to saturate the task pool.
+    /// too aggressive. Later we schedule the prefetch of up to 50 versions (by default) every 20
+    /// versions, this gives us a good buffer until we see prefetch again and is high enough to
+    /// saturate the task pool.
     fn should_prefetch(&self, next: &PubGrubPackage) -> (usize, bool) {
         let PubGrubPackageInner::Package {
             name,
@@ -182,6 +190,13 @@ impl BatchPrefetcher {
         (num_tried, do_prefetch)
     }
 
+    /// Returns the number of distinct versions tried for each package.
+    pub(crate) fn tried_versions(&self) -> impl Iterator<Item = (&PackageName, usize)> {
+        self.tried_versions
+            .iter()
+            .map(|(name, versions)| (name, versions.len()))
+    }
+
     /// Log stats about how many versions we tried.
     pub(crate) fn log_tried_versions(&self) {
         let total_versions: usize = self.tried_versions.values().map(FxHashSet::len).sum();
@@ -4,6 +4,7 @@ use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_pypi_types::{ConflictItem, ConflictItemRef, ResolverMarkerEnvironment};
 
+use crate::fork_report::ForkCause;
 use crate::pubgrub::{PubGrubDependency, PubGrubPackage};
 use crate::requires_python::RequiresPythonRange;
 use crate::resolver::ForkState;
@@ -335,7 +336,9 @@ impl ResolverEnvironment {
                         .chain(exclude.into_iter().map(Err)),
                 )?;
                 env = env.narrow_environment(combined.pep508());
-                Some(Ok(init.clone().with_env(env)))
+                let mut state = init.clone();
+                state.record_fork(ForkCause::Initial, &env);
+                Some(Ok(state.with_env(env)))
             })
             .collect()
     }
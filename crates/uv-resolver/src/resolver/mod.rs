@@ -6,7 +6,7 @@ use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
 use std::ops::Bound;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{iter, slice, thread};
 
 use dashmap::DashMap;
@@ -28,19 +28,23 @@ use uv_distribution_types::{
     IndexUrl, InstalledDist, PythonRequirementKind, RemoteSource, ResolvedDist, ResolvedDistRef,
     SourceDist, VersionOrUrlRef,
 };
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{release_specifiers_to_ranges, Version, VersionSpecifiers, MIN_VERSION};
 use uv_pep508::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString};
 use uv_platform_tags::Tags;
-use uv_pypi_types::{ConflictItem, ConflictItemRef, Conflicts, Requirement, VerbatimParsedUrl};
+use uv_pypi_types::{
+    ConflictItem, ConflictItemRef, Conflicts, LicenseMode, ParsedUrl, Requirement,
+    VerbatimParsedUrl,
+};
 use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
+use crate::error::{BacktrackHotspot, BudgetExceededError, NoSolutionError, ResolveError};
 use crate::fork_indexes::ForkIndexes;
+use crate::fork_report::{ForkCause, ForkPoint};
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
@@ -75,7 +79,9 @@ pub use crate::resolver::provider::{
 };
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::yanks::AllowedYanks;
-use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode, VersionMap};
+use crate::{
+    marker, BudgetLimit, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode, VersionMap,
+};
 
 mod availability;
 mod batch_prefetch;
@@ -105,6 +111,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     overrides: Overrides,
     preferences: Preferences,
     git: GitResolver,
+    hg: HgResolver,
     capabilities: IndexCapabilities,
     locations: IndexLocations,
     exclusions: Exclusions,
@@ -170,9 +177,10 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
+            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode, &options.yanked),
             hasher,
             options.exclude_newer,
+            build_context.locations(),
             build_context.build_options(),
             build_context.capabilities(),
         );
@@ -187,6 +195,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             conflicts,
             index,
             build_context.git(),
+            build_context.hg(),
             build_context.capabilities(),
             build_context.locations(),
             provider,
@@ -209,6 +218,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         conflicts: Conflicts,
         index: &InMemoryIndex,
         git: &GitResolver,
+        hg: &HgResolver,
         capabilities: &IndexCapabilities,
         locations: &IndexLocations,
         provider: Provider,
@@ -217,10 +227,11 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let state = ResolverState {
             index: index.clone(),
             git: git.clone(),
+            hg: hg.clone(),
             capabilities: capabilities.clone(),
             selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
-            urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode),
+            urls: Urls::from_manifest(&manifest, &env, git, hg, options.dependency_mode),
             indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
             project: manifest.project,
             workspace_members: manifest.workspace_members,
@@ -290,7 +301,17 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
 
         state.on_complete();
-        resolution
+
+        // If the resolution failed due to packages that were missing from the cache while the
+        // network was disabled, report the versions of those packages that _are_ cached.
+        match resolution {
+            Err(ResolveError::NoSolution(err)) => {
+                Err(ResolveError::NoSolution(err.with_cached_versions(|name| {
+                    provider.cached_versions(name)
+                })))
+            }
+            resolution => resolution,
+        }
     }
 }
 
@@ -314,6 +335,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let root = PubGrubPackage::from(PubGrubPackageInner::Root(self.project.clone()));
         let pubgrub = State::init(root.clone(), MIN_VERSION.clone());
         let prefetcher = BatchPrefetcher::new(
+            self.options.prefetch_versions,
             self.capabilities.clone(),
             self.index.clone(),
             request_sink.clone(),
@@ -328,6 +350,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
 
+        // Track the work performed across all forks, to enforce the resolution budget.
+        let solve_start = Instant::now();
+        let mut iterations = 0usize;
+
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
                 let requires_python = state.python_requirement.target();
@@ -335,6 +361,18 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                iterations += 1;
+                if let Some(limit) = self
+                    .options
+                    .budget
+                    .exceeded(iterations, || solve_start.elapsed())
+                {
+                    state.prefetcher.log_tried_versions();
+                    return Err(state
+                        .into_budget_exceeded_error(limit, iterations, solve_start.elapsed())
+                        .into());
+                }
+
                 let highest_priority_pkg =
                     if let Some(initial) = state.initial_id.take() {
                         // If we just forked based on `requires-python`, we can skip unit
@@ -544,8 +582,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                     let version = match version {
                         ResolverVersion::Unforked(version) => version,
-                        ResolverVersion::Forked(forks) => {
-                            forked_states.extend(self.version_forks_to_fork_states(state, forks));
+                        ResolverVersion::Forked(forks, cause) => {
+                            forked_states
+                                .extend(self.version_forks_to_fork_states(state, forks, cause));
                             continue 'FORK;
                         }
                         ResolverVersion::Unavailable(version, reason) => {
@@ -636,6 +675,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             &self.indexes,
                             dependencies,
                             &self.git,
+                            &self.hg,
                             &self.workspace_members,
                             self.selector.resolution_strategy(),
                         )?;
@@ -711,6 +751,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.preferences,
             &self.index,
             &self.git,
+            &self.hg,
             &self.python_requirement,
             &self.conflicts,
             self.selector.resolution_strategy(),
@@ -841,6 +882,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         // into `forked_states`, and then only clone it if
         // there is at least one more fork to visit.
         let package = current_state.next;
+        let cause = ForkCause::Dependencies {
+            package: current_state.pubgrub.package_store[package].clone(),
+            version: version.clone(),
+            diverging: diverging_packages.to_vec(),
+        };
         let mut cur_state = Some(current_state);
         let forks_len = forks.len();
         forks
@@ -848,12 +894,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             .enumerate()
             .map(move |(i, fork)| {
                 let is_last = i == forks_len - 1;
-                let forked_state = cur_state.take().unwrap();
+                let mut forked_state = cur_state.take().unwrap();
                 if !is_last {
                     cur_state = Some(forked_state.clone());
                 }
 
                 let env = fork.env.clone();
+                forked_state.record_fork(cause.clone(), &env);
                 (fork, forked_state.with_env(env))
             })
             .map(move |(fork, mut forked_state)| {
@@ -864,6 +911,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     &self.indexes,
                     fork.dependencies.clone(),
                     &self.git,
+                    &self.hg,
                     &self.workspace_members,
                     self.selector.resolution_strategy(),
                 )?;
@@ -892,6 +940,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         &self,
         current_state: ForkState,
         forks: Vec<VersionFork>,
+        cause: ForkCause,
     ) -> impl Iterator<Item = ForkState> + '_ {
         // This is a somewhat tortured technique to ensure
         // that our resolver state is only cloned as much
@@ -908,6 +957,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             forked_state.initial_id = Some(fork.id);
             forked_state.initial_version = fork.version;
+            forked_state.record_fork(cause.clone(), &fork.env);
             forked_state.with_env(fork.env)
         })
     }
@@ -1266,7 +1316,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 version: None,
                             })
                             .collect();
-                        return Ok(Some(ResolverVersion::Forked(forks)));
+                        let cause = ForkCause::RequiresPython {
+                            package: package.clone(),
+                            version: candidate.version().clone(),
+                            requires_python: requires_python.clone(),
+                        };
+                        return Ok(Some(ResolverVersion::Forked(forks, cause)));
                     }
                 }
             }
@@ -1277,6 +1332,23 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             )));
         }
 
+        // With `--binary-only`, check whether this version has wheels for the entire fork.
+        if let Some(marker) =
+            self.missing_wheel_coverage(dist, id, env, python_requirement, pubgrub)
+        {
+            debug!(
+                "Rejecting {name}=={} due to missing wheels for: `{}`",
+                candidate.version(),
+                marker.try_to_string().unwrap_or_else(|| "true".to_string()),
+            );
+            return Ok(Some(ResolverVersion::Unavailable(
+                candidate.version().clone(),
+                UnavailableVersion::IncompatibleDist(IncompatibleDist::Wheel(
+                    IncompatibleWheel::MissingPlatform(marker),
+                )),
+            )));
+        }
+
         // Check whether this version covers all supported platforms; and, if not, generate a fork.
         if let Some(forked) = self.fork_version_registry(
             &candidate,
@@ -1319,6 +1391,37 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(Some(ResolverVersion::Unforked(version)))
     }
 
+    /// With `--binary-only`, return the part of a universal fork in which the package is required
+    /// but that the candidate's wheels don't cover, if any.
+    ///
+    /// If the user marked platforms as required, coverage is instead enforced for each of those
+    /// platforms, by forking (see [`ResolverState::fork_version_registry`]).
+    fn missing_wheel_coverage(
+        &self,
+        dist: &CompatibleDist,
+        id: Id<PubGrubPackage>,
+        env: &ResolverEnvironment,
+        python_requirement: &PythonRequirement,
+        pubgrub: &State<UvDependencyProvider>,
+    ) -> Option<MarkerTree> {
+        if !self.options.build_options.binary_only()
+            || !self.options.required_environments.is_empty()
+        {
+            return None;
+        }
+
+        // The markers of a universal fork, or `None` for a platform-specific resolution, in which
+        // incompatible wheels were already filtered out by their tags.
+        let mut uncovered = env.try_universal_markers()?.pep508();
+        uncovered.and(python_requirement.to_marker_tree());
+        uncovered.and(find_environments(id, pubgrub));
+        uncovered.and(dist.wheel_markers().negate());
+        if uncovered.is_false() {
+            return None;
+        }
+        Some(python_requirement.simplify_markers(uncovered))
+    }
+
     /// Determine whether a candidate covers all supported platforms; and, if not, generate a fork.
     ///
     /// This only ever applies to versions that lack source distributions And, for now, we only
@@ -1352,9 +1455,17 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             return Ok(None);
         }
 
+        // With `--binary-only`, only wheels count towards platform support, as source
+        // distributions can't be built.
+        let implied_markers = if self.options.build_options.binary_only() {
+            dist.wheel_markers()
+        } else {
+            dist.implied_markers()
+        };
+
         // If the package is already compatible with all environments (as is the case for
         // packages that include a source distribution), we don't need to fork.
-        if dist.implied_markers().is_true() {
+        if implied_markers.is_true() {
             return Ok(None);
         };
 
@@ -1363,7 +1474,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             // If the platform is part of the current environment...
             if env.included_by_marker(marker) {
                 // But isn't supported by the distribution...
-                if dist.implied_markers().is_disjoint(marker)
+                if implied_markers.is_disjoint(marker)
                     && !find_environments(id, pubgrub).is_disjoint(marker)
                 {
                     // Then we need to fork.
@@ -1399,7 +1510,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             version: None,
                         },
                     ];
-                    return Ok(Some(ResolverVersion::Forked(forks)));
+                    let cause = ForkCause::RequiredEnvironment {
+                        package: package.clone(),
+                        version: candidate.version().clone(),
+                    };
+                    return Ok(Some(ResolverVersion::Forked(forks, cause)));
                 }
             }
         }
@@ -1535,7 +1650,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 version: Some(candidate.version().clone()),
             },
         ];
-        Ok(Some(ResolverVersion::Forked(forks)))
+        let cause = ForkCause::LocalVersion {
+            package: package.clone(),
+            version: candidate.version().clone(),
+        };
+        Ok(Some(ResolverVersion::Forked(forks, cause)))
     }
 
     /// Visit a selected candidate.
@@ -1742,6 +1861,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 };
 
+                // If the distribution declares a disallowed license, reject it, unless it's a
+                // local source tree (e.g., a workspace member).
+                if self.options.licenses.mode() == LicenseMode::Deny
+                    && url.is_none_or(|url| !matches!(url.parsed_url, ParsedUrl::Directory(_)))
+                {
+                    if let Some(license) = metadata
+                        .license_expression
+                        .as_ref()
+                        .filter(|license| !self.options.licenses.permits(license))
+                    {
+                        debug!(
+                            "Rejecting {name}=={version} due to disallowed license: `{license}`"
+                        );
+                        return Ok(Dependencies::Unavailable(
+                            UnavailableVersion::DisallowedLicense(license.clone()),
+                        ));
+                    }
+                }
+
                 let requirements = self.flatten_requirements(
                     &metadata.requires_dist,
                     &metadata.dependency_groups,
@@ -1752,7 +1890,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     python_requirement,
                 );
 
-                requirements
+                let dependencies = requirements
                     .flat_map(|requirement| {
                         PubGrubDependency::from_requirement(
                             &self.conflicts,
@@ -1761,7 +1899,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             Some(name),
                         )
                     })
-                    .collect()
+                    .collect();
+
+                self.detect_extra_cycles(id, package, version, dependencies, pubgrub)
             }
 
             PubGrubPackageInner::Python(_) => return Ok(Dependencies::Unforkable(Vec::default())),
@@ -1833,9 +1973,131 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 ))
             }
         };
+        self.check_excluded_dependencies(id, package, version, &dependencies, pubgrub)?;
         Ok(Dependencies::Available(dependencies))
     }
 
+    /// Reject any dependency on a package that was banned via `exclude-dependencies`, reporting
+    /// the chain of packages that introduced it.
+    fn check_excluded_dependencies(
+        &self,
+        id: Id<PubGrubPackage>,
+        package: &PubGrubPackage,
+        version: &Version,
+        dependencies: &[PubGrubDependency],
+        pubgrub: &State<UvDependencyProvider>,
+    ) -> Result<(), ResolveError> {
+        if self.options.exclude_dependencies.is_empty() {
+            return Ok(());
+        }
+
+        let Some(excluded) = dependencies
+            .iter()
+            .filter_map(|dependency| dependency.package.name())
+            .find(|name| self.options.exclude_dependencies.contains(*name))
+        else {
+            return Ok(());
+        };
+
+        let mut chain = Vec::new();
+        if let Some(name) = package.name_no_root() {
+            if let Some(derivation) = DerivationChainBuilder::from_state(id, version, pubgrub) {
+                chain.extend(derivation.iter().map(|step| {
+                    format_chain_step(&step.name, step.extra.as_ref(), step.version.as_ref())
+                }));
+            }
+            chain.push(format_chain_step(name, package.extra(), Some(version)));
+        }
+        chain.push(format_chain_step(excluded, None, None));
+
+        Err(ResolveError::ExcludedDependency(
+            excluded.clone(),
+            chain.join(" -> "),
+        ))
+    }
+
+    /// Detect dependency cycles that are introduced through extras, as in `a[x] -> b -> a[y]`.
+    ///
+    /// A cycle is detected when one of the dependencies of the current package enables an extra
+    /// on a package that's already part of the derivation chain (with a different extra, or
+    /// without any extra). Such cycles are reported with the full chain; if
+    /// `--break-extra-cycles` is enabled, the back-edge that closes the cycle is dropped instead.
+    fn detect_extra_cycles(
+        &self,
+        id: Id<PubGrubPackage>,
+        package: &PubGrubPackage,
+        version: &Version,
+        dependencies: Vec<PubGrubDependency>,
+        pubgrub: &State<UvDependencyProvider>,
+    ) -> Vec<PubGrubDependency> {
+        let Some(name) = package.name_no_root() else {
+            return dependencies;
+        };
+
+        // Only dependencies that enable an extra on another package can close such a cycle.
+        if !dependencies.iter().any(|dependency| {
+            dependency.package.extra().is_some()
+                && dependency.package.name().is_some_and(|dep| dep != name)
+        }) {
+            return dependencies;
+        }
+
+        let Some(chain) = DerivationChainBuilder::from_state(id, version, pubgrub) else {
+            return dependencies;
+        };
+
+        dependencies
+            .into_iter()
+            .filter(|dependency| {
+                let (Some(dep_name), Some(dep_extra)) =
+                    (dependency.package.name(), dependency.package.extra())
+                else {
+                    return true;
+                };
+                if dep_name == name {
+                    return true;
+                }
+
+                // Find the ancestor that introduced the package with a different extra.
+                let Some(start) = chain.iter().position(|step| {
+                    step.name == *dep_name && step.extra.as_ref() != Some(dep_extra)
+                }) else {
+                    return true;
+                };
+
+                let cycle = chain
+                    .iter()
+                    .skip(start)
+                    .map(|step| {
+                        format_chain_step(&step.name, step.extra.as_ref(), step.version.as_ref())
+                    })
+                    .chain(std::iter::once(format_chain_step(
+                        name,
+                        package.extra(),
+                        Some(version),
+                    )))
+                    .chain(std::iter::once(format_chain_step(
+                        dep_name,
+                        Some(dep_extra),
+                        None,
+                    )))
+                    .join(" -> ");
+
+                if self.options.break_extra_cycles {
+                    debug!("Ignoring back-edge of dependency cycle through extras: {cycle}");
+                    false
+                } else {
+                    warn_user_once!(
+                        "Detected a dependency cycle through extras: {cycle}. \
+                        Use `--break-extra-cycles` to ignore the back-edge \
+                        on `{dep_name}[{dep_extra}]`."
+                    );
+                    true
+                }
+            })
+            .collect()
+    }
+
     /// The regular and dev dependencies filtered by Python version and the markers of this fork,
     /// plus the extras dependencies of the current package (e.g., `black` depending on
     /// `black[colorama]`).
@@ -2593,6 +2855,8 @@ pub(crate) struct ForkState {
     ///
     /// Tracked on the fork state to avoid counting each identical version between forks as new try.
     prefetcher: BatchPrefetcher,
+    /// The fork points that led to this state, from the root of the resolution.
+    fork_history: Vec<ForkPoint>,
 }
 
 impl ForkState {
@@ -2616,6 +2880,7 @@ impl ForkState {
             python_requirement,
             conflict_tracker: ConflictTracker::default(),
             prefetcher,
+            fork_history: Vec::new(),
         }
     }
 
@@ -2629,6 +2894,7 @@ impl ForkState {
         indexes: &Indexes,
         dependencies: Vec<PubGrubDependency>,
         git: &GitResolver,
+        hg: &HgResolver,
         workspace_members: &BTreeSet<PackageName>,
         resolution_strategy: &ResolutionStrategy,
     ) -> Result<(), ResolveError> {
@@ -2645,7 +2911,7 @@ impl ForkState {
                 // requirement was a URL requirement. `Urls` applies canonicalization to this and
                 // override URLs to both URL and registry requirements, which we then check for
                 // conflicts using [`ForkUrl`].
-                for url in urls.get_url(&self.env, name, url.as_ref(), git)? {
+                for url in urls.get_url(&self.env, name, url.as_ref(), git, hg)? {
                     self.fork_urls.insert(name, url, &self.env)?;
                     has_url = true;
                 }
@@ -2769,6 +3035,58 @@ impl ForkState {
         }
     }
 
+    /// Convert the fork state into a [`BudgetExceededError`], reporting the packages that were
+    /// responsible for the most backtracking.
+    fn into_budget_exceeded_error(
+        self,
+        limit: BudgetLimit,
+        iterations: usize,
+        elapsed: Duration,
+    ) -> BudgetExceededError {
+        /// The maximum number of packages to include in the report.
+        const MAX_HOTSPOTS: usize = 10;
+
+        fn entry<'a>(
+            hotspots: &'a mut FxHashMap<PackageName, BacktrackHotspot>,
+            name: &PackageName,
+        ) -> &'a mut BacktrackHotspot {
+            hotspots
+                .entry(name.clone())
+                .or_insert_with(|| BacktrackHotspot {
+                    name: name.clone(),
+                    candidates: 0,
+                    retries: 0,
+                    conflicts: 0,
+                })
+        }
+
+        let mut hotspots = FxHashMap::default();
+        for (name, count) in self.prefetcher.tried_versions() {
+            entry(&mut hotspots, name).candidates = count;
+        }
+        for (package, count) in &self.conflict_tracker.affected {
+            if let Some(name) = self.pubgrub.package_store[*package].name() {
+                entry(&mut hotspots, name).retries += count;
+            }
+        }
+        for (package, count) in &self.conflict_tracker.culprit {
+            if let Some(name) = self.pubgrub.package_store[*package].name() {
+                entry(&mut hotspots, name).conflicts += count;
+            }
+        }
+
+        // Packages for which we only tried a single version without any conflicts didn't cause
+        // any backtracking.
+        let mut hotspots = hotspots
+            .into_values()
+            .filter(|hotspot| hotspot.score() > 1)
+            .collect::<Vec<_>>();
+        hotspots.sort_by(|a, b| b.score().cmp(&a.score()).then_with(|| a.name.cmp(&b.name)));
+        hotspots.truncate(MAX_HOTSPOTS);
+
+        BudgetExceededError::new(limit, iterations, elapsed, self.env, hotspots)
+    }
+
     fn add_unavailable_version(&mut self, version: Version, reason: UnavailableVersion) {
         // Incompatible requires-python versions are special in that we track
         // them as incompatible dependencies instead of marking the package version
@@ -2804,6 +3122,15 @@ impl ForkState {
             ));
     }
 
+    /// Record that this state was created by taking the branch for the given environment at a
+    /// fork point.
+    fn record_fork(&mut self, cause: ForkCause, env: &ResolverEnvironment) {
+        self.fork_history.push(ForkPoint {
+            cause,
+            marker: env.try_universal_markers().unwrap_or(UniversalMarker::TRUE),
+        });
+    }
+
     /// Subset the current markers with the new markers and update the python requirements fields
     /// accordingly.
     ///
@@ -3086,6 +3413,7 @@ impl ForkState {
             edges,
             pins: self.pins,
             env: self.env,
+            fork_history: self.fork_history,
         }
     }
 }
@@ -3101,6 +3429,8 @@ pub(crate) struct Resolution {
     pub(crate) pins: FilePins,
     /// The environment setting this resolution was found under.
     pub(crate) env: ResolverEnvironment,
+    /// The fork points that led to this resolution.
+    pub(crate) fork_history: Vec<ForkPoint>,
 }
 
 /// Package representation we used during resolution where each extra and also the dev-dependencies
@@ -3717,6 +4047,23 @@ fn find_environments(id: Id<PubGrubPackage>, state: &State<UvDependencyProvider>
     marker
 }
 
+/// Format a step in a dependency chain, e.g., `a[x]==1.0.0`.
+fn format_chain_step(
+    name: &PackageName,
+    extra: Option<&ExtraName>,
+    version: Option<&Version>,
+) -> String {
+    let mut step = match extra {
+        Some(extra) => format!("`{name}[{extra}]"),
+        None => format!("`{name}"),
+    };
+    if let Some(version) = version {
+        step.push_str(&format!("=={version}"));
+    }
+    step.push('`');
+    step
+}
+
 #[derive(Debug, Default, Clone)]
 struct ConflictTracker {
     /// How often a decision on the package was discarded due to another package decided earlier.
@@ -1,13 +1,17 @@
+use std::collections::BTreeSet;
 use std::future::Future;
 use std::sync::Arc;
 
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
-use uv_distribution_types::{Dist, IndexCapabilities, IndexUrl, InstalledDist, RequestedDist};
+use uv_distribution_types::{
+    Dist, IndexCapabilities, IndexLocations, IndexUrl, InstalledDist, RequestedDist,
+};
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy};
+use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatIndex;
 use crate::version_map::VersionMap;
@@ -97,6 +101,10 @@ pub trait ResolverProvider {
         dist: &'io InstalledDist,
     ) -> impl Future<Output = WheelMetadataResult> + 'io;
 
+    /// Return the versions of a package that are available in the cache, e.g., to report the
+    /// versions that could be used when the network is disabled.
+    fn cached_versions(&self, package_name: &PackageName) -> BTreeSet<Version>;
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self;
@@ -114,6 +122,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    index_locations: &'a IndexLocations,
     build_options: &'a BuildOptions,
     capabilities: &'a IndexCapabilities,
 }
@@ -128,6 +137,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: Option<ExcludeNewer>,
+        index_locations: &'a IndexLocations,
         build_options: &'a BuildOptions,
         capabilities: &'a IndexCapabilities,
     ) -> Self {
@@ -139,6 +149,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            index_locations,
             build_options,
             capabilities,
         }
@@ -152,6 +163,18 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         package_name: &'io PackageName,
         index: Option<&'io IndexUrl>,
     ) -> PackageVersionsResult {
+        // If the package selects a flat `path` source, read the distributions from the source
+        // directly, rather than querying it as a registry.
+        if let Some(index) = index.filter(|index| self.flat_index.is_explicit(index)) {
+            return if let Some(flat_index) = self.flat_index.get_explicit(index, package_name) {
+                Ok(VersionsResponse::Found(vec![VersionMap::from(
+                    flat_index.clone(),
+                )]))
+            } else {
+                Ok(VersionsResponse::NotFound)
+            };
+        }
+
         let result = self
             .fetcher
             .client()
@@ -165,6 +188,16 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                 results
                     .into_iter()
                     .map(|(index, metadata)| {
+                        let upload_time_fallback = self.index_locations.upload_time_fallback(index);
+                        if self.exclude_newer.is_some()
+                            && upload_time_fallback.is_none()
+                            && !self.capabilities.provides_upload_time(index)
+                        {
+                            warn_user_once!(
+                                "Index `{}` does not provide upload times, so its files are excluded by `--exclude-newer`; set `upload-time-fallback` on the index to include them",
+                                index.redacted()
+                            );
+                        }
                         VersionMap::from_metadata(
                             metadata,
                             package_name,
@@ -174,6 +207,7 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                             &self.allowed_yanks,
                             &self.hasher,
                             self.exclude_newer.as_ref(),
+                            upload_time_fallback,
                             self.flat_index.get(package_name).cloned(),
                             self.build_options,
                         )
@@ -275,6 +309,12 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         }
     }
 
+    /// Return the versions of a package for which distributions or metadata are cached.
+    fn cached_versions(&self, package_name: &PackageName) -> BTreeSet<Version> {
+        self.fetcher
+            .cached_versions(package_name, self.index_locations)
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -5,7 +5,7 @@ use tracing::debug;
 
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Verbatim;
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, VerbatimUrl};
 use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl, VerbatimParsedUrl};
@@ -37,6 +37,7 @@ impl Urls {
         manifest: &Manifest,
         env: &ResolverEnvironment,
         git: &GitResolver,
+        hg: &HgResolver,
         dependencies: DependencyMode,
     ) -> Self {
         let mut regular: FxHashMap<PackageName, Vec<VerbatimParsedUrl>> = FxHashMap::default();
@@ -51,10 +52,9 @@ impl Urls {
             };
 
             let package_urls = regular.entry(requirement.name.clone()).or_default();
-            if let Some(package_url) = package_urls
-                .iter_mut()
-                .find(|package_url| same_resource(&package_url.parsed_url, &url.parsed_url, git))
-            {
+            if let Some(package_url) = package_urls.iter_mut().find(|package_url| {
+                same_resource(&package_url.parsed_url, &url.parsed_url, git, hg)
+            }) {
                 // Allow editables to override non-editables.
                 let previous_editable = package_url.is_editable();
                 *package_url = url;
@@ -108,6 +108,7 @@ impl Urls {
         name: &'a PackageName,
         url: Option<&'a VerbatimParsedUrl>,
         git: &'a GitResolver,
+        hg: &'a HgResolver,
     ) -> Result<impl Iterator<Item = &'a VerbatimParsedUrl>, ResolveError> {
         if let Some(override_urls) = self.get_overrides(name) {
             Ok(Either::Left(Either::Left(override_urls.iter().filter_map(
@@ -121,7 +122,7 @@ impl Urls {
             ))))
         } else if let Some(url) = url {
             let url =
-                self.canonicalize_allowed_url(env, name, git, &url.verbatim, &url.parsed_url)?;
+                self.canonicalize_allowed_url(env, name, git, hg, &url.verbatim, &url.parsed_url)?;
             Ok(Either::Left(Either::Right(std::iter::once(url))))
         } else {
             Ok(Either::Right(std::iter::empty()))
@@ -152,6 +153,7 @@ impl Urls {
         env: &ResolverEnvironment,
         package_name: &'a PackageName,
         git: &'a GitResolver,
+        hg: &'a HgResolver,
         verbatim_url: &'a VerbatimUrl,
         parsed_url: &'a ParsedUrl,
     ) -> Result<&'a VerbatimParsedUrl, ResolveError> {
@@ -164,7 +166,7 @@ impl Urls {
 
         let matching_urls: Vec<_> = expected
             .iter()
-            .filter(|requirement| same_resource(&requirement.parsed_url, parsed_url, git))
+            .filter(|requirement| same_resource(&requirement.parsed_url, parsed_url, git, hg))
             .collect();
 
         let [allowed_url] = matching_urls.as_slice() else {
@@ -185,7 +187,7 @@ impl Urls {
 }
 
 /// Returns `true` if the [`ParsedUrl`] instances point to the same resource.
-fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver) -> bool {
+fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver, hg: &HgResolver) -> bool {
     match (a, b) {
         (ParsedUrl::Archive(a), ParsedUrl::Archive(b)) => {
             a.subdirectory.as_deref().map(uv_fs::normalize_path)
@@ -197,6 +199,11 @@ fn same_resource(a: &ParsedUrl, b: &ParsedUrl, git: &GitResolver) -> bool {
                 == b.subdirectory.as_deref().map(uv_fs::normalize_path)
                 && git.same_ref(&a.url, &b.url)
         }
+        (ParsedUrl::Hg(a), ParsedUrl::Hg(b)) => {
+            a.subdirectory.as_deref().map(uv_fs::normalize_path)
+                == b.subdirectory.as_deref().map(uv_fs::normalize_path)
+                && hg.same_ref(&a.url, &b.url)
+        }
         (ParsedUrl::Path(a), ParsedUrl::Path(b)) => {
             a.install_path == b.install_path
                 || is_same_file(&a.install_path, &b.install_path).unwrap_or(false)
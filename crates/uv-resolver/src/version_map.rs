@@ -3,6 +3,7 @@ use std::collections::Bound;
 use std::ops::RangeBounds;
 use std::sync::OnceLock;
 
+use jiff::Timestamp;
 use pubgrub::Ranges;
 use tracing::instrument;
 
@@ -50,6 +51,7 @@ impl VersionMap {
         allowed_yanks: &AllowedYanks,
         hasher: &HashStrategy,
         exclude_newer: Option<&ExcludeNewer>,
+        upload_time_fallback: Option<Timestamp>,
         flat_index: Option<FlatDistributions>,
         build_options: &BuildOptions,
     ) -> Self {
@@ -112,6 +114,7 @@ impl VersionMap {
                 hasher: hasher.clone(),
                 requires_python: requires_python.clone(),
                 exclude_newer: exclude_newer.copied(),
+                upload_time_fallback: upload_time_fallback.map(Timestamp::as_millisecond),
             }),
         }
     }
@@ -341,6 +344,8 @@ struct VersionMapLazy {
     tags: Option<Tags>,
     /// Whether files newer than this timestamp should be excluded or not.
     exclude_newer: Option<ExcludeNewer>,
+    /// The upload time to assume for files that don't provide one, in milliseconds.
+    upload_time_fallback: Option<i64>,
     /// Which yanked versions are allowed
     allowed_yanks: AllowedYanks,
     /// The hashes of allowed distributions.
@@ -394,9 +399,13 @@ impl VersionMapLazy {
             let mut priority_dist = init.cloned().unwrap_or_default();
             for (filename, file) in files.all() {
                 // Support resolving as if it were an earlier timestamp, at least as long files have
-                // upload time information.
+                // upload time information (or the index provides a fallback).
                 let (excluded, upload_time) = if let Some(exclude_newer) = self.exclude_newer {
-                    match file.upload_time_utc_ms.as_ref() {
+                    match file
+                        .upload_time_utc_ms
+                        .or(self.upload_time_fallback)
+                        .as_ref()
+                    {
                         Some(&upload_time) if upload_time >= exclude_newer.timestamp_millis() => {
                             (true, Some(upload_time))
                         }
@@ -1,26 +1,215 @@
+use std::fmt::Formatter;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionParseError};
 use uv_pypi_types::RequirementSource;
 
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
+/// How to treat distributions that are marked as yanked by the relevant index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum YankedMode {
+    /// Never select yanked versions, unless they're listed in `allow-yanked`.
+    Deny,
+    /// Only select yanked versions if they're pinned with `==` or by the lockfile, and warn when
+    /// doing so.
+    #[default]
+    Warn,
+    /// Select yanked versions like any other version.
+    Allow,
+}
+
+impl std::fmt::Display for YankedMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deny => write!(f, "deny"),
+            Self::Warn => write!(f, "warn"),
+            Self::Allow => write!(f, "allow"),
+        }
+    }
+}
+
+/// A package version that is permitted even if it's marked as yanked, e.g., `flask==2.3.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AllowedYank {
+    name: PackageName,
+    version: Version,
+}
+
+impl AllowedYank {
+    /// Returns `true` if the [`AllowedYank`] matches the given package version.
+    pub fn matches(&self, name: &PackageName, version: &Version) -> bool {
+        self.name == *name && self.version == *version
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AllowedYankError {
+    #[error("Expected a pinned package version (e.g., `flask==2.3.0`), but found: `{0}`")]
+    MissingVersion(String),
+    #[error(transparent)]
+    Name(#[from] InvalidNameError),
+    #[error(transparent)]
+    Version(#[from] VersionParseError),
+}
+
+impl FromStr for AllowedYank {
+    type Err = AllowedYankError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, version)) = s.split_once("==") else {
+            return Err(AllowedYankError::MissingVersion(s.to_string()));
+        };
+        Ok(Self {
+            name: PackageName::from_str(name.trim())?,
+            version: Version::from_str(version.trim())?,
+        })
+    }
+}
+
+impl std::fmt::Display for AllowedYank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}=={}", self.name, self.version)
+    }
+}
+
+impl serde::Serialize for AllowedYank {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AllowedYank {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = AllowedYank;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                AllowedYank::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AllowedYank {
+    fn schema_name() -> String {
+        String::from("AllowedYank")
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A pinned package version, e.g., `flask==2.3.0`.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// The user-provided policy for yanked distributions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct YankedPolicy {
+    /// The configured mode, if any.
+    mode: Option<YankedMode>,
+    /// The package versions that are permitted regardless of the mode.
+    allowed: Vec<AllowedYank>,
+}
+
+impl YankedPolicy {
+    /// Create a [`YankedPolicy`] from the user-provided settings.
+    pub fn from_args(mode: Option<YankedMode>, allowed: Vec<AllowedYank>) -> Self {
+        Self { mode, allowed }
+    }
+
+    /// Returns the effective [`YankedMode`].
+    pub fn mode(&self) -> YankedMode {
+        self.mode.unwrap_or_default()
+    }
+
+    /// Returns `true` if the user explicitly configured a [`YankedMode`].
+    ///
+    /// Locked distributions are only re-checked against the index at install time when a mode
+    /// was configured explicitly, as doing so requires additional network requests.
+    pub fn is_explicit(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    /// Returns `true` if the given package version was explicitly allowed by the user.
+    pub fn is_allowed(&self, name: &PackageName, version: &Version) -> bool {
+        self.allowed
+            .iter()
+            .any(|allowed| allowed.matches(name, version))
+    }
+
+    /// Returns `true` if selecting the given yanked package version should be reported to the
+    /// user.
+    pub fn should_warn(&self, name: &PackageName, version: &Version) -> bool {
+        self.mode() != YankedMode::Allow && !self.is_allowed(name, version)
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
-pub struct AllowedYanks(Arc<FxHashMap<PackageName, FxHashSet<Version>>>);
+pub struct AllowedYanks {
+    /// Whether all yanked versions are permitted.
+    all: bool,
+    /// The package versions that are permitted.
+    versions: Arc<FxHashMap<PackageName, FxHashSet<Version>>>,
+}
 
 impl AllowedYanks {
     pub fn from_manifest(
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        policy: &YankedPolicy,
     ) -> Self {
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
+        // Allow yanks for any versions explicitly allowed by the user.
+        for allowed in &policy.allowed {
+            allowed_yanks
+                .entry(allowed.name.clone())
+                .or_default()
+                .insert(allowed.version.clone());
+        }
+
+        match policy.mode() {
+            YankedMode::Allow => {
+                return Self {
+                    all: true,
+                    versions: Arc::new(allowed_yanks),
+                };
+            }
+            YankedMode::Deny => {
+                return Self {
+                    all: false,
+                    versions: Arc::new(allowed_yanks),
+                };
+            }
+            YankedMode::Warn => {}
+        }
+
         // Allow yanks for any pinned input requirements.
         for requirement in manifest.requirements(env, dependencies) {
             let RequirementSource::Registry { specifier, .. } = &requirement.source else {
@@ -48,13 +237,68 @@ impl AllowedYanks {
                 .extend(preferences.map(|(.., version)| version.clone()));
         }
 
-        Self(Arc::new(allowed_yanks))
+        Self {
+            all: false,
+            versions: Arc::new(allowed_yanks),
+        }
     }
 
     /// Returns `true` if the package-version is allowed, even if it's marked as yanked.
     pub fn contains(&self, package_name: &PackageName, version: &Version) -> bool {
-        self.0
+        if self.all {
+            return true;
+        }
+        self.versions
             .get(package_name)
             .is_some_and(|versions| versions.contains(version))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::{AllowedYank, YankedMode, YankedPolicy};
+
+    #[test]
+    fn parse_allowed_yank() {
+        let allowed = AllowedYank::from_str("Flask == 2.3.0").unwrap();
+        assert!(allowed.matches(
+            &PackageName::from_str("flask").unwrap(),
+            &Version::from_str("2.3.0").unwrap()
+        ));
+        assert_eq!(allowed.to_string(), "flask==2.3.0");
+
+        let err = AllowedYank::from_str("flask>=2.3.0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected a pinned package version (e.g., `flask==2.3.0`), but found: `flask>=2.3.0`"
+        );
+    }
+
+    #[test]
+    fn yanked_policy() {
+        let flask = PackageName::from_str("flask").unwrap();
+        let allowed = Version::from_str("2.3.0").unwrap();
+        let other = Version::from_str("2.3.1").unwrap();
+
+        let policy = YankedPolicy::default();
+        assert!(!policy.is_explicit());
+        assert_eq!(policy.mode(), YankedMode::Warn);
+        assert!(policy.should_warn(&flask, &allowed));
+
+        let policy = YankedPolicy::from_args(
+            Some(YankedMode::Deny),
+            vec![AllowedYank::from_str("flask==2.3.0").unwrap()],
+        );
+        assert!(policy.is_explicit());
+        assert!(!policy.should_warn(&flask, &allowed));
+        assert!(policy.should_warn(&flask, &other));
+
+        let policy = YankedPolicy::from_args(Some(YankedMode::Allow), vec![]);
+        assert!(!policy.should_warn(&flask, &other));
+    }
+}
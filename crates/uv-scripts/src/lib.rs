@@ -530,6 +530,28 @@ fn extract_shebang(contents: &[u8]) -> Result<(String, String), Pep723Error> {
     }
 }
 
+/// Extracts the name of the Python executable that the shebang line of a script invokes, if any.
+///
+/// For example, `python3.12` is returned for both `#!/usr/bin/env python3.12` and
+/// `#!/usr/bin/python3.12`. Shebangs that don't invoke a Python executable directly, like
+/// `#!/usr/bin/env -S uv run --script`, are ignored.
+pub fn shebang_python(contents: &[u8]) -> Option<&str> {
+    let line = contents
+        .strip_prefix(b"#!")?
+        .split(|&b| b == b'\r' || b == b'\n')
+        .next()?;
+    let mut args = std::str::from_utf8(line).ok()?.split_whitespace();
+
+    // Skip `env`, along with its options and variable assignments, e.g., `/usr/bin/env -S`.
+    let mut program = args.next()?;
+    if program.rsplit(['/', '\\']).next() == Some("env") {
+        program = args.find(|arg| !arg.starts_with('-') && !arg.contains('='))?;
+    }
+
+    let name = program.rsplit(['/', '\\']).next()?;
+    (name.starts_with("python") || name.starts_with("pypy")).then_some(name)
+}
+
 /// Formats the provided metadata by prefixing each line with `#` and wrapping it with script markers.
 fn serialize_metadata(metadata: &str) -> String {
     let mut output = String::with_capacity(metadata.len() + 32);
@@ -554,7 +576,7 @@ fn serialize_metadata(metadata: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::{serialize_metadata, Pep723Error, ScriptTag};
+    use crate::{serialize_metadata, shebang_python, Pep723Error, ScriptTag};
 
     #[test]
     fn missing_space() {
@@ -782,4 +804,20 @@ mod tests {
         let result = serialize_metadata(metadata);
         assert_eq!(result, expected_output);
     }
+
+    #[test]
+    fn shebang() {
+        assert_eq!(
+            shebang_python(b"#!/usr/bin/env python3.12\nimport sys\n"),
+            Some("python3.12")
+        );
+        assert_eq!(shebang_python(b"#!/usr/bin/python3\r\n"), Some("python3"));
+        assert_eq!(
+            shebang_python(b"#!/usr/bin/env -S pypy3.10 -u"),
+            Some("pypy3.10")
+        );
+        assert_eq!(shebang_python(b"#!/usr/bin/env -S uv run --script\n"), None);
+        assert_eq!(shebang_python(b"#!/bin/sh\n"), None);
+        assert_eq!(shebang_python(b"import sys\n"), None);
+    }
 }
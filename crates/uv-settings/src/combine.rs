@@ -1,17 +1,21 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::path::PathBuf;
 
 use url::Url;
 
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, RequiredVersion, TargetTriple,
-    TrustedPublishing,
+    AddressFamily, Bandwidth, CacheDirs, CacheRetention, CacheSize, ConfigSettings,
+    HttpRetryOptions, IndexStrategy, IndexTracking, KeyringProviderType, RequiredVersion,
+    TargetTriple, TrustedPublishing,
 };
 use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode, SupportedPython,
+    YankedMode,
+};
 
 use crate::{FilesystemOptions, Options, PipOptions};
 
@@ -72,14 +76,21 @@ macro_rules! impl_combine_or {
     };
 }
 
+impl_combine_or!(AddressFamily);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(Bandwidth);
+impl_combine_or!(CacheDirs);
+impl_combine_or!(CacheRetention);
+impl_combine_or!(CacheSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ForkStrategy);
+impl_combine_or!(IndexTracking);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
+impl_combine_or!(NonZeroU64);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
 impl_combine_or!(PipExtraIndex);
@@ -94,10 +105,14 @@ impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
+impl_combine_or!(SupportedPython);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
+impl_combine_or!(YankedMode);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
+impl_combine_or!(usize);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -113,6 +128,24 @@ impl<T> Combine for Option<Vec<T>> {
     }
 }
 
+impl Combine for Option<HttpRetryOptions> {
+    /// Combine two [`HttpRetryOptions`] field by field, preferring the values in `self` and
+    /// joining the status codes and per-host overrides, if they're both `Some`.
+    fn combine(self, other: Option<HttpRetryOptions>) -> Option<HttpRetryOptions> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(HttpRetryOptions {
+                retries: a.retries.or(b.retries),
+                status_codes: a.status_codes.combine(b.status_codes),
+                backoff_min: a.backoff_min.or(b.backoff_min),
+                backoff_max: a.backoff_max.or(b.backoff_max),
+                backoff_base: a.backoff_base.or(b.backoff_base),
+                hosts: a.hosts.combine(b.hosts),
+            }),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
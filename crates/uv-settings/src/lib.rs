@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use etcetera::BaseStrategy;
 
 use uv_fs::Simplified;
+use uv_python::policy::PythonPolicy;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
@@ -36,11 +37,9 @@ impl Deref for FilesystemOptions {
 impl FilesystemOptions {
     /// Load the user [`FilesystemOptions`].
     pub fn user() -> Result<Option<Self>, Error> {
-        let Some(dir) = user_config_dir() else {
+        let Some(file) = user_config_file() else {
             return Ok(None);
         };
-        let root = dir.join("uv");
-        let file = root.join("uv.toml");
 
         tracing::debug!("Searching for user configuration in: `{}`", file.display());
         match read_file(&file) {
@@ -64,7 +63,7 @@ impl FilesystemOptions {
     }
 
     pub fn system() -> Result<Option<Self>, Error> {
-        let Some(file) = system_config_file() else {
+        let Some(file) = system_config_file("uv.toml") else {
             return Ok(None);
         };
         tracing::debug!("Found system configuration in: `{}`", file.display());
@@ -190,59 +189,70 @@ fn user_config_dir() -> Option<PathBuf> {
         .ok()
 }
 
+/// Returns the path to the user configuration file, e.g., `~/.config/uv/uv.toml`.
+pub fn user_config_file() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("uv").join("uv.toml"))
+}
+
 #[cfg(not(windows))]
-fn locate_system_config_xdg(value: Option<&str>) -> Option<PathBuf> {
+fn locate_system_config_xdg(value: Option<&str>, file_name: &str) -> Option<PathBuf> {
     // On Linux and macOS, read the `XDG_CONFIG_DIRS` environment variable.
     let default = "/etc/xdg";
     let config_dirs = value.filter(|s| !s.is_empty()).unwrap_or(default);
 
     for dir in config_dirs.split(':').take_while(|s| !s.is_empty()) {
-        let uv_toml_path = Path::new(dir).join("uv").join("uv.toml");
-        if uv_toml_path.is_file() {
-            return Some(uv_toml_path);
+        let path = Path::new(dir).join("uv").join(file_name);
+        if path.is_file() {
+            return Some(path);
         }
     }
     None
 }
 
 #[cfg(windows)]
-fn locate_system_config_windows(system_drive: impl AsRef<Path>) -> Option<PathBuf> {
+fn locate_system_config_windows(
+    system_drive: impl AsRef<Path>,
+    file_name: &str,
+) -> Option<PathBuf> {
     // On Windows, use `%SYSTEMDRIVE%\ProgramData\uv\uv.toml` (e.g., `C:\ProgramData`).
     let candidate = system_drive
         .as_ref()
         .join("ProgramData")
         .join("uv")
-        .join("uv.toml");
+        .join(file_name);
     candidate.as_path().is_file().then_some(candidate)
 }
 
-/// Returns the path to the system configuration file.
+/// Returns the path to a system configuration file, e.g., `uv.toml`.
 ///
 /// On Unix-like systems, uses the `XDG_CONFIG_DIRS` environment variable (falling back to
 /// `/etc/xdg/uv/uv.toml` if unset or empty) and then `/etc/uv/uv.toml`
 ///
 /// On Windows, uses `%SYSTEMDRIVE%\ProgramData\uv\uv.toml`.
-fn system_config_file() -> Option<PathBuf> {
+fn system_config_file(file_name: &str) -> Option<PathBuf> {
     #[cfg(windows)]
     {
         env::var(EnvVars::SYSTEMDRIVE)
             .ok()
-            .and_then(|system_drive| locate_system_config_windows(format!("{system_drive}\\")))
+            .and_then(|system_drive| {
+                locate_system_config_windows(format!("{system_drive}\\"), file_name)
+            })
     }
 
     #[cfg(not(windows))]
     {
-        if let Some(path) =
-            locate_system_config_xdg(env::var(EnvVars::XDG_CONFIG_DIRS).ok().as_deref())
-        {
+        if let Some(path) = locate_system_config_xdg(
+            env::var(EnvVars::XDG_CONFIG_DIRS).ok().as_deref(),
+            file_name,
+        ) {
             return Some(path);
         }
 
         // Fallback to `/etc/uv/uv.toml` if `XDG_CONFIG_DIRS` is not set or no valid
         // path is found.
-        let candidate = Path::new("/etc/uv/uv.toml");
+        let candidate = Path::new("/etc/uv").join(file_name);
         match candidate.try_exists() {
-            Ok(true) => Some(candidate.to_path_buf()),
+            Ok(true) => Some(candidate),
             Ok(false) => None,
             Err(err) => {
                 tracing::warn!("Failed to query system configuration file: {err}");
@@ -252,6 +262,21 @@ fn system_config_file() -> Option<PathBuf> {
     }
 }
 
+/// Load the system-level [`PythonPolicy`] from a `python-policy.toml` file, if any.
+///
+/// The file is located alongside the system configuration file (e.g., at
+/// `/etc/uv/python-policy.toml`), and takes precedence over all other settings.
+pub fn system_python_policy() -> Result<Option<PythonPolicy>, Error> {
+    let Some(file) = system_config_file("python-policy.toml") else {
+        return Ok(None);
+    };
+    tracing::debug!("Found system Python policy in: `{}`", file.display());
+    let content = fs_err::read_to_string(&file)?;
+    let policy = toml::from_str::<PythonPolicy>(&content)
+        .map_err(|err| Error::PythonPolicy(file.clone(), Box::new(err)))?;
+    Ok(Some(policy))
+}
+
 /// Load [`Options`] from a `uv.toml` file.
 fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
@@ -294,6 +319,48 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if options.package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if options.allowed_licenses.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "allowed-licenses",
+        ));
+    }
+    if options.denied_licenses.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "denied-licenses",
+        ));
+    }
+    if options.license_mode.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "license-mode",
+        ));
+    }
+    if options.torch_backend.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "torch-backend",
+        ));
+    }
+    if options.require_hashes.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "require-hashes",
+        ));
+    }
+    if options.prefer_smaller_wheels.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "prefer-smaller-wheels",
+        ));
+    }
+    if options.exclude_dependencies.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "exclude-dependencies",
+        ));
+    }
     Ok(())
 }
 
@@ -311,6 +378,9 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    PythonPolicy(PathBuf, #[source] Box<toml::de::Error>),
+
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1)]
     PyprojectOnlyField(PathBuf, &'static str),
 }
@@ -339,17 +409,17 @@ mod test {
         })?;
 
         // None
-        assert_eq!(locate_system_config_xdg(None), None);
+        assert_eq!(locate_system_config_xdg(None, "uv.toml"), None);
 
         // Empty string
-        assert_eq!(locate_system_config_xdg(Some("")), None);
+        assert_eq!(locate_system_config_xdg(Some(""), "uv.toml"), None);
 
         // Single colon
-        assert_eq!(locate_system_config_xdg(Some(":")), None);
+        assert_eq!(locate_system_config_xdg(Some(":"), "uv.toml"), None);
 
         // Assert that the `system_config_file` function returns the correct path.
         assert_eq!(
-            locate_system_config_xdg(Some(context.to_str().unwrap())).unwrap(),
+            locate_system_config_xdg(Some(context.to_str().unwrap()), "uv.toml").unwrap(),
             context.child("uv").child("uv.toml").path()
         );
 
@@ -359,9 +429,10 @@ mod test {
         first_config.write_str("")?;
 
         assert_eq!(
-            locate_system_config_xdg(Some(
-                format!("{}:{}", first.to_string_lossy(), context.to_string_lossy()).as_str()
-            ))
+            locate_system_config_xdg(
+                Some(format!("{}:{}", first.to_string_lossy(), context.to_string_lossy()).as_str()),
+                "uv.toml"
+            )
             .unwrap(),
             first_config.path()
         );
@@ -382,7 +453,7 @@ mod test {
         .unwrap();
 
         assert_eq!(
-            locate_system_config_xdg(Some(context.to_str().unwrap())),
+            locate_system_config_xdg(Some(context.to_str().unwrap()), "uv.toml"),
             None
         );
 
@@ -406,7 +477,7 @@ mod test {
         // This is typically only a drive (that is, letter and colon) but we
         // allow anything, including a path to the test fixtures...
         assert_eq!(
-            locate_system_config_windows(context.path()).unwrap(),
+            locate_system_config_windows(context.path(), "uv.toml").unwrap(),
             context
                 .child("ProgramData")
                 .child("uv")
@@ -416,7 +487,10 @@ mod test {
 
         // This does not have a `ProgramData` child, so contains no config.
         let context = assert_fs::TempDir::new()?;
-        assert_eq!(locate_system_config_windows(context.path()), None);
+        assert_eq!(
+            locate_system_config_windows(context.path(), "uv.toml"),
+            None
+        );
 
         Ok(())
     }
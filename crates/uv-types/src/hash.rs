@@ -319,6 +319,7 @@ impl HashStrategy {
             }
             RequirementSource::Url { url, .. }
             | RequirementSource::Git { url, .. }
+            | RequirementSource::Hg { url, .. }
             | RequirementSource::Path { url, .. }
             | RequirementSource::Directory { url, .. } => Some(VersionId::from_url(url)),
         }
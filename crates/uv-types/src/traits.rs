@@ -13,7 +13,7 @@ use uv_distribution_types::{
     CachedDist, DependencyMetadata, DistributionId, IndexCapabilities, IndexLocations,
     InstalledDist, IsBuildBackendError, Resolution, SourceDist,
 };
-use uv_git::GitResolver;
+use uv_git::{GitResolver, HgResolver};
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{Interpreter, PythonEnvironment};
@@ -67,6 +67,9 @@ pub trait BuildContext {
     /// Return a reference to the Git resolver.
     fn git(&self) -> &GitResolver;
 
+    /// Return a reference to the Mercurial resolver.
+    fn hg(&self) -> &HgResolver;
+
     /// Return a reference to the discovered registry capabilities.
     fn capabilities(&self) -> &IndexCapabilities;
 
@@ -12,6 +12,7 @@ use tracing::debug;
 
 use uv_fs::{cachedir, Simplified, CWD};
 use uv_pypi_types::Scheme;
+use uv_python::managed::minor_version_link_executable;
 use uv_python::{Interpreter, VirtualEnvironment};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_version::version;
@@ -65,6 +66,10 @@ pub(crate) fn create(
         interpreter.to_base_python()?
     };
 
+    // Refer to a managed Python installation through the link to its minor version, such that the
+    // environment uses later patch versions once they're installed, e.g., by `uv python upgrade`.
+    let base_python = minor_version_link_executable(&base_python).unwrap_or(base_python);
+
     debug!(
         "Using base executable for virtual environment: {}",
         base_python.display()
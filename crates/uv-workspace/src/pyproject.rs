@@ -19,7 +19,7 @@ use serde::{de::IntoDeserializer, de::SeqAccess, Deserialize, Deserializer, Seri
 use thiserror::Error;
 use url::Url;
 
-use uv_distribution_types::{Index, IndexName};
+use uv_distribution_types::{Index, IndexName, IndexUrl, IndexUrlError, TorchBackend};
 use uv_fs::{relative_to, PortablePathBuf};
 use uv_git_types::GitReference;
 use uv_macros::OptionsMetadata;
@@ -27,7 +27,8 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{
-    Conflicts, RequirementSource, SchemaConflicts, SupportedEnvironments, VerbatimParsedUrl,
+    Conflicts, LicenseId, LicenseMode, RequirementSource, SchemaConflicts, SupportedEnvironments,
+    VerbatimParsedUrl,
 };
 
 #[derive(Error, Debug)]
@@ -648,6 +649,140 @@ pub struct ToolUv {
         "#
     )]
     pub conflicts: Option<SchemaConflicts>,
+
+    /// A list of SPDX license identifiers that are permitted in the resolution.
+    ///
+    /// If set, uv will reject any package whose declared license expression can't be satisfied
+    /// by the allowed licenses, preferring alternative versions where possible. For example, with
+    /// `allowed-licenses = ["MIT"]`, a package licensed under `MIT OR GPL-3.0-only` is permitted,
+    /// while a package licensed under `MIT AND GPL-3.0-only` is not.
+    ///
+    /// Licenses are read from the `License-Expression` metadata field, falling back to the `License`
+    /// field if it contains a valid SPDX expression. Packages that don't declare a recognizable
+    /// license, along with local source trees (like workspace members), are always permitted.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(description = "A list of SPDX license identifiers, e.g., `MIT`.")
+    )]
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            # Only permit packages with a permissive license.
+            allowed-licenses = ["MIT", "BSD-3-Clause", "Apache-2.0"]
+        "#
+    )]
+    pub allowed_licenses: Option<Vec<LicenseId>>,
+
+    /// A list of SPDX license identifiers that are forbidden in the resolution.
+    ///
+    /// If set, uv will reject any package whose declared license expression requires one of the
+    /// denied licenses, preferring alternative versions where possible. GNU licenses may be listed
+    /// with or without the `-only` and `-or-later` suffixes.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(description = "A list of SPDX license identifiers, e.g., `GPL-3.0`.")
+    )]
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            # Forbid packages with a copyleft license.
+            denied-licenses = ["GPL-3.0", "AGPL-3.0"]
+        "#
+    )]
+    pub denied_licenses: Option<Vec<LicenseId>>,
+
+    /// How to treat packages with a license that's disallowed by `allowed-licenses` or
+    /// `denied-licenses`.
+    ///
+    /// By default (`deny`), uv will refuse to select such packages, and report the offending
+    /// dependency if no alternative can be found. With `warn`, uv will select such packages, but
+    /// warn about them along with the chain of dependencies through which they were included.
+    #[option(
+        default = "\"deny\"",
+        value_type = "str",
+        example = r#"
+            license-mode = "warn"
+        "#
+    )]
+    pub license_mode: Option<LicenseMode>,
+
+    /// The hardware backend to use for PyTorch (e.g., `cpu`, `cu121`, or `rocm6.2`).
+    ///
+    /// When set, uv will pin PyTorch and its companion packages (e.g., `torch`, `torchvision`,
+    /// and `torchaudio`) to the PyTorch index for the given backend (e.g.,
+    /// `https://download.pytorch.org/whl/cu121`), which hosts builds tagged with a local version
+    /// (e.g., `torch==2.5.1+cu121`). Packages with an explicit entry in `tool.uv.sources` are
+    /// unaffected.
+    ///
+    /// The index is registered under the name `pytorch-<backend>` (e.g., `pytorch-cu121`), such
+    /// that it can be overridden with a `[[tool.uv.index]]` entry of the same name. The selected
+    /// backend is recorded in the lockfile.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            torch-backend = "cu121"
+        "#
+    )]
+    pub torch_backend: Option<TorchBackend>,
+
+    /// Require a hash for every requirement that isn't resolved from a package index.
+    ///
+    /// When enabled, every direct URL requirement (e.g., `foo @ https://example.com/foo.whl`) must
+    /// include a hash fragment (e.g., `#sha256=...`), against which the downloaded archive is
+    /// validated, and every distribution resolved from a `--find-links` location must include a
+    /// hash. Locking will fail if any such requirement or distribution lacks a hash.
+    ///
+    /// Git dependencies and local source trees are exempt, as they can't be hashed.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            require-hashes = true
+        "#
+    )]
+    pub require_hashes: Option<bool>,
+
+    /// Prefer the smallest compatible wheel when installing from the lockfile.
+    ///
+    /// By default, when multiple wheels are compatible with the current platform, uv installs
+    /// the wheel with the most specific platform tags (e.g., a `manylinux` wheel over a
+    /// `py3-none-any` wheel). When enabled, uv instead installs the compatible wheel with the
+    /// smallest download size, as recorded in the lockfile, which can reduce download costs on
+    /// constrained CI runners. Wheels without a recorded size are considered last.
+    ///
+    /// The setting is recorded in the lockfile, alongside the size of each distribution.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            prefer-smaller-wheels = true
+        "#
+    )]
+    pub prefer_smaller_wheels: Option<bool>,
+
+    /// Packages to exclude from the resolution entirely.
+    ///
+    /// If any first-party or transitive dependency requires an excluded package, resolution will
+    /// fail with an error that includes the chain of dependencies that introduced it, rather than
+    /// silently installing the package.
+    ///
+    /// Unlike `override-dependencies`, which replaces the requirement for a package, excluded
+    /// packages are banned from the dependency graph altogether.
+    ///
+    /// Exclusions are only read from the `pyproject.toml` at the workspace root; declarations in
+    /// other workspace members are ignored.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            # Fail resolution if any dependency requires `example-cve-package`.
+            exclude-dependencies = ["example-cve-package"]
+        "#
+    )]
+    pub exclude_dependencies: Option<Vec<PackageName>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -1032,6 +1167,10 @@ pub enum Source {
         rev: Option<String>,
         tag: Option<String>,
         branch: Option<String>,
+        /// The path to the SSH private key (e.g., a deploy key) with which to fetch the repository
+        /// over SSH, relative to the project root.
+        #[serde(rename = "ssh-key")]
+        ssh_key: Option<PortablePathBuf>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1065,10 +1204,18 @@ pub enum Source {
     /// The path to a dependency, either a wheel (a `.whl` file), source distribution (a `.zip` or
     /// `.tar.gz` file), or source tree (i.e., a directory containing a `pyproject.toml` or
     /// `setup.py` file in the root).
+    ///
+    /// With `kind = "flat"`, the path instead refers to a directory of distributions, from which
+    /// the resolver selects the best-matching version, as in:
+    /// ```toml
+    /// mypkg = { path = "dist/", kind = "flat" }
+    /// ```
     Path {
         path: PortablePathBuf,
         /// `false` by default.
         editable: Option<bool>,
+        /// The kind of the path, if it doesn't refer to a single distribution or source tree.
+        kind: Option<PathSourceKind>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1121,9 +1268,11 @@ impl<'de> Deserialize<'de> for Source {
             rev: Option<String>,
             tag: Option<String>,
             branch: Option<String>,
+            ssh_key: Option<PortablePathBuf>,
             url: Option<Url>,
             path: Option<PortablePathBuf>,
             editable: Option<bool>,
+            kind: Option<PathSourceKind>,
             index: Option<IndexName>,
             workspace: Option<bool>,
             #[serde(
@@ -1143,9 +1292,11 @@ impl<'de> Deserialize<'de> for Source {
             rev,
             tag,
             branch,
+            ssh_key,
             url,
             path,
             editable,
+            kind,
             index,
             workspace,
             marker,
@@ -1187,6 +1338,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `git` and `editable`",
                 ));
             }
+            if kind.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `git` and `kind`",
+                ));
+            }
 
             // At most one of `rev`, `tag`, or `branch` may be set.
             match (rev.as_ref(), tag.as_ref(), branch.as_ref()) {
@@ -1214,6 +1370,7 @@ impl<'de> Deserialize<'de> for Source {
                 rev,
                 tag,
                 branch,
+                ssh_key,
                 marker,
                 extra,
                 group,
@@ -1257,11 +1414,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `branch`",
                 ));
             }
+            if ssh_key.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `ssh-key`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `editable`",
                 ));
             }
+            if kind.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `kind`",
+                ));
+            }
 
             return Ok(Self::Url {
                 url,
@@ -1309,10 +1476,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `branch`",
                 ));
             }
+            if ssh_key.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `ssh-key`",
+                ));
+            }
+            if kind == Some(PathSourceKind::Flat) && editable.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `kind = \"flat\"` and `editable`",
+                ));
+            }
 
             return Ok(Self::Path {
                 path,
                 editable,
+                kind,
                 marker,
                 extra,
                 group,
@@ -1356,11 +1534,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `branch`",
                 ));
             }
+            if ssh_key.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `ssh-key`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `editable`",
                 ));
             }
+            if kind.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `kind`",
+                ));
+            }
 
             return Ok(Self::Registry {
                 index,
@@ -1407,11 +1595,21 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `workspace` and `branch`",
                 ));
             }
+            if ssh_key.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `ssh-key`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `workspace` and `editable`",
                 ));
             }
+            if kind.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `kind`",
+                ));
+            }
 
             return Ok(Self::Workspace {
                 workspace,
@@ -1428,6 +1626,16 @@ impl<'de> Deserialize<'de> for Source {
     }
 }
 
+/// The kind of a `path` source in `tool.uv.sources`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum PathSourceKind {
+    /// A directory of built distributions (e.g., `dist/`), in which multiple versions and builds
+    /// of a package may coexist, akin to a `--find-links` location.
+    Flat,
+}
+
 #[derive(Error, Debug)]
 pub enum SourceError {
     #[error("Failed to resolve Git reference: `{0}`")]
@@ -1523,7 +1731,7 @@ impl Source {
                         group: None,
                     }))
                 }
-                RequirementSource::Url { .. } => {
+                RequirementSource::Url { .. } | RequirementSource::Hg { .. } => {
                     Err(SourceError::WorkspacePackageUrl(name.to_string()))
                 }
                 RequirementSource::Git { .. } => {
@@ -1539,6 +1747,11 @@ impl Source {
             RequirementSource::Registry { index: Some(_), .. } => {
                 return Ok(None);
             }
+            // Mercurial sources can't be expressed in `tool.uv.sources`, so they're retained as
+            // direct URL requirements.
+            RequirementSource::Hg { .. } => {
+                return Ok(None);
+            }
             RequirementSource::Registry { index: None, .. } => {
                 if let Some(index) = index {
                     Source::Registry {
@@ -1554,6 +1767,7 @@ impl Source {
             RequirementSource::Path { install_path, .. }
             | RequirementSource::Directory { install_path, .. } => Source::Path {
                 editable,
+                kind: None,
                 path: PortablePathBuf::from(
                     relative_to(&install_path, root)
                         .or_else(|_| std::path::absolute(&install_path))
@@ -1581,6 +1795,7 @@ impl Source {
                     let rev = match git.reference() {
                         GitReference::Branch(rev) => Some(rev),
                         GitReference::Tag(rev) => Some(rev),
+                        GitReference::TagRange(rev) => Some(rev),
                         GitReference::BranchOrTag(rev) => Some(rev),
                         GitReference::BranchOrTagOrCommit(rev) => Some(rev),
                         GitReference::NamedRef(rev) => Some(rev),
@@ -1592,6 +1807,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        ssh_key: None,
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1603,6 +1819,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        ssh_key: None,
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1614,6 +1831,49 @@ impl Source {
         Ok(Some(source))
     }
 
+    /// Return the [`Index`] for a flat `path` source, resolved relative to the given root
+    /// directory.
+    ///
+    /// Returns `None` if the source isn't a flat `path` source.
+    pub fn flat_index(&self, root: &Path) -> Option<Result<Index, IndexUrlError>> {
+        let Source::Path {
+            path,
+            kind: Some(PathSourceKind::Flat),
+            ..
+        } = self
+        else {
+            return None;
+        };
+        // The directory is only consulted for the packages that select it explicitly.
+        Some(
+            IndexUrl::parse(&path.to_string(), Some(root)).map(|url| Index {
+                explicit: true,
+                ..Index::from_find_links(url)
+            }),
+        )
+    }
+
+    /// Return the path to the SSH key for a Git source, resolved relative to the given root
+    /// directory.
+    ///
+    /// Paths that begin with `~` are left for SSH to expand. Returns `None` if the source isn't a
+    /// Git source, or doesn't specify an SSH key.
+    pub fn ssh_key(&self, root: &Path) -> Option<PathBuf> {
+        let Source::Git {
+            ssh_key: Some(ssh_key),
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let ssh_key = PathBuf::from(ssh_key.clone());
+        if ssh_key.starts_with("~") {
+            Some(ssh_key)
+        } else {
+            Some(root.join(ssh_key))
+        }
+    }
+
     /// Return the [`MarkerTree`] for the source.
     pub fn marker(&self) -> MarkerTree {
         match self {
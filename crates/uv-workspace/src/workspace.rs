@@ -6,20 +6,21 @@ use std::path::{Path, PathBuf};
 use glob::{glob, GlobError, PatternError};
 use rustc_hash::FxHashSet;
 use tracing::{debug, trace, warn};
-use uv_distribution_types::Index;
+use uv_distribution_types::{Index, TorchBackend};
 use uv_fs::{Simplified, CWD};
 use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl};
 use uv_pypi_types::{
-    Conflicts, Requirement, RequirementSource, SupportedEnvironments, VerbatimParsedUrl,
+    Conflicts, LicensePolicy, Requirement, RequirementSource, SupportedEnvironments,
+    VerbatimParsedUrl,
 };
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Sources, ToolUvSources, ToolUvWorkspace,
+    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -245,13 +246,7 @@ impl Workspace {
             let workspace_pyproject_toml = pyproject_toml.clone();
 
             // Refresh the workspace sources.
-            let workspace_sources = workspace_pyproject_toml
-                .tool
-                .clone()
-                .and_then(|tool| tool.uv)
-                .and_then(|uv| uv.sources)
-                .map(ToolUvSources::into_inner)
-                .unwrap_or_default();
+            let workspace_sources = workspace_sources(&workspace_pyproject_toml);
 
             // Set the `pyproject.toml` for the member.
             member.pyproject_toml = pyproject_toml;
@@ -383,6 +378,58 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the license policy for the workspace.
+    pub fn license_policy(&self) -> LicensePolicy {
+        let Some(uv) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+        else {
+            return LicensePolicy::default();
+        };
+        LicensePolicy::new(
+            uv.allowed_licenses.clone().unwrap_or_default(),
+            uv.denied_licenses.clone().unwrap_or_default(),
+            uv.license_mode.unwrap_or_default(),
+        )
+    }
+
+    /// Returns `true` if the workspace requires hashes for all non-registry requirements.
+    pub fn require_hashes(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.require_hashes)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the smallest compatible wheel should be preferred at install time.
+    pub fn prefer_smaller_wheels(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.prefer_smaller_wheels)
+            .unwrap_or_default()
+    }
+
+    /// Returns the set of packages that are excluded from the workspace resolution.
+    pub fn exclude_dependencies(&self) -> Vec<PackageName> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.exclude_dependencies.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the PyTorch backend for the workspace, if any.
+    pub fn torch_backend(&self) -> Option<TorchBackend> {
+        torch_backend(&self.pyproject_toml)
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Conflicts {
         let mut conflicting = Conflicts::empty();
@@ -855,20 +902,8 @@ impl Workspace {
             }
         }
 
-        let workspace_sources = workspace_pyproject_toml
-            .tool
-            .clone()
-            .and_then(|tool| tool.uv)
-            .and_then(|uv| uv.sources)
-            .map(ToolUvSources::into_inner)
-            .unwrap_or_default();
-
-        let workspace_indexes = workspace_pyproject_toml
-            .tool
-            .clone()
-            .and_then(|tool| tool.uv)
-            .and_then(|uv| uv.index)
-            .unwrap_or_default();
+        let workspace_sources = workspace_sources(&workspace_pyproject_toml);
+        let workspace_indexes = workspace_indexes(&workspace_pyproject_toml);
 
         Ok(Workspace {
             install_path: workspace_root,
@@ -880,6 +915,67 @@ impl Workspace {
     }
 }
 
+/// Read the sources table from the workspace `pyproject.toml`.
+fn workspace_sources(pyproject_toml: &PyProjectToml) -> BTreeMap<PackageName, Sources> {
+    let mut sources = pyproject_toml
+        .tool
+        .clone()
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.sources)
+        .map(ToolUvSources::into_inner)
+        .unwrap_or_default();
+    for (name, source) in torch_backend_sources(pyproject_toml) {
+        sources.entry(name).or_insert(source);
+    }
+    sources
+}
+
+/// Read the index table from the workspace `pyproject.toml`.
+fn workspace_indexes(pyproject_toml: &PyProjectToml) -> Vec<Index> {
+    let mut indexes = pyproject_toml
+        .tool
+        .clone()
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.index)
+        .unwrap_or_default();
+    if let Some(index) = torch_backend(pyproject_toml).map(TorchBackend::index) {
+        // Allow the PyTorch index to be overridden by an index with the same name.
+        if !indexes.iter().any(|existing| existing.name == index.name) {
+            indexes.push(index);
+        }
+    }
+    indexes
+}
+
+/// Returns the sources implied by the `torch-backend` setting, which pin each PyTorch package to
+/// the index for the backend.
+fn torch_backend_sources(
+    pyproject_toml: &PyProjectToml,
+) -> impl Iterator<Item = (PackageName, Sources)> {
+    torch_backend(pyproject_toml)
+        .into_iter()
+        .flat_map(|torch_backend| {
+            TorchBackend::packages().map(move |name| {
+                let source = Source::Registry {
+                    index: torch_backend.index_name(),
+                    marker: MarkerTree::TRUE,
+                    extra: None,
+                    group: None,
+                };
+                (name, Sources::from_iter([source]))
+            })
+        })
+}
+
+/// Read the PyTorch backend from the workspace `pyproject.toml`.
+fn torch_backend(pyproject_toml: &PyProjectToml) -> Option<TorchBackend> {
+    pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.torch_backend)
+}
+
 /// A project in a workspace.
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(serde::Serialize))]
@@ -1175,9 +1271,13 @@ impl ProjectWorkspace {
                     install_path: project_path.clone(),
                     packages: current_project_as_members,
                     // There may be package sources, but we don't need to duplicate them into the
-                    // workspace sources.
-                    sources: BTreeMap::default(),
-                    indexes: Vec::default(),
+                    // workspace sources. The sources and index implied by the PyTorch backend,
+                    // however, aren't declared in the project.
+                    sources: torch_backend_sources(project_pyproject_toml).collect(),
+                    indexes: torch_backend(project_pyproject_toml)
+                        .map(TorchBackend::index)
+                        .into_iter()
+                        .collect(),
                     pyproject_toml: project_pyproject_toml.clone(),
                 },
             });
@@ -1746,7 +1846,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
@@ -1840,7 +1947,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
@@ -2048,7 +2162,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
@@ -2154,7 +2275,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
@@ -2273,7 +2401,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
@@ -2366,7 +2501,14 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
-                      "conflicts": null
+                      "conflicts": null,
+                      "allowed-licenses": null,
+                      "denied-licenses": null,
+                      "license-mode": null,
+                      "torch-backend": null,
+                      "require-hashes": null,
+                      "prefer-smaller-wheels": null,
+                      "exclude-dependencies": null
                     }
                   },
                   "dependency-groups": null
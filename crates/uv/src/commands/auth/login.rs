@@ -0,0 +1,58 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::IndexLocations;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for an index.
+pub(crate) fn login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = resolve_service(service, index_locations)?;
+
+    let term = Term::stderr();
+    let username = match username {
+        Some(username) => username,
+        None if term.is_term() => {
+            let prompt = "Enter username ('__token__' if using a token): ";
+            uv_console::input(prompt, &term).context("Failed to read username")?
+        }
+        None => bail!("No username provided; use `--username` or `--token`"),
+    };
+    if username.is_empty() {
+        bail!("The username must not be empty");
+    }
+    let password = match password {
+        Some(password) => password,
+        None if term.is_term() => {
+            let prompt = "Enter password: ";
+            uv_console::password(prompt, &term).context("Failed to read password")?
+        }
+        None => bail!("No password provided; use `--password` or `--token`"),
+    };
+
+    let mut store = CredentialStore::from_settings()?;
+    store
+        .login(&url, &username, &password)
+        .with_context(|| format!("Failed to store credentials for {url}"))?;
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} at {}",
+        username.bold(),
+        url.cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
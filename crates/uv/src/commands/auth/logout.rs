@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::IndexLocations;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove stored credentials for an index.
+pub(crate) fn logout(
+    service: &str,
+    username: Option<&str>,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = resolve_service(service, index_locations)?;
+
+    let mut store = CredentialStore::from_settings()?;
+    let removed = store
+        .logout(&url, username)
+        .with_context(|| format!("Failed to remove credentials for {url}"))?;
+
+    if removed.is_empty() {
+        writeln!(printer.stderr(), "No credentials found for {}", url.cyan())?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    for credential in removed {
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for {} at {}",
+            credential.username.bold(),
+            credential.service.cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,41 @@
+use anyhow::{bail, Result};
+use url::Url;
+
+use uv_distribution_types::IndexLocations;
+
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// Resolve the URL of the service to authenticate with, given either the name of an index in the
+/// configuration or a URL.
+fn resolve_service(service: &str, index_locations: &IndexLocations) -> Result<Url> {
+    if let Some(index) = index_locations.simple_indexes().find(|index| {
+        index
+            .name
+            .as_ref()
+            .is_some_and(|name| name.as_ref() == service)
+    }) {
+        return Ok(index.raw_url().clone());
+    }
+
+    if service.contains("://") {
+        let url = Url::parse(service)?;
+        if url.host_str().is_none() {
+            bail!("Expected a URL with a host, but got: `{service}`");
+        }
+        return Ok(url);
+    }
+
+    let mut index_names: Vec<String> = index_locations
+        .simple_indexes()
+        .filter_map(|index| index.name.as_ref())
+        .map(ToString::to_string)
+        .collect();
+    index_names.sort();
+    if index_names.is_empty() {
+        bail!("No indexes were found, and `{service}` is not a URL")
+    }
+    let index_names = index_names.join("`, `");
+    bail!("Index not found: `{service}`. Found indexes: `{index_names}`")
+}
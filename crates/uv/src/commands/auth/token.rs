@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use anstream::println;
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::IndexLocations;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the stored password or token for an index.
+pub(crate) fn token(
+    service: &str,
+    username: Option<&str>,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = resolve_service(service, index_locations)?;
+
+    let store = CredentialStore::from_settings()?;
+    let password = match store.find(&url, username) {
+        Some(credential) => CredentialStore::password(credential)
+            .with_context(|| format!("Failed to read credentials for {url}"))?,
+        None => None,
+    };
+
+    let Some(password) = password else {
+        writeln!(printer.stderr(), "No credentials found for {}", url.cyan())?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    println!("{password}");
+
+    Ok(ExitStatus::Success)
+}
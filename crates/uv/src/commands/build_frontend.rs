@@ -33,6 +33,7 @@ use uv_fs::{relative_to, Simplified};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -110,7 +111,9 @@ pub(crate) async fn build_frontend(
     network_settings: &NetworkSettings,
     no_config: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -135,7 +138,9 @@ pub(crate) async fn build_frontend(
         network_settings,
         no_config,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         concurrency,
         cache,
         printer,
@@ -178,7 +183,9 @@ async fn build_impl(
     network_settings: &NetworkSettings,
     no_config: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -201,6 +208,12 @@ async fn build_impl(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        minimal_changes: _,
+        break_extra_cycles: _,
+        prefetch_versions: _,
+        budget: _,
+        yanked: _,
+        supported_python: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
@@ -215,7 +228,8 @@ async fn build_impl(
     let client_builder = BaseClientBuilder::default()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Determine the source to build.
     let src = if let Some(src) = src {
@@ -322,7 +336,9 @@ async fn build_impl(
             no_config,
             workspace.as_ref(),
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             cache,
             printer,
             index_locations,
@@ -400,7 +416,9 @@ async fn build_package(
     no_config: bool,
     workspace: Result<&Workspace, &WorkspaceError>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     cache: &Cache,
     printer: Printer,
     index_locations: &IndexLocations,
@@ -472,12 +490,15 @@ async fn build_package(
         interpreter_request.as_ref(),
         EnvironmentPreference::Any,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         client_builder,
         cache,
         Some(&PythonDownloadReporter::single(printer)),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
     .into_interpreter();
@@ -522,8 +543,11 @@ async fn build_package(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -3,17 +3,19 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, CacheEntryKind, PackageFilter, Removal};
 use uv_fs::Simplified;
-use uv_normalize::PackageName;
 
 use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageReporter};
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Clear the cache, removing all entries or those linked to specific packages.
+///
+/// For packages, removal may be restricted to specific versions and kinds of cache entries.
 pub(crate) fn cache_clean(
-    packages: &[PackageName],
+    packages: &[PackageFilter],
+    kinds: &[CacheEntryKind],
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -44,9 +46,9 @@ pub(crate) fn cache_clean(
         let mut summary = Removal::default();
 
         for package in packages {
-            let removed = cache.remove(package)?;
+            let removed = cache.remove_filtered(package, kinds)?;
             summary += removed;
-            reporter.on_clean(package.as_str(), &summary);
+            reporter.on_clean(&package.to_string(), &summary);
         }
         reporter.on_complete();
 
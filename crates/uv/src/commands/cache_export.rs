@@ -0,0 +1,189 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use async_compression::tokio::write::ZstdEncoder;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_distribution::HttpArchivePointer;
+use uv_distribution_types::{BuiltDist, Dist, SourceDist};
+use uv_fs::Simplified;
+use uv_resolver::Lock;
+use uv_warnings::warn_user;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Export the cached artifacts needed to install from a lockfile to a `.tar.zst` archive.
+///
+/// For each package in the lockfile, the archive includes any downloaded wheels (alongside their
+/// unzipped contents) and any source distributions (alongside the wheels built from them) that are
+/// present in the cache. Entries are stored relative to the cache root, such that the archive can
+/// be imported into the cache of another machine with `uv cache import`.
+pub(crate) async fn cache_export(
+    lock_path: &Path,
+    output: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let encoded = fs_err::tokio::read_to_string(lock_path).await?;
+    let lock: Lock = toml::from_str(&encoded)
+        .with_context(|| format!("Failed to parse lockfile at: {}", lock_path.user_display()))?;
+
+    // Relative paths in the lockfile are relative to the directory that contains it.
+    let workspace_root = std::path::absolute(lock_path)?
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    // Collect the cache entries for each package.
+    let mut entries = BTreeSet::new();
+    let mut exported = 0usize;
+    let mut missing = Vec::new();
+    for package in lock.packages() {
+        let mut cacheable = false;
+        let mut found = false;
+        for dist in package.to_dists(&workspace_root)? {
+            let Some(paths) = cache_entries(&dist, cache)? else {
+                continue;
+            };
+            cacheable = true;
+            found |= !paths.is_empty();
+            entries.extend(paths);
+        }
+        if found {
+            exported += 1;
+        } else if cacheable {
+            missing.push(package.name());
+        }
+    }
+
+    if !missing.is_empty() {
+        warn_user!(
+            "No cached artifacts found for {} {}: {}",
+            missing.len(),
+            if missing.len() == 1 {
+                "package"
+            } else {
+                "packages"
+            },
+            missing.iter().sorted().join(", ")
+        );
+    }
+
+    // Write the entries to the archive, relative to the cache root.
+    let file = fs_err::tokio::File::create(output).await?;
+    let mut builder = tokio_tar::Builder::new(ZstdEncoder::new(file));
+    builder.follow_symlinks(false);
+    for entry in &entries {
+        let name = entry
+            .strip_prefix(cache.root())
+            .expect("cache entries are within the cache root");
+        if entry.is_dir() {
+            builder.append_dir_all(name, entry).await?;
+        } else {
+            builder.append_path_with_name(entry, name).await?;
+        }
+    }
+    let mut encoder = builder.into_inner().await?;
+    encoder.shutdown().await?;
+
+    let (bytes, unit) = human_readable_bytes(fs_err::tokio::metadata(output).await?.len());
+    writeln!(
+        printer.stderr(),
+        "Exported cached artifacts for {} to: {} {}",
+        format!(
+            "{exported} {}",
+            if exported == 1 { "package" } else { "packages" }
+        )
+        .bold(),
+        output.user_display().cyan(),
+        format!("({bytes:.1}{unit})").dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the cache entries that exist for a distribution.
+///
+/// Returns `None` for distributions that aren't cached by URL (e.g., Git repositories and local
+/// paths), and so can't be exported.
+fn cache_entries(dist: &Dist, cache: &Cache) -> Result<Option<Vec<PathBuf>>> {
+    let entries = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => {
+            let wheel = wheels.best_wheel();
+            let pointer = cache
+                .shard(
+                    CacheBucket::Wheels,
+                    WheelCache::Index(&wheel.index).wheel_dir(wheel.name().as_ref()),
+                )
+                .entry(format!("{}.http", wheel.filename.cache_key()));
+            archive_entries(pointer, cache)?
+        }
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+            let pointer = cache
+                .shard(
+                    CacheBucket::Wheels,
+                    WheelCache::Url(&wheel.url).wheel_dir(wheel.name().as_ref()),
+                )
+                .entry(format!("{}.http", wheel.filename.cache_key()));
+            archive_entries(pointer, cache)?
+        }
+        Dist::Source(SourceDist::Registry(sdist)) => {
+            let shard = cache.shard(
+                CacheBucket::SourceDistributions,
+                WheelCache::Index(&sdist.index)
+                    .wheel_dir(sdist.name.as_ref())
+                    .join(sdist.version.to_string()),
+            );
+            existing(shard.into_path_buf())
+        }
+        Dist::Source(SourceDist::DirectUrl(sdist)) => {
+            let shard = cache.shard(
+                CacheBucket::SourceDistributions,
+                WheelCache::Url(&sdist.url).root(),
+            );
+            existing(shard.into_path_buf())
+        }
+        Dist::Built(BuiltDist::Path(_))
+        | Dist::Source(
+            SourceDist::Git(_) | SourceDist::Hg(_) | SourceDist::Path(_) | SourceDist::Directory(_),
+        ) => return Ok(None),
+    };
+    Ok(Some(entries))
+}
+
+/// Return the pointer to a downloaded wheel, along with the unzipped wheel it points to, if both
+/// exist.
+fn archive_entries(pointer: CacheEntry, cache: &Cache) -> Result<Vec<PathBuf>> {
+    let Some(archive) = HttpArchivePointer::read_from(&pointer)? else {
+        return Ok(Vec::new());
+    };
+    let archive = cache.archive(&archive.into_archive().id);
+    if !archive.is_dir() {
+        return Ok(Vec::new());
+    }
+    Ok(vec![pointer.into_path_buf(), archive])
+}
+
+/// Return the path as a single entry, if it exists.
+fn existing(path: PathBuf) -> Vec<PathBuf> {
+    if path.exists() {
+        vec![path]
+    } else {
+        Vec::new()
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_distribution_filename::SourceDistExtension;
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Import cached artifacts from an archive created by `uv cache export`.
+///
+/// Existing cache entries are retained, such that importing an archive never replaces an entry
+/// that's in use.
+pub(crate) async fn cache_import(
+    archive: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Extract the archive into a temporary directory within the cache, such that each entry can
+    // be moved into place atomically.
+    let temp_dir = tempfile::tempdir_in(cache.root())?;
+    let reader = fs_err::tokio::File::open(archive).await?;
+    uv_extract::stream::archive(
+        tokio::io::BufReader::new(reader),
+        SourceDistExtension::TarZst,
+        temp_dir.path(),
+    )
+    .await
+    .with_context(|| format!("Failed to extract archive: {}", archive.user_display()))?;
+
+    // Collect the files up front, as they're moved out of the temporary directory below.
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(temp_dir.path()) {
+        let entry = entry?;
+        if !entry.file_type().is_dir() {
+            files.push(entry.into_path());
+        }
+    }
+
+    // Move each file into place, retaining any existing entries.
+    let mut imported = 0usize;
+    let mut skipped = 0usize;
+    for file in files {
+        let relative = file
+            .strip_prefix(temp_dir.path())
+            .expect("extracted files are within the temporary directory");
+        let target = cache.root().join(relative);
+        if target.symlink_metadata().is_ok() {
+            skipped += 1;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::rename(&file, &target)?;
+        imported += 1;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Imported {} into cache at: {}",
+        format!(
+            "{imported} {}",
+            if imported == 1 { "file" } else { "files" }
+        )
+        .bold(),
+        cache.root().user_display().cyan()
+    )?;
+    if skipped > 0 {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Skipped {skipped} {} already present in the cache",
+                if skipped == 1 { "file" } else { "files" }
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -4,13 +4,29 @@ use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
 use uv_cache::{Cache, Removal};
+use uv_configuration::{CacheRetention, CacheSize};
+use uv_distribution_types::IndexUrl;
 use uv_fs::Simplified;
 
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// If indexes are provided, every entry supplied by any of the indexes is removed first, such that
+/// the objects that become unreachable are pruned alongside them.
+///
+/// If retention periods are provided, any entries that haven't been used within the periods are
+/// removed. If a maximum size is provided, the least-recently-used entries are then evicted until
+/// the cache is within the limit.
+pub(crate) fn cache_prune(
+    ci: bool,
+    indexes: &[IndexUrl],
+    max_size: Option<CacheSize>,
+    retention: Option<CacheRetention>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -28,6 +44,13 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
 
     let mut summary = Removal::default();
 
+    // Remove the entries supplied by each of the given indexes.
+    for index in indexes {
+        summary += cache.remove_index(index).with_context(|| {
+            format!("Failed to remove entries from index: {}", index.redacted())
+        })?;
+    }
+
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
     summary += uv_distribution::prune(cache)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
@@ -37,6 +60,26 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Remove any entries that haven't been used within the retention periods.
+    if let Some(retention) = retention {
+        summary += cache.expire(&retention.buckets()).with_context(|| {
+            format!(
+                "Failed to remove unused entries from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+    }
+
+    // Evict the least-recently-used entries until the cache is within the size limit.
+    if let Some(max_size) = max_size {
+        summary += cache.evict(max_size.bytes()).with_context(|| {
+            format!(
+                "Failed to evict entries from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Show the size of each cache bucket, and the cache accesses of recent commands.
+pub(crate) fn cache_stats(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+    writeln!(printer.stdout())?;
+
+    let usage = cache
+        .usage()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+    let width = usage
+        .iter()
+        .map(|usage| label(usage.bucket).len())
+        .max()
+        .unwrap_or_default();
+    for usage in &usage {
+        writeln!(
+            printer.stdout(),
+            "{:width$}  {:>10}  {}",
+            label(usage.bucket),
+            format_bytes(usage.total_bytes),
+            format_files(usage.num_files).dimmed(),
+        )?;
+    }
+    writeln!(
+        printer.stdout(),
+        "{:width$}  {:>10}  {}",
+        "Total".bold(),
+        format_bytes(usage.iter().map(|usage| usage.total_bytes).sum()).bold(),
+        format_files(usage.iter().map(|usage| usage.num_files).sum()).dimmed(),
+    )?;
+
+    let accesses = cache
+        .accesses()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+    writeln!(printer.stdout())?;
+    if accesses.is_empty() {
+        writeln!(printer.stdout(), "No HTTP cache accesses recorded")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(printer.stdout(), "HTTP cache accesses of recent commands:")?;
+    for access in accesses.iter().rev() {
+        let timestamp = i64::try_from(access.timestamp)
+            .ok()
+            .and_then(|timestamp| jiff::Timestamp::from_second(timestamp).ok())
+            .unwrap_or(jiff::Timestamp::UNIX_EPOCH)
+            .strftime("%Y-%m-%d %H:%M:%S UTC");
+        let hit_rate = (access.hits + access.revalidations) * 100 / access.total().max(1);
+        writeln!(
+            printer.stdout(),
+            "  {}  {} hits, {} revalidated, {} misses ({})",
+            timestamp.dimmed(),
+            access.hits,
+            access.revalidations,
+            access.misses,
+            format!("{hit_rate}% served from cache").green(),
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return a human-readable description of the entries in a cache bucket.
+fn label(bucket: CacheBucket) -> &'static str {
+    match bucket {
+        CacheBucket::Wheels => "Wheels",
+        CacheBucket::SourceDistributions => "Source distribution builds",
+        CacheBucket::FlatIndex => "Flat index metadata",
+        CacheBucket::Git => "Git repositories",
+        CacheBucket::Hg => "Mercurial repositories",
+        CacheBucket::Interpreter => "Interpreter metadata",
+        CacheBucket::Simple => "Package index metadata",
+        CacheBucket::Content => "Deduplicated wheel files",
+        CacheBucket::Archive => "Unzipped wheels",
+        CacheBucket::Builds => "Build environments",
+        CacheBucket::Environments => "Ephemeral environments",
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+fn format_files(num_files: u64) -> String {
+    if num_files == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{num_files} files")
+    }
+}
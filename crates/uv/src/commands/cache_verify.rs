@@ -0,0 +1,127 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{rm_rf, Cache, CacheBucket};
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, removing any corrupted entries.
+///
+/// The files of each unzipped wheel are verified against the hashes in its `RECORD` file, and the
+/// files in the content-addressed store are verified against the hashes under which they're
+/// stored. Removed entries are downloaded (or built) again when next used.
+pub(crate) fn cache_verify(dry_run: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let mut corrupted: Vec<(PathBuf, String)> = Vec::new();
+
+    // Verify the content-addressed store first, such that corrupted files aren't linked into new
+    // unzipped wheels.
+    for path in cache
+        .corrupted_content()
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?
+    {
+        corrupted.push((path, "Contents don't match the stored hash".to_string()));
+    }
+
+    // Verify each unzipped wheel against its `RECORD` file.
+    let mut checked = 0usize;
+    let archives = cache.bucket(CacheBucket::Archive);
+    if archives.is_dir() {
+        for entry in fs_err::read_dir(&archives)? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            // Cached environments are stored alongside unzipped wheels, but aren't verifiable.
+            if path.join("pyvenv.cfg").is_file() {
+                continue;
+            }
+
+            checked += 1;
+            match uv_install_wheel::verify_record(&path) {
+                Ok(_) => {}
+                Err(uv_install_wheel::Error::Io(err)) => {
+                    return Err(err).with_context(|| {
+                        format!("Failed to verify cache entry: {}", path.user_display())
+                    });
+                }
+                Err(err) => corrupted.push((path, err.to_string())),
+            }
+        }
+    }
+
+    for (path, reason) in &corrupted {
+        writeln!(
+            printer.stderr(),
+            "{}: {} ({reason})",
+            "Corrupted".red().bold(),
+            path.user_display().cyan(),
+        )?;
+    }
+
+    let wheels = format!(
+        "{checked} unzipped {}",
+        if checked == 1 { "wheel" } else { "wheels" }
+    );
+    let entries = format!(
+        "{} corrupted {}",
+        corrupted.len(),
+        if corrupted.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        }
+    );
+
+    if corrupted.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Checked {}; no corrupted entries found",
+            wheels.bold()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "Checked {}; found {}",
+            wheels.bold(),
+            entries.bold()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    for (path, _) in &corrupted {
+        rm_rf(path)
+            .with_context(|| format!("Failed to remove cache entry: {}", path.user_display()))?;
+    }
+    writeln!(
+        printer.stderr(),
+        "Checked {}; removed {}",
+        wheels.bold(),
+        entries.bold()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use version_ranges::Ranges;
@@ -85,6 +86,10 @@ impl OperationDiagnostic {
                 }
                 None
             }
+            pip::operations::Error::Resolve(uv_resolver::ResolveError::BudgetExceeded(err)) => {
+                budget_exceeded(err);
+                None
+            }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Dist(
                 kind,
                 dist,
@@ -253,6 +258,42 @@ pub(crate) fn no_solution_hint(err: uv_resolver::NoSolutionError, help: String)
     anstream::eprint!("{report:?}");
 }
 
+/// Render a [`uv_resolver::BudgetExceededError`] with a help message.
+pub(crate) fn budget_exceeded(err: uv_resolver::BudgetExceededError) {
+    #[derive(Debug, miette::Diagnostic, thiserror::Error)]
+    #[error("{err}")]
+    #[diagnostic()]
+    struct Error {
+        /// The underlying error.
+        err: uv_resolver::BudgetExceededError,
+
+        /// The help message to display.
+        #[help]
+        help: String,
+    }
+
+    let help = if err.hotspots().is_empty() {
+        format!(
+            "Consider increasing the limit with `{}` or `{}`",
+            "--max-resolver-iterations".green(),
+            "--resolver-timeout".green()
+        )
+    } else {
+        format!(
+            "Consider adding constraints on {} to narrow the search space, or increasing the limit with `{}` or `{}`",
+            err.hotspots()
+                .iter()
+                .take(3)
+                .map(|hotspot| format!("`{}`", hotspot.name.cyan()))
+                .join(", "),
+            "--max-resolver-iterations".green(),
+            "--resolver-timeout".green()
+        )
+    };
+    let report = miette::Report::new(Error { err, help });
+    anstream::eprint!("{report:?}");
+}
+
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
 pub(crate) fn native_tls_hint(err: uv_client::Error) {
     #[derive(Debug, miette::Diagnostic)]
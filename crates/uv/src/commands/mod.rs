@@ -7,10 +7,17 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+pub(crate) use auth::login::login as auth_login;
+pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
+pub(crate) use cache_import::cache_import;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_stats::cache_stats;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -25,6 +32,7 @@ pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
 pub(crate) use project::lock::lock;
+pub(crate) use project::prefetch::prefetch as cache_prefetch;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
 pub(crate) use project::sync::sync;
@@ -35,7 +43,10 @@ pub(crate) use python::find::find as python_find;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::relocate::relocate as python_move;
+pub(crate) use python::shim::shim as python_shim;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use python::upgrade::upgrade as python_upgrade;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
@@ -59,11 +70,16 @@ pub(crate) use version::version;
 
 use crate::printer::Printer;
 
+mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_export;
+mod cache_import;
 mod cache_prune;
+mod cache_stats;
+mod cache_verify;
 mod diagnostics;
 mod help;
 pub(crate) mod pip;
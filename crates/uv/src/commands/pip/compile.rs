@@ -36,7 +36,7 @@ use uv_requirements::{
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
     InMemoryIndex, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
-    ResolutionMode, ResolverEnvironment,
+    ResolutionBudget, ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::warn_user;
@@ -64,6 +64,11 @@ pub(crate) async fn pip_compile(
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
+    minimal_changes: bool,
+    break_extra_cycles: bool,
+    prefetch_versions: Option<usize>,
+    budget: ResolutionBudget,
+    yanked: YankedPolicy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -97,6 +102,7 @@ pub(crate) async fn pip_compile(
     mut python: Option<String>,
     system: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     concurrency: Concurrency,
     quiet: bool,
     cache: Cache,
@@ -138,7 +144,8 @@ pub(crate) async fn pip_compile(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -219,7 +226,13 @@ pub(crate) async fn pip_compile(
     let environment_preference = EnvironmentPreference::from_system_flag(system, false);
     let interpreter = if let Some(python) = python.as_ref() {
         let request = PythonRequest::parse(python);
-        PythonInstallation::find(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find(
+            &request,
+            environment_preference,
+            python_preference,
+            python_install_dir,
+            &cache,
+        )
     } else {
         // TODO(zanieb): The split here hints at a problem with the request abstraction; we should
         // be able to use `PythonInstallation::find(...)` here.
@@ -229,7 +242,13 @@ pub(crate) async fn pip_compile(
         } else {
             PythonRequest::default()
         };
-        PythonInstallation::find_best(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find_best(
+            &request,
+            environment_preference,
+            python_preference,
+            python_install_dir,
+            &cache,
+        )
     }?
     .into_interpreter();
 
@@ -339,6 +358,8 @@ pub(crate) async fn pip_compile(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -403,6 +424,11 @@ pub(crate) async fn pip_compile(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .fork_strategy(fork_strategy)
+        .minimal_changes(minimal_changes)
+        .break_extra_cycles(break_extra_cycles)
+        .prefetch_versions(prefetch_versions)
+        .budget(budget)
+        .yanked(yanked)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
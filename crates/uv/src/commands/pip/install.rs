@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
 
 use itertools::Itertools;
@@ -31,13 +32,14 @@ use uv_python::{
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::report::InstallationReport;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
@@ -78,16 +80,20 @@ pub(crate) async fn pip_install(
     python_platform: Option<TargetTriple>,
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
+    yanked: YankedPolicy,
     sources: SourceStrategy,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    report: Option<&Path>,
     printer: Printer,
     preview: PreviewMode,
 ) -> anyhow::Result<ExitStatus> {
@@ -97,7 +103,8 @@ pub(crate) async fn pip_install(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -158,7 +165,7 @@ pub(crate) async fn pip_install(
             .collect();
 
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if target.is_some() || prefix.is_some() || user {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
@@ -166,6 +173,7 @@ pub(crate) async fn pip_install(
                 .unwrap_or_default(),
             EnvironmentPreference::from_system_flag(system, false),
             python_preference,
+            python_install_dir,
             &cache,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -183,7 +191,7 @@ pub(crate) async fn pip_install(
         environment
     };
 
-    // Apply any `--target` or `--prefix` directories.
+    // Apply any `--target`, `--prefix`, or `--user` directories.
     let environment = if let Some(target) = target {
         debug!(
             "Using `--target` directory at {}",
@@ -196,6 +204,21 @@ pub(crate) async fn pip_install(
             prefix.root().user_display()
         );
         environment.with_prefix(prefix)?
+    } else if user {
+        if environment.interpreter().is_virtualenv() {
+            return Err(anyhow::anyhow!(
+                "Can't perform a `--user` install, as the user site directory is not visible in the virtual environment at {}",
+                environment.root().user_display().cyan()
+            ));
+        }
+        let environment = environment.with_user()?;
+        if let Some(user) = environment.interpreter().user() {
+            debug!(
+                "Using user site directory at {}",
+                user.root().user_display()
+            );
+        }
+        environment
     } else {
         environment
     };
@@ -260,6 +283,9 @@ pub(crate) async fn pip_install(
                 if dry_run.enabled() {
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
+                if let Some(report) = report {
+                    InstallationReport::empty(&marker_env).write(report)?;
+                }
 
                 return Ok(ExitStatus::Success);
             }
@@ -335,6 +361,8 @@ pub(crate) async fn pip_install(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -410,13 +438,14 @@ pub(crate) async fn pip_install(
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .yanked(yanked)
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
         .build();
 
     // Resolve the requirements.
     let resolution = match operations::resolve(
-        requirements,
+        requirements.clone(),
         constraints,
         overrides,
         source_trees,
@@ -459,6 +488,7 @@ pub(crate) async fn pip_install(
         modifications,
         &reinstall,
         &build_options,
+        false,
         link_mode,
         compile,
         &index_locations,
@@ -478,7 +508,19 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(_) => {}
+        Ok(changelog) => {
+            // Write the installation report, if requested.
+            if let Some(report) = report {
+                InstallationReport::from_changelog(
+                    &changelog,
+                    &resolution,
+                    &requirements,
+                    &environment,
+                    &marker_env,
+                )?
+                .write(report)?;
+            }
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -9,6 +9,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use uv_distribution_types::{InstalledMetadata, Name};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_resolver::ForkReport;
 
 use crate::commands::pip::operations::Changelog;
 use crate::commands::{elapsed, ChangeEvent, ChangeEventKind};
@@ -399,6 +400,17 @@ pub(crate) trait ResolveLogger {
     /// Log the completion of the operation.
     fn on_complete(&self, count: usize, start: std::time::Instant, printer: Printer)
         -> fmt::Result;
+
+    /// Returns `true` if the logger reports on the forks created during resolution, in which
+    /// case a fresh resolution is required, even if the existing lockfile is up-to-date.
+    fn reports_forks(&self) -> bool {
+        false
+    }
+
+    /// Log the forks that were created during resolution.
+    fn on_forks(&self, _report: &ForkReport, _printer: Printer) -> fmt::Result {
+        Ok(())
+    }
 }
 
 /// The default logger for resolve operations.
@@ -434,6 +446,30 @@ impl ResolveLogger for DefaultResolveLogger {
     }
 }
 
+/// A logger that reports on the forks created during resolution, in addition to the default
+/// output.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ForkReportResolveLogger;
+
+impl ResolveLogger for ForkReportResolveLogger {
+    fn on_complete(
+        &self,
+        count: usize,
+        start: std::time::Instant,
+        printer: Printer,
+    ) -> fmt::Result {
+        DefaultResolveLogger.on_complete(count, start, printer)
+    }
+
+    fn reports_forks(&self) -> bool {
+        true
+    }
+
+    fn on_forks(&self, report: &ForkReport, printer: Printer) -> fmt::Result {
+        write!(printer.stdout(), "{report}")
+    }
+}
+
 /// A logger that doesn't show any output.
 #[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct SummaryResolveLogger;
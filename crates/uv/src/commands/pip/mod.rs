@@ -13,6 +13,7 @@ pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod report;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
@@ -27,7 +27,9 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::{Plan, Planner, Preparer, SitePackages};
+use uv_installer::{
+    ConflictPolicy, Plan, Planner, Preparer, Reproducible, SitePackages, Transaction,
+};
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
@@ -404,6 +406,7 @@ pub(crate) async fn install(
     modifications: Modifications,
     reinstall: &Reinstall,
     build_options: &BuildOptions,
+    allow_incompatible_wheels: bool,
     link_mode: LinkMode,
     compile: bool,
     index_urls: &IndexLocations,
@@ -426,6 +429,7 @@ pub(crate) async fn install(
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
+        .with_allow_incompatible_wheels(allow_incompatible_wheels)
         .build(
             site_packages,
             reinstall,
@@ -494,69 +498,127 @@ pub(crate) async fn install(
         wheels
     };
 
-    // Remove any upgraded or extraneous installations.
+    let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
-    if !uninstalls.is_empty() {
-        let start = std::time::Instant::now();
 
-        for dist_info in &uninstalls {
-            match uv_installer::uninstall(dist_info).await {
-                Ok(summary) => {
-                    debug!(
-                        "Uninstalled {} ({} file{}, {} director{})",
-                        dist_info.name(),
-                        summary.file_count,
-                        if summary.file_count == 1 { "" } else { "s" },
-                        summary.dir_count,
-                        if summary.dir_count == 1 { "y" } else { "ies" },
-                    );
-                }
-                Err(uv_installer::UninstallError::Uninstall(
-                    uv_install_wheel::Error::MissingRecord(_),
-                )) => {
+    // Detect any modules or entrypoints that would be written by more than one distribution.
+    let conflicts = uv_installer::find_conflicts(&installs, &uninstalls, venv)?;
+    if !conflicts.is_empty() {
+        match ConflictPolicy::from_env()? {
+            ConflictPolicy::Warn => {
+                for conflict in &conflicts {
                     warn_user!(
-                        "Failed to uninstall package at {} due to missing `RECORD` file. Installation may result in an incomplete environment.",
-                        dist_info.path().user_display().cyan(),
+                        "{conflict}; installing `{}` will overwrite it",
+                        conflict.new
                     );
                 }
-                Err(uv_installer::UninstallError::Uninstall(
-                    uv_install_wheel::Error::MissingTopLevel(_),
-                )) => {
-                    warn_user!(
-                        "Failed to uninstall package at {} due to missing `top-level.txt` file. Installation may result in an incomplete environment.",
-                        dist_info.path().user_display().cyan(),
-                    );
+            }
+            ConflictPolicy::Error => {
+                return Err(anyhow!(
+                    "Refusing to install packages that would overwrite one another:\n{}",
+                    conflicts
+                        .iter()
+                        .map(|conflict| format!("- {conflict}"))
+                        .join("\n")
+                )
+                .into());
+            }
+        }
+    }
+
+    // Stage any modifications to the environment, such that they can be rolled back if the
+    // installation fails partway through.
+    let mut transaction = Transaction::begin(venv);
+    let result = async {
+        // Remove any upgraded or extraneous installations.
+        if !uninstalls.is_empty() {
+            let start = std::time::Instant::now();
+
+            for dist_info in &uninstalls {
+                transaction.stage_uninstall(dist_info)?;
+                match uv_installer::uninstall(dist_info).await {
+                    Ok(summary) => {
+                        debug!(
+                            "Uninstalled {} ({} file{}, {} director{})",
+                            dist_info.name(),
+                            summary.file_count,
+                            if summary.file_count == 1 { "" } else { "s" },
+                            summary.dir_count,
+                            if summary.dir_count == 1 { "y" } else { "ies" },
+                        );
+                    }
+                    Err(uv_installer::UninstallError::Uninstall(
+                        uv_install_wheel::Error::MissingRecord(_),
+                    )) => {
+                        warn_user!(
+                            "Failed to uninstall package at {} due to missing `RECORD` file. Installation may result in an incomplete environment.",
+                            dist_info.path().user_display().cyan(),
+                        );
+                    }
+                    Err(uv_installer::UninstallError::Uninstall(
+                        uv_install_wheel::Error::MissingTopLevel(_),
+                    )) => {
+                        warn_user!(
+                            "Failed to uninstall package at {} due to missing `top-level.txt` file. Installation may result in an incomplete environment.",
+                            dist_info.path().user_display().cyan(),
+                        );
+                    }
+                    Err(err) => return Err(err.into()),
                 }
-                Err(err) => return Err(err.into()),
             }
+
+            logger.on_uninstall(uninstalls.len(), start, printer)?;
         }
 
-        logger.on_uninstall(uninstalls.len(), start, printer)?;
-    }
+        // Install the resolved distributions.
+        if !installs.is_empty() {
+            let start = std::time::Instant::now();
+            transaction.stage_installs(&installs);
+            installs = uv_installer::Installer::new(venv)
+                .with_link_mode(link_mode)
+                .with_cache(cache)
+                .with_installer_metadata(installer_metadata)
+                .with_reporter(Arc::new(
+                    InstallReporter::from(printer).with_length(installs.len() as u64),
+                ))
+                // This technically can block the runtime, but we are on the main thread and
+                // have no other running tasks at this point, so this lets us avoid spawning a
+                // blocking task.
+                .install_blocking(installs)?;
+
+            logger.on_install(installs.len(), start, printer)?;
+        }
 
-    // Install the resolved distributions.
-    let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
-    if !installs.is_empty() {
-        let start = std::time::Instant::now();
-        installs = uv_installer::Installer::new(venv)
-            .with_link_mode(link_mode)
-            .with_cache(cache)
-            .with_installer_metadata(installer_metadata)
-            .with_reporter(Arc::new(
-                InstallReporter::from(printer).with_length(installs.len() as u64),
-            ))
-            // This technically can block the runtime, but we are on the main thread and
-            // have no other running tasks at this point, so this lets us avoid spawning a blocking
-            // task.
-            .install_blocking(installs)?;
-
-        logger.on_install(installs.len(), start, printer)?;
+        Ok::<_, Error>(installs)
     }
+    .await;
+    let installs = match result {
+        Ok(installs) => {
+            transaction.commit();
+            installs
+        }
+        Err(err) => {
+            if let Err(rollback_err) = transaction.rollback() {
+                warn_user!(
+                    "Failed to restore the environment after a failed installation: {rollback_err}"
+                );
+            }
+            return Err(err);
+        }
+    };
 
+    // Bytecode compilation and modification time normalization operate on an environment that
+    // is already consistent, so they're performed after the transaction is committed.
     if compile {
         compile_bytecode(venv, &concurrency, cache, printer).await?;
     }
 
+    // If reproducible installs are enabled, normalize the modification times of the installed
+    // files, such that repeated installs produce bit-identical environments.
+    if let Some(reproducible) = Reproducible::from_env()? {
+        reproducible.normalize_mtimes(venv)?;
+    }
+
     // Construct a summary of the changes made to the environment.
     let changelog = Changelog::new(installs, uninstalls);
 
@@ -847,6 +909,15 @@ pub(crate) enum Error {
     #[error(transparent)]
     Uninstall(#[from] uv_installer::UninstallError),
 
+    #[error(transparent)]
+    Conflict(#[from] uv_installer::ConflictError),
+
+    #[error(transparent)]
+    Reproducible(#[from] uv_installer::ReproducibleError),
+
+    #[error(transparent)]
+    Transaction(#[from] uv_installer::TransactionError),
+
     #[error(transparent)]
     Hash(#[from] uv_types::HashStrategyError),
 
@@ -0,0 +1,250 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use uv_distribution_types::{
+    InstalledDist, Name, Node, Resolution, ResolvedDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
+};
+use uv_installer::SitePackages;
+use uv_normalize::ExtraName;
+use uv_pep508::MarkerEnvironment;
+use uv_pypi_types::{ArchiveInfo, DirectUrl, Metadata23, ResolverMarkerEnvironment, Yanked};
+use uv_python::PythonEnvironment;
+
+use crate::commands::pip::operations::Changelog;
+
+/// An installation report, following the format of pip's `--report` output.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+#[derive(Debug, Serialize)]
+pub(crate) struct InstallationReport {
+    /// The version of the report format.
+    version: &'static str,
+    /// The version of uv used to produce the report.
+    uv_version: &'static str,
+    /// The distributions that were installed.
+    install: Vec<InstallationReportItem>,
+    /// The marker environment that was used for the installation.
+    environment: MarkerEnvironment,
+}
+
+#[derive(Debug, Serialize)]
+struct InstallationReportItem {
+    /// The location from which the distribution was downloaded, in the format of
+    /// `direct_url.json`.
+    download_info: DirectUrl,
+    /// Whether the distribution was requested via a direct URL.
+    is_direct: bool,
+    /// Whether the distribution was yanked from the index.
+    is_yanked: bool,
+    /// Whether the distribution was requested directly, rather than as a transitive dependency.
+    requested: bool,
+    /// The extras that were requested for the distribution, if any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_extras: Vec<ExtraName>,
+    /// The core metadata of the distribution, converted to JSON as described in PEP 566.
+    metadata: Map<String, Value>,
+}
+
+impl InstallationReport {
+    /// Construct an [`InstallationReport`] for the distributions that were installed as part of
+    /// the given [`Changelog`].
+    pub(crate) fn from_changelog(
+        changelog: &Changelog,
+        resolution: &Resolution,
+        requirements: &[UnresolvedRequirementSpecification],
+        environment: &PythonEnvironment,
+        marker_env: &ResolverMarkerEnvironment,
+    ) -> Result<Self> {
+        let site_packages = SitePackages::from_environment(environment)?;
+
+        // Determine the direct dependencies of the resolution.
+        let graph = resolution.graph();
+        let requested = graph
+            .node_indices()
+            .filter(|index| matches!(graph[*index], Node::Root))
+            .flat_map(|root| graph.neighbors(root))
+            .filter_map(|index| match &graph[index] {
+                Node::Dist { dist, .. } => Some(dist.name().clone()),
+                Node::Root => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut install = Vec::new();
+        for name in changelog
+            .installed
+            .iter()
+            .chain(&changelog.reinstalled)
+            .map(Name::name)
+            .collect::<BTreeSet<_>>()
+        {
+            let Some(installed) = site_packages.get_packages(name).into_iter().next() else {
+                continue;
+            };
+            let resolved = resolution.distributions().find(|dist| dist.name() == name);
+
+            let (download_info, is_direct) =
+                if let Some(direct_url) = InstalledDist::direct_url(installed.path())? {
+                    (direct_url, true)
+                } else if let Some(download_info) = resolved.and_then(registry_download_info) {
+                    (download_info, false)
+                } else {
+                    continue;
+                };
+
+            let is_yanked = resolved
+                .and_then(ResolvedDist::yanked)
+                .is_some_and(Yanked::is_yanked);
+
+            let requested_extras = requirements
+                .iter()
+                .filter_map(|entry| match &entry.requirement {
+                    UnresolvedRequirement::Named(requirement) => Some(requirement),
+                    UnresolvedRequirement::Unnamed(_) => None,
+                })
+                .filter(|requirement| requirement.name == *name)
+                .flat_map(|requirement| requirement.extras.iter().cloned())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            install.push(InstallationReportItem {
+                download_info,
+                is_direct,
+                is_yanked,
+                requested: requested.contains(name),
+                requested_extras,
+                metadata: metadata_json(installed.path())?,
+            });
+        }
+
+        Ok(Self {
+            install,
+            ..Self::empty(marker_env)
+        })
+    }
+
+    /// Construct an [`InstallationReport`] for an installation that made no changes.
+    pub(crate) fn empty(marker_env: &ResolverMarkerEnvironment) -> Self {
+        Self {
+            version: "1",
+            uv_version: uv_version::version(),
+            install: Vec::new(),
+            environment: marker_env.markers().clone(),
+        }
+    }
+
+    /// Write the report to the given path, or to stdout if the path is `-`.
+    pub(crate) fn write(&self, path: &Path) -> Result<()> {
+        let report = serde_json::to_string_pretty(self)?;
+        if path == Path::new("-") {
+            anstream::println!("{report}");
+        } else {
+            fs_err::write(path, report)?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the `download_info` for a distribution that was installed from a registry.
+fn registry_download_info(dist: &ResolvedDist) -> Option<DirectUrl> {
+    let ResolvedDist::Installable { dist, .. } = dist else {
+        return None;
+    };
+    let file = dist.file()?;
+    let url = file.url.to_url().ok()?;
+    let hashes = file
+        .hashes
+        .iter()
+        .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    let hash = file
+        .hashes
+        .first()
+        .map(|digest| format!("{}={}", digest.algorithm, digest.digest));
+    Some(DirectUrl::ArchiveUrl {
+        url: url.to_string(),
+        archive_info: ArchiveInfo {
+            hash,
+            hashes: (!hashes.is_empty()).then_some(hashes),
+        },
+        subdirectory: None,
+    })
+}
+
+/// Read the `METADATA` file from a `.dist-info` directory, and convert it to JSON as described in
+/// PEP 566.
+///
+/// See: <https://peps.python.org/pep-0566/#json-compatible-metadata>
+fn metadata_json(dist_info: &Path) -> Result<Map<String, Value>> {
+    let path = dist_info.join("METADATA");
+    let contents = fs_err::read(&path)?;
+    let metadata = Metadata23::parse(&contents)
+        .with_context(|| format!("Failed to parse metadata from: {}", path.display()))?;
+
+    let mut json = Map::new();
+    let mut single = |key: &str, value: Option<String>| {
+        if let Some(value) = value {
+            json.insert(key.to_string(), Value::String(value));
+        }
+    };
+    single("metadata_version", Some(metadata.metadata_version));
+    single("name", Some(metadata.name));
+    single("version", Some(metadata.version));
+    single("summary", metadata.summary);
+    single("description", metadata.description);
+    single(
+        "description_content_type",
+        metadata.description_content_type,
+    );
+    single("home_page", metadata.home_page);
+    single("download_url", metadata.download_url);
+    single("author", metadata.author);
+    single("author_email", metadata.author_email);
+    single("maintainer", metadata.maintainer);
+    single("maintainer_email", metadata.maintainer_email);
+    single("license", metadata.license);
+    single("license_expression", metadata.license_expression);
+    single("requires_python", metadata.requires_python);
+
+    // Per PEP 566, `keywords` is converted to a list by splitting on commas.
+    if let Some(keywords) = metadata.keywords {
+        json.insert(
+            "keywords".to_string(),
+            Value::Array(
+                keywords
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|keyword| !keyword.is_empty())
+                    .map(|keyword| Value::String(keyword.to_string()))
+                    .collect(),
+            ),
+        );
+    }
+
+    let mut multiple = |key: &str, values: Vec<String>| {
+        if !values.is_empty() {
+            json.insert(
+                key.to_string(),
+                Value::Array(values.into_iter().map(Value::String).collect()),
+            );
+        }
+    };
+    multiple("platform", metadata.platforms);
+    multiple("supported_platform", metadata.supported_platforms);
+    multiple("license_file", metadata.license_files);
+    multiple("classifier", metadata.classifiers);
+    multiple("requires_dist", metadata.requires_dist);
+    multiple("provides_dist", metadata.provides_dist);
+    multiple("obsoletes_dist", metadata.obsoletes_dist);
+    multiple("requires_external", metadata.requires_external);
+    multiple("project_url", metadata.project_urls);
+    multiple("provides_extra", metadata.provides_extras);
+    multiple("dynamic", metadata.dynamic);
+
+    Ok(json)
+}
@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -28,7 +29,7 @@ use uv_python::{
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
@@ -65,6 +66,7 @@ pub(crate) async fn pip_sync(
     python_platform: Option<TargetTriple>,
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
+    yanked: YankedPolicy,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
@@ -72,6 +74,7 @@ pub(crate) async fn pip_sync(
     prefix: Option<Prefix>,
     sources: SourceStrategy,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
@@ -82,7 +85,8 @@ pub(crate) async fn pip_sync(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Initialize a few defaults.
     let overrides = &[];
@@ -139,6 +143,7 @@ pub(crate) async fn pip_sync(
                 .unwrap_or_default(),
             EnvironmentPreference::from_system_flag(system, false),
             python_preference,
+            python_install_dir,
             &cache,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -264,6 +269,8 @@ pub(crate) async fn pip_sync(
         .cache(cache.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -345,6 +352,7 @@ pub(crate) async fn pip_sync(
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .yanked(yanked)
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
         .build();
@@ -393,6 +401,7 @@ pub(crate) async fn pip_sync(
         Modifications::Exact,
         &reinstall,
         &build_options,
+        false,
         link_mode,
         compile,
         &index_locations,
@@ -90,8 +90,11 @@ pub(crate) async fn pip_tree(
                 .connectivity(network_settings.connectivity)
                 .index_urls(index_locations.index_urls())
                 .index_strategy(index_strategy)
+                .index_tracking(network_settings.index_tracking)
+                .range_request_size(network_settings.range_request_size)
                 .keyring(keyring_provider)
                 .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                .http_retries(network_settings.http_retries.clone())
                 .markers(environment.interpreter().markers())
                 .platform(environment.interpreter().platform())
                 .build();
@@ -1,16 +1,20 @@
 use std::fmt::Write;
 
 use anyhow::Result;
+use console::Term;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DryRun, KeyringProviderType};
-use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
+use uv_distribution_types::{InstalledDist, InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
-use uv_pep508::UnnamedRequirement;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep508::{MarkerEnvironment, UnnamedRequirement};
 use uv_pypi_types::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::EnvironmentPreference;
@@ -32,10 +36,12 @@ pub(crate) async fn pip_uninstall(
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: bool,
     cache: Cache,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     dry_run: DryRun,
+    autoremove: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -44,7 +50,8 @@ pub(crate) async fn pip_uninstall(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Read all requirements from the provided sources.
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
@@ -55,13 +62,13 @@ pub(crate) async fn pip_uninstall(
             .as_deref()
             .map(PythonRequest::parse)
             .unwrap_or_default(),
-        EnvironmentPreference::from_system_flag(system, true),
+        EnvironmentPreference::from_system_flag(system, !user),
         &cache,
     )?;
 
     report_target_environment(&environment, &cache, printer)?;
 
-    // Apply any `--target` or `--prefix` directories.
+    // Apply any `--target`, `--prefix`, or `--user` directories.
     let environment = if let Some(target) = target {
         debug!(
             "Using `--target` directory at {}",
@@ -74,6 +81,21 @@ pub(crate) async fn pip_uninstall(
             prefix.root().user_display()
         );
         environment.with_prefix(prefix)?
+    } else if user {
+        if environment.interpreter().is_virtualenv() {
+            return Err(anyhow::anyhow!(
+                "Can't perform a `--user` uninstall, as the user site directory is not visible in the virtual environment at {}",
+                environment.root().user_display().cyan()
+            ));
+        }
+        let environment = environment.with_user()?;
+        if let Some(user) = environment.interpreter().user() {
+            debug!(
+                "Using user site directory at {}",
+                user.root().user_display()
+            );
+        }
+        environment
     } else {
         environment
     };
@@ -101,7 +123,7 @@ pub(crate) async fn pip_uninstall(
     let _lock = environment.lock().await?;
 
     // Index the current `site-packages` directory.
-    let site_packages = uv_installer::SitePackages::from_environment(&environment)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
 
     // Partition the requirements into named and unnamed requirements.
     let (named, unnamed): (Vec<Requirement>, Vec<UnnamedRequirement<VerbatimParsedUrl>>) = spec
@@ -136,7 +158,7 @@ pub(crate) async fn pip_uninstall(
     };
 
     // Map to the local distributions.
-    let distributions = {
+    let mut distributions = {
         let mut distributions = Vec::with_capacity(names.len() + urls.len());
 
         // Identify all packages that are installed.
@@ -181,6 +203,40 @@ pub(crate) async fn pip_uninstall(
         distributions
     };
 
+    // Identify any dependencies that would be orphaned by the uninstallation.
+    if autoremove && !distributions.is_empty() {
+        let markers = environment.interpreter().resolver_marker_environment();
+        let mut orphans = find_orphans(&site_packages, &distributions, &markers)?;
+
+        // Prompt the user before removing any packages they didn't request explicitly.
+        if !orphans.is_empty() && !dry_run.enabled() {
+            let term = Term::stderr();
+            if term.is_term() {
+                let s = if orphans.len() == 1 { "" } else { "s" };
+                writeln!(
+                    printer.stderr(),
+                    "The following package{s} will no longer be required:"
+                )?;
+                for orphan in &orphans {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}{}",
+                        "-".red(),
+                        orphan.name().as_ref().bold(),
+                        orphan.installed_version().to_string().dimmed()
+                    )?;
+                }
+                let prompt = format!("Uninstall {} additional package{s}?", orphans.len());
+                if !uv_console::confirm(&prompt, &term, true)? {
+                    orphans.clear();
+                }
+            }
+        }
+
+        distributions.extend(orphans);
+        distributions.sort_unstable_by_key(|dist| dist.path());
+    }
+
     if distributions.is_empty() {
         if dry_run.enabled() {
             writeln!(printer.stderr(), "Would make no changes")?;
@@ -247,3 +303,68 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Identify the installed distributions that are required (directly or transitively) by the given
+/// distributions, but by no other package in the environment, and would thus be orphaned by their
+/// removal.
+fn find_orphans<'a>(
+    site_packages: &'a SitePackages,
+    distributions: &[&'a InstalledDist],
+    markers: &MarkerEnvironment,
+) -> Result<Vec<&'a InstalledDist>> {
+    // Map each installed package to the packages it requires. Every extra is treated as enabled,
+    // such that optional dependencies are never considered orphaned.
+    let mut requires: FxHashMap<&PackageName, FxHashSet<PackageName>> = FxHashMap::default();
+    for dist in site_packages.iter() {
+        let metadata = dist.metadata()?;
+        requires.entry(dist.name()).or_default().extend(
+            metadata
+                .requires_dist
+                .into_iter()
+                .filter(|requirement| {
+                    requirement
+                        .marker
+                        .evaluate(markers, &metadata.provides_extras)
+                })
+                .map(|requirement| requirement.name),
+        );
+    }
+
+    // Iteratively remove any packages that are only required by removed packages.
+    let mut removed: FxHashSet<&PackageName> =
+        distributions.iter().map(|dist| dist.name()).collect();
+    let mut orphans = Vec::new();
+    loop {
+        let mut changed = false;
+        for dist in site_packages.iter() {
+            let name = dist.name();
+            if removed.contains(name) {
+                continue;
+            }
+
+            let mut required_by_removed = false;
+            let mut required_by_remaining = false;
+            for (parent, dependencies) in &requires {
+                if *parent != name && dependencies.contains(name) {
+                    if removed.contains(parent) {
+                        required_by_removed = true;
+                    } else {
+                        required_by_remaining = true;
+                    }
+                }
+            }
+
+            if required_by_removed && !required_by_remaining {
+                debug!("Removing orphaned dependency: {name}");
+                removed.insert(name);
+                orphans.push(dist);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    Ok(orphans)
+}
@@ -28,6 +28,7 @@ use uv_git_types::GitReference;
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
 use uv_pep508::{ExtraName, Requirement, UnnamedRequirement, VersionOrUrl};
 use uv_pypi_types::{redact_credentials, ParsedUrl, RequirementSource, VerbatimParsedUrl};
+use uv_python::policy::PythonPolicy;
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::FlatIndex;
@@ -79,7 +80,9 @@ pub(crate) async fn add(
     network_settings: NetworkSettings,
     script: Option<ScriptPath>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     no_config: bool,
@@ -130,7 +133,8 @@ pub(crate) async fn add(
         let client_builder = BaseClientBuilder::new()
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
         // metadata tag.
@@ -143,7 +147,9 @@ pub(crate) async fn add(
                     project_dir,
                     false,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     no_config,
                     &client_builder,
                     cache,
@@ -160,7 +166,9 @@ pub(crate) async fn add(
             python.as_deref().map(PythonRequest::parse),
             &network_settings,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             &install_mirrors,
             no_config,
             active,
@@ -207,7 +215,9 @@ pub(crate) async fn add(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 active,
@@ -226,9 +236,12 @@ pub(crate) async fn add(
                 &install_mirrors,
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 no_config,
                 active,
+                false,
                 cache,
                 DryRun::Disabled,
                 printer,
@@ -244,7 +257,8 @@ pub(crate) async fn add(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(settings.keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Read the requirements.
     let RequirementsSpecification { requirements, .. } =
@@ -297,6 +311,8 @@ pub(crate) async fn add(
             let client = RegistryClientBuilder::try_from(client_builder)?
                 .index_urls(settings.index_locations.index_urls())
                 .index_strategy(settings.index_strategy)
+                .index_tracking(network_settings.index_tracking)
+                .range_request_size(network_settings.range_request_size)
                 .markers(target.interpreter().markers())
                 .platform(target.interpreter().platform())
                 .build();
@@ -452,6 +468,7 @@ pub(crate) async fn add(
                 rev,
                 tag,
                 branch,
+                ssh_key,
                 marker,
                 extra,
                 group,
@@ -470,6 +487,7 @@ pub(crate) async fn add(
                     rev,
                     tag,
                     branch,
+                    ssh_key,
                     marker,
                     extra,
                     group,
@@ -861,6 +879,7 @@ async fn lock_and_sync(
         EditableMode::Editable,
         InstallOptions::default(),
         Modifications::Sufficient,
+        None,
         settings.into(),
         network_settings,
         &sync_state,
@@ -897,7 +916,7 @@ fn augment_requirement(
                         let git = if let Some(rev) = rev {
                             git.with_reference(GitReference::from_rev(rev.to_string()))
                         } else if let Some(tag) = tag {
-                            git.with_reference(GitReference::Tag(tag.to_string()))
+                            git.with_reference(GitReference::from_tag(tag.to_string()))
                         } else if let Some(branch) = branch {
                             git.with_reference(GitReference::Branch(branch.to_string()))
                         } else {
@@ -921,7 +940,7 @@ fn augment_requirement(
                         let reference = if let Some(rev) = rev {
                             Some(GitReference::from_rev(rev.to_string()))
                         } else if let Some(tag) = tag {
-                            Some(GitReference::Tag(tag.to_string()))
+                            Some(GitReference::from_tag(tag.to_string()))
                         } else {
                             branch.map(|branch| GitReference::Branch(branch.to_string()))
                         };
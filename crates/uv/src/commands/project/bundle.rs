@@ -0,0 +1,220 @@
+//! Bundles of locked distributions, for installing a project without access to a registry.
+//!
+//! A bundle is a directory (or an archive of a directory) containing the wheels and source
+//! distributions for every registry package in a lockfile, alongside a `bundle.json` manifest that
+//! maps the URL of each file in the lockfile to its location within the bundle.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::BaseClient;
+use uv_configuration::Concurrency;
+use uv_distribution_filename::SourceDistExtension;
+use uv_distribution_types::{
+    BuiltDist, Dist, File, FileLocation, Resolution, ResolvedDist, SourceDist, UrlString,
+};
+use uv_fs::Simplified;
+use uv_resolver::Package;
+
+/// The name of the manifest file at the root of a bundle.
+const MANIFEST: &str = "bundle.json";
+
+/// The manifest at the root of a bundle.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BundleManifest {
+    /// The version of the manifest format.
+    version: u32,
+    /// A map from the URL of each file in the lockfile to its filename within the bundle.
+    files: BTreeMap<UrlString, String>,
+}
+
+/// A bundle of locked distributions, as produced by `uv export --bundle`.
+#[derive(Debug)]
+pub(crate) struct Bundle {
+    /// The root directory of the bundle.
+    root: PathBuf,
+    /// The manifest of files in the bundle.
+    manifest: BundleManifest,
+    /// The temporary directory into which the bundle was extracted, if it was read from an
+    /// archive.
+    _temp_dir: Option<TempDir>,
+}
+
+impl Bundle {
+    /// Download the registry distributions for the given packages into a bundle at `root`.
+    ///
+    /// Returns the number of files in the bundle.
+    pub(crate) async fn write(
+        packages: impl Iterator<Item = &Package>,
+        root: &Path,
+        client: &BaseClient,
+        concurrency: Concurrency,
+    ) -> Result<usize> {
+        fs_err::tokio::create_dir_all(root).await?;
+
+        let mut manifest = BundleManifest {
+            version: 1,
+            files: BTreeMap::new(),
+        };
+        for url in packages.flat_map(Package::registry_urls) {
+            let filename = url
+                .base_str()
+                .rsplit('/')
+                .next()
+                .filter(|filename| !filename.is_empty())
+                .with_context(|| format!("Failed to determine filename for: {url}"))?;
+            manifest.files.insert(url.clone(), filename.to_string());
+        }
+
+        let mut downloads = futures::stream::iter(&manifest.files)
+            .map(|(url, filename)| async move {
+                let url = url.to_url()?;
+                let path = root.join(filename);
+                download(client, &url, &path)
+                    .await
+                    .with_context(|| format!("Failed to download: {url}"))
+            })
+            .buffer_unordered(concurrency.downloads);
+        while let Some(result) = downloads.next().await {
+            result?;
+        }
+
+        fs_err::tokio::write(
+            root.join(MANIFEST),
+            serde_json::to_string_pretty(&manifest)?,
+        )
+        .await?;
+
+        Ok(manifest.files.len())
+    }
+
+    /// Read a bundle from a directory, or from a `.tar`, `.tar.gz`, or `.zip` archive of one.
+    pub(crate) async fn read(path: &Path, cache: &Cache) -> Result<Self> {
+        let (root, temp_dir) = if path.is_dir() {
+            (path.to_path_buf(), None)
+        } else {
+            let ext = SourceDistExtension::from_path(path).with_context(|| {
+                format!(
+                    "Expected a directory or archive for the bundle, but found: {}",
+                    path.user_display()
+                )
+            })?;
+            let temp_dir = tempfile::tempdir_in(cache.root())?;
+            let reader = fs_err::tokio::File::open(path).await?;
+            uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
+
+            // Allow the bundle to be nested within a single top-level directory.
+            let root = if temp_dir.path().join(MANIFEST).is_file() {
+                temp_dir.path().to_path_buf()
+            } else {
+                uv_extract::strip_component(temp_dir.path())?
+            };
+            (root, Some(temp_dir))
+        };
+
+        let manifest = fs_err::tokio::read_to_string(root.join(MANIFEST))
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to read bundle manifest at: {}",
+                    root.join(MANIFEST).user_display()
+                )
+            })?;
+        let manifest: BundleManifest = serde_json::from_str(&manifest)?;
+        if manifest.version != 1 {
+            bail!(
+                "Unsupported bundle version: {} (expected 1)",
+                manifest.version
+            );
+        }
+        debug!(
+            "Read bundle with {} files from: {}",
+            manifest.files.len(),
+            root.user_display()
+        );
+
+        Ok(Self {
+            root,
+            manifest,
+            _temp_dir: temp_dir,
+        })
+    }
+
+    /// Redirect any registry distributions in the [`Resolution`] to the corresponding files in
+    /// the bundle.
+    pub(crate) fn apply(&self, resolution: Resolution) -> Resolution {
+        resolution.map(|dist| {
+            let ResolvedDist::Installable { dist, version } = dist else {
+                return None;
+            };
+            let dist = match dist.as_ref() {
+                Dist::Built(BuiltDist::Registry(built)) => {
+                    let mut built = built.clone();
+                    for wheel in &mut built.wheels {
+                        self.relocate(&mut wheel.file);
+                    }
+                    if let Some(sdist) = built.sdist.as_mut() {
+                        self.relocate(&mut sdist.file);
+                    }
+                    Dist::Built(BuiltDist::Registry(built))
+                }
+                Dist::Source(SourceDist::Registry(sdist)) => {
+                    let mut sdist = sdist.clone();
+                    self.relocate(&mut sdist.file);
+                    for wheel in &mut sdist.wheels {
+                        self.relocate(&mut wheel.file);
+                    }
+                    Dist::Source(SourceDist::Registry(sdist))
+                }
+                _ => return None,
+            };
+            Some(ResolvedDist::Installable {
+                dist: Arc::new(dist),
+                version: version.clone(),
+            })
+        })
+    }
+
+    /// Point the given [`File`] at its location in the bundle, if it's present.
+    fn relocate(&self, file: &mut File) {
+        let FileLocation::AbsoluteUrl(url) = &file.url else {
+            return;
+        };
+        let Some(filename) = self.manifest.files.get(url) else {
+            return;
+        };
+        let Ok(url) = Url::from_file_path(self.root.join(filename)) else {
+            return;
+        };
+        file.url = FileLocation::AbsoluteUrl(UrlString::from(url));
+    }
+}
+
+/// Download the file at the given URL to the given path.
+async fn download(client: &BaseClient, url: &Url, path: &Path) -> Result<()> {
+    let response = client
+        .for_host(url)
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let mut file = fs_err::tokio::File::create(path).await?;
+    let mut reader = response.bytes_stream();
+    while let Some(chunk) = reader.next().await {
+        file.write_all(&chunk?).await?;
+    }
+    file.flush().await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use std::env;
+use std::fmt::Write;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
@@ -7,17 +8,21 @@ use std::path::{Path, PathBuf};
 use uv_settings::PythonInstallMirrors;
 
 use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
     PreviewMode,
 };
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
+use uv_python::policy::PythonPolicy;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_resolver::RequirementsTxtExport;
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::bundle::Bundle;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{do_safe_lock, LockMode};
 use crate::commands::project::lock_target::LockTarget;
@@ -58,6 +63,7 @@ pub(crate) async fn export(
     hashes: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
+    bundle: Option<PathBuf>,
     extras: ExtrasSpecification,
     dev: DependencyGroups,
     editable: EditableMode,
@@ -70,7 +76,9 @@ pub(crate) async fn export(
     settings: ResolverSettings,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     concurrency: Concurrency,
     no_config: bool,
     quiet: bool,
@@ -121,7 +129,9 @@ pub(crate) async fn export(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -136,7 +146,9 @@ pub(crate) async fn export(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -270,6 +282,23 @@ pub(crate) async fn export(
                 writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
             }
             write!(writer, "{export}")?;
+
+            // Download the exported distributions into the bundle, if requested.
+            if let Some(bundle) = bundle.as_deref() {
+                let client = BaseClientBuilder::new()
+                    .connectivity(network_settings.connectivity)
+                    .native_tls(network_settings.native_tls)
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .http_retries(network_settings.http_retries.clone())
+                    .build();
+                let files = Bundle::write(export.packages(), bundle, &client, concurrency).await?;
+                writeln!(
+                    printer.stderr(),
+                    "Bundled {} into: {}",
+                    format!("{files} file{}", if files == 1 { "" } else { "s" }).bold(),
+                    bundle.user_display().cyan()
+                )?;
+            }
         }
     }
 
@@ -16,6 +16,7 @@ use uv_fs::{Simplified, CWD};
 use uv_git::GIT;
 use uv_pep440::Version;
 use uv_pep508::PackageName;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -55,7 +56,9 @@ pub(crate) async fn init(
     no_workspace: bool,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
@@ -76,7 +79,9 @@ pub(crate) async fn init(
                 install_mirrors,
                 network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 cache,
                 printer,
                 no_workspace,
@@ -145,7 +150,9 @@ pub(crate) async fn init(
                 no_workspace,
                 network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 no_config,
                 cache,
                 printer,
@@ -190,7 +197,9 @@ async fn init_script(
     install_mirrors: PythonInstallMirrors,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     cache: &Cache,
     printer: Printer,
     no_workspace: bool,
@@ -215,7 +224,8 @@ async fn init_script(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -248,7 +258,9 @@ async fn init_script(
         &CWD,
         pin_python,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         no_config,
         &client_builder,
         cache,
@@ -285,7 +297,9 @@ async fn init_project(
     no_workspace: bool,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
@@ -337,7 +351,8 @@ async fn init_project(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // First, determine if there is an request for Python
     let python_request = if let Some(request) = python {
@@ -424,12 +439,15 @@ async fn init_project(
                         Some(python_request),
                         EnvironmentPreference::OnlySystem,
                         python_preference,
+                        python_install_dir,
                         python_downloads,
+                        python_policy,
                         &client_builder,
                         cache,
                         Some(&reporter),
                         install_mirrors.python_install_mirror.as_deref(),
                         install_mirrors.pypy_install_mirror.as_deref(),
+                        install_mirrors.python_downloads_json_url.as_deref(),
                     )
                     .await?
                     .into_interpreter();
@@ -450,12 +468,15 @@ async fn init_project(
                     Some(&python_request),
                     EnvironmentPreference::OnlySystem,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &client_builder,
                     cache,
                     Some(&reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?
                 .into_interpreter();
@@ -515,12 +536,15 @@ async fn init_project(
                 Some(&python_request),
                 EnvironmentPreference::OnlySystem,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &client_builder,
                 cache,
                 Some(&reporter),
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
+                install_mirrors.python_downloads_json_url.as_deref(),
             )
             .await?
             .into_interpreter();
@@ -541,12 +565,15 @@ async fn init_project(
             None,
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             &client_builder,
             cache,
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?
         .into_interpreter();
@@ -125,28 +125,43 @@ impl<'lock> InstallTarget<'lock> {
         }
     }
 
-    /// Return an iterator over all [`Sources`] defined by the target.
-    pub(crate) fn sources(&self) -> impl Iterator<Item = &Source> {
+    /// Return an iterator over all [`Sources`] defined by the target, along with the directory
+    /// relative to which each source is defined.
+    pub(crate) fn sources(&self) -> impl Iterator<Item = (&Path, &Source)> {
         match self {
             Self::Project { workspace, .. }
             | Self::Workspace { workspace, .. }
             | Self::NonProjectWorkspace { workspace, .. } => {
-                Either::Left(workspace.sources().values().flat_map(Sources::iter).chain(
-                    workspace.packages().values().flat_map(|member| {
-                        member
-                            .pyproject_toml()
-                            .tool
-                            .as_ref()
-                            .and_then(|tool| tool.uv.as_ref())
-                            .and_then(|uv| uv.sources.as_ref())
-                            .map(ToolUvSources::inner)
-                            .into_iter()
-                            .flat_map(|sources| sources.values().flat_map(Sources::iter))
-                    }),
-                ))
+                let root = workspace.install_path().as_path();
+                Either::Left(
+                    workspace
+                        .sources()
+                        .values()
+                        .flat_map(Sources::iter)
+                        .map(move |source| (root, source))
+                        .chain(workspace.packages().values().flat_map(|member| {
+                            member
+                                .pyproject_toml()
+                                .tool
+                                .as_ref()
+                                .and_then(|tool| tool.uv.as_ref())
+                                .and_then(|uv| uv.sources.as_ref())
+                                .map(ToolUvSources::inner)
+                                .into_iter()
+                                .flat_map(|sources| sources.values().flat_map(Sources::iter))
+                                .map(move |source| (member.root().as_path(), source))
+                        })),
+                )
             }
             Self::Script { script, .. } => {
-                Either::Right(script.sources().values().flat_map(Sources::iter))
+                let root = self.install_path();
+                Either::Right(
+                    script
+                        .sources()
+                        .values()
+                        .flat_map(Sources::iter)
+                        .map(move |source| (root, source)),
+                )
             }
         }
     }
@@ -1,5 +1,6 @@
 #![allow(clippy::single_match_else)]
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::path::Path;
@@ -13,24 +14,25 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DryRun, ExtrasSpecification, PreviewMode,
-    Reinstall, Upgrade,
+    Reinstall, SourceStrategy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    UnresolvedRequirementSpecification,
+    TorchBackend, UnresolvedRequirementSpecification,
 };
-use uv_git::ResolvedRepositoryReference;
+use uv_git::{ResolvedHgRepositoryReference, ResolvedRepositoryReference};
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
-use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments};
+use uv_pypi_types::{Conflicts, LicenseMode, Requirement, SupportedEnvironments};
+use uv_python::policy::PythonPolicy;
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
     FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PythonRequirement, RequiresPython,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    ResolveError, ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -38,7 +40,9 @@ use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrateg
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceMember};
 
-use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::pip::loggers::{
+    DefaultResolveLogger, ForkReportResolveLogger, ResolveLogger, SummaryResolveLogger,
+};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     init_script_python_requirement, ProjectError, ProjectInterpreter, ScriptInterpreter,
@@ -81,13 +85,16 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    dump_forks: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     network_settings: NetworkSettings,
     script: Option<ScriptPath>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -100,7 +107,8 @@ pub(crate) async fn lock(
             let client_builder = BaseClientBuilder::new()
                 .connectivity(network_settings.connectivity)
                 .native_tls(network_settings.native_tls)
-                .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                .http_retries(network_settings.http_retries.clone());
             let reporter = PythonDownloadReporter::single(printer);
             let requires_python = init_script_python_requirement(
                 python.as_deref(),
@@ -108,7 +116,9 @@ pub(crate) async fn lock(
                 project_dir,
                 false,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 no_config,
                 &client_builder,
                 cache,
@@ -142,7 +152,9 @@ pub(crate) async fn lock(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -156,7 +168,9 @@ pub(crate) async fn lock(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -186,7 +200,11 @@ pub(crate) async fn lock(
         settings.as_ref(),
         &network_settings,
         &state,
-        Box::new(DefaultResolveLogger),
+        if dump_forks {
+            Box::new(ForkReportResolveLogger)
+        } else {
+            Box::new(DefaultResolveLogger)
+        },
         concurrency,
         cache,
         printer,
@@ -361,6 +379,12 @@ async fn do_lock(
         resolution,
         prerelease,
         fork_strategy,
+        minimal_changes,
+        break_extra_cycles,
+        prefetch_versions,
+        budget,
+        yanked,
+        supported_python,
         dependency_metadata,
         config_setting,
         no_build_isolation,
@@ -372,6 +396,27 @@ async fn do_lock(
         sources,
     } = settings;
 
+    // Collect the directories of any flat `path` sources (i.e., `kind = "flat"`).
+    let flat_sources = match sources {
+        SourceStrategy::Enabled => target.flat_sources()?,
+        SourceStrategy::Disabled => vec![],
+    };
+
+    // If a PyTorch backend is configured, include its index in the set of available indexes.
+    // Similarly, include each flat `path` source as an explicit index.
+    let index_locations = if target.torch_backend().is_some() || !flat_sources.is_empty() {
+        let indexes = target
+            .torch_backend()
+            .map(TorchBackend::index)
+            .into_iter()
+            .chain(flat_sources.iter().cloned())
+            .collect();
+        Cow::Owned(index_locations.clone().combine(indexes, vec![], false))
+    } else {
+        Cow::Borrowed(index_locations)
+    };
+    let index_locations = &*index_locations;
+
     // Collect the requirements, etc.
     let members = target.members();
     let packages = target.packages();
@@ -491,6 +536,21 @@ async fn do_lock(
         default
     };
 
+    // If a supported Python range was provided, narrow the Python requirement accordingly.
+    let requires_python = if let Some(supported_python) = supported_python {
+        RequiresPython::intersection(
+            [requires_python.specifiers(), supported_python.specifiers()].into_iter(),
+        )
+        .ok_or_else(|| {
+            ProjectError::DisjointSupportedPython(
+                supported_python.clone(),
+                requires_python.specifiers().clone(),
+            )
+        })?
+    } else {
+        requires_python
+    };
+
     // If any of the forks are incompatible with the Python requirement, error.
     for environment in environments
         .map(SupportedEnvironments::as_markers)
@@ -541,8 +601,11 @@ async fn do_lock(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -563,10 +626,19 @@ async fn do_lock(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .fork_strategy(fork_strategy)
+        .minimal_changes(minimal_changes)
+        .break_extra_cycles(break_extra_cycles)
+        .prefetch_versions(prefetch_versions)
+        .budget(budget)
+        .yanked(yanked.clone())
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
+        .licenses(target.license_policy())
+        .torch_backend(target.torch_backend())
+        .prefer_smaller_wheels(target.prefer_smaller_wheels())
+        .exclude_dependencies(target.exclude_dependencies())
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -584,7 +656,14 @@ async fn do_lock(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, None, &hasher, build_options)
+        let explicit_entries = client.fetch(flat_sources.iter().map(Index::url)).await?;
+        FlatIndex::from_entries(entries, None, &hasher, build_options).with_explicit_entries(
+            flat_sources.iter().map(Index::url),
+            explicit_entries,
+            None,
+            &hasher,
+            build_options,
+        )
     };
 
     // Create a build dispatch.
@@ -653,12 +732,75 @@ async fn do_lock(
         None
     };
 
+    // If the licenses of the locked packages are to be checked, check an up-to-date lockfile
+    // against the (cached) metadata of its packages, rather than re-resolving.
+    let disallowed_licenses = match &existing_lock {
+        Some(ValidatedLock::Satisfies(lock)) if !options.licenses.is_empty() => {
+            match lock
+                .disallowed_licenses(
+                    target.install_path(),
+                    &options.licenses,
+                    interpreter.tags()?,
+                    &hasher,
+                    state.index(),
+                    &database,
+                )
+                .await
+            {
+                Ok(disallowed) => Some(
+                    disallowed
+                        .into_iter()
+                        .map(|(package, license)| {
+                            let package = match package.version() {
+                                Some(version) => format!("{}=={version}", package.name()),
+                                None => package.name().to_string(),
+                            };
+                            (package, license)
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                Err(err) if err.is_resolution() => return Err(ProjectError::Lock(err)),
+                Err(err) => {
+                    warn_user!("Failed to check the licenses of the existing lockfile: {err}");
+                    None
+                }
+            }
+        }
+        _ => Some(Vec::new()),
+    };
+
+    // If the forks created during resolution are to be reported, or the hashes of the locked
+    // packages are to be checked, an up-to-date lockfile can't be reused as-is. The same applies
+    // if any locked package has a license that the policy denies, such that an alternative
+    // version can be selected. Instead, re-resolve, preferring the locked versions and forks.
+    let refresh = target.require_hashes()
+        || disallowed_licenses.as_ref().is_none_or(|disallowed| {
+            !disallowed.is_empty() && options.licenses.mode() == LicenseMode::Deny
+        });
+    let forced = (logger.reports_forks() || refresh)
+        && matches!(existing_lock, Some(ValidatedLock::Satisfies(_)));
+
+    // If an up-to-date lockfile is only re-resolved to report on its forks, the lockfile is left
+    // as-is, and its forks aren't reused, such that the report reflects the cause of each fork
+    // rather than the forks of the lockfile.
+    let report_only = forced && !refresh;
+    let existing_lock = if forced {
+        existing_lock.map(|lock| ValidatedLock::Preferable(lock.into_lock()))
+    } else {
+        existing_lock
+    };
+
     match existing_lock {
         // Resolution from the lockfile succeeded.
         Some(ValidatedLock::Satisfies(lock)) => {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
+            // In `warn` mode, report any packages with a disallowed license.
+            for (package, license) in disallowed_licenses.into_iter().flatten() {
+                warn_user!("`{package}` has a disallowed license (`{license}`)");
+            }
+
             Ok(LockResult::Unchanged(lock))
         }
 
@@ -674,7 +816,11 @@ async fn do_lock(
             });
 
             // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
+            let LockedRequirements {
+                preferences,
+                git,
+                hg,
+            } = versions_lock
                 .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
                 .transpose()?
                 .unwrap_or_default();
@@ -685,13 +831,22 @@ async fn do_lock(
                 state.git().insert(reference, sha);
             }
 
+            // Populate the Mercurial changesets.
+            for ResolvedHgRepositoryReference { reference, node } in hg {
+                debug!("Inserting Mercurial reference into resolver: `{reference:?}` at `{node}`");
+                state.hg().insert(reference, node);
+            }
+
             // Determine whether we can reuse the existing package forks.
-            let forks_lock = existing_lock.as_ref().and_then(|lock| match &lock {
-                ValidatedLock::Satisfies(lock) => Some(lock),
-                ValidatedLock::Preferable(lock) => Some(lock),
-                ValidatedLock::Versions(_) => None,
-                ValidatedLock::Unusable(_) => None,
-            });
+            let forks_lock = existing_lock
+                .as_ref()
+                .filter(|_| !report_only)
+                .and_then(|lock| match &lock {
+                    ValidatedLock::Satisfies(lock) => Some(lock),
+                    ValidatedLock::Preferable(lock) => Some(lock),
+                    ValidatedLock::Versions(_) => None,
+                    ValidatedLock::Unusable(_) => None,
+                });
 
             // When we run the same resolution from the lockfile again, we could get a different result the
             // second time due to the preferences causing us to skip a fork point (see the
@@ -715,65 +870,118 @@ async fn do_lock(
                     }),
             );
 
+            // Collect the requirements, including the members' requirements with their extras
+            // expanded.
+            let resolution_requirements = ExtrasResolver::new(&hasher, state.index(), database)
+                .with_reporter(Arc::new(ResolverReporter::from(printer)))
+                .resolve(target.members_requirements())
+                .await
+                .map_err(|err| ProjectError::Operation(err.into()))?
+                .into_iter()
+                .chain(target.group_requirements())
+                .chain(requirements.iter().cloned())
+                .chain(
+                    dependency_groups
+                        .values()
+                        .flat_map(|requirements| requirements.iter().cloned()),
+                )
+                .map(UnresolvedRequirementSpecification::from)
+                .collect::<Vec<_>>();
+
+            // If the existing forks can be reused, seed the resolver with the previously locked
+            // distributions, such that packages that are unaffected by the change don't need to
+            // be re-fetched from the registry. If the locked distributions are insufficient, we
+            // fall back to resolving against the full set of available versions.
+            let mut seeded_index = forks_lock.and_then(|lock| {
+                let index = InMemoryIndex::default();
+                match lock.seed_index(
+                    target.install_path(),
+                    &index,
+                    index_locations,
+                    upgrade,
+                    build_options,
+                ) {
+                    Ok(()) => Some(index),
+                    Err(err) => {
+                        debug!("Failed to seed resolver with locked distributions: {err}");
+                        None
+                    }
+                }
+            });
+
             // Resolve the requirements.
-            let resolution = pip::operations::resolve(
-                ExtrasResolver::new(&hasher, state.index(), database)
-                    .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                    .resolve(target.members_requirements())
-                    .await
-                    .map_err(|err| ProjectError::Operation(err.into()))?
-                    .into_iter()
-                    .chain(target.group_requirements())
-                    .chain(requirements.iter().cloned())
-                    .chain(
-                        dependency_groups
-                            .values()
-                            .flat_map(|requirements| requirements.iter().cloned()),
-                    )
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
-                constraints
-                    .iter()
-                    .cloned()
-                    .map(NameRequirementSpecification::from)
-                    .collect(),
-                overrides
-                    .iter()
-                    .cloned()
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
-                source_trees,
-                // The root is always null in workspaces, it "depends on" the projects
-                None,
-                packages.keys().cloned().collect(),
-                &extras,
-                &groups,
-                preferences,
-                EmptyInstalledPackages,
-                &hasher,
-                &Reinstall::default(),
-                upgrade,
-                None,
-                resolver_env,
-                python_requirement,
-                conflicts,
-                &client,
-                &flat_index,
-                state.index(),
-                &build_dispatch,
-                concurrency,
-                options,
-                Box::new(SummaryResolveLogger),
-                printer,
-            )
-            .await?;
+            let resolution = loop {
+                let index = seeded_index.as_ref().unwrap_or(state.index());
+                match pip::operations::resolve(
+                    resolution_requirements.clone(),
+                    constraints
+                        .iter()
+                        .cloned()
+                        .map(NameRequirementSpecification::from)
+                        .collect(),
+                    overrides
+                        .iter()
+                        .cloned()
+                        .map(UnresolvedRequirementSpecification::from)
+                        .collect(),
+                    source_trees.clone(),
+                    // The root is always null in workspaces, it "depends on" the projects
+                    None,
+                    packages.keys().cloned().collect(),
+                    &extras,
+                    &groups,
+                    preferences.clone(),
+                    EmptyInstalledPackages,
+                    &hasher,
+                    &Reinstall::default(),
+                    upgrade,
+                    None,
+                    resolver_env.clone(),
+                    python_requirement.clone(),
+                    conflicts.clone(),
+                    &client,
+                    &flat_index,
+                    index,
+                    &build_dispatch,
+                    concurrency,
+                    options.clone(),
+                    Box::new(SummaryResolveLogger),
+                    printer,
+                )
+                .await
+                {
+                    Ok(resolution) => break resolution,
+                    Err(pip::operations::Error::Resolve(ResolveError::NoSolution(_)))
+                        if seeded_index.is_some() =>
+                    {
+                        debug!("Failed to resolve from locked distributions; retrying with all versions");
+                        seeded_index = None;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            };
 
             // Print the success message after completing resolution.
             logger.on_complete(resolution.len(), start, printer)?;
 
+            // Report the forks that were created during resolution, if requested.
+            logger.on_forks(resolution.fork_report(), printer)?;
+
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // If hashes are required, ensure that every distribution that wasn't resolved from a
+            // package index includes a hash.
+            if target.require_hashes() {
+                let flat_indexes = index_locations
+                    .flat_indexes()
+                    .map(Index::url)
+                    .collect::<Vec<_>>();
+                resolution
+                    .require_hashes(&flat_indexes)
+                    .map_err(|err| ProjectError::Operation(err.into()))?;
+            }
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
@@ -801,6 +1009,21 @@ async fn do_lock(
                         .unwrap_or_default(),
                 );
 
+            // If the lockfile was only re-resolved to report on its forks, keep it as-is: without
+            // the locked forks, the resolver may fork differently (e.g., if preferences cause it to
+            // skip a fork point). Otherwise, avoid marking it as changed if the resolution is
+            // identical.
+            if forced {
+                if let Some(previous) = previous.as_ref() {
+                    if report_only {
+                        return Ok(LockResult::Unchanged(previous.clone()));
+                    }
+                    if previous.to_toml()? == lock.to_toml()? {
+                        return Ok(LockResult::Unchanged(lock));
+                    }
+                }
+            }
+
             Ok(LockResult::Changed(previous, lock))
         }
     }
@@ -873,6 +1096,35 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        match (lock.torch_backend(), options.torch_backend) {
+            (None, None) => (),
+            (Some(existing), Some(provided)) if existing == provided => (),
+            (Some(existing), Some(provided)) => {
+                let _ = writeln!(
+                    printer.stderr(),
+                    "Ignoring existing lockfile due to change in PyTorch backend: `{}` vs. `{}`",
+                    existing.cyan(),
+                    provided.cyan()
+                );
+                return Ok(Self::Unusable(lock));
+            }
+            (Some(existing), None) => {
+                let _ = writeln!(
+                    printer.stderr(),
+                    "Ignoring existing lockfile due to removal of PyTorch backend: `{}`",
+                    existing.cyan(),
+                );
+                return Ok(Self::Unusable(lock));
+            }
+            (None, Some(provided)) => {
+                let _ = writeln!(
+                    printer.stderr(),
+                    "Ignoring existing lockfile due to addition of PyTorch backend: `{}`",
+                    provided.cyan()
+                );
+                return Ok(Self::Unusable(lock));
+            }
+        }
         match (lock.exclude_newer(), options.exclude_newer) {
             (None, None) => (),
             (Some(existing), Some(provided)) if existing == provided => (),
@@ -918,6 +1170,20 @@ impl ValidatedLock {
             }
         }
 
+        // If the lockfile contains an excluded package, re-resolve, such that the resolver can
+        // report the chain of dependencies that introduced it.
+        if let Some(package) = lock
+            .packages()
+            .iter()
+            .find(|package| options.exclude_dependencies.contains(package.name()))
+        {
+            debug!(
+                "Ignoring existing lockfile due to excluded package: `{}`",
+                package.name()
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the Requires-Python bound has changed, we have to perform a clean resolution, since
         // the set of `resolution-markers` may no longer cover the entire supported Python range.
         if lock.requires_python().range() != requires_python.range() {
@@ -1010,6 +1276,12 @@ impl ValidatedLock {
             .await?
         {
             SatisfiesResult::Satisfied => {
+                // If the wheel size preference has changed, the existing resolution can be
+                // reused, but the lockfile must be rewritten.
+                if lock.prefer_smaller_wheels() != options.prefer_smaller_wheels {
+                    debug!("Ignoring existing lockfile due to change in wheel size preference");
+                    return Ok(Self::Preferable(lock));
+                }
                 debug!("Existing `uv.lock` satisfies workspace requirements");
                 Ok(Self::Satisfies(lock))
             }
@@ -2,13 +2,16 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use itertools::Either;
+use rustc_hash::FxHashSet;
 
 use uv_configuration::SourceStrategy;
-use uv_distribution::LoweredRequirement;
-use uv_distribution_types::{Index, IndexLocations};
+use uv_distribution::{LoweredRequirement, LoweringError};
+use uv_distribution_types::{Index, IndexLocations, TorchBackend};
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
-use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pypi_types::{
+    Conflicts, LicensePolicy, Requirement, SupportedEnvironments, VerbatimParsedUrl,
+};
 use uv_resolver::{Lock, LockVersion, RequiresPython, VERSION};
 use uv_scripts::Pep723Script;
 use uv_workspace::dependency_groups::DependencyGroupError;
@@ -179,6 +182,46 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the license policy for the [`LockTarget`].
+    pub(crate) fn license_policy(self) -> LicensePolicy {
+        match self {
+            Self::Workspace(workspace) => workspace.license_policy(),
+            Self::Script(_) => LicensePolicy::default(),
+        }
+    }
+
+    /// Returns `true` if the [`LockTarget`] requires hashes for all non-registry requirements.
+    pub(crate) fn require_hashes(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.require_hashes(),
+            Self::Script(_) => false,
+        }
+    }
+
+    /// Returns `true` if the [`LockTarget`] prefers the smallest compatible wheel at install time.
+    pub(crate) fn prefer_smaller_wheels(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.prefer_smaller_wheels(),
+            Self::Script(_) => false,
+        }
+    }
+
+    /// Returns the set of packages that are excluded from the [`LockTarget`] resolution.
+    pub(crate) fn exclude_dependencies(self) -> Vec<PackageName> {
+        match self {
+            Self::Workspace(workspace) => workspace.exclude_dependencies(),
+            Self::Script(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the PyTorch backend for the [`LockTarget`], if any.
+    pub(crate) fn torch_backend(self) -> Option<TorchBackend> {
+        match self {
+            Self::Workspace(workspace) => workspace.torch_backend(),
+            Self::Script(_) => None,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Conflicts {
         match self {
@@ -215,6 +258,53 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Return the [`Index`] for each flat `path` source in the [`LockTarget`] (e.g.,
+    /// `{ path = "dist/", kind = "flat" }`).
+    pub(crate) fn flat_sources(self) -> Result<Vec<Index>, LoweringError> {
+        let mut indexes = Vec::new();
+        match self {
+            Self::Workspace(workspace) => {
+                for sources in workspace.sources().values() {
+                    for source in sources.iter() {
+                        if let Some(index) = source.flat_index(workspace.install_path()) {
+                            indexes.push(index?);
+                        }
+                    }
+                }
+                for member in workspace.packages().values() {
+                    let Some(sources) = member
+                        .pyproject_toml()
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.sources.as_ref())
+                    else {
+                        continue;
+                    };
+                    for sources in sources.inner().values() {
+                        for source in sources.iter() {
+                            if let Some(index) = source.flat_index(member.root()) {
+                                indexes.push(index?);
+                            }
+                        }
+                    }
+                }
+            }
+            Self::Script(script) => {
+                for sources in script.sources().values() {
+                    for source in sources.iter() {
+                        if let Some(index) = source.flat_index(self.install_path()) {
+                            indexes.push(index?);
+                        }
+                    }
+                }
+            }
+        }
+        let mut seen = FxHashSet::default();
+        indexes.retain(|index| seen.insert(index.url.clone()));
+        Ok(indexes)
+    }
+
     /// Return the `Requires-Python` bound for the [`LockTarget`].
     #[allow(clippy::result_large_err)]
     pub(crate) fn requires_python(self) -> Result<Option<RequiresPython>, ProjectError> {
@@ -21,22 +21,23 @@ use uv_distribution_types::{
     Index, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::{LockedFile, Simplified, CWD};
-use uv_git::ResolvedRepositoryReference;
+use uv_git::{ResolvedHgRepositoryReference, ResolvedRepositoryReference};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{ExtraName, GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_pypi_types::{ConflictPackage, ConflictSet, Conflicts, Requirement};
+use uv_python::policy::PythonPolicy;
 use uv_python::{
-    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PyVenvConfiguration,
+    PythonDownloads, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
     FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolverEnvironment,
-    ResolverOutput,
+    ResolverOutput, SupportedPython,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -57,12 +58,14 @@ use crate::settings::{
 };
 
 pub(crate) mod add;
+mod bundle;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
 mod lock_target;
+pub(crate) mod prefetch;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
@@ -93,6 +96,9 @@ pub(crate) enum ProjectError {
     #[error("The current Python platform is not compatible with the lockfile's supported environments: {0}")]
     LockedPlatformIncompatibility(String),
 
+    #[error("The lockfile references distributions that have since been yanked: {0}. To use them anyway, add them to `allow-yanked`, or update the lockfile with `uv lock --upgrade-package`.")]
+    LockedYanked(String),
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -169,6 +175,9 @@ pub(crate) enum ProjectError {
     #[error("Environment markers `{0}` don't overlap with Python requirement `{1}`")]
     DisjointEnvironment(MarkerTreeContents, VersionSpecifiers),
 
+    #[error("The supported Python range (`{0}`) does not overlap with the workspace `requires-python` (`{1}`)")]
+    DisjointSupportedPython(SupportedPython, VersionSpecifiers),
+
     #[error("The workspace contains conflicting Python requirements:\n{}", _0.iter().map(|(name, specifiers)| format!("- `{name}`: `{specifiers}`")).join("\n"))]
     DisjointRequiresPython(BTreeMap<PackageName, VersionSpecifiers>),
 
@@ -634,7 +643,9 @@ impl ScriptInterpreter {
         python_request: Option<PythonRequest>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
+        python_install_dir: Option<&Path>,
         python_downloads: PythonDownloads,
+        python_policy: Option<&PythonPolicy>,
         install_mirrors: &PythonInstallMirrors,
         no_config: bool,
         active: Option<bool>,
@@ -688,7 +699,8 @@ impl ScriptInterpreter {
         let client_builder = BaseClientBuilder::new()
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -696,12 +708,15 @@ impl ScriptInterpreter {
             python_request.as_ref(),
             EnvironmentPreference::Any,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             &client_builder,
             cache,
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?
         .into_interpreter();
@@ -775,7 +790,9 @@ impl ProjectInterpreter {
         python_request: Option<PythonRequest>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
+        python_install_dir: Option<&Path>,
         python_downloads: PythonDownloads,
+        python_policy: Option<&PythonPolicy>,
         install_mirrors: &PythonInstallMirrors,
         no_config: bool,
         active: Option<bool>,
@@ -861,7 +878,8 @@ impl ProjectInterpreter {
         let client_builder = BaseClientBuilder::default()
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -870,12 +888,15 @@ impl ProjectInterpreter {
             python_request.as_ref(),
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             &client_builder,
             cache,
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?;
 
@@ -1137,9 +1158,12 @@ impl ProjectEnvironment {
         install_mirrors: &PythonInstallMirrors,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
+        python_install_dir: Option<&Path>,
         python_downloads: PythonDownloads,
+        python_policy: Option<&PythonPolicy>,
         no_config: bool,
         active: Option<bool>,
+        relocatable: bool,
         cache: &Cache,
         dry_run: DryRun,
         printer: Printer,
@@ -1153,7 +1177,9 @@ impl ProjectEnvironment {
             python,
             network_settings,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             install_mirrors,
             no_config,
             active,
@@ -1163,7 +1189,15 @@ impl ProjectEnvironment {
         .await?
         {
             // If we found an existing, compatible environment, use it.
-            ProjectInterpreter::Environment(environment) => Ok(Self::Existing(environment)),
+            ProjectInterpreter::Environment(environment) => {
+                if relocatable && !environment.relocatable() {
+                    warn_user!(
+                        "Ignoring `--relocatable` for the existing environment at: {}. Remove the environment to recreate it as relocatable.",
+                        environment.root().user_display().cyan()
+                    );
+                }
+                Ok(Self::Existing(environment))
+            }
 
             // Otherwise, create a virtual environment with the discovered interpreter.
             ProjectInterpreter::Interpreter(interpreter) => {
@@ -1196,6 +1230,12 @@ impl ProjectEnvironment {
                     }
                 };
 
+                // If the environment is being replaced, retain its relocatability.
+                let relocatable = relocatable
+                    || (replace
+                        && PyVenvConfiguration::parse(root.join("pyvenv.cfg"))
+                            .is_ok_and(|cfg| cfg.is_relocatable()));
+
                 // Determine a prompt for the environment, in order of preference:
                 //
                 // 1) The name of the project
@@ -1224,7 +1264,7 @@ impl ProjectEnvironment {
                         prompt,
                         false,
                         false,
-                        false,
+                        relocatable,
                         false,
                     )?;
                     return Ok(if replace {
@@ -1261,7 +1301,7 @@ impl ProjectEnvironment {
                     prompt,
                     false,
                     false,
-                    false,
+                    relocatable,
                     false,
                 )?;
 
@@ -1338,7 +1378,9 @@ impl ScriptEnvironment {
         python_request: Option<PythonRequest>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
+        python_install_dir: Option<&Path>,
         python_downloads: PythonDownloads,
+        python_policy: Option<&PythonPolicy>,
         install_mirrors: &PythonInstallMirrors,
         no_config: bool,
         active: Option<bool>,
@@ -1354,7 +1396,9 @@ impl ScriptEnvironment {
             python_request,
             network_settings,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             install_mirrors,
             no_config,
             active,
@@ -1503,6 +1547,12 @@ pub(crate) async fn resolve_names(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        minimal_changes: _,
+        break_extra_cycles: _,
+        prefetch_versions: _,
+        budget: _,
+        yanked: _,
+        supported_python: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
@@ -1533,8 +1583,11 @@ pub(crate) async fn resolve_names(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1641,6 +1694,12 @@ pub(crate) async fn resolve_environment(
         resolution,
         prerelease,
         fork_strategy,
+        minimal_changes,
+        break_extra_cycles,
+        prefetch_versions,
+        budget,
+        yanked,
+        supported_python: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
@@ -1684,8 +1743,11 @@ pub(crate) async fn resolve_environment(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1706,6 +1768,11 @@ pub(crate) async fn resolve_environment(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .fork_strategy(fork_strategy)
+        .minimal_changes(minimal_changes)
+        .break_extra_cycles(break_extra_cycles)
+        .prefetch_versions(prefetch_versions)
+        .budget(budget)
+        .yanked(yanked.clone())
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
         .build_options(build_options.clone())
@@ -1725,7 +1792,11 @@ pub(crate) async fn resolve_environment(
     let upgrade = Upgrade::default();
 
     // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = spec
+    let LockedRequirements {
+        preferences,
+        git,
+        hg,
+    } = spec
         .lock
         .map(|(lock, install_path)| read_lock_requirements(lock, install_path, &upgrade))
         .transpose()?
@@ -1737,6 +1808,12 @@ pub(crate) async fn resolve_environment(
         state.git().insert(reference, sha);
     }
 
+    // Populate the Mercurial changesets.
+    for ResolvedHgRepositoryReference { reference, node } in hg {
+        debug!("Inserting Mercurial reference into resolver: `{reference:?}` at `{node}`");
+        state.hg().insert(reference, node);
+    }
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, cache);
@@ -1828,6 +1905,7 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         sources,
+        yanked: _,
     } = settings;
 
     let site_packages = SitePackages::from_environment(&venv)?;
@@ -1853,8 +1931,11 @@ pub(crate) async fn sync_environment(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1913,6 +1994,7 @@ pub(crate) async fn sync_environment(
         modifications,
         reinstall,
         build_options,
+        false,
         link_mode,
         compile_bytecode,
         index_locations,
@@ -1980,6 +2062,12 @@ pub(crate) async fn update_environment(
         resolution,
         prerelease,
         fork_strategy,
+        minimal_changes,
+        break_extra_cycles,
+        prefetch_versions,
+        budget,
+        yanked,
+        supported_python: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
@@ -2055,8 +2143,11 @@ pub(crate) async fn update_environment(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -2074,6 +2165,11 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .minimal_changes(*minimal_changes)
+        .break_extra_cycles(*break_extra_cycles)
+        .prefetch_versions(*prefetch_versions)
+        .budget(*budget)
+        .yanked(yanked.clone())
         .exclude_newer(*exclude_newer)
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2164,6 +2260,7 @@ pub(crate) async fn update_environment(
         modifications,
         reinstall,
         build_options,
+        false,
         *link_mode,
         *compile_bytecode,
         index_locations,
@@ -2199,7 +2296,9 @@ pub(crate) async fn init_script_python_requirement(
     directory: &Path,
     no_pin_python: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     no_config: bool,
     client_builder: &BaseClientBuilder<'_>,
     cache: &Cache,
@@ -2228,12 +2327,15 @@ pub(crate) async fn init_script_python_requirement(
         Some(&python_request),
         EnvironmentPreference::Any,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         client_builder,
         cache,
         Some(reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
     .into_interpreter();
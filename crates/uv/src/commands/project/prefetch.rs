@@ -0,0 +1,317 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::{FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, Constraints, DependencyGroups, ExtrasSpecification, HashCheckingMode,
+    InstallOptions, PreviewMode, TargetTriple,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{BuiltDist, Dist, Index, ResolvedDist, SourceDist};
+use uv_installer::Preparer;
+use uv_pep508::MarkerTree;
+use uv_python::policy::PythonPolicy;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::store_credentials_from_target;
+use crate::commands::project::{
+    default_dependency_groups, PlatformState, ProjectError, ProjectInterpreter, UniversalState,
+};
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{diagnostics, elapsed, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// Download (and optionally build) the artifacts needed to install from the project's lockfile,
+/// storing them in the cache.
+///
+/// The lockfile is read as-is. Every package in the workspace is included, alongside all extras and
+/// dependency groups, filtered to those required on the target platform.
+pub(crate) async fn prefetch(
+    project_dir: &Path,
+    build: bool,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    // Identify the project. As the lockfile is read as-is, there's no need to discover members.
+    let project = VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions {
+            members: MemberDiscovery::None,
+            ..DiscoveryOptions::default()
+        },
+    )
+    .await?;
+    let workspace = project.workspace();
+
+    // Find an interpreter, which determines the target platform (unless overridden) and is used to
+    // build any source distributions.
+    let interpreter = ProjectInterpreter::discover(
+        workspace,
+        project_dir,
+        python.as_deref().map(PythonRequest::parse),
+        network_settings,
+        python_preference,
+        python_install_dir,
+        python_downloads,
+        python_policy,
+        &install_mirrors,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Read the lockfile.
+    let lock = match do_safe_lock(
+        LockMode::Frozen,
+        LockTarget::from(workspace),
+        settings.as_ref(),
+        network_settings,
+        &UniversalState::default(),
+        Box::new(DefaultResolveLogger),
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let target = match &project {
+        VirtualProject::Project(..) => InstallTarget::Workspace {
+            workspace,
+            lock: &lock,
+        },
+        VirtualProject::NonProject(..) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Determine the markers and tags of the target platform.
+    let marker_env = resolution_markers(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    );
+    let tags = resolution_tags(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    )?;
+
+    // Validate that the platform is supported by the lockfile.
+    let environments = lock.supported_environments();
+    if !environments.is_empty()
+        && !environments
+            .iter()
+            .any(|env| env.evaluate(&marker_env, &[]))
+    {
+        return Err(ProjectError::LockedPlatformIncompatibility(
+            lock.simplified_supported_environments()
+                .into_iter()
+                .filter_map(MarkerTree::contents)
+                .map(|env| format!("`{env}`"))
+                .join(", "),
+        )
+        .into());
+    }
+
+    // Include every extra and dependency group.
+    let extras = ExtrasSpecification::All;
+    let dev = DependencyGroups::from_args(false, false, false, vec![], vec![], false, vec![], true)
+        .with_defaults(default_dependency_groups(workspace.pyproject_toml())?);
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        &extras,
+        &dev,
+        &settings.build_options,
+        &InstallOptions::default(),
+    )?;
+
+    // Collect the remote distributions; local distributions don't need to be fetched.
+    let (wheels, sdists): (Vec<_>, Vec<_>) = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => match dist.as_ref() {
+                Dist::Built(BuiltDist::Path(..))
+                | Dist::Source(SourceDist::Path(..) | SourceDist::Directory(..)) => None,
+                _ => Some(dist.clone()),
+            },
+            ResolvedDist::Installed { .. } => None,
+        })
+        .partition(|dist| matches!(dist.as_ref(), Dist::Built(..)));
+
+    // Add all authenticated sources to the cache.
+    for index in settings.index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            let credentials = Arc::new(credentials);
+            uv_auth::store_credentials(index.raw_url(), credentials.clone());
+            if let Some(root_url) = index.root_url() {
+                uv_auth::store_credentials(&root_url, credentials.clone());
+            }
+        }
+    }
+    store_credentials_from_target(target);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(network_settings.native_tls)
+        .connectivity(network_settings.connectivity)
+        .index_urls(settings.index_locations.index_urls())
+        .index_strategy(settings.index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Extract the hashes from the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache);
+        let entries = client
+            .fetch(settings.index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&*tags), &hasher, &settings.build_options)
+    };
+
+    // Create a build dispatch. Builds are always isolated, as there's no environment to share.
+    let build_constraints = Constraints::default();
+    let build_hasher = HashStrategy::default();
+    let state = PlatformState::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        &interpreter,
+        &settings.index_locations,
+        &flat_index,
+        &settings.dependency_metadata,
+        state.clone().into_inner(),
+        settings.index_strategy,
+        &settings.config_setting,
+        BuildIsolation::Isolated,
+        settings.link_mode,
+        &settings.build_options,
+        &build_hasher,
+        settings.exclude_newer,
+        settings.sources,
+        concurrency,
+        preview,
+    );
+
+    let start = std::time::Instant::now();
+
+    // Download (and unzip) the wheels, alongside any source distributions that should be built.
+    let (prepare, download) = if build {
+        (wheels.into_iter().chain(sdists).collect(), Vec::new())
+    } else {
+        (wheels, sdists)
+    };
+    let prepared = prepare.len();
+    if !prepare.is_empty() {
+        let preparer = Preparer::new(
+            cache,
+            &tags,
+            &hasher,
+            &settings.build_options,
+            DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+        )
+        .with_reporter(Arc::new(
+            PrepareReporter::from(printer).with_length(prepare.len() as u64),
+        ));
+        preparer
+            .prepare(prepare, state.in_flight(), &resolution)
+            .await?;
+    }
+
+    // Download the remaining source distributions, without building them. Their metadata is read
+    // from the source distribution itself where possible.
+    let downloaded = download.len();
+    if !download.is_empty() {
+        let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+        let mut fetches = futures::stream::iter(&download)
+            .map(|dist| {
+                database.get_or_build_wheel_metadata(dist.as_ref(), hasher.get(dist.as_ref()))
+            })
+            .buffer_unordered(concurrency.downloads);
+        while let Some(result) = fetches.next().await {
+            result?;
+        }
+    }
+
+    let count = prepared + downloaded;
+    let s = if count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Prefetched {} {}",
+            format!("{count} package{s}").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    if downloaded > 0 {
+        let s = if downloaded == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Downloaded {downloaded} source distribution{s} without building; pass `--build` \
+                 to build them"
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -15,6 +15,7 @@ use uv_configuration::{
 use uv_fs::Simplified;
 use uv_normalize::DEV_DEPENDENCIES;
 use uv_pep508::PackageName;
+use uv_python::policy::PythonPolicy;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_scripts::{Pep723ItemRef, Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -54,7 +55,9 @@ pub(crate) async fn remove(
     network_settings: NetworkSettings,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     no_config: bool,
@@ -203,7 +206,9 @@ pub(crate) async fn remove(
                     python.as_deref().map(PythonRequest::parse),
                     &network_settings,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &install_mirrors,
                     no_config,
                     active,
@@ -222,9 +227,12 @@ pub(crate) async fn remove(
                     &install_mirrors,
                     &network_settings,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     no_config,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -241,7 +249,9 @@ pub(crate) async fn remove(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 active,
@@ -330,6 +340,7 @@ pub(crate) async fn remove(
         EditableMode::Editable,
         install_options,
         Modifications::Exact,
+        None,
         settings.as_ref().into(),
         &network_settings,
         &state,
@@ -24,6 +24,7 @@ use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -82,7 +83,9 @@ pub(crate) async fn run(
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -219,7 +222,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 active.map_or(Some(false), Some),
@@ -286,6 +291,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 editable,
                 install_options,
                 modifications,
+                None,
                 settings.as_ref().into(),
                 &network_settings,
                 &sync_state,
@@ -338,7 +344,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python.as_deref().map(PythonRequest::parse),
                     &network_settings,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &install_mirrors,
                     no_config,
                     active.map_or(Some(false), Some),
@@ -393,7 +401,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python.as_deref().map(PythonRequest::parse),
                     &network_settings,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &install_mirrors,
                     no_config,
                     active.map_or(Some(false), Some),
@@ -554,7 +564,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .http_retries(network_settings.http_retries.clone());
 
                 // Resolve the Python request and requirement for the workspace.
                 let WorkspacePython {
@@ -573,12 +584,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python_request.as_ref(),
                     EnvironmentPreference::Any,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &client_builder,
                     cache,
                     Some(&download_reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?
                 .into_interpreter();
@@ -612,9 +626,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     &install_mirrors,
                     &network_settings,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     no_config,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -740,6 +757,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     editable,
                     install_options,
                     modifications,
+                    None,
                     settings.as_ref().into(),
                     &network_settings,
                     &sync_state,
@@ -782,7 +800,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .http_retries(network_settings.http_retries.clone());
 
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
@@ -802,12 +821,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     // No opt-in is required for system environments, since we are not mutating it.
                     EnvironmentPreference::Any,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     &client_builder,
                     cache,
                     Some(&download_reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?;
 
@@ -850,7 +872,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         let client_builder = BaseClientBuilder::new()
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
 
         let spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
@@ -1416,6 +1439,7 @@ impl RunCommand {
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .http_retries(network_settings.http_retries.clone())
                     .build();
                 let response = client.for_host(&url).get(url.clone()).send().await?;
 
@@ -1,29 +1,37 @@
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
+use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use tokio::sync::Semaphore;
+use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{FlatIndexClient, RegistryClientBuilder};
+use uv_client::{
+    Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder, VersionFiles,
+};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
     ExtrasSpecification, HashCheckingMode, InstallOptions, PreviewMode,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, DirectorySourceDist, Dist, Index, IndexCapabilities, Resolution, ResolvedDist,
+    SourceDist,
 };
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, VersionOrUrl};
-use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, Yanked};
+use uv_python::policy::PythonPolicy;
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, Installable};
+use uv_resolver::{FlatIndex, Installable, YankedMode, YankedPolicy};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
@@ -34,6 +42,7 @@ use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace}
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations;
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::bundle::Bundle;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{do_safe_lock, LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
@@ -53,6 +62,8 @@ pub(crate) async fn sync(
     frozen: bool,
     dry_run: DryRun,
     active: Option<bool>,
+    relocatable: bool,
+    from_bundle: Option<PathBuf>,
     all_packages: bool,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
@@ -63,7 +74,9 @@ pub(crate) async fn sync(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
     script: Option<Pep723Script>,
@@ -74,6 +87,18 @@ pub(crate) async fn sync(
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
+    // If installing from a bundle, read it, and avoid accessing the network.
+    let (bundle, network_settings) = if let Some(from_bundle) = from_bundle {
+        let bundle = Bundle::read(&from_bundle, cache).await?;
+        let network_settings = NetworkSettings {
+            connectivity: Connectivity::Offline,
+            ..network_settings
+        };
+        (Some(bundle), network_settings)
+    } else {
+        (None, network_settings)
+    };
+
     // Identify the target.
     let target = if let Some(script) = script {
         SyncTarget::Script(script)
@@ -125,9 +150,12 @@ pub(crate) async fn sync(
                 &install_mirrors,
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 no_config,
                 active,
+                relocatable,
                 cache,
                 dry_run,
                 printer,
@@ -140,7 +168,9 @@ pub(crate) async fn sync(
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 active,
@@ -449,6 +479,7 @@ pub(crate) async fn sync(
         editable,
         install_options,
         modifications,
+        bundle.as_ref(),
         settings.as_ref().into(),
         &network_settings,
         &state,
@@ -511,6 +542,7 @@ pub(super) async fn do_sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    bundle: Option<&Bundle>,
     settings: InstallerSettingsRef<'_>,
     network_settings: &NetworkSettings,
     state: &PlatformState,
@@ -537,6 +569,7 @@ pub(super) async fn do_sync(
         reinstall,
         build_options,
         sources,
+        yanked,
     } = settings;
 
     // Validate that the Python version is supported by the lockfile.
@@ -604,6 +637,13 @@ pub(super) async fn do_sync(
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // If installing from a bundle, read registry distributions from the bundle.
+    let resolution = if let Some(bundle) = bundle {
+        bundle.apply(resolution)
+    } else {
+        resolution
+    };
+
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
@@ -624,12 +664,18 @@ pub(super) async fn do_sync(
         .connectivity(network_settings.connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
+        .index_tracking(network_settings.index_tracking)
+        .range_request_size(network_settings.range_request_size)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         .markers(venv.interpreter().markers())
         .platform(venv.interpreter().platform())
         .build();
 
+    // Validate that none of the locked distributions have since been yanked.
+    check_yanked(&resolution, yanked, &client, concurrency).await?;
+
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(venv)
@@ -687,6 +733,7 @@ pub(super) async fn do_sync(
         modifications,
         reinstall,
         build_options,
+        install_options.allow_incompatible_wheels,
         link_mode,
         compile_bytecode,
         index_locations,
@@ -709,6 +756,141 @@ pub(super) async fn do_sync(
     Ok(())
 }
 
+/// Check whether any of the locked registry distributions were yanked after the lockfile was
+/// created.
+///
+/// The check is only performed if the user explicitly configured a [`YankedMode`], since it
+/// requires fetching the index metadata for every locked package.
+async fn check_yanked(
+    resolution: &Resolution,
+    yanked: &YankedPolicy,
+    client: &RegistryClient,
+    concurrency: Concurrency,
+) -> Result<(), ProjectError> {
+    if !yanked.is_explicit() || yanked.mode() == YankedMode::Allow {
+        return Ok(());
+    }
+
+    // Collect the locked files that were sourced from a registry.
+    let locked = resolution
+        .distributions()
+        .filter_map(|dist| {
+            let ResolvedDist::Installable { dist, .. } = dist else {
+                return None;
+            };
+            let (name, version, filename, index) = match dist.as_ref() {
+                Dist::Built(BuiltDist::Registry(wheels)) => {
+                    let wheel = wheels.best_wheel();
+                    (
+                        &wheel.filename.name,
+                        &wheel.filename.version,
+                        wheel.file.filename.as_ref(),
+                        &wheel.index,
+                    )
+                }
+                Dist::Source(SourceDist::Registry(sdist)) => (
+                    &sdist.name,
+                    &sdist.version,
+                    sdist.file.filename.as_ref(),
+                    &sdist.index,
+                ),
+                _ => return None,
+            };
+            yanked
+                .should_warn(name, version)
+                .then_some((name, version, filename, index))
+        })
+        .collect::<Vec<_>>();
+
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    let mut fetches = futures::stream::iter(locked)
+        .map(|(name, version, filename, index)| {
+            let capabilities = &capabilities;
+            let download_concurrency = &download_concurrency;
+            async move {
+                let archives = match client
+                    .simple(name, Some(index), capabilities, download_concurrency)
+                    .await
+                {
+                    Ok(archives) => archives,
+                    Err(err) => {
+                        return match err.into_kind() {
+                            uv_client::ErrorKind::PackageNotFound(_)
+                            | uv_client::ErrorKind::NoIndex(_)
+                            | uv_client::ErrorKind::Offline(_) => {
+                                debug!("Unable to check whether `{name}=={version}` is yanked");
+                                Ok(None)
+                            }
+                            kind => Err(uv_client::Error::from(kind)),
+                        };
+                    }
+                };
+
+                for (_, archive) in archives {
+                    for datum in archive.iter() {
+                        let datum_version =
+                            rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                                .expect("archived version always deserializes");
+                        if datum_version != *version {
+                            continue;
+                        }
+                        let files =
+                            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                                .expect("archived version files always deserializes");
+                        for (_, file) in files.all() {
+                            if *file.filename != *filename {
+                                continue;
+                            }
+                            if let Some(yanked) = file.yanked.filter(|yanked| yanked.is_yanked()) {
+                                return Ok(Some((name, version, yanked)));
+                            }
+                        }
+                    }
+                }
+                Ok(None)
+            }
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut yanks = Vec::new();
+    while let Some(result) = fetches.next().await {
+        if let Some(yank) = result.map_err(anyhow::Error::from)? {
+            yanks.push(yank);
+        }
+    }
+    yanks.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    match yanked.mode() {
+        YankedMode::Deny => {
+            if !yanks.is_empty() {
+                return Err(ProjectError::LockedYanked(
+                    yanks
+                        .iter()
+                        .map(|(name, version, _)| format!("`{name}=={version}`"))
+                        .join(", "),
+                ));
+            }
+        }
+        YankedMode::Warn => {
+            for (name, version, yanked) in yanks {
+                match *yanked {
+                    Yanked::Reason(reason) => {
+                        warn_user!("`{name}=={version}` is yanked (reason: \"{reason}\")");
+                    }
+                    Yanked::Bool(_) => {
+                        warn_user!("`{name}=={version}` is yanked");
+                    }
+                }
+            }
+        }
+        YankedMode::Allow => {}
+    }
+
+    Ok(())
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -770,7 +952,7 @@ fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolu
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>) {
+pub(super) fn store_credentials_from_target(target: InstallTarget<'_>) {
     // Iterate over any idnexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
@@ -783,10 +965,13 @@ fn store_credentials_from_target(target: InstallTarget<'_>) {
     }
 
     // Iterate over any sources in the target.
-    for source in target.sources() {
+    for (root, source) in target.sources() {
         match source {
             Source::Git { git, .. } => {
                 uv_git::store_credentials_from_url(git);
+                if let Some(ssh_key) = source.ssh_key(root) {
+                    uv_git::store_ssh_key(git, ssh_key);
+                }
             }
             Source::Url { url, .. } => {
                 uv_auth::store_credentials_from_url(url);
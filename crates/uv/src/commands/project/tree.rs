@@ -10,6 +10,7 @@ use uv_client::RegistryClientBuilder;
 use uv_configuration::{Concurrency, DependencyGroups, PreviewMode, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
 use uv_pep508::PackageName;
+use uv_python::policy::PythonPolicy;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_resolver::{PackageMap, TreeDisplay};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
@@ -51,7 +52,9 @@ pub(crate) async fn tree(
     network_settings: &NetworkSettings,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -85,7 +88,9 @@ pub(crate) async fn tree(
                 python.as_deref().map(PythonRequest::parse),
                 network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -100,7 +105,9 @@ pub(crate) async fn tree(
                 python.as_deref().map(PythonRequest::parse),
                 network_settings,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &install_mirrors,
                 no_config,
                 Some(false),
@@ -186,6 +193,12 @@ pub(crate) async fn tree(
                 resolution: _,
                 prerelease: _,
                 fork_strategy: _,
+                minimal_changes: _,
+                break_extra_cycles: _,
+                prefetch_versions: _,
+                budget: _,
+                yanked: _,
+                supported_python: _,
                 dependency_metadata: _,
                 config_setting: _,
                 no_build_isolation: _,
@@ -207,6 +220,9 @@ pub(crate) async fn tree(
             .connectivity(network_settings.connectivity)
             .keyring(*keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone())
+            .index_tracking(network_settings.index_tracking)
+            .range_request_size(network_settings.range_request_size)
             .build();
             let download_concurrency = Semaphore::new(concurrency.downloads);
 
@@ -58,6 +58,7 @@ pub(crate) async fn publish(
         .keyring(keyring_provider)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone())
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
         // Set a very high timeout for uploads, connections are often 10x slower on upload than
@@ -95,8 +96,11 @@ pub(crate) async fn publish(
             .native_tls(network_settings.native_tls)
             .connectivity(network_settings.connectivity)
             .index_urls(index_urls)
+            .index_tracking(network_settings.index_tracking)
+            .range_request_size(network_settings.range_request_size)
             .keyring(keyring_provider)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
         Some(CheckUrlClient {
             index_url: index_url.clone(),
             registry_client_builder,
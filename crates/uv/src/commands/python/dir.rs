@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use anstream::println;
 use anyhow::Context;
 use owo_colors::OwoColorize;
@@ -6,12 +8,12 @@ use uv_fs::Simplified;
 use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
 
 /// Show the Python installation directory.
-pub(crate) fn dir(bin: bool) -> anyhow::Result<()> {
+pub(crate) fn dir(bin: bool, install_dir: Option<PathBuf>) -> anyhow::Result<()> {
     if bin {
         let bin = python_executable_dir()?;
         println!("{}", bin.simplified_display().cyan());
     } else {
-        let installed_toolchains = ManagedPythonInstallations::from_settings(None)
+        let installed_toolchains = ManagedPythonInstallations::from_settings(install_dir)
             .context("Failed to initialize toolchain settings")?;
         println!(
             "{}",
@@ -3,26 +3,61 @@ use anyhow::Result;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
 use uv_fs::Simplified;
-use uv_python::{EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest};
+use uv_python::policy::PythonPolicy;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+};
+use uv_scripts::{Pep723ItemRef, Pep723Script};
+use uv_settings::PythonInstallMirrors;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceError};
 
+use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{
-    project::{validate_project_requires_python, WorkspacePython},
+    project::{validate_project_requires_python, ScriptInterpreter, WorkspacePython},
     ExitStatus,
 };
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
 
 /// Find a Python interpreter.
 pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     no_project: bool,
+    script_path: Option<&Path>,
+    script: Option<Pep723Script>,
     no_config: bool,
     system: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
+    install_mirrors: &PythonInstallMirrors,
+    network_settings: &NetworkSettings,
     cache: &Cache,
+    printer: Printer,
 ) -> Result<ExitStatus> {
+    if let Some(script_path) = script_path {
+        return find_script(
+            script_path,
+            script.as_ref(),
+            request,
+            no_config,
+            python_preference,
+            python_install_dir,
+            python_downloads,
+            python_policy,
+            install_mirrors,
+            network_settings,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
     let environment_preference = if system {
         EnvironmentPreference::OnlySystem
     } else {
@@ -60,6 +95,7 @@ pub(crate) async fn find(
         &python_request.unwrap_or_default(),
         environment_preference,
         python_preference,
+        python_install_dir,
         cache,
     )?;
 
@@ -85,3 +121,93 @@ pub(crate) async fn find(
 
     Ok(ExitStatus::Success)
 }
+
+/// Find a Python interpreter for a script.
+///
+/// Prefers the interpreter of the script environment, if any; otherwise, an interpreter is found
+/// (or downloaded) that satisfies the script's `requires-python` and the Python version in its
+/// shebang.
+async fn find_script(
+    script_path: &Path,
+    script: Option<&Pep723Script>,
+    request: Option<String>,
+    no_config: bool,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
+    install_mirrors: &PythonInstallMirrors,
+    network_settings: &NetworkSettings,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // An explicit request takes precedence over the shebang, e.g., `#!/usr/bin/env python3.12`.
+    let python_request = match request {
+        Some(request) => Some(PythonRequest::parse(&request)),
+        None => {
+            let contents = fs_err::tokio::read(script_path).await?;
+            uv_scripts::shebang_python(&contents)
+                .map(PythonRequest::parse)
+                .filter(|request| {
+                    matches!(
+                        request,
+                        PythonRequest::Version(_)
+                            | PythonRequest::Implementation(_)
+                            | PythonRequest::ImplementationVersion(..)
+                    )
+                })
+        }
+    };
+
+    let interpreter = if let Some(script) = script {
+        ScriptInterpreter::discover(
+            Pep723ItemRef::Script(script),
+            python_request,
+            network_settings,
+            python_preference,
+            python_install_dir,
+            python_downloads,
+            python_policy,
+            install_mirrors,
+            no_config,
+            None,
+            cache,
+            printer,
+        )
+        .await?
+        .into_interpreter()
+    } else {
+        // Without PEP 723 metadata, the script doesn't have an environment of its own.
+        let client_builder = BaseClientBuilder::new()
+            .connectivity(network_settings.connectivity)
+            .native_tls(network_settings.native_tls)
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone());
+
+        let reporter = PythonDownloadReporter::single(printer);
+
+        PythonInstallation::find_or_download(
+            python_request.as_ref(),
+            EnvironmentPreference::OnlySystem,
+            python_preference,
+            python_install_dir,
+            python_downloads,
+            python_policy,
+            &client_builder,
+            cache,
+            Some(&reporter),
+            install_mirrors.python_install_mirror.as_deref(),
+            install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
+        )
+        .await?
+        .into_interpreter()
+    };
+
+    println!(
+        "{}",
+        std::path::absolute(interpreter.sys_executable())?.simplified_display()
+    );
+
+    Ok(ExitStatus::Success)
+}
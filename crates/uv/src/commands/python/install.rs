@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Write;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Error, Result};
 use futures::stream::FuturesUnordered;
@@ -11,15 +12,22 @@ use owo_colors::OwoColorize;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
+use uv_cache::Cache;
+use uv_cli::PythonBuildVariant;
+use uv_client::Connectivity;
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
+use uv_python::adopt::{find_external_installations, ExternalInstallation};
+use uv_python::build_pin::{PythonBuild, PythonBuildFile};
 use uv_python::downloads::{self, DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::managed::{
     python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
 };
-use uv_python::platform::Libc;
+use uv_python::platform::{Arch, Libc};
+use uv_python::policy::PythonPolicy;
+use uv_python::source_build::PythonSourceBuild;
 use uv_python::{
-    PythonDownloads, PythonInstallationKey, PythonRequest, PythonVersionFile,
+    Interpreter, PythonDownloads, PythonInstallationKey, PythonRequest, PythonVersionFile,
     VersionFileDiscoveryOptions, VersionFilePreference,
 };
 use uv_shell::Shell;
@@ -30,7 +38,34 @@ use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{NetworkSettings, PythonInstallSettings};
+
+/// A distribution that satisfies an install request.
+#[derive(Debug, Clone)]
+enum Distribution {
+    /// A pre-built distribution, e.g., from `python-build-standalone`.
+    Download(ManagedPythonDownload),
+    /// A CPython version built from source, for platforms without a pre-built distribution.
+    SourceBuild(PythonSourceBuild),
+}
+
+impl Distribution {
+    fn key(&self) -> &PythonInstallationKey {
+        match self {
+            Self::Download(download) => download.key(),
+            Self::SourceBuild(build) => build.key(),
+        }
+    }
+}
+
+impl std::fmt::Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Download(download) => write!(f, "{download}"),
+            Self::SourceBuild(build) => write!(f, "{build} (from source)"),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 struct InstallRequest {
@@ -38,30 +73,78 @@ struct InstallRequest {
     request: PythonRequest,
     /// A download request corresponding to the `request` with platform information filled
     download_request: PythonDownloadRequest,
-    /// A download that satisfies the request
-    download: &'static ManagedPythonDownload,
+    /// A distribution that satisfies the request
+    distribution: Distribution,
+    /// The build pinned by the project for the request, if any
+    pin: Option<PythonBuild>,
 }
 
 impl InstallRequest {
-    fn new(request: PythonRequest) -> Result<Self> {
+    /// Create an [`InstallRequest`], targeting the given C library and architecture, if any, rather
+    /// than those of the host.
+    ///
+    /// If the project pins a build that satisfies the request, the pinned build is used rather
+    /// than the latest build. If `build_from_source` is set and no pre-built distribution
+    /// satisfies the request, CPython is built from a pinned source distribution instead.
+    fn new(
+        request: PythonRequest,
+        libc: Option<Libc>,
+        arch: Option<Arch>,
+        python_policy: Option<&PythonPolicy>,
+        python_downloads_json_url: Option<&str>,
+        pins: Option<&PythonBuildFile>,
+        build_from_source: bool,
+    ) -> Result<Self> {
         // Make sure the request is a valid download request and fill platform information
-        let download_request = PythonDownloadRequest::from_request(&request)
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "`{}` is not a valid Python download request; see `uv help python` for supported formats and `uv python list --only-downloads` for available versions",
-                    request.to_canonical_string()
-                )
-            })?
-            .fill()?;
+        let download_request = PythonDownloadRequest::from_request(&request).ok_or_else(|| {
+            anyhow::anyhow!(
+                "`{}` is not a valid Python download request; see `uv help python` for supported formats and `uv python list --only-downloads` for available versions",
+                request.to_canonical_string()
+            )
+        })?;
+        let download_request = match libc {
+            Some(libc) => download_request.with_libc(libc),
+            None => download_request,
+        };
+        let download_request = match arch {
+            Some(arch) => download_request.with_arch(arch),
+            None => download_request,
+        }
+        .fill()?;
 
-        // Find a matching download
-        let download = match ManagedPythonDownload::from_request(&download_request) {
-            Ok(download) => download,
+        // Find a matching download, preferring the build pinned by the project
+        let pin = pins.and_then(|pins| pins.find(&download_request)).cloned();
+        let download = match &pin {
+            Some(pin) => {
+                debug!(
+                    "Using pinned build of `{}` for request `{}`",
+                    pin.key(),
+                    request
+                );
+                ManagedPythonDownload::from_build(pin, &download_request, python_policy)
+            }
+            None => ManagedPythonDownload::from_request(
+                &download_request,
+                python_policy,
+                python_downloads_json_url,
+            ),
+        };
+        let distribution = match download {
+            Ok(download) => Distribution::Download(download),
+            Err(downloads::Error::NoDownloadFound(request)) if build_from_source => {
+                let Some(build) = PythonSourceBuild::from_request(&request, python_policy) else {
+                    return Err(anyhow::anyhow!(
+                        "No Python distribution found for `{request}`, and no pinned CPython source distribution to build it from"
+                    ));
+                };
+                debug!("No download found for `{}`; building from source", request);
+                Distribution::SourceBuild(build)
+            }
             Err(downloads::Error::NoDownloadFound(request))
                 if request.libc().is_some_and(Libc::is_musl) =>
             {
                 return Err(anyhow::anyhow!(
-                    "uv does not yet provide musl Python distributions. See https://github.com/astral-sh/uv/issues/6890 to track support."
+                    "No musl Python distribution found for `{request}`; musl distributions are only available for x86-64 Linux"
                 ));
             }
             Err(err) => return Err(err.into()),
@@ -70,13 +153,24 @@ impl InstallRequest {
         Ok(Self {
             request,
             download_request,
-            download,
+            distribution,
+            pin,
         })
     }
 
     fn matches_installation(&self, installation: &ManagedPythonInstallation) -> bool {
         self.download_request.satisfied_by_key(installation.key())
     }
+
+    /// Returns `true` if the installation satisfies the request, i.e., matches the request and,
+    /// if the project pins a build, is of the pinned build.
+    fn is_satisfied_by(&self, installation: &ManagedPythonInstallation) -> bool {
+        self.matches_installation(installation)
+            && self
+                .pin
+                .as_ref()
+                .is_none_or(|pin| pin.matches_installation(installation))
+    }
 }
 
 impl std::fmt::Display for InstallRequest {
@@ -121,24 +215,52 @@ impl Changelog {
 }
 
 /// Download and install Python versions.
-#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn install(
     project_dir: &Path,
-    install_dir: Option<PathBuf>,
-    targets: Vec<String>,
-    reinstall: bool,
-    force: bool,
-    python_install_mirror: Option<String>,
-    pypy_install_mirror: Option<String>,
+    settings: PythonInstallSettings,
     network_settings: NetworkSettings,
-    default: bool,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     no_config: bool,
+    cache: &Cache,
     preview: PreviewMode,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    let PythonInstallSettings {
+        install_dir,
+        targets,
+        reinstall,
+        force,
+        python_install_mirror,
+        pypy_install_mirror,
+        python_downloads_json_url,
+        default,
+        variant: variants,
+        no_pip,
+        arch,
+        from_dir,
+        build_from_source,
+        adopt,
+        registry,
+    } = settings;
+
+    // Registration in the Windows registry is opt-in, unless preview mode is enabled.
+    let registry = registry.unwrap_or(preview.is_enabled());
+
+    if adopt {
+        let requests = if targets.is_empty() {
+            vec![PythonRequest::Any]
+        } else {
+            targets
+                .iter()
+                .map(|target| PythonRequest::parse(target.as_str()))
+                .collect()
+        };
+        return adopt_installations(install_dir, &requests, reinstall, cache, printer).await;
+    }
+
     if default && !preview.is_enabled() {
         writeln!(printer.stderr(), "The `--default` flag is only available in preview mode; add the `--preview` flag to use `--default`")?;
         return Ok(ExitStatus::Failure);
@@ -148,6 +270,29 @@ pub(crate) async fn install(
         anyhow::bail!("The `--default` flag cannot be used with multiple targets");
     }
 
+    // Target the requested C library, rather than that of the host.
+    let libc = match (
+        variants.contains(&PythonBuildVariant::Gnu),
+        variants.contains(&PythonBuildVariant::Musl),
+    ) {
+        (true, true) => anyhow::bail!("The `gnu` and `musl` variants can't be combined"),
+        (true, false) => Some(Libc::from_str("gnu")?),
+        (false, true) => Some(Libc::from_str("musl")?),
+        (false, false) => None,
+    };
+
+    let minimal = variants.contains(&PythonBuildVariant::Minimal);
+    if no_pip && !minimal {
+        anyhow::bail!("The `--no-pip` flag requires the `minimal` variant (`--variant minimal`)");
+    }
+
+    // Read the builds pinned by the project, if any
+    let pins = if no_config {
+        None
+    } else {
+        PythonBuildFile::discover(project_dir).await?
+    };
+
     // Resolve the requests
     let mut is_default_install = false;
     let requests: Vec<_> = if targets.is_empty() {
@@ -170,13 +315,33 @@ pub(crate) async fn install(
             }]
         })
         .into_iter()
-        .map(InstallRequest::new)
+        .map(|request| {
+            InstallRequest::new(
+                request,
+                libc,
+                arch,
+                python_policy,
+                python_downloads_json_url.as_deref(),
+                pins.as_ref(),
+                build_from_source,
+            )
+        })
         .collect::<Result<Vec<_>>>()?
     } else {
         targets
             .iter()
             .map(|target| PythonRequest::parse(target.as_str()))
-            .map(InstallRequest::new)
+            .map(|request| {
+                InstallRequest::new(
+                    request,
+                    libc,
+                    arch,
+                    python_policy,
+                    python_downloads_json_url.as_deref(),
+                    pins.as_ref(),
+                    build_from_source,
+                )
+            })
             .collect::<Result<Vec<_>>>()?
     };
 
@@ -216,7 +381,15 @@ pub(crate) async fn install(
                 changelog.existing.insert(installation.key().clone());
                 if matches!(&request.request, &PythonRequest::Any) {
                     // Construct a install request matching the existing installation
-                    match InstallRequest::new(PythonRequest::Key(installation.into())) {
+                    match InstallRequest::new(
+                        PythonRequest::Key(installation.into()),
+                        None,
+                        None,
+                        python_policy,
+                        python_downloads_json_url.as_deref(),
+                        pins.as_ref(),
+                        build_from_source,
+                    ) {
                         Ok(request) => {
                             debug!("Will reinstall `{}`", installation.key().green());
                             unsatisfied.push(Cow::Owned(request));
@@ -252,7 +425,7 @@ pub(crate) async fn install(
         requests.iter().partition_map(|request| {
             if let Some(installation) = existing_installations
                 .iter()
-                .find(|installation| request.matches_installation(installation))
+                .find(|installation| request.is_satisfied_by(installation))
             {
                 debug!(
                     "Found `{}` for request `{}`",
@@ -267,8 +440,30 @@ pub(crate) async fn install(
         })
     };
 
-    // Check if Python downloads are banned
-    if matches!(python_downloads, PythonDownloads::Never) && !unsatisfied.is_empty() {
+    // Replace the installations of other builds than those pinned by the project
+    let replaced: FxHashSet<&PythonInstallationKey> = unsatisfied
+        .iter()
+        .filter(|request| request.pin.is_some())
+        .filter_map(|request| {
+            existing_installations
+                .iter()
+                .find(|installation| installation.key() == request.distribution.key())
+        })
+        .map(|installation| {
+            debug!(
+                "Replacing `{}` with the pinned build",
+                installation.key().green()
+            );
+            changelog.existing.insert(installation.key().clone());
+            installation.key()
+        })
+        .collect();
+
+    // Check if Python downloads are banned; installing from local archives is not a download
+    if matches!(python_downloads, PythonDownloads::Never)
+        && from_dir.is_none()
+        && !unsatisfied.is_empty()
+    {
         writeln!(
             printer.stderr(),
             "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit installs.",
@@ -282,45 +477,99 @@ pub(crate) async fn install(
         .inspect(|request| {
             debug!(
                 "Found download `{}` for request `{}`",
-                request.download,
+                request.distribution,
                 request.cyan(),
             );
         })
-        .map(|request| request.download)
+        .map(|request| &request.distribution)
         // Ensure we only download each version once
-        .unique_by(|download| download.key())
+        .unique_by(|distribution| distribution.key())
         .collect::<Vec<_>>();
 
+    for distribution in &downloads {
+        if let Distribution::SourceBuild(build) = distribution {
+            writeln!(
+                printer.stderr(),
+                "Building {} from source; this may take a while",
+                build.key().green()
+            )?;
+        }
+    }
+
+    // The mirrors required by the system policy take precedence over the user's settings.
+    let (python_install_mirror, pypy_install_mirror) = PythonPolicy::restrict_install_mirrors(
+        python_policy,
+        python_install_mirror.as_deref(),
+        pypy_install_mirror.as_deref(),
+    );
+
     // Download and unpack the Python versions concurrently
-    let client = uv_client::BaseClientBuilder::new()
-        .connectivity(network_settings.connectivity)
+    let client_builder = uv_client::BaseClientBuilder::new()
+        .connectivity(if from_dir.is_some() {
+            Connectivity::Offline
+        } else {
+            network_settings.connectivity
+        })
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
-        .build();
+        .http_retries(network_settings.http_retries.clone());
+    let client = downloads::with_download_settings(
+        client_builder,
+        python_install_mirror,
+        pypy_install_mirror,
+    )
+    .build();
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
     let mut tasks = FuturesUnordered::new();
-    for download in &downloads {
+    for distribution in &downloads {
         tasks.push(async {
-            (
-                *download,
-                download
-                    .fetch_with_retry(
+            let result = match distribution {
+                Distribution::Download(download) => match from_dir.as_deref() {
+                    Some(from_dir) => {
+                        download
+                            .fetch_from_dir(
+                                from_dir,
+                                &client,
+                                installations_dir,
+                                &scratch_dir,
+                                reinstall || replaced.contains(download.key()),
+                                Some(&reporter),
+                            )
+                            .await
+                    }
+                    None => {
+                        download
+                            .fetch_with_retry(
+                                &client,
+                                installations_dir,
+                                &scratch_dir,
+                                reinstall || replaced.contains(download.key()),
+                                python_install_mirror,
+                                pypy_install_mirror,
+                                Some(&reporter),
+                            )
+                            .await
+                    }
+                }
+                .map_err(anyhow::Error::new),
+                Distribution::SourceBuild(build) => build
+                    .build(
                         &client,
                         installations_dir,
                         &scratch_dir,
                         reinstall,
-                        python_install_mirror.as_deref(),
-                        pypy_install_mirror.as_deref(),
                         Some(&reporter),
                     )
-                    .await,
-            )
+                    .await
+                    .map_err(anyhow::Error::new),
+            };
+            (*distribution, result)
         });
     }
 
     let mut errors = vec![];
     let mut downloaded = Vec::with_capacity(downloads.len());
-    while let Some((download, result)) = tasks.next().await {
+    while let Some((distribution, result)) = tasks.next().await {
         match result {
             Ok(download_result) => {
                 let path = match download_result {
@@ -329,7 +578,14 @@ pub(crate) async fn install(
                     DownloadResult::Fetched(path) => path,
                 };
 
-                let installation = ManagedPythonInstallation::new(path, download);
+                let installation = match distribution {
+                    Distribution::Download(download) => {
+                        ManagedPythonInstallation::new(path, download)
+                    }
+                    Distribution::SourceBuild(build) => {
+                        ManagedPythonInstallation::from_source_build(path, build)
+                    }
+                };
                 changelog.installed.insert(installation.key().clone());
                 if changelog.existing.contains(installation.key()) {
                     changelog.uninstalled.insert(installation.key().clone());
@@ -337,7 +593,7 @@ pub(crate) async fn install(
                 downloaded.push(installation);
             }
             Err(err) => {
-                errors.push((download.key().clone(), anyhow::Error::new(err)));
+                errors.push((distribution.key().clone(), err));
             }
         }
     }
@@ -356,9 +612,13 @@ pub(crate) async fn install(
         installation.ensure_externally_managed()?;
         installation.ensure_sysconfig_patched()?;
         installation.ensure_canonical_executables()?;
+        installation.ensure_minor_version_link()?;
         if let Err(e) = installation.ensure_dylib_patched() {
             e.warn_user(installation);
         }
+        if minimal {
+            installation.ensure_minimal(!no_pip)?;
+        }
 
         if preview.is_disabled() {
             debug!("Skipping installation of Python executables, use `--preview` to enable.");
@@ -370,21 +630,29 @@ pub(crate) async fn install(
             .expect("We should have a bin directory with preview enabled")
             .as_path();
 
-        create_bin_links(
-            installation,
-            bin,
-            reinstall,
-            force,
-            default,
-            is_default_install,
-            first_request,
-            &existing_installations,
-            &installations,
-            &mut changelog,
-            &mut errors,
-        )?;
+        // Executables of emulated architectures would shadow those of the host's architecture.
+        if installation.key().arch().family() == Arch::from_env().family() {
+            create_bin_links(
+                installation,
+                bin,
+                reinstall,
+                force,
+                default,
+                is_default_install,
+                first_request,
+                &existing_installations,
+                &installations,
+                &mut changelog,
+                &mut errors,
+            )?;
+        } else {
+            debug!(
+                "Skipping installation of Python executables for `{}`, which targets another architecture",
+                installation.key()
+            );
+        }
 
-        if preview.is_enabled() {
+        if registry {
             #[cfg(windows)]
             {
                 uv_python::windows_registry::create_registry_entry(installation, &mut errors)?;
@@ -497,6 +765,177 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Adopt the Python installations of pyenv and asdf that satisfy the requests as managed Python
+/// installations.
+async fn adopt_installations(
+    install_dir: Option<PathBuf>,
+    requests: &[PythonRequest],
+    reinstall: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
+    let _lock = installations.lock().await?;
+    let existing_installations: FxHashSet<_> = installations
+        .find_all()?
+        .map(|installation| installation.key().clone())
+        .collect();
+
+    // Query the installations of the other version managers; only CPython and PyPy can be adopted.
+    let candidates = find_external_installations()
+        .into_iter()
+        .filter_map(
+            |external| match Interpreter::query(external.executable(), cache) {
+                Ok(interpreter) => Some((external, interpreter)),
+                Err(err) => {
+                    debug!("Skipping `{external}`: {err}");
+                    None
+                }
+            },
+        )
+        .filter(|(external, interpreter)| {
+            if matches!(interpreter.implementation_name(), "cpython" | "pypy") {
+                true
+            } else {
+                debug!(
+                    "Skipping `{external}`: {} cannot be adopted",
+                    interpreter.implementation_name()
+                );
+                false
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Select the installations that satisfy the requests, preferring pyenv over asdf for the same
+    // key.
+    let mut selected: Vec<(PythonInstallationKey, &ExternalInstallation)> = Vec::new();
+    let mut unsatisfied = Vec::new();
+    for request in requests {
+        let mut found = false;
+        for (external, interpreter) in &candidates {
+            if !request.satisfied(interpreter, cache) {
+                continue;
+            }
+            found = true;
+            let key = interpreter.key();
+            if selected.iter().any(|(selected, _)| *selected == key) {
+                continue;
+            }
+            if existing_installations.contains(&key) && !reinstall {
+                debug!("`{}` is already installed", key.green());
+                continue;
+            }
+            selected.push((key, external));
+        }
+        if !found && !matches!(request, PythonRequest::Any) {
+            unsatisfied.push(request);
+        }
+    }
+
+    let mut adopted = Vec::with_capacity(selected.len());
+    let mut errors = vec![];
+    for (key, external) in selected {
+        debug!("Adopting `{external}` as `{}`", key.green());
+        let result = installations
+            .adopt(key.clone(), external)
+            .and_then(|installation| {
+                installation.ensure_canonical_executables()?;
+                installation.ensure_minor_version_link()?;
+                Ok(installation)
+            });
+        match result {
+            Ok(installation) => adopted.push((installation, external)),
+            Err(err) => errors.push((key, err)),
+        }
+    }
+
+    if !adopted.is_empty() {
+        if let [(installation, _)] = adopted.as_slice() {
+            // Ex) "Adopted Python 3.12.4 in 12ms"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Adopted {} {}",
+                    format!("Python {}", installation.version()).bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        } else {
+            // Ex) "Adopted 2 versions in 12ms"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Adopted {} {}",
+                    format!("{} versions", adopted.len()).bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        }
+        for (installation, external) in adopted
+            .iter()
+            .sorted_unstable_by(|(a, _), (b, _)| a.key().cmp(b.key()))
+        {
+            let marker = if existing_installations.contains(installation.key()) {
+                "~".yellow().to_string()
+            } else {
+                "+".green().to_string()
+            };
+            writeln!(
+                printer.stderr(),
+                " {marker} {} (from {})",
+                installation.key().bold(),
+                external.manager()
+            )?;
+        }
+    } else if errors.is_empty() && unsatisfied.is_empty() {
+        if candidates.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No Python installations of pyenv or asdf found"
+            )?;
+        } else {
+            writeln!(printer.stderr(), "All requested versions already installed")?;
+        }
+    }
+
+    if unsatisfied.is_empty() && errors.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    for request in unsatisfied {
+        writeln!(
+            printer.stderr(),
+            "{}: No Python installation of pyenv or asdf found for `{}`",
+            "error".red().bold(),
+            request.to_canonical_string().cyan()
+        )?;
+    }
+    for (key, err) in errors {
+        writeln!(
+            printer.stderr(),
+            "{}: Failed to adopt {}",
+            "error".red().bold(),
+            key.green()
+        )?;
+        let err = anyhow::Error::new(err);
+        for err in err.chain() {
+            writeln!(
+                printer.stderr(),
+                "  {}: {}",
+                "Caused by".red().bold(),
+                err.to_string().trim()
+            )?;
+        }
+    }
+    Ok(ExitStatus::Failure)
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 #[allow(clippy::fn_params_excessive_bools)]
 fn create_bin_links(
@@ -705,7 +1144,7 @@ pub(crate) fn format_executables(
     format!(" ({names})")
 }
 
-fn warn_if_not_on_path(bin: &Path) {
+pub(super) fn warn_if_not_on_path(bin: &Path) {
     if !Shell::contains_path(bin) {
         if let Some(shell) = Shell::from_env() {
             if let Some(command) = shell.prepend_path(bin) {
@@ -1,6 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use uv_cli::PythonListFormat;
 use uv_pep440::Version;
 
@@ -11,6 +12,8 @@ use rustc_hash::FxHashSet;
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
+use uv_python::platform::Arch;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
     PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
@@ -20,10 +23,15 @@ use crate::commands::ExitStatus;
 use crate::printer::Printer;
 use crate::settings::PythonListKinds;
 
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+/// The source of a listed Python version.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
 enum Kind {
+    /// A download that is available for installation.
     Download,
+    /// An installation managed by uv.
     Managed,
+    /// An installation discovered on the system.
     System,
 }
 
@@ -47,6 +55,7 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    source: Kind,
 }
 
 /// List available Python installations.
@@ -56,19 +65,25 @@ pub(crate) async fn list(
     all_versions: bool,
     all_platforms: bool,
     all_arches: bool,
+    arch: Option<Arch>,
     show_urls: bool,
     output_format: PythonListFormat,
+    python_downloads_json_url: Option<&str>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let mut output = BTreeSet::new();
     if python_preference != PythonPreference::OnlySystem {
         let download_request = match kinds {
-            PythonListKinds::Installed => None,
+            PythonListKinds::Installed | PythonListKinds::Managed => None,
             PythonListKinds::Downloads => Some(if all_platforms {
                 PythonDownloadRequest::default()
+            } else if arch.is_some() {
+                PythonDownloadRequest::from_env()?.with_any_arch()
             } else {
                 PythonDownloadRequest::from_env()?
             }),
@@ -76,7 +91,7 @@ pub(crate) async fn list(
                 if python_downloads.is_automatic() {
                     Some(if all_platforms {
                         PythonDownloadRequest::default()
-                    } else if all_arches {
+                    } else if all_arches || arch.is_some() {
                         PythonDownloadRequest::from_env()?.with_any_arch()
                     } else {
                         PythonDownloadRequest::from_env()?
@@ -92,7 +107,8 @@ pub(crate) async fn list(
 
         let downloads = download_request
             .as_ref()
-            .map(PythonDownloadRequest::iter_downloads)
+            .map(|request| request.iter_downloads(python_policy, python_downloads_json_url))
+            .transpose()?
             .into_iter()
             .flatten();
 
@@ -100,18 +116,23 @@ pub(crate) async fn list(
             output.insert((
                 download.key().clone(),
                 Kind::Download,
-                Either::Right(download.url()),
+                Either::Right(download.url().to_string()),
             ));
         }
     };
 
     let installed =
         match kinds {
-            PythonListKinds::Installed | PythonListKinds::Default => {
+            PythonListKinds::Installed | PythonListKinds::Managed | PythonListKinds::Default => {
                 Some(find_python_installations(
                 &PythonRequest::Any,
                 EnvironmentPreference::OnlySystem,
-                python_preference,
+                if matches!(kinds, PythonListKinds::Managed) {
+                    PythonPreference::OnlyManaged
+                } else {
+                    python_preference
+                },
+                python_install_dir,
                 cache,
             )
             // Raise discovery errors if critical
@@ -149,6 +170,11 @@ pub(crate) async fn list(
     let mut seen_paths = FxHashSet::default();
     let mut include = Vec::new();
     for (key, kind, uri) in output.iter().rev() {
+        // Only show versions for the requested architecture
+        if arch.is_some_and(|arch| *key.arch() != arch) {
+            continue;
+        }
+
         // Do not show the same path more than once
         if let Either::Left(path) = uri {
             if !seen_paths.insert(path) {
@@ -190,14 +216,14 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, *kind, uri));
     }
 
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, kind, uri)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -212,7 +238,7 @@ pub(crate) async fn list(
                             }
                         }
                         Either::Right(url) => {
-                            url_or_none = Some((*url).to_string());
+                            url_or_none = Some(url.clone());
                         }
                     };
                     let version = key.version();
@@ -235,6 +261,7 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        source: *kind,
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -244,9 +271,9 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, _, uri) in include {
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
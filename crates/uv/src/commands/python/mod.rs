@@ -1,9 +1,22 @@
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashSet;
+
+use uv_fs::{Simplified, CWD};
+use uv_normalize::PackageName;
+use uv_python::PyVenvConfiguration;
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+
 pub(crate) mod dir;
 pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
+pub(crate) mod relocate;
+pub(crate) mod shim;
 pub(crate) mod uninstall;
+pub(crate) mod upgrade;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub(super) enum ChangeEventKind {
@@ -20,3 +33,49 @@ pub(super) struct ChangeEvent {
     key: uv_python::PythonInstallationKey,
     kind: ChangeEventKind,
 }
+
+/// A virtual environment that may have been created from a managed Python installation.
+#[derive(Debug)]
+pub(super) enum DependentEnvironment {
+    /// The environment of an installed tool.
+    Tool(PackageName),
+    /// A virtual environment, e.g., the active environment or the `.venv` of a project.
+    Virtualenv(PathBuf),
+}
+
+/// Find the known virtual environments, along with their root directories.
+///
+/// The known environments are those of installed tools, the active virtual environment, and the
+/// `.venv` discovered from the working directory.
+pub(super) fn find_known_environments() -> Vec<(DependentEnvironment, PathBuf)> {
+    let mut environments = Vec::new();
+
+    if let Ok(installed_tools) = InstalledTools::from_settings() {
+        for (name, _) in installed_tools.tools().unwrap_or_default() {
+            let root = installed_tools.tool_dir(&name);
+            environments.push((DependentEnvironment::Tool(name), root));
+        }
+    }
+
+    let active = std::env::var_os(EnvVars::VIRTUAL_ENV)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+    let discovered = CWD
+        .ancestors()
+        .map(|dir| dir.join(".venv"))
+        .find(|dir| dir.join("pyvenv.cfg").is_file());
+    let mut seen = FxHashSet::default();
+    for root in active.into_iter().chain(discovered) {
+        if seen.insert(root.simple_canonicalize().unwrap_or_else(|_| root.clone())) {
+            environments.push((DependentEnvironment::Virtualenv(root.clone()), root));
+        }
+    }
+
+    environments
+}
+
+/// Return the `home` directory recorded in the `pyvenv.cfg` of a virtual environment.
+pub(super) fn virtualenv_home(root: &Path) -> Option<PathBuf> {
+    let cfg = PyVenvConfiguration::parse(root.join("pyvenv.cfg")).ok()?;
+    cfg.home().map(Path::to_path_buf)
+}
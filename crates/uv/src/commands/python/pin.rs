@@ -8,6 +8,7 @@ use tracing::debug;
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
+use uv_python::build_pin::{PythonBuild, PythonBuildFile, PYTHON_BUILD_FILENAME};
 use uv_python::{
     EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
     VersionFileDiscoveryOptions, PYTHON_VERSION_FILENAME,
@@ -19,16 +20,21 @@ use crate::commands::{project::find_requires_python, ExitStatus};
 use crate::printer::Printer;
 
 /// Pin to a specific Python version.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub(crate) async fn pin(
     project_dir: &Path,
     request: Option<String>,
     resolved: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     no_project: bool,
+    global: bool,
+    build: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
-    let virtual_project = if no_project {
+    // The global pin is not validated against the project.
+    let virtual_project = if no_project || global {
         None
     } else {
         match VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await {
@@ -40,12 +46,45 @@ pub(crate) async fn pin(
         }
     };
 
-    let version_file =
-        PythonVersionFile::discover(project_dir, &VersionFileDiscoveryOptions::default()).await;
+    let global_path = if global {
+        let Some(path) = PythonVersionFile::global_path() else {
+            bail!("Failed to determine the user configuration directory for the global Python pin");
+        };
+        Some(path)
+    } else {
+        None
+    };
+
+    let version_file = if let Some(global_path) = &global_path {
+        PythonVersionFile::try_from_path(global_path.clone()).await
+    } else {
+        PythonVersionFile::discover(project_dir, &VersionFileDiscoveryOptions::default()).await
+    };
 
     let Some(request) = request else {
         // Display the current pinned Python version
         if let Some(file) = version_file? {
+            // Or pin its build
+            if build {
+                let Some(pin) = file.version() else {
+                    bail!("No pinned Python version found")
+                };
+                let python = PythonInstallation::find(
+                    pin,
+                    EnvironmentPreference::OnlySystem,
+                    python_preference,
+                    python_install_dir,
+                    cache,
+                )?;
+                // Pin the build next to the version file, unless it's the global version file.
+                let dir = if PythonVersionFile::global_path().as_deref() == Some(file.path()) {
+                    project_dir
+                } else {
+                    file.path().parent().unwrap_or(project_dir)
+                };
+                pin_build(dir, &python, printer).await?;
+                return Ok(ExitStatus::Success);
+            }
             for pin in file.versions() {
                 writeln!(printer.stdout(), "{}", pin.to_canonical_string())?;
                 if let Some(virtual_project) = &virtual_project {
@@ -53,6 +92,7 @@ pub(crate) async fn pin(
                         pin,
                         virtual_project,
                         python_preference,
+                        python_install_dir,
                         cache,
                     );
                 }
@@ -67,11 +107,13 @@ pub(crate) async fn pin(
         &request,
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_install_dir,
         cache,
     ) {
         Ok(python) => Some(python),
-        // If no matching Python version is found, don't fail unless `resolved` was requested
-        Err(uv_python::Error::MissingPython(err)) if !resolved => {
+        // If no matching Python version is found, don't fail unless `resolved` or `build` was
+        // requested
+        Err(uv_python::Error::MissingPython(err)) if !resolved && !build => {
             warn_user_once!("{err}");
             None
         }
@@ -115,6 +157,7 @@ pub(crate) async fn pin(
         // TODO(zanieb): Maybe avoid reparsing here?
         PythonRequest::parse(
             &python
+                .as_ref()
                 .unwrap()
                 .interpreter()
                 .sys_executable()
@@ -127,8 +170,10 @@ pub(crate) async fn pin(
 
     let existing = version_file.ok().flatten();
     // TODO(zanieb): Allow updating the discovered version file with an `--update` flag.
-    let new = PythonVersionFile::new(project_dir.join(PYTHON_VERSION_FILENAME))
-        .with_versions(vec![request]);
+    let new = PythonVersionFile::new(
+        global_path.unwrap_or_else(|| project_dir.join(PYTHON_VERSION_FILENAME)),
+    )
+    .with_versions(vec![request]);
 
     new.write().await?;
 
@@ -155,9 +200,54 @@ pub(crate) async fn pin(
         )?;
     }
 
+    if build {
+        // SAFETY: We exit early if Python is not found and build is `true`
+        pin_build(project_dir, python.as_ref().unwrap(), printer).await?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
+/// Pin the exact build of a Python installation in the `.python-build.json` file in the given
+/// directory, replacing any existing pin of the same installation key.
+async fn pin_build(dir: &Path, python: &PythonInstallation, printer: Printer) -> Result<()> {
+    let Some(build) = PythonBuild::read(python.interpreter().sys_base_prefix()) else {
+        let key = python.key();
+        bail!(
+            "The build of `{}` can't be pinned; only Python versions downloaded by uv can be pinned (run `{}` to download it)",
+            key.green(),
+            format!("uv python install --reinstall {key}").green()
+        );
+    };
+
+    let path = dir.join(PYTHON_BUILD_FILENAME);
+    let mut file = PythonBuildFile::try_from_path(path.clone())
+        .await?
+        .unwrap_or_else(|| PythonBuildFile::new(path));
+    let key = build.key().to_string();
+    let tag = build.build().map(ToString::to_string);
+    file.insert(build);
+    file.write().await?;
+
+    match tag {
+        Some(tag) => writeln!(
+            printer.stdout(),
+            "Pinned `{}` to `{}` (build `{}`)",
+            file.path().user_display().cyan(),
+            key.green(),
+            tag.green()
+        )?,
+        None => writeln!(
+            printer.stdout(),
+            "Pinned `{}` to `{}`",
+            file.path().user_display().cyan(),
+            key.green()
+        )?,
+    }
+
+    Ok(())
+}
+
 fn pep440_version_from_request(request: &PythonRequest) -> Option<uv_pep440::Version> {
     let version_request = match request {
         PythonRequest::Version(ref version)
@@ -185,6 +275,7 @@ fn warn_if_existing_pin_incompatible_with_project(
     pin: &PythonRequest,
     virtual_project: &VirtualProject,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     cache: &Cache,
 ) {
     // Check if the pinned version is compatible with the project.
@@ -209,6 +300,7 @@ fn warn_if_existing_pin_incompatible_with_project(
         pin,
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_install_dir,
         cache,
     ) {
         Ok(python) => {
@@ -0,0 +1,290 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
+use uv_python::PyVenvConfiguration;
+use uv_settings::FilesystemOptions;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+
+use crate::commands::python::{find_known_environments, virtualenv_home};
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Move the managed Python installations to another directory.
+///
+/// The new location is persisted as the `python-install-dir` setting in the user configuration,
+/// so that subsequent invocations use the moved installations.
+pub(crate) async fn relocate(
+    install_dir: Option<PathBuf>,
+    python_install_dir: Option<PathBuf>,
+    target: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Ensure that the new location can be persisted before moving anything.
+    if std::env::var_os(EnvVars::UV_PYTHON_INSTALL_DIR).is_some_and(|dir| !dir.is_empty()) {
+        anyhow::bail!(
+            "`{}` would take precedence over the moved Python versions; unset it and use `{}` to select the installations to move",
+            EnvVars::UV_PYTHON_INSTALL_DIR.green(),
+            "--install-dir".green()
+        );
+    }
+    let Some(config) = uv_settings::user_config_file() else {
+        anyhow::bail!("Failed to determine the user configuration directory");
+    };
+    if let Some(python_install_dir) = python_install_dir {
+        let user = FilesystemOptions::user()?;
+        if user
+            .as_ref()
+            .and_then(|user| user.globals.python_install_dir.as_ref())
+            != Some(&python_install_dir)
+        {
+            anyhow::bail!(
+                "`{}` is set in a project configuration file and would take precedence over the moved Python versions",
+                "python-install-dir".green()
+            );
+        }
+    }
+    let mut document = match fs_err::read_to_string(&config) {
+        Ok(content) => content
+            .parse::<toml_edit::DocumentMut>()
+            .with_context(|| format!("Failed to parse `{}`", config.user_display()))?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => toml_edit::DocumentMut::new(),
+        Err(err) => return Err(err.into()),
+    };
+
+    let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
+    let _lock = installations.lock().await?;
+
+    let source = std::path::absolute(installations.root())?;
+    let target = std::path::absolute(target)?;
+    if target.starts_with(&source)
+        || target.simple_canonicalize().is_ok_and(|target| {
+            source
+                .simple_canonicalize()
+                .is_ok_and(|source| target == source)
+        })
+    {
+        anyhow::bail!(
+            "The Python installations can't be moved into the Python installation directory (`{}`)",
+            source.user_display()
+        );
+    }
+
+    let existing = installations.find_all()?.collect::<Vec<_>>();
+    if existing.is_empty() {
+        writeln!(printer.stderr(), "No Python versions to move")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let targets = ManagedPythonInstallations::from_settings(Some(target.clone()))?.init()?;
+    let _target_lock = targets.lock().await?;
+
+    // Environments may refer to the installations through their canonical paths.
+    let sources = [Some(source.clone()), source.simple_canonicalize().ok()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut moved = Vec::with_capacity(existing.len());
+    for installation in existing {
+        if installation.is_source_build() {
+            warn_user!(
+                "`{}` was built from source and can't be moved (run `{}` to reinstall it)",
+                installation.key().green(),
+                format!(
+                    "uv python install --build-from-source {}",
+                    installation.version()
+                )
+                .green()
+            );
+            continue;
+        }
+        if targets.root().join(installation.key().to_string()).exists() {
+            warn_user!(
+                "`{}` already exists in `{}`; skipping",
+                installation.key().green(),
+                target.user_display().cyan()
+            );
+            continue;
+        }
+
+        debug!(
+            "Moving `{}` to `{}`",
+            installation.key(),
+            target.user_display()
+        );
+        let relocated = installation.relocate(targets.root())?;
+        if let Err(err) = relocated.ensure_dylib_patched() {
+            err.warn_user(&relocated);
+        }
+        #[cfg(windows)]
+        {
+            // Point the registry entry at the moved installation.
+            let mut errors = Vec::new();
+            uv_python::windows_registry::create_registry_entry(&relocated, &mut errors)?;
+            for (key, err) in errors {
+                warn_user!("Failed to update the registry entry for `{key}`: {err}");
+            }
+        }
+        installation.remove_minor_version_link()?;
+        moved.push(relocated);
+    }
+    targets.ensure_minor_version_links()?;
+
+    if moved.is_empty() {
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Point the known environments and the Python executables at the moved installations.
+    let mut environments = 0;
+    for (_, root) in find_known_environments() {
+        if relocate_environment(&root, &sources, &target)? {
+            debug!("Updated environment at `{}`", root.user_display());
+            environments += 1;
+        }
+    }
+    if cfg!(unix) {
+        if let Ok(bin) = python_executable_dir() {
+            for path in uv_fs::entries(&bin)? {
+                relocate_link(&path, &sources, &target)?;
+            }
+        }
+    }
+
+    // Clean up the Python installation directory, if it's empty.
+    if uv_fs::directories(&source)?.all(|path| uv_fs::is_temporary(&path)) {
+        fs_err::tokio::remove_dir_all(&source).await?;
+    }
+
+    if let [installation] = moved.as_slice() {
+        // Ex) "Moved Python 3.12.9 to `/data/uv/python` in 12ms"
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Moved {} to {} {}",
+                format!("Python {}", installation.version()).bold(),
+                format!("`{}`", target.user_display()).cyan(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    } else {
+        // Ex) "Moved 2 versions to `/data/uv/python` in 12ms"
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Moved {} to {} {}",
+                format!("{} versions", moved.len()).bold(),
+                format!("`{}`", target.user_display()).cyan(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    }
+    let s = if environments == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Relinked {} to the moved versions",
+            format!("{environments} environment{s}").bold()
+        )
+        .dimmed()
+    )?;
+
+    // Only the known environments can be relinked; any others still refer to the old location.
+    warn_user!(
+        "Only the environments of installed tools, the active virtual environment, and the project `.venv` were relinked; other virtual environments created from the moved versions must be re-created with `{}`",
+        "uv venv".green()
+    );
+
+    // Persist the new location.
+    document["python-install-dir"] = toml_edit::value(target.simplified_display().to_string());
+    if let Some(parent) = config.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    fs_err::write(&config, document.to_string())
+        .with_context(|| format!("Failed to update `{}`", config.user_display()))?;
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Updated {} in {}",
+            "`python-install-dir`".bold(),
+            format!("`{}`", config.user_display()).cyan()
+        )
+        .dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Update a virtual environment that was created from a Python installation in one of the
+/// `sources` to use the installation in `target`.
+///
+/// Returns `true` if the environment was updated.
+fn relocate_environment(root: &Path, sources: &[PathBuf], target: &Path) -> Result<bool> {
+    let Some(home) = virtualenv_home(root) else {
+        return Ok(false);
+    };
+    let Some(relocated) = relocate_path(&home, sources, target) else {
+        return Ok(false);
+    };
+
+    let cfg = root.join("pyvenv.cfg");
+    let content = fs_err::read_to_string(&cfg)?;
+    fs_err::write(
+        &cfg,
+        PyVenvConfiguration::set(
+            &content,
+            "home",
+            &relocated.simplified_display().to_string(),
+        ),
+    )?;
+
+    // On Unix, the Python executables of the environment link to the base interpreter.
+    if cfg!(unix) {
+        for path in uv_fs::entries(root.join("bin"))? {
+            relocate_link(&path, sources, target)?;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Point a link to a path in one of the `sources` at the corresponding path in `target`, if the
+/// path is a link and the corresponding path exists.
+fn relocate_link(path: &Path, sources: &[PathBuf], target: &Path) -> Result<()> {
+    if !path.is_symlink() {
+        return Ok(());
+    }
+    let Some(relocated) = relocate_path(&fs_err::read_link(path)?, sources, target) else {
+        return Ok(());
+    };
+    debug!(
+        "Updating link `{}` to `{}`",
+        path.user_display(),
+        relocated.user_display()
+    );
+    uv_fs::replace_symlink(&relocated, path)?;
+    Ok(())
+}
+
+/// Return the path in `target` corresponding to a path in one of the `sources`, if it exists.
+fn relocate_path(path: &Path, sources: &[PathBuf], target: &Path) -> Option<PathBuf> {
+    sources
+        .iter()
+        .find_map(|source| path.strip_prefix(source).ok())
+        .map(|relative| target.join(relative))
+        .filter(|relocated| relocated.exists())
+}
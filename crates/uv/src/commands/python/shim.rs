@@ -0,0 +1,127 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_python::managed::python_executable_dir;
+
+use crate::commands::python::install::warn_if_not_on_path;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The names of the shims.
+const SHIMS: &[&str] = &["python", "python3"];
+
+/// A line included in every shim, to distinguish shims from other Python executables.
+const SHIM_MARKER: &str = "# uv-python-shim";
+
+/// Install (or uninstall) the `python` and `python3` shims.
+pub(crate) fn shim(force: bool, uninstall: bool, printer: Printer) -> Result<ExitStatus> {
+    if cfg!(windows) {
+        anyhow::bail!("Python shims are not supported on Windows");
+    }
+
+    let bin = python_executable_dir()?;
+
+    if uninstall {
+        let mut removed = 0;
+        for name in SHIMS {
+            let path = bin.join(name);
+            if !is_shim(&path) {
+                debug!("No shim found at `{}`", path.user_display());
+                continue;
+            }
+            fs_err::remove_file(&path)?;
+            removed += 1;
+        }
+        if removed == 0 {
+            writeln!(printer.stderr(), "No Python shims installed")?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Removed the Python shims from {}",
+                format!("`{}`", bin.simplified_display()).cyan()
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Refuse to replace other Python executables, e.g., from `uv python install --default`.
+    if !force {
+        if let Some(path) = SHIMS
+            .iter()
+            .map(|name| bin.join(name))
+            .find(|path| path.symlink_metadata().is_ok() && !is_shim(path))
+        {
+            anyhow::bail!(
+                "Executable already exists at `{}` (use `{}` to replace it)",
+                path.simplified_display(),
+                "--force".green()
+            );
+        }
+    }
+
+    let uv = std::env::current_exe().context("Failed to determine the path to uv")?;
+    let contents = shim_contents(&uv);
+
+    fs_err::create_dir_all(&bin)?;
+    for name in SHIMS {
+        let path = bin.join(name);
+        debug!("Installing shim at `{}`", path.user_display());
+        // Remove the existing executable first, rather than writing through a link.
+        if path.symlink_metadata().is_ok() {
+            fs_err::remove_file(&path)?;
+        }
+        fs_err::write(&path, &contents)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Installed Python shims for {} in {}",
+        SHIMS
+            .iter()
+            .map(|name| format!("`{name}`").bold().to_string())
+            .collect::<Vec<_>>()
+            .join(" and "),
+        format!("`{}`", bin.simplified_display()).cyan()
+    )?;
+    warn_if_not_on_path(&bin);
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the contents of a shim that runs the managed Python interpreter that `uv python find`
+/// reports for the working directory.
+///
+/// System interpreters are excluded, such that the search doesn't find the shim itself.
+fn shim_contents(uv: &Path) -> String {
+    let uv = uv.simplified_display().to_string().replace('\'', r"'\''");
+    format!(
+        "#!/bin/sh\n\
+         {SHIM_MARKER}\n\
+         # Runs the managed Python interpreter for the working directory; see `uv python shim`.\n\
+         python=\"$('{uv}' python find --python-preference only-managed)\" || exit $?\n\
+         exec \"$python\" \"$@\"\n"
+    )
+}
+
+/// Returns `true` if the file at the given path is a shim.
+fn is_shim(path: &Path) -> bool {
+    if path.is_symlink() {
+        return false;
+    }
+    fs_err::read_to_string(path).is_ok_and(|contents| {
+        contents
+            .lines()
+            .nth(1)
+            .is_some_and(|line| line == SHIM_MARKER)
+    })
+}
@@ -13,11 +13,16 @@ use tracing::{debug, warn};
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
-use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
+use uv_python::managed::{
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+};
 use uv_python::{PythonInstallationKey, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::python::install::format_executables;
-use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::python::{
+    find_known_environments, virtualenv_home, ChangeEvent, ChangeEventKind, DependentEnvironment,
+};
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 
@@ -26,6 +31,7 @@ pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    registry: Option<bool>,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
@@ -33,8 +39,11 @@ pub(crate) async fn uninstall(
 
     let _lock = installations.lock().await?;
 
+    // As with `uv python install`, the registry is only updated on request or in preview mode.
+    let registry = registry.unwrap_or(preview.is_enabled());
+
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer, preview).await?;
+    do_uninstall(&installations, targets, all, registry, printer).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -62,8 +71,8 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    registry: bool,
     printer: Printer,
-    preview: PreviewMode,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
@@ -110,7 +119,7 @@ async fn do_uninstall(
         }
         if !found {
             // Clear any remnants in the registry
-            if preview.is_enabled() {
+            if registry {
                 #[cfg(windows)]
                 {
                     uv_python::windows_registry::remove_orphan_registry_entries(
@@ -140,6 +149,10 @@ async fn do_uninstall(
         return Ok(ExitStatus::Failure);
     }
 
+    // Find the environments that were created from the installations, to warn about those that
+    // are broken by the uninstallation.
+    let dependents = find_dependent_environments(&matching_installations);
+
     // Find and remove all relevant Python executables
     let mut uninstalled_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>> =
         FxHashMap::default();
@@ -208,8 +221,15 @@ async fn do_uninstall(
         }
     }
 
+    // Point the minor version links of the uninstalled versions at the latest remaining patch
+    // version, or remove them if no patch version remains.
+    installations.ensure_minor_version_links()?;
+    for installation in &matching_installations {
+        installation.remove_minor_version_link()?;
+    }
+
     #[cfg(windows)]
-    if preview.is_enabled() {
+    if registry {
         uv_python::windows_registry::remove_registry_entry(
             &matching_installations,
             all,
@@ -219,7 +239,8 @@ async fn do_uninstall(
     }
 
     // Report on any uninstalled installations.
-    if !uninstalled.is_empty() {
+    let any_uninstalled = !uninstalled.is_empty();
+    if any_uninstalled {
         if let [uninstalled] = uninstalled.as_slice() {
             // Ex) "Uninstalled Python 3.9.7 in 1.68s"
             writeln!(
@@ -271,6 +292,34 @@ async fn do_uninstall(
         }
     }
 
+    // Warn about any environments whose base interpreter no longer exists. Environments created
+    // through a minor version link keep working if another patch version remains.
+    for (environment, home) in dependents {
+        if home.is_dir() {
+            continue;
+        }
+        match environment {
+            DependentEnvironment::Tool(name) => {
+                warn_user!(
+                    "The environment for tool `{name}` was created with an uninstalled Python version (run `{}` to reinstall)",
+                    format!("uv tool install {name} --reinstall").green()
+                );
+            }
+            DependentEnvironment::Virtualenv(path) => {
+                warn_user!(
+                    "The virtual environment at `{}` was created with an uninstalled Python version (run `{}` to recreate)",
+                    path.user_display().cyan(),
+                    format!("uv venv {}", path.user_display()).green()
+                );
+            }
+        }
+    }
+    if any_uninstalled {
+        warn_user!(
+            "Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work"
+        );
+    }
+
     if !errors.is_empty() {
         for (key, err) in errors {
             writeln!(
@@ -285,3 +334,19 @@ async fn do_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Find the known environments that were created from any of the given installations, along
+/// with the `home` directory of their base interpreter.
+fn find_dependent_environments(
+    installations: &BTreeSet<ManagedPythonInstallation>,
+) -> Vec<(DependentEnvironment, PathBuf)> {
+    find_known_environments()
+        .into_iter()
+        .filter(|(_, root)| {
+            installations
+                .iter()
+                .any(|installation| installation.is_base_of_virtualenv(root))
+        })
+        .filter_map(|(environment, root)| Some((environment, virtualenv_home(&root)?)))
+        .collect()
+}
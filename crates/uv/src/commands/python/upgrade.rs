@@ -0,0 +1,182 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_python::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
+use uv_python::policy::PythonPolicy;
+use uv_python::{PythonDownloads, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::python::install::install;
+use crate::commands::python::{find_known_environments, virtualenv_home, DependentEnvironment};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, PythonInstallSettings};
+
+/// Upgrade installed Python versions to the latest patch release of their minor version.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    install_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    python_install_mirror: Option<String>,
+    pypy_install_mirror: Option<String>,
+    python_downloads_json_url: Option<String>,
+    registry: Option<bool>,
+    network_settings: NetworkSettings,
+    python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
+    no_config: bool,
+    cache: &Cache,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
+    let requests = targets
+        .iter()
+        .map(|target| PythonRequest::parse(target))
+        .collect::<Vec<_>>();
+
+    // Find the latest installed patch version of each requested minor version. Pre-release
+    // versions are upgraded with `uv python install` instead.
+    let installed = installations
+        .find_all()?
+        .filter(|installation| installation.version().pre().is_none())
+        .filter(|installation| {
+            requests.is_empty()
+                || requests
+                    .iter()
+                    .any(|request| installation.satisfies(request))
+        })
+        .unique_by(ManagedPythonInstallation::minor_version_link)
+        .collect::<Vec<_>>();
+
+    for request in &requests {
+        if !installed
+            .iter()
+            .any(|installation| installation.satisfies(request))
+        {
+            writeln!(
+                printer.stderr(),
+                "No Python installations found matching {}",
+                request.cyan()
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
+    if installed.is_empty() {
+        writeln!(printer.stderr(), "No Python installations found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Find the latest patch release of each minor version.
+    let mut upgrades = Vec::new();
+    let mut outdated = Vec::new();
+    for installation in &installed {
+        if let Some(download) =
+            installation.find_upgrade(python_policy, python_downloads_json_url.as_deref())?
+        {
+            debug!(
+                "Found upgrade `{}` for `{}`",
+                download.key().green(),
+                installation.key().green()
+            );
+            upgrades.push(download.key().to_string());
+            outdated.push(installation);
+        } else {
+            debug!(
+                "Found latest patch release `{}`",
+                installation.key().green()
+            );
+        }
+    }
+
+    let status = if upgrades.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "All Python versions are already on the latest patch release"
+        )?;
+        ExitStatus::Success
+    } else {
+        let settings = PythonInstallSettings {
+            install_dir,
+            targets: upgrades,
+            reinstall: false,
+            force: false,
+            python_install_mirror,
+            pypy_install_mirror,
+            python_downloads_json_url,
+            default: false,
+            variant: Vec::new(),
+            no_pip: false,
+            arch: None,
+            from_dir: None,
+            build_from_source: false,
+            adopt: false,
+            registry,
+        };
+        install(
+            project_dir,
+            settings,
+            network_settings,
+            python_downloads,
+            python_policy,
+            no_config,
+            cache,
+            preview,
+            printer,
+        )
+        .await?
+    };
+
+    // Point the minor version links at the latest installed patch versions, including those of
+    // installations that predate the links.
+    let _lock = installations.lock().await?;
+    installations.ensure_minor_version_links()?;
+
+    // Environments created from a specific patch version, rather than through its minor version
+    // link, keep using the previous patch version.
+    if !outdated.is_empty() {
+        for (environment, root) in find_known_environments() {
+            let Some(home) = virtualenv_home(&root) else {
+                continue;
+            };
+            let Some(installation) = outdated
+                .iter()
+                .find(|installation| home.starts_with(installation.path()))
+            else {
+                continue;
+            };
+            match environment {
+                DependentEnvironment::Tool(name) => {
+                    warn_user!(
+                        "The environment for tool `{name}` still uses Python {} (run `{}` to upgrade it)",
+                        installation.version(),
+                        format!("uv tool install {name} --reinstall").green()
+                    );
+                }
+                DependentEnvironment::Virtualenv(path) => {
+                    warn_user!(
+                        "The virtual environment at `{}` still uses Python {} (run `{}` to recreate it)",
+                        path.user_display().cyan(),
+                        installation.version(),
+                        format!("uv venv {}", path.user_display()).green()
+                    );
+                }
+            }
+        }
+        warn_user!(
+            "Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from a specific patch version, rather than through a minor version link, still use the previous version"
+        );
+    }
+
+    Ok(status)
+}
@@ -3,6 +3,7 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::collections::Bound;
 use std::fmt::Write;
+use std::path::Path;
 use std::{collections::BTreeSet, ffi::OsString};
 use tracing::{debug, warn};
 use uv_cache::Cache;
@@ -15,6 +16,7 @@ use uv_installer::SitePackages;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, VersionRequest,
@@ -78,7 +80,9 @@ pub(crate) async fn refine_interpreter(
     reporter: &PythonDownloadReporter,
     install_mirrors: &PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     cache: &Cache,
 ) -> anyhow::Result<Option<Interpreter>, ProjectError> {
     let pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(ref no_solution_err)) =
@@ -144,12 +148,15 @@ pub(crate) async fn refine_interpreter(
         Some(&python_request),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         client_builder,
         cache,
         Some(reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
     .into_interpreter();
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{bail, Result};
@@ -14,6 +15,7 @@ use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
 };
@@ -52,7 +54,9 @@ pub(crate) async fn install(
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: Cache,
@@ -62,7 +66,8 @@ pub(crate) async fn install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -74,12 +79,15 @@ pub(crate) async fn install(
         python_request.as_ref(),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         &client_builder,
         &cache,
         Some(&reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
     .into_interpreter();
@@ -90,7 +98,8 @@ pub(crate) async fn install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     // Parse the input requirement.
     let request = ToolRequest::parse(&package, from.as_deref());
@@ -460,7 +469,9 @@ pub(crate) async fn install(
                         &reporter,
                         &install_mirrors,
                         python_preference,
+                        python_install_dir,
                         python_downloads,
+                        python_policy,
                         &cache,
                     )
                     .await
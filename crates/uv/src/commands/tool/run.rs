@@ -25,6 +25,7 @@ use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
+use uv_python::policy::PythonPolicy;
 use uv_python::VersionRequest;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
@@ -87,7 +88,9 @@ pub(crate) async fn run(
     invocation_source: ToolRunCommand,
     isolated: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: Cache,
@@ -176,7 +179,9 @@ pub(crate) async fn run(
         &network_settings,
         isolated,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         installer_metadata,
         concurrency,
         &cache,
@@ -509,7 +514,9 @@ async fn get_or_create_environment(
     network_settings: &NetworkSettings,
     isolated: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -519,7 +526,8 @@ async fn get_or_create_environment(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -560,12 +568,15 @@ async fn get_or_create_environment(
         python_request.as_ref(),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         &client_builder,
         cache,
         Some(&reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
     .into_interpreter();
@@ -863,7 +874,9 @@ async fn get_or_create_environment(
                     &reporter,
                     &install_mirrors,
                     python_preference,
+                    python_install_dir,
                     python_downloads,
+                    python_policy,
                     cache,
                 )
                 .await
@@ -3,6 +3,7 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::collections::BTreeMap;
 use std::fmt::Write;
+use std::path::Path;
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -11,6 +12,7 @@ use uv_configuration::{Concurrency, DryRun, PreviewMode};
 use uv_fs::CWD;
 use uv_normalize::PackageName;
 use uv_pypi_types::Requirement;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
     PythonRequest,
@@ -41,7 +43,9 @@ pub(crate) async fn upgrade(
     filesystem: ResolverInstallerOptions,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -81,7 +85,8 @@ pub(crate) async fn upgrade(
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     let python_request = python.as_deref().map(PythonRequest::parse);
 
@@ -91,12 +96,15 @@ pub(crate) async fn upgrade(
                 python_request.as_ref(),
                 EnvironmentPreference::OnlySystem,
                 python_preference,
+                python_install_dir,
                 python_downloads,
+                python_policy,
                 &client_builder,
                 cache,
                 Some(&reporter),
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
+                install_mirrors.python_downloads_json_url.as_deref(),
             )
             .await?
             .into_interpreter(),
@@ -21,6 +21,7 @@ use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::Requirement;
+use uv_python::policy::PythonPolicy;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
 };
@@ -47,7 +48,9 @@ pub(crate) async fn venv(
     python_request: Option<&str>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     link_mode: LinkMode,
     index_locations: &IndexLocations,
     index_strategy: IndexStrategy,
@@ -82,7 +85,9 @@ pub(crate) async fn venv(
         system_site_packages,
         seed,
         python_preference,
+        python_install_dir,
         python_downloads,
+        python_policy,
         allow_existing,
         exclude_newer,
         concurrency,
@@ -139,7 +144,9 @@ async fn venv_impl(
     system_site_packages: bool,
     seed: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     python_downloads: PythonDownloads,
+    python_policy: Option<&PythonPolicy>,
     allow_existing: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
@@ -190,7 +197,8 @@ async fn venv_impl(
     let client_builder = BaseClientBuilder::default()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .http_retries(network_settings.http_retries.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -213,12 +221,15 @@ async fn venv_impl(
             python_request.as_ref(),
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_install_dir,
             python_downloads,
+            python_policy,
             &client_builder,
             cache,
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await
         .into_diagnostic()?;
@@ -294,8 +305,11 @@ async fn venv_impl(
             .cache(cache.clone())
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
+            .index_tracking(network_settings.index_tracking)
+            .range_request_size(network_settings.range_request_size)
             .keyring(keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .http_retries(network_settings.http_retries.clone())
             .markers(interpreter.markers())
             .platform(interpreter.platform())
             .build();
@@ -19,8 +19,8 @@ use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand,
 };
 use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
@@ -241,6 +241,26 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             },
             _ => None,
         }
+    } else if let Commands::Python(PythonNamespace {
+        command:
+            PythonCommand::Find(uv_cli::PythonFindArgs {
+                script: Some(script),
+                ..
+            }),
+    }) = &*cli.command
+    {
+        // For `uv python find --script`, the PEP 723 tag is optional.
+        match Pep723Script::read(&script).await {
+            Ok(Some(script)) => Some(Pep723Item::Script(script)),
+            Ok(None) => None,
+            Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!(
+                    "Failed to read `{}` (not found)",
+                    script.user_display().cyan()
+                )
+            }
+            Err(err) => return Err(err.into()),
+        }
     } else {
         None
     };
@@ -256,7 +276,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .combine(filesystem);
 
     // Resolve the global settings.
-    let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+    let mut globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+
+    // Enforce the system Python policy, which takes precedence over the user's settings.
+    if let Some(policy) = uv_settings::system_python_policy()? {
+        globals.python_downloads = policy.restrict_python_downloads(globals.python_downloads);
+        globals.python_policy = Some(policy);
+    }
 
     // Resolve the cache settings.
     let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
@@ -325,6 +351,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Don't initialize the rayon threadpool yet, this is too costly when we're doing a noop sync.
     uv_configuration::RAYON_PARALLELISM.store(globals.concurrency.installs, Ordering::SeqCst);
 
+    // Apply any network limits to every client created by this process.
+    globals.network_settings.limits.init();
+    globals.network_settings.connection.init();
+    if let Some(trace_http) = globals.network_settings.trace_http.clone() {
+        uv_client::init_http_trace(trace_http);
+    }
+
     debug!("uv {}", uv_cli::version::version());
 
     // Write out any resolved settings.
@@ -345,7 +378,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_remote(cache_settings.remote_url)
+        .with_base(cache_settings.base_dir)
+        .with_deduplication(cache_settings.deduplicate)
+        .with_discovery_cache(!cache_settings.no_discovery_cache);
+
+    if let Some(timeout) = cache_settings.lock_timeout {
+        uv_cache::set_lock_timeout(timeout);
+    }
+
+    // Retain a handle to the cache, as commands may take ownership of it.
+    let cache_handle = cache.clone();
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -405,6 +449,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.fork_strategy,
+                args.settings.minimal_changes,
+                args.settings.break_extra_cycles,
+                args.settings.prefetch_versions,
+                args.settings.budget,
+                args.settings.yanked,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -438,6 +487,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.concurrency,
                 globals.quiet,
                 cache,
@@ -501,6 +551,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python_platform,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.yanked,
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
@@ -508,6 +559,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.prefix,
                 args.settings.sources,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.concurrency,
                 cache,
                 args.dry_run,
@@ -593,16 +645,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python_platform,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.yanked,
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.user,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
                 printer,
                 globals.preview,
             )
@@ -634,10 +690,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.user,
                 cache,
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.dry_run,
+                args.autoremove,
                 printer,
             )
             .await
@@ -768,13 +826,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, &cache, printer)
+            commands::cache_clean(&args.package, &args.bucket, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(
+                args.ci,
+                &args.index,
+                args.max_size.or(cache_settings.max_size),
+                cache_settings.retention,
+                &cache,
+                printer,
+            )
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -782,6 +847,59 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats,
+        }) => commands::cache_stats(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_export(&args.from_lock, &args.output, &cache, printer).await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+            let cache = cache.init()?;
+            commands::cache_import(&args.archive, &cache, printer).await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Prefetch(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CachePrefetchSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::cache_prefetch(
+                &project_dir,
+                args.build,
+                args.python_version,
+                args.python_platform,
+                args.python,
+                args.install_mirrors,
+                args.resolver,
+                &globals.network_settings,
+                globals.python_preference,
+                globals.python_install_dir.as_deref(),
+                globals.python_downloads,
+                globals.python_policy.as_ref(),
+                globals.concurrency,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.dry_run, &cache, printer)
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -819,7 +937,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &globals.network_settings,
                 cli.top_level.no_config,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.concurrency,
                 &cache,
                 printer,
@@ -864,7 +984,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.install_mirrors,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 args.settings.link_mode,
                 &args.settings.index_locations,
                 args.settings.index_strategy,
@@ -1013,7 +1135,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 invocation_source,
                 args.isolated,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 cache,
@@ -1078,7 +1202,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings,
                 globals.network_settings,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 cache,
@@ -1123,7 +1249,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.filesystem,
                 globals.network_settings,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 &cache,
@@ -1172,10 +1300,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.all_versions,
                 args.all_platforms,
                 args.all_arches,
+                args.arch,
                 args.show_urls,
                 args.output_format,
+                args.python_downloads_json_url.as_deref(),
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 &cache,
                 printer,
             )
@@ -1188,18 +1320,45 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonInstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             commands::python_install(
+                &project_dir,
+                args,
+                globals.network_settings,
+                globals.python_downloads,
+                globals.python_policy.as_ref(),
+                cli.top_level.no_config,
+                &cache,
+                globals.preview,
+                printer,
+            )
+            .await
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Upgrade(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_upgrade(
                 &project_dir,
                 args.install_dir,
                 args.targets,
-                args.reinstall,
-                args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
+                args.python_downloads_json_url,
+                args.registry,
                 globals.network_settings,
-                args.default,
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 cli.top_level.no_config,
+                &cache,
                 globals.preview,
                 printer,
             )
@@ -1216,11 +1375,36 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_dir,
                 args.targets,
                 args.all,
+                args.registry,
                 printer,
                 globals.preview,
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Move(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonMoveSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_move(
+                args.install_dir,
+                args.python_install_dir,
+                &args.target,
+                printer,
+            )
+            .await
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Shim(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonShimSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_shim(args.force, args.uninstall, printer)
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
         }) => {
@@ -1230,14 +1414,31 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Initialize the cache.
             let cache = cache.init()?;
 
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv python find` does not support stdin"),
+                Pep723Item::Remote(..) => {
+                    unreachable!("`uv python find` does not support remote files")
+                }
+            });
+
             commands::python_find(
                 &project_dir,
                 args.request,
                 args.no_project,
+                args.script.as_deref(),
+                script,
                 cli.top_level.no_config,
                 args.system,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
+                globals.python_downloads,
+                globals.python_policy.as_ref(),
+                &args.install_mirrors,
+                &globals.network_settings,
                 &cache,
+                printer,
             )
             .await
         }
@@ -1255,7 +1456,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.request,
                 args.resolved,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 args.no_project,
+                args.global,
+                args.build,
                 &cache,
                 printer,
             )
@@ -1268,7 +1472,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonDirSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::python_dir(args.bin)?;
+            commands::python_dir(args.bin, globals.python_install_dir)?;
             Ok(ExitStatus::Success)
         }
         Commands::Publish(args) => {
@@ -1347,6 +1551,56 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the workspace configuration.
+            let settings = settings::AuthSettings::resolve(filesystem);
+            show_settings!(settings);
+
+            // Tokens are encoded in the same way as username/password.
+            let (username, password) = if let Some(token) = args.token {
+                (Some("__token__".to_string()), Some(token))
+            } else {
+                (args.username, args.password)
+            };
+
+            commands::auth_login(
+                &args.service,
+                username,
+                password,
+                &settings.index_locations,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the workspace configuration.
+            let settings = settings::AuthSettings::resolve(filesystem);
+            show_settings!(settings);
+
+            commands::auth_logout(
+                &args.service,
+                args.username.as_deref(),
+                &settings.index_locations,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the workspace configuration.
+            let settings = settings::AuthSettings::resolve(filesystem);
+            show_settings!(settings);
+
+            commands::auth_token(
+                &args.service,
+                args.username.as_deref(),
+                &settings.index_locations,
+                printer,
+            )
+        }
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1384,6 +1638,60 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .await
         .expect("tokio threadpool exited unexpectedly"),
     };
+
+    // Record the cache accesses of the command, for `uv cache stats`.
+    if let Err(err) = cache_handle.record_accesses() {
+        debug!("Failed to record cache accesses: {err}");
+    }
+
+    // Enforce the cache size limit, if any, once the command has completed.
+    if let Some(max_size) = cache_settings.max_size {
+        match cache_handle.evict_periodically(max_size.bytes()) {
+            Ok(Some(removal)) if removal.total_bytes > 0 => {
+                debug!(
+                    "Evicted {} bytes from the cache to enforce the size limit",
+                    removal.total_bytes
+                );
+            }
+            Ok(_) => {}
+            Err(err) => {
+                debug!("Failed to enforce the cache size limit: {err}");
+            }
+        }
+    }
+
+    // Remove unused cache entries according to the retention periods, if any.
+    if let Some(retention) = cache_settings.retention {
+        match cache_handle.expire_periodically(&retention.buckets()) {
+            Ok(Some(removal)) if removal.total_bytes > 0 => {
+                debug!(
+                    "Removed {} bytes of unused entries from the cache",
+                    removal.total_bytes
+                );
+            }
+            Ok(_) => {}
+            Err(err) => {
+                debug!("Failed to remove unused cache entries: {err}");
+            }
+        }
+    }
+
+    // Compress the source trees of unused source distributions, if enabled.
+    if cache_settings.compression {
+        match cache_handle.compress_sources_periodically().await {
+            Ok(Some(removal)) if removal.total_bytes > 0 => {
+                debug!(
+                    "Compressed {} bytes of unused source trees in the cache",
+                    removal.total_bytes
+                );
+            }
+            Ok(_) => {}
+            Err(err) => {
+                debug!("Failed to compress unused source trees: {err}");
+            }
+        }
+    }
+
     result
 }
 
@@ -1438,7 +1746,9 @@ async fn run_project(
                 args.no_workspace,
                 &globals.network_settings,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 no_config,
                 &cache,
                 printer,
@@ -1499,7 +1809,9 @@ async fn run_project(
                 args.settings,
                 globals.network_settings,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 &cache,
@@ -1536,6 +1848,8 @@ async fn run_project(
                 args.frozen,
                 args.dry_run,
                 args.active,
+                args.relocatable,
+                args.from_bundle,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -1546,7 +1860,9 @@ async fn run_project(
                 args.python,
                 args.install_mirrors,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 args.settings,
                 globals.network_settings,
                 script,
@@ -1588,13 +1904,16 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.dump_forks,
                 args.python,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
                 script,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -1661,7 +1980,9 @@ async fn run_project(
                 globals.network_settings,
                 script,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 no_config,
@@ -1705,7 +2026,9 @@ async fn run_project(
                 globals.network_settings,
                 script,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.installer_metadata,
                 globals.concurrency,
                 no_config,
@@ -1750,7 +2073,9 @@ async fn run_project(
                 &globals.network_settings,
                 script,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -1783,6 +2108,7 @@ async fn run_project(
                 args.hashes,
                 args.install_options,
                 args.output_file,
+                args.bundle,
                 args.extras,
                 args.dev,
                 args.editable,
@@ -1795,7 +2121,9 @@ async fn run_project(
                 args.settings,
                 globals.network_settings,
                 globals.python_preference,
+                globals.python_install_dir.as_deref(),
                 globals.python_downloads,
+                globals.python_policy.as_ref(),
                 globals.concurrency,
                 no_config,
                 globals.quiet,
@@ -1937,6 +2265,9 @@ where
         .join()
         .expect("Tokio executor failed, was there a panic?");
 
+    // Summarize any rate limiting that slowed down the command.
+    uv_client::warn_on_throttling();
+
     match result {
         Ok(code) => code.into(),
         Err(err) => {
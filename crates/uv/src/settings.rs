@@ -1,8 +1,9 @@
 use std::env::VarError;
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
 use url::Url;
 
@@ -10,32 +11,38 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, CachePrefetchArgs, ExportArgs, PublishArgs, PythonBuildVariant,
+    PythonDirArgs, ResolverInstallerArgs, ToolUpgradeArgs,
 };
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
     PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
-    ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    PythonListFormat, PythonMoveArgs, PythonPinArgs, PythonShimArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs,
+    ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+};
+use uv_client::{
+    ConnectionOptions, Connectivity, HttpRetryPolicy, NetworkLimits, DEFAULT_RANGE_REQUEST_SIZE,
 };
-use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall,
-    RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
-    VersionControlSystem,
+    BuildOptions, CacheRetention, CacheSize, Concurrency, ConfigSettings, DependencyGroups, DryRun,
+    EditableMode, ExportFormat, ExtrasSpecification, HashCheckingMode, HttpRetryOptions,
+    IndexStrategy, IndexTracking, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    PreviewMode, ProjectBuildBackend, Reinstall, RequiredVersion, SourceStrategy, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep508::{ExtraName, RequirementOrigin};
 use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_python::platform::Arch;
+use uv_python::policy::PythonPolicy;
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode,
+    AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionBudget,
+    ResolutionMode, SupportedPython, YankedPolicy,
 };
 use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, PublishOptions, PythonInstallMirrors,
@@ -65,6 +72,10 @@ pub(crate) struct GlobalSettings {
     pub(crate) preview: PreviewMode,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
+    /// The system-level policy for Python downloads, if any, which is read separately from the
+    /// settings (see [`uv_settings::system_python_policy`]).
+    pub(crate) python_policy: Option<PythonPolicy>,
+    pub(crate) python_install_dir: Option<PathBuf>,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
 }
@@ -129,6 +140,13 @@ impl GlobalSettings {
                 .combine(env(env::UV_PYTHON_DOWNLOADS))
                 .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
                 .unwrap_or_default(),
+            python_policy: None,
+            python_install_dir: std::env::var_os(EnvVars::UV_PYTHON_INSTALL_DIR)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| {
+                    workspace.and_then(|workspace| workspace.globals.python_install_dir.clone())
+                }),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
@@ -143,6 +161,12 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) http_retries: HttpRetryPolicy,
+    pub(crate) limits: NetworkLimits,
+    pub(crate) connection: ConnectionOptions,
+    pub(crate) index_tracking: IndexTracking,
+    pub(crate) range_request_size: NonZeroU64,
+    pub(crate) trace_http: Option<PathBuf>,
 }
 
 impl NetworkSettings {
@@ -175,10 +199,56 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let http_retries = HttpRetryPolicy::from_options(
+            Some(HttpRetryOptions {
+                retries: env(env::HTTP_RETRIES),
+                backoff_min: env(env::HTTP_RETRY_BACKOFF_MIN),
+                backoff_max: env(env::HTTP_RETRY_BACKOFF_MAX),
+                backoff_base: env(env::HTTP_RETRY_BACKOFF_BASE),
+                ..HttpRetryOptions::default()
+            })
+            .combine(workspace.and_then(|workspace| workspace.globals.http_retries.clone()))
+            .as_ref(),
+        );
+        let limits = NetworkLimits {
+            concurrent_downloads_per_host: env(env::CONCURRENT_DOWNLOADS_PER_HOST).combine(
+                workspace.and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+            ),
+            bandwidth: env(env::BANDWIDTH_LIMIT)
+                .combine(workspace.and_then(|workspace| workspace.globals.bandwidth_limit)),
+            connect_timeout: env(env::HTTP_CONNECT_TIMEOUT)
+                .combine(workspace.and_then(|workspace| workspace.globals.http_connect_timeout))
+                .map(Duration::from_secs),
+            read_timeout: workspace
+                .and_then(|workspace| workspace.globals.http_read_timeout)
+                .map(Duration::from_secs),
+        };
+        let connection = ConnectionOptions {
+            http2: workspace.and_then(|workspace| workspace.globals.http2),
+            pool_size: workspace.and_then(|workspace| workspace.globals.http_pool_size),
+            pool_idle_timeout: workspace
+                .and_then(|workspace| workspace.globals.http_pool_idle_timeout)
+                .map(Duration::from_secs),
+            address_family: workspace
+                .and_then(|workspace| workspace.globals.http_address_family)
+                .unwrap_or_default(),
+        };
+        let index_tracking = env(env::INDEX_TRACKING)
+            .combine(workspace.and_then(|workspace| workspace.globals.index_tracking))
+            .unwrap_or_default();
+        let range_request_size = env(env::RANGE_REQUEST_SIZE)
+            .combine(workspace.and_then(|workspace| workspace.globals.range_request_size))
+            .unwrap_or(DEFAULT_RANGE_REQUEST_SIZE);
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
+            http_retries,
+            limits,
+            connection,
+            index_tracking,
+            range_request_size,
+            trace_http: args.trace_http.clone(),
         }
     }
 }
@@ -189,6 +259,14 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) max_size: Option<CacheSize>,
+    pub(crate) retention: Option<CacheRetention>,
+    pub(crate) lock_timeout: Option<Duration>,
+    pub(crate) no_discovery_cache: bool,
+    pub(crate) remote_url: Option<Url>,
+    pub(crate) base_dir: Option<PathBuf>,
+    pub(crate) compression: bool,
+    pub(crate) deduplicate: bool,
 }
 
 impl CacheSettings {
@@ -199,9 +277,31 @@ impl CacheSettings {
                 || workspace
                     .and_then(|workspace| workspace.globals.no_cache)
                     .unwrap_or(false),
-            cache_dir: args
-                .cache_dir
-                .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_dir: args.cache_dir.or_else(|| {
+                let cache_dir = workspace?.globals.cache_dir.as_ref()?.select();
+                if cache_dir.is_none() {
+                    warn_user_once!(
+                        "None of the paths in `cache-dir` are writable; using the default cache directory"
+                    );
+                }
+                cache_dir
+            }),
+            max_size: env(env::CACHE_MAX_SIZE)
+                .combine(workspace.and_then(|workspace| workspace.globals.cache_max_size)),
+            retention: workspace.and_then(|workspace| workspace.globals.cache_retention),
+            lock_timeout: args.cache_lock_timeout.map(Duration::from_secs),
+            no_discovery_cache: args.no_discovery_cache,
+            remote_url: env(env::REMOTE_CACHE_URL).combine(
+                workspace.and_then(|workspace| workspace.globals.remote_cache_url.clone()),
+            ),
+            base_dir: env(env::BASE_CACHE_DIR)
+                .combine(workspace.and_then(|workspace| workspace.globals.base_cache_dir.clone())),
+            compression: env(env::CACHE_COMPRESSION)
+                .combine(workspace.and_then(|workspace| workspace.globals.cache_compression))
+                .unwrap_or(false),
+            deduplicate: env(env::CACHE_DEDUPLICATE)
+                .combine(workspace.and_then(|workspace| workspace.globals.cache_deduplicate))
+                .unwrap_or(false),
         }
     }
 }
@@ -665,6 +765,10 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            minimal_changes,
+            break_extra_cycles,
+            max_resolver_iterations,
+            resolver_timeout,
             config_setting,
             no_build_isolation,
             no_build_isolation_package,
@@ -699,6 +803,10 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            minimal_changes,
+            break_extra_cycles,
+            max_resolver_iterations,
+            resolver_timeout,
             config_setting,
             no_build_isolation,
             no_build_isolation_package,
@@ -800,6 +908,8 @@ pub(crate) enum PythonListKinds {
     Downloads,
     /// Only list installed versions.
     Installed,
+    /// Only list installed versions managed by uv.
+    Managed,
 }
 
 /// The resolved settings to use for a `tool run` invocation.
@@ -809,20 +919,31 @@ pub(crate) struct PythonListSettings {
     pub(crate) kinds: PythonListKinds,
     pub(crate) all_platforms: bool,
     pub(crate) all_arches: bool,
+    pub(crate) arch: Option<Arch>,
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
     pub(crate) output_format: PythonListFormat,
+    pub(crate) python_downloads_json_url: Option<String>,
 }
 
 impl PythonListSettings {
     /// Resolve the [`PythonListSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: PythonListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: PythonListArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonInstallMirrors {
+            python_downloads_json_url,
+            ..
+        } = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
         let PythonListArgs {
             all_versions,
             all_platforms,
             all_arches,
+            arch,
             only_installed,
+            only_managed,
             only_downloads,
             show_urls,
             output_format,
@@ -830,6 +951,8 @@ impl PythonListSettings {
 
         let kinds = if only_installed {
             PythonListKinds::Installed
+        } else if only_managed {
+            PythonListKinds::Managed
         } else if only_downloads {
             PythonListKinds::Downloads
         } else {
@@ -840,9 +963,11 @@ impl PythonListSettings {
             kinds,
             all_platforms,
             all_arches,
+            arch,
             all_versions,
             show_urls,
             output_format,
+            python_downloads_json_url,
         }
     }
 }
@@ -874,7 +999,15 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) force: bool,
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
+    pub(crate) variant: Vec<PythonBuildVariant>,
+    pub(crate) no_pip: bool,
+    pub(crate) arch: Option<Arch>,
+    pub(crate) from_dir: Option<PathBuf>,
+    pub(crate) build_from_source: bool,
+    pub(crate) adopt: bool,
+    pub(crate) registry: Option<bool>,
 }
 
 impl PythonInstallSettings {
@@ -882,12 +1015,20 @@ impl PythonInstallSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonInstallArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror) = match options {
+        let python_install_dir = options
+            .as_ref()
+            .and_then(|options| options.globals.python_install_dir.clone());
+        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
             Some(options) => (
                 options.install_mirrors.python_install_mirror,
                 options.install_mirrors.pypy_install_mirror,
+                options.install_mirrors.python_downloads_json_url,
+            ),
+            None => (
+                None,
+                None,
+                PythonInstallMirrors::default().python_downloads_json_url,
             ),
-            None => (None, None),
         };
         let python_mirror = args.mirror.or(python_mirror);
         let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
@@ -900,16 +1041,78 @@ impl PythonInstallSettings {
             mirror: _,
             pypy_mirror: _,
             default,
+            variant,
+            no_pip,
+            arch,
+            from_dir,
+            build_from_source,
+            adopt,
+            registry,
+            no_registry,
         } = args;
 
         Self {
-            install_dir,
+            install_dir: install_dir.or(python_install_dir),
             targets,
             reinstall,
             force,
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
+            python_downloads_json_url,
             default,
+            variant,
+            no_pip,
+            arch,
+            from_dir,
+            build_from_source,
+            adopt,
+            registry: flag(registry, no_registry),
+        }
+    }
+}
+
+/// The resolved settings to use for a `python upgrade` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonUpgradeSettings {
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) targets: Vec<String>,
+    pub(crate) python_install_mirror: Option<String>,
+    pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) registry: Option<bool>,
+}
+
+impl PythonUpgradeSettings {
+    /// Resolve the [`PythonUpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonUpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let python_install_dir = filesystem
+            .as_ref()
+            .and_then(|fs| fs.globals.python_install_dir.clone());
+        let PythonInstallMirrors {
+            python_install_mirror,
+            pypy_install_mirror,
+            python_downloads_json_url,
+        } = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        let PythonUpgradeArgs {
+            install_dir,
+            targets,
+            mirror,
+            pypy_mirror,
+            registry,
+            no_registry,
+        } = args;
+
+        Self {
+            install_dir: install_dir.or(python_install_dir),
+            targets,
+            python_install_mirror: mirror.or(python_install_mirror),
+            pypy_install_mirror: pypy_mirror.or(pypy_install_mirror),
+            python_downloads_json_url,
+            registry: flag(registry, no_registry),
         }
     }
 }
@@ -921,6 +1124,7 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) registry: Option<bool>,
 }
 
 impl PythonUninstallSettings {
@@ -928,46 +1132,103 @@ impl PythonUninstallSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(
         args: PythonUninstallArgs,
-        _filesystem: Option<FilesystemOptions>,
+        filesystem: Option<FilesystemOptions>,
     ) -> Self {
         let PythonUninstallArgs {
             install_dir,
             targets,
             all,
+            registry,
+            no_registry,
         } = args;
 
         Self {
-            install_dir,
+            install_dir: install_dir
+                .or_else(|| filesystem.and_then(|fs| fs.globals.python_install_dir.clone())),
             targets,
             all,
+            registry: flag(registry, no_registry),
+        }
+    }
+}
+
+/// The resolved settings to use for a `python move` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonMoveSettings {
+    pub(crate) target: PathBuf,
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) python_install_dir: Option<PathBuf>,
+}
+
+impl PythonMoveSettings {
+    /// Resolve the [`PythonMoveSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonMoveArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonMoveArgs {
+            target,
+            install_dir,
+        } = args;
+
+        let python_install_dir = filesystem.and_then(|fs| fs.globals.python_install_dir.clone());
+
+        Self {
+            target,
+            install_dir: install_dir.or_else(|| python_install_dir.clone()),
+            python_install_dir,
         }
     }
 }
 
+/// The resolved settings to use for a `python shim` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonShimSettings {
+    pub(crate) force: bool,
+    pub(crate) uninstall: bool,
+}
+
+impl PythonShimSettings {
+    /// Resolve the [`PythonShimSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonShimArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonShimArgs { force, uninstall } = args;
+
+        Self { force, uninstall }
+    }
+}
+
 /// The resolved settings to use for a `python find` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) no_project: bool,
+    pub(crate) script: Option<PathBuf>,
     pub(crate) system: bool,
+    pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
 impl PythonFindSettings {
     /// Resolve the [`PythonFindSettings`] from the CLI and workspace configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: PythonFindArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: PythonFindArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PythonFindArgs {
             request,
             no_project,
+            script,
             system,
             no_system,
         } = args;
 
+        let install_mirrors = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
         Self {
             request,
             no_project,
+            script,
             system: flag(system, no_system).unwrap_or_default(),
+            install_mirrors,
         }
     }
 }
@@ -979,6 +1240,8 @@ pub(crate) struct PythonPinSettings {
     pub(crate) request: Option<String>,
     pub(crate) resolved: bool,
     pub(crate) no_project: bool,
+    pub(crate) global: bool,
+    pub(crate) build: bool,
 }
 
 impl PythonPinSettings {
@@ -990,12 +1253,16 @@ impl PythonPinSettings {
             no_resolved,
             resolved,
             no_project,
+            global,
+            build,
         } = args;
 
         Self {
             request,
             resolved: flag(resolved, no_resolved).unwrap_or(false),
             no_project,
+            global,
+            build,
         }
     }
 }
@@ -1009,6 +1276,8 @@ pub(crate) struct SyncSettings {
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) relocatable: bool,
+    pub(crate) from_bundle: Option<PathBuf>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DependencyGroups,
     pub(crate) editable: EditableMode,
@@ -1045,11 +1314,14 @@ impl SyncSettings {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            allow_incompatible_wheels,
             locked,
             frozen,
             active,
             no_active,
+            relocatable,
             dry_run,
+            from_bundle,
             installer,
             build,
             refresh,
@@ -1074,6 +1346,8 @@ impl SyncSettings {
             dry_run: DryRun::from_args(dry_run),
             script,
             active: flag(active, no_active),
+            relocatable,
+            from_bundle,
             extras: ExtrasSpecification::from_args(
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 no_extra,
@@ -1094,7 +1368,8 @@ impl SyncSettings {
                 no_install_project,
                 no_install_workspace,
                 no_install_package,
-            ),
+            )
+            .with_allow_incompatible_wheels(allow_incompatible_wheels),
             modifications: if flag(exact, inexact).unwrap_or(true) {
                 Modifications::Exact
             } else {
@@ -1117,6 +1392,7 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) dump_forks: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1132,11 +1408,13 @@ impl LockSettings {
             check,
             check_exists,
             dry_run,
+            dump_forks,
             script,
             resolver,
             build,
             refresh,
             python,
+            python_version,
         } = args;
 
         let install_mirrors = filesystem
@@ -1144,14 +1422,20 @@ impl LockSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
+        let options = ResolverOptions {
+            supported_python: python_version,
+            ..resolver_options(resolver, build)
+        };
+
         Self {
             locked: check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
+            dump_forks,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings: ResolverSettings::combine(options, filesystem),
             install_mirrors,
         }
     }
@@ -1462,6 +1746,47 @@ impl TreeSettings {
     }
 }
 
+/// The resolved settings to use for a `cache prefetch` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CachePrefetchSettings {
+    pub(crate) build: bool,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl CachePrefetchSettings {
+    /// Resolve the [`CachePrefetchSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: CachePrefetchArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CachePrefetchArgs {
+            build,
+            build_options,
+            resolver,
+            python_version,
+            python_platform,
+            python,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            build,
+            python_version,
+            python_platform,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(
+                resolver_options(resolver, build_options),
+                filesystem,
+            ),
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for an `export` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1476,6 +1801,7 @@ pub(crate) struct ExportSettings {
     pub(crate) hashes: bool,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) bundle: Option<PathBuf>,
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) include_header: bool,
@@ -1513,6 +1839,7 @@ impl ExportSettings {
             hashes,
             no_hashes,
             output_file,
+            bundle,
             no_emit_project,
             no_emit_workspace,
             no_emit_package,
@@ -1557,6 +1884,7 @@ impl ExportSettings {
                 no_emit_package,
             ),
             output_file,
+            bundle,
             locked,
             frozen,
             include_header: flag(header, no_header).unwrap_or(true),
@@ -1836,6 +2164,8 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) user: bool,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
@@ -1872,6 +2202,7 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            user,
             no_build,
             build,
             no_binary,
@@ -1883,6 +2214,7 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
             compat_args: _,
         } = args;
 
@@ -1945,6 +2277,8 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            user,
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -1987,6 +2321,8 @@ pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) autoremove: bool,
+    pub(crate) user: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2004,7 +2340,9 @@ impl PipUninstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            user,
             dry_run,
+            autoremove,
             compat_args: _,
         } = args;
 
@@ -2012,6 +2350,8 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            autoremove,
+            user,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2392,6 +2732,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
+    pub(crate) yanked: &'a YankedPolicy,
 }
 
 /// The resolved settings to use for an invocation of the uv CLI when resolving dependencies.
@@ -2407,6 +2748,12 @@ pub(crate) struct ResolverSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) minimal_changes: bool,
+    pub(crate) break_extra_cycles: bool,
+    pub(crate) prefetch_versions: Option<usize>,
+    pub(crate) budget: ResolutionBudget,
+    pub(crate) yanked: YankedPolicy,
+    pub(crate) supported_python: Option<SupportedPython>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) no_build_isolation: bool,
@@ -2426,6 +2773,12 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) minimal_changes: bool,
+    pub(crate) break_extra_cycles: bool,
+    pub(crate) prefetch_versions: Option<usize>,
+    pub(crate) budget: ResolutionBudget,
+    pub(crate) yanked: &'a YankedPolicy,
+    pub(crate) supported_python: Option<&'a SupportedPython>,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) no_build_isolation: bool,
@@ -2458,6 +2811,12 @@ impl ResolverSettings {
             resolution: self.resolution,
             prerelease: self.prerelease,
             fork_strategy: self.fork_strategy,
+            minimal_changes: self.minimal_changes,
+            break_extra_cycles: self.break_extra_cycles,
+            prefetch_versions: self.prefetch_versions,
+            budget: self.budget,
+            yanked: &self.yanked,
+            supported_python: self.supported_python.as_ref(),
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
             no_build_isolation: self.no_build_isolation,
@@ -2489,10 +2848,20 @@ impl From<ResolverOptions> for ResolverSettings {
                     .map(Index::from)
                     .collect(),
                 value.no_index.unwrap_or_default(),
-            ),
+            )
+            .with_strict_index(value.strict_index.unwrap_or_default()),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            minimal_changes: value.minimal_changes.unwrap_or_default(),
+            break_extra_cycles: value.break_extra_cycles.unwrap_or_default(),
+            prefetch_versions: value.prefetch_versions,
+            budget: ResolutionBudget::from_args(
+                value.max_resolver_iterations,
+                value.resolver_timeout,
+            ),
+            yanked: YankedPolicy::from_args(value.yanked, value.allow_yanked.unwrap_or_default()),
+            supported_python: value.supported_python,
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2516,7 +2885,9 @@ impl From<ResolverOptions> for ResolverSettings {
             build_options: BuildOptions::new(
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-            ),
+            )
+            .with_prefer_binary(value.prefer_binary_package.unwrap_or_default())
+            .with_binary_only(value.binary_only.unwrap_or_default()),
         }
     }
 }
@@ -2529,6 +2900,12 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) minimal_changes: bool,
+    pub(crate) break_extra_cycles: bool,
+    pub(crate) prefetch_versions: Option<usize>,
+    pub(crate) budget: ResolutionBudget,
+    pub(crate) yanked: &'a YankedPolicy,
+    pub(crate) supported_python: Option<&'a SupportedPython>,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) no_build_isolation: bool,
@@ -2556,6 +2933,12 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) minimal_changes: bool,
+    pub(crate) break_extra_cycles: bool,
+    pub(crate) prefetch_versions: Option<usize>,
+    pub(crate) budget: ResolutionBudget,
+    pub(crate) yanked: YankedPolicy,
+    pub(crate) supported_python: Option<SupportedPython>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) no_build_isolation: bool,
@@ -2593,6 +2976,12 @@ impl ResolverInstallerSettings {
             resolution: self.resolution,
             prerelease: self.prerelease,
             fork_strategy: self.fork_strategy,
+            minimal_changes: self.minimal_changes,
+            break_extra_cycles: self.break_extra_cycles,
+            prefetch_versions: self.prefetch_versions,
+            budget: self.budget,
+            yanked: &self.yanked,
+            supported_python: self.supported_python.as_ref(),
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
             no_build_isolation: self.no_build_isolation,
@@ -2626,10 +3015,20 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                     .map(Index::from)
                     .collect(),
                 value.no_index.unwrap_or_default(),
-            ),
+            )
+            .with_strict_index(value.strict_index.unwrap_or_default()),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            minimal_changes: value.minimal_changes.unwrap_or_default(),
+            break_extra_cycles: value.break_extra_cycles.unwrap_or_default(),
+            prefetch_versions: value.prefetch_versions,
+            budget: ResolutionBudget::from_args(
+                value.max_resolver_iterations,
+                value.resolver_timeout,
+            ),
+            yanked: YankedPolicy::from_args(value.yanked, value.allow_yanked.unwrap_or_default()),
+            supported_python: value.supported_python,
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2658,7 +3057,9 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             build_options: BuildOptions::new(
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-            ),
+            )
+            .with_prefer_binary(value.prefer_binary_package.unwrap_or_default())
+            .with_binary_only(value.binary_only.unwrap_or_default()),
         }
     }
 }
@@ -2690,6 +3091,11 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) minimal_changes: bool,
+    pub(crate) break_extra_cycles: bool,
+    pub(crate) prefetch_versions: Option<usize>,
+    pub(crate) budget: ResolutionBudget,
+    pub(crate) yanked: YankedPolicy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -2740,6 +3146,7 @@ impl PipSettings {
             index_url,
             extra_index_url,
             no_index,
+            strict_index,
             find_links,
             index_strategy,
             keyring_provider,
@@ -2757,6 +3164,13 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            minimal_changes,
+            break_extra_cycles,
+            prefetch_versions,
+            max_resolver_iterations,
+            resolver_timeout,
+            yanked,
+            allow_yanked,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -2793,12 +3207,21 @@ impl PipSettings {
             index_url: top_level_index_url,
             extra_index_url: top_level_extra_index_url,
             no_index: top_level_no_index,
+            strict_index: top_level_strict_index,
             find_links: top_level_find_links,
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            minimal_changes: top_level_minimal_changes,
+            break_extra_cycles: top_level_break_extra_cycles,
+            prefetch_versions: top_level_prefetch_versions,
+            max_resolver_iterations: top_level_max_resolver_iterations,
+            resolver_timeout: top_level_resolver_timeout,
+            yanked: top_level_yanked,
+            supported_python: _,
+            allow_yanked: top_level_allow_yanked,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             no_build_isolation: top_level_no_build_isolation,
@@ -2815,6 +3238,8 @@ impl PipSettings {
             no_build_package: top_level_no_build_package,
             no_binary: top_level_no_binary,
             no_binary_package: top_level_no_binary_package,
+            prefer_binary_package: top_level_prefer_binary_package,
+            binary_only: top_level_binary_only,
         } = top_level;
 
         // Merge the top-level options (`tool.uv`) with the pip-specific options (`tool.uv.pip`),
@@ -2823,6 +3248,7 @@ impl PipSettings {
         // For example, prefer `tool.uv.pip.index-url` over `tool.uv.index-url`.
         let index = index.combine(top_level_index);
         let no_index = no_index.combine(top_level_no_index);
+        let strict_index = strict_index.combine(top_level_strict_index);
         let index_url = index_url.combine(top_level_index_url);
         let extra_index_url = extra_index_url.combine(top_level_extra_index_url);
         let find_links = find_links.combine(top_level_find_links);
@@ -2831,6 +3257,14 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let minimal_changes = minimal_changes.combine(top_level_minimal_changes);
+        let break_extra_cycles = break_extra_cycles.combine(top_level_break_extra_cycles);
+        let prefetch_versions = prefetch_versions.combine(top_level_prefetch_versions);
+        let max_resolver_iterations =
+            max_resolver_iterations.combine(top_level_max_resolver_iterations);
+        let resolver_timeout = resolver_timeout.combine(top_level_resolver_timeout);
+        let yanked = yanked.combine(top_level_yanked);
+        let allow_yanked = allow_yanked.combine(top_level_allow_yanked);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let no_build_isolation = no_build_isolation.combine(top_level_no_build_isolation);
@@ -2863,7 +3297,8 @@ impl PipSettings {
                     .map(Index::from)
                     .collect(),
                 args.no_index.combine(no_index).unwrap_or_default(),
-            ),
+            )
+            .with_strict_index(args.strict_index.combine(strict_index).unwrap_or_default()),
             extras: ExtrasSpecification::from_args(
                 args.all_extras.combine(all_extras).unwrap_or_default(),
                 args.no_extra.combine(no_extra).unwrap_or_default(),
@@ -2890,6 +3325,24 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            minimal_changes: args
+                .minimal_changes
+                .combine(minimal_changes)
+                .unwrap_or_default(),
+            break_extra_cycles: args
+                .break_extra_cycles
+                .combine(break_extra_cycles)
+                .unwrap_or_default(),
+            prefetch_versions: args.prefetch_versions.combine(prefetch_versions),
+            budget: ResolutionBudget::from_args(
+                args.max_resolver_iterations
+                    .combine(max_resolver_iterations),
+                args.resolver_timeout.combine(resolver_timeout),
+            ),
+            yanked: YankedPolicy::from_args(
+                args.yanked.combine(yanked),
+                args.allow_yanked.combine(allow_yanked).unwrap_or_default(),
+            ),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -3017,7 +3470,9 @@ impl PipSettings {
                     top_level_no_build,
                     top_level_no_build_package.unwrap_or_default(),
                 )),
-            ),
+            )
+            .with_prefer_binary(top_level_prefer_binary_package.unwrap_or_default())
+            .with_binary_only(top_level_binary_only.unwrap_or_default()),
             install_mirrors,
         }
     }
@@ -3032,6 +3487,12 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             resolution: settings.resolution,
             prerelease: settings.prerelease,
             fork_strategy: settings.fork_strategy,
+            minimal_changes: settings.minimal_changes,
+            break_extra_cycles: settings.break_extra_cycles,
+            prefetch_versions: settings.prefetch_versions,
+            budget: settings.budget,
+            yanked: settings.yanked,
+            supported_python: settings.supported_python,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
             no_build_isolation: settings.no_build_isolation,
@@ -3061,6 +3522,7 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
             reinstall: settings.reinstall,
             build_options: settings.build_options,
             sources: settings.sources,
+            yanked: settings.yanked,
         }
     }
 }
@@ -3145,6 +3607,42 @@ impl PublishSettings {
     }
 }
 
+/// The resolved settings to use for an invocation of the `uv auth` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthSettings {
+    // Configuration only
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl AuthSettings {
+    /// Resolve the [`AuthSettings`] from the filesystem configuration.
+    pub(crate) fn resolve(filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            index,
+            extra_index_url,
+            index_url,
+            ..
+        } = top_level;
+
+        Self {
+            index_locations: IndexLocations::new(
+                index
+                    .into_iter()
+                    .flatten()
+                    .chain(extra_index_url.into_iter().flatten().map(Index::from))
+                    .chain(index_url.into_iter().map(Index::from))
+                    .collect(),
+                Vec::new(),
+                false,
+            ),
+        }
+    }
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
@@ -3158,6 +3656,60 @@ mod env {
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_DOWNLOADS_PER_HOST: (&str, &str) = (
+        EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+        "a non-zero integer",
+    );
+
+    pub(super) const CACHE_MAX_SIZE: (&str, &str) = (
+        EnvVars::UV_CACHE_MAX_SIZE,
+        "a number of bytes, e.g., '10GB' or '512MiB'",
+    );
+
+    pub(super) const CACHE_COMPRESSION: (&str, &str) =
+        (EnvVars::UV_CACHE_COMPRESSION, "'true' or 'false'");
+
+    pub(super) const CACHE_DEDUPLICATE: (&str, &str) =
+        (EnvVars::UV_CACHE_DEDUPLICATE, "'true' or 'false'");
+
+    pub(super) const REMOTE_CACHE_URL: (&str, &str) = (EnvVars::UV_REMOTE_CACHE_URL, "a URL");
+
+    pub(super) const BASE_CACHE_DIR: (&str, &str) = (EnvVars::UV_BASE_CACHE_DIR, "a path");
+
+    pub(super) const BANDWIDTH_LIMIT: (&str, &str) = (
+        EnvVars::UV_BANDWIDTH_LIMIT,
+        "a number of bytes per second, e.g., '10M' or '512KiB'",
+    );
+
+    pub(super) const HTTP_RETRIES: (&str, &str) =
+        (EnvVars::UV_HTTP_RETRIES, "a non-negative integer");
+
+    pub(super) const HTTP_RETRY_BACKOFF_MIN: (&str, &str) = (
+        EnvVars::UV_HTTP_RETRY_BACKOFF_MIN,
+        "a non-negative number of seconds",
+    );
+
+    pub(super) const HTTP_RETRY_BACKOFF_MAX: (&str, &str) = (
+        EnvVars::UV_HTTP_RETRY_BACKOFF_MAX,
+        "a non-negative number of seconds",
+    );
+
+    pub(super) const HTTP_RETRY_BACKOFF_BASE: (&str, &str) = (
+        EnvVars::UV_HTTP_RETRY_BACKOFF_BASE,
+        "a non-negative integer",
+    );
+
+    pub(super) const HTTP_CONNECT_TIMEOUT: (&str, &str) =
+        (EnvVars::UV_HTTP_CONNECT_TIMEOUT, "a non-negative integer");
+
+    pub(super) const INDEX_TRACKING: (&str, &str) = (
+        EnvVars::UV_INDEX_TRACKING,
+        "one of 'warn', 'strict', or 'ignore'",
+    );
+
+    pub(super) const RANGE_REQUEST_SIZE: (&str, &str) =
+        (EnvVars::UV_RANGE_REQUEST_SIZE, "a positive integer");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
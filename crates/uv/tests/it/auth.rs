@@ -0,0 +1,59 @@
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// An index name that isn't in the configuration should be rejected.
+#[test]
+fn auth_token_unknown_index() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.internal.example.com/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.auth_token().arg("missing"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index not found: `missing`. Found indexes: `internal`
+    "###);
+
+    Ok(())
+}
+
+/// Without any stored credentials, `uv auth token` and `uv auth logout` should fail.
+#[test]
+fn auth_no_credentials() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.auth_token().arg("https://example.com/simple/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for https://example.com/simple/
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth_logout().arg("https://example.com/simple/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for https://example.com/simple/
+    "###);
+}
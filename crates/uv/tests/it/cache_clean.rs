@@ -2,6 +2,8 @@ use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 
+use uv_static::EnvVars;
+
 use crate::common::{uv_snapshot, TestContext};
 
 /// `cache clean` should remove all packages.
@@ -51,7 +53,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v15")
+        .child("simple-v16")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -125,7 +127,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v15")
+        .child("simple-v16")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
@@ -167,3 +169,130 @@ fn clean_package_index() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache clean iniconfig==2.0.0 --bucket wheels` should remove the downloaded wheels for a single
+/// version of `iniconfig`, while retaining its index metadata.
+#[test]
+fn clean_package_version_bucket() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let rkyv = context
+        .cache_dir
+        .child("simple-v16")
+        .child("pypi")
+        .child("iniconfig.rkyv");
+    assert!(
+        rkyv.exists(),
+        "Expected the `.rkyv` file to exist for `iniconfig`"
+    );
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The cache entry does not have a stable key, so we filter it out.
+            (
+                r"\[CACHE_DIR\](\\|\/)(.+)(\\|\/).*",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+            // The file count varies by operating system, so we filter it out.
+            ("Removed \\d+ files?", "Removed [N] files"),
+        ])
+        .collect();
+
+    // A non-matching version should have no effect.
+    uv_snapshot!(&filters, context.clean().arg("iniconfig<2").arg("--bucket").arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found
+    "###);
+
+    uv_snapshot!(&filters, context.clean().arg("--verbose").arg("iniconfig==2.0.0").arg("--bucket").arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Removing dangling cache entry: [CACHE_DIR]/archive-v0/[ENTRY]
+    Removed [N] files ([SIZE])
+    "###);
+
+    // The index metadata is retained.
+    assert!(
+        rkyv.exists(),
+        "Expected the `.rkyv` file to be retained for `iniconfig`"
+    );
+
+    Ok(())
+}
+
+/// With `cache-deduplicate`, the files of unzipped wheels are stored in the content-addressed
+/// store, which `cache clean` prunes once the wheels are removed.
+#[cfg(unix)]
+#[test]
+fn clean_deduplicated() -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0")?;
+
+    let stored_files = || -> Vec<std::path::PathBuf> {
+        walkdir::WalkDir::new(context.cache_dir.child("content-v0"))
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .map(walkdir::DirEntry::into_path)
+            .collect()
+    };
+
+    // By default, the files of unzipped wheels aren't deduplicated.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+    assert!(stored_files().is_empty());
+
+    // Once enabled, newly unzipped wheels are deduplicated.
+    context.clean().assert().success();
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .env(EnvVars::UV_CACHE_DEDUPLICATE, "true")
+        .assert()
+        .success();
+    let stored = stored_files();
+    assert!(!stored.is_empty());
+    for path in &stored {
+        assert!(fs_err::metadata(path)?.nlink() > 1);
+    }
+
+    // Once a wheel is removed from the cache and the environment, its stored files are removed
+    // too, while those of the remaining wheels are retained.
+    fs_err::remove_dir_all(&context.venv)?;
+    context.clean().arg("anyio").assert().success();
+    let remaining = stored_files();
+    assert!(remaining.len() < stored.len());
+    for path in &remaining {
+        assert!(fs_err::metadata(path)?.nlink() > 1);
+    }
+
+    Ok(())
+}
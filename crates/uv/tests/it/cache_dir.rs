@@ -0,0 +1,64 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Use the first writable path in `cache-dir`, skipping any that reference an unset variable.
+#[test]
+fn cache_dir_candidates() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        cache-dir = ["$UV_TEST_UNSET/cache", "${UV_TEST_SCRATCH}/cache"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.cache_dir()
+        .env_remove("UV_TEST_UNSET")
+        .env("UV_TEST_SCRATCH", context.temp_dir.child("scratch").as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/scratch/cache
+
+    ----- stderr -----
+    "###);
+
+    // The directory is created, if necessary.
+    context
+        .temp_dir
+        .child("scratch")
+        .child("cache")
+        .assert(predicates::path::is_dir());
+
+    Ok(())
+}
+
+/// Fall back to the default cache directory if none of the paths in `cache-dir` are writable.
+#[test]
+#[cfg(unix)]
+fn cache_dir_none_writable() -> Result<()> {
+    use uv_static::EnvVars;
+
+    let context = TestContext::new("3.12");
+
+    // A path beneath a file can't be created.
+    context.temp_dir.child("file").touch()?;
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        cache-dir = ["$UV_TEST_UNSET/cache", "./file/cache"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.cache_dir()
+        .env_remove("UV_TEST_UNSET")
+        .env(EnvVars::XDG_CACHE_HOME, context.temp_dir.child("xdg").as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/xdg/uv
+
+    ----- stderr -----
+    warning: None of the paths in `cache-dir` are writable; using the default cache directory
+    "###);
+
+    Ok(())
+}
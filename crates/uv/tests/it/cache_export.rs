@@ -0,0 +1,111 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache export` should write the cached artifacts for a lockfile to an archive, which
+/// `cache import` can then use to seed an empty cache for an offline installation.
+#[test]
+fn export_import() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    // Install the project, to populate the cache.
+    context.sync().assert().success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\([\d.]+[KMGTPE]?i?B\)", "([SIZE])"),
+            (r"Imported \d+ files", "Imported [N] files"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_export().arg("--from-lock").arg("uv.lock").arg("cache.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported cached artifacts for 1 package to: cache.tar.zst ([SIZE])
+    "###);
+
+    // Clear the cache and the environment, then seed the cache from the archive.
+    context.clean().assert().success();
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(&filters, context.cache_import().arg("cache.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported [N] files into cache at: [CACHE_DIR]/
+    "###);
+
+    // The project can be installed without network access.
+    uv_snapshot!(&filters, context.sync().arg("--frozen").arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// `cache export` should warn about packages in the lockfile with no cached artifacts.
+#[test]
+fn export_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    // Lock the project, without installing it.
+    context.lock().assert().success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"\([\d.]+[KMGTPE]?i?B\)", "([SIZE])")])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_export().arg("--from-lock").arg("uv.lock").arg("cache.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: No cached artifacts found for 1 package: iniconfig
+    Exported cached artifacts for 0 packages to: cache.tar.zst ([SIZE])
+    "###);
+
+    Ok(())
+}
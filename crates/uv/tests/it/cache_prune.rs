@@ -158,7 +158,7 @@ fn prune_stale_symlink() -> Result<()> {
         .success();
 
     // Remove the wheels directory, causing the symlink to become stale.
-    let wheels = context.cache_dir.child("wheels-v5");
+    let wheels = context.cache_dir.child("wheels-v6");
     fs_err::remove_dir_all(wheels)?;
 
     let filters: Vec<_> = context
@@ -261,7 +261,225 @@ fn prune_unzipped() -> Result<()> {
 
           hint: Pre-releases are available for `iniconfig` in the requested range (e.g., 0.2.dev0), but pre-releases weren't enabled (try: `--prerelease=allow`)
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: Resolving the dependencies of `iniconfig` (2.0.0, 1.1.1, 1.1.0, and 3 more) requires its metadata, which wasn't found in the cache, and the network was disabled. No versions of `iniconfig` are cached.
+    "###);
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should evict the least-recently-used unzipped archives.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // The size limit is validated.
+    uv_snapshot!(&filters, context.prune().env(EnvVars::UV_CACHE_MAX_SIZE, "big"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value for UV_CACHE_MAX_SIZE, expected a number of bytes, e.g., '10GB' or '512MiB'
+    "###);
+
+    // A generous limit doesn't evict anything.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("10GiB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // A tight limit evicts the unzipped wheel.
+    uv_snapshot!(&filters, context.prune().env(EnvVars::UV_CACHE_MAX_SIZE, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    // Reinstalling the package requires a download, since the wheel was evicted.
+    context.venv().assert().success();
+    uv_snapshot!(&filters, context.pip_install().arg("-r").arg("requirements.txt").arg("--offline"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because all versions of iniconfig need to be downloaded from a registry and you require iniconfig, we can conclude that your requirements are unsatisfiable.
+
+          hint: Pre-releases are available for `iniconfig` in the requested range (e.g., 0.2.dev0), but pre-releases weren't enabled (try: `--prerelease=allow`)
+
+          hint: Resolving the dependencies of `iniconfig` (2.0.0, 1.1.1, 1.1.0, and 3 more) requires its metadata, which wasn't found in the cache, and the network was disabled. No versions of `iniconfig` are cached.
+    "###);
+
+    Ok(())
+}
+
+/// `cache prune` should remove the unzipped archives that are unused beyond the `cache-retention`
+/// period.
+#[test]
+fn prune_retention() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // The retention period is validated.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"cache-retention = "soon""#)?;
+    uv_snapshot!(&filters, context.prune(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`
+      Caused by: TOML parse error at line 1, column 19
+      |
+    1 | cache-retention = "soon"
+      |                   ^^^^^^
+    invalid retention period: `soon`; expected a number with a unit (e.g., `30d` or `12h`)
+    "###);
+
+    // Install a requirement, to populate the cache, then remove the environment, such that the
+    // unzipped wheel is no longer in use.
+    uv_toml.write_str(r#"cache-retention = { wheels = "30d" }"#)?;
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+    context.venv().assert().success();
+
+    // Recently used entries are retained.
+    uv_snapshot!(&filters, context.prune(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // Entries that haven't been used within the period are removed.
+    let unused = filetime::FileTime::from_unix_time(1_700_000_000, 0);
+    for entry in walkdir::WalkDir::new(context.cache_dir.child("archive-v0")) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            filetime::set_file_times(entry.path(), unused, unused)?;
+        }
+    }
+    uv_snapshot!(&filters, context.prune(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    Ok(())
+}
+
+/// With `cache-compression`, unused source trees should be compressed once a command completes.
+#[test]
+fn compress_unused_sources() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source-distribution==0.0.1")?;
+
+    // The setting is validated.
+    uv_snapshot!(context.filters(), context.pip_freeze().env(EnvVars::UV_CACHE_COMPRESSION, "maybe"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value for UV_CACHE_COMPRESSION, expected 'true' or 'false'
+    "###);
+
+    // Build the source distribution, to populate the cache with its source tree.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+    // The source tree is the outermost `src` directory in the revision.
+    let source = walkdir::WalkDir::new(context.cache_dir.child("sdists-v9"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_type().is_dir() && entry.file_name() == "src")
+        .map(walkdir::DirEntry::into_path)
+        .expect("the source tree is cached");
+
+    // A source tree that was used recently is left as-is.
+    context
+        .pip_freeze()
+        .env(EnvVars::UV_CACHE_COMPRESSION, "true")
+        .assert()
+        .success();
+    assert!(source.is_dir());
+
+    // Once unused for a week, the source tree is replaced by an archive, at most once a day.
+    let unused = filetime::FileTime::from_unix_time(1_700_000_000, 0);
+    for entry in walkdir::WalkDir::new(&source) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            filetime::set_file_times(entry.path(), unused, unused)?;
+        }
+    }
+    fs_err::remove_file(context.cache_dir.child(".compressed"))?;
+    context
+        .pip_freeze()
+        .env(EnvVars::UV_CACHE_COMPRESSION, "true")
+        .assert()
+        .success();
+    assert!(!source.exists());
+    assert!(source.with_file_name("src.tar.zst").is_file());
+
+    // The package can still be installed from the cache.
+    context.venv().assert().success();
+    uv_snapshot!(context.filters(), context.pip_install().arg("-r").arg("requirements.txt").arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
     "###);
 
     Ok(())
@@ -348,7 +566,7 @@ fn prune_stale_revision() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
-    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v8/[ENTRY]
+    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v9/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
     "###);
@@ -381,3 +599,57 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --index` should remove the entries supplied by the index.
+#[test]
+fn prune_index() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // Entries from other indexes are retained.
+    uv_snapshot!(&filters, context.prune().arg("--index").arg("https://mirror.example.com/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    uv_snapshot!(&filters, context.prune().arg("--index").arg("https://pypi.org/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    // Reinstalling the requirement should require a download, since we pruned the index.
+    context.venv().assert().success();
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--offline")
+        .assert()
+        .failure();
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache stats` should report the size of each bucket, and the accesses of recent commands.
+#[test]
+fn stats() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"  +[\d.]+[KMGT]?i?B  \d+ files?", "  [SIZE]  [N] files"),
+            (r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} UTC", "[TIMESTAMP]"),
+            (
+                r"\d+ hits, \d+ revalidated, \d+ misses \(\d+%",
+                "[N] hits, [N] revalidated, [N] misses ([N]%",
+            ),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_stats(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache at: [CACHE_DIR]/
+
+    Wheels  [SIZE]  [N] files
+    Source distribution builds  [SIZE]  [N] files
+    Flat index metadata  [SIZE]  [N] files
+    Git repositories  [SIZE]  [N] files
+    Mercurial repositories  [SIZE]  [N] files
+    Interpreter metadata  [SIZE]  [N] files
+    Package index metadata  [SIZE]  [N] files
+    Deduplicated wheel files  [SIZE]  [N] files
+    Unzipped wheels  [SIZE]  [N] files
+    Build environments  [SIZE]  [N] files
+    Ephemeral environments  [SIZE]  [N] files
+    Total  [SIZE]  [N] files
+
+    HTTP cache accesses of recent commands:
+      [TIMESTAMP]  [N] hits, [N] revalidated, [N] misses ([N]% served from cache)
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
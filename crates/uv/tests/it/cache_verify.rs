@@ -0,0 +1,84 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache verify` should detect unzipped wheels that no longer match their `RECORD` file, and
+/// remove them, such that they're downloaded again when next used.
+#[test]
+fn verify_corrupted_wheel() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"archive-v0(\\|/)[^ ]+", "archive-v0/[ENTRY]")])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Checked 1 unzipped wheel; no corrupted entries found
+    "###);
+
+    // Replace a file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .next()
+        .unwrap()?
+        .path();
+    let file = archive.join("iniconfig").join("py.typed");
+    fs_err::remove_file(&file)?;
+    fs_err::write(&file, "corrupted")?;
+
+    uv_snapshot!(&filters, context.cache_verify().arg("--dry-run"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupted: [CACHE_DIR]/archive-v0/[ENTRY] (RECORD file doesn't match wheel contents: Hash mismatch for: iniconfig/py.typed)
+    Checked 1 unzipped wheel; found 1 corrupted entry
+    "###);
+
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupted: [CACHE_DIR]/archive-v0/[ENTRY] (RECORD file doesn't match wheel contents: Hash mismatch for: iniconfig/py.typed)
+    Checked 1 unzipped wheel; removed 1 corrupted entry
+    "###);
+
+    assert!(!archive.exists());
+
+    // The wheel is downloaded again when next used.
+    uv_snapshot!(&filters, context.pip_install().arg("iniconfig==2.0.0").arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
@@ -606,6 +606,10 @@ impl TestContext {
             .env(EnvVars::COLUMNS, "100")
             .env(EnvVars::PATH, path)
             .env(EnvVars::HOME, self.home_dir.as_os_str())
+            .env(
+                EnvVars::UV_CREDENTIALS_DIR,
+                self.home_dir.child("credentials").as_os_str(),
+            )
             .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
             // Installations are not allowed by default; see `Self::with_managed_python_dirs`
             .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
@@ -616,6 +620,8 @@ impl TestContext {
             .env(EnvVars::UV_TEST_NO_CLI_PROGRESS, "1")
             .env_remove(EnvVars::UV_CACHE_DIR)
             .env_remove(EnvVars::UV_TOOL_BIN_DIR)
+            .env_remove(EnvVars::PYENV_ROOT)
+            .env_remove(EnvVars::ASDF_DATA_DIR)
             .current_dir(self.temp_dir.path());
 
         for (key, value) in &self.extra_env {
@@ -767,6 +773,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv auth logout` command with options shared across scenarios.
+    pub fn auth_logout(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("logout");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth token` command with options shared across scenarios.
+    pub fn auth_token(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("token");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv python find` command with options shared across scenarios.
     pub fn python_find(&self) -> Command {
         let mut command = self.new_command();
@@ -791,6 +813,39 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python upgrade` command with options shared across scenarios.
+    pub fn python_upgrade(&self) -> Command {
+        let mut command = self.new_command();
+        self.add_shared_options(&mut command, true);
+        command
+            .arg("python")
+            .arg("upgrade")
+            .current_dir(&self.temp_dir);
+        command
+    }
+
+    /// Create a `uv python move` command with options shared across scenarios.
+    pub fn python_move(&self) -> Command {
+        let mut command = self.new_command();
+        self.add_shared_options(&mut command, true);
+        command
+            .arg("python")
+            .arg("move")
+            .current_dir(&self.temp_dir);
+        command
+    }
+
+    /// Create a `uv python shim` command with options shared across scenarios.
+    pub fn python_shim(&self) -> Command {
+        let mut command = self.new_command();
+        self.add_shared_options(&mut command, true);
+        command
+            .arg("python")
+            .arg("shim")
+            .current_dir(&self.temp_dir);
+        command
+    }
+
     /// Create a `uv python uninstall` command with options shared across scenarios.
     pub fn python_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -810,6 +865,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python list` command with options shared across scenarios.
+    pub fn python_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv python dir` command with options shared across scenarios.
     pub fn python_dir(&self) -> Command {
         let mut command = self.new_command();
@@ -909,6 +972,17 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache dir` command.
+    ///
+    /// Unlike the other commands, the cache directory isn't passed on the command line, such that
+    /// it's read from the configuration.
+    pub fn cache_dir(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("dir");
+        self.add_shared_env(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache prune` command.
     pub fn prune(&self) -> Command {
         let mut command = self.new_command();
@@ -917,6 +991,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache prefetch` command.
+    pub fn cache_prefetch(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("prefetch");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1268,6 +1382,7 @@ pub fn python_installations_for_versions(
                 &PythonRequest::parse(python_version),
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::Managed,
+                None,
                 &cache,
             ) {
                 python.into_interpreter().sys_executable().to_owned()
@@ -1452,6 +1567,79 @@ pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Create a stub wheel for `name==version` with the given tag (e.g., `py3-none-any`) in `dir`,
+/// returning the path to the wheel.
+///
+/// The wheel contains only `METADATA` and `WHEEL` files; any `headers` (e.g.,
+/// `License-Expression: MIT`) are appended to the `METADATA`.
+pub fn make_wheel(
+    dir: &Path,
+    name: &str,
+    version: &str,
+    tag: &str,
+    headers: &[&str],
+) -> anyhow::Result<PathBuf> {
+    use std::io::Write;
+
+    let mut metadata = format!("Metadata-Version: 2.4\nName: {name}\nVersion: {version}\n");
+    for header in headers {
+        metadata.push_str(header);
+        metadata.push('\n');
+    }
+    let purelib = tag.ends_with("-none-any");
+
+    let path = dir.join(format!("{name}-{version}-{tag}.whl"));
+    let mut writer = zip::ZipWriter::new(fs_err::File::create(&path)?);
+    let options = zip::write::FileOptions::default();
+    writer.start_file(format!("{name}-{version}.dist-info/METADATA"), options)?;
+    writer.write_all(metadata.as_bytes())?;
+    writer.start_file(format!("{name}-{version}.dist-info/WHEEL"), options)?;
+    writer.write_all(
+        format!("Wheel-Version: 1.0\nRoot-Is-Purelib: {purelib}\nTag: {tag}\n").as_bytes(),
+    )?;
+    writer.finish()?;
+    Ok(path)
+}
+
+/// Create a Mercurial repository in `dir` for a stub package `name`, with a commit and a
+/// `v{version}` tag for each of the given versions.
+#[cfg(feature = "hg")]
+pub fn make_hg_repository(dir: &Path, name: &str, versions: &[&str]) -> anyhow::Result<()> {
+    let hg = |args: &[&str]| {
+        Command::new("hg")
+            .args(args)
+            .arg("--date")
+            .arg("2024-03-25 00:00:00 +0000")
+            .current_dir(dir)
+            .env(EnvVars::HGPLAIN, "1")
+            .env("HGUSER", "uv <uv@example.com>")
+            .assert()
+            .success();
+    };
+
+    fs_err::create_dir_all(dir)?;
+    Command::new("hg")
+        .arg("init")
+        .current_dir(dir)
+        .assert()
+        .success();
+    for version in versions {
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            formatdoc! {r#"
+                [project]
+                name = "{name}"
+                version = "{version}"
+                requires-python = ">=3.12"
+                dependencies = []
+            "#},
+        )?;
+        hg(&["commit", "--addremove", "--message", version]);
+        hg(&["tag", &format!("v{version}")]);
+    }
+    Ok(())
+}
+
 // This is a fine-grained token that only has read-only access to the `uv-private-pypackage` repository
 pub const READ_ONLY_GITHUB_TOKEN: &[&str] = &[
     "Z2l0aHViX3BhdA==",
@@ -6,6 +6,7 @@ use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use insta::assert_snapshot;
+use predicates::prelude::predicate;
 use std::process::Stdio;
 
 #[test]
@@ -522,7 +523,7 @@ fn dependency_conflicting_markers() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.12"
             resolution-markers = [
                 "sys_platform == 'darwin'",
@@ -1220,7 +1221,7 @@ fn non_project_fork() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.12"
             resolution-markers = [
                 "sys_platform == 'win32'",
@@ -2128,7 +2129,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
         resolution-markers = [
             "sys_platform == 'win32'",
@@ -2245,7 +2246,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
         resolution-markers = [
             "sys_platform == 'win32'",
@@ -2425,6 +2426,101 @@ fn conflicts() -> Result<()> {
     Ok(())
 }
 
+/// Export a project with conflicting dependency groups, selecting the locked versions for the
+/// enabled group.
+#[test]
+fn conflicts_groups() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+
+        [tool.uv]
+        conflicts = [
+            [
+              { group = "group1" },
+              { group = "group2" },
+            ],
+        ]
+
+        [dependency-groups]
+        group1 = ["sortedcontainers==2.3.0"]
+        group2 = ["sortedcontainers==2.4.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR]
+    -e .
+    iniconfig==1.1.1 \
+        --hash=sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3 \
+        --hash=sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--group").arg("group1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --group group1
+    -e .
+    iniconfig==1.1.1 \
+        --hash=sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3 \
+        --hash=sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32
+    sortedcontainers==2.3.0 \
+        --hash=sha256:37257a32add0a3ee490bb170b599e93095eed89a55da91fa9f48753ea12fd73f \
+        --hash=sha256:59cc937650cf60d677c16775597c89a960658a09cf7c1a668f86e1e4464b10a1
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--only-group").arg("group2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --only-group group2
+    sortedcontainers==2.4.0 \
+        --hash=sha256:25caa5a06cc30b6b83d11423433f65d1f9d76c4c6a0c90e3379eaa43b9bfdb88 \
+        --hash=sha256:a163dcaede0f1c021485e957a39245190e74249897e2ae4b2aa38595db237ee0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--group").arg("group1").arg("--group").arg("group2"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: Groups `group1` and `group2` are incompatible with the declared conflicts: {`project:group1`, `project:group2`}
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn simple_conflict_markers() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2768,3 +2864,60 @@ fn complex_conflict_markers() -> Result<()> {
 
     Ok(())
 }
+
+/// Export a bundle, then install from it without access to the registry.
+#[test]
+fn bundle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-header").arg("--no-hashes").arg("--bundle").arg("bundle"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Bundled 2 files into: bundle
+    "###);
+
+    let bundle = context.temp_dir.child("bundle");
+    bundle
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+    bundle
+        .child("iniconfig-2.0.0.tar.gz")
+        .assert(predicate::path::is_file());
+    bundle
+        .child("bundle.json")
+        .assert(predicate::path::is_file());
+
+    // Clear the cache, such that the distributions must be read from the bundle.
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--from-bundle").arg("bundle"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
@@ -30,6 +30,7 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -37,9 +38,15 @@ fn help() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -62,6 +69,8 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -111,15 +120,22 @@ fn help_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -142,6 +158,8 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -190,15 +208,22 @@ fn help_short_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -221,6 +246,8 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -295,9 +322,12 @@ fn help_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
+      move       Move the managed Python installations to another directory
+      shim       Install `python` and `python3` shims that run the managed Python version
       uninstall  Uninstall Python versions
 
     Cache options:
@@ -317,6 +347,26 @@ fn help_subcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-lock-timeout <SECONDS>
+              The maximum time to wait for a lock on a cache entry, in seconds.
+              
+              By default, uv waits indefinitely for other uv processes to release the locks that guard
+              writes to the cache. When the cache is shared between machines (e.g., on an NFS or SMB
+              volume), a lock may be held by a process that was terminated without releasing it; with a
+              timeout, uv fails with an error instead of waiting forever.
+              
+              [env: UV_CACHE_LOCK_TIMEOUT=]
+
+          --no-discovery-cache
+              Avoid reading cached information about Python interpreters, querying each interpreter
+              instead.
+              
+              By default, uv caches the result of querying a Python interpreter, keyed by the path and
+              modification time of its executable, to avoid spawning every interpreter on each
+              invocation. The cache is refreshed with the results of the new queries.
+              
+              [env: UV_NO_DISCOVERY_CACHE=]
+
     Python options:
           --python-preference <PYTHON_PREFERENCE>
               Whether to prefer uv-managed or system Python installations.
@@ -385,7 +435,8 @@ fn help_subcommand() {
               Can be provided multiple times.
               
               Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
-              `localhost:8080`), or a URL (e.g., `https://localhost`).
+              `localhost:8080`), a URL (e.g., `https://localhost`), or a pattern matching any subdomain
+              (e.g., `*.example.com:8443`).
               
               WARNING: Hosts included in this list will not be verified against the system's certificate
               store. Only use `--allow-insecure-host` in a secure network with verified sources, as it
@@ -393,6 +444,19 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging.
+              
+              Each request to a registry or index is recorded with its method, URL, response status,
+              timing, and cache disposition (i.e., whether the response was served from the cache,
+              revalidated, or fetched). Credentials and headers are omitted.
+              
+              If the file has a `.har` extension, the requests are written as an HTTP Archive (HAR),
+              which can be viewed in a browser's developer tools. Otherwise, each request is written as
+              a line of JSON.
+              
+              [env: UV_TRACE_HTTP=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -566,6 +630,26 @@ fn help_subsubcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-lock-timeout <SECONDS>
+              The maximum time to wait for a lock on a cache entry, in seconds.
+              
+              By default, uv waits indefinitely for other uv processes to release the locks that guard
+              writes to the cache. When the cache is shared between machines (e.g., on an NFS or SMB
+              volume), a lock may be held by a process that was terminated without releasing it; with a
+              timeout, uv fails with an error instead of waiting forever.
+              
+              [env: UV_CACHE_LOCK_TIMEOUT=]
+
+          --no-discovery-cache
+              Avoid reading cached information about Python interpreters, querying each interpreter
+              instead.
+              
+              By default, uv caches the result of querying a Python interpreter, keyed by the path and
+              modification time of its executable, to avoid spawning every interpreter on each
+              invocation. The cache is refreshed with the results of the new queries.
+              
+              [env: UV_NO_DISCOVERY_CACHE=]
+
     Python options:
           --python-preference <PYTHON_PREFERENCE>
               Whether to prefer uv-managed or system Python installations.
@@ -634,7 +718,8 @@ fn help_subsubcommand() {
               Can be provided multiple times.
               
               Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
-              `localhost:8080`), or a URL (e.g., `https://localhost`).
+              `localhost:8080`), a URL (e.g., `https://localhost`), or a pattern matching any subdomain
+              (e.g., `*.example.com:8443`).
               
               WARNING: Hosts included in this list will not be verified against the system's certificate
               store. Only use `--allow-insecure-host` in a secure network with verified sources, as it
@@ -642,6 +727,19 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging.
+              
+              Each request to a registry or index is recorded with its method, URL, response status,
+              timing, and cache disposition (i.e., whether the response was served from the cache,
+              revalidated, or fetched). Credentials and headers are omitted.
+              
+              If the file has a `.har` extension, the requests are written as an HTTP Archive (HAR),
+              which can be viewed in a browser's developer tools. Otherwise, each request is written as
+              a line of JSON.
+              
+              [env: UV_TRACE_HTTP=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -712,15 +810,24 @@ fn help_flag_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
+      move       Move the managed Python installations to another directory
+      shim       Install `python` and `python3` shims that run the managed Python version
       uninstall  Uninstall Python versions
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -743,6 +850,8 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -791,9 +900,15 @@ fn help_flag_subsubcommand() {
           --default                    Use as the default Python version
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -816,6 +931,8 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -860,6 +977,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        auth
         cache
         self
         version
@@ -887,6 +1005,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        auth
         cache
         self
         version
@@ -941,6 +1060,7 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -948,9 +1068,15 @@ fn help_with_global_option() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -973,6 +1099,8 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1057,6 +1185,7 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -1064,9 +1193,15 @@ fn help_with_no_pager() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                      Avoid reading from or writing to the cache, instead using a
+                                          temporary directory for the duration of the operation [env:
+                                          UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]         Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <SECONDS>  The maximum time to wait for a lock on a cache entry, in
+                                          seconds [env: UV_CACHE_LOCK_TIMEOUT=]
+          --no-discovery-cache            Avoid reading cached information about Python interpreters,
+                                          querying each interpreter instead [env:
+                                          UV_NO_DISCOVERY_CACHE=]
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -1089,6 +1224,8 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --trace-http <TRACE_HTTP>
+              Record HTTP requests to the given file, for debugging [env: UV_TRACE_HTTP=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
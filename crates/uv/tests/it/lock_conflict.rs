@@ -91,7 +91,7 @@ fn extra_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -285,7 +285,7 @@ fn extra_basic_three_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -760,7 +760,7 @@ fn extra_multiple_independent() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -910,7 +910,7 @@ fn extra_config_change_ignore_lockfile() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "extra1" },
@@ -1725,7 +1725,7 @@ fn group_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "group1" },
@@ -1880,7 +1880,7 @@ fn group_default() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "group1" },
@@ -2092,7 +2092,7 @@ fn mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "group1" },
@@ -2262,7 +2262,7 @@ fn multiple_sources_index_disjoint_extras() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -2412,7 +2412,7 @@ fn multiple_sources_index_disjoint_groups() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "cu118" },
@@ -2561,7 +2561,7 @@ fn multiple_sources_index_disjoint_extras_with_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -2730,7 +2730,7 @@ fn multiple_sources_index_disjoint_extras_with_marker() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         resolution-markers = [
             "extra != 'extra-7-project-cu118' and extra == 'extra-7-project-cu124'",
@@ -3056,7 +3056,7 @@ fn shared_optional_dependency_extra1() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -3196,7 +3196,7 @@ fn shared_optional_dependency_group1() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "foo" },
@@ -3337,7 +3337,7 @@ fn shared_optional_dependency_mixed1() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -3482,7 +3482,7 @@ fn shared_optional_dependency_extra2() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -3623,7 +3623,7 @@ fn shared_optional_dependency_group2() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", group = "foo" },
@@ -3769,7 +3769,7 @@ fn shared_optional_dependency_mixed2() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -3913,7 +3913,7 @@ fn shared_dependency_extra() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -4088,7 +4088,7 @@ fn shared_dependency_group() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "foo" },
@@ -4264,7 +4264,7 @@ fn shared_dependency_mixed() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "foo" },
@@ -4481,7 +4481,7 @@ conflicts = [
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = "==3.11.*"
         conflicts = [[
             { package = "project", extra = "x1" },
@@ -4667,7 +4667,7 @@ fn jinja_no_conflict_markers1() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", extra = "cu118" },
@@ -4829,7 +4829,7 @@ fn jinja_no_conflict_markers2() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         resolution-markers = [
             "extra != 'extra-7-project-cu118' and extra == 'extra-7-project-cu124'",
@@ -4990,7 +4990,7 @@ fn collision_extra() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg", extra = "foo" },
@@ -5219,7 +5219,7 @@ fn extra_inferences() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         conflicts = [[
             { package = "pkg", extra = "x1" },
@@ -7255,7 +7255,7 @@ fn deduplicate_resolution_markers() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         resolution-markers = [
             "sys_platform != 'linux' and extra != 'extra-3-pkg-x1' and extra == 'extra-3-pkg-x2'",
@@ -7409,7 +7409,7 @@ fn incorrect_extra_simplification_leads_to_multiple_torch_packages() -> Result<(
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
         resolution-markers = [
             "python_full_version >= '3.12' and sys_platform == 'win32' and extra != 'extra-4-test-chgnet' and extra == 'extra-4-test-m3gnet'",
@@ -10184,7 +10184,7 @@ fn duplicate_torch_and_sympy_because_of_wrong_inferences() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
         resolution-markers = [
             "python_full_version >= '3.12' and sys_platform == 'win32' and extra == 'extra-4-test-alignn' and extra != 'extra-4-test-all' and extra != 'extra-4-test-chgnet' and extra == 'extra-4-test-m3gnet'",
@@ -13445,7 +13445,7 @@ fn overlapping_resolution_markers() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = "==3.10.*"
         resolution-markers = [
             "sys_platform == 'linux' and extra != 'extra-14-ads-mega-model-cpu' and extra == 'extra-14-ads-mega-model-cu118'",
@@ -14128,7 +14128,7 @@ fn avoids_exponential_lock_file_growth() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         resolution-markers = [
             "extra != 'extra-27-resolution-markers-for-days-cpu' and extra == 'extra-27-resolution-markers-for-days-cu124'",
@@ -14543,7 +14543,7 @@ fn avoids_exponential_lock_file_growth() -> Result<()> {
             lock,
             @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
         resolution-markers = [
             "extra != 'extra-27-resolution-markers-for-days-cpu' and extra == 'extra-27-resolution-markers-for-days-cu124'",
@@ -123,7 +123,7 @@ fn wrong_backtracking_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -247,7 +247,7 @@ fn fork_allows_non_conflicting_non_overlapping_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -365,7 +365,7 @@ fn fork_allows_non_conflicting_repeated_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -465,7 +465,7 @@ fn fork_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -751,7 +751,7 @@ fn fork_filter_sibling_dependencies() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'linux'",
@@ -930,7 +930,7 @@ fn fork_upgrade() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -1051,7 +1051,7 @@ fn fork_incomplete_markers() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "python_full_version >= '3.11'",
@@ -1205,7 +1205,7 @@ fn fork_marker_accrue() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -1409,7 +1409,7 @@ fn fork_marker_inherit_combined_allowed() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "implementation_name == 'pypy' and sys_platform == 'darwin'",
@@ -1590,7 +1590,7 @@ fn fork_marker_inherit_combined_disallowed() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "implementation_name == 'pypy' and sys_platform == 'darwin'",
@@ -1760,7 +1760,7 @@ fn fork_marker_inherit_combined() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "implementation_name == 'pypy' and sys_platform == 'darwin'",
@@ -1923,7 +1923,7 @@ fn fork_marker_inherit_isolated() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2072,7 +2072,7 @@ fn fork_marker_inherit_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2229,7 +2229,7 @@ fn fork_marker_inherit() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2368,7 +2368,7 @@ fn fork_marker_limited_inherit() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2524,7 +2524,7 @@ fn fork_marker_selection() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2680,7 +2680,7 @@ fn fork_marker_track() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'darwin'",
@@ -2833,7 +2833,7 @@ fn fork_non_fork_marker_transitive() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -3133,7 +3133,7 @@ fn fork_overlapping_markers_basic() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "python_full_version >= '3.11'",
@@ -3301,7 +3301,7 @@ fn preferences_dependent_forking_bistable() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'linux'",
@@ -3680,7 +3680,7 @@ fn preferences_dependent_forking_tristable() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'linux'",
@@ -3964,7 +3964,7 @@ fn preferences_dependent_forking() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "sys_platform == 'linux'",
@@ -4139,7 +4139,7 @@ fn fork_remaining_universe_partitioning() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
         resolution-markers = [
             "os_name == 'darwin' and sys_platform == 'illumos'",
@@ -4292,7 +4292,7 @@ fn fork_requires_python_full_prerelease() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
 
         [[package]]
@@ -4377,7 +4377,7 @@ fn fork_requires_python_full() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
 
         [[package]]
@@ -4466,7 +4466,7 @@ fn fork_requires_python_patch_overlap() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10.1"
 
         [[package]]
@@ -4560,7 +4560,7 @@ fn fork_requires_python() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
 
         [[package]]
@@ -4641,7 +4641,7 @@ fn requires_python_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.10"
 
         [[package]]
@@ -4738,7 +4738,7 @@ fn unreachable_package() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -4840,7 +4840,7 @@ fn unreachable_wheels() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
@@ -4969,7 +4969,7 @@ fn specific_architecture() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.8"
 
         [[package]]
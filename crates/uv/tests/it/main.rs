@@ -3,6 +3,8 @@
 
 pub(crate) mod common;
 
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -14,9 +16,24 @@ mod build_backend;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(feature = "python")]
+mod cache_dir;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_export;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_prefetch;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_stats;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -75,6 +92,9 @@ mod python_find;
 #[cfg(feature = "python-managed")]
 mod python_install;
 
+#[cfg(feature = "python")]
+mod python_list;
+
 #[cfg(feature = "python")]
 mod python_pin;
 
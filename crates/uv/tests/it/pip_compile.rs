@@ -5033,6 +5033,89 @@ fn generate_hashes_registry() -> Result<()> {
     Ok(())
 }
 
+/// Include hashes from sidecar files in a local directory index without an `index.html`.
+#[test]
+fn generate_hashes_local_index_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    let tqdm = context.temp_dir.child("simple").child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        tqdm.join("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+    tqdm.child("tqdm-1000.0.0-py3-none-any.whl.sha256")
+        .write_str("a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--generate-hashes")
+        .arg("--index-url")
+        .arg("./simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes
+    tqdm==1000.0.0 \
+        --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include hashes from a checksum manifest in a flat local directory index.
+#[test]
+fn generate_hashes_local_index_flat() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    let wheels = context.temp_dir.child("wheels");
+    fs_err::create_dir_all(&wheels)?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        wheels.join("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+    wheels.child("SHA256SUMS").write_str(indoc! {r"
+        a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13  tqdm-1000.0.0-py3-none-any.whl
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--generate-hashes")
+        .arg("--index-url")
+        .arg("./wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes
+    tqdm==1000.0.0 \
+        --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Include hashes from the URL in the generated output.
 #[test]
 fn generate_hashes_source_distribution_url() -> Result<()> {
@@ -6717,7 +6800,7 @@ fn offline_registry() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because black was not found in the cache and you require black==23.10.1, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `black` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `black` are cached.
     "###
     );
 
@@ -6821,6 +6904,84 @@ fn offline_registry_backtrack() -> Result<()> {
     "###
     );
 
+    // Resolve with `--offline`, with a requirement on a version that isn't cached. We should list
+    // the versions that are.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--offline"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig==2.0.0 needs to be downloaded from a registry and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Resolving the dependencies of `iniconfig` (2.0.0) requires its metadata, which wasn't found in the cache, and the network was disabled. Only the following versions of `iniconfig` are cached: 1.1.1.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Record the HTTP requests made during a resolution via `--trace-http`.
+#[test]
+fn trace_http() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    // With an empty cache, the project page is fetched from the index.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--trace-http")
+            .arg("trace.jsonl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --trace-http trace.jsonl
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    let trace = fs_err::read_to_string(context.temp_dir.child("trace.jsonl"))?;
+    assert!(trace.lines().any(|line| {
+        line.contains(r#""method":"GET","url":"https://pypi.org/simple/iniconfig/","status":200"#)
+            && line.contains(r#""cache":"miss""#)
+    }));
+
+    // With a populated cache, the project page is served from the cache.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--trace-http")
+            .arg("trace.jsonl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --trace-http trace.jsonl
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    let trace = fs_err::read_to_string(context.temp_dir.child("trace.jsonl"))?;
+    assert!(trace.lines().any(|line| {
+        line.contains(r#""url":"https://pypi.org/simple/iniconfig/","status":null"#)
+            && line.contains(r#""cache":"hit""#)
+    }));
+
     Ok(())
 }
 
@@ -6848,7 +7009,7 @@ fn offline_find_links() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because tqdm was not found in the cache and you require tqdm, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `tqdm` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `tqdm` are cached.
     "###
     );
 
@@ -6867,7 +7028,7 @@ fn offline_find_links() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because tqdm was not found in the cache and you require tqdm, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `tqdm` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `tqdm` are cached.
     "###
     );
 
@@ -15371,3 +15532,55 @@ fn respect_non_local_preference() -> Result<()> {
 
     Ok(())
 }
+
+/// Abort resolution once the iteration budget is exhausted.
+#[test]
+fn max_resolver_iterations() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+\.\d+s\)", "([TIME])")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("scripts").join("links"))
+            .arg("--max-resolver-iterations")
+            .arg("1"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Resolution exceeded the iteration limit of 1 after 2 iterations ([TIME])
+      help: Consider increasing the limit with `--max-resolver-iterations` or `--resolver-timeout`
+    "###
+    );
+
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("scripts").join("links"))
+            .env(EnvVars::UV_MAX_RESOLVER_ITERATIONS, "100"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    ok==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
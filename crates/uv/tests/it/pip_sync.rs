@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::env::consts::EXE_SUFFIX;
 use std::path::Path;
 use std::process::Command;
+use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
@@ -10,6 +12,8 @@ use fs_err as fs;
 use indoc::indoc;
 use predicates::Predicate;
 use url::Url;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::common::{
     download_to_disk, site_packages_path, uv_snapshot, venv_to_interpreter, TestContext,
@@ -189,6 +193,38 @@ fn install_copy() -> Result<()> {
     Ok(())
 }
 
+/// Install a package into a virtual environment using copy-on-write semantics, via the `reflink`
+/// alias for `clone`. On filesystems without reflink support, uv falls back to copying.
+#[test]
+fn install_reflink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("reflink")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    context
+        .assert_command("from markupsafe import Markup")
+        .success();
+
+    // Removing the cache shouldn't invalidate the virtual environment.
+    fs::remove_dir_all(context.cache_dir.path())?;
+
+    context
+        .assert_command("from markupsafe import Markup")
+        .success();
+
+    Ok(())
+}
+
 /// Install a package into a virtual environment using hardlink semantics.
 #[test]
 fn install_hardlink() -> Result<()> {
@@ -295,6 +331,56 @@ fn install_symlink_no_cache() -> Result<()> {
     Ok(())
 }
 
+/// Restore the environment if the installation fails after packages have been uninstalled.
+#[test]
+fn install_rollback() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==1.1.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Upgrading fails after `iniconfig==1.1.1` has already been uninstalled.
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--no-cache"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    error: Symlink-based installation is not supported with `--no-cache`. The created environment will be rendered unusable by the removal of the cache.
+    "###
+    );
+
+    // The original installation should be restored.
+    uv_snapshot!(context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==1.1.1
+
+    ----- stderr -----
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
 /// Install multiple packages into a virtual environment.
 #[test]
 fn install_many() -> Result<()> {
@@ -2241,6 +2327,60 @@ fn refresh_package() -> Result<()> {
     Ok(())
 }
 
+/// Refresh the cached metadata for a named index, without invalidating the cached wheels.
+#[test]
+fn refresh_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index")
+        .arg("pypi=https://pypi.org/simple")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
+
+    // Re-run the installation with `--refresh-index`. The wheels should be reused from the cache.
+    context.reset_venv();
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index")
+        .arg("pypi=https://pypi.org/simple")
+        .arg("--refresh-index")
+        .arg("pypi")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
 #[test]
 fn sync_editable() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2774,7 +2914,7 @@ fn find_links_offline_no_match() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because numpy was not found in the cache and you require numpy, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `numpy` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `numpy` are cached.
     "###
     );
 
@@ -2899,7 +3039,7 @@ fn offline() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because black was not found in the cache and you require black==23.10.1, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `black` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `black` are cached.
     "###
     );
 
@@ -3298,6 +3438,91 @@ fn compile() -> Result<()> {
     Ok(())
 }
 
+/// Install with bytecode compilation at an optimization level of `2` (as in `python -OO`).
+#[test]
+fn compile_optimize() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict")
+        .env(EnvVars::UV_COMPILE_BYTECODE_OPTIMIZE, "2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.opt-2.pyc")
+        .exists());
+
+    Ok(())
+}
+
+/// Install with `SOURCE_DATE_EPOCH` set, which should produce reproducible modification times
+/// and hash-based bytecode.
+#[test]
+fn compile_reproducible() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "315532800"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let epoch = std::time::UNIX_EPOCH + std::time::Duration::from_secs(315_532_800);
+    let package = context.site_packages().join("markupsafe");
+    let bytecode = package.join("__pycache__").join("__init__.cpython-312.pyc");
+    for path in [
+        package.join("__init__.py"),
+        package.join("__pycache__"),
+        bytecode.clone(),
+        context
+            .site_packages()
+            .join("MarkupSafe-2.1.3.dist-info")
+            .join("RECORD"),
+    ] {
+        assert_eq!(fs::metadata(&path)?.modified()?, epoch);
+    }
+
+    // The bytecode should use `checked-hash` invalidation (flags of `0b11`), per PEP 552.
+    let header = fs::read(&bytecode)?;
+    assert_eq!(header[4..8], [3, 0, 0, 0]);
+
+    Ok(())
+}
+
 /// Re-install with bytecode compilation.
 #[test]
 fn recompile() -> Result<()> {
@@ -3665,6 +3890,169 @@ fn require_hashes_source_no_binary() -> Result<()> {
     Ok(())
 }
 
+/// Mount an in-memory remote cache, storing the body of each `PUT` request and serving it to
+/// subsequent `GET` requests.
+async fn mount_remote_cache(server: &MockServer) {
+    let entries = Arc::new(Mutex::new(HashMap::<String, Vec<u8>>::new()));
+
+    let stored = entries.clone();
+    Mock::given(method("PUT"))
+        .respond_with(move |req: &wiremock::Request| {
+            stored
+                .lock()
+                .unwrap()
+                .insert(req.url.path().to_string(), req.body.clone());
+            ResponseTemplate::new(200)
+        })
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(move |req: &wiremock::Request| {
+            match entries.lock().unwrap().get(req.url.path()) {
+                Some(body) => ResponseTemplate::new(200).set_body_bytes(body.clone()),
+                None => ResponseTemplate::new(404),
+            }
+        })
+        .mount(server)
+        .await;
+}
+
+/// Share the wheel built from a source distribution with a known hash via a remote cache.
+#[tokio::test]
+async fn remote_cache() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+    let server = MockServer::start().await;
+    mount_remote_cache(&server).await;
+    let endpoint = server.uri();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution==0.0.1 --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+
+    // The wheel is built, then stored in the remote cache.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes")
+        .env(EnvVars::UV_REMOTE_CACHE_URL, format!("{endpoint}/cache")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+    let stored = |requests: &[wiremock::Request], method: &str| {
+        requests
+            .iter()
+            .filter(|req| {
+                req.method.as_str() == method
+                    && req.url.path().starts_with("/cache/v0/")
+                    && req.url.path().contains("/wheels/")
+            })
+            .count()
+    };
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(stored(&requests, "PUT"), 1);
+
+    // With an empty local cache, the wheel is fetched from the remote cache rather than rebuilt.
+    context.clean().assert().success();
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes")
+        .arg("--reinstall")
+        .env(EnvVars::UV_REMOTE_CACHE_URL, format!("{endpoint}/cache")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ source-distribution==0.0.1
+    "###
+    );
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(stored(&requests, "PUT"), 1);
+    assert_eq!(stored(&requests, "GET"), 2);
+
+    Ok(())
+}
+
+/// Reuse the wheel built from a source distribution with a known hash, regardless of where the
+/// source distribution was fetched from.
+#[test]
+fn reuse_built_wheel() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    // Build the source distribution from the index.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution==0.0.1 --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // The wheel is stored by package name, source distribution, and build environment.
+    let built = context
+        .cache_dir
+        .child("sdists-v9")
+        .child("built")
+        .child("source-distribution");
+    assert_eq!(fs::read_dir(&built)?.count(), 1);
+
+    // The same source distribution from a direct URL reuses the wheel, rather than building it
+    // again.
+    requirements_txt
+        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Using previously built wheel for:"));
+    assert_eq!(fs::read_dir(&built)?.count(), 1);
+
+    // Refreshing the package builds it again.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall")
+        .arg("--refresh-package")
+        .arg("source-distribution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
+    "###
+    );
+
+    Ok(())
+}
+
 /// Include the hash for _just_ the source distribution, with `--binary-only`.
 #[test]
 fn require_hashes_source_only_binary() -> Result<()> {
@@ -5495,6 +5883,71 @@ fn prefix() -> Result<()> {
     Ok(())
 }
 
+/// Sync to a `--prefix` directory, placing scripts and packages according to the prefix's scheme.
+#[cfg(unix)]
+#[test]
+fn prefix_scheme() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("pip==24.0")?;
+
+    let prefix = context.temp_dir.child("prefix");
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pip==24.0
+    "###);
+
+    // The package is installed into the prefix's `site-packages`, and its scripts into the
+    // prefix's `bin`, with the `RECORD` pointing at the scripts relative to `site-packages`.
+    let site_packages = site_packages_path(prefix.path(), "python3.12");
+    assert!(site_packages.join("pip").is_dir());
+    prefix
+        .child("bin")
+        .child("pip")
+        .assert(predicates::path::is_file());
+    let record = fs::read_to_string(site_packages.join("pip-24.0.dist-info").join("RECORD"))?;
+    assert!(record
+        .lines()
+        .any(|line| line.starts_with("../../../bin/pip,")));
+
+    // The installed package is discovered in the prefix, such that a second sync is a no-op.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Audited 1 package in [TIME]
+    "###);
+
+    // And nothing is installed into the environment itself.
+    assert!(!site_packages_path(&context.venv, "python3.12")
+        .join("pip")
+        .exists());
+
+    Ok(())
+}
+
 /// Ensure that we install packages with markers on them.
 #[test]
 fn preserve_markers() -> Result<()> {
@@ -141,6 +141,62 @@ fn uninstall() -> Result<()> {
     Ok(())
 }
 
+/// Uninstall a package alongside any dependencies that are no longer required.
+#[test]
+fn uninstall_autoremove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0\nidna==3.6\nsniffio==1.3.1\niniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("anyio")
+        .arg("--autoremove")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 3 packages
+     - anyio==4.3.0
+     - idna==3.6
+     - sniffio==1.3.1
+    "###
+    );
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("anyio")
+        .arg("--autoremove"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 3 packages in [TIME]
+     - anyio==4.3.0
+     - idna==3.6
+     - sniffio==1.3.1
+    "###
+    );
+
+    // Unrelated packages are retained.
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import iniconfig")
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
 #[test]
 fn missing_record() -> Result<()> {
     let context = TestContext::new("3.12");
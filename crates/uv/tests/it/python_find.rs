@@ -664,6 +664,85 @@ fn python_find_venv_invalid() {
     "###);
 }
 
+#[test]
+fn python_find_script() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // Without a requirement, we find the first interpreter on the path.
+    let script = context.temp_dir.child("script.py");
+    script
+        .write_str(indoc! {r#"
+        import sys
+    "#})
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    // The `requires-python` of the script should be respected.
+    script
+        .write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = []
+        # ///
+        import sys
+    "#})
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###);
+
+    // As should the Python version in the shebang, even without PEP 723 metadata.
+    script
+        .write_str(indoc! {r#"
+        #!/usr/bin/env python3.12
+        import sys
+    "#})
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###);
+
+    // An explicit request takes precedence over the shebang.
+    uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("script.py").arg("3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    // A missing script is an error.
+    uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("missing.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read `missing.py` (not found)
+    "###);
+}
+
 /// See: <https://github.com/astral-sh/uv/issues/11825>
 #[test]
 #[cfg(unix)]
@@ -1,11 +1,14 @@
 use std::{path::Path, process::Command};
 
-use crate::common::{uv_snapshot, TestContext};
+use crate::common::{uv_snapshot, venv_to_interpreter, TestContext};
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{
     assert::PathAssert,
-    prelude::{FileTouch, PathChild, PathCreateDir},
+    prelude::{FileTouch, FileWriteStr, PathChild, PathCreateDir},
 };
+use indoc::formatdoc;
 use predicates::prelude::predicate;
+use url::Url;
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 
@@ -88,6 +91,7 @@ fn python_install() {
     Searching for Python versions matching: Python 3.13
     Uninstalled Python 3.13.2 in [TIME]
      - cpython-3.13.2-[PLATFORM]
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
     "###);
 }
 
@@ -406,6 +410,7 @@ fn python_install_preview() {
     Searching for Python versions matching: Python 3.13
     Uninstalled Python 3.13.2 in [TIME]
      - cpython-3.13.2-[PLATFORM] (python, python3, python3.13)
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
     "###);
 
     // The executable should be removed
@@ -447,6 +452,424 @@ fn python_install_preview() {
     }
 }
 
+#[test]
+fn python_upgrade() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Nothing to upgrade yet
+    uv_snapshot!(context.filters(), context.python_upgrade(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No Python installations found
+    "###);
+
+    // Install an outdated patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.6 in [TIME]
+     + cpython-3.12.6-[PLATFORM]
+    "###);
+
+    // Create a virtual environment from it
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let python = venv_to_interpreter(&context.venv);
+    uv_snapshot!(context.filters(), Command::new(&python).arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.6
+
+    ----- stderr -----
+    "###);
+
+    // Requesting a minor version that isn't installed should fail
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.11"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No Python installations found matching Python 3.11
+    "###);
+
+    // Upgrade to the latest patch version
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from a specific patch version, rather than through a minor version link, still use the previous version
+    "###);
+
+    // The virtual environment should use the upgraded version
+    uv_snapshot!(context.filters(), Command::new(&python).arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.9
+
+    ----- stderr -----
+    "###);
+
+    // Upgrading again should be a no-op
+    uv_snapshot!(context.filters(), context.python_upgrade(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All Python versions are already on the latest patch release
+    "###);
+}
+
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "System configuration is not read from `XDG_CONFIG_DIRS`"
+)]
+fn python_install_policy() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Only allow downloads of Python 3.12
+    let config_dir = context.temp_dir.child("config");
+    config_dir
+        .child("uv")
+        .child("python-policy.toml")
+        .write_str(r#"allowed-versions = ["3.12"]"#)
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13").env(EnvVars::XDG_CONFIG_DIRS, config_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The download for request `cpython-3.13-[PLATFORM]` is not allowed by the system Python policy
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").env(EnvVars::XDG_CONFIG_DIRS, config_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+}
+
+#[cfg(unix)]
+#[test]
+fn python_install_adopt() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+    let pyenv_root = context.temp_dir.child("pyenv");
+
+    // Without any pyenv installations, there's nothing to adopt
+    uv_snapshot!(context.filters(), context.python_install().arg("--adopt").env(EnvVars::PYENV_ROOT, pyenv_root.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No Python installations of pyenv or asdf found
+    "###);
+
+    // Create a pyenv installation of the test interpreter
+    let bin = pyenv_root.child("versions").child("3.12").child("bin");
+    bin.create_dir_all().unwrap();
+    let executable = &context.python_versions[0].1;
+    std::os::unix::fs::symlink(executable, bin.child("python3")).unwrap();
+    std::os::unix::fs::symlink(executable, bin.child("python3.12")).unwrap();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--adopt").arg("3.12").env(EnvVars::PYENV_ROOT, pyenv_root.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Adopted Python 3.12.[X] in [TIME]
+     + cpython-3.12.[X]-[PLATFORM] (from pyenv)
+    "###);
+
+    // Adopting it again is a no-op
+    uv_snapshot!(context.filters(), context.python_install().arg("--adopt").arg("3.12").env(EnvVars::PYENV_ROOT, pyenv_root.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested versions already installed
+    "###);
+
+    // A version that pyenv doesn't provide can't be adopted
+    uv_snapshot!(context.filters(), context.python_install().arg("--adopt").arg("3.11").env(EnvVars::PYENV_ROOT, pyenv_root.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No Python installation of pyenv or asdf found for `3.11`
+    "###);
+
+    // Uninstalling the adopted version leaves the pyenv installation intact
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12
+    Uninstalled Python 3.12.[X] in [TIME]
+     - cpython-3.12.[X]-[PLATFORM]
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
+    "###);
+
+    bin.child("python3.12").assert(predicate::path::exists());
+}
+
+#[test]
+fn python_uninstall_dependent_environment() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install a version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    // Create a virtual environment from it
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Uninstalling the version should warn about the broken virtual environment
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12
+    Uninstalled Python 3.12.9 in [TIME]
+     - cpython-3.12.9-[PLATFORM]
+    warning: The virtual environment at `.venv` was created with an uninstalled Python version (run `uv venv .venv` to recreate)
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
+    "###);
+}
+
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn python_move() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+    let config = context.temp_dir.child("config");
+
+    // Install a version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    // Create a virtual environment from it
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Moving the installations while `UV_PYTHON_INSTALL_DIR` is set should fail, since the
+    // variable would take precedence over the persisted location
+    uv_snapshot!(context.filters(), context.python_move().arg("moved")
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `UV_PYTHON_INSTALL_DIR` would take precedence over the moved Python versions; unset it and use `--install-dir` to select the installations to move
+    "###);
+
+    config.assert(predicate::path::missing());
+
+    // Move the installations, which should update the virtual environment
+    let target = context.temp_dir.child("moved");
+    uv_snapshot!(context.filters(), context.python_move().arg("moved")
+        .arg("--install-dir").arg("managed")
+        .env_remove(EnvVars::UV_PYTHON_INSTALL_DIR)
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Moved Python 3.12.9 to `moved` in [TIME]
+    Relinked 1 environment to the moved versions
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were relinked; other virtual environments created from the moved versions must be re-created with `uv venv`
+    Updated `python-install-dir` in `config/uv/uv.toml`
+    "###);
+
+    target.assert(predicate::path::is_dir());
+    context
+        .temp_dir
+        .child("managed")
+        .assert(predicate::path::missing());
+    config
+        .child("uv")
+        .child("uv.toml")
+        .assert(predicate::str::contains("python-install-dir = "));
+
+    // The moved version should be discovered from the persisted installation directory
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12")
+        .env_remove(EnvVars::UV_PYTHON_INSTALL_DIR)
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/moved/cpython-3.12.9-[PLATFORM]/bin/python3.12
+
+    ----- stderr -----
+    "###);
+
+    // And the virtual environment should still work
+    let python = venv_to_interpreter(&context.venv);
+    uv_snapshot!(context.filters(), Command::new(&python).arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.9
+
+    ----- stderr -----
+    "###);
+}
+
+#[cfg(unix)]
+#[test]
+fn python_shim() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+
+    // Install a version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    // Existing executables should not be replaced
+    context.bin_dir.child("python3").touch().unwrap();
+    uv_snapshot!(context.filters(), context.python_shim(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Executable already exists at `[BIN]/python3` (use `--force` to replace it)
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_shim().arg("--force"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python shims for `python` and `python3` in `[BIN]`
+    "###);
+
+    // The shims should run the managed version
+    uv_snapshot!(context.filters(), Command::new(context.bin_dir.child("python3").as_os_str())
+        .arg("--version")
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .env(EnvVars::UV_CACHE_DIR, context.cache_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.child("managed").as_os_str())
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.9
+
+    ----- stderr -----
+    "###);
+
+    // Installing the shims again is a no-op
+    uv_snapshot!(context.filters(), context.python_shim(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python shims for `python` and `python3` in `[BIN]`
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_shim().arg("--uninstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed the Python shims from `[BIN]`
+    "###);
+
+    context
+        .bin_dir
+        .child("python")
+        .assert(predicate::path::missing());
+    context
+        .bin_dir
+        .child("python3")
+        .assert(predicate::path::missing());
+}
+
 #[test]
 fn python_install_preview_upgrade() {
     let context = TestContext::new_with_versions(&[])
@@ -615,48 +1038,165 @@ fn python_install_freethreaded() {
     #[cfg(unix)]
     bin_python.assert(predicate::path::is_symlink());
 
-    // The executable should "work"
-    uv_snapshot!(context.filters(), Command::new(bin_python.as_os_str())
-        .arg("-c").arg("import subprocess; print('hello world')"), @r###"
+    // The executable should "work"
+    uv_snapshot!(context.filters(), Command::new(bin_python.as_os_str())
+        .arg("-c").arg("import subprocess; print('hello world')"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello world
+
+    ----- stderr -----
+    "###);
+
+    // Should be distinct from 3.13
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.2 in [TIME]
+     + cpython-3.13.2-[PLATFORM]
+    "###);
+
+    // Should not work with older Python versions
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12t"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No download found for request: cpython-3.12t-[PLATFORM]
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled 2 versions in [TIME]
+     - cpython-3.13.2-[PLATFORM]
+     - cpython-3.13.2+freethreaded-[PLATFORM] (python3.13t)
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
+    "###);
+}
+
+/// Install a statically linked musl build, which runs on glibc hosts too.
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn python_install_musl() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--variant").arg("musl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-linux-x86_64-musl
+    "###);
+
+    // The installation should be discovered, even on a glibc host.
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/cpython-3.12.9-linux-x86_64-musl/bin/python3.12
+
+    ----- stderr -----
+    "###);
+}
+
+/// List a managed installation, filtering by architecture.
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn python_install_list_managed() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context.python_install().arg("3.13").assert().success();
+
+    // The installation is reported as managed.
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-managed").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"key":"cpython-3.13.2-linux-x86_64-gnu","version":"3.13.2","version_parts":{"major":3,"minor":13,"patch":2},"path":"[TEMP_DIR]/managed/cpython-3.13.2-linux-x86_64-gnu/bin/python3.13","symlink":null,"url":null,"os":"linux","variant":"default","implementation":"cpython","arch":"x86_64","libc":"gnu","source":"managed"}]
+
+    ----- stderr -----
+    "###);
+
+    // The installation matches its own architecture...
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-managed").arg("--arch").arg("x86_64"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.13.2-linux-x86_64-gnu    [TEMP_DIR]/managed/cpython-3.13.2-linux-x86_64-gnu/bin/python3.13
+
+    ----- stderr -----
+    "###);
+
+    // ...and is filtered out for any other.
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-managed").arg("--arch").arg("aarch64"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
-    hello world
 
     ----- stderr -----
     "###);
+}
 
-    // Should be distinct from 3.13
-    uv_snapshot!(context.filters(), context.python_install().arg("3.13"), @r###"
+/// Install an x86-64 build on ARM64 macOS, which runs with Rosetta 2.
+#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+#[test]
+fn python_install_emulated_arch() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--arch").arg("x86_64"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Installed Python 3.13.2 in [TIME]
-     + cpython-3.13.2-[PLATFORM]
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-macos-x86_64-none
     "###);
 
-    // Should not work with older Python versions
-    uv_snapshot!(context.filters(), context.python_install().arg("3.12t"), @r###"
-    success: false
-    exit_code: 2
+    // The installation should be discovered, even on an ARM64 host.
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    [TEMP_DIR]/managed/cpython-3.12.9-macos-x86_64-none/bin/python3.12
 
     ----- stderr -----
-    error: No download found for request: cpython-3.12t-[PLATFORM]
     "###);
 
-    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r###"
+    // Environments created from it should target x86-64.
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let python = venv_to_interpreter(&context.venv);
+    uv_snapshot!(context.filters(), Command::new(&python).arg("-c").arg("import platform; print(platform.machine())"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
+    x86_64
 
     ----- stderr -----
-    Searching for Python installations
-    Uninstalled 2 versions in [TIME]
-     - cpython-3.13.2-[PLATFORM]
-     - cpython-3.13.2+freethreaded-[PLATFORM] (python3.13t)
     "###);
 }
 
@@ -769,6 +1309,7 @@ fn python_install_default() {
     Searching for Python installations
     Uninstalled Python 3.13.2 in [TIME]
      - cpython-3.13.2-[PLATFORM] (python, python3, python3.13)
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
     "###);
 
     // The executables should be removed
@@ -802,6 +1343,7 @@ fn python_install_default() {
     Searching for Python versions matching: Python 3.13
     Uninstalled Python 3.13.2 in [TIME]
      - cpython-3.13.2-[PLATFORM] (python, python3, python3.13)
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
     "###);
 
     // We should remove all the executables
@@ -1113,6 +1655,7 @@ fn python_install_default_from_env() {
     Uninstalled 2 versions in [TIME]
      - cpython-3.11.11-[PLATFORM]
      - cpython-3.12.9-[PLATFORM]
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
     "###);
 
     // Uninstall with no targets should error
@@ -1148,7 +1691,6 @@ fn python_install_default_from_env() {
 #[cfg(target_os = "macos")]
 #[test]
 fn python_install_patch_dylib() {
-    use assert_cmd::assert::OutputAssertExt;
     use uv_python::managed::platform_key_from_env;
 
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -1190,3 +1732,413 @@ fn python_install_patch_dylib() {
     ----- stderr -----
     "###);
 }
+
+#[test]
+fn python_install_from_dir() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let archives = context.temp_dir.child("archives");
+    archives.create_dir_all().unwrap();
+
+    let mut filters = context.filters();
+    filters.push((r"cpython-3\.12\.9\+\d+-[^`]+", "[ARCHIVE]"));
+
+    // Without the archive, the installation should fail, rather than downloading it.
+    uv_snapshot!(filters, context.python_install().arg("3.12.9").arg("--from-dir").arg("archives"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install cpython-3.12.9-[PLATFORM]
+      Caused by: No archive found for `cpython-3.12.9-[PLATFORM]` in `archives` (expected `[ARCHIVE]`)
+    "###);
+}
+
+#[cfg(unix)]
+#[test]
+fn python_install_build_from_source() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+
+    // There's no pre-built distribution for the platform.
+    uv_snapshot!(context.filters(), context.python_install().arg("cpython-3.12-linux-aarch64-musl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No musl Python distribution found for `cpython-3.12-linux-aarch64-musl`; musl distributions are only available for x86-64 Linux
+    "###);
+
+    // With `--build-from-source`, the latest pinned source distribution is built instead, which
+    // can't be downloaded offline.
+    uv_snapshot!(context.filters(), context.python_install().arg("cpython-3.12-linux-aarch64-musl").arg("--build-from-source").arg("--offline"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Building cpython-3.12.9-linux-aarch64-musl from source; this may take a while
+    error: Failed to install cpython-3.12.9-linux-aarch64-musl
+      Caused by: Failed to download https://www.python.org/ftp/python/3.12.9/Python-3.12.9.tgz
+      Caused by: Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://www.python.org/ftp/python/3.12.9/Python-3.12.9.tgz`
+    "###);
+
+    // Versions without a pinned source distribution can't be built.
+    uv_snapshot!(context.filters(), context.python_install().arg("cpython-3.12.8-linux-aarch64-musl").arg("--build-from-source"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No Python distribution found for `cpython-3.12.8-linux-aarch64-musl`, and no pinned CPython source distribution to build it from
+    "###);
+}
+
+#[test]
+fn python_install_pinned_build() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    // Pin the version and the build of the installation
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12.9").arg("--build"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.12.9`
+    Pinned `.python-build.json` to `cpython-3.12.9-[PLATFORM]` (build `20250212`)
+
+    ----- stderr -----
+    "###);
+
+    let build_file = context.temp_dir.child(".python-build.json");
+    build_file.assert(predicate::str::contains(r#""build": "20250212""#));
+
+    // The installation is of the pinned build
+    uv_snapshot!(context.filters(), context.python_install(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Once uninstalled, the pinned build is downloaded again
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12.9
+    Uninstalled Python 3.12.9 in [TIME]
+     - cpython-3.12.9-[PLATFORM]
+    warning: Only the environments of installed tools, the active virtual environment, and the project `.venv` were checked; other virtual environments created from the uninstalled versions may no longer work
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_install(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    // Requesting the pinned version explicitly is satisfied by the pinned build
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // If the pinned build differs from the installation, it's replaced, and the archive is
+    // verified against the pinned checksum
+    let contents = fs_err::read_to_string(&build_file).unwrap();
+    let contents = regex::Regex::new(r#""sha256": "[0-9a-f]{64}""#)
+        .unwrap()
+        .replace(&contents, format!(r#""sha256": "{}""#, "0".repeat(64)));
+    build_file.write_str(&contents).unwrap();
+
+    context
+        .python_install()
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Hash mismatch for `cpython-3.12.9-",
+        ));
+}
+
+#[test]
+fn python_install_minimal() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // `--no-pip` requires the minimal variant
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--no-pip"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `--no-pip` flag requires the `minimal` variant (`--variant minimal`)
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--variant").arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    "###);
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    let python = venv_to_interpreter(&context.venv);
+    // Include the `site-packages` of the base interpreter, to find pip.
+    let script = "import importlib.util as util, site, sys; sys.path.extend(site.getsitepackages([sys.base_prefix])); print([util.find_spec(name) is not None for name in ('test', 'tkinter', 'idlelib', 'pip')])";
+
+    // The test suite and Tk are omitted, but pip is retained
+    uv_snapshot!(context.filters(), Command::new(&python).arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [False, False, False, True]
+
+    ----- stderr -----
+    "###);
+
+    // The existing installation is stripped of pip, too
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--variant").arg("minimal").arg("--no-pip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), Command::new(&python).arg("-c").arg(script), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [False, False, False, False]
+
+    ----- stderr -----
+    "###);
+
+    // The `gnu` and `musl` variants can't be combined
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--variant").arg("gnu,musl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `gnu` and `musl` variants can't be combined
+    "###);
+}
+
+/// Verify a distribution from a mirror against the checksum published alongside it.
+#[test]
+fn python_install_mirror_checksum() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Serve a distribution without a known checksum from a mirror.
+    let archive = "20250101/cpython-3.12.99-install_only.tar.gz";
+    let mirror = context.temp_dir.child("mirror");
+    mirror.child("20250101").create_dir_all().unwrap();
+    {
+        let file = fs_err::File::create(mirror.child(archive).path()).unwrap();
+        let mut tar = tar::Builder::new(flate2::write::GzEncoder::new(
+            file,
+            flate2::Compression::default(),
+        ));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(0);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, "python/README", std::io::empty())
+            .unwrap();
+        tar.into_inner().unwrap().finish().unwrap();
+    }
+
+    let libc = if cfg!(target_os = "linux") {
+        "gnu"
+    } else {
+        "none"
+    };
+    let manifest = context.temp_dir.child("download-metadata.json");
+    manifest
+        .write_str(&formatdoc! {r#"
+            {{
+              "cpython-3.12.99-{os}-{arch}-{libc}": {{
+                "name": "cpython",
+                "arch": {{ "family": "{arch}", "variant": null }},
+                "os": "{os}",
+                "libc": "{libc}",
+                "major": 3,
+                "minor": 12,
+                "patch": 99,
+                "prerelease": "",
+                "url": "https://github.com/astral-sh/python-build-standalone/releases/download/{archive}",
+                "sha256": null,
+                "variant": null
+              }}
+            }}
+            "#,
+            os = std::env::consts::OS,
+            arch = std::env::consts::ARCH,
+        })
+        .unwrap();
+
+    // An unreadable checksum is rejected.
+    mirror
+        .child(format!("{archive}.sha256"))
+        .write_str("<html>Not Found</html>")
+        .unwrap();
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12.99")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, manifest.path())
+        .env(EnvVars::UV_PYTHON_INSTALL_MIRROR, Url::from_directory_path(mirror.path()).unwrap().as_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install cpython-3.12.99-[PLATFORM]
+      Caused by: Failed to read checksum from `file://[TEMP_DIR]/mirror/20250101/cpython-3.12.99-install_only.tar.gz.sha256`; expected a SHA-256 digest
+    "###);
+
+    // A distribution that doesn't match its checksum is rejected, with the URL it was read from.
+    mirror
+        .child(format!("{archive}.sha256"))
+        .write_str(&format!(
+            "{}  cpython-3.12.99-install_only.tar.gz\n",
+            "0".repeat(64)
+        ))
+        .unwrap();
+    context
+        .python_install()
+        .arg("3.12.99")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, manifest.path())
+        .env(
+            EnvVars::UV_PYTHON_INSTALL_MIRROR,
+            Url::from_directory_path(mirror.path()).unwrap().as_str(),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "(downloaded from `{}`)",
+            Url::from_file_path(mirror.child(archive).path()).unwrap()
+        )));
+}
+
+/// Returns `true` if the given Python installation is registered in the Windows registry.
+#[cfg(windows)]
+fn is_registered(tag: &str) -> bool {
+    Command::new("reg")
+        .arg("query")
+        .arg(format!("HKCU\\Software\\Python\\Astral\\{tag}"))
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[test]
+#[cfg(windows)]
+fn python_install_registry() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+
+    // Without `--registry` or preview mode, the installation isn't registered.
+    context.python_install().arg("3.10.14").assert().success();
+    assert!(!is_registered("CPython3.10.14"));
+
+    // With `--registry`, it is.
+    context
+        .python_install()
+        .arg("--registry")
+        .arg("--reinstall")
+        .arg("3.10.14")
+        .assert()
+        .success();
+    assert!(is_registered("CPython3.10.14"));
+
+    // Upgrades are only registered on request, too.
+    context
+        .python_upgrade()
+        .arg("--no-registry")
+        .arg("3.10")
+        .assert()
+        .success();
+    assert!(!is_registered("CPython3.10.16"));
+
+    // Without `--registry` or preview mode, uninstalling leaves the registry untouched.
+    context.python_uninstall().arg("3.10.16").assert().success();
+    assert!(is_registered("CPython3.10.14"));
+
+    // With `--registry`, the entries are removed.
+    context
+        .python_uninstall()
+        .arg("--registry")
+        .arg("3.10.14")
+        .assert()
+        .success();
+    assert!(!is_registered("CPython3.10.14"));
+}
+
+#[test]
+#[cfg(windows)]
+fn python_upgrade_registry() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+
+    context.python_install().arg("3.9.18").assert().success();
+
+    // The `--registry` setting is applied to the upgraded installation.
+    context
+        .python_upgrade()
+        .arg("--registry")
+        .arg("3.9")
+        .assert()
+        .success();
+    assert!(!is_registered("CPython3.9.18"));
+    assert!(is_registered("CPython3.9.21"));
+
+    context
+        .python_uninstall()
+        .arg("--registry")
+        .arg("--all")
+        .assert()
+        .success();
+    assert!(!is_registered("CPython3.9.21"));
+}
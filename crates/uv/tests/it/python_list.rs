@@ -0,0 +1,140 @@
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use indoc::formatdoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn python_list_only_managed() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // The test interpreters are not managed by uv, so none are listed.
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-managed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-managed").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    "###);
+}
+
+#[test]
+fn python_list_arch() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // An unknown architecture is rejected.
+    uv_snapshot!(context.filters(), context.python_list().arg("--arch").arg("foo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'foo' for '--arch <ARCH>': Unknown architecture: foo
+
+    For more information, try '--help'.
+    "###);
+
+    // The test interpreters are not built for this architecture, so none are listed.
+    uv_snapshot!(context.filters(), context.python_list().arg("--only-installed").arg("--arch").arg("s390x"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+}
+
+#[test]
+fn python_list_downloads_json_url() {
+    let context: TestContext = TestContext::new_with_versions(&[]);
+
+    let entry = |version: &str, variant: &str| {
+        let [major, minor, patch] = version.split('.').collect::<Vec<_>>()[..] else {
+            unreachable!()
+        };
+        formatdoc! {r#"
+            {{
+              "name": "cpython",
+              "arch": {{ "family": "x86_64", "variant": null }},
+              "os": "linux",
+              "libc": "gnu",
+              "major": {major},
+              "minor": {minor},
+              "patch": {patch},
+              "prerelease": "",
+              "url": "https://python.example.com/cpython-{version}{variant}.tar.gz",
+              "sha256": null,
+              "variant": {}
+            }}"#,
+            if variant.is_empty() {
+                "null".to_string()
+            } else {
+                format!(r#""{variant}""#)
+            },
+        }
+    };
+    let manifest = context.temp_dir.child("download-metadata.json");
+    manifest
+        .write_str(&format!(
+            r#"{{ "cpython-3.12.9-linux-x86_64-gnu": {}, "cpython-3.13.2-linux-x86_64-gnu": {}, "cpython-3.13.2+debug-linux-x86_64-gnu": {} }}"#,
+            entry("3.12.9", ""),
+            entry("3.13.2", ""),
+            entry("3.13.2", "debug"),
+        ))
+        .unwrap();
+
+    // Only the downloads in the manifest are listed, omitting debug builds.
+    uv_snapshot!(context.filters(), context.python_list()
+        .arg("--only-downloads")
+        .arg("--all-platforms")
+        .arg("--show-urls")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, manifest.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.13.2-linux-x86_64-gnu    https://python.example.com/cpython-3.13.2.tar.gz
+    cpython-3.12.9-linux-x86_64-gnu    https://python.example.com/cpython-3.12.9.tar.gz
+
+    ----- stderr -----
+    "###);
+
+    // The manifest must be a local file.
+    uv_snapshot!(context.filters(), context.python_list()
+        .arg("--only-downloads")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, "https://example.com/download-metadata.json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Reading Python download manifests from remote URLs is not supported; `python-downloads-json-url` must be a local path or a `file://` URL, but found: `https://example.com/download-metadata.json`
+    "###);
+
+    // An invalid manifest is rejected.
+    context
+        .temp_dir
+        .child("invalid.json")
+        .write_str("<html>Not Found</html>")
+        .unwrap();
+    uv_snapshot!(context.filters(), context.python_list()
+        .arg("--only-downloads")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, "invalid.json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse Python download manifest: invalid.json
+      Caused by: expected value at line 1 column 1
+    "###);
+}
@@ -6,6 +6,7 @@ use uv_python::{
     platform::{Arch, Os},
     PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
 };
+use uv_static::EnvVars;
 
 #[test]
 fn python_pin() {
@@ -659,3 +660,140 @@ fn python_pin_with_comments() -> Result<()> {
 
     Ok(())
 }
+
+/// Pin a range of Python versions, and respect the range during discovery.
+#[test]
+fn python_pin_range() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg(">=3.11,<3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `>=3.11, <3.12`
+
+    ----- stderr -----
+    "###);
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @r#">=3.11, <3.12"#);
+
+    // The range should be respected, rather than using the latest installed version.
+    uv_snapshot!(context.filters(), context.python_find(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg(">=3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.python-version` from `>=3.11, <3.12` -> `>=3.12`
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_find(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###);
+}
+
+/// Pin a global Python version, which is used in the absence of a local pin.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn python_pin_global() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+    let config = context.temp_dir.child("config");
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--global").arg("3.11")
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `config/uv/.python-version` to `3.11`
+
+    ----- stderr -----
+    "###);
+
+    let python_version = context.read("config/uv/.python-version");
+    assert_snapshot!(python_version, @r#"3.11"#);
+
+    // Without a local pin, the global pin is used.
+    uv_snapshot!(context.filters(), context.python_pin()
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    // A local pin takes precedence over the global pin.
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12")
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.12`
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###);
+
+    // An explicit request takes precedence over both.
+    uv_snapshot!(context.filters(), context.python_find().arg("3.11")
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+
+    // Updating the global pin doesn't affect the local pin.
+    uv_snapshot!(context.filters(), context.python_pin().arg("--global").arg("3.12")
+        .env(EnvVars::XDG_CONFIG_HOME, config.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `config/uv/.python-version` from `3.11` -> `3.12`
+
+    ----- stderr -----
+    "###);
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @r#"3.12"#);
+}
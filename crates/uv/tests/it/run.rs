@@ -817,7 +817,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
@@ -926,7 +926,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
@@ -1732,7 +1732,7 @@ fn run_locked() -> Result<()> {
         assert_snapshot!(
             existing, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -64,6 +64,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -74,6 +99,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -82,6 +108,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -135,15 +169,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -175,6 +220,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -182,6 +229,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -238,6 +296,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -248,6 +331,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -256,6 +340,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -309,15 +401,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -349,6 +452,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -356,6 +461,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -413,6 +529,31 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -423,6 +564,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -431,6 +573,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -484,15 +634,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -524,6 +685,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -531,6 +694,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -620,6 +794,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -630,6 +829,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -638,6 +838,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -691,15 +899,26 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -731,6 +950,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -738,6 +959,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -796,6 +1028,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -806,6 +1063,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -814,6 +1072,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -841,11 +1107,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -877,6 +1145,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -884,6 +1154,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -951,6 +1232,31 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -961,6 +1267,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -969,6 +1276,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1022,15 +1337,26 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -1062,6 +1388,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1069,6 +1397,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1150,6 +1489,31 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1160,6 +1524,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -1168,6 +1533,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1221,6 +1594,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -1250,15 +1632,26 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -1290,6 +1683,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1297,6 +1692,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1355,6 +1761,31 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1365,6 +1796,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -1373,6 +1805,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1428,6 +1868,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -1457,6 +1906,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -1486,15 +1944,26 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -1526,6 +1995,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1533,6 +2004,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1614,6 +2096,31 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1624,6 +2131,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -1632,6 +2140,14 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1686,14 +2202,25 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 no_index: true,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -1725,6 +2252,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1732,6 +2261,17 @@ fn resolve_find_links() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1812,6 +2352,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1822,6 +2387,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -1830,6 +2396,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -1857,11 +2431,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -1893,6 +2469,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1900,6 +2478,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1973,6 +2562,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1983,6 +2597,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -1991,6 +2606,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2044,6 +2667,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -2073,15 +2705,26 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2113,6 +2756,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2120,6 +2765,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2176,6 +2832,31 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2186,6 +2867,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -2194,6 +2876,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2247,6 +2937,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -2276,15 +2975,26 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2316,6 +3026,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2323,6 +3035,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2403,6 +3126,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2413,6 +3161,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -2421,6 +3170,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2448,11 +3205,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2484,6 +3243,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2491,6 +3252,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2554,6 +3326,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2564,6 +3361,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -2572,6 +3370,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2599,11 +3405,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2635,6 +3443,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2642,6 +3452,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2705,6 +3526,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2715,6 +3561,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -2723,6 +3570,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2750,11 +3605,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2786,6 +3643,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2793,6 +3652,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2858,6 +3728,31 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2868,6 +3763,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -2876,6 +3772,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -2903,11 +3807,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -2939,6 +3845,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2946,6 +3854,17 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3030,6 +3949,31 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3040,6 +3984,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -3048,6 +3993,14 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3071,6 +4024,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             index_url: None,
             extra_index_url: None,
             no_index: None,
+            strict_index: None,
             find_links: None,
             index_strategy: None,
             keyring_provider: None,
@@ -3079,6 +4033,14 @@ fn resolve_tool() -> anyhow::Result<()> {
             ),
             prerelease: None,
             fork_strategy: None,
+            supported_python: None,
+            max_resolver_iterations: None,
+            resolver_timeout: None,
+            minimal_changes: None,
+            break_extra_cycles: None,
+            prefetch_versions: None,
+            yanked: None,
+            allow_yanked: None,
             dependency_metadata: None,
             config_settings: None,
             no_build_isolation: None,
@@ -3097,18 +4059,33 @@ fn resolve_tool() -> anyhow::Result<()> {
             no_build_package: None,
             no_binary: None,
             no_binary_package: None,
+            prefer_binary_package: None,
+            binary_only: None,
         },
         settings: ResolverInstallerSettings {
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
+            supported_python: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3126,6 +4103,8 @@ fn resolve_tool() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
         },
         force: false,
@@ -3133,6 +4112,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_downloads_json_url: None,
         },
     }
 
@@ -3191,6 +4171,31 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3201,6 +4206,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -3209,6 +4215,14 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -3236,11 +4250,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -3272,6 +4288,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3279,6 +4297,17 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3370,6 +4399,31 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3380,6 +4434,7 @@ fn resolve_both() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -3388,6 +4443,14 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -3441,15 +4504,26 @@ fn resolve_both() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -3481,6 +4555,8 @@ fn resolve_both() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3488,6 +4564,17 @@ fn resolve_both() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3667,6 +4754,31 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3677,6 +4789,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -3685,6 +4798,14 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -3738,15 +4859,26 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -3778,6 +4910,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3785,6 +4919,17 @@ fn resolve_config_file() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3852,7 +4997,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `concurrent-downloads-per-host`, `bandwidth-limit`, `http-connect-timeout`, `http-read-timeout`, `http2`, `http-pool-size`, `http-pool-idle-timeout`, `http-address-family`, `http-retries`, `index-tracking`, `range-request-size`, `index`, `index-url`, `extra-index-url`, `no-index`, `strict-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `supported-python`, `max-resolver-iterations`, `resolver-timeout`, `minimal-changes`, `break-extra-cycles`, `prefetch-versions`, `yanked`, `allow-yanked`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `prefer-binary-package`, `binary-only`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`, `allowed-licenses`, `denied-licenses`, `license-mode`, `torch-backend`, `require-hashes`, `prefer-smaller-wheels`, `exclude-dependencies`, `build-backend`
     "###
     );
 
@@ -3942,6 +5087,31 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3952,6 +5122,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -3960,6 +5131,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -3987,11 +5166,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4023,6 +5204,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4030,6 +5213,17 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4096,6 +5290,31 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4106,6 +5325,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -4114,6 +5334,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -4141,11 +5369,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4177,6 +5407,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4184,6 +5416,17 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4269,6 +5512,31 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4279,6 +5547,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -4287,6 +5556,14 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -4314,11 +5591,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4350,6 +5629,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4357,6 +5638,17 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4434,6 +5726,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4444,6 +5761,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -4452,6 +5770,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -4507,6 +5833,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -4536,15 +5871,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4576,6 +5922,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4583,6 +5931,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4639,6 +5998,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4649,6 +6033,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -4657,6 +6042,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -4712,6 +6105,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -4741,15 +6143,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4781,6 +6194,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4788,6 +6203,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4850,6 +6276,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4860,6 +6311,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -4868,6 +6320,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -4923,6 +6383,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -4952,15 +6421,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -4992,6 +6472,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4999,6 +6481,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5056,6 +6549,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5066,6 +6584,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -5074,6 +6593,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -5129,6 +6656,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -5158,15 +6694,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -5198,6 +6745,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5205,6 +6754,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5269,6 +6829,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5279,6 +6864,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -5287,6 +6873,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -5342,6 +6936,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -5371,15 +6974,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -5411,6 +7025,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5418,6 +7034,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5475,6 +7102,31 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5485,6 +7137,7 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -5493,6 +7146,14 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipCompileSettings {
         src_file: [
@@ -5548,6 +7209,15 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                     Index {
                         name: None,
@@ -5577,15 +7247,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        upload_time_fallback: None,
+                        proxy: None,
+                        mirrors: [],
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        credential_helper: None,
+                        cache_max_age: None,
+                        allow_insecure_host: false,
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -5617,6 +7298,8 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5624,6 +7307,17 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5694,6 +7388,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5704,6 +7423,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -5712,6 +7432,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -5723,6 +7451,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -5740,11 +7470,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -5776,6 +7508,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5783,6 +7517,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5839,6 +7584,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5849,6 +7619,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -5857,6 +7628,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -5868,6 +7647,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -5885,11 +7666,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -5921,6 +7704,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5928,6 +7713,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5982,6 +7778,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5992,6 +7813,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -6000,6 +7822,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -6011,6 +7841,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6028,11 +7860,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -6064,6 +7898,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6071,6 +7907,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6127,6 +7974,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6137,6 +8009,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -6145,6 +8018,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -6156,6 +8037,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6173,11 +8056,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -6209,6 +8094,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6216,6 +8103,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6270,6 +8168,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6280,6 +8203,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -6288,6 +8212,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -6299,6 +8231,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6316,11 +8250,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -6352,6 +8288,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6359,6 +8297,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6414,6 +8363,31 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            http_retries: HttpRetryPolicy {
+                default: RetryParameters {
+                    retries: 3,
+                    status_codes: [],
+                    backoff_min: 1s,
+                    backoff_max: 1800s,
+                    backoff_base: 2,
+                },
+                hosts: [],
+            },
+            limits: NetworkLimits {
+                concurrent_downloads_per_host: None,
+                bandwidth: None,
+                connect_timeout: None,
+                read_timeout: None,
+            },
+            connection: ConnectionOptions {
+                http2: None,
+                pool_size: None,
+                pool_idle_timeout: None,
+                address_family: Auto,
+            },
+            index_tracking: Warn,
+            range_request_size: 16384,
+            trace_http: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6424,6 +8398,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        python_install_dir: None,
         no_progress: false,
         installer_metadata: true,
     }
@@ -6432,6 +8407,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        retention: None,
+        lock_timeout: None,
+        no_discovery_cache: false,
+        remote_url: None,
+        base_dir: None,
+        compression: false,
+        deduplicate: false,
     }
     PipInstallSettings {
         package: [],
@@ -6443,6 +8426,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        user: false,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6460,11 +8445,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                strict_index: false,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_downloads_json_url: None,
             },
             system: false,
             extras: None,
@@ -6496,6 +8483,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_binary: [],
+                binary_only: false,
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6503,6 +8492,17 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            minimal_changes: false,
+            break_extra_cycles: false,
+            prefetch_versions: None,
+            budget: ResolutionBudget {
+                max_iterations: None,
+                timeout: None,
+            },
+            yanked: YankedPolicy {
+                mode: None,
+                allowed: [],
+            },
             dependency_metadata: DependencyMetadata(
                 {},
             ),
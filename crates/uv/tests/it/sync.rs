@@ -1076,7 +1076,7 @@ fn sync_relative_wheel() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.12"
 
             [options]
@@ -1119,6 +1119,56 @@ fn sync_relative_wheel() -> Result<()> {
     Ok(())
 }
 
+/// With `prefer-smaller-wheels`, install the smallest compatible wheel rather than the most
+/// specific one.
+#[test]
+fn sync_prefer_smaller_wheels() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `charset-normalizer` publishes platform-specific wheels alongside a smaller, pure-Python
+    // `py3-none-any` wheel.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["charset-normalizer==3.3.2"]
+
+        [tool.uv]
+        prefer-smaller-wheels = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + charset-normalizer==3.3.2
+    "###);
+
+    // The preference should be recorded in the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("prefer-smaller-wheels = true"));
+
+    // The pure-Python wheel should be installed.
+    let wheel = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("charset_normalizer-3.3.2.dist-info")
+            .join("WHEEL"),
+    )?;
+    assert!(wheel.contains("Tag: py3-none-any"));
+
+    Ok(())
+}
+
 /// Syncing against an unstable environment should fail (but locking should succeed).
 #[test]
 fn sync_environment() -> Result<()> {
@@ -2461,7 +2511,7 @@ fn sync_group_legacy_non_project_member() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -2572,7 +2622,7 @@ fn sync_group_self() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -3359,7 +3409,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -3419,7 +3469,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -3488,7 +3538,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -3553,7 +3603,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -4658,7 +4708,13 @@ fn sync_wheel_url_source_error() -> Result<()> {
     Resolved 3 packages in [TIME]
     "###);
 
-    uv_snapshot!(context.filters(), context.sync(), @r###"
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"`cp312-cp312-[^`]+`", "`cp312-cp312-[PLATFORM]`")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.sync(), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -4668,6 +4724,23 @@ fn sync_wheel_url_source_error() -> Result<()> {
     error: Distribution `cffi==1.17.1 @ direct+https://files.pythonhosted.org/packages/08/fd/cc2fedbd887223f9f5d170c96e57cbf655df9831a6546c1727ae13fa977a/cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl` can't be installed because the binary distribution is incompatible with the current platform
 
     hint: You're using CPython 3.12 (`cp312`), but  `cffi` (v1.17.1) only has wheels with the following Python ABI tag: `cp310`
+
+    hint: The current interpreter's most specific tag is `cp312-cp312-[PLATFORM]`, but `cffi` (v1.17.1) is locked with wheel tagged: `cp310-cp310-macosx_11_0_arm64`. To install an incompatible wheel anyway, use `uv sync --allow-incompatible-wheels`
+    "###);
+
+    // Install the incompatible wheel anyway.
+    uv_snapshot!(filters, context.sync().arg("--allow-incompatible-wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: Installing a wheel for `cffi` that is incompatible with the current platform
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + cffi==1.17.1 (from https://files.pythonhosted.org/packages/08/fd/cc2fedbd887223f9f5d170c96e57cbf655df9831a6546c1727ae13fa977a/cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl)
+     + pycparser==2.21
     "###);
 
     Ok(())
@@ -4709,7 +4782,13 @@ fn sync_wheel_path_source_error() -> Result<()> {
     Resolved 3 packages in [TIME]
     "###);
 
-    uv_snapshot!(context.filters(), context.sync(), @r###"
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"`cp312-cp312-[^`]+`", "`cp312-cp312-[PLATFORM]`")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.sync(), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -4719,6 +4798,8 @@ fn sync_wheel_path_source_error() -> Result<()> {
     error: Distribution `cffi==1.17.1 @ path+cffi-1.17.1-cp310-cp310-macosx_11_0_arm64.whl` can't be installed because the binary distribution is incompatible with the current platform
 
     hint: You're using CPython 3.12 (`cp312`), but  `cffi` (v1.17.1) only has wheels with the following Python ABI tag: `cp310`
+
+    hint: The current interpreter's most specific tag is `cp312-cp312-[PLATFORM]`, but `cffi` (v1.17.1) is locked with wheel tagged: `cp310-cp310-macosx_11_0_arm64`. To install an incompatible wheel anyway, use `uv sync --allow-incompatible-wheels`
     "###);
 
     Ok(())
@@ -5025,7 +5106,7 @@ fn sync_dynamic_extra() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.12"
 
             [options]
@@ -6369,7 +6450,7 @@ fn sync_stale_egg_info() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.13"
 
             [options]
@@ -6476,7 +6557,7 @@ fn sync_git_repeated_member_static_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.13"
 
             [options]
@@ -6570,7 +6651,7 @@ fn sync_git_repeated_member_dynamic_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.13"
 
             [options]
@@ -6688,7 +6769,7 @@ fn sync_git_repeated_member_backwards_path() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.13"
 
             [options]
@@ -6870,7 +6951,7 @@ fn sync_git_path_dependency() -> Result<()> {
             assert_snapshot!(
                 lock, @r###"
             version = 1
-            revision = 1
+            revision = 2
             requires-python = ">=3.13"
 
             [options]
@@ -6978,7 +7059,7 @@ fn sync_build_tag() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]
@@ -7228,6 +7309,58 @@ fn find_links_relative_in_config_works_from_subdir() -> Result<()> {
     Ok(())
 }
 
+/// Create a relocatable project environment with `--relocatable`.
+#[test]
+fn sync_relocatable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    context
+        .venv
+        .child("pyvenv.cfg")
+        .assert(predicate::str::contains("relocatable = true"));
+
+    // Syncing the existing, relocatable environment should not warn.
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "python-managed")]
 fn sync_dry_run() -> Result<()> {
@@ -7529,7 +7662,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
@@ -7634,7 +7767,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
@@ -7913,3 +8046,94 @@ fn prune_cache_url_subdirectory() -> Result<()> {
 
     Ok(())
 }
+
+/// With an explicit `yanked` policy, `uv sync` checks whether any locked distributions were
+/// yanked after the lockfile was created.
+#[test]
+fn sync_locked_yanked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Lock a yanked version under the default policy, as if it had been yanked afterwards.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Under `deny`, the sync fails.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+
+        [tool.uv]
+        yanked = "deny"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The lockfile references distributions that have since been yanked: `colorama==0.4.2`. To use them anyway, add them to `allow-yanked`, or update the lockfile with `uv lock --upgrade-package`.
+    "###);
+
+    // Under `warn`, the sync succeeds with a warning.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+
+        [tool.uv]
+        yanked = "warn"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + colorama==0.4.2
+    "###);
+
+    // Versions in `allow-yanked` are permitted, even under `deny`.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+
+        [tool.uv]
+        yanked = "deny"
+        allow-yanked = ["colorama==0.4.2"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
@@ -5,6 +5,8 @@ use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use url::Url;
 
+use uv_fs::Simplified;
+
 use crate::common::{uv_snapshot, TestContext};
 
 #[test]
@@ -914,6 +916,65 @@ fn group() -> Result<()> {
     Ok(())
 }
 
+/// Show the dependencies that are relevant for the selected groups, when groups conflict.
+#[test]
+fn group_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [dependency-groups]
+        foo = ["ok==1.0.0"]
+        bar = ["ok==2.0.0"]
+
+        [tool.uv]
+        conflicts = [
+            [
+                {{ group = "foo" }},
+                {{ group = "bar" }},
+            ],
+        ]
+        no-index = true
+        find-links = ["{}"]
+        "#,
+        context.workspace_root.join("scripts/links").portable_display(),
+    })?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.tree().arg("--group").arg("foo"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    ├── ok v1.0.0
+    └── ok v1.0.0 (group: foo)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.tree().arg("--group").arg("bar"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    ├── ok v2.0.0
+    └── ok v2.0.0 (group: bar)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn cycle() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1248,7 +1309,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
@@ -1434,7 +1495,7 @@ fn script() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.11"
 
         [options]
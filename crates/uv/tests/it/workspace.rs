@@ -1117,7 +1117,7 @@ fn workspace_inherit_sources() -> Result<()> {
       ╰─▶ Because library was not found in the cache and leaf depends on library, we can conclude that leaf's requirements are unsatisfiable.
           And because your workspace requires leaf, we can conclude that your workspace's requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+          hint: The available versions of `library` weren't found in the cache, and listing them requires network access, which was disabled. No versions of `library` are cached.
     "###
     );
 
@@ -1201,7 +1201,7 @@ fn workspace_inherit_sources() -> Result<()> {
         assert_snapshot!(
             lock, @r###"
         version = 1
-        revision = 1
+        revision = 2
         requires-python = ">=3.12"
 
         [options]